@@ -46,7 +46,9 @@ impl GameRepository for GrpcHandler {
 
         let player_action = PlayerAction {
             request_uuid: Uuid::now_v7().to_string(),
-            action: Some(Action::GetCorporation(GetCorporationRequest {})),
+            action: Some(Action::GetCorporation(GetCorporationRequest {
+                at_game_tick: None,
+            })),
         };
 
         Ok(client_action_tx.send(player_action).await?)
@@ -72,10 +74,12 @@ impl GameRepository for GrpcHandler {
                     market_uuid: req.market_uuid,
                     min_operational_expenses: req.min_operational_expenses,
                     max_operational_expenses: req.max_operational_expenses,
+                    is_auction: None,
                     sort_by: req.sort_by,
                     sort_direction: req.sort_direction,
                     limit: req.limit,
                     offset: req.offset,
+                    at_game_tick: None,
                 },
             )),
         };
@@ -42,12 +42,12 @@ pub(super) async fn handle_main<AUTH, ADMIN, GAME>(
                 app.response_list_state.select(None);
                 app.current_screen = CurrentScreen::Main(CurrentScreenMain::Services);
             }
-            KeyCode::Char('l') | KeyCode::Right => {
-                if !app.response_list_widget.responses.is_empty() {
-                    app.service_list_state.select(None);
-                    app.response_list_state.select(Some(0));
-                    app.current_screen = CurrentScreen::Main(CurrentScreenMain::Responses);
-                }
+            KeyCode::Char('l') | KeyCode::Right
+                if !app.response_list_widget.responses.is_empty() =>
+            {
+                app.service_list_state.select(None);
+                app.response_list_state.select(Some(0));
+                app.current_screen = CurrentScreen::Main(CurrentScreenMain::Responses);
             }
             KeyCode::Char('j') | KeyCode::Down => {
                 match app.current_screen {
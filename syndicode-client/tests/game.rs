@@ -96,7 +96,9 @@ async fn should_create_user() {
     client_action_tx
         .send(PlayerAction {
             request_uuid: get_corp_request_uuid.clone(),
-            action: Some(Action::GetCorporation(GetCorporationRequest {})),
+            action: Some(Action::GetCorporation(GetCorporationRequest {
+                at_game_tick: None,
+            })),
         })
         .await
         .unwrap();
@@ -14,6 +14,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "../protos/interface/v1/interface.proto",
                 "../protos/warfare/v1/warfare.proto",
                 "../protos/economy/v1/economy.proto",
+                "../protos/diplomacy/v1/diplomacy.proto",
+                "../protos/espionage/v1/espionage.proto",
             ],
             &["../protos"],
         )?;
@@ -3,7 +3,9 @@ use tonic_reflection::server::{
     Error,
 };
 
+pub mod syndicode_diplomacy_v1;
 pub mod syndicode_economy_v1;
+pub mod syndicode_espionage_v1;
 pub mod syndicode_interface_v1;
 pub mod syndicode_warfare_v1;
 
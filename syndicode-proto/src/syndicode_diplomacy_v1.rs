@@ -0,0 +1,155 @@
+// This file is @generated by prost-build.
+/// Request to propose a treaty to another corporation.
+/// The treaty takes effect once the partner accepts it.
+/// Two corporations can only have one treaty of each type, counting pending proposals in both directions.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposeTreatyRequest {
+    /// UUID of the corporation the treaty is proposed to.
+    #[prost(string, tag = "1")]
+    pub partner_corporation_uuid: ::prost::alloc::string::String,
+    /// The kind of treaty.
+    #[prost(enumeration = "TreatyType", tag = "2")]
+    pub treaty_type: i32,
+}
+/// Response after a treaty has been proposed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposeTreatyResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the proposed treaty.
+    #[prost(string, tag = "2")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+    /// The kind of treaty.
+    #[prost(enumeration = "TreatyType", tag = "3")]
+    pub treaty_type: i32,
+    /// UUID of the corporation the treaty is proposed to.
+    #[prost(string, tag = "4")]
+    pub partner_corporation_uuid: ::prost::alloc::string::String,
+}
+/// Request to accept a treaty proposed to the corporation.
+/// Accepting an alliance or a non-aggression pact calls off all attacks between the two corporations.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcceptTreatyRequest {
+    /// UUID of the treaty.
+    #[prost(string, tag = "1")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+}
+/// Response after a treaty has been accepted.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcceptTreatyResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the treaty.
+    #[prost(string, tag = "2")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+    /// The kind of treaty.
+    #[prost(enumeration = "TreatyType", tag = "3")]
+    pub treaty_type: i32,
+    /// UUID of the corporation that proposed the treaty.
+    #[prost(string, tag = "4")]
+    pub proposer_corporation_uuid: ::prost::alloc::string::String,
+}
+/// Request to break a treaty the corporation is a party to.
+/// Breaking a pending proposal withdraws or declines it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BreakTreatyRequest {
+    /// UUID of the treaty.
+    #[prost(string, tag = "1")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+}
+/// Response after a treaty has been broken.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BreakTreatyResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the treaty.
+    #[prost(string, tag = "2")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+    /// The kind of treaty.
+    #[prost(enumeration = "TreatyType", tag = "3")]
+    pub treaty_type: i32,
+    /// UUID of the other party of the treaty.
+    #[prost(string, tag = "4")]
+    pub counterparty_corporation_uuid: ::prost::alloc::string::String,
+}
+/// Notifies the corporation that another corporation has proposed a treaty to it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TreatyProposalReceivedNotification {
+    /// UUID of the treaty.
+    #[prost(string, tag = "1")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+    /// The kind of treaty.
+    #[prost(enumeration = "TreatyType", tag = "2")]
+    pub treaty_type: i32,
+    /// UUID of the corporation that proposed the treaty.
+    #[prost(string, tag = "3")]
+    pub proposer_corporation_uuid: ::prost::alloc::string::String,
+}
+/// Notifies the corporation that its proposal has been accepted and the treaty is in effect.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TreatyConcludedNotification {
+    /// UUID of the treaty.
+    #[prost(string, tag = "1")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+    /// The kind of treaty.
+    #[prost(enumeration = "TreatyType", tag = "2")]
+    pub treaty_type: i32,
+    /// UUID of the corporation that accepted the treaty.
+    #[prost(string, tag = "3")]
+    pub partner_corporation_uuid: ::prost::alloc::string::String,
+}
+/// Notifies the corporation that the other party has broken a treaty.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TreatyTerminatedNotification {
+    /// UUID of the treaty.
+    #[prost(string, tag = "1")]
+    pub treaty_uuid: ::prost::alloc::string::String,
+    /// The kind of treaty.
+    #[prost(enumeration = "TreatyType", tag = "2")]
+    pub treaty_type: i32,
+    /// UUID of the corporation that broke the treaty.
+    #[prost(string, tag = "3")]
+    pub breaking_corporation_uuid: ::prost::alloc::string::String,
+    /// Whether the treaty was in effect. A pending proposal has been withdrawn or declined otherwise.
+    #[prost(bool, tag = "4")]
+    pub was_active: bool,
+}
+/// The kinds of treaties between corporations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TreatyType {
+    Unspecified = 0,
+    /// The members don't attack each other and see each other's holdings.
+    Alliance = 1,
+    /// The members don't attack each other.
+    NonAggression = 2,
+    /// The members don't charge each other fees for listing businesses.
+    TradePact = 3,
+}
+impl TreatyType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "TREATY_TYPE_UNSPECIFIED",
+            Self::Alliance => "TREATY_TYPE_ALLIANCE",
+            Self::NonAggression => "TREATY_TYPE_NON_AGGRESSION",
+            Self::TradePact => "TREATY_TYPE_TRADE_PACT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TREATY_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "TREATY_TYPE_ALLIANCE" => Some(Self::Alliance),
+            "TREATY_TYPE_NON_AGGRESSION" => Some(Self::NonAggression),
+            "TREATY_TYPE_TRADE_PACT" => Some(Self::TradePact),
+            _ => None,
+        }
+    }
+}
@@ -25,6 +25,14 @@ pub struct QueryBuildingsRequest {
     /// Maximum number of results to return (optional, maximum 100).
     #[prost(message, optional, tag = "7")]
     pub limit: ::core::option::Option<i64>,
+    /// Game tick to read the state of instead of the current one (optional).
+    /// Only ticks kept by the server's retention policy can be queried.
+    #[prost(message, optional, tag = "8")]
+    pub at_game_tick: ::core::option::Option<i64>,
+    /// Filter by ownership (optional, defaults to owned buildings only).
+    /// Set to false to find buildings that can be acquired.
+    #[prost(message, optional, tag = "9")]
+    pub is_owned: ::core::option::Option<bool>,
 }
 /// Represents detailed information about a building.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -35,6 +43,15 @@ pub struct BuildingDetails {
     pub longitude: f64,
     #[prost(double, tag = "3")]
     pub latitude: f64,
+    /// Volume in cubic meters. Prices and upkeep of buildings grow with it.
+    #[prost(double, tag = "4")]
+    pub volume: f64,
+    /// PLATEAU usage code of the building (e.g. 401 for offices, 402 for commercial facilities).
+    #[prost(message, optional, tag = "5")]
+    pub usage_code: ::core::option::Option<::prost::alloc::string::String>,
+    /// UUID of the business owning the building, if it is owned.
+    #[prost(message, optional, tag = "6")]
+    pub owning_business_uuid: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Response containing the list of matching building details
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -48,6 +65,64 @@ pub struct QueryBuildingsResponse {
     #[prost(int64, tag = "3")]
     pub total_count: i64,
 }
+/// Request to query the control of the wards.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QueryTerritoriesRequest {
+    /// Query the territories at a specific game tick. Defaults to the current tick.
+    #[prost(message, optional, tag = "1")]
+    pub at_game_tick: ::core::option::Option<i64>,
+}
+/// Response containing the control of every ward.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryTerritoriesResponse {
+    /// The tick for which the update is relevant.
+    #[prost(int64, tag = "1")]
+    pub game_tick: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub territories: ::prost::alloc::vec::Vec<Territory>,
+}
+/// A ward and the corporations competing for its control.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Territory {
+    /// Code of the ward (e.g. 13101 for Chiyoda).
+    #[prost(string, tag = "1")]
+    pub city_code: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Exterior ring of the hull around the buildings of the ward.
+    #[prost(message, repeated, tag = "3")]
+    pub boundary: ::prost::alloc::vec::Vec<GeoPoint>,
+    /// Building volume owned by all corporations together.
+    #[prost(int64, tag = "4")]
+    pub owned_volume: i64,
+    /// UUID of the corporation controlling the ward, if any.
+    #[prost(message, optional, tag = "5")]
+    pub controlling_corporation_uuid: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Shares of the corporations, the largest first. Only the controlling corporation sees all of
+    /// them, everybody else only sees the controlling corporation and their own share.
+    #[prost(message, repeated, tag = "6")]
+    pub shares: ::prost::alloc::vec::Vec<TerritoryControlShare>,
+}
+/// Building volume a corporation owns within a ward.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TerritoryControlShare {
+    #[prost(string, tag = "1")]
+    pub corporation_uuid: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub volume: i64,
+    /// Part of the owned building volume of the ward, in basis points.
+    #[prost(int64, tag = "3")]
+    pub share_bps: i64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GeoPoint {
+    #[prost(double, tag = "1")]
+    pub longitude: f64,
+    #[prost(double, tag = "2")]
+    pub latitude: f64,
+}
 /// Request to query business listings based on criteria
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBusinessListingsRequest {
@@ -69,6 +144,9 @@ pub struct QueryBusinessListingsRequest {
     /// Filter by maximum operational expenses (inclusive)
     #[prost(message, optional, tag = "7")]
     pub max_operational_expenses: ::core::option::Option<i64>,
+    /// Filter by listing type: only auctions (true) or only fixed-price listings (false)
+    #[prost(message, optional, tag = "8")]
+    pub is_auction: ::core::option::Option<bool>,
     /// Field to sort by.
     #[prost(enumeration = "BusinessListingSortBy", tag = "20")]
     pub sort_by: i32,
@@ -82,6 +160,11 @@ pub struct QueryBusinessListingsRequest {
     /// Number of results to skip (for pagination)
     #[prost(message, optional, tag = "31")]
     pub offset: ::core::option::Option<i64>,
+    /// --- History ---
+    /// Game tick to read the state of instead of the current one.
+    /// Only ticks kept by the server's retention policy can be queried.
+    #[prost(message, optional, tag = "40")]
+    pub at_game_tick: ::core::option::Option<i64>,
 }
 /// Request to query businesses based on criteria
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -93,9 +176,11 @@ pub struct QueryBusinessesRequest {
     #[prost(message, optional, tag = "2")]
     pub market_uuid: ::core::option::Option<::prost::alloc::string::String>,
     /// Filter by minimum operational expenses (inclusive)
+    /// Like sorting, only compares the operational expenses rounded down to the fog of war precision.
     #[prost(message, optional, tag = "3")]
     pub min_operational_expenses: ::core::option::Option<i64>,
     /// Filter by maximum operational expenses (inclusive)
+    /// Like sorting, only compares the operational expenses rounded down to the fog of war precision.
     #[prost(message, optional, tag = "4")]
     pub max_operational_expenses: ::core::option::Option<i64>,
     /// Field to sort by.
@@ -111,6 +196,11 @@ pub struct QueryBusinessesRequest {
     /// Number of results to skip (for pagination)
     #[prost(message, optional, tag = "31")]
     pub offset: ::core::option::Option<i64>,
+    /// --- History ---
+    /// Game tick to read the state of instead of the current one.
+    /// Only ticks kept by the server's retention policy can be queried.
+    #[prost(message, optional, tag = "40")]
+    pub at_game_tick: ::core::option::Option<i64>,
 }
 /// Represents detailed information about a business, including related market data.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -139,6 +229,10 @@ pub struct BusinessDetails {
     pub image_number: i32,
     #[prost(int32, tag = "12")]
     pub market_number: i32,
+    /// Whether the operational expenses are rounded down, because the business belongs to a rival
+    /// without a business in the same market, buildings in the same ward or a mole of the player.
+    #[prost(bool, tag = "13")]
+    pub operational_expenses_estimated: bool,
 }
 /// Response containing the list of matching business details.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -182,6 +276,9 @@ pub struct BusinessListingDetails {
     pub image_number: i32,
     #[prost(int32, tag = "13")]
     pub market_number: i32,
+    /// Tick at which the auction closes. Unset for fixed-price listings.
+    #[prost(message, optional, tag = "14")]
+    pub auction_closes_at_tick: ::core::option::Option<i64>,
 }
 /// Response containing the list of matching business listing details.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -197,7 +294,12 @@ pub struct QueryBusinessListingsResponse {
 }
 /// Request to get the player's corporation details.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct GetCorporationRequest {}
+pub struct GetCorporationRequest {
+    /// Game tick to read the state of instead of the current one (optional).
+    /// Only ticks kept by the server's retention policy can be queried.
+    #[prost(message, optional, tag = "1")]
+    pub at_game_tick: ::core::option::Option<i64>,
+}
 /// Response containing corporation details.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCorporationResponse {
@@ -208,6 +310,61 @@ pub struct GetCorporationResponse {
     #[prost(message, optional, tag = "2")]
     pub corporation: ::core::option::Option<Corporation>,
 }
+/// Request to fetch the ledger of the player's corporation, most recent entries first.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetCorporationLedgerRequest {
+    /// First game tick to include (inclusive, optional).
+    #[prost(message, optional, tag = "1")]
+    pub from_game_tick: ::core::option::Option<i64>,
+    /// Last game tick to include (inclusive, optional).
+    #[prost(message, optional, tag = "2")]
+    pub to_game_tick: ::core::option::Option<i64>,
+    /// --- Pagination ---
+    /// Maximum number of results to return (maximum 100)
+    #[prost(message, optional, tag = "30")]
+    pub limit: ::core::option::Option<i64>,
+    /// Number of results to skip (for pagination)
+    #[prost(message, optional, tag = "31")]
+    pub offset: ::core::option::Option<i64>,
+}
+/// Response containing the matching ledger entries.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCorporationLedgerResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<LedgerEntry>,
+    /// Total number of entries matching the criteria
+    #[prost(int64, tag = "2")]
+    pub total_count: i64,
+}
+/// Request to query the history of the markets, most recent ticks first.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryMarketsRequest {
+    /// Filter by market uuid (optional).
+    #[prost(message, optional, tag = "1")]
+    pub market_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// First game tick to include (inclusive, optional).
+    #[prost(message, optional, tag = "2")]
+    pub from_game_tick: ::core::option::Option<i64>,
+    /// Last game tick to include (inclusive, optional).
+    #[prost(message, optional, tag = "3")]
+    pub to_game_tick: ::core::option::Option<i64>,
+    /// --- Pagination ---
+    /// Maximum number of results to return (maximum 100)
+    #[prost(message, optional, tag = "30")]
+    pub limit: ::core::option::Option<i64>,
+    /// Number of results to skip (for pagination)
+    #[prost(message, optional, tag = "31")]
+    pub offset: ::core::option::Option<i64>,
+}
+/// Response containing the matching market snapshots.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryMarketsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub snapshots: ::prost::alloc::vec::Vec<MarketSnapshot>,
+    /// Total number of snapshots matching the criteria
+    #[prost(int64, tag = "2")]
+    pub total_count: i64,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateCorporationResponse {
     /// UUID generated on the client to match the response with the initial request.
@@ -244,6 +401,493 @@ pub struct AcquireListedBusinessResponse {
     #[prost(message, optional, tag = "2")]
     pub business: ::core::option::Option<Business>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBusinessForSaleRequest {
+    /// UUID of the business to put up for sale.
+    #[prost(string, tag = "1")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// Price the seller is asking for.
+    #[prost(int64, tag = "2")]
+    pub asking_price: i64,
+    /// Number of ticks sealed bids are collected for. If set, the listing is an auction
+    /// and the asking price is the reserve price, otherwise the business is sold at the asking price.
+    #[prost(message, optional, tag = "3")]
+    pub auction_duration_ticks: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBusinessForSaleResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// The newly created listing.
+    #[prost(message, optional, tag = "2")]
+    pub listing: ::core::option::Option<BusinessListing>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelBusinessListingRequest {
+    /// UUID of the business listing.
+    #[prost(string, tag = "1")]
+    pub business_listing_uuid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelBusinessListingResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the cancelled business listing.
+    #[prost(string, tag = "2")]
+    pub business_listing_uuid: ::prost::alloc::string::String,
+    /// UUID of the business that is no longer for sale.
+    #[prost(string, tag = "3")]
+    pub business_uuid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MakeBusinessOfferRequest {
+    /// UUID of the business the offer is made for.
+    #[prost(string, tag = "1")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// Price offered to the owning corporation. The amount is held in escrow until the offer is resolved.
+    #[prost(int64, tag = "2")]
+    pub offer_price: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MakeBusinessOfferResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// The newly created offer.
+    #[prost(message, optional, tag = "2")]
+    pub offer: ::core::option::Option<BusinessOffer>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcceptBusinessOfferRequest {
+    /// UUID of the business offer.
+    #[prost(string, tag = "1")]
+    pub business_offer_uuid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcceptBusinessOfferResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the accepted business offer.
+    #[prost(string, tag = "2")]
+    pub business_offer_uuid: ::prost::alloc::string::String,
+    /// The sold business including its new owner.
+    #[prost(message, optional, tag = "3")]
+    pub business: ::core::option::Option<Business>,
+    /// Amount credited to the accepting corporation.
+    #[prost(int64, tag = "4")]
+    pub offer_price: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectBusinessOfferRequest {
+    /// UUID of the business offer.
+    #[prost(string, tag = "1")]
+    pub business_offer_uuid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectBusinessOfferResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the rejected business offer.
+    #[prost(string, tag = "2")]
+    pub business_offer_uuid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawBusinessOfferRequest {
+    /// UUID of the business offer.
+    #[prost(string, tag = "1")]
+    pub business_offer_uuid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawBusinessOfferResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the withdrawn business offer.
+    #[prost(string, tag = "2")]
+    pub business_offer_uuid: ::prost::alloc::string::String,
+    /// Escrowed amount returned to the offering corporation.
+    #[prost(int64, tag = "3")]
+    pub refunded_amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetOperationalExpensesRequest {
+    /// UUID of the business.
+    #[prost(string, tag = "1")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// New operational expenses of the business per game tick.
+    #[prost(int64, tag = "2")]
+    pub operational_expenses: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetOperationalExpensesResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the business.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// Operational expenses of the business from now on.
+    #[prost(int64, tag = "3")]
+    pub operational_expenses: i64,
+    /// Operational expenses of the business before the change.
+    #[prost(int64, tag = "4")]
+    pub previous_operational_expenses: i64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TakeLoanRequest {
+    /// Amount to borrow from the bank.
+    #[prost(int64, tag = "1")]
+    pub amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TakeLoanResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub loan: ::core::option::Option<Loan>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RepayLoanRequest {
+    /// UUID of the loan.
+    #[prost(string, tag = "1")]
+    pub loan_uuid: ::prost::alloc::string::String,
+    /// Amount to pay back. Amounts above the outstanding balance only settle the loan.
+    #[prost(int64, tag = "2")]
+    pub amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RepayLoanResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the loan.
+    #[prost(string, tag = "2")]
+    pub loan_uuid: ::prost::alloc::string::String,
+    /// Amount that has been paid back.
+    #[prost(int64, tag = "3")]
+    pub amount: i64,
+    /// Amount still owed. The loan is settled if it is zero.
+    #[prost(int64, tag = "4")]
+    pub outstanding_balance: i64,
+}
+/// Debt of a corporation towards the bank.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Loan {
+    /// UUID of the loan.
+    #[prost(string, tag = "1")]
+    pub uuid: ::prost::alloc::string::String,
+    /// Amount paid out to the corporation.
+    #[prost(int64, tag = "2")]
+    pub principal: i64,
+    /// Interest added to the outstanding balance every tick, in basis points.
+    #[prost(int64, tag = "3")]
+    pub interest_rate_bps: i64,
+    /// Tick at which the bank collects the outstanding balance.
+    #[prost(int64, tag = "4")]
+    pub due_at_tick: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferFundsRequest {
+    /// UUID of the corporation to send the cash to.
+    #[prost(string, tag = "1")]
+    pub recipient_corporation_uuid: ::prost::alloc::string::String,
+    /// Amount to send.
+    #[prost(int64, tag = "2")]
+    pub amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferFundsResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation the cash has been sent to.
+    #[prost(string, tag = "2")]
+    pub recipient_corporation_uuid: ::prost::alloc::string::String,
+    /// Amount that has been sent.
+    #[prost(int64, tag = "3")]
+    pub amount: i64,
+}
+/// Pushed to the corporation when another corporation has sent it cash.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundsReceivedNotification {
+    /// UUID of the corporation that has sent the cash.
+    #[prost(string, tag = "1")]
+    pub sender_corporation_uuid: ::prost::alloc::string::String,
+    /// Amount that has been received.
+    #[prost(int64, tag = "2")]
+    pub amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateContractRequest {
+    /// UUID of the corporation to pay.
+    #[prost(string, tag = "1")]
+    pub payee_corporation_uuid: ::prost::alloc::string::String,
+    /// Amount to put into escrow and pay once the contract is due.
+    #[prost(int64, tag = "2")]
+    pub amount: i64,
+    /// Tick at which the contract is settled.
+    #[prost(int64, tag = "3")]
+    pub due_at_tick: i64,
+    /// What has to hold for the payee to be paid.
+    #[prost(enumeration = "ContractConditionType", tag = "4")]
+    pub condition_type: i32,
+    /// UUID of the business the condition refers to, if any.
+    #[prost(message, optional, tag = "5")]
+    pub business_uuid: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateContractResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub contract: ::core::option::Option<Contract>,
+}
+/// Cash held in escrow to pay another corporation at a later tick.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Contract {
+    /// UUID of the contract.
+    #[prost(string, tag = "1")]
+    pub uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation that pays.
+    #[prost(string, tag = "2")]
+    pub payer_corporation_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation that is paid.
+    #[prost(string, tag = "3")]
+    pub payee_corporation_uuid: ::prost::alloc::string::String,
+    /// Amount held in escrow.
+    #[prost(int64, tag = "4")]
+    pub amount: i64,
+    /// What has to hold for the payee to be paid.
+    #[prost(enumeration = "ContractConditionType", tag = "5")]
+    pub condition_type: i32,
+    /// UUID of the business the condition refers to, if any.
+    #[prost(message, optional, tag = "6")]
+    pub business_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// Tick at which the contract is settled.
+    #[prost(int64, tag = "7")]
+    pub due_at_tick: i64,
+}
+/// Pushed to the payee when another corporation has signed a contract in its favour.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractReceivedNotification {
+    #[prost(message, optional, tag = "1")]
+    pub contract: ::core::option::Option<Contract>,
+}
+/// Pushed to both parties once a contract is due.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractSettledNotification {
+    /// UUID of the settled contract.
+    #[prost(string, tag = "1")]
+    pub contract_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation that has put the amount into escrow.
+    #[prost(string, tag = "2")]
+    pub payer_corporation_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation the contract was in favour of.
+    #[prost(string, tag = "3")]
+    pub payee_corporation_uuid: ::prost::alloc::string::String,
+    /// Escrowed amount.
+    #[prost(int64, tag = "4")]
+    pub amount: i64,
+    /// Whether the payee has been paid. Otherwise the amount has been returned to the payer.
+    #[prost(bool, tag = "5")]
+    pub paid: bool,
+}
+/// Pushed to the corporation when a due loan could not be paid back in full.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoanDefaultedNotification {
+    /// UUID of the loan.
+    #[prost(string, tag = "1")]
+    pub loan_uuid: ::prost::alloc::string::String,
+    /// Amount the bank collected from the cash balance.
+    #[prost(int64, tag = "2")]
+    pub collected_amount: i64,
+    /// Amount the bank wrote off.
+    #[prost(int64, tag = "3")]
+    pub written_off_amount: i64,
+    /// UUIDs of the businesses the bank seized and put up for sale.
+    #[prost(string, repeated, tag = "4")]
+    pub liquidated_business_uuids: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+}
+/// Pushed to the owning corporation when it receives an offer for one of its businesses.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BusinessOfferReceivedNotification {
+    #[prost(message, optional, tag = "1")]
+    pub offer: ::core::option::Option<BusinessOffer>,
+}
+/// Pushed to the offering corporation when one of its offers has been rejected or has expired.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BusinessOfferClosedNotification {
+    /// UUID of the closed business offer.
+    #[prost(string, tag = "1")]
+    pub business_offer_uuid: ::prost::alloc::string::String,
+    /// UUID of the business the offer was made for.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// Escrowed amount returned to the offering corporation.
+    #[prost(int64, tag = "3")]
+    pub refunded_amount: i64,
+    /// Why the offer has been closed.
+    #[prost(enumeration = "BusinessOfferCloseReason", tag = "4")]
+    pub reason: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlaceAuctionBidRequest {
+    /// UUID of the auction listing.
+    #[prost(string, tag = "1")]
+    pub business_listing_uuid: ::prost::alloc::string::String,
+    /// Amount bid for the business. It is held in escrow until the auction closes.
+    #[prost(int64, tag = "2")]
+    pub amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlaceAuctionBidResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the placed bid.
+    #[prost(string, tag = "2")]
+    pub auction_bid_uuid: ::prost::alloc::string::String,
+    /// UUID of the auction listing.
+    #[prost(string, tag = "3")]
+    pub business_listing_uuid: ::prost::alloc::string::String,
+    /// UUID of the auctioned business.
+    #[prost(string, tag = "4")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// Amount moved into escrow.
+    #[prost(int64, tag = "5")]
+    pub amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcquireBuildingRequest {
+    /// UUID of the business the building is acquired for.
+    #[prost(string, tag = "1")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// GML ID of the building. It must not be owned yet and lie near the headquarter of the business.
+    #[prost(string, tag = "2")]
+    pub building_gml_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcquireBuildingResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the business now owning the building.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// GML ID of the acquired building.
+    #[prost(string, tag = "3")]
+    pub building_gml_id: ::prost::alloc::string::String,
+    /// Price paid for the building.
+    #[prost(int64, tag = "4")]
+    pub price: i64,
+    /// Upkeep the building costs per tick.
+    #[prost(int64, tag = "5")]
+    pub upkeep: i64,
+}
+/// Pushed to the seller and all bidders when an auction has closed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuctionClosedNotification {
+    /// UUID of the auction listing.
+    #[prost(string, tag = "1")]
+    pub business_listing_uuid: ::prost::alloc::string::String,
+    /// UUID of the auctioned business.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation that won the business, if it has been sold.
+    #[prost(message, optional, tag = "3")]
+    pub winning_corporation_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// Price the business has been sold for, if it has been sold.
+    #[prost(message, optional, tag = "4")]
+    pub winning_bid: ::core::option::Option<i64>,
+    /// Escrowed amount returned to the receiving corporation.
+    #[prost(int64, tag = "5")]
+    pub refunded_amount: i64,
+    /// Why the auction has been closed.
+    #[prost(enumeration = "AuctionCloseReason", tag = "6")]
+    pub reason: i32,
+}
+/// Pushed to the corporation after every tick in which its cash has moved.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CorporationLedgerSummaryNotification {
+    /// UUID of the corporation.
+    #[prost(string, tag = "1")]
+    pub corporation_uuid: ::prost::alloc::string::String,
+    /// Market share income of all businesses.
+    #[prost(int64, tag = "2")]
+    pub business_income: i64,
+    /// Operational expenses of all businesses (negative).
+    #[prost(int64, tag = "3")]
+    pub operational_expenses: i64,
+    /// Prices paid for acquired businesses (negative).
+    #[prost(int64, tag = "4")]
+    pub acquisitions: i64,
+    /// Prices received for sold businesses.
+    #[prost(int64, tag = "5")]
+    pub sales: i64,
+    /// Costs of spawned units (negative).
+    #[prost(int64, tag = "6")]
+    pub unit_spawns: i64,
+    /// Cash moved into escrow for offers and bids minus the refunds of closed ones.
+    #[prost(int64, tag = "7")]
+    pub escrow: i64,
+    /// Change of the balance within the tick, the sum of all cash movements.
+    #[prost(int64, tag = "8")]
+    pub net_change: i64,
+    /// Balance at the end of the tick.
+    #[prost(int64, tag = "9")]
+    pub balance: i64,
+    /// Cash paid out for loans minus the repayments.
+    #[prost(int64, tag = "10")]
+    pub loans: i64,
+    /// Prices and upkeep paid for buildings (negative).
+    #[prost(int64, tag = "11")]
+    pub buildings: i64,
+    /// Territory tax and listing fees collected from rivals minus the ones paid to them.
+    #[prost(int64, tag = "12")]
+    pub territory_tax: i64,
+    /// Costs of espionage operations (negative).
+    #[prost(int64, tag = "13")]
+    pub espionage: i64,
+    /// Cash received from other corporations minus the cash sent to them.
+    #[prost(int64, tag = "14")]
+    pub transfers: i64,
+}
+/// Pushed to the corporation when its cash balance has dropped to or below zero.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CorporationInsolventNotification {
+    /// UUID of the corporation.
+    #[prost(string, tag = "1")]
+    pub corporation_uuid: ::prost::alloc::string::String,
+    /// Balance at the end of the tick.
+    #[prost(int64, tag = "2")]
+    pub balance: i64,
+    /// Tick at which the corporation goes bankrupt unless its balance is positive again.
+    #[prost(int64, tag = "3")]
+    pub bankruptcy_at_tick: i64,
+}
+/// Pushed to the former owner after their corporation went bankrupt and has been dissolved.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CorporationBankruptNotification {
+    /// UUID of the dissolved corporation.
+    #[prost(string, tag = "1")]
+    pub corporation_uuid: ::prost::alloc::string::String,
+    /// UUIDs of the businesses that have been put up for sale without proceeds for the corporation.
+    #[prost(string, repeated, tag = "2")]
+    pub liquidated_business_uuids: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+    /// UUIDs of the units that have been disbanded.
+    #[prost(string, repeated, tag = "3")]
+    pub disbanded_unit_uuids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 /// Represents a player's corporation in the game.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Corporation {
@@ -281,6 +925,89 @@ pub struct Business {
     #[prost(int32, tag = "6")]
     pub image_number: i32,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BusinessListing {
+    /// UUID of the business listing.
+    #[prost(string, tag = "1")]
+    pub uuid: ::prost::alloc::string::String,
+    /// UUID of the listed business.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation selling the business.
+    #[prost(message, optional, tag = "3")]
+    pub seller_corporation_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// Price the seller is asking for. The reserve price if the listing is an auction.
+    #[prost(int64, tag = "4")]
+    pub asking_price: i64,
+    /// Tick at which the auction closes. Unset for fixed-price listings.
+    #[prost(message, optional, tag = "5")]
+    pub auction_closes_at_tick: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BusinessOffer {
+    /// UUID of the business offer.
+    #[prost(string, tag = "1")]
+    pub uuid: ::prost::alloc::string::String,
+    /// UUID of the business the offer is made for.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation making the offer.
+    #[prost(string, tag = "3")]
+    pub offering_corporation_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation owning the business at the time of the offer.
+    #[prost(message, optional, tag = "4")]
+    pub target_corporation_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// Price offered for the business.
+    #[prost(int64, tag = "5")]
+    pub offer_price: i64,
+    /// Last game tick in which the offer can be accepted.
+    #[prost(int64, tag = "6")]
+    pub expires_at_tick: i64,
+}
+/// A market as it was at the end of a game tick.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketSnapshot {
+    /// Game tick the snapshot was taken in.
+    #[prost(int64, tag = "1")]
+    pub game_tick: i64,
+    /// UUID of the market.
+    #[prost(string, tag = "2")]
+    pub market_uuid: ::prost::alloc::string::String,
+    /// Name of the market.
+    #[prost(string, tag = "3")]
+    pub market_name: ::prost::alloc::string::String,
+    /// Number of the market.
+    #[prost(int32, tag = "4")]
+    pub market_number: i32,
+    /// Volume shared by the businesses of the market as income, by their operational expenses.
+    #[prost(int64, tag = "5")]
+    pub volume: i64,
+    /// Operational expenses of all businesses in the market that are owned by a corporation.
+    #[prost(int64, tag = "6")]
+    pub invested_operational_expenses: i64,
+    /// Number of businesses in the market that are owned by a corporation.
+    #[prost(int64, tag = "7")]
+    pub active_businesses: i64,
+}
+/// A single movement of a corporation's cash.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LedgerEntry {
+    /// Game tick the cash moved in.
+    #[prost(int64, tag = "1")]
+    pub game_tick: i64,
+    /// Position of the entry within its game tick.
+    #[prost(int32, tag = "2")]
+    pub sequence: i32,
+    /// What moved the cash.
+    #[prost(enumeration = "LedgerEntryKind", tag = "3")]
+    pub kind: i32,
+    /// Change of the balance, negative for expenses.
+    #[prost(int64, tag = "4")]
+    pub amount: i64,
+    /// UUID of the business the movement relates to.
+    #[prost(message, optional, tag = "5")]
+    pub business_uuid: ::core::option::Option<::prost::alloc::string::String>,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum BusinessListingSortBy {
@@ -348,3 +1075,187 @@ impl BusinessSortBy {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ContractConditionType {
+    Unspecified = 0,
+    /// The payee is paid in any case.
+    Unconditional = 1,
+    /// The payee is paid if the payer owns the business by then.
+    PayerOwnsBusiness = 2,
+    /// The payee is paid if an alliance or non-aggression pact between the parties is in effect.
+    PeaceHolds = 3,
+}
+impl ContractConditionType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CONTRACT_CONDITION_TYPE_UNSPECIFIED",
+            Self::Unconditional => "CONTRACT_CONDITION_TYPE_UNCONDITIONAL",
+            Self::PayerOwnsBusiness => "CONTRACT_CONDITION_TYPE_PAYER_OWNS_BUSINESS",
+            Self::PeaceHolds => "CONTRACT_CONDITION_TYPE_PEACE_HOLDS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CONTRACT_CONDITION_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CONTRACT_CONDITION_TYPE_UNCONDITIONAL" => Some(Self::Unconditional),
+            "CONTRACT_CONDITION_TYPE_PAYER_OWNS_BUSINESS" => {
+                Some(Self::PayerOwnsBusiness)
+            }
+            "CONTRACT_CONDITION_TYPE_PEACE_HOLDS" => Some(Self::PeaceHolds),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BusinessOfferCloseReason {
+    Unspecified = 0,
+    Rejected = 1,
+    Expired = 2,
+}
+impl BusinessOfferCloseReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "BUSINESS_OFFER_CLOSE_REASON_UNSPECIFIED",
+            Self::Rejected => "BUSINESS_OFFER_CLOSE_REASON_REJECTED",
+            Self::Expired => "BUSINESS_OFFER_CLOSE_REASON_EXPIRED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BUSINESS_OFFER_CLOSE_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "BUSINESS_OFFER_CLOSE_REASON_REJECTED" => Some(Self::Rejected),
+            "BUSINESS_OFFER_CLOSE_REASON_EXPIRED" => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AuctionCloseReason {
+    Unspecified = 0,
+    /// The business went to the highest bid.
+    Sold = 1,
+    /// No bid has been placed until the auction closed.
+    Unsold = 2,
+    /// The listing has been removed before the auction closed.
+    Cancelled = 3,
+}
+impl AuctionCloseReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "AUCTION_CLOSE_REASON_UNSPECIFIED",
+            Self::Sold => "AUCTION_CLOSE_REASON_SOLD",
+            Self::Unsold => "AUCTION_CLOSE_REASON_UNSOLD",
+            Self::Cancelled => "AUCTION_CLOSE_REASON_CANCELLED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AUCTION_CLOSE_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "AUCTION_CLOSE_REASON_SOLD" => Some(Self::Sold),
+            "AUCTION_CLOSE_REASON_UNSOLD" => Some(Self::Unsold),
+            "AUCTION_CLOSE_REASON_CANCELLED" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LedgerEntryKind {
+    Unspecified = 0,
+    BusinessIncome = 1,
+    OperationalExpenses = 2,
+    BusinessAcquisition = 3,
+    BusinessSale = 4,
+    UnitSpawn = 5,
+    OfferEscrow = 6,
+    OfferRefund = 7,
+    LoanDisbursement = 8,
+    LoanRepayment = 9,
+    AuctionEscrow = 10,
+    AuctionRefund = 11,
+    BuildingAcquisition = 12,
+    BuildingUpkeep = 13,
+    TerritoryTax = 14,
+    Espionage = 15,
+    Transfer = 16,
+    ContractEscrow = 17,
+    ContractRefund = 18,
+    ContractPayment = 19,
+    ListingFee = 20,
+}
+impl LedgerEntryKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "LEDGER_ENTRY_KIND_UNSPECIFIED",
+            Self::BusinessIncome => "LEDGER_ENTRY_KIND_BUSINESS_INCOME",
+            Self::OperationalExpenses => "LEDGER_ENTRY_KIND_OPERATIONAL_EXPENSES",
+            Self::BusinessAcquisition => "LEDGER_ENTRY_KIND_BUSINESS_ACQUISITION",
+            Self::BusinessSale => "LEDGER_ENTRY_KIND_BUSINESS_SALE",
+            Self::UnitSpawn => "LEDGER_ENTRY_KIND_UNIT_SPAWN",
+            Self::OfferEscrow => "LEDGER_ENTRY_KIND_OFFER_ESCROW",
+            Self::OfferRefund => "LEDGER_ENTRY_KIND_OFFER_REFUND",
+            Self::LoanDisbursement => "LEDGER_ENTRY_KIND_LOAN_DISBURSEMENT",
+            Self::LoanRepayment => "LEDGER_ENTRY_KIND_LOAN_REPAYMENT",
+            Self::AuctionEscrow => "LEDGER_ENTRY_KIND_AUCTION_ESCROW",
+            Self::AuctionRefund => "LEDGER_ENTRY_KIND_AUCTION_REFUND",
+            Self::BuildingAcquisition => "LEDGER_ENTRY_KIND_BUILDING_ACQUISITION",
+            Self::BuildingUpkeep => "LEDGER_ENTRY_KIND_BUILDING_UPKEEP",
+            Self::TerritoryTax => "LEDGER_ENTRY_KIND_TERRITORY_TAX",
+            Self::Espionage => "LEDGER_ENTRY_KIND_ESPIONAGE",
+            Self::Transfer => "LEDGER_ENTRY_KIND_TRANSFER",
+            Self::ContractEscrow => "LEDGER_ENTRY_KIND_CONTRACT_ESCROW",
+            Self::ContractRefund => "LEDGER_ENTRY_KIND_CONTRACT_REFUND",
+            Self::ContractPayment => "LEDGER_ENTRY_KIND_CONTRACT_PAYMENT",
+            Self::ListingFee => "LEDGER_ENTRY_KIND_LISTING_FEE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LEDGER_ENTRY_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "LEDGER_ENTRY_KIND_BUSINESS_INCOME" => Some(Self::BusinessIncome),
+            "LEDGER_ENTRY_KIND_OPERATIONAL_EXPENSES" => Some(Self::OperationalExpenses),
+            "LEDGER_ENTRY_KIND_BUSINESS_ACQUISITION" => Some(Self::BusinessAcquisition),
+            "LEDGER_ENTRY_KIND_BUSINESS_SALE" => Some(Self::BusinessSale),
+            "LEDGER_ENTRY_KIND_UNIT_SPAWN" => Some(Self::UnitSpawn),
+            "LEDGER_ENTRY_KIND_OFFER_ESCROW" => Some(Self::OfferEscrow),
+            "LEDGER_ENTRY_KIND_OFFER_REFUND" => Some(Self::OfferRefund),
+            "LEDGER_ENTRY_KIND_LOAN_DISBURSEMENT" => Some(Self::LoanDisbursement),
+            "LEDGER_ENTRY_KIND_LOAN_REPAYMENT" => Some(Self::LoanRepayment),
+            "LEDGER_ENTRY_KIND_AUCTION_ESCROW" => Some(Self::AuctionEscrow),
+            "LEDGER_ENTRY_KIND_AUCTION_REFUND" => Some(Self::AuctionRefund),
+            "LEDGER_ENTRY_KIND_BUILDING_ACQUISITION" => Some(Self::BuildingAcquisition),
+            "LEDGER_ENTRY_KIND_BUILDING_UPKEEP" => Some(Self::BuildingUpkeep),
+            "LEDGER_ENTRY_KIND_TERRITORY_TAX" => Some(Self::TerritoryTax),
+            "LEDGER_ENTRY_KIND_ESPIONAGE" => Some(Self::Espionage),
+            "LEDGER_ENTRY_KIND_TRANSFER" => Some(Self::Transfer),
+            "LEDGER_ENTRY_KIND_CONTRACT_ESCROW" => Some(Self::ContractEscrow),
+            "LEDGER_ENTRY_KIND_CONTRACT_REFUND" => Some(Self::ContractRefund),
+            "LEDGER_ENTRY_KIND_CONTRACT_PAYMENT" => Some(Self::ContractPayment),
+            "LEDGER_ENTRY_KIND_LISTING_FEE" => Some(Self::ListingFee),
+            _ => None,
+        }
+    }
+}
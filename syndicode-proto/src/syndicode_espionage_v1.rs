@@ -0,0 +1,234 @@
+// This file is @generated by prost-build.
+/// Request to spy on a rival corporation.
+/// The cost is deducted whether the operation succeeds or not.
+/// A mole planted in the rival makes the operation more likely to succeed and less likely to be noticed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GatherIntelRequest {
+    /// UUID of the corporation to spy on.
+    #[prost(string, tag = "1")]
+    pub target_corporation_uuid: ::prost::alloc::string::String,
+    /// What the operation should reveal.
+    #[prost(enumeration = "IntelType", tag = "2")]
+    pub intel_type: i32,
+}
+/// Response after an intel operation has been carried out.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GatherIntelResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation that has been spied on.
+    #[prost(string, tag = "2")]
+    pub target_corporation_uuid: ::prost::alloc::string::String,
+    /// What the operation should reveal.
+    #[prost(enumeration = "IntelType", tag = "3")]
+    pub intel_type: i32,
+    /// The cash that has been deducted from the corporation for the operation.
+    #[prost(int64, tag = "4")]
+    pub cost: i64,
+    /// Whether the target has noticed the operation.
+    #[prost(bool, tag = "5")]
+    pub detected: bool,
+    /// The gathered intel, only set if the operation succeeded.
+    #[prost(message, optional, tag = "6")]
+    pub report: ::core::option::Option<IntelReport>,
+}
+/// Intel gathered about a rival corporation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IntelReport {
+    #[prost(oneof = "intel_report::Report", tags = "1, 2, 3")]
+    pub report: ::core::option::Option<intel_report::Report>,
+}
+/// Nested message and enum types in `IntelReport`.
+pub mod intel_report {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Report {
+        /// The cash balance of the rival.
+        #[prost(message, tag = "1")]
+        Balance(super::BalanceIntel),
+        /// The businesses owned by the rival.
+        #[prost(message, tag = "2")]
+        Holdings(super::HoldingsIntel),
+        /// The businesses the rival has listed for sale.
+        #[prost(message, tag = "3")]
+        Listings(super::ListingsIntel),
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BalanceIntel {
+    /// Cash balance of the rival.
+    #[prost(int64, tag = "1")]
+    pub cash_balance: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HoldingsIntel {
+    /// UUIDs of the businesses owned by the rival.
+    #[prost(string, repeated, tag = "1")]
+    pub business_uuids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListingsIntel {
+    /// The pending listings of the rival.
+    #[prost(message, repeated, tag = "1")]
+    pub listings: ::prost::alloc::vec::Vec<ListingIntel>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListingIntel {
+    /// UUID of the business listing.
+    #[prost(string, tag = "1")]
+    pub business_listing_uuid: ::prost::alloc::string::String,
+    /// UUID of the listed business.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// Asking price, or the reserve price of an auction.
+    #[prost(int64, tag = "3")]
+    pub asking_price: i64,
+    /// Tick at which the auction closes, if the listing is an auction.
+    #[prost(message, optional, tag = "4")]
+    pub auction_closes_at_tick: ::core::option::Option<i64>,
+}
+/// Request to plant a mole in a rival corporation.
+/// The cost is deducted whether the attempt succeeds or not. A detected attempt always fails.
+/// A corporation can only have one mole in each rival.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlantMoleRequest {
+    /// UUID of the corporation to plant the mole in.
+    #[prost(string, tag = "1")]
+    pub target_corporation_uuid: ::prost::alloc::string::String,
+}
+/// Response after an attempt to plant a mole.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlantMoleResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation the mole should be planted in.
+    #[prost(string, tag = "2")]
+    pub target_corporation_uuid: ::prost::alloc::string::String,
+    /// The cash that has been deducted from the corporation for the attempt.
+    #[prost(int64, tag = "3")]
+    pub cost: i64,
+    /// Whether the target has noticed the attempt.
+    #[prost(bool, tag = "4")]
+    pub detected: bool,
+    /// UUID of the planted mole, only set if the attempt succeeded.
+    #[prost(message, optional, tag = "5")]
+    pub mole_uuid: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Request to sweep the corporation for moles planted by rivals.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CounterIntelRequest {}
+/// Response after a sweep for moles.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CounterIntelResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// The cash that has been deducted from the corporation for the sweep.
+    #[prost(int64, tag = "2")]
+    pub cost: i64,
+    /// The moles that have been exposed and removed.
+    #[prost(message, repeated, tag = "3")]
+    pub exposed_moles: ::prost::alloc::vec::Vec<ExposedMole>,
+}
+/// A mole that has been exposed by counter-intelligence.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExposedMole {
+    /// UUID of the mole.
+    #[prost(string, tag = "1")]
+    pub mole_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation that planted the mole.
+    #[prost(string, tag = "2")]
+    pub spy_corporation_uuid: ::prost::alloc::string::String,
+}
+/// Notifies the corporation that a rival has been caught spying on it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EspionageDetectedNotification {
+    /// UUID of the corporation behind the operation.
+    #[prost(string, tag = "1")]
+    pub spy_corporation_uuid: ::prost::alloc::string::String,
+    /// The kind of the operation.
+    #[prost(enumeration = "EspionageOperation", tag = "2")]
+    pub operation: i32,
+    /// What the rival tried to find out. Only set for intel operations.
+    #[prost(enumeration = "IntelType", tag = "3")]
+    pub intel_type: i32,
+}
+/// Notifies the corporation that one of its moles has been exposed and is lost.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoleExposedNotification {
+    /// UUID of the mole.
+    #[prost(string, tag = "1")]
+    pub mole_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation the mole was planted in.
+    #[prost(string, tag = "2")]
+    pub target_corporation_uuid: ::prost::alloc::string::String,
+}
+/// What an intel operation reveals about its target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IntelType {
+    Unspecified = 0,
+    /// The cash balance.
+    Balance = 1,
+    /// The businesses owned.
+    Holdings = 2,
+    /// The businesses listed for sale.
+    Listings = 3,
+}
+impl IntelType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "INTEL_TYPE_UNSPECIFIED",
+            Self::Balance => "INTEL_TYPE_BALANCE",
+            Self::Holdings => "INTEL_TYPE_HOLDINGS",
+            Self::Listings => "INTEL_TYPE_LISTINGS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INTEL_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "INTEL_TYPE_BALANCE" => Some(Self::Balance),
+            "INTEL_TYPE_HOLDINGS" => Some(Self::Holdings),
+            "INTEL_TYPE_LISTINGS" => Some(Self::Listings),
+            _ => None,
+        }
+    }
+}
+/// The kinds of espionage operations a target can notice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EspionageOperation {
+    Unspecified = 0,
+    /// An attempt to gather intel.
+    GatherIntel = 1,
+    /// An attempt to plant a mole.
+    PlantMole = 2,
+}
+impl EspionageOperation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ESPIONAGE_OPERATION_UNSPECIFIED",
+            Self::GatherIntel => "ESPIONAGE_OPERATION_GATHER_INTEL",
+            Self::PlantMole => "ESPIONAGE_OPERATION_PLANT_MOLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ESPIONAGE_OPERATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "ESPIONAGE_OPERATION_GATHER_INTEL" => Some(Self::GatherIntel),
+            "ESPIONAGE_OPERATION_PLANT_MOLE" => Some(Self::PlantMole),
+            _ => None,
+        }
+    }
+}
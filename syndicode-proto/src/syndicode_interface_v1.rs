@@ -1311,7 +1311,10 @@ pub struct PlayerAction {
     /// UUID generated on the client to make the request trackable.
     #[prost(string, tag = "1")]
     pub request_uuid: ::prost::alloc::string::String,
-    #[prost(oneof = "player_action::Action", tags = "2, 3, 4, 5, 6, 7")]
+    #[prost(
+        oneof = "player_action::Action",
+        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30"
+    )]
     pub action: ::core::option::Option<player_action::Action>,
 }
 /// Nested message and enum types in `PlayerAction`.
@@ -1340,6 +1343,87 @@ pub mod player_action {
         /// Request to query businesses.
         #[prost(message, tag = "7")]
         QueryBusinesses(super::super::syndicode_economy_v1::QueryBusinessesRequest),
+        /// Put a business owned by the corporation up for sale.
+        #[prost(message, tag = "8")]
+        ListBusinessForSale(
+            super::super::syndicode_economy_v1::ListBusinessForSaleRequest,
+        ),
+        /// Withdraw a business listing of the corporation.
+        #[prost(message, tag = "9")]
+        CancelBusinessListing(
+            super::super::syndicode_economy_v1::CancelBusinessListingRequest,
+        ),
+        /// Make an offer for a business owned by another corporation.
+        #[prost(message, tag = "10")]
+        MakeBusinessOffer(super::super::syndicode_economy_v1::MakeBusinessOfferRequest),
+        /// Accept an offer made for one of the corporation's businesses.
+        #[prost(message, tag = "11")]
+        AcceptBusinessOffer(
+            super::super::syndicode_economy_v1::AcceptBusinessOfferRequest,
+        ),
+        /// Reject an offer made for one of the corporation's businesses.
+        #[prost(message, tag = "12")]
+        RejectBusinessOffer(
+            super::super::syndicode_economy_v1::RejectBusinessOfferRequest,
+        ),
+        /// Withdraw an offer the corporation has made.
+        #[prost(message, tag = "13")]
+        WithdrawBusinessOffer(
+            super::super::syndicode_economy_v1::WithdrawBusinessOfferRequest,
+        ),
+        /// Request to move a unit to another building.
+        #[prost(message, tag = "14")]
+        MoveUnit(super::super::syndicode_warfare_v1::MoveUnitRequest),
+        /// Request to commit units to an attack on a rival's building.
+        #[prost(message, tag = "15")]
+        AttackBuilding(super::super::syndicode_warfare_v1::AttackBuildingRequest),
+        /// Change the operational expenses of one of the corporation's businesses.
+        #[prost(message, tag = "16")]
+        SetOperationalExpenses(
+            super::super::syndicode_economy_v1::SetOperationalExpensesRequest,
+        ),
+        /// Borrow cash from the bank.
+        #[prost(message, tag = "17")]
+        TakeLoan(super::super::syndicode_economy_v1::TakeLoanRequest),
+        /// Pay back a loan.
+        #[prost(message, tag = "18")]
+        RepayLoan(super::super::syndicode_economy_v1::RepayLoanRequest),
+        /// Place a sealed bid on an auction listing.
+        #[prost(message, tag = "19")]
+        PlaceAuctionBid(super::super::syndicode_economy_v1::PlaceAuctionBidRequest),
+        /// Buy a building near the headquarter of a business.
+        #[prost(message, tag = "20")]
+        AcquireBuilding(super::super::syndicode_economy_v1::AcquireBuildingRequest),
+        /// Spy on a rival corporation.
+        #[prost(message, tag = "21")]
+        GatherIntel(super::super::syndicode_espionage_v1::GatherIntelRequest),
+        /// Plant a mole in a rival corporation.
+        #[prost(message, tag = "22")]
+        PlantMole(super::super::syndicode_espionage_v1::PlantMoleRequest),
+        /// Sweep the corporation for moles planted by rivals.
+        #[prost(message, tag = "23")]
+        CounterIntel(super::super::syndicode_espionage_v1::CounterIntelRequest),
+        /// Propose a treaty to another corporation.
+        #[prost(message, tag = "24")]
+        ProposeTreaty(super::super::syndicode_diplomacy_v1::ProposeTreatyRequest),
+        /// Accept a treaty proposed to the corporation.
+        #[prost(message, tag = "25")]
+        AcceptTreaty(super::super::syndicode_diplomacy_v1::AcceptTreatyRequest),
+        /// Break a treaty or decline a proposal.
+        #[prost(message, tag = "26")]
+        BreakTreaty(super::super::syndicode_diplomacy_v1::BreakTreatyRequest),
+        /// Send cash to another corporation.
+        #[prost(message, tag = "27")]
+        TransferFunds(super::super::syndicode_economy_v1::TransferFundsRequest),
+        /// Put cash into escrow to pay another corporation at a later tick.
+        #[prost(message, tag = "28")]
+        CreateContract(super::super::syndicode_economy_v1::CreateContractRequest),
+        /// Receive the world events of a topic on this stream.
+        #[prost(message, tag = "29")]
+        Subscribe(super::SubscribeRequest),
+        /// Stop receiving the world events of a subscription.
+        #[prost(message, tag = "30")]
+        Unsubscribe(super::UnsubscribeRequest),
     }
 }
 /// Represents an update sent to the client in response to a player action.
@@ -1350,7 +1434,7 @@ pub struct GameUpdate {
     pub game_tick: i64,
     #[prost(
         oneof = "game_update::Update",
-        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 13, 11, 12"
+        tags = "2, 3, 4, 5, 6, 7, 8, 9, 10, 13, 11, 12, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55"
     )]
     pub update: ::core::option::Option<game_update::Update>,
 }
@@ -1398,6 +1482,164 @@ pub mod game_update {
         /// Response notifying about the deletion of a corporation..
         #[prost(message, tag = "12")]
         DeleteCorporation(super::super::syndicode_economy_v1::DeleteCorporationResponse),
+        /// Response with the data of the newly created business listing.
+        #[prost(message, tag = "14")]
+        ListBusinessForSale(
+            super::super::syndicode_economy_v1::ListBusinessForSaleResponse,
+        ),
+        /// Response confirming the cancellation of a business listing.
+        #[prost(message, tag = "15")]
+        CancelBusinessListing(
+            super::super::syndicode_economy_v1::CancelBusinessListingResponse,
+        ),
+        /// Response with the data of the newly created business offer.
+        #[prost(message, tag = "16")]
+        MakeBusinessOffer(super::super::syndicode_economy_v1::MakeBusinessOfferResponse),
+        /// Response with the data of the business sold through an accepted offer.
+        #[prost(message, tag = "17")]
+        AcceptBusinessOffer(
+            super::super::syndicode_economy_v1::AcceptBusinessOfferResponse,
+        ),
+        /// Response confirming the rejection of a business offer.
+        #[prost(message, tag = "18")]
+        RejectBusinessOffer(
+            super::super::syndicode_economy_v1::RejectBusinessOfferResponse,
+        ),
+        /// Response confirming the withdrawal of a business offer.
+        #[prost(message, tag = "19")]
+        WithdrawBusinessOffer(
+            super::super::syndicode_economy_v1::WithdrawBusinessOfferResponse,
+        ),
+        /// Notifies the corporation about an offer made for one of its businesses.
+        #[prost(message, tag = "20")]
+        BusinessOfferReceived(
+            super::super::syndicode_economy_v1::BusinessOfferReceivedNotification,
+        ),
+        /// Notifies the corporation that one of its offers has been rejected or has expired.
+        #[prost(message, tag = "21")]
+        BusinessOfferClosed(
+            super::super::syndicode_economy_v1::BusinessOfferClosedNotification,
+        ),
+        /// Response with the data of the unit that has been sent on its way.
+        #[prost(message, tag = "22")]
+        MoveUnit(super::super::syndicode_warfare_v1::MoveUnitResponse),
+        /// Notifies the corporation that one of its units has arrived at its destination.
+        #[prost(message, tag = "23")]
+        UnitArrived(super::super::syndicode_warfare_v1::UnitArrivedNotification),
+        /// Response with the units that have been committed to an attack.
+        #[prost(message, tag = "24")]
+        AttackBuilding(super::super::syndicode_warfare_v1::AttackBuildingResponse),
+        /// Report about a round of combat the corporation is involved in.
+        #[prost(message, tag = "25")]
+        BattleReport(super::super::syndicode_warfare_v1::BattleReportNotification),
+        /// Summary of the corporation's cash movements within the tick.
+        #[prost(message, tag = "26")]
+        CorporationLedgerSummary(
+            super::super::syndicode_economy_v1::CorporationLedgerSummaryNotification,
+        ),
+        /// Response with the new operational expenses of a business.
+        #[prost(message, tag = "27")]
+        SetOperationalExpenses(
+            super::super::syndicode_economy_v1::SetOperationalExpensesResponse,
+        ),
+        /// Notifies the corporation that its balance has dropped to or below zero.
+        #[prost(message, tag = "28")]
+        CorporationInsolvent(
+            super::super::syndicode_economy_v1::CorporationInsolventNotification,
+        ),
+        /// Notifies the user that their corporation went bankrupt and has been dissolved.
+        #[prost(message, tag = "29")]
+        CorporationBankrupt(
+            super::super::syndicode_economy_v1::CorporationBankruptNotification,
+        ),
+        /// Response with the data of the newly taken loan.
+        #[prost(message, tag = "30")]
+        TakeLoan(super::super::syndicode_economy_v1::TakeLoanResponse),
+        /// Response with the remaining balance of a loan, also pushed when the bank collects a due loan.
+        #[prost(message, tag = "31")]
+        RepayLoan(super::super::syndicode_economy_v1::RepayLoanResponse),
+        /// Notifies the corporation that a due loan could not be paid back in full.
+        #[prost(message, tag = "32")]
+        LoanDefaulted(super::super::syndicode_economy_v1::LoanDefaultedNotification),
+        /// Response with the data of the placed auction bid.
+        #[prost(message, tag = "33")]
+        PlaceAuctionBid(super::super::syndicode_economy_v1::PlaceAuctionBidResponse),
+        /// Notifies the seller and the bidders that an auction has closed.
+        #[prost(message, tag = "34")]
+        AuctionClosed(super::super::syndicode_economy_v1::AuctionClosedNotification),
+        /// Response with the data of the acquired building.
+        #[prost(message, tag = "35")]
+        AcquireBuilding(super::super::syndicode_economy_v1::AcquireBuildingResponse),
+        /// Response with the result of an intel operation.
+        #[prost(message, tag = "36")]
+        GatherIntel(super::super::syndicode_espionage_v1::GatherIntelResponse),
+        /// Response with the result of an attempt to plant a mole.
+        #[prost(message, tag = "37")]
+        PlantMole(super::super::syndicode_espionage_v1::PlantMoleResponse),
+        /// Response with the moles exposed by a sweep.
+        #[prost(message, tag = "38")]
+        CounterIntel(super::super::syndicode_espionage_v1::CounterIntelResponse),
+        /// Notifies the corporation that a rival has been caught spying on it.
+        #[prost(message, tag = "39")]
+        EspionageDetected(
+            super::super::syndicode_espionage_v1::EspionageDetectedNotification,
+        ),
+        /// Notifies the corporation that one of its moles has been exposed.
+        #[prost(message, tag = "40")]
+        MoleExposed(super::super::syndicode_espionage_v1::MoleExposedNotification),
+        /// Response with the proposed treaty.
+        #[prost(message, tag = "41")]
+        ProposeTreaty(super::super::syndicode_diplomacy_v1::ProposeTreatyResponse),
+        /// Response with the accepted treaty.
+        #[prost(message, tag = "42")]
+        AcceptTreaty(super::super::syndicode_diplomacy_v1::AcceptTreatyResponse),
+        /// Response with the broken treaty.
+        #[prost(message, tag = "43")]
+        BreakTreaty(super::super::syndicode_diplomacy_v1::BreakTreatyResponse),
+        /// Notifies the corporation that another corporation has proposed a treaty to it.
+        #[prost(message, tag = "44")]
+        TreatyProposalReceived(
+            super::super::syndicode_diplomacy_v1::TreatyProposalReceivedNotification,
+        ),
+        /// Notifies the corporation that its proposal has been accepted.
+        #[prost(message, tag = "45")]
+        TreatyConcluded(
+            super::super::syndicode_diplomacy_v1::TreatyConcludedNotification,
+        ),
+        /// Notifies the corporation that the other party has broken a treaty.
+        #[prost(message, tag = "46")]
+        TreatyTerminated(
+            super::super::syndicode_diplomacy_v1::TreatyTerminatedNotification,
+        ),
+        /// Response with the transferred amount.
+        #[prost(message, tag = "47")]
+        TransferFunds(super::super::syndicode_economy_v1::TransferFundsResponse),
+        /// Response with the created contract.
+        #[prost(message, tag = "48")]
+        CreateContract(super::super::syndicode_economy_v1::CreateContractResponse),
+        /// Notifies the corporation that another corporation has sent it cash.
+        #[prost(message, tag = "49")]
+        FundsReceived(super::super::syndicode_economy_v1::FundsReceivedNotification),
+        /// Notifies the corporation that another corporation has signed a contract in its favour.
+        #[prost(message, tag = "50")]
+        ContractReceived(
+            super::super::syndicode_economy_v1::ContractReceivedNotification,
+        ),
+        /// Notifies both parties that a contract has been settled.
+        #[prost(message, tag = "51")]
+        ContractSettled(super::super::syndicode_economy_v1::ContractSettledNotification),
+        /// Everything that has changed for the corporation within the tick.
+        #[prost(message, tag = "52")]
+        CorporationStateDiff(super::CorporationStateDiffNotification),
+        /// Response with the created subscription.
+        #[prost(message, tag = "53")]
+        Subscribe(super::SubscribeResponse),
+        /// Response confirming the end of a subscription.
+        #[prost(message, tag = "54")]
+        Unsubscribe(super::UnsubscribeResponse),
+        /// World event matching one or more of the user's subscriptions.
+        #[prost(message, tag = "55")]
+        WorldEvent(super::WorldEventNotification),
     }
 }
 /// Response returned for actions that failed to process.
@@ -1407,8 +1649,73 @@ pub struct ActionFailedResponse {
     #[prost(string, tag = "1")]
     pub request_uuid: ::prost::alloc::string::String,
     /// The reason why the action failed to process.
+    /// Meant for humans, use `code` and `details` to react to the failure.
     #[prost(string, tag = "2")]
     pub reason: ::prost::alloc::string::String,
+    /// Stable category of the failure.
+    #[prost(enumeration = "ActionFailureCode", tag = "3")]
+    pub code: i32,
+    /// Structured data about the failure. Not set for every code.
+    #[prost(oneof = "action_failed_response::Details", tags = "4, 5, 6, 7")]
+    pub details: ::core::option::Option<action_failed_response::Details>,
+}
+/// Nested message and enum types in `ActionFailedResponse`.
+pub mod action_failed_response {
+    /// Structured data about the failure. Not set for every code.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Details {
+        /// The corporation can't pay for the action.
+        #[prost(message, tag = "4")]
+        InsufficientFunds(super::InsufficientFundsDetails),
+        /// A provided value is out of the accepted range.
+        #[prost(message, tag = "5")]
+        InvalidValue(super::InvalidValueDetails),
+        /// The entity the failure refers to.
+        #[prost(message, tag = "6")]
+        Entity(super::EntityDetails),
+        /// The action is on cooldown.
+        #[prost(message, tag = "7")]
+        Cooldown(super::CooldownDetails),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InsufficientFundsDetails {
+    /// UUID of the corporation.
+    #[prost(string, tag = "1")]
+    pub corporation_uuid: ::prost::alloc::string::String,
+    /// Amount the action needs.
+    #[prost(int64, tag = "2")]
+    pub required: i64,
+    /// Amount the corporation can spend.
+    #[prost(int64, tag = "3")]
+    pub available: i64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InvalidValueDetails {
+    /// The value sent with the action.
+    #[prost(int64, tag = "1")]
+    pub provided: i64,
+    /// Lowest accepted value, if there is one.
+    #[prost(message, optional, tag = "2")]
+    pub min: ::core::option::Option<i64>,
+    /// Highest accepted value, if there is one.
+    #[prost(message, optional, tag = "3")]
+    pub max: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EntityDetails {
+    /// Kind of the entity.
+    #[prost(enumeration = "FailureEntityType", tag = "1")]
+    pub entity_type: i32,
+    /// Identifier of the entity. A UUID for all entity types except buildings, which use their GML ID.
+    #[prost(string, tag = "2")]
+    pub entity_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CooldownDetails {
+    /// First game tick in which the action is accepted again.
+    #[prost(int64, tag = "1")]
+    pub available_at_tick: i64,
 }
 /// Notifies the user that their rate limit has been exceeded.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1424,6 +1731,286 @@ pub struct TickNotification {
     #[prost(message, optional, tag = "1")]
     pub effective_at: ::core::option::Option<::prost_types::Timestamp>,
 }
+/// Pushed after a tick to each corporation whose businesses, listings or units have changed
+/// within it. Applying it to the previously queried state yields the current one.
+/// The cash balance is pushed with the ledger summary of the tick.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CorporationStateDiffNotification {
+    /// UUID of the corporation.
+    #[prost(string, tag = "1")]
+    pub corporation_uuid: ::prost::alloc::string::String,
+    /// UUIDs of the businesses the corporation has acquired or captured.
+    #[prost(string, repeated, tag = "2")]
+    pub businesses_gained: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// UUIDs of the businesses the corporation has sold or lost.
+    #[prost(string, repeated, tag = "3")]
+    pub businesses_lost: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// UUIDs of the corporation's listings that have been sold.
+    #[prost(string, repeated, tag = "4")]
+    pub listings_sold: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Units of the corporation that have been spawned, moved or damaged.
+    #[prost(message, repeated, tag = "5")]
+    pub units_updated: ::prost::alloc::vec::Vec<super::syndicode_warfare_v1::Unit>,
+    /// UUIDs of the corporation's units that have been destroyed or disbanded.
+    #[prost(string, repeated, tag = "6")]
+    pub units_removed: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Subscribes to world events of a topic. Subscriptions last until they are cancelled
+/// or the user has no stream open anymore.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub topic: ::core::option::Option<WorldEventTopic>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the subscription, referenced by world event notifications and unsubscribe requests.
+    #[prost(string, tag = "2")]
+    pub subscription_uuid: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub topic: ::core::option::Option<WorldEventTopic>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnsubscribeRequest {
+    /// UUID of the subscription to cancel.
+    #[prost(string, tag = "1")]
+    pub subscription_uuid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnsubscribeResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// UUID of the cancelled subscription.
+    #[prost(string, tag = "2")]
+    pub subscription_uuid: ::prost::alloc::string::String,
+}
+/// Filter for the world events a player is interested in.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WorldEventTopic {
+    #[prost(oneof = "world_event_topic::Topic", tags = "1, 2")]
+    pub topic: ::core::option::Option<world_event_topic::Topic>,
+}
+/// Nested message and enum types in `WorldEventTopic`.
+pub mod world_event_topic {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Topic {
+        /// New business listings.
+        #[prost(message, tag = "1")]
+        BusinessListings(super::BusinessListingsTopic),
+        /// Buildings that changed owner.
+        #[prost(message, tag = "2")]
+        BuildingOwnership(super::BuildingOwnershipTopic),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BusinessListingsTopic {
+    /// Only listings of businesses in this market (optional).
+    #[prost(message, optional, tag = "1")]
+    pub market_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// Only listings up to this asking price (optional).
+    #[prost(message, optional, tag = "2")]
+    pub max_asking_price: ::core::option::Option<i64>,
+}
+/// Bounding box the buildings have to be located in.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BuildingOwnershipTopic {
+    #[prost(double, tag = "1")]
+    pub min_longitude: f64,
+    #[prost(double, tag = "2")]
+    pub min_latitude: f64,
+    #[prost(double, tag = "3")]
+    pub max_longitude: f64,
+    #[prost(double, tag = "4")]
+    pub max_latitude: f64,
+}
+/// Pushed once per event that matches one or more of the user's subscriptions.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WorldEventNotification {
+    /// UUIDs of the subscriptions the event matches.
+    #[prost(string, repeated, tag = "1")]
+    pub subscription_uuids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(oneof = "world_event_notification::Event", tags = "2, 3")]
+    pub event: ::core::option::Option<world_event_notification::Event>,
+}
+/// Nested message and enum types in `WorldEventNotification`.
+pub mod world_event_notification {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        /// A business has been put up for sale.
+        #[prost(message, tag = "2")]
+        BusinessListed(super::BusinessListedEvent),
+        /// A building changed owner.
+        #[prost(message, tag = "3")]
+        BuildingOwnerChanged(super::BuildingOwnerChangedEvent),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BusinessListedEvent {
+    /// UUID of the listing.
+    #[prost(string, tag = "1")]
+    pub listing_uuid: ::prost::alloc::string::String,
+    /// UUID of the listed business.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// UUID of the market the business operates in.
+    #[prost(string, tag = "3")]
+    pub market_uuid: ::prost::alloc::string::String,
+    /// UUID of the selling corporation, none if the business is sold by the market.
+    #[prost(message, optional, tag = "4")]
+    pub seller_corporation_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// Fixed price, or the reserve price of an auction.
+    #[prost(int64, tag = "5")]
+    pub asking_price: i64,
+    /// Tick at which the auction closes, none for fixed price listings.
+    #[prost(message, optional, tag = "6")]
+    pub auction_closes_at_tick: ::core::option::Option<i64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuildingOwnerChangedEvent {
+    /// GML ID of the building.
+    #[prost(string, tag = "1")]
+    pub building_gml_id: ::prost::alloc::string::String,
+    #[prost(double, tag = "2")]
+    pub longitude: f64,
+    #[prost(double, tag = "3")]
+    pub latitude: f64,
+    /// UUID of the corporation that owned the building before (optional).
+    #[prost(message, optional, tag = "4")]
+    pub previous_owner_corporation_uuid: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// UUID of the corporation that owns the building now (optional).
+    #[prost(message, optional, tag = "5")]
+    pub owning_corporation_uuid: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ActionFailureCode {
+    Unspecified = 0,
+    /// Unexpected server side failure.
+    Internal = 1,
+    /// The request contains an invalid value.
+    InvalidArgument = 2,
+    /// A referenced entity doesn't exist (anymore).
+    NotFound = 3,
+    /// A referenced entity doesn't belong to the corporation of the user.
+    NotOwner = 4,
+    /// The corporation of the user already owns the entity.
+    AlreadyOwned = 5,
+    /// The entity the action would create already exists.
+    AlreadyExists = 6,
+    /// The corporation doesn't have enough cash.
+    InsufficientFunds = 7,
+    /// The loan would exceed the credit limit of the corporation.
+    CreditLimitExceeded = 8,
+    /// The action can't be repeated yet.
+    Cooldown = 9,
+    /// The entity is in a state that doesn't allow the action.
+    InvalidState = 10,
+    /// Another action competing for the same business won in this tick.
+    ContestLost = 11,
+    /// The rate limit of the user has been exceeded.
+    RateLimited = 12,
+    /// The user is not allowed to perform the action.
+    PermissionDenied = 13,
+}
+impl ActionFailureCode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "ACTION_FAILURE_CODE_UNSPECIFIED",
+            Self::Internal => "ACTION_FAILURE_CODE_INTERNAL",
+            Self::InvalidArgument => "ACTION_FAILURE_CODE_INVALID_ARGUMENT",
+            Self::NotFound => "ACTION_FAILURE_CODE_NOT_FOUND",
+            Self::NotOwner => "ACTION_FAILURE_CODE_NOT_OWNER",
+            Self::AlreadyOwned => "ACTION_FAILURE_CODE_ALREADY_OWNED",
+            Self::AlreadyExists => "ACTION_FAILURE_CODE_ALREADY_EXISTS",
+            Self::InsufficientFunds => "ACTION_FAILURE_CODE_INSUFFICIENT_FUNDS",
+            Self::CreditLimitExceeded => "ACTION_FAILURE_CODE_CREDIT_LIMIT_EXCEEDED",
+            Self::Cooldown => "ACTION_FAILURE_CODE_COOLDOWN",
+            Self::InvalidState => "ACTION_FAILURE_CODE_INVALID_STATE",
+            Self::ContestLost => "ACTION_FAILURE_CODE_CONTEST_LOST",
+            Self::RateLimited => "ACTION_FAILURE_CODE_RATE_LIMITED",
+            Self::PermissionDenied => "ACTION_FAILURE_CODE_PERMISSION_DENIED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ACTION_FAILURE_CODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "ACTION_FAILURE_CODE_INTERNAL" => Some(Self::Internal),
+            "ACTION_FAILURE_CODE_INVALID_ARGUMENT" => Some(Self::InvalidArgument),
+            "ACTION_FAILURE_CODE_NOT_FOUND" => Some(Self::NotFound),
+            "ACTION_FAILURE_CODE_NOT_OWNER" => Some(Self::NotOwner),
+            "ACTION_FAILURE_CODE_ALREADY_OWNED" => Some(Self::AlreadyOwned),
+            "ACTION_FAILURE_CODE_ALREADY_EXISTS" => Some(Self::AlreadyExists),
+            "ACTION_FAILURE_CODE_INSUFFICIENT_FUNDS" => Some(Self::InsufficientFunds),
+            "ACTION_FAILURE_CODE_CREDIT_LIMIT_EXCEEDED" => {
+                Some(Self::CreditLimitExceeded)
+            }
+            "ACTION_FAILURE_CODE_COOLDOWN" => Some(Self::Cooldown),
+            "ACTION_FAILURE_CODE_INVALID_STATE" => Some(Self::InvalidState),
+            "ACTION_FAILURE_CODE_CONTEST_LOST" => Some(Self::ContestLost),
+            "ACTION_FAILURE_CODE_RATE_LIMITED" => Some(Self::RateLimited),
+            "ACTION_FAILURE_CODE_PERMISSION_DENIED" => Some(Self::PermissionDenied),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FailureEntityType {
+    Unspecified = 0,
+    Corporation = 1,
+    Business = 2,
+    BusinessListing = 3,
+    BusinessOffer = 4,
+    Unit = 5,
+    Building = 6,
+    Loan = 7,
+    Treaty = 8,
+}
+impl FailureEntityType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "FAILURE_ENTITY_TYPE_UNSPECIFIED",
+            Self::Corporation => "FAILURE_ENTITY_TYPE_CORPORATION",
+            Self::Business => "FAILURE_ENTITY_TYPE_BUSINESS",
+            Self::BusinessListing => "FAILURE_ENTITY_TYPE_BUSINESS_LISTING",
+            Self::BusinessOffer => "FAILURE_ENTITY_TYPE_BUSINESS_OFFER",
+            Self::Unit => "FAILURE_ENTITY_TYPE_UNIT",
+            Self::Building => "FAILURE_ENTITY_TYPE_BUILDING",
+            Self::Loan => "FAILURE_ENTITY_TYPE_LOAN",
+            Self::Treaty => "FAILURE_ENTITY_TYPE_TREATY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FAILURE_ENTITY_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "FAILURE_ENTITY_TYPE_CORPORATION" => Some(Self::Corporation),
+            "FAILURE_ENTITY_TYPE_BUSINESS" => Some(Self::Business),
+            "FAILURE_ENTITY_TYPE_BUSINESS_LISTING" => Some(Self::BusinessListing),
+            "FAILURE_ENTITY_TYPE_BUSINESS_OFFER" => Some(Self::BusinessOffer),
+            "FAILURE_ENTITY_TYPE_UNIT" => Some(Self::Unit),
+            "FAILURE_ENTITY_TYPE_BUILDING" => Some(Self::Building),
+            "FAILURE_ENTITY_TYPE_LOAN" => Some(Self::Loan),
+            "FAILURE_ENTITY_TYPE_TREATY" => Some(Self::Treaty),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod game_service_client {
     #![allow(
@@ -1517,6 +2104,8 @@ pub mod game_service_client {
             self
         }
         /// Bidirectional stream where players send actions and receive updates.
+        /// Clients resuming after a disconnect can pass the `last-seen-game-tick` metadata
+        /// to receive the outcomes of later ticks they have missed, oldest first.
         pub async fn play_stream(
             &mut self,
             request: impl tonic::IntoStreamingRequest<Message = super::PlayerAction>,
@@ -1565,6 +2154,8 @@ pub mod game_service_server {
             + std::marker::Send
             + 'static;
         /// Bidirectional stream where players send actions and receive updates.
+        /// Clients resuming after a disconnect can pass the `last-seen-game-tick` metadata
+        /// to receive the outcomes of later ticks they have missed, oldest first.
         async fn play_stream(
             &self,
             request: tonic::Request<tonic::Streaming<super::PlayerAction>>,
@@ -1859,6 +2450,40 @@ pub mod economy_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Lists the cash movements of the current corporation.
+        pub async fn get_corporation_ledger(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::GetCorporationLedgerRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::syndicode_economy_v1::GetCorporationLedgerResponse,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/GetCorporationLedger",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "GetCorporationLedger",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Queries buildings with optional filters and pagination.
         pub async fn query_buildings(
             &mut self,
@@ -1891,6 +2516,40 @@ pub mod economy_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Queries the wards and the corporations controlling them.
+        pub async fn query_territories(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::QueryTerritoriesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::syndicode_economy_v1::QueryTerritoriesResponse,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/QueryTerritories",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "QueryTerritories",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Queries businesses with optional filters and pagination.
         pub async fn query_businesses(
             &mut self,
@@ -1957,6 +2616,38 @@ pub mod economy_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Queries the volume history of the markets with optional filters and pagination.
+        pub async fn query_markets(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::QueryMarketsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::syndicode_economy_v1::QueryMarketsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/QueryMarkets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "QueryMarkets",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Acquires a listed business for the current corporation.
         pub async fn acquire_listed_business(
             &mut self,
@@ -1989,160 +2680,1101 @@ pub mod economy_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod economy_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with EconomyServiceServer.
-    #[async_trait]
-    pub trait EconomyService: std::marker::Send + std::marker::Sync + 'static {
-        /// Request to fetch corporation data.
-        async fn get_current_corporation(
-            &self,
-            request: tonic::Request<
-                super::super::syndicode_economy_v1::GetCorporationRequest,
-            >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::syndicode_economy_v1::Corporation>,
-            tonic::Status,
-        >;
-        /// Queries buildings with optional filters and pagination.
-        async fn query_buildings(
-            &self,
-            request: tonic::Request<
-                super::super::syndicode_economy_v1::QueryBuildingsRequest,
+        /// Puts a business of the current corporation up for sale.
+        pub async fn list_business_for_sale(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::ListBusinessForSaleRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::syndicode_economy_v1::QueryBuildingsResponse>,
+            tonic::Response<super::ActionInitResponse>,
             tonic::Status,
-        >;
-        /// Queries businesses with optional filters and pagination.
-        async fn query_businesses(
-            &self,
-            request: tonic::Request<
-                super::super::syndicode_economy_v1::QueryBusinessesRequest,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/ListBusinessForSale",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "ListBusinessForSale",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Cancels a business listing of the current corporation.
+        pub async fn cancel_business_listing(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::CancelBusinessListingRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::syndicode_economy_v1::QueryBusinessesResponse>,
+            tonic::Response<super::ActionInitResponse>,
             tonic::Status,
-        >;
-        /// Queries business listings with optional filters and pagination.
-        async fn query_business_listings(
-            &self,
-            request: tonic::Request<
-                super::super::syndicode_economy_v1::QueryBusinessListingsRequest,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/CancelBusinessListing",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "CancelBusinessListing",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Makes an offer for a business owned by another corporation.
+        pub async fn make_business_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::MakeBusinessOfferRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<
-                super::super::syndicode_economy_v1::QueryBusinessListingsResponse,
-            >,
+            tonic::Response<super::ActionInitResponse>,
             tonic::Status,
-        >;
-        /// Acquires a listed business for the current corporation.
-        async fn acquire_listed_business(
-            &self,
-            request: tonic::Request<
-                super::super::syndicode_economy_v1::AcquireListedBusinessRequest,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/MakeBusinessOffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "MakeBusinessOffer",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Accepts an offer made for a business of the current corporation.
+        pub async fn accept_business_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::AcceptBusinessOfferRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<super::ActionInitResponse>,
             tonic::Status,
-        >;
-    }
-    /// EconomyService provides methods for querying economy-related data.
-    #[derive(Debug)]
-    pub struct EconomyServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> EconomyServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
-        }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/AcceptBusinessOffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "AcceptBusinessOffer",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
+        /// Rejects an offer made for a business of the current corporation.
+        pub async fn reject_business_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::RejectBusinessOfferRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/RejectBusinessOffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "RejectBusinessOffer",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
+        /// Withdraws an offer made by the current corporation.
+        pub async fn withdraw_business_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::WithdrawBusinessOfferRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/WithdrawBusinessOffer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "WithdrawBusinessOffer",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
+        /// Changes the operational expenses of a business of the current corporation.
+        pub async fn set_operational_expenses(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::SetOperationalExpensesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/SetOperationalExpenses",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "SetOperationalExpenses",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for EconomyServiceServer<T>
-    where
-        T: EconomyService,
-        B: Body + std::marker::Send + 'static,
-        B::Error: Into<StdError> + std::marker::Send + 'static,
-    {
-        type Response = http::Response<tonic::body::Body>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        /// Borrows cash from the bank, up to the credit limit of the current corporation.
+        pub async fn take_loan(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::TakeLoanRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/TakeLoan",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("syndicode_interface_v1.EconomyService", "TakeLoan"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            match req.uri().path() {
-                "/syndicode_interface_v1.EconomyService/GetCurrentCorporation" => {
+        /// Pays back a loan of the current corporation.
+        pub async fn repay_loan(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::RepayLoanRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/RepayLoan",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("syndicode_interface_v1.EconomyService", "RepayLoan"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Places a sealed bid on an auction listing.
+        pub async fn place_auction_bid(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::PlaceAuctionBidRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/PlaceAuctionBid",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "PlaceAuctionBid",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Buys an unowned building near the headquarter of a business of the current corporation.
+        pub async fn acquire_building(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::syndicode_economy_v1::AcquireBuildingRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/syndicode_interface_v1.EconomyService/AcquireBuilding",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "syndicode_interface_v1.EconomyService",
+                        "AcquireBuilding",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod economy_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with EconomyServiceServer.
+    #[async_trait]
+    pub trait EconomyService: std::marker::Send + std::marker::Sync + 'static {
+        /// Request to fetch corporation data.
+        async fn get_current_corporation(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::GetCorporationRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::syndicode_economy_v1::Corporation>,
+            tonic::Status,
+        >;
+        /// Lists the cash movements of the current corporation.
+        async fn get_corporation_ledger(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::GetCorporationLedgerRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::syndicode_economy_v1::GetCorporationLedgerResponse,
+            >,
+            tonic::Status,
+        >;
+        /// Queries buildings with optional filters and pagination.
+        async fn query_buildings(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::QueryBuildingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::syndicode_economy_v1::QueryBuildingsResponse>,
+            tonic::Status,
+        >;
+        /// Queries the wards and the corporations controlling them.
+        async fn query_territories(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::QueryTerritoriesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::syndicode_economy_v1::QueryTerritoriesResponse,
+            >,
+            tonic::Status,
+        >;
+        /// Queries businesses with optional filters and pagination.
+        async fn query_businesses(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::QueryBusinessesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::syndicode_economy_v1::QueryBusinessesResponse>,
+            tonic::Status,
+        >;
+        /// Queries business listings with optional filters and pagination.
+        async fn query_business_listings(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::QueryBusinessListingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::syndicode_economy_v1::QueryBusinessListingsResponse,
+            >,
+            tonic::Status,
+        >;
+        /// Queries the volume history of the markets with optional filters and pagination.
+        async fn query_markets(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::QueryMarketsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::syndicode_economy_v1::QueryMarketsResponse>,
+            tonic::Status,
+        >;
+        /// Acquires a listed business for the current corporation.
+        async fn acquire_listed_business(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::AcquireListedBusinessRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Puts a business of the current corporation up for sale.
+        async fn list_business_for_sale(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::ListBusinessForSaleRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Cancels a business listing of the current corporation.
+        async fn cancel_business_listing(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::CancelBusinessListingRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Makes an offer for a business owned by another corporation.
+        async fn make_business_offer(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::MakeBusinessOfferRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Accepts an offer made for a business of the current corporation.
+        async fn accept_business_offer(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::AcceptBusinessOfferRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Rejects an offer made for a business of the current corporation.
+        async fn reject_business_offer(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::RejectBusinessOfferRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Withdraws an offer made by the current corporation.
+        async fn withdraw_business_offer(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::WithdrawBusinessOfferRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Changes the operational expenses of a business of the current corporation.
+        async fn set_operational_expenses(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::SetOperationalExpensesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Borrows cash from the bank, up to the credit limit of the current corporation.
+        async fn take_loan(
+            &self,
+            request: tonic::Request<super::super::syndicode_economy_v1::TakeLoanRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Pays back a loan of the current corporation.
+        async fn repay_loan(
+            &self,
+            request: tonic::Request<super::super::syndicode_economy_v1::RepayLoanRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Places a sealed bid on an auction listing.
+        async fn place_auction_bid(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::PlaceAuctionBidRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+        /// Buys an unowned building near the headquarter of a business of the current corporation.
+        async fn acquire_building(
+            &self,
+            request: tonic::Request<
+                super::super::syndicode_economy_v1::AcquireBuildingRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ActionInitResponse>,
+            tonic::Status,
+        >;
+    }
+    /// EconomyService provides methods for querying economy-related data.
+    #[derive(Debug)]
+    pub struct EconomyServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> EconomyServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for EconomyServiceServer<T>
+    where
+        T: EconomyService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/syndicode_interface_v1.EconomyService/GetCurrentCorporation" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCurrentCorporationSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::GetCorporationRequest,
+                    > for GetCurrentCorporationSvc<T> {
+                        type Response = super::super::syndicode_economy_v1::Corporation;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::GetCorporationRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::get_current_corporation(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCurrentCorporationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/GetCorporationLedger" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCorporationLedgerSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::GetCorporationLedgerRequest,
+                    > for GetCorporationLedgerSvc<T> {
+                        type Response = super::super::syndicode_economy_v1::GetCorporationLedgerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::GetCorporationLedgerRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::get_corporation_ledger(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCorporationLedgerSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/QueryBuildings" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryBuildingsSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::QueryBuildingsRequest,
+                    > for QueryBuildingsSvc<T> {
+                        type Response = super::super::syndicode_economy_v1::QueryBuildingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::QueryBuildingsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::query_buildings(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryBuildingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/QueryTerritories" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryTerritoriesSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::QueryTerritoriesRequest,
+                    > for QueryTerritoriesSvc<T> {
+                        type Response = super::super::syndicode_economy_v1::QueryTerritoriesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::QueryTerritoriesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::query_territories(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryTerritoriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/QueryBusinesses" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryBusinessesSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::QueryBusinessesRequest,
+                    > for QueryBusinessesSvc<T> {
+                        type Response = super::super::syndicode_economy_v1::QueryBusinessesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::QueryBusinessesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::query_businesses(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryBusinessesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/QueryBusinessListings" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryBusinessListingsSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::QueryBusinessListingsRequest,
+                    > for QueryBusinessListingsSvc<T> {
+                        type Response = super::super::syndicode_economy_v1::QueryBusinessListingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::QueryBusinessListingsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::query_business_listings(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryBusinessListingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/QueryMarkets" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryMarketsSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::QueryMarketsRequest,
+                    > for QueryMarketsSvc<T> {
+                        type Response = super::super::syndicode_economy_v1::QueryMarketsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::QueryMarketsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::query_markets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryMarketsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/AcquireListedBusiness" => {
+                    #[allow(non_camel_case_types)]
+                    struct AcquireListedBusinessSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::AcquireListedBusinessRequest,
+                    > for AcquireListedBusinessSvc<T> {
+                        type Response = super::ActionInitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::AcquireListedBusinessRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::acquire_listed_business(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AcquireListedBusinessSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/ListBusinessForSale" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListBusinessForSaleSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::ListBusinessForSaleRequest,
+                    > for ListBusinessForSaleSvc<T> {
+                        type Response = super::ActionInitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::ListBusinessForSaleRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::list_business_for_sale(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListBusinessForSaleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/CancelBusinessListing" => {
                     #[allow(non_camel_case_types)]
-                    struct GetCurrentCorporationSvc<T: EconomyService>(pub Arc<T>);
+                    struct CancelBusinessListingSvc<T: EconomyService>(pub Arc<T>);
                     impl<
                         T: EconomyService,
                     > tonic::server::UnaryService<
-                        super::super::syndicode_economy_v1::GetCorporationRequest,
-                    > for GetCurrentCorporationSvc<T> {
-                        type Response = super::super::syndicode_economy_v1::Corporation;
+                        super::super::syndicode_economy_v1::CancelBusinessListingRequest,
+                    > for CancelBusinessListingSvc<T> {
+                        type Response = super::ActionInitResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2150,12 +3782,12 @@ pub mod economy_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::syndicode_economy_v1::GetCorporationRequest,
+                                super::super::syndicode_economy_v1::CancelBusinessListingRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as EconomyService>::get_current_corporation(
+                                <T as EconomyService>::cancel_business_listing(
                                         &inner,
                                         request,
                                     )
@@ -2170,7 +3802,7 @@ pub mod economy_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetCurrentCorporationSvc(inner);
+                        let method = CancelBusinessListingSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2186,15 +3818,15 @@ pub mod economy_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/syndicode_interface_v1.EconomyService/QueryBuildings" => {
+                "/syndicode_interface_v1.EconomyService/MakeBusinessOffer" => {
                     #[allow(non_camel_case_types)]
-                    struct QueryBuildingsSvc<T: EconomyService>(pub Arc<T>);
+                    struct MakeBusinessOfferSvc<T: EconomyService>(pub Arc<T>);
                     impl<
                         T: EconomyService,
                     > tonic::server::UnaryService<
-                        super::super::syndicode_economy_v1::QueryBuildingsRequest,
-                    > for QueryBuildingsSvc<T> {
-                        type Response = super::super::syndicode_economy_v1::QueryBuildingsResponse;
+                        super::super::syndicode_economy_v1::MakeBusinessOfferRequest,
+                    > for MakeBusinessOfferSvc<T> {
+                        type Response = super::ActionInitResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2202,12 +3834,12 @@ pub mod economy_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::syndicode_economy_v1::QueryBuildingsRequest,
+                                super::super::syndicode_economy_v1::MakeBusinessOfferRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as EconomyService>::query_buildings(&inner, request)
+                                <T as EconomyService>::make_business_offer(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2219,7 +3851,7 @@ pub mod economy_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = QueryBuildingsSvc(inner);
+                        let method = MakeBusinessOfferSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2235,15 +3867,15 @@ pub mod economy_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/syndicode_interface_v1.EconomyService/QueryBusinesses" => {
+                "/syndicode_interface_v1.EconomyService/AcceptBusinessOffer" => {
                     #[allow(non_camel_case_types)]
-                    struct QueryBusinessesSvc<T: EconomyService>(pub Arc<T>);
+                    struct AcceptBusinessOfferSvc<T: EconomyService>(pub Arc<T>);
                     impl<
                         T: EconomyService,
                     > tonic::server::UnaryService<
-                        super::super::syndicode_economy_v1::QueryBusinessesRequest,
-                    > for QueryBusinessesSvc<T> {
-                        type Response = super::super::syndicode_economy_v1::QueryBusinessesResponse;
+                        super::super::syndicode_economy_v1::AcceptBusinessOfferRequest,
+                    > for AcceptBusinessOfferSvc<T> {
+                        type Response = super::ActionInitResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2251,12 +3883,15 @@ pub mod economy_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::syndicode_economy_v1::QueryBusinessesRequest,
+                                super::super::syndicode_economy_v1::AcceptBusinessOfferRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as EconomyService>::query_businesses(&inner, request)
+                                <T as EconomyService>::accept_business_offer(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -2268,7 +3903,7 @@ pub mod economy_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = QueryBusinessesSvc(inner);
+                        let method = AcceptBusinessOfferSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2284,15 +3919,15 @@ pub mod economy_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/syndicode_interface_v1.EconomyService/QueryBusinessListings" => {
+                "/syndicode_interface_v1.EconomyService/RejectBusinessOffer" => {
                     #[allow(non_camel_case_types)]
-                    struct QueryBusinessListingsSvc<T: EconomyService>(pub Arc<T>);
+                    struct RejectBusinessOfferSvc<T: EconomyService>(pub Arc<T>);
                     impl<
                         T: EconomyService,
                     > tonic::server::UnaryService<
-                        super::super::syndicode_economy_v1::QueryBusinessListingsRequest,
-                    > for QueryBusinessListingsSvc<T> {
-                        type Response = super::super::syndicode_economy_v1::QueryBusinessListingsResponse;
+                        super::super::syndicode_economy_v1::RejectBusinessOfferRequest,
+                    > for RejectBusinessOfferSvc<T> {
+                        type Response = super::ActionInitResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2300,12 +3935,12 @@ pub mod economy_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::syndicode_economy_v1::QueryBusinessListingsRequest,
+                                super::super::syndicode_economy_v1::RejectBusinessOfferRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as EconomyService>::query_business_listings(
+                                <T as EconomyService>::reject_business_offer(
                                         &inner,
                                         request,
                                     )
@@ -2320,7 +3955,7 @@ pub mod economy_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = QueryBusinessListingsSvc(inner);
+                        let method = RejectBusinessOfferSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2336,14 +3971,14 @@ pub mod economy_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/syndicode_interface_v1.EconomyService/AcquireListedBusiness" => {
+                "/syndicode_interface_v1.EconomyService/WithdrawBusinessOffer" => {
                     #[allow(non_camel_case_types)]
-                    struct AcquireListedBusinessSvc<T: EconomyService>(pub Arc<T>);
+                    struct WithdrawBusinessOfferSvc<T: EconomyService>(pub Arc<T>);
                     impl<
                         T: EconomyService,
                     > tonic::server::UnaryService<
-                        super::super::syndicode_economy_v1::AcquireListedBusinessRequest,
-                    > for AcquireListedBusinessSvc<T> {
+                        super::super::syndicode_economy_v1::WithdrawBusinessOfferRequest,
+                    > for WithdrawBusinessOfferSvc<T> {
                         type Response = super::ActionInitResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -2352,12 +3987,12 @@ pub mod economy_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::syndicode_economy_v1::AcquireListedBusinessRequest,
+                                super::super::syndicode_economy_v1::WithdrawBusinessOfferRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as EconomyService>::acquire_listed_business(
+                                <T as EconomyService>::withdraw_business_offer(
                                         &inner,
                                         request,
                                     )
@@ -2372,7 +4007,253 @@ pub mod economy_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = AcquireListedBusinessSvc(inner);
+                        let method = WithdrawBusinessOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/SetOperationalExpenses" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetOperationalExpensesSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::SetOperationalExpensesRequest,
+                    > for SetOperationalExpensesSvc<T> {
+                        type Response = super::ActionInitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::SetOperationalExpensesRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::set_operational_expenses(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetOperationalExpensesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/TakeLoan" => {
+                    #[allow(non_camel_case_types)]
+                    struct TakeLoanSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::TakeLoanRequest,
+                    > for TakeLoanSvc<T> {
+                        type Response = super::ActionInitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::TakeLoanRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::take_loan(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TakeLoanSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/RepayLoan" => {
+                    #[allow(non_camel_case_types)]
+                    struct RepayLoanSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::RepayLoanRequest,
+                    > for RepayLoanSvc<T> {
+                        type Response = super::ActionInitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::RepayLoanRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::repay_loan(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RepayLoanSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/PlaceAuctionBid" => {
+                    #[allow(non_camel_case_types)]
+                    struct PlaceAuctionBidSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::PlaceAuctionBidRequest,
+                    > for PlaceAuctionBidSvc<T> {
+                        type Response = super::ActionInitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::PlaceAuctionBidRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::place_auction_bid(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PlaceAuctionBidSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/syndicode_interface_v1.EconomyService/AcquireBuilding" => {
+                    #[allow(non_camel_case_types)]
+                    struct AcquireBuildingSvc<T: EconomyService>(pub Arc<T>);
+                    impl<
+                        T: EconomyService,
+                    > tonic::server::UnaryService<
+                        super::super::syndicode_economy_v1::AcquireBuildingRequest,
+                    > for AcquireBuildingSvc<T> {
+                        type Response = super::ActionInitResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::syndicode_economy_v1::AcquireBuildingRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as EconomyService>::acquire_building(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AcquireBuildingSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
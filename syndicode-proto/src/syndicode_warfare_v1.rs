@@ -1,7 +1,15 @@
 // This file is @generated by prost-build.
 /// Request to spawn a new combat unit.
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct SpawnUnitRequest {}
+/// Units can only be spawned at buildings owned by one of the corporation's businesses.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpawnUnitRequest {
+    /// The type of the unit to spawn. Determines its stats and spawn cost.
+    #[prost(enumeration = "UnitType", tag = "1")]
+    pub unit_type: i32,
+    /// GML ID of the building the unit is spawned at.
+    #[prost(string, tag = "2")]
+    pub building_gml_id: ::prost::alloc::string::String,
+}
 /// Response after attempting to spawn a unit.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpawnUnitResponse {
@@ -11,8 +19,111 @@ pub struct SpawnUnitResponse {
     /// The unit that has been spawned.
     #[prost(message, optional, tag = "2")]
     pub unit: ::core::option::Option<Unit>,
+    /// The cash that has been deducted from the corporation for the unit.
+    #[prost(int64, tag = "3")]
+    pub spawn_cost: i64,
+}
+/// Request to move a unit to another building.
+/// The travel time depends on the distance between the buildings and the speed of the unit.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveUnitRequest {
+    /// UUID of the unit to move.
+    #[prost(string, tag = "1")]
+    pub unit_uuid: ::prost::alloc::string::String,
+    /// GML ID of the building the unit should move to.
+    #[prost(string, tag = "2")]
+    pub destination_building_gml_id: ::prost::alloc::string::String,
+}
+/// Response after a unit has been sent on its way.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveUnitResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// The unit including its destination and arrival tick.
+    #[prost(message, optional, tag = "2")]
+    pub unit: ::core::option::Option<Unit>,
+}
+/// Notifies the corporation that one of its units has arrived at its destination.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnitArrivedNotification {
+    /// The unit at its new location.
+    #[prost(message, optional, tag = "1")]
+    pub unit: ::core::option::Option<Unit>,
+}
+/// Request to commit units to an attack on a building owned by a rival corporation.
+/// Units which are not yet located at the building move there first and join the fight on arrival.
+/// Capturing the headquarter building of a business transfers the business to the attacker.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttackBuildingRequest {
+    /// GML ID of the building to attack.
+    #[prost(string, tag = "1")]
+    pub building_gml_id: ::prost::alloc::string::String,
+    /// UUIDs of the units to commit to the attack.
+    #[prost(string, repeated, tag = "2")]
+    pub unit_uuids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Response after units have been committed to an attack.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttackBuildingResponse {
+    /// UUID generated on the client to match the response with the initial request.
+    #[prost(string, tag = "1")]
+    pub request_uuid: ::prost::alloc::string::String,
+    /// GML ID of the attacked building.
+    #[prost(string, tag = "2")]
+    pub building_gml_id: ::prost::alloc::string::String,
+    /// UUIDs of the units that have been committed to the attack.
+    #[prost(string, repeated, tag = "3")]
+    pub unit_uuids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Tick at which the last committed unit arrives at the building.
+    #[prost(int64, tag = "4")]
+    pub arrival_tick: i64,
+}
+/// Report about a round of combat, sent to every corporation involved in the fight.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BattleReportNotification {
+    /// GML ID of the contested building.
+    #[prost(string, tag = "1")]
+    pub building_gml_id: ::prost::alloc::string::String,
+    /// UUID of the business owning the building.
+    #[prost(string, tag = "2")]
+    pub business_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation defending the building.
+    #[prost(string, tag = "3")]
+    pub defender_corporation_uuid: ::prost::alloc::string::String,
+    /// UUIDs of the attacking corporations.
+    #[prost(string, repeated, tag = "4")]
+    pub attacker_corporation_uuids: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
+    /// Units that have been destroyed in this round.
+    #[prost(message, repeated, tag = "5")]
+    pub destroyed_units: ::prost::alloc::vec::Vec<DestroyedUnit>,
+    /// State of the battle after this round.
+    #[prost(enumeration = "BattleResult", tag = "6")]
+    pub result: i32,
+    /// UUID of the business that has been captured by the attackers (optional).
+    #[prost(message, optional, tag = "7")]
+    pub captured_business_uuid: ::core::option::Option<::prost::alloc::string::String>,
+    /// UUID of the corporation that captured the business (optional).
+    #[prost(message, optional, tag = "8")]
+    pub capturing_corporation_uuid: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+}
+/// A unit that has been destroyed in combat.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DestroyedUnit {
+    /// UUID of the destroyed unit.
+    #[prost(string, tag = "1")]
+    pub unit_uuid: ::prost::alloc::string::String,
+    /// UUID of the corporation which owned the unit.
+    #[prost(string, tag = "2")]
+    pub corporation_uuid: ::prost::alloc::string::String,
 }
 /// Request to list all units owned by a player.
+/// Units of rivals are only listed in full if the player has planted a mole in them. Otherwise
+/// only the units stationed in wards the player owns buildings in are listed, without their orders.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListUnitsRequest {
     /// UUID of the corporation owning the units.
@@ -38,4 +149,104 @@ pub struct Unit {
     /// UUID of the corporation which owns the unit.
     #[prost(string, tag = "2")]
     pub corporation_uuid: ::prost::alloc::string::String,
+    /// The type of the unit.
+    #[prost(enumeration = "UnitType", tag = "3")]
+    pub unit_type: i32,
+    /// Remaining health of the unit.
+    #[prost(int32, tag = "4")]
+    pub health: i32,
+    /// Attack strength of the unit.
+    #[prost(int32, tag = "5")]
+    pub attack: i32,
+    /// Defense strength of the unit.
+    #[prost(int32, tag = "6")]
+    pub defense: i32,
+    /// GML ID of the building the unit is located at, or departed from while moving.
+    #[prost(string, tag = "7")]
+    pub building_gml_id: ::prost::alloc::string::String,
+    /// GML ID of the building the unit is moving to (optional).
+    #[prost(message, optional, tag = "8")]
+    pub destination_building_gml_id: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+    /// Tick at which the unit arrives at its destination (optional).
+    #[prost(message, optional, tag = "9")]
+    pub arrival_tick: ::core::option::Option<i64>,
+    /// GML ID of the building the unit has been committed to attack (optional).
+    #[prost(message, optional, tag = "10")]
+    pub attack_target_building_gml_id: ::core::option::Option<
+        ::prost::alloc::string::String,
+    >,
+}
+/// State of a battle after a round of combat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BattleResult {
+    Unspecified = 0,
+    /// Both sides have units left, the fight continues next tick.
+    Ongoing = 1,
+    /// All defenders have been destroyed.
+    AttackersWon = 2,
+    /// All attackers have been destroyed.
+    DefendersWon = 3,
+}
+impl BattleResult {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "BATTLE_RESULT_UNSPECIFIED",
+            Self::Ongoing => "BATTLE_RESULT_ONGOING",
+            Self::AttackersWon => "BATTLE_RESULT_ATTACKERS_WON",
+            Self::DefendersWon => "BATTLE_RESULT_DEFENDERS_WON",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BATTLE_RESULT_UNSPECIFIED" => Some(Self::Unspecified),
+            "BATTLE_RESULT_ONGOING" => Some(Self::Ongoing),
+            "BATTLE_RESULT_ATTACKERS_WON" => Some(Self::AttackersWon),
+            "BATTLE_RESULT_DEFENDERS_WON" => Some(Self::DefendersWon),
+            _ => None,
+        }
+    }
+}
+/// The available types of units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UnitType {
+    Unspecified = 0,
+    /// Balanced infantry unit.
+    Enforcer = 1,
+    /// Fast but fragile reconnaissance unit.
+    Drone = 2,
+    /// Slow and expensive heavy combat unit.
+    Mech = 3,
+}
+impl UnitType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "UNIT_TYPE_UNSPECIFIED",
+            Self::Enforcer => "UNIT_TYPE_ENFORCER",
+            Self::Drone => "UNIT_TYPE_DRONE",
+            Self::Mech => "UNIT_TYPE_MECH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNIT_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "UNIT_TYPE_ENFORCER" => Some(Self::Enforcer),
+            "UNIT_TYPE_DRONE" => Some(Self::Drone),
+            "UNIT_TYPE_MECH" => Some(Self::Mech),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,9 @@
+-- Tick after which an open business offer expires and its escrow is refunded
+ALTER TABLE business_offers ADD COLUMN IF NOT EXISTS expires_at_tick BIGINT;
+
+-- Offers made before expiry existed get the default expiry (SERVER_BUSINESS_OFFER_EXPIRY_TICKS) from now on
+UPDATE business_offers
+SET expires_at_tick = (SELECT current_game_tick FROM current_game_tick) + 200
+WHERE expires_at_tick IS NULL;
+
+ALTER TABLE business_offers ALTER COLUMN expires_at_tick SET NOT NULL;
@@ -0,0 +1,30 @@
+-- Unit stats and positioning on the building map
+ALTER TABLE units
+    ADD COLUMN IF NOT EXISTS unit_type SMALLINT NOT NULL DEFAULT 1,
+    ADD COLUMN IF NOT EXISTS health INTEGER NOT NULL DEFAULT 100,
+    ADD COLUMN IF NOT EXISTS attack INTEGER NOT NULL DEFAULT 12,
+    ADD COLUMN IF NOT EXISTS defense INTEGER NOT NULL DEFAULT 10,
+    ADD COLUMN IF NOT EXISTS building_uuid UUID,
+    ADD COLUMN IF NOT EXISTS destination_building_uuid UUID,
+    ADD COLUMN IF NOT EXISTS arrival_tick BIGINT;
+
+-- Station already existing units at the headquarter of one of their corporation's businesses
+UPDATE units u
+SET building_uuid = (
+    SELECT b.headquarter_building_uuid
+    FROM businesses b
+    WHERE
+        b.owning_corporation_uuid = u.corporation_uuid
+        AND b.game_tick = u.game_tick
+    ORDER BY b.uuid
+    LIMIT 1
+)
+WHERE u.building_uuid IS NULL;
+
+-- Units of corporations without any business have nowhere to be stationed
+DELETE FROM units WHERE building_uuid IS NULL;
+
+ALTER TABLE units ALTER COLUMN building_uuid SET NOT NULL;
+
+-- Index to potentially find all units stationed at a building at a specific tick
+CREATE INDEX IF NOT EXISTS idx_units_game_tick_building ON units (game_tick, building_uuid);
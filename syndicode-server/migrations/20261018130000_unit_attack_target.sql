@@ -0,0 +1,2 @@
+-- Building a unit has been committed to attack
+ALTER TABLE units ADD COLUMN IF NOT EXISTS attack_target_building_uuid UUID;
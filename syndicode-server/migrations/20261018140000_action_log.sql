@@ -0,0 +1,10 @@
+-- Append-only log of the actions pulled for every tick, used to replay ticks
+CREATE TABLE IF NOT EXISTS action_log (
+    game_tick BIGINT PRIMARY KEY,
+    seed BIGINT NOT NULL,
+    actions BYTEA NOT NULL,
+    state_checksum BIGINT NOT NULL,
+    outcomes_checksum BIGINT NOT NULL,
+    checksum_version TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,44 @@
+-- State tables store row versions instead of a full copy of every row per tick.
+-- A version becomes visible at `game_tick` and stays visible until `superseded_at_tick` (exclusive).
+-- The current version of a row has no `superseded_at_tick`.
+ALTER TABLE corporations ADD COLUMN IF NOT EXISTS superseded_at_tick BIGINT;
+ALTER TABLE markets ADD COLUMN IF NOT EXISTS superseded_at_tick BIGINT;
+ALTER TABLE businesses ADD COLUMN IF NOT EXISTS superseded_at_tick BIGINT;
+ALTER TABLE business_listings ADD COLUMN IF NOT EXISTS superseded_at_tick BIGINT;
+ALTER TABLE business_offers ADD COLUMN IF NOT EXISTS superseded_at_tick BIGINT;
+ALTER TABLE building_ownerships ADD COLUMN IF NOT EXISTS superseded_at_tick BIGINT;
+ALTER TABLE units ADD COLUMN IF NOT EXISTS superseded_at_tick BIGINT;
+
+-- Existing rows are full copies per tick, so every row older than the latest tick was only visible in its own tick
+UPDATE corporations SET superseded_at_tick = game_tick + 1
+WHERE game_tick < (SELECT MAX(game_tick) FROM corporations);
+UPDATE markets SET superseded_at_tick = game_tick + 1
+WHERE game_tick < (SELECT MAX(game_tick) FROM markets);
+UPDATE businesses SET superseded_at_tick = game_tick + 1
+WHERE game_tick < (SELECT MAX(game_tick) FROM businesses);
+UPDATE business_listings SET superseded_at_tick = game_tick + 1
+WHERE game_tick < (SELECT MAX(game_tick) FROM business_listings);
+UPDATE business_offers SET superseded_at_tick = game_tick + 1
+WHERE game_tick < (SELECT MAX(game_tick) FROM business_offers);
+UPDATE building_ownerships SET superseded_at_tick = game_tick + 1
+WHERE game_tick < (SELECT MAX(game_tick) FROM building_ownerships);
+UPDATE units SET superseded_at_tick = game_tick + 1
+WHERE game_tick < (SELECT MAX(game_tick) FROM units);
+
+-- Indexes to find the current version of a row when it gets superseded
+CREATE INDEX IF NOT EXISTS idx_corporations_current ON corporations (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_markets_current ON markets (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_businesses_current ON businesses (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_business_listings_current ON business_listings (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_business_offers_current ON business_offers (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_building_ownerships_current ON building_ownerships (building_uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_units_current ON units (uuid) WHERE superseded_at_tick IS NULL;
+
+-- Indexes to prune versions that are no longer visible
+CREATE INDEX IF NOT EXISTS idx_corporations_superseded ON corporations (superseded_at_tick);
+CREATE INDEX IF NOT EXISTS idx_markets_superseded ON markets (superseded_at_tick);
+CREATE INDEX IF NOT EXISTS idx_businesses_superseded ON businesses (superseded_at_tick);
+CREATE INDEX IF NOT EXISTS idx_business_listings_superseded ON business_listings (superseded_at_tick);
+CREATE INDEX IF NOT EXISTS idx_business_offers_superseded ON business_offers (superseded_at_tick);
+CREATE INDEX IF NOT EXISTS idx_building_ownerships_superseded ON building_ownerships (superseded_at_tick);
+CREATE INDEX IF NOT EXISTS idx_units_superseded ON units (superseded_at_tick);
@@ -0,0 +1,13 @@
+-- Append-only history of the cash movements of every corporation
+CREATE TABLE IF NOT EXISTS corporation_ledger_entries (
+    game_tick BIGINT NOT NULL,
+    sequence INTEGER NOT NULL,
+    corporation_uuid UUID NOT NULL,
+    kind SMALLINT NOT NULL,
+    amount BIGINT NOT NULL,
+    business_uuid UUID,
+    PRIMARY KEY (game_tick, sequence)
+);
+
+CREATE INDEX IF NOT EXISTS idx_corporation_ledger_entries_corporation
+    ON corporation_ledger_entries (corporation_uuid, game_tick DESC, sequence DESC);
@@ -0,0 +1,13 @@
+-- Append-only history of every market at the end of each tick
+CREATE TABLE IF NOT EXISTS market_snapshots (
+    game_tick BIGINT NOT NULL,
+    market_uuid UUID NOT NULL,
+    market_name SMALLINT NOT NULL,
+    volume BIGINT NOT NULL,
+    invested_operational_expenses BIGINT NOT NULL,
+    active_businesses BIGINT NOT NULL,
+    PRIMARY KEY (game_tick, market_uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_market_snapshots_market
+    ON market_snapshots (market_uuid, game_tick DESC);
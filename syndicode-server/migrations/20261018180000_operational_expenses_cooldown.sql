@@ -0,0 +1,2 @@
+-- Tick of the last change of the operational expenses, used for the cooldown between changes
+ALTER TABLE businesses ADD COLUMN IF NOT EXISTS operational_expenses_changed_at_tick BIGINT;
@@ -0,0 +1,2 @@
+-- Tick since which the cash balance of a corporation has been at or below zero
+ALTER TABLE corporations ADD COLUMN IF NOT EXISTS insolvent_since_tick BIGINT;
@@ -0,0 +1,18 @@
+-- Loans granted by the NPC bank, versioned like the other state tables
+CREATE TABLE IF NOT EXISTS loans (
+    game_tick BIGINT NOT NULL,
+    uuid UUID NOT NULL,
+    corporation_uuid UUID NOT NULL,
+    principal BIGINT NOT NULL,
+    outstanding_balance BIGINT NOT NULL,
+    interest_rate_bps BIGINT NOT NULL,
+    taken_at_tick BIGINT NOT NULL,
+    due_at_tick BIGINT NOT NULL,
+    superseded_at_tick BIGINT,
+
+    PRIMARY KEY (game_tick, uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_loans_corporation_uuid ON loans (corporation_uuid, game_tick);
+CREATE INDEX IF NOT EXISTS idx_loans_current ON loans (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_loans_superseded ON loans (superseded_at_tick);
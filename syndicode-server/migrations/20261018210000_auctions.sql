@@ -0,0 +1,20 @@
+-- Listings with a closing tick are sealed-bid auctions, the asking price is the reserve price
+ALTER TABLE business_listings ADD COLUMN IF NOT EXISTS auction_closes_at_tick BIGINT;
+
+-- Sealed bids on auction listings, versioned like the other state tables
+CREATE TABLE IF NOT EXISTS auction_bids (
+    game_tick BIGINT NOT NULL,
+    uuid UUID NOT NULL,
+    business_listing_uuid UUID NOT NULL,
+    business_uuid UUID NOT NULL,
+    bidding_corporation_uuid UUID NOT NULL,
+    amount BIGINT NOT NULL,
+    placed_at_tick BIGINT NOT NULL,
+    superseded_at_tick BIGINT,
+
+    PRIMARY KEY (game_tick, uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_auction_bids_business_listing_uuid ON auction_bids (business_listing_uuid, game_tick);
+CREATE INDEX IF NOT EXISTS idx_auction_bids_current ON auction_bids (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_auction_bids_superseded ON auction_bids (superseded_at_tick);
@@ -0,0 +1,13 @@
+-- Wards the corporations compete for, derived from the buildings of each city code
+CREATE TABLE IF NOT EXISTS wards (
+    city_code TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    boundary GEOMETRY(Polygon, 4326) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_buildings_city_code ON buildings (city_code);
+
+-- The wards are seeded from the buildings once the database has been initialized
+INSERT INTO system_flags (flag_key, is_set)
+VALUES ('territory_domain_initialized', FALSE)
+ON CONFLICT (flag_key) DO NOTHING;
@@ -0,0 +1,15 @@
+-- Moles planted by corporations in their rivals, versioned like the other state tables
+CREATE TABLE IF NOT EXISTS moles (
+    game_tick BIGINT NOT NULL,
+    uuid UUID NOT NULL,
+    spy_corporation_uuid UUID NOT NULL,
+    target_corporation_uuid UUID NOT NULL,
+    planted_at_tick BIGINT NOT NULL,
+    superseded_at_tick BIGINT,
+
+    PRIMARY KEY (game_tick, uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_moles_target_corporation_uuid ON moles (target_corporation_uuid, game_tick);
+CREATE INDEX IF NOT EXISTS idx_moles_current ON moles (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_moles_superseded ON moles (superseded_at_tick);
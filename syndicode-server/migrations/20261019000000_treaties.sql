@@ -0,0 +1,18 @@
+-- Treaties proposed and concluded between corporations, versioned like the other state tables
+CREATE TABLE IF NOT EXISTS treaties (
+    game_tick BIGINT NOT NULL,
+    uuid UUID NOT NULL,
+    treaty_type SMALLINT NOT NULL,
+    proposer_corporation_uuid UUID NOT NULL,
+    partner_corporation_uuid UUID NOT NULL,
+    proposed_at_tick BIGINT NOT NULL,
+    accepted_at_tick BIGINT,
+    superseded_at_tick BIGINT,
+
+    PRIMARY KEY (game_tick, uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_treaties_proposer_corporation_uuid ON treaties (proposer_corporation_uuid, game_tick);
+CREATE INDEX IF NOT EXISTS idx_treaties_partner_corporation_uuid ON treaties (partner_corporation_uuid, game_tick);
+CREATE INDEX IF NOT EXISTS idx_treaties_current ON treaties (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_treaties_superseded ON treaties (superseded_at_tick);
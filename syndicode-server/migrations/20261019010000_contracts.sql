@@ -0,0 +1,20 @@
+-- Cash held in escrow by one corporation to pay another one at a later tick, versioned like the other state tables
+CREATE TABLE IF NOT EXISTS contracts (
+    game_tick BIGINT NOT NULL,
+    uuid UUID NOT NULL,
+    payer_corporation_uuid UUID NOT NULL,
+    payee_corporation_uuid UUID NOT NULL,
+    amount BIGINT NOT NULL,
+    condition_kind SMALLINT NOT NULL,
+    condition_business_uuid UUID,
+    created_at_tick BIGINT NOT NULL,
+    due_at_tick BIGINT NOT NULL,
+    superseded_at_tick BIGINT,
+
+    PRIMARY KEY (game_tick, uuid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contracts_payer_corporation_uuid ON contracts (payer_corporation_uuid, game_tick);
+CREATE INDEX IF NOT EXISTS idx_contracts_payee_corporation_uuid ON contracts (payee_corporation_uuid, game_tick);
+CREATE INDEX IF NOT EXISTS idx_contracts_current ON contracts (uuid) WHERE superseded_at_tick IS NULL;
+CREATE INDEX IF NOT EXISTS idx_contracts_superseded ON contracts (superseded_at_tick);
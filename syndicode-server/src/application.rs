@@ -1,8 +1,10 @@
 pub mod action;
 pub mod admin;
 pub mod auth;
+pub mod diplomacy;
 pub mod economy;
 pub mod error;
+pub mod espionage;
 pub mod game;
 pub mod init;
 pub mod leader;
@@ -4,22 +4,50 @@ use bon::Builder;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::economy::corporation::model::name::CorporationName;
+use crate::domain::{
+    diplomacy::model::treaty_type::TreatyType,
+    economy::{
+        contract::model::condition::ContractCondition, corporation::model::name::CorporationName,
+    },
+    espionage::model::intel::IntelType,
+    unit::model::unit_type::UnitType,
+};
 
-#[derive(Builder, serde::Serialize, serde::Deserialize)]
+#[derive(Builder, Clone, serde::Serialize, serde::Deserialize)]
 pub struct QueuedAction {
     pub id: String,
     pub payload: QueuedActionPayload,
 }
 
-#[derive(Builder, serde::Serialize, serde::Deserialize)]
+/// The actions pulled for a single tick together with everything needed to replay it.
+#[derive(Builder, Clone, Serialize, Deserialize)]
+pub struct ActionLogEntry {
+    pub game_tick: i64,
+    pub seed: u64,
+    /// The actions in the order they were pulled from the queue.
+    pub actions: Vec<QueuedAction>,
+    pub state_checksum: u64,
+    pub outcomes_checksum: u64,
+    /// The build that calculated the checksums, see [`ActionLogEntry::current_checksum_version`].
+    pub checksum_version: String,
+}
+
+impl ActionLogEntry {
+    /// Identifies the builds whose checksums are comparable, by the server version and the
+    /// target architecture. The version has to be bumped whenever the hashed structs change.
+    pub fn current_checksum_version() -> String {
+        format!("{}-{}", env!("CARGO_PKG_VERSION"), std::env::consts::ARCH)
+    }
+}
+
+#[derive(Builder, Clone, serde::Serialize, serde::Deserialize)]
 pub struct QueuedActionPayload {
     pub request_uuid: Uuid,
     pub req_user_uuid: Uuid,
     pub details: ActionDetails,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum ActionDetails {
     CreateCorporation {
         user_uuid: Uuid,
@@ -28,19 +56,141 @@ pub enum ActionDetails {
     DeleteCorporation {
         corporation_uuid: Uuid,
     },
-    SpawnUnit,
+    SpawnUnit {
+        unit_type: UnitType,
+        building_gml_id: String,
+    },
+    MoveUnit {
+        unit_uuid: Uuid,
+        destination_building_gml_id: String,
+    },
+    AttackBuilding {
+        building_gml_id: String,
+        unit_uuids: Vec<Uuid>,
+    },
     AcquireListedBusiness {
         business_listing_uuid: Uuid,
     },
+    ListBusinessForSale {
+        business_uuid: Uuid,
+        asking_price: i64,
+        auction_duration_ticks: Option<i64>,
+    },
+    CancelBusinessListing {
+        business_listing_uuid: Uuid,
+    },
+    MakeBusinessOffer {
+        business_uuid: Uuid,
+        offer_price: i64,
+    },
+    AcceptBusinessOffer {
+        business_offer_uuid: Uuid,
+    },
+    RejectBusinessOffer {
+        business_offer_uuid: Uuid,
+    },
+    WithdrawBusinessOffer {
+        business_offer_uuid: Uuid,
+    },
+    SetOperationalExpenses {
+        business_uuid: Uuid,
+        operational_expenses: i64,
+    },
+    TakeLoan {
+        amount: i64,
+    },
+    RepayLoan {
+        loan_uuid: Uuid,
+        amount: i64,
+    },
+    PlaceAuctionBid {
+        business_listing_uuid: Uuid,
+        amount: i64,
+    },
+    AcquireBuilding {
+        business_uuid: Uuid,
+        building_gml_id: String,
+    },
+    GatherIntel {
+        target_corporation_uuid: Uuid,
+        intel_type: IntelType,
+    },
+    PlantMole {
+        target_corporation_uuid: Uuid,
+    },
+    CounterIntel,
+    ProposeTreaty {
+        partner_corporation_uuid: Uuid,
+        treaty_type: TreatyType,
+    },
+    AcceptTreaty {
+        treaty_uuid: Uuid,
+    },
+    BreakTreaty {
+        treaty_uuid: Uuid,
+    },
+    TransferFunds {
+        recipient_corporation_uuid: Uuid,
+        amount: i64,
+    },
+    CreateContract {
+        payee_corporation_uuid: Uuid,
+        amount: i64,
+        due_at_tick: i64,
+        condition: ContractCondition,
+    },
 }
 
 impl ActionDetails {
+    /// GML ID of the building the action refers to, if any.
+    pub fn building_gml_id(&self) -> Option<&str> {
+        match self {
+            ActionDetails::SpawnUnit {
+                building_gml_id, ..
+            }
+            | ActionDetails::AttackBuilding {
+                building_gml_id, ..
+            }
+            | ActionDetails::AcquireBuilding {
+                building_gml_id, ..
+            } => Some(building_gml_id),
+            ActionDetails::MoveUnit {
+                destination_building_gml_id,
+                ..
+            } => Some(destination_building_gml_id),
+            _ => None,
+        }
+    }
+
     pub fn get_order(&self) -> u16 {
         match self {
             ActionDetails::CreateCorporation { .. } => 1,
-            ActionDetails::SpawnUnit => 2,
-            ActionDetails::AcquireListedBusiness { .. } => 3,
-            ActionDetails::DeleteCorporation { .. } => 4,
+            // Treaties change before the units move, so a broken pact allows attacks in the same tick
+            ActionDetails::BreakTreaty { .. } => 2,
+            ActionDetails::ProposeTreaty { .. } => 3,
+            ActionDetails::AcceptTreaty { .. } => 4,
+            ActionDetails::SpawnUnit { .. } => 5,
+            ActionDetails::MoveUnit { .. } => 6,
+            ActionDetails::AttackBuilding { .. } => 7,
+            ActionDetails::CancelBusinessListing { .. } => 8,
+            ActionDetails::WithdrawBusinessOffer { .. } => 9,
+            ActionDetails::RejectBusinessOffer { .. } => 10,
+            ActionDetails::TakeLoan { .. } => 11,
+            ActionDetails::TransferFunds { .. } => 12,
+            // Both can take over the same business, so the contest lottery decides between them
+            ActionDetails::AcquireListedBusiness { .. }
+            | ActionDetails::AcceptBusinessOffer { .. } => 13,
+            ActionDetails::AcquireBuilding { .. } => 14,
+            ActionDetails::SetOperationalExpenses { .. } => 15,
+            ActionDetails::RepayLoan { .. } => 16,
+            ActionDetails::CreateContract { .. } => 17,
+            ActionDetails::ListBusinessForSale { .. } => 18,
+            ActionDetails::MakeBusinessOffer { .. } => 19,
+            ActionDetails::PlaceAuctionBid { .. } => 20,
+            ActionDetails::CounterIntel => 21,
+            ActionDetails::PlantMole { .. } => 22,
+            ActionDetails::GatherIntel { .. } => 23,
+            ActionDetails::DeleteCorporation { .. } => 24,
         }
     }
 }
@@ -50,8 +200,29 @@ impl Display for ActionDetails {
         match self {
             ActionDetails::CreateCorporation { .. } => write!(f, "CreateCorporation"),
             ActionDetails::DeleteCorporation { .. } => write!(f, "DeleteCorporation"),
-            ActionDetails::SpawnUnit => write!(f, "SpawnUnit"),
+            ActionDetails::SpawnUnit { .. } => write!(f, "SpawnUnit"),
+            ActionDetails::MoveUnit { .. } => write!(f, "MoveUnit"),
+            ActionDetails::AttackBuilding { .. } => write!(f, "AttackBuilding"),
             ActionDetails::AcquireListedBusiness { .. } => write!(f, "AcquireListedBusiness"),
+            ActionDetails::ListBusinessForSale { .. } => write!(f, "ListBusinessForSale"),
+            ActionDetails::CancelBusinessListing { .. } => write!(f, "CancelBusinessListing"),
+            ActionDetails::MakeBusinessOffer { .. } => write!(f, "MakeBusinessOffer"),
+            ActionDetails::AcceptBusinessOffer { .. } => write!(f, "AcceptBusinessOffer"),
+            ActionDetails::RejectBusinessOffer { .. } => write!(f, "RejectBusinessOffer"),
+            ActionDetails::WithdrawBusinessOffer { .. } => write!(f, "WithdrawBusinessOffer"),
+            ActionDetails::SetOperationalExpenses { .. } => write!(f, "SetOperationalExpenses"),
+            ActionDetails::TakeLoan { .. } => write!(f, "TakeLoan"),
+            ActionDetails::RepayLoan { .. } => write!(f, "RepayLoan"),
+            ActionDetails::PlaceAuctionBid { .. } => write!(f, "PlaceAuctionBid"),
+            ActionDetails::AcquireBuilding { .. } => write!(f, "AcquireBuilding"),
+            ActionDetails::GatherIntel { .. } => write!(f, "GatherIntel"),
+            ActionDetails::PlantMole { .. } => write!(f, "PlantMole"),
+            ActionDetails::CounterIntel => write!(f, "CounterIntel"),
+            ActionDetails::ProposeTreaty { .. } => write!(f, "ProposeTreaty"),
+            ActionDetails::AcceptTreaty { .. } => write!(f, "AcceptTreaty"),
+            ActionDetails::BreakTreaty { .. } => write!(f, "BreakTreaty"),
+            ActionDetails::TransferFunds { .. } => write!(f, "TransferFunds"),
+            ActionDetails::CreateContract { .. } => write!(f, "CreateContract"),
         }
     }
 }
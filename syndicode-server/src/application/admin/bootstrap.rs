@@ -86,7 +86,8 @@ where
 
                     let corporation_name = CorporationName::new(corporation_name)?;
 
-                    let corporation = Corporation::new(user_to_create.uuid, corporation_name);
+                    let corporation =
+                        Corporation::new(Uuid::now_v7(), user_to_create.uuid, corporation_name);
 
                     ctx.create_corporation(&corporation)
                         .await
@@ -54,7 +54,7 @@ where
         // Check if the corporation exists and get the id
         let get_corp_outcome = self
             .corporation_repo
-            .get_corporation_by_user(user_uuid)
+            .get_corporation_by_user(user_uuid, None)
             .await
             .map_err(|err| match err {
                 RepositoryError::NotFound => ApplicationError::CorporationForUserNotFound,
@@ -0,0 +1,4 @@
+pub mod accept_treaty;
+pub mod break_treaty;
+pub mod list_treaties;
+pub mod propose_treaty;
@@ -0,0 +1,23 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::diplomacy::{model::Treaty, repository::TreatyRepository},
+};
+use bon::Builder;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct ListTreatiesUseCase<TRT>
+where
+    TRT: TreatyRepository,
+{
+    treaty_repo: Arc<TRT>,
+}
+
+impl<TRT> ListTreatiesUseCase<TRT>
+where
+    TRT: TreatyRepository,
+{
+    pub async fn execute(&self, game_tick: i64) -> ApplicationResult<Vec<Treaty>> {
+        Ok(self.treaty_repo.list_treaties_in_tick(game_tick).await?)
+    }
+}
@@ -1,12 +1,34 @@
+pub mod accept_business_offer;
+pub mod acquire_building;
 pub mod acquire_listed_business;
 pub mod bootstrap;
+pub mod cancel_business_listing;
+pub mod create_contract;
+pub mod get_building_locations;
 pub mod get_corporation;
+pub mod get_corporation_ledger;
+pub mod list_auction_bids;
+pub mod list_building_locations;
 pub mod list_building_ownerships;
+pub mod list_business_for_sale;
 pub mod list_business_listings;
 pub mod list_business_offers;
 pub mod list_businesses;
+pub mod list_contracts;
 pub mod list_corporations;
+pub mod list_loans;
 pub mod list_markets;
+pub mod make_business_offer;
+pub mod place_auction_bid;
 pub mod query_buildings;
 pub mod query_business_listings;
 pub mod query_businesses;
+pub mod query_markets;
+pub mod query_territories;
+pub mod reject_business_offer;
+pub mod repay_loan;
+pub mod seed_wards;
+pub mod set_operational_expenses;
+pub mod take_loan;
+pub mod transfer_funds;
+pub mod withdraw_business_offer;
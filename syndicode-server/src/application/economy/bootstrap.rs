@@ -113,7 +113,7 @@ where
             let market = Market::builder()
                 .uuid(Uuid::now_v7())
                 .name(name)
-                .volume(1000)
+                .volume(self.config.market.base_volume)
                 .build();
 
             markets.push(market);
@@ -0,0 +1,30 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::economy::building::{model::BuildingLocation, repository::BuildingRepository},
+};
+use bon::Builder;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct GetBuildingLocationsUseCase<BUI>
+where
+    BUI: BuildingRepository,
+{
+    building_repo: Arc<BUI>,
+}
+
+impl<BUI> GetBuildingLocationsUseCase<BUI>
+where
+    BUI: BuildingRepository,
+{
+    pub async fn execute(&self, gml_ids: Vec<String>) -> ApplicationResult<Vec<BuildingLocation>> {
+        if gml_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .building_repo
+            .list_building_locations_by_gml_ids(gml_ids)
+            .await?)
+    }
+}
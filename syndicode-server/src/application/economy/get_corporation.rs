@@ -18,10 +18,14 @@ impl<CRP> GetCorporationUseCase<CRP>
 where
     CRP: CorporationRepository,
 {
-    pub async fn execute(&self, user_uuid: Uuid) -> ApplicationResult<GetCorporationOutcome> {
+    pub async fn execute(
+        &self,
+        user_uuid: Uuid,
+        at_game_tick: Option<i64>,
+    ) -> ApplicationResult<GetCorporationOutcome> {
         Ok(self
             .corporation_repo
-            .get_corporation_by_user(user_uuid)
+            .get_corporation_by_user(user_uuid, at_game_tick)
             .await?)
     }
 }
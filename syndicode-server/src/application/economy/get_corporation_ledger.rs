@@ -0,0 +1,58 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::economy::{
+        corporation::repository::CorporationRepository,
+        ledger::{
+            model::LedgerEntry,
+            repository::{LedgerRepository, QueryLedgerEntriesRequest},
+        },
+    },
+};
+use bon::{bon, Builder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Builder)]
+pub struct GetCorporationLedgerUseCase<CRP, LED>
+where
+    CRP: CorporationRepository,
+    LED: LedgerRepository,
+{
+    corporation_repo: Arc<CRP>,
+    ledger_repo: Arc<LED>,
+}
+
+#[bon]
+impl<CRP, LED> GetCorporationLedgerUseCase<CRP, LED>
+where
+    CRP: CorporationRepository,
+    LED: LedgerRepository,
+{
+    /// Lists the ledger entries of the user's current corporation along with the total number of matches.
+    #[builder]
+    pub async fn execute(
+        &self,
+        user_uuid: Uuid,
+        from_game_tick: Option<i64>,
+        to_game_tick: Option<i64>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> ApplicationResult<(i64, Vec<LedgerEntry>)> {
+        let corporation_uuid = self
+            .corporation_repo
+            .get_corporation_by_user(user_uuid, None)
+            .await?
+            .corporation
+            .uuid;
+
+        let req = QueryLedgerEntriesRequest::builder()
+            .corporation_uuid(corporation_uuid)
+            .maybe_from_game_tick(from_game_tick)
+            .maybe_to_game_tick(to_game_tick)
+            .maybe_limit(limit)
+            .maybe_offset(offset)
+            .build();
+
+        Ok(self.ledger_repo.query_ledger_entries(&req).await?)
+    }
+}
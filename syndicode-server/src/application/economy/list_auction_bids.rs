@@ -0,0 +1,26 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::economy::auction_bid::{model::AuctionBid, repository::AuctionBidRepository},
+};
+use bon::Builder;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct ListAuctionBidsUseCase<AB>
+where
+    AB: AuctionBidRepository,
+{
+    auction_bid_repo: Arc<AB>,
+}
+
+impl<AB> ListAuctionBidsUseCase<AB>
+where
+    AB: AuctionBidRepository,
+{
+    pub async fn execute(&self, game_tick: i64) -> ApplicationResult<Vec<AuctionBid>> {
+        Ok(self
+            .auction_bid_repo
+            .list_auction_bids_in_tick(game_tick)
+            .await?)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::economy::building::{model::BuildingLocation, repository::BuildingRepository},
+};
+use bon::Builder;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct ListBuildingLocationsUseCase<BUI>
+where
+    BUI: BuildingRepository,
+{
+    building_repo: Arc<BUI>,
+}
+
+impl<BUI> ListBuildingLocationsUseCase<BUI>
+where
+    BUI: BuildingRepository,
+{
+    pub async fn execute(&self, game_tick: i64) -> ApplicationResult<Vec<BuildingLocation>> {
+        Ok(self
+            .building_repo
+            .list_building_locations(game_tick)
+            .await?)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::economy::contract::{model::Contract, repository::ContractRepository},
+};
+use bon::Builder;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct ListContractsUseCase<CTR>
+where
+    CTR: ContractRepository,
+{
+    contract_repo: Arc<CTR>,
+}
+
+impl<CTR> ListContractsUseCase<CTR>
+where
+    CTR: ContractRepository,
+{
+    pub async fn execute(&self, game_tick: i64) -> ApplicationResult<Vec<Contract>> {
+        Ok(self.contract_repo.list_contracts_in_tick(game_tick).await?)
+    }
+}
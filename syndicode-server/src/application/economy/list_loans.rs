@@ -0,0 +1,23 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::economy::loan::{model::Loan, repository::LoanRepository},
+};
+use bon::Builder;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct ListLoansUseCase<LN>
+where
+    LN: LoanRepository,
+{
+    loan_repo: Arc<LN>,
+}
+
+impl<LN> ListLoansUseCase<LN>
+where
+    LN: LoanRepository,
+{
+    pub async fn execute(&self, game_tick: i64) -> ApplicationResult<Vec<Loan>> {
+        Ok(self.loan_repo.list_loans_in_tick(game_tick).await?)
+    }
+}
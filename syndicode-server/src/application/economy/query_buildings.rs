@@ -31,6 +31,8 @@ where
         min_lat: Option<f64>,
         max_lat: Option<f64>,
         limit: Option<i64>,
+        at_game_tick: Option<i64>,
+        is_owned: Option<bool>,
     ) -> ApplicationResult<(i64, Vec<BuildingDetails>)> {
         let req = QueryBuildingsRequest::builder()
             .maybe_owning_corporation_uuid(owning_corporation_uuid)
@@ -40,6 +42,8 @@ where
             .maybe_min_lat(min_lat)
             .maybe_max_lat(max_lat)
             .maybe_limit(limit)
+            .maybe_at_game_tick(at_game_tick)
+            .maybe_is_owned(is_owned)
             .build();
 
         Ok(self.building_repo.query_buildings(req).await?)
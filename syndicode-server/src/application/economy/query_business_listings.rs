@@ -34,10 +34,12 @@ where
         seller_corporation_uuid: Option<Uuid>,
         min_operational_expenses: Option<i64>,
         max_operational_expenses: Option<i64>,
+        is_auction: Option<bool>,
         sort_by: Option<DomainBusinessListingSortBy>,
         sort_direction: Option<DomainSortDirection>,
         limit: Option<i64>,
         offset: Option<i64>,
+        at_game_tick: Option<i64>,
     ) -> ApplicationResult<(i64, Vec<BusinessListingDetails>)> {
         let req = QueryBusinessListingsRequest::builder()
             .maybe_market_uuid(market_uuid)
@@ -46,10 +48,12 @@ where
             .maybe_seller_corporation_uuid(seller_corporation_uuid)
             .maybe_min_operational_expenses(min_operational_expenses)
             .maybe_max_operational_expenses(max_operational_expenses)
+            .maybe_is_auction(is_auction)
             .maybe_sort_by(sort_by)
             .maybe_sort_direction(sort_direction)
             .maybe_limit(limit)
             .maybe_offset(offset)
+            .maybe_at_game_tick(at_game_tick)
             .build();
 
         Ok(self
@@ -1,8 +1,10 @@
 use crate::{
     application::error::ApplicationResult,
+    config::ServerConfig,
     domain::{
         economy::business::repository::{
-            BusinessDetails, BusinessRepository, DomainBusinessSortBy, QueryBusinessesRequest,
+            BusinessDetails, BusinessRepository, DomainBusinessSortBy, QueryBusinessesOutcome,
+            QueryBusinessesRequest,
         },
         repository::DomainSortDirection,
     },
@@ -16,6 +18,7 @@ pub struct QueryBusinessesUseCase<B>
 where
     B: BusinessRepository,
 {
+    config: Arc<ServerConfig>,
     business_repo: Arc<B>,
 }
 
@@ -24,9 +27,11 @@ impl<B> QueryBusinessesUseCase<B>
 where
     B: BusinessRepository,
 {
+    /// Operational expenses of rivals out of sight of the user's corporation are rounded down.
     #[builder]
     pub async fn execute(
         &self,
+        req_user_uuid: Uuid,
         owning_corporation_uuid: Option<Uuid>,
         market_uuid: Option<Uuid>,
         min_operational_expenses: Option<i64>,
@@ -35,8 +40,12 @@ where
         sort_direction: Option<DomainSortDirection>,
         limit: Option<i64>,
         offset: Option<i64>,
+        at_game_tick: Option<i64>,
     ) -> ApplicationResult<(i64, Vec<BusinessDetails>)> {
+        let precision = self.config.fog_of_war.operational_expenses_precision;
+
         let req = QueryBusinessesRequest::builder()
+            .req_user_uuid(req_user_uuid)
             .maybe_owning_corporation_uuid(owning_corporation_uuid)
             .maybe_market_uuid(market_uuid)
             .maybe_min_operational_expenses(min_operational_expenses)
@@ -45,8 +54,20 @@ where
             .maybe_sort_direction(sort_direction)
             .maybe_limit(limit)
             .maybe_offset(offset)
+            .maybe_at_game_tick(at_game_tick)
+            .operational_expenses_precision(precision)
             .build();
 
-        Ok(self.business_repo.query_businesses(&req).await?)
+        let QueryBusinessesOutcome {
+            game_tick,
+            visibility,
+            mut businesses,
+        } = self.business_repo.query_businesses(&req).await?;
+
+        for business in businesses.iter_mut() {
+            business.redact_for(&visibility, precision);
+        }
+
+        Ok((game_tick, businesses))
     }
 }
@@ -0,0 +1,45 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::economy::market::{
+        model::MarketSnapshot,
+        repository::{MarketRepository, QueryMarketsRequest},
+    },
+};
+use bon::{bon, Builder};
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Builder)]
+pub struct QueryMarketsUseCase<MRK>
+where
+    MRK: MarketRepository,
+{
+    market_repo: Arc<MRK>,
+}
+
+#[bon]
+impl<MRK> QueryMarketsUseCase<MRK>
+where
+    MRK: MarketRepository,
+{
+    /// Lists the market snapshots along with the total number of matches.
+    #[builder]
+    pub async fn execute(
+        &self,
+        market_uuid: Option<Uuid>,
+        from_game_tick: Option<i64>,
+        to_game_tick: Option<i64>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> ApplicationResult<(i64, Vec<MarketSnapshot>)> {
+        let req = QueryMarketsRequest::builder()
+            .maybe_market_uuid(market_uuid)
+            .maybe_from_game_tick(from_game_tick)
+            .maybe_to_game_tick(to_game_tick)
+            .maybe_limit(limit)
+            .maybe_offset(offset)
+            .build();
+
+        Ok(self.market_repo.query_market_snapshots(&req).await?)
+    }
+}
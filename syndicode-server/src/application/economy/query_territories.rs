@@ -0,0 +1,87 @@
+use crate::{
+    application::error::ApplicationResult,
+    config::ServerConfig,
+    domain::{
+        economy::{
+            corporation::repository::CorporationRepository,
+            territory::{
+                model::{Territory, Ward},
+                repository::TerritoryRepository,
+            },
+        },
+        repository::RepositoryError,
+    },
+};
+use bon::{bon, Builder};
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+#[derive(Builder)]
+pub struct QueryTerritoriesUseCase<CRP, TER>
+where
+    CRP: CorporationRepository,
+    TER: TerritoryRepository,
+{
+    config: Arc<ServerConfig>,
+    corporation_repo: Arc<CRP>,
+    territory_repo: Arc<TER>,
+}
+
+#[bon]
+impl<CRP, TER> QueryTerritoriesUseCase<CRP, TER>
+where
+    CRP: CorporationRepository,
+    TER: TerritoryRepository,
+{
+    /// Lists every ward with its control at the given tick, as far as the user's corporation
+    /// has insight into it.
+    #[builder]
+    pub async fn execute(
+        &self,
+        req_user_uuid: Uuid,
+        at_game_tick: Option<i64>,
+    ) -> ApplicationResult<(i64, Vec<(Ward, Territory)>)> {
+        let (game_tick, ward_volumes) = self.territory_repo.list_ward_volumes(at_game_tick).await?;
+
+        let corporation_uuid = match self
+            .corporation_repo
+            .get_corporation_by_user(req_user_uuid, Some(game_tick))
+            .await
+        {
+            Ok(outcome) => Some(outcome.corporation.uuid),
+            // Users without a corporation only learn who controls the wards
+            Err(RepositoryError::NotFound) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut volumes_by_city_code: HashMap<String, Vec<(Uuid, i64)>> = HashMap::new();
+        for ward_volume in ward_volumes {
+            volumes_by_city_code
+                .entry(ward_volume.city_code)
+                .or_default()
+                .push((ward_volume.corporation_uuid, ward_volume.volume));
+        }
+
+        let territories = self
+            .territory_repo
+            .list_wards()
+            .await?
+            .into_iter()
+            .map(|ward| {
+                let volumes = volumes_by_city_code
+                    .remove(&ward.city_code)
+                    .unwrap_or_default();
+                let mut territory = Territory::from_volumes(
+                    ward.city_code.clone(),
+                    volumes,
+                    self.config.territory.control_threshold_bps,
+                );
+                territory.redact_for(corporation_uuid);
+
+                (ward, territory)
+            })
+            .collect();
+
+        Ok((game_tick, territories))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::application::{
+    error::ApplicationResult,
+    ports::{
+        init::{FlagKey, InitializationRepository},
+        uow::UnitOfWork,
+    },
+};
+use bon::Builder;
+use std::sync::Arc;
+
+/// Derives the wards from the imported buildings once they exist.
+/// This runs apart from the economy bootstrap, so economies bootstrapped or restored
+/// before wards existed get them as well.
+#[derive(Builder)]
+pub struct SeedWardsUseCase<UOW, INI>
+where
+    UOW: UnitOfWork,
+    INI: InitializationRepository,
+{
+    uow: Arc<UOW>,
+    init_repo: Arc<INI>,
+}
+
+impl<UOW, INI> SeedWardsUseCase<UOW, INI>
+where
+    UOW: UnitOfWork,
+    INI: InitializationRepository,
+{
+    pub async fn execute(&self) -> ApplicationResult<()> {
+        if self.init_repo.is_flag_set(FlagKey::TerritoryDomain).await? {
+            tracing::info!("Territory Domain initialization flag is already set. Skipping.");
+
+            return Ok(());
+        }
+
+        self.uow
+            .execute(|ctx| {
+                Box::pin(async move {
+                    if ctx.is_flag_set(FlagKey::TerritoryDomain).await? {
+                        return Ok(());
+                    }
+
+                    tracing::info!("Deriving wards from buildings...");
+                    ctx.insert_wards_from_buildings().await?;
+
+                    ctx.set_flag(FlagKey::TerritoryDomain).await?;
+
+                    Ok(())
+                })
+            })
+            .await?;
+
+        tracing::info!("Territory Domain initialization complete and flag set.");
+
+        Ok(())
+    }
+}
@@ -20,6 +20,9 @@ pub enum ApplicationError {
     #[error("Failed to retrieve the corporation of the provided user")]
     CorporationForUserNotFound,
 
+    #[error("The state of game tick {0} is not retained")]
+    GameTickNotRetained(i64),
+
     #[error("The provided corporation name can't be longer than {0} characters")]
     CorporationNameTooLong(usize),
 
@@ -89,6 +92,9 @@ impl From<RepositoryError> for ApplicationError {
         match value {
             RepositoryError::UserNameAlreadyTaken => ApplicationError::UserNameAlreadyTaken,
             RepositoryError::EmailInUse => ApplicationError::EmailInUse,
+            RepositoryError::GameTickNotRetained(game_tick) => {
+                ApplicationError::GameTickNotRetained(game_tick)
+            }
             _ => ApplicationError::from(value),
         }
     }
@@ -0,0 +1,4 @@
+pub mod counter_intel;
+pub mod gather_intel;
+pub mod list_moles;
+pub mod plant_mole;
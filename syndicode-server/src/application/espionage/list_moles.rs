@@ -0,0 +1,23 @@
+use crate::{
+    application::error::ApplicationResult,
+    domain::espionage::{model::Mole, repository::MoleRepository},
+};
+use bon::Builder;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct ListMolesUseCase<MOL>
+where
+    MOL: MoleRepository,
+{
+    mole_repo: Arc<MOL>,
+}
+
+impl<MOL> ListMolesUseCase<MOL>
+where
+    MOL: MoleRepository,
+{
+    pub async fn execute(&self, game_tick: i64) -> ApplicationResult<Vec<Mole>> {
+        Ok(self.mole_repo.list_moles_in_tick(game_tick).await?)
+    }
+}
@@ -1 +1,3 @@
 pub mod get_game_tick;
+pub mod load_game_state;
+pub mod replay_ticks;
@@ -0,0 +1,147 @@
+use crate::{
+    application::{
+        action::QueuedAction,
+        diplomacy::list_treaties::ListTreatiesUseCase,
+        economy::{
+            get_building_locations::GetBuildingLocationsUseCase,
+            list_auction_bids::ListAuctionBidsUseCase,
+            list_building_locations::ListBuildingLocationsUseCase,
+            list_building_ownerships::ListBuildingOwnershipsUseCase,
+            list_business_listings::ListBusinessListingUseCase,
+            list_business_offers::ListBusinessOffersUseCase,
+            list_businesses::ListBusinessesUseCase, list_contracts::ListContractsUseCase,
+            list_corporations::ListCorporationsUseCase, list_loans::ListLoansUseCase,
+            list_markets::ListMarketsUseCase,
+        },
+        error::ApplicationResult,
+        espionage::list_moles::ListMolesUseCase,
+        warfare::list_units::ListUnitsUseCase,
+    },
+    domain::{
+        diplomacy::repository::TreatyRepository,
+        economy::{
+            auction_bid::repository::AuctionBidRepository,
+            building::repository::BuildingRepository,
+            building_ownership::repository::BuildingOwnershipRepository,
+            business::repository::BusinessRepository,
+            business_listing::repository::BusinessListingRepository,
+            business_offer::repository::BusinessOfferRepository,
+            contract::repository::ContractRepository,
+            corporation::repository::CorporationRepository, loan::repository::LoanRepository,
+            market::repository::MarketRepository,
+        },
+        espionage::repository::MoleRepository,
+        simulation::game_state::GameState,
+        unit::repository::UnitRepository,
+    },
+};
+use bon::Builder;
+use std::sync::Arc;
+
+/// Loads the complete game state persisted for a tick.
+#[derive(Builder)]
+pub struct LoadGameStateUseCase<UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+where
+    UNT: UnitRepository,
+    CRP: CorporationRepository,
+    MRK: MarketRepository,
+    BSN: BusinessRepository,
+    BL: BusinessListingRepository,
+    BO: BusinessOfferRepository,
+    LN: LoanRepository,
+    AB: AuctionBidRepository,
+    MOL: MoleRepository,
+    TRT: TreatyRepository,
+    CTR: ContractRepository,
+    BLO: BuildingOwnershipRepository,
+    BUI: BuildingRepository,
+{
+    list_units_uc: Arc<ListUnitsUseCase<UNT>>,
+    list_corporations_uc: Arc<ListCorporationsUseCase<CRP>>,
+    list_markets_uc: Arc<ListMarketsUseCase<MRK>>,
+    list_businesses_uc: Arc<ListBusinessesUseCase<BSN>>,
+    list_business_listings_uc: Arc<ListBusinessListingUseCase<BL>>,
+    list_business_offers_uc: Arc<ListBusinessOffersUseCase<BO>>,
+    list_loans_uc: Arc<ListLoansUseCase<LN>>,
+    list_auction_bids_uc: Arc<ListAuctionBidsUseCase<AB>>,
+    list_moles_uc: Arc<ListMolesUseCase<MOL>>,
+    list_treaties_uc: Arc<ListTreatiesUseCase<TRT>>,
+    list_contracts_uc: Arc<ListContractsUseCase<CTR>>,
+    list_building_ownerships_uc: Arc<ListBuildingOwnershipsUseCase<BLO>>,
+    list_building_locations_uc: Arc<ListBuildingLocationsUseCase<BUI>>,
+    get_building_locations_uc: Arc<GetBuildingLocationsUseCase<BUI>>,
+}
+
+impl<UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+    LoadGameStateUseCase<UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+where
+    UNT: UnitRepository,
+    CRP: CorporationRepository,
+    MRK: MarketRepository,
+    BSN: BusinessRepository,
+    BL: BusinessListingRepository,
+    BO: BusinessOfferRepository,
+    LN: LoanRepository,
+    AB: AuctionBidRepository,
+    MOL: MoleRepository,
+    TRT: TreatyRepository,
+    CTR: ContractRepository,
+    BLO: BuildingOwnershipRepository,
+    BUI: BuildingRepository,
+{
+    pub async fn execute(&self, game_tick: i64) -> ApplicationResult<GameState> {
+        let units_vec = self.list_units_uc.execute(game_tick).await?;
+        let corporations_vec = self.list_corporations_uc.execute(game_tick).await?;
+        let markets_vec = self.list_markets_uc.execute(game_tick).await?;
+        let businesses_vec = self.list_businesses_uc.execute(game_tick).await?;
+        let business_listings_vec = self.list_business_listings_uc.execute(game_tick).await?;
+        let business_offers_vec = self.list_business_offers_uc.execute(game_tick).await?;
+        let loans_vec = self.list_loans_uc.execute(game_tick).await?;
+        let auction_bids_vec = self.list_auction_bids_uc.execute(game_tick).await?;
+        let moles_vec = self.list_moles_uc.execute(game_tick).await?;
+        let treaties_vec = self.list_treaties_uc.execute(game_tick).await?;
+        let contracts_vec = self.list_contracts_uc.execute(game_tick).await?;
+        let building_ownerships_vec = self.list_building_ownerships_uc.execute(game_tick).await?;
+        let building_locations_vec = self.list_building_locations_uc.execute(game_tick).await?;
+
+        Ok(GameState::build()
+            .last_processed_tick(game_tick)
+            .units_vec(units_vec)
+            .corporations_vec(corporations_vec)
+            .markets_vec(markets_vec)
+            .businesses_vec(businesses_vec)
+            .business_listings_vec(business_listings_vec)
+            .business_offers_vec(business_offers_vec)
+            .loans_vec(loans_vec)
+            .auction_bids_vec(auction_bids_vec)
+            .moles_vec(moles_vec)
+            .treaties_vec(treaties_vec)
+            .contracts_vec(contracts_vec)
+            .building_ownerships_vec(building_ownerships_vec)
+            .building_locations_vec(building_locations_vec)
+            .call())
+    }
+
+    /// Only the buildings the state refers to are loaded, so the ones the actions of a tick
+    /// refer to are looked up before the tick is calculated.
+    pub async fn load_action_buildings(
+        &self,
+        state: &mut GameState,
+        actions: &[QueuedAction],
+    ) -> ApplicationResult<()> {
+        let mut gml_ids: Vec<String> = actions
+            .iter()
+            .filter_map(|action| action.payload.details.building_gml_id())
+            .filter(|gml_id| state.get_building_uuid_by_gml_id(gml_id).is_none())
+            .map(str::to_string)
+            .collect();
+        gml_ids.sort();
+        gml_ids.dedup();
+
+        for building_location in self.get_building_locations_uc.execute(gml_ids).await? {
+            state.add_building_location(building_location);
+        }
+
+        Ok(())
+    }
+}
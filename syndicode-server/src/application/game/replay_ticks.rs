@@ -0,0 +1,175 @@
+use super::load_game_state::LoadGameStateUseCase;
+use crate::{
+    application::{
+        action::ActionLogEntry, error::ApplicationResult, ports::action_log::ActionLogRepository,
+    },
+    domain::{
+        diplomacy::repository::TreatyRepository,
+        economy::{
+            auction_bid::repository::AuctionBidRepository,
+            building::repository::BuildingRepository,
+            building_ownership::repository::BuildingOwnershipRepository,
+            business::repository::BusinessRepository,
+            business_listing::repository::BusinessListingRepository,
+            business_offer::repository::BusinessOfferRepository,
+            contract::repository::ContractRepository,
+            corporation::repository::CorporationRepository, loan::repository::LoanRepository,
+            market::repository::MarketRepository,
+        },
+        espionage::repository::MoleRepository,
+        outcome::outcomes_checksum,
+        ports::simulation::Simulationable,
+        unit::repository::UnitRepository,
+    },
+};
+use bon::{bon, Builder};
+use std::sync::Arc;
+
+/// The first tick whose replayed result differs from the recorded one.
+#[derive(Debug)]
+pub struct TickDivergence {
+    pub game_tick: i64,
+    pub recorded_state_checksum: u64,
+    pub replayed_state_checksum: u64,
+    pub recorded_outcomes_checksum: u64,
+    pub replayed_outcomes_checksum: u64,
+}
+
+#[derive(Debug)]
+pub struct ReplayReport {
+    pub replayed_ticks: usize,
+    pub divergence: Option<TickDivergence>,
+}
+
+/// Rebuilds the state of a tick from its snapshot and re-applies the recorded actions
+/// of the following ticks, comparing every result with the recorded checksums.
+#[derive(Builder)]
+#[allow(clippy::type_complexity)]
+pub struct ReplayTicksUseCase<S, ALR, UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+where
+    S: Simulationable,
+    ALR: ActionLogRepository,
+    UNT: UnitRepository,
+    CRP: CorporationRepository,
+    MRK: MarketRepository,
+    BSN: BusinessRepository,
+    BL: BusinessListingRepository,
+    BO: BusinessOfferRepository,
+    LN: LoanRepository,
+    AB: AuctionBidRepository,
+    MOL: MoleRepository,
+    TRT: TreatyRepository,
+    CTR: ContractRepository,
+    BLO: BuildingOwnershipRepository,
+    BUI: BuildingRepository,
+{
+    simulation: Arc<S>,
+    action_log_repo: Arc<ALR>,
+    load_game_state_uc:
+        LoadGameStateUseCase<UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>,
+}
+
+#[bon]
+impl<S, ALR, UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+    ReplayTicksUseCase<S, ALR, UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+where
+    S: Simulationable,
+    ALR: ActionLogRepository,
+    UNT: UnitRepository,
+    CRP: CorporationRepository,
+    MRK: MarketRepository,
+    BSN: BusinessRepository,
+    BL: BusinessListingRepository,
+    BO: BusinessOfferRepository,
+    LN: LoanRepository,
+    AB: AuctionBidRepository,
+    MOL: MoleRepository,
+    TRT: TreatyRepository,
+    CTR: ContractRepository,
+    BLO: BuildingOwnershipRepository,
+    BUI: BuildingRepository,
+{
+    #[builder]
+    pub async fn execute(&self, from_tick: i64, to_tick: i64) -> ApplicationResult<ReplayReport> {
+        if to_tick <= from_tick {
+            return Err(anyhow::anyhow!(
+                "The tick to replay to ({to_tick}) has to be after the snapshot tick ({from_tick})"
+            )
+            .into());
+        }
+
+        let entries = self
+            .action_log_repo
+            .list_action_log_entries(from_tick + 1, to_tick)
+            .await?;
+
+        // Every tick of the range has to be recorded, otherwise the replay would skip actions
+        for (index, expected_tick) in (from_tick + 1..=to_tick).enumerate() {
+            if entries.get(index).map(|entry| entry.game_tick) != Some(expected_tick) {
+                return Err(anyhow::anyhow!("No action log entry for tick {expected_tick}").into());
+            }
+        }
+
+        // Checksums of another build would diverge even if the simulation behaved the same
+        let checksum_version = ActionLogEntry::current_checksum_version();
+        if let Some(entry) = entries
+            .iter()
+            .find(|entry| entry.checksum_version != checksum_version)
+        {
+            return Err(anyhow::anyhow!(
+                "Tick {} was recorded by checksum version {}, which isn't comparable with {}",
+                entry.game_tick,
+                entry.checksum_version,
+                checksum_version
+            )
+            .into());
+        }
+
+        let mut state = self.load_game_state_uc.execute(from_tick).await?;
+        let mut replayed_ticks = 0;
+
+        for entry in entries {
+            self.load_game_state_uc
+                .load_action_buildings(&mut state, &entry.actions)
+                .await?;
+            let mut action_ids = Vec::with_capacity(entry.actions.len());
+            let outcomes = self.simulation.calculate_next_state(
+                entry.game_tick,
+                entry.seed,
+                entry.actions,
+                &mut action_ids,
+                &mut state,
+            );
+            state.last_processed_tick = entry.game_tick;
+            replayed_ticks += 1;
+
+            let replayed_state_checksum = state.checksum();
+            let replayed_outcomes_checksum = outcomes_checksum(&outcomes);
+
+            if replayed_state_checksum != entry.state_checksum
+                || replayed_outcomes_checksum != entry.outcomes_checksum
+            {
+                return Ok(ReplayReport {
+                    replayed_ticks,
+                    divergence: Some(TickDivergence {
+                        game_tick: entry.game_tick,
+                        recorded_state_checksum: entry.state_checksum,
+                        replayed_state_checksum,
+                        recorded_outcomes_checksum: entry.outcomes_checksum,
+                        replayed_outcomes_checksum,
+                    }),
+                });
+            }
+
+            tracing::debug!(
+                tick = entry.game_tick,
+                "Replayed tick matches the recording."
+            );
+        }
+
+        Ok(ReplayReport {
+            replayed_ticks,
+            divergence: None,
+        })
+    }
+}
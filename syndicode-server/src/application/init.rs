@@ -2,7 +2,7 @@ use bon::Builder;
 
 use super::{
     admin::bootstrap::BootstrapAdminUseCase,
-    economy::bootstrap::BootstrapEconomyUseCase,
+    economy::{bootstrap::BootstrapEconomyUseCase, seed_wards::SeedWardsUseCase},
     error::ApplicationResult,
     ports::{
         crypto::PasswordHandler, downloader::BackupDownloader, init::InitializationRepository,
@@ -29,6 +29,7 @@ where
     downloader: Arc<DOW>,
     bootstrap_admin_uc: Arc<BootstrapAdminUseCase<UOW, P, INI>>,
     bootstrap_economy_uc: Arc<BootstrapEconomyUseCase<UOW, INI>>,
+    seed_wards_uc: Arc<SeedWardsUseCase<UOW, INI>>,
     restore_url: Option<String>,
 }
 
@@ -47,10 +48,17 @@ where
         let is_initialized = self.init_repo.is_flag_set(FlagKey::Database).await?;
         if is_initialized {
             tracing::info!("Database has already been initialized...");
-
-            return Ok(());
+        } else {
+            self.initialize_database().await?;
         }
 
+        // Wards are derived from the buildings, so they are seeded once the database is initialized
+        self.seed_wards_uc.execute().await?;
+
+        Ok(())
+    }
+
+    async fn initialize_database(&self) -> ApplicationResult<()> {
         match self.restore_url.clone() {
             Some(url) => {
                 tracing::info!("Downloading database dump...");
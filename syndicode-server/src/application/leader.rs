@@ -362,7 +362,7 @@ mod tests {
     use crate::{
         application::{
             admin::bootstrap::BootstrapAdminUseCase,
-            economy::bootstrap::BootstrapEconomyUseCase,
+            economy::{bootstrap::BootstrapEconomyUseCase, seed_wards::SeedWardsUseCase},
             ports::{
                 crypto::MockPasswordHandler,
                 downloader::MockBackupDownloader,
@@ -433,12 +433,19 @@ mod tests {
 
         let bootstrap_economy_uc = Arc::new(
             BootstrapEconomyUseCase::builder()
-                .uow(uow_arc)
+                .uow(uow_arc.clone())
                 .init_repo(init_repo_arc.clone())
                 .config(config.clone())
                 .build(),
         );
 
+        let seed_wards_uc = Arc::new(
+            SeedWardsUseCase::builder()
+                .uow(uow_arc)
+                .init_repo(init_repo_arc.clone())
+                .build(),
+        );
+
         let orchestrator = Arc::new(
             InitializationOrchestrator::builder()
                 .config(config)
@@ -448,6 +455,7 @@ mod tests {
                 .downloader(downloader)
                 .bootstrap_admin_uc(bootstrap_admin_uc)
                 .bootstrap_economy_uc(bootstrap_economy_uc)
+                .seed_wards_uc(seed_wards_uc)
                 .build(),
         );
 
@@ -648,6 +656,12 @@ mod tests {
             .times(1)
             .returning(|_| Ok(true));
 
+        init_repo
+            .expect_is_flag_set()
+            .withf(|key| key.to_string() == FlagKey::TerritoryDomain.to_string())
+            .times(1)
+            .returning(|_| Ok(true));
+
         processor
             .expect_process_next_tick()
             .times(1)
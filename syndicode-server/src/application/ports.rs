@@ -1,3 +1,4 @@
+pub mod action_log;
 pub mod crypto;
 pub mod downloader;
 pub mod game_tick;
@@ -0,0 +1,17 @@
+use crate::{application::action::ActionLogEntry, domain::repository::RepositoryResult};
+
+#[tonic::async_trait]
+pub trait ActionLogRepository: Send + Sync {
+    /// Lists the entries of all ticks in the inclusive range, ordered by tick.
+    async fn list_action_log_entries(
+        &self,
+        from_game_tick: i64,
+        to_game_tick: i64,
+    ) -> RepositoryResult<Vec<ActionLogEntry>>;
+}
+
+#[tonic::async_trait]
+pub trait ActionLogTxRepository: Send + Sync {
+    /// Appends the entry of a processed tick. Entries are never updated.
+    async fn append_action_log_entry(&mut self, entry: &ActionLogEntry) -> RepositoryResult<()>;
+}
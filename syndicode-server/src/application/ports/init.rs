@@ -9,6 +9,7 @@ pub enum FlagKey {
     Database,
     AdminDomain,
     EconomyDomain,
+    TerritoryDomain,
 }
 
 impl Display for FlagKey {
@@ -17,6 +18,7 @@ impl Display for FlagKey {
             FlagKey::Database => write!(f, "database_initialized"),
             FlagKey::AdminDomain => write!(f, "admin_domain_initialized"),
             FlagKey::EconomyDomain => write!(f, "economy_domain_initialized"),
+            FlagKey::TerritoryDomain => write!(f, "territory_domain_initialized"),
         }
     }
 }
@@ -16,6 +16,9 @@ pub enum OutcomeError {
     #[error("Failed to publish the progression of the game tick: {0}")]
     PublishingGametickFailed(String),
 
+    #[error("Failed to publish world events: {0}")]
+    PublishingWorldEventsFailed(String),
+
     #[error("An unexpected queue error occurred: {0}")]
     Unexpected(#[from] anyhow::Error),
 }
@@ -25,6 +28,14 @@ pub type OutcomeResult<T> = Result<T, OutcomeError>;
 #[tonic::async_trait]
 pub trait OutcomeStoreWriter: Send + Sync {
     async fn store_outcome(&self, request_uuid: Uuid, payload: &[u8]) -> OutcomeResult<()>;
+
+    /// Keeps the outcomes of a tick in the bounded backlogs of their users, to be replayed when
+    /// a stream is resumed. The outcomes are given as pairs of user UUID and payload.
+    async fn append_to_backlog(
+        &self,
+        game_tick: i64,
+        outcomes: &[(Uuid, &[u8])],
+    ) -> OutcomeResult<()>;
 }
 
 #[tonic::async_trait]
@@ -34,6 +45,13 @@ pub trait OutcomeStoreReader: Send + Sync {
 
     /// Optional cleanup
     async fn delete_outcome(&self, request_uuid: Uuid) -> OutcomeResult<()>;
+
+    /// The backlogged outcomes of the user after the given tick, oldest first.
+    async fn retrieve_backlog(
+        &self,
+        user_uuid: Uuid,
+        after_game_tick: i64,
+    ) -> OutcomeResult<Vec<Vec<u8>>>;
 }
 
 #[tonic::async_trait]
@@ -41,4 +59,7 @@ pub trait OutcomeNotifier: Send + Sync {
     async fn notify_outcome_ready(&self, user_uuid: Uuid, request_uuid: Uuid) -> OutcomeResult<()>;
 
     async fn notify_game_tick_advanced(&self, game_tick: i64) -> OutcomeResult<()>;
+
+    /// Publishes the serialized world events of a tick to every server instance.
+    async fn notify_world_events(&self, payload: &[u8]) -> OutcomeResult<()>;
 }
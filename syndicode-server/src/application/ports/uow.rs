@@ -1,19 +1,27 @@
 #[cfg(test)]
 use mockall::{automock, predicate::*};
 
-use super::{game_tick::GameTickTxRepository, init::InitializationTxRepository};
+use super::{
+    action_log::ActionLogTxRepository, game_tick::GameTickTxRepository,
+    init::InitializationTxRepository,
+};
 use crate::{
     application::error::ApplicationResult,
     domain::{
+        diplomacy::repository::TreatyTxRepository,
         economy::{
+            auction_bid::repository::AuctionBidTxRepository,
             building::repository::BuildingTxRepository,
             building_ownership::repository::BuildingOwnershipTxRepository,
             business::repository::BusinessTxRepository,
             business_listing::repository::BusinessListingTxRepository,
             business_offer::repository::BusinessOfferTxRepository,
+            contract::repository::ContractTxRepository,
             corporation::repository::CorporationTxRepository,
-            market::repository::MarketTxRepository,
+            ledger::repository::LedgerTxRepository, loan::repository::LoanTxRepository,
+            market::repository::MarketTxRepository, territory::repository::TerritoryTxRepository,
         },
+        espionage::repository::MoleTxRepository,
         unit::repository::UnitTxRespository,
         user::repository::UserTxRepository,
         user_verify::repository::UserVerificationTxRepository,
@@ -27,17 +35,25 @@ use tonic::async_trait;
 // The 'a lifetime ensures it cannot outlive the transaction scope.
 pub trait TransactionalContext<'a>:
     GameTickTxRepository
+    + ActionLogTxRepository
     + InitializationTxRepository
     + UserTxRepository
     + UserVerificationTxRepository
     + CorporationTxRepository
+    + LedgerTxRepository
     + MarketTxRepository
     + BusinessTxRepository
     + BusinessListingTxRepository
     + BusinessOfferTxRepository
+    + LoanTxRepository
+    + AuctionBidTxRepository
     + UnitTxRespository
     + BuildingTxRepository
     + BuildingOwnershipTxRepository
+    + TerritoryTxRepository
+    + MoleTxRepository
+    + TreatyTxRepository
+    + ContractTxRepository
     + Send
     + Sync
 {
@@ -1,10 +1,6 @@
 use super::{
-    economy::{
-        list_building_ownerships::ListBuildingOwnershipsUseCase,
-        list_business_listings::ListBusinessListingUseCase,
-        list_business_offers::ListBusinessOffersUseCase, list_businesses::ListBusinessesUseCase,
-        list_corporations::ListCorporationsUseCase, list_markets::ListMarketsUseCase,
-    },
+    action::ActionLogEntry,
+    game::load_game_state::LoadGameStateUseCase,
     ports::{
         game_tick::GameTickRepository,
         init::InitializationRepository,
@@ -13,25 +9,33 @@ use super::{
         puller::ActionPullable,
         uow::UnitOfWork,
     },
-    warfare::list_units::ListUnitsUseCase,
 };
 use crate::{
     application::ports::{init::FlagKey, processor::ProcessorError},
     domain::{
+        diplomacy::repository::TreatyRepository,
         economy::{
-            building_ownership::{
-                model::BuildingOwnership, repository::BuildingOwnershipRepository,
-            },
-            business::{model::Business, repository::BusinessRepository},
-            business_listing::{model::BusinessListing, repository::BusinessListingRepository},
-            business_offer::{model::BusinessOffer, repository::BusinessOfferRepository},
-            corporation::{model::Corporation, repository::CorporationRepository},
-            market::{model::Market, repository::MarketRepository},
+            auction_bid::repository::AuctionBidRepository,
+            building::repository::BuildingRepository,
+            building_ownership::repository::BuildingOwnershipRepository,
+            business::repository::BusinessRepository,
+            business_listing::repository::BusinessListingRepository,
+            business_offer::repository::BusinessOfferRepository,
+            contract::repository::ContractRepository,
+            corporation::repository::CorporationRepository, loan::repository::LoanRepository,
+            market::repository::MarketRepository,
         },
-        outcome::DomainActionOutcome,
+        espionage::repository::MoleRepository,
+        outcome::{outcomes_checksum, DomainActionOutcome},
         ports::simulation::Simulationable,
-        simulation::game_state::GameState,
-        unit::{model::Unit, repository::UnitRepository},
+        repository::TickRetention,
+        simulation::{
+            game_state::GameState,
+            rng::SimulationRng,
+            state_diff::{corporation_state_diffs, world_events},
+        },
+        unit::repository::UnitRepository,
+        world_event::model::WorldEventBatch,
     },
 };
 use anyhow::Context;
@@ -40,8 +44,29 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 #[derive(Builder)]
-pub struct GameTickProcessor<INI, S, P, RSW, RN, UOW, GTR, UNT, CRP, MRK, BSN, BL, BO, BLO>
-where
+#[allow(clippy::type_complexity)]
+pub struct GameTickProcessor<
+    INI,
+    S,
+    P,
+    RSW,
+    RN,
+    UOW,
+    GTR,
+    UNT,
+    CRP,
+    MRK,
+    BSN,
+    BL,
+    BO,
+    LN,
+    AB,
+    MOL,
+    TRT,
+    CTR,
+    BLO,
+    BUI,
+> where
     INI: InitializationRepository,
     S: Simulationable,
     P: ActionPullable,
@@ -55,7 +80,13 @@ where
     BSN: BusinessRepository,
     BL: BusinessListingRepository,
     BO: BusinessOfferRepository,
+    LN: LoanRepository,
+    AB: AuctionBidRepository,
+    MOL: MoleRepository,
+    TRT: TreatyRepository,
+    CTR: ContractRepository,
     BLO: BuildingOwnershipRepository,
+    BUI: BuildingRepository,
 {
     init_repo: Arc<INI>,
     state: Arc<Mutex<Option<GameState>>>,
@@ -65,17 +96,34 @@ where
     outcome_notifier: Arc<RN>,
     uow: Arc<UOW>,
     game_tick_repo: Arc<GTR>,
-    list_units_uc: Arc<ListUnitsUseCase<UNT>>,
-    list_corporations_uc: Arc<ListCorporationsUseCase<CRP>>,
-    list_markets_uc: Arc<ListMarketsUseCase<MRK>>,
-    list_businesses_uc: Arc<ListBusinessesUseCase<BSN>>,
-    list_business_listings_uc: Arc<ListBusinessListingUseCase<BL>>,
-    list_business_offers_uc: Arc<ListBusinessOffersUseCase<BO>>,
-    list_building_ownerships: Arc<ListBuildingOwnershipsUseCase<BLO>>,
+    load_game_state_uc:
+        LoadGameStateUseCase<UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>,
+    tick_retention: TickRetention,
 }
 
-impl<INI, S, P, RSW, RN, UOW, GTR, UNT, CRP, MRK, BSN, BL, BO, BLO>
-    GameTickProcessor<INI, S, P, RSW, RN, UOW, GTR, UNT, CRP, MRK, BSN, BL, BO, BLO>
+impl<INI, S, P, RSW, RN, UOW, GTR, UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+    GameTickProcessor<
+        INI,
+        S,
+        P,
+        RSW,
+        RN,
+        UOW,
+        GTR,
+        UNT,
+        CRP,
+        MRK,
+        BSN,
+        BL,
+        BO,
+        LN,
+        AB,
+        MOL,
+        TRT,
+        CTR,
+        BLO,
+        BUI,
+    >
 where
     INI: InitializationRepository,
     S: Simulationable,
@@ -90,7 +138,13 @@ where
     BSN: BusinessRepository,
     BL: BusinessListingRepository,
     BO: BusinessOfferRepository,
+    LN: LoanRepository,
+    AB: AuctionBidRepository,
+    MOL: MoleRepository,
+    TRT: TreatyRepository,
+    CTR: ContractRepository,
     BLO: BuildingOwnershipRepository,
+    BUI: BuildingRepository,
 {
     // Helper to serialize outcomes
     fn serialize_outcome_for_delivery(
@@ -113,34 +167,7 @@ where
 
         // Load all state components from the database
         let current_game_tick = self.game_tick_repo.get_current_game_tick().await?;
-
-        let units_vec = self.list_units_uc.execute(current_game_tick).await?;
-        let corporations_vec = self.list_corporations_uc.execute(current_game_tick).await?;
-        let markets_vec = self.list_markets_uc.execute(current_game_tick).await?;
-        let businesses_vec = self.list_businesses_uc.execute(current_game_tick).await?;
-        let business_listings_vec = self
-            .list_business_listings_uc
-            .execute(current_game_tick)
-            .await?;
-        let business_offers_vec = self
-            .list_business_offers_uc
-            .execute(current_game_tick)
-            .await?;
-        let building_ownerships_vec = self
-            .list_building_ownerships
-            .execute(current_game_tick)
-            .await?;
-
-        let game_state = GameState::build()
-            .last_processed_tick(current_game_tick)
-            .units_vec(units_vec)
-            .corporations_vec(corporations_vec)
-            .markets_vec(markets_vec)
-            .businesses_vec(businesses_vec)
-            .business_listings_vec(business_listings_vec)
-            .business_offers_vec(business_offers_vec)
-            .building_ownerships_vec(building_ownerships_vec)
-            .call();
+        let game_state = self.load_game_state_uc.execute(current_game_tick).await?;
 
         tracing::info!(
             tick = current_game_tick,
@@ -152,8 +179,30 @@ where
 }
 
 #[tonic::async_trait]
-impl<INI, S, P, RSW, RN, UOW, GTR, UNT, CRP, MRK, BSN, BL, BO, BLO> GameTickProcessable
-    for GameTickProcessor<INI, S, P, RSW, RN, UOW, GTR, UNT, CRP, MRK, BSN, BL, BO, BLO>
+impl<INI, S, P, RSW, RN, UOW, GTR, UNT, CRP, MRK, BSN, BL, BO, LN, AB, MOL, TRT, CTR, BLO, BUI>
+    GameTickProcessable
+    for GameTickProcessor<
+        INI,
+        S,
+        P,
+        RSW,
+        RN,
+        UOW,
+        GTR,
+        UNT,
+        CRP,
+        MRK,
+        BSN,
+        BL,
+        BO,
+        LN,
+        AB,
+        MOL,
+        TRT,
+        CTR,
+        BLO,
+        BUI,
+    >
 where
     INI: InitializationRepository,
     S: Simulationable,
@@ -168,7 +217,13 @@ where
     BSN: BusinessRepository,
     BL: BusinessListingRepository,
     BO: BusinessOfferRepository,
+    LN: LoanRepository,
+    AB: AuctionBidRepository,
+    MOL: MoleRepository,
+    TRT: TreatyRepository,
+    CTR: ContractRepository,
     BLO: BuildingOwnershipRepository,
+    BUI: BuildingRepository,
 {
     async fn process_next_tick(&self) -> ProcessorResult<i64> {
         // Acquire a lock on the state. This lock is held for the entire tick processing.
@@ -200,130 +255,206 @@ where
             "Pulled actions for tick {}.",
             next_game_tick
         );
+        // The pulled actions are processed either way, the ones referring to a building that
+        // couldn't be looked up fail with the building not being found
+        if let Err(err) = self
+            .load_game_state_uc
+            .load_action_buildings(&mut game_state, &queued_actions)
+            .await
+        {
+            tracing::error!(error = %err, "Failed to look up the buildings of the pulled actions.");
+        }
 
         // 3. Calculate State N+1 (using the in-memory game_state)
+        // The pulled actions and the seed are recorded, so the tick can be replayed later on
+        let seed = self.simulation.tick_seed(next_game_tick);
+        let recorded_actions = queued_actions.clone();
         let mut action_ids: Vec<String> = Vec::with_capacity(act_msg_count);
         let action_outcomes = self.simulation.calculate_next_state(
             next_game_tick,
+            seed,
             queued_actions,
             &mut action_ids,
             &mut game_state,
         );
+        let action_log_entry = ActionLogEntry::builder()
+            .game_tick(next_game_tick)
+            .seed(seed)
+            .actions(recorded_actions)
+            .state_checksum(game_state.checksum())
+            .outcomes_checksum(outcomes_checksum(&action_outcomes))
+            .checksum_version(ActionLogEntry::current_checksum_version())
+            .build();
         tracing::debug!("Calculated next state in memory.");
 
         // 4. Write State N+1 Atomically
-        // Convert the owned HashMaps into owned Vecs.
-        // This is very cheap because it just moves the values, no deep clones.
-        let units: Vec<Unit> = std::mem::take(&mut game_state.units_map)
-            .into_values()
-            .collect();
-        let corporations: Vec<Corporation> = std::mem::take(&mut game_state.corporations_map)
-            .into_values()
-            .collect();
-        let markets: Vec<Market> = std::mem::take(&mut game_state.markets_map)
-            .into_values()
-            .collect();
-        let businesses: Vec<Business> = std::mem::take(&mut game_state.businesses_map)
-            .into_values()
-            .collect();
-        let business_listings: Vec<BusinessListing> =
-            std::mem::take(&mut game_state.business_listings_map)
-                .into_values()
-                .collect();
-        let business_offers: Vec<BusinessOffer> =
-            std::mem::take(&mut game_state.business_offers_map)
-                .into_values()
-                .collect();
-        let building_ownerships: Vec<BuildingOwnership> =
-            std::mem::take(&mut game_state.building_ownerships_map)
-                .into_values()
-                .collect();
+        // Only the rows that changed since the last tick are written as new versions,
+        // unchanged rows stay visible through their existing versions.
+        let state_changes = game_state.changes();
+        let ledger_entries = game_state.take_ledger_entries();
+        let market_snapshots = game_state.take_market_snapshots();
+        // Row versions that are not visible at any retained tick, and the ledger entries of ticks
+        // that are not retained, are pruned in the same transaction.
+        let keep_from_tick = self.tick_retention.keep_from_tick(next_game_tick);
+        let snapshot_interval = self.tick_retention.snapshot_interval;
 
-        let (
-            units,
-            corporations,
-            markets,
-            businesses,
-            business_listings,
-            business_offers,
-            building_ownerships,
-        ) = self
+        let state_changes = self
             .uow
             .execute(move |ctx| {
                 Box::pin(async move {
+                    let changes = &state_changes;
+
                     // Units
-                    ctx.insert_units_in_tick(next_game_tick, &units).await?;
-                    ctx.delete_units_before_tick(current_game_tick).await?;
+                    ctx.supersede_units_in_tick(next_game_tick, &changes.units.removed)
+                        .await?;
+                    ctx.insert_units_in_tick(next_game_tick, &changes.units.upserted)
+                        .await?;
+                    ctx.delete_units_before_tick(keep_from_tick, snapshot_interval)
+                        .await?;
 
                     // Corporations
-                    ctx.insert_corporations_in_tick(next_game_tick, &corporations)
+                    ctx.supersede_corporations_in_tick(
+                        next_game_tick,
+                        &changes.corporations.removed,
+                    )
+                    .await?;
+                    ctx.insert_corporations_in_tick(next_game_tick, &changes.corporations.upserted)
                         .await?;
-                    ctx.delete_corporations_before_tick(current_game_tick)
+                    ctx.delete_corporations_before_tick(keep_from_tick, snapshot_interval)
                         .await?;
 
                     // Markets
-                    ctx.insert_markets_in_tick(next_game_tick, &markets).await?;
-                    ctx.delete_markets_before_tick(current_game_tick).await?;
+                    ctx.supersede_markets_in_tick(next_game_tick, &changes.markets.removed)
+                        .await?;
+                    ctx.insert_markets_in_tick(next_game_tick, &changes.markets.upserted)
+                        .await?;
+                    ctx.delete_markets_before_tick(keep_from_tick, snapshot_interval)
+                        .await?;
+                    ctx.insert_market_snapshots(&market_snapshots).await?;
 
                     // Businesses
-                    ctx.insert_businesses_in_tick(next_game_tick, &businesses)
+                    ctx.supersede_businesses_in_tick(next_game_tick, &changes.businesses.removed)
+                        .await?;
+                    ctx.insert_businesses_in_tick(next_game_tick, &changes.businesses.upserted)
+                        .await?;
+                    ctx.delete_businesses_before_tick(keep_from_tick, snapshot_interval)
                         .await?;
-                    ctx.delete_businesses_before_tick(current_game_tick).await?;
 
                     // Business Listings
-                    ctx.insert_business_listings_in_tick(next_game_tick, &business_listings)
-                        .await?;
-                    ctx.delete_business_listings_before_tick(current_game_tick)
+                    ctx.supersede_business_listings_in_tick(
+                        next_game_tick,
+                        &changes.business_listings.removed,
+                    )
+                    .await?;
+                    ctx.insert_business_listings_in_tick(
+                        next_game_tick,
+                        &changes.business_listings.upserted,
+                    )
+                    .await?;
+                    ctx.delete_business_listings_before_tick(keep_from_tick, snapshot_interval)
                         .await?;
 
                     // Business Offers
-                    ctx.insert_business_offers_in_tick(next_game_tick, &business_offers)
+                    ctx.supersede_business_offers_in_tick(
+                        next_game_tick,
+                        &changes.business_offers.removed,
+                    )
+                    .await?;
+                    ctx.insert_business_offers_in_tick(
+                        next_game_tick,
+                        &changes.business_offers.upserted,
+                    )
+                    .await?;
+                    ctx.delete_business_offers_before_tick(keep_from_tick, snapshot_interval)
+                        .await?;
+
+                    // Loans
+                    ctx.supersede_loans_in_tick(next_game_tick, &changes.loans.removed)
                         .await?;
-                    ctx.delete_business_offers_before_tick(current_game_tick)
+                    ctx.insert_loans_in_tick(next_game_tick, &changes.loans.upserted)
+                        .await?;
+                    ctx.delete_loans_before_tick(keep_from_tick, snapshot_interval)
+                        .await?;
+
+                    // Auction Bids
+                    ctx.supersede_auction_bids_in_tick(
+                        next_game_tick,
+                        &changes.auction_bids.removed,
+                    )
+                    .await?;
+                    ctx.insert_auction_bids_in_tick(next_game_tick, &changes.auction_bids.upserted)
+                        .await?;
+                    ctx.delete_auction_bids_before_tick(keep_from_tick, snapshot_interval)
+                        .await?;
+
+                    // Moles
+                    ctx.supersede_moles_in_tick(next_game_tick, &changes.moles.removed)
+                        .await?;
+                    ctx.insert_moles_in_tick(next_game_tick, &changes.moles.upserted)
+                        .await?;
+                    ctx.delete_moles_before_tick(keep_from_tick, snapshot_interval)
+                        .await?;
+
+                    // Treaties
+                    ctx.supersede_treaties_in_tick(next_game_tick, &changes.treaties.removed)
+                        .await?;
+                    ctx.insert_treaties_in_tick(next_game_tick, &changes.treaties.upserted)
+                        .await?;
+                    ctx.delete_treaties_before_tick(keep_from_tick, snapshot_interval)
+                        .await?;
+
+                    // Contracts
+                    ctx.supersede_contracts_in_tick(next_game_tick, &changes.contracts.removed)
+                        .await?;
+                    ctx.insert_contracts_in_tick(next_game_tick, &changes.contracts.upserted)
+                        .await?;
+                    ctx.delete_contracts_before_tick(keep_from_tick, snapshot_interval)
                         .await?;
 
                     // Building Ownerships
-                    ctx.insert_building_ownerships_in_tick(next_game_tick, &building_ownerships)
+                    ctx.supersede_building_ownerships_in_tick(
+                        next_game_tick,
+                        &changes.building_ownerships.removed,
+                    )
+                    .await?;
+                    ctx.insert_building_ownerships_in_tick(
+                        next_game_tick,
+                        &changes.building_ownerships.upserted,
+                    )
+                    .await?;
+                    ctx.delete_building_ownerships_before_tick(keep_from_tick, snapshot_interval)
                         .await?;
-                    ctx.delete_building_ownerships_before_tick(current_game_tick)
+
+                    // Corporation Ledger
+                    ctx.insert_ledger_entries(&ledger_entries).await?;
+                    ctx.delete_ledger_entries_before_tick(keep_from_tick, snapshot_interval)
                         .await?;
 
+                    // Action Log
+                    ctx.append_action_log_entry(&action_log_entry).await?;
+
                     // Game Tick Update
                     ctx.update_current_game_tick(next_game_tick).await?;
 
-                    Ok((
-                        units,
-                        corporations,
-                        markets,
-                        businesses,
-                        business_listings,
-                        business_offers,
-                        building_ownerships,
-                    ))
+                    Ok(state_changes)
                 })
             })
             .await?;
 
-        // --- REBUILD THE STATE ---
-        // Whether the UOW succeeded or failed, we must restore the full game_state
-        // object before releasing the lock, so it's ready for the next attempt.
-        // We do this by converting the vectors back into HashMaps.
-        game_state.corporations_map = corporations.into_iter().map(|c| (c.uuid, c)).collect();
-        game_state.units_map = units.into_iter().map(|u| (u.uuid, u)).collect();
-        game_state.markets_map = markets.into_iter().map(|m| (m.uuid, m)).collect();
-        game_state.businesses_map = businesses.into_iter().map(|b| (b.uuid, b)).collect();
-        game_state.business_listings_map = business_listings
-            .into_iter()
-            .map(|bl| (bl.uuid, bl))
-            .collect();
-        game_state.business_offers_map = business_offers
-            .into_iter()
-            .map(|bo| (bo.uuid, bo))
-            .collect();
-        game_state.building_ownerships_map = building_ownerships
-            .into_iter()
-            .map(|buw| (buw.building_uuid, buw))
-            .collect();
+        // The written rows are also what has changed for the corporations within the tick.
+        // The diffs draw their ids from a generator of their own, derived from the tick's seed,
+        // so they don't repeat the ids handed out within the tick.
+        let mut diff_rng = SimulationRng::from_seed(SimulationRng::tick_seed(seed, next_game_tick));
+        let state_diffs =
+            corporation_state_diffs(&game_state, &state_changes, &mut diff_rng, next_game_tick);
+        let world_event_batch = WorldEventBatch {
+            game_tick: next_game_tick,
+            events: world_events(&game_state, &state_changes),
+        };
+
+        // The written rows are the new baseline for the next tick's changes.
+        // If the UOW failed, the state is dropped and reloaded from the database on the next attempt.
+        game_state.mark_persisted(&state_changes);
         game_state.last_processed_tick = next_game_tick;
 
         *state_guard = Some(game_state);
@@ -336,11 +467,37 @@ where
             tracing::debug!(num_acked = act_msg_count, "Acknowledged processed actions.");
         }
 
-        if !action_outcomes.is_empty() {
-            for outcome in action_outcomes {
-                let request_uuid = outcome.get_request_uuid();
-                let user_uuid = outcome.get_req_user_uuid();
+        // The state diffs go out after the outcomes that caused them
+        if !action_outcomes.is_empty() || !state_diffs.is_empty() {
+            let mut deliveries = Vec::with_capacity(action_outcomes.len() + state_diffs.len());
+            for outcome in action_outcomes.into_iter().chain(state_diffs) {
                 let result_payload = self.serialize_outcome_for_delivery(&outcome)?;
+                deliveries.push((
+                    outcome.get_request_uuid(),
+                    outcome.get_req_user_uuid(),
+                    result_payload,
+                ));
+            }
+
+            // The backlog is only needed to resume streams, so failing to write it doesn't fail
+            // the tick. It is written before the notifications, so a replay can't miss outcomes.
+            let backlog: Vec<_> = deliveries
+                .iter()
+                .map(|(_, user_uuid, result_payload)| (*user_uuid, result_payload.as_slice()))
+                .collect();
+            if let Err(err) = self
+                .outcome_store_writer
+                .append_to_backlog(next_game_tick, &backlog)
+                .await
+            {
+                tracing::error!(
+                    error = %err,
+                    "Failed to append the outcomes of tick {} to the backlogs.",
+                    next_game_tick
+                );
+            }
+
+            for (request_uuid, user_uuid, result_payload) in deliveries {
                 self.outcome_store_writer
                     .store_outcome(request_uuid, &result_payload)
                     .await?;
@@ -350,6 +507,12 @@ where
             }
         }
 
+        if !world_event_batch.events.is_empty() {
+            let payload = rmp_serde::to_vec(&world_event_batch)
+                .context("Failed to serialize world events for delivery")?;
+            self.outcome_notifier.notify_world_events(&payload).await?;
+        }
+
         self.outcome_notifier
             .notify_game_tick_advanced(next_game_tick)
             .await?;
@@ -1,3 +1,5 @@
+pub mod attack_building;
 pub mod list_units;
 pub mod list_units_by_corporation;
+pub mod move_unit;
 pub mod spawn_unit;
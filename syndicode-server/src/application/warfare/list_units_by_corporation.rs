@@ -1,29 +1,176 @@
 use crate::{
     application::error::ApplicationResult,
-    domain::unit::repository::{ListUnitsOutcome, UnitRepository},
+    domain::{
+        economy::corporation::repository::CorporationRepository,
+        unit::repository::{ListUnitsOutcome, UnitRepository},
+    },
 };
 use bon::{bon, Builder};
 use std::sync::Arc;
 use uuid::Uuid;
 
 #[derive(Builder)]
-pub struct ListUnitsByCorporationUseCase<UNT>
+pub struct ListUnitsByCorporationUseCase<UNT, CRP>
 where
     UNT: UnitRepository,
+    CRP: CorporationRepository,
 {
     unit_repository: Arc<UNT>,
+    corporation_repo: Arc<CRP>,
 }
 
 #[bon]
-impl<UNT> ListUnitsByCorporationUseCase<UNT>
+impl<UNT, CRP> ListUnitsByCorporationUseCase<UNT, CRP>
 where
     UNT: UnitRepository,
+    CRP: CorporationRepository,
 {
+    /// Units of rivals are only listed in full if the user's corporation has planted a mole in
+    /// them. Otherwise only the units stationed in wards it has presence in are spotted, without
+    /// their orders.
     #[builder]
-    pub async fn execute(&self, corporation_uuid: Uuid) -> ApplicationResult<ListUnitsOutcome> {
-        Ok(self
+    pub async fn execute(
+        &self,
+        req_user_uuid: Uuid,
+        corporation_uuid: Uuid,
+    ) -> ApplicationResult<ListUnitsOutcome> {
+        let mut outcome = self
             .unit_repository
             .list_units_by_corporation(corporation_uuid)
-            .await?)
+            .await?;
+
+        let visibility = self
+            .corporation_repo
+            .get_visibility_by_user(req_user_uuid, outcome.game_tick)
+            .await?;
+
+        if !visibility.sees_corporation(&corporation_uuid) {
+            outcome
+                .units
+                .retain(|details| visibility.sees_ward(details.city_code.as_deref()));
+
+            for details in outcome.units.iter_mut() {
+                details.redact_orders();
+            }
+        }
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::corporation::model::visibility::Visibility;
+    use crate::domain::economy::corporation::repository::MockCorporationRepository;
+    use crate::domain::unit::model::{unit_type::UnitType, Unit, UnitDetails};
+    use crate::domain::unit::repository::MockUnitRepository;
+    use mockall::predicate::*;
+    use std::collections::HashSet;
+
+    fn unit_details(corporation_uuid: Uuid, city_code: &str) -> UnitDetails {
+        let mut unit = Unit::spawn(
+            Uuid::now_v7(),
+            corporation_uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        );
+        unit.destination_building_uuid = Some(Uuid::now_v7());
+        unit.arrival_tick = Some(12);
+
+        UnitDetails {
+            unit,
+            building_gml_id: "BLD_ORIGIN".to_string(),
+            destination_building_gml_id: Some("BLD_DESTINATION".to_string()),
+            attack_target_building_gml_id: None,
+            city_code: Some(city_code.to_string()),
+        }
+    }
+
+    fn use_case(
+        rival_uuid: Uuid,
+        visibility: Visibility,
+    ) -> (
+        ListUnitsByCorporationUseCase<MockUnitRepository, MockCorporationRepository>,
+        Uuid,
+    ) {
+        let spotted = unit_details(rival_uuid, "13101");
+        let spotted_uuid = spotted.unit.uuid;
+        let hidden = unit_details(rival_uuid, "13102");
+
+        let mut unit_repo = MockUnitRepository::new();
+        unit_repo
+            .expect_list_units_by_corporation()
+            .with(eq(rival_uuid))
+            .returning(move |_| {
+                Ok(ListUnitsOutcome {
+                    game_tick: 10,
+                    units: vec![spotted.clone(), hidden.clone()],
+                })
+            });
+
+        let mut corporation_repo = MockCorporationRepository::new();
+        corporation_repo
+            .expect_get_visibility_by_user()
+            .with(always(), eq(10))
+            .returning(move |_, _| Ok(visibility.clone()));
+
+        let use_case = ListUnitsByCorporationUseCase::builder()
+            .unit_repository(Arc::new(unit_repo))
+            .corporation_repo(Arc::new(corporation_repo))
+            .build();
+
+        (use_case, spotted_uuid)
+    }
+
+    #[tokio::test]
+    async fn should_only_spot_rival_units_in_wards_with_presence() {
+        let rival_uuid = Uuid::now_v7();
+        let visibility = Visibility {
+            corporation_uuid: Some(Uuid::now_v7()),
+            city_codes: HashSet::from(["13101".to_string()]),
+            ..Visibility::default()
+        };
+        let (use_case, spotted_uuid) = use_case(rival_uuid, visibility);
+
+        let outcome = use_case
+            .execute()
+            .req_user_uuid(Uuid::now_v7())
+            .corporation_uuid(rival_uuid)
+            .call()
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.units.len(), 1);
+        let details = &outcome.units[0];
+        assert_eq!(details.unit.uuid, spotted_uuid);
+        assert_eq!(details.unit.destination_building_uuid, None);
+        assert_eq!(details.unit.arrival_tick, None);
+        assert_eq!(details.destination_building_gml_id, None);
+    }
+
+    #[tokio::test]
+    async fn should_list_all_units_of_an_infiltrated_rival() {
+        let rival_uuid = Uuid::now_v7();
+        let visibility = Visibility {
+            corporation_uuid: Some(Uuid::now_v7()),
+            infiltrated_corporation_uuids: HashSet::from([rival_uuid]),
+            ..Visibility::default()
+        };
+        let (use_case, _) = use_case(rival_uuid, visibility);
+
+        let outcome = use_case
+            .execute()
+            .req_user_uuid(Uuid::now_v7())
+            .corporation_uuid(rival_uuid)
+            .call()
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.units.len(), 2);
+        assert!(outcome
+            .units
+            .iter()
+            .all(|details| details.unit.arrival_tick == Some(12)));
     }
 }
@@ -3,6 +3,7 @@ use crate::application::{
     error::ApplicationResult,
     ports::{game_tick::GameTickRepository, queuer::ActionQueueable},
 };
+use crate::domain::unit::model::unit_type::UnitType;
 use bon::{bon, Builder};
 use std::sync::Arc;
 use uuid::Uuid;
@@ -24,11 +25,20 @@ where
     GTR: GameTickRepository,
 {
     #[builder]
-    pub async fn execute(&self, request_uuid: Uuid, req_user_uuid: Uuid) -> ApplicationResult<i64> {
+    pub async fn execute(
+        &self,
+        request_uuid: Uuid,
+        req_user_uuid: Uuid,
+        unit_type: UnitType,
+        building_gml_id: String,
+    ) -> ApplicationResult<i64> {
         let action = QueuedActionPayload::builder()
             .request_uuid(request_uuid)
             .req_user_uuid(req_user_uuid)
-            .details(ActionDetails::SpawnUnit)
+            .details(ActionDetails::SpawnUnit {
+                unit_type,
+                building_gml_id,
+            })
             .build();
 
         match self.action_queuer.enqueue_action(action).await {
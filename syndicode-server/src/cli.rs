@@ -1,4 +1,4 @@
-use clap::{command, Parser};
+use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -7,4 +7,21 @@ pub struct Cli {
     /// If provided, the application will perform the restore.
     #[arg(long)]
     pub restore: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Rebuilds the state of a tick from its snapshot, re-applies the recorded actions
+    /// of the following ticks and verifies that the results are identical.
+    Replay {
+        /// The tick whose persisted snapshot the replay starts from.
+        #[arg(long)]
+        from_tick: i64,
+        /// The last tick to replay.
+        #[arg(long)]
+        to_tick: i64,
+    },
 }
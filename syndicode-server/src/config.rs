@@ -3,7 +3,10 @@ use bon::Builder;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{application::ports::limiter::LimiterCategory, utils::read_env_var};
+use crate::{
+    application::ports::limiter::LimiterCategory, domain::repository::TickRetention,
+    utils::read_env_var,
+};
 use std::path::Path;
 
 pub const CONFIG_FILE_PATH: &str = "server_config.toml";
@@ -144,6 +147,293 @@ impl Default for ProcessorConfig {
     }
 }
 
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct GameConfig {
+    /// Number of ticks after which an unanswered business offer expires and is refunded.
+    pub business_offer_expiry_ticks: i64,
+    /// Seed of the world. Every tick derives its own seed from it.
+    pub world_seed: u64,
+    /// Number of most recent ticks whose state stays queryable.
+    pub retained_recent_ticks: i64,
+    /// The state of every Nth tick stays queryable as well. Disabled if 0.
+    pub snapshot_interval_ticks: i64,
+    /// Lowest operational expenses a corporation can set for a business.
+    pub min_operational_expenses: i64,
+    /// Highest operational expenses a corporation can set for a business.
+    pub max_operational_expenses: i64,
+    /// Number of ticks before the operational expenses of a business can be changed again.
+    pub operational_expenses_cooldown_ticks: i64,
+    /// Number of ticks a corporation can stay at or below zero cash before it goes bankrupt.
+    pub insolvency_grace_ticks: i64,
+    /// Asking price of the listings created for the businesses of a bankrupt corporation.
+    pub liquidation_asking_price: i64,
+    /// Shortest bidding window a corporation can list a business for auction with.
+    pub min_auction_duration_ticks: i64,
+    /// Longest bidding window a corporation can list a business for auction with.
+    pub max_auction_duration_ticks: i64,
+    /// Number of ticks ahead a contract can be due at most, so escrowed cash doesn't pile up.
+    pub max_contract_duration_ticks: i64,
+}
+
+impl GameConfig {
+    pub fn tick_retention(&self) -> TickRetention {
+        TickRetention {
+            recent_ticks: self.retained_recent_ticks,
+            snapshot_interval: self.snapshot_interval_ticks,
+        }
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            business_offer_expiry_ticks: 200,
+            world_seed: rand::random(),
+            retained_recent_ticks: 2,
+            snapshot_interval_ticks: 0,
+            min_operational_expenses: 0,
+            max_operational_expenses: 100_000,
+            operational_expenses_cooldown_ticks: 10,
+            insolvency_grace_ticks: 20,
+            liquidation_asking_price: 1000,
+            min_auction_duration_ticks: 5,
+            max_auction_duration_ticks: 100,
+            max_contract_duration_ticks: 500,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MarketConfig {
+    /// Volume every market starts with and drifts back to without any investment.
+    pub base_volume: i64,
+    /// Volume a market gains per unit of operational expenses invested by corporations.
+    pub investment_weight: f64,
+    /// Volume a market gains per business owned by a corporation.
+    pub business_weight: i64,
+    /// Share of the gap to its target volume a market closes every tick (0 to 1).
+    pub adjustment_rate: f64,
+    /// Chance of a random shock hitting a market in a tick (0 to 1).
+    pub shock_probability: f64,
+    /// Largest relative change of the volume caused by a random shock.
+    pub max_shock: f64,
+    pub min_volume: i64,
+    pub max_volume: i64,
+}
+
+impl Default for MarketConfig {
+    fn default() -> Self {
+        Self {
+            base_volume: 1000,
+            investment_weight: 0.5,
+            business_weight: 50,
+            adjustment_rate: 0.1,
+            shock_probability: 0.02,
+            max_shock: 0.3,
+            min_volume: 100,
+            max_volume: 1_000_000,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct BankConfig {
+    /// Interest added to the outstanding balance of a loan every tick, in basis points.
+    pub interest_rate_bps: i64,
+    /// Number of ticks after which the bank collects the outstanding balance of a loan.
+    pub loan_term_ticks: i64,
+    /// A business is valued at its expected income over this number of ticks.
+    pub valuation_ticks: i64,
+    /// Share of the value of its businesses a corporation can borrow in total (0 to 1).
+    pub loan_to_value: f64,
+}
+
+impl Default for BankConfig {
+    fn default() -> Self {
+        Self {
+            interest_rate_bps: 5,
+            loan_term_ticks: 500,
+            valuation_ticks: 200,
+            loan_to_value: 0.5,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MarketMakerConfig {
+    /// Number of ticks between two runs of the market maker.
+    pub interval_ticks: i64,
+    /// Price every business is worth regardless of its market and buildings.
+    pub base_price: i64,
+    /// A business is valued at its share of the market volume over this number of ticks.
+    pub valuation_ticks: i64,
+    /// Price added per cubic meter of the buildings a business owns.
+    pub price_per_building_volume: f64,
+    /// Share of its asking price an unsold listing loses on every run (0 to 1).
+    pub price_decay: f64,
+    /// Share of the fair price an unsold listing never drops below (0 to 1).
+    pub min_price_ratio: f64,
+    /// Businesses with at least this fair price are auctioned instead of listed at a fixed
+    /// price. The floor of the decay becomes their reserve price. Disabled if 0.
+    pub auction_min_price: i64,
+    /// Bidding window of the auctions created by the market maker.
+    pub auction_duration_ticks: i64,
+}
+
+impl Default for MarketMakerConfig {
+    fn default() -> Self {
+        Self {
+            interval_ticks: 10,
+            base_price: 500,
+            valuation_ticks: 100,
+            price_per_building_volume: 0.01,
+            price_decay: 0.05,
+            min_price_ratio: 0.25,
+            auction_min_price: 50_000,
+            auction_duration_ticks: 20,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct BuildingConfig {
+    /// Largest distance in meters between a building and the headquarter of the business buying it.
+    pub acquisition_radius_meters: f64,
+    /// Price every building costs regardless of its size.
+    pub base_price: i64,
+    /// Price added per cubic meter of a building.
+    pub price_per_volume: f64,
+    /// Upkeep a business pays per tick and cubic meter of the buildings it owns besides its headquarter.
+    pub upkeep_per_volume: f64,
+    /// Market share weight a business gains per commercial building, in percent of its
+    /// operational expenses.
+    pub market_share_bonus_percent: i64,
+    /// Operational expenses a business can set above the maximum per office, warehouse or factory.
+    pub capacity_bonus: i64,
+}
+
+impl Default for BuildingConfig {
+    fn default() -> Self {
+        Self {
+            acquisition_radius_meters: 500.0,
+            base_price: 1000,
+            price_per_volume: 0.05,
+            upkeep_per_volume: 0.0002,
+            market_share_bonus_percent: 10,
+            capacity_bonus: 10_000,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct TerritoryConfig {
+    /// Share of the owned building volume of a ward a corporation needs to exceed to control it,
+    /// in basis points.
+    pub control_threshold_bps: i64,
+    /// Share of the income of businesses headquartered in a ward that its controlling
+    /// corporation collects from its rivals, in percent.
+    pub tax_percent: i64,
+    /// Discount on units spawned in a ward the corporation controls, in percent.
+    pub unit_spawn_discount_percent: i64,
+    /// Share of the asking price a corporation pays the controller of the ward a business is
+    /// headquartered in when listing it for sale, in percent.
+    pub listing_fee_percent: i64,
+}
+
+impl Default for TerritoryConfig {
+    fn default() -> Self {
+        Self {
+            control_threshold_bps: 5000,
+            tax_percent: 5,
+            unit_spawn_discount_percent: 25,
+            listing_fee_percent: 2,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct EspionageConfig {
+    /// Cash deducted for every attempt to gather intel on a rival.
+    pub gather_intel_cost: i64,
+    /// Chance of an intel operation to succeed, in percent.
+    pub gather_intel_success_percent: i64,
+    /// Cash deducted for every attempt to plant a mole in a rival.
+    pub plant_mole_cost: i64,
+    /// Chance of a mole to be planted successfully, in percent.
+    pub plant_mole_success_percent: i64,
+    /// Cash deducted for a sweep for moles planted in the corporation.
+    pub counter_intel_cost: i64,
+    /// Chance of a sweep to expose each of the moles planted in the corporation, in percent.
+    pub counter_intel_exposure_percent: i64,
+    /// Chance of an operation to be noticed by its target, in percent.
+    pub detection_percent: i64,
+    /// Bonus on the success chance of intel operations against a rival the spying corporation
+    /// has planted a mole in, in percent.
+    pub mole_success_bonus_percent: i64,
+    /// Chance of an intel operation backed by a mole to be noticed by its target, in percent.
+    pub mole_detection_percent: i64,
+}
+
+impl Default for EspionageConfig {
+    fn default() -> Self {
+        Self {
+            gather_intel_cost: 5_000,
+            gather_intel_success_percent: 60,
+            plant_mole_cost: 25_000,
+            plant_mole_success_percent: 40,
+            counter_intel_cost: 15_000,
+            counter_intel_exposure_percent: 50,
+            detection_percent: 30,
+            mole_success_bonus_percent: 30,
+            mole_detection_percent: 5,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct FogOfWarConfig {
+    /// Precision rival operational expenses are rounded down to for corporations without
+    /// presence in their market or ward.
+    pub operational_expenses_precision: i64,
+}
+
+impl Default for FogOfWarConfig {
+    fn default() -> Self {
+        Self {
+            operational_expenses_precision: 5_000,
+        }
+    }
+}
+
+#[derive(Builder, Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct StreamConfig {
+    /// Maximum number of world event subscriptions a user can hold on the game stream.
+    pub max_subscriptions_per_user: usize,
+    /// Number of recent outcomes kept per user to be replayed when the stream is resumed.
+    pub outcome_backlog_size: usize,
+    /// Seconds after the last outcome until a user's backlog expires.
+    pub outcome_backlog_ttl_secs: u64,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            max_subscriptions_per_user: 20,
+            outcome_backlog_size: 500,
+            outcome_backlog_ttl_secs: 3600,
+        }
+    }
+}
+
 #[derive(Builder, Serialize, Deserialize, Debug, Clone)]
 pub struct PostgresConfig {
     pub max_connections: u32,
@@ -215,6 +505,24 @@ pub struct ServerConfig {
     pub bootstrap: BootstrapConfig,
     pub rate_limiter: RateLimiterConfig,
     pub processor: ProcessorConfig,
+    #[serde(default)]
+    pub game: GameConfig,
+    #[serde(default)]
+    pub market: MarketConfig,
+    #[serde(default)]
+    pub bank: BankConfig,
+    #[serde(default)]
+    pub market_maker: MarketMakerConfig,
+    #[serde(default)]
+    pub building: BuildingConfig,
+    #[serde(default)]
+    pub territory: TerritoryConfig,
+    #[serde(default)]
+    pub espionage: EspionageConfig,
+    #[serde(default)]
+    pub fog_of_war: FogOfWarConfig,
+    #[serde(default)]
+    pub stream: StreamConfig,
     pub postgres: PostgresConfig,
     pub valkey: ValkeyConfig,
     pub email: EmailConfig,
@@ -349,6 +657,211 @@ impl ServerConfig {
             config.processor.non_leader_acquisition_retry_internal = val;
         }
 
+        // GameConfig
+        if let Ok(val) = int_from_env("SERVER_BUSINESS_OFFER_EXPIRY_TICKS") {
+            config.game.business_offer_expiry_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_WORLD_SEED") {
+            config.game.world_seed = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_RETAINED_RECENT_TICKS") {
+            config.game.retained_recent_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_SNAPSHOT_INTERVAL_TICKS") {
+            config.game.snapshot_interval_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MIN_OPERATIONAL_EXPENSES") {
+            config.game.min_operational_expenses = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MAX_OPERATIONAL_EXPENSES") {
+            config.game.max_operational_expenses = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_OPERATIONAL_EXPENSES_COOLDOWN_TICKS") {
+            config.game.operational_expenses_cooldown_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_INSOLVENCY_GRACE_TICKS") {
+            config.game.insolvency_grace_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_LIQUIDATION_ASKING_PRICE") {
+            config.game.liquidation_asking_price = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MIN_AUCTION_DURATION_TICKS") {
+            config.game.min_auction_duration_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MAX_AUCTION_DURATION_TICKS") {
+            config.game.max_auction_duration_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MAX_CONTRACT_DURATION_TICKS") {
+            config.game.max_contract_duration_ticks = val;
+        }
+
+        // MarketConfig
+        if let Ok(val) = int_from_env("SERVER_MARKET_BASE_VOLUME") {
+            config.market.base_volume = val;
+        }
+        if let Ok(val) = read_env_var("SERVER_MARKET_INVESTMENT_WEIGHT") {
+            if let Ok(parsed) = val.parse() {
+                config.market.investment_weight = parsed;
+            }
+        }
+        if let Ok(val) = int_from_env("SERVER_MARKET_BUSINESS_WEIGHT") {
+            config.market.business_weight = val;
+        }
+        if let Ok(val) = read_env_var("SERVER_MARKET_ADJUSTMENT_RATE") {
+            if let Ok(parsed) = val.parse() {
+                config.market.adjustment_rate = parsed;
+            }
+        }
+        if let Ok(val) = read_env_var("SERVER_MARKET_SHOCK_PROBABILITY") {
+            if let Ok(parsed) = val.parse() {
+                config.market.shock_probability = parsed;
+            }
+        }
+        if let Ok(val) = read_env_var("SERVER_MARKET_MAX_SHOCK") {
+            if let Ok(parsed) = val.parse() {
+                config.market.max_shock = parsed;
+            }
+        }
+        if let Ok(val) = int_from_env("SERVER_MARKET_MIN_VOLUME") {
+            config.market.min_volume = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MARKET_MAX_VOLUME") {
+            config.market.max_volume = val;
+        }
+
+        // BankConfig
+        if let Ok(val) = int_from_env("SERVER_BANK_INTEREST_RATE_BPS") {
+            config.bank.interest_rate_bps = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_BANK_LOAN_TERM_TICKS") {
+            config.bank.loan_term_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_BANK_VALUATION_TICKS") {
+            config.bank.valuation_ticks = val;
+        }
+        if let Ok(val) = read_env_var("SERVER_BANK_LOAN_TO_VALUE") {
+            if let Ok(parsed) = val.parse() {
+                config.bank.loan_to_value = parsed;
+            }
+        }
+
+        // MarketMakerConfig
+        if let Ok(val) = int_from_env("SERVER_MARKET_MAKER_INTERVAL_TICKS") {
+            config.market_maker.interval_ticks = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MARKET_MAKER_BASE_PRICE") {
+            config.market_maker.base_price = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MARKET_MAKER_VALUATION_TICKS") {
+            config.market_maker.valuation_ticks = val;
+        }
+        if let Ok(val) = read_env_var("SERVER_MARKET_MAKER_PRICE_PER_BUILDING_VOLUME") {
+            if let Ok(parsed) = val.parse() {
+                config.market_maker.price_per_building_volume = parsed;
+            }
+        }
+        if let Ok(val) = read_env_var("SERVER_MARKET_MAKER_PRICE_DECAY") {
+            if let Ok(parsed) = val.parse() {
+                config.market_maker.price_decay = parsed;
+            }
+        }
+        if let Ok(val) = read_env_var("SERVER_MARKET_MAKER_MIN_PRICE_RATIO") {
+            if let Ok(parsed) = val.parse() {
+                config.market_maker.min_price_ratio = parsed;
+            }
+        }
+        if let Ok(val) = int_from_env("SERVER_MARKET_MAKER_AUCTION_MIN_PRICE") {
+            config.market_maker.auction_min_price = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_MARKET_MAKER_AUCTION_DURATION_TICKS") {
+            config.market_maker.auction_duration_ticks = val;
+        }
+
+        // BuildingConfig
+        if let Ok(val) = read_env_var("SERVER_BUILDING_ACQUISITION_RADIUS_METERS") {
+            if let Ok(parsed) = val.parse() {
+                config.building.acquisition_radius_meters = parsed;
+            }
+        }
+        if let Ok(val) = int_from_env("SERVER_BUILDING_BASE_PRICE") {
+            config.building.base_price = val;
+        }
+        if let Ok(val) = read_env_var("SERVER_BUILDING_PRICE_PER_VOLUME") {
+            if let Ok(parsed) = val.parse() {
+                config.building.price_per_volume = parsed;
+            }
+        }
+        if let Ok(val) = read_env_var("SERVER_BUILDING_UPKEEP_PER_VOLUME") {
+            if let Ok(parsed) = val.parse() {
+                config.building.upkeep_per_volume = parsed;
+            }
+        }
+        if let Ok(val) = int_from_env("SERVER_BUILDING_MARKET_SHARE_BONUS_PERCENT") {
+            config.building.market_share_bonus_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_BUILDING_CAPACITY_BONUS") {
+            config.building.capacity_bonus = val;
+        }
+
+        // TerritoryConfig
+        if let Ok(val) = int_from_env("SERVER_TERRITORY_CONTROL_THRESHOLD_BPS") {
+            config.territory.control_threshold_bps = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_TERRITORY_TAX_PERCENT") {
+            config.territory.tax_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_TERRITORY_UNIT_SPAWN_DISCOUNT_PERCENT") {
+            config.territory.unit_spawn_discount_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_TERRITORY_LISTING_FEE_PERCENT") {
+            config.territory.listing_fee_percent = val;
+        }
+
+        // EspionageConfig
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_GATHER_INTEL_COST") {
+            config.espionage.gather_intel_cost = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_GATHER_INTEL_SUCCESS_PERCENT") {
+            config.espionage.gather_intel_success_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_PLANT_MOLE_COST") {
+            config.espionage.plant_mole_cost = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_PLANT_MOLE_SUCCESS_PERCENT") {
+            config.espionage.plant_mole_success_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_COUNTER_INTEL_COST") {
+            config.espionage.counter_intel_cost = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_COUNTER_INTEL_EXPOSURE_PERCENT") {
+            config.espionage.counter_intel_exposure_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_DETECTION_PERCENT") {
+            config.espionage.detection_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_MOLE_SUCCESS_BONUS_PERCENT") {
+            config.espionage.mole_success_bonus_percent = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_ESPIONAGE_MOLE_DETECTION_PERCENT") {
+            config.espionage.mole_detection_percent = val;
+        }
+
+        // FogOfWarConfig
+        if let Ok(val) = int_from_env("SERVER_FOG_OF_WAR_OPERATIONAL_EXPENSES_PRECISION") {
+            config.fog_of_war.operational_expenses_precision = val;
+        }
+
+        // StreamConfig
+        if let Ok(val) = int_from_env("SERVER_STREAM_MAX_SUBSCRIPTIONS_PER_USER") {
+            config.stream.max_subscriptions_per_user = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_STREAM_OUTCOME_BACKLOG_SIZE") {
+            config.stream.outcome_backlog_size = val;
+        }
+        if let Ok(val) = int_from_env("SERVER_STREAM_OUTCOME_BACKLOG_TTL_SECS") {
+            config.stream.outcome_backlog_ttl_secs = val;
+        }
+
         // PostgresConfig
         if let Ok(val) = int_from_env("SERVER_POSTGRES_MAX_CONNECTIONS") {
             config.postgres.max_connections = val;
@@ -1,4 +1,6 @@
+pub mod diplomacy;
 pub mod economy;
+pub mod espionage;
 pub mod outcome;
 pub mod ports;
 pub mod repository;
@@ -6,3 +8,4 @@ pub mod simulation;
 pub mod unit;
 pub mod user;
 pub mod user_verify;
+pub mod world_event;
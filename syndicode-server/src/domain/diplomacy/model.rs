@@ -0,0 +1,73 @@
+pub mod treaty_type;
+
+use bon::Builder;
+use treaty_type::TreatyType;
+use uuid::Uuid;
+
+/// Agreement between two corporations. A proposed treaty only takes effect once the partner has
+/// accepted it, and either party can break it at any time.
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
+pub struct Treaty {
+    pub uuid: Uuid,
+    pub treaty_type: TreatyType,
+    pub proposer_corporation_uuid: Uuid,
+    pub partner_corporation_uuid: Uuid,
+    pub proposed_at_tick: i64,
+    /// The tick the partner has accepted the treaty at. Pending proposals have none.
+    pub accepted_at_tick: Option<i64>,
+}
+
+impl Treaty {
+    pub fn is_active(&self) -> bool {
+        self.accepted_at_tick.is_some()
+    }
+
+    pub fn involves(&self, corporation_uuid: &Uuid) -> bool {
+        self.proposer_corporation_uuid == *corporation_uuid
+            || self.partner_corporation_uuid == *corporation_uuid
+    }
+
+    pub fn is_between(&self, corporation_uuid: &Uuid, other_corporation_uuid: &Uuid) -> bool {
+        self.involves(corporation_uuid)
+            && self.involves(other_corporation_uuid)
+            && corporation_uuid != other_corporation_uuid
+    }
+
+    /// The other party of the treaty, if the given corporation is one of them.
+    pub fn counterparty(&self, corporation_uuid: &Uuid) -> Option<Uuid> {
+        if self.proposer_corporation_uuid == *corporation_uuid {
+            Some(self.partner_corporation_uuid)
+        } else if self.partner_corporation_uuid == *corporation_uuid {
+            Some(self.proposer_corporation_uuid)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_resolve_the_counterparty() {
+        let proposer_uuid = Uuid::now_v7();
+        let partner_uuid = Uuid::now_v7();
+        let outsider_uuid = Uuid::now_v7();
+        let treaty = Treaty {
+            uuid: Uuid::now_v7(),
+            treaty_type: TreatyType::Alliance,
+            proposer_corporation_uuid: proposer_uuid,
+            partner_corporation_uuid: partner_uuid,
+            proposed_at_tick: 1,
+            accepted_at_tick: None,
+        };
+
+        assert_eq!(treaty.counterparty(&proposer_uuid), Some(partner_uuid));
+        assert_eq!(treaty.counterparty(&partner_uuid), Some(proposer_uuid));
+        assert_eq!(treaty.counterparty(&outsider_uuid), None);
+        assert!(treaty.is_between(&partner_uuid, &proposer_uuid));
+        assert!(!treaty.is_between(&proposer_uuid, &outsider_uuid));
+        assert!(!treaty.is_active());
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(i16)]
+pub enum TreatyType {
+    /// The members don't attack each other and share everything they know.
+    Alliance,
+    /// The members don't attack each other.
+    NonAggression,
+    /// The members don't charge each other fees for listing businesses.
+    TradePact,
+}
+
+impl TreatyType {
+    pub fn forbids_attacks(&self) -> bool {
+        matches!(self, TreatyType::Alliance | TreatyType::NonAggression)
+    }
+
+    pub fn shares_intel(&self) -> bool {
+        matches!(self, TreatyType::Alliance)
+    }
+
+    pub fn waives_listing_fee(&self) -> bool {
+        matches!(self, TreatyType::TradePact)
+    }
+}
+
+impl TryFrom<i16> for TreatyType {
+    type Error = String;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Alliance),
+            2 => Ok(Self::NonAggression),
+            3 => Ok(Self::TradePact),
+            _ => Err(format!("Unknown treaty type '{value}'")),
+        }
+    }
+}
+
+impl From<TreatyType> for i16 {
+    fn from(value: TreatyType) -> Self {
+        match value {
+            TreatyType::Alliance => 1,
+            TreatyType::NonAggression => 2,
+            TreatyType::TradePact => 3,
+        }
+    }
+}
+
+impl Display for TreatyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TreatyType::Alliance => write!(f, "Alliance"),
+            TreatyType::NonAggression => write!(f, "NonAggression"),
+            TreatyType::TradePact => write!(f, "TradePact"),
+        }
+    }
+}
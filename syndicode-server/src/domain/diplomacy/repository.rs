@@ -0,0 +1,30 @@
+use super::model::Treaty;
+use crate::domain::repository::RepositoryResult;
+use tonic::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait TreatyRepository: Send + Sync {
+    async fn list_treaties_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Treaty>>;
+}
+
+#[async_trait]
+pub trait TreatyTxRepository: Send + Sync {
+    async fn insert_treaties_in_tick(
+        &mut self,
+        game_tick: i64,
+        treaties: &[Treaty],
+    ) -> RepositoryResult<()>;
+
+    async fn supersede_treaties_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_treaties_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
+}
@@ -1,7 +1,12 @@
+pub mod auction_bid;
 pub mod building;
 pub mod building_ownership;
 pub mod business;
 pub mod business_listing;
 pub mod business_offer;
+pub mod contract;
 pub mod corporation;
+pub mod ledger;
+pub mod loan;
 pub mod market;
+pub mod territory;
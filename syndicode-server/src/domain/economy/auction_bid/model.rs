@@ -0,0 +1,14 @@
+use bon::Builder;
+use uuid::Uuid;
+
+/// Sealed bid of a corporation on an auction listing. The amount is held in escrow until the auction closes.
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
+pub struct AuctionBid {
+    pub uuid: Uuid,
+    pub business_listing_uuid: Uuid,
+    pub business_uuid: Uuid,
+    pub bidding_corporation_uuid: Uuid,
+    pub amount: i64,
+    /// Among equal bids, the earliest one wins.
+    pub placed_at_tick: i64,
+}
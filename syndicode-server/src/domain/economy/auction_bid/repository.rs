@@ -0,0 +1,30 @@
+use super::model::AuctionBid;
+use crate::domain::repository::RepositoryResult;
+use tonic::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AuctionBidRepository: Send + Sync {
+    async fn list_auction_bids_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<AuctionBid>>;
+}
+
+#[async_trait]
+pub trait AuctionBidTxRepository: Send + Sync {
+    async fn insert_auction_bids_in_tick(
+        &mut self,
+        game_tick: i64,
+        auction_bids: &[AuctionBid],
+    ) -> RepositoryResult<()>;
+
+    async fn supersede_auction_bids_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_auction_bids_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
+}
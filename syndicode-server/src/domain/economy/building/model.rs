@@ -20,3 +20,60 @@ pub struct Building {
     pub height: f64,
     pub volume: f64,
 }
+
+/// Lightweight view of a building, used by the simulation to position units and value businesses.
+#[derive(Builder, Debug, Clone, PartialEq)]
+pub struct BuildingLocation {
+    pub uuid: Uuid,
+    pub gml_id: String,
+    pub longitude: f64,
+    pub latitude: f64,
+    pub volume: f64,
+    pub usage_code: Option<String>,
+    /// Code of the ward the building is located in.
+    pub city_code: Option<String>,
+}
+
+impl BuildingLocation {
+    pub fn center(&self) -> Point<f64> {
+        Point::new(self.longitude, self.latitude)
+    }
+
+    /// Price of the building at the given rates.
+    pub fn price(&self, base_price: i64, price_per_volume: f64) -> i64 {
+        base_price + (self.volume * price_per_volume).round() as i64
+    }
+
+    /// Upkeep of the building per tick at the given rate.
+    pub fn upkeep(&self, upkeep_per_volume: f64) -> i64 {
+        (self.volume * upkeep_per_volume).round() as i64
+    }
+
+    pub fn bonus(&self) -> Option<BuildingBonus> {
+        self.usage_code
+            .as_deref()
+            .and_then(BuildingBonus::from_usage_code)
+    }
+}
+
+/// Bonus a building grants to the business owning it, depending on what the building is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuildingBonus {
+    /// Shops attract customers, so the business gains market share.
+    MarketShare,
+    /// Offices, warehouses and factories let the business put more operational expenses to use.
+    Capacity,
+}
+
+impl BuildingBonus {
+    /// Maps a PLATEAU usage code (`bldg:usage`) to the bonus it grants.
+    pub fn from_usage_code(usage_code: &str) -> Option<Self> {
+        match usage_code {
+            // Commercial facility, commercial complex, residences with shops
+            "402" | "404" | "413" | "414" => Some(Self::MarketShare),
+            // Office, transport and warehouse facility, factory
+            "401" | "431" | "441" => Some(Self::Capacity),
+            _ => None,
+        }
+    }
+}
@@ -4,7 +4,7 @@ use uuid::Uuid;
 
 use crate::domain::repository::RepositoryResult;
 
-use super::model::Building;
+use super::model::{Building, BuildingLocation};
 
 #[derive(Builder, Clone, PartialEq)]
 pub struct QueryBuildingsRequest {
@@ -15,6 +15,9 @@ pub struct QueryBuildingsRequest {
     pub min_lat: Option<f64>,
     pub max_lat: Option<f64>,
     pub limit: Option<i64>,
+    pub at_game_tick: Option<i64>,
+    /// Only owned buildings unless set to false.
+    pub is_owned: Option<bool>,
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -22,6 +25,9 @@ pub struct BuildingDetails {
     pub gml_id: String,
     pub longitude: f64,
     pub latitude: f64,
+    pub volume: f64,
+    pub usage_code: Option<String>,
+    pub owning_business_uuid: Option<Uuid>,
 }
 
 #[async_trait]
@@ -30,6 +36,15 @@ pub trait BuildingRepository: Send + Sync {
         &self,
         req: QueryBuildingsRequest,
     ) -> RepositoryResult<(i64, Vec<BuildingDetails>)>;
+    /// Locations of the buildings the game state refers to at the given tick.
+    async fn list_building_locations(
+        &self,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<BuildingLocation>>;
+    async fn list_building_locations_by_gml_ids(
+        &self,
+        gml_ids: Vec<String>,
+    ) -> RepositoryResult<Vec<BuildingLocation>>;
 }
 
 #[async_trait]
@@ -1,7 +1,7 @@
 use bon::Builder;
 use uuid::Uuid;
 
-#[derive(Builder, Clone, Copy)]
+#[derive(Builder, Clone, Copy, Hash)]
 pub struct BuildingOwnership {
     pub building_uuid: Uuid,
     pub owning_business_uuid: Uuid,
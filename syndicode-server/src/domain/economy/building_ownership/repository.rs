@@ -1,4 +1,5 @@
 use tonic::async_trait;
+use uuid::Uuid;
 
 use crate::domain::repository::RepositoryResult;
 
@@ -20,8 +21,15 @@ pub trait BuildingOwnershipTxRepository: Send + Sync {
         building_ownerships: &[BuildingOwnership],
     ) -> RepositoryResult<()>;
 
+    async fn supersede_building_ownerships_in_tick(
+        &mut self,
+        game_tick: i64,
+        building_uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
     async fn delete_building_ownerships_before_tick(
         &mut self,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64>;
 }
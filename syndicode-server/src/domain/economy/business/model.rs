@@ -2,7 +2,7 @@ use bon::Builder;
 use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
-#[derive(Builder, Clone, FromRow)]
+#[derive(Builder, Clone, Hash, FromRow)]
 pub struct Business {
     pub uuid: Uuid,
     pub market_uuid: Uuid,
@@ -11,4 +11,6 @@ pub struct Business {
     pub operational_expenses: i64,
     pub headquarter_building_uuid: Uuid,
     pub image_number: i16,
+    /// Game tick in which the corporation last changed the operational expenses.
+    pub operational_expenses_changed_at_tick: Option<i64>,
 }
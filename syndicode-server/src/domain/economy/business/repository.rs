@@ -1,5 +1,8 @@
 use super::model::Business;
-use crate::domain::repository::{DomainSortDirection, RepositoryResult};
+use crate::domain::{
+    economy::corporation::model::visibility::Visibility,
+    repository::{DomainSortDirection, RepositoryResult},
+};
 use bon::Builder;
 use tonic::async_trait;
 use uuid::Uuid;
@@ -20,6 +23,8 @@ impl Default for &DomainBusinessSortBy {
 
 #[derive(Builder, Clone, PartialEq)]
 pub struct QueryBusinessesRequest {
+    /// The user whose corporation decides which operational expenses are in sight.
+    pub req_user_uuid: Uuid,
     pub owning_corporation_uuid: Option<Uuid>,
     pub market_uuid: Option<Uuid>,
     pub min_operational_expenses: Option<i64>,
@@ -28,6 +33,17 @@ pub struct QueryBusinessesRequest {
     pub sort_direction: Option<DomainSortDirection>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    pub at_game_tick: Option<i64>,
+    /// Filters and sorting by operational expenses only distinguish multiples of it for
+    /// businesses out of sight, so they can't be used to narrow down figures hidden by fog of war.
+    pub operational_expenses_precision: Option<i64>,
+}
+
+pub struct QueryBusinessesOutcome {
+    pub game_tick: i64,
+    /// What the requesting user's corporation could see at the game tick.
+    pub visibility: Visibility,
+    pub businesses: Vec<BusinessDetails>,
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -42,8 +58,30 @@ pub struct BusinessDetails {
     pub headquarter_building_gml_id: String,
     pub headquarter_longitude: f64,
     pub headquarter_latitude: f64,
+    pub headquarter_city_code: Option<String>,
     pub image_number: i16,
     pub market_number: i16,
+    /// Whether the operational expenses have been rounded for a rival out of sight.
+    pub operational_expenses_estimated: bool,
+}
+
+impl BusinessDetails {
+    /// Rounds the operational expenses down to the given precision unless the business is in
+    /// sight of the requesting corporation.
+    pub fn redact_for(&mut self, visibility: &Visibility, precision: i64) {
+        if precision <= 1
+            || visibility.sees_business(
+                self.owning_corporation_uuid.as_ref(),
+                &self.market_uuid,
+                self.headquarter_city_code.as_deref(),
+            )
+        {
+            return;
+        }
+
+        self.operational_expenses = self.operational_expenses.div_euclid(precision) * precision;
+        self.operational_expenses_estimated = true;
+    }
 }
 
 #[async_trait]
@@ -51,7 +89,7 @@ pub trait BusinessRepository: Send + Sync {
     async fn query_businesses(
         &self,
         req: &QueryBusinessesRequest,
-    ) -> RepositoryResult<(i64, Vec<BusinessDetails>)>;
+    ) -> RepositoryResult<QueryBusinessesOutcome>;
 
     async fn list_businesses_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Business>>;
 }
@@ -64,5 +102,15 @@ pub trait BusinessTxRepository: Send + Sync {
         businesses: &[Business],
     ) -> RepositoryResult<()>;
 
-    async fn delete_businesses_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64>;
+    async fn supersede_businesses_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_businesses_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
 }
@@ -1,10 +1,20 @@
 use bon::Builder;
 use uuid::Uuid;
 
-#[derive(Builder, Debug, Clone, Copy, PartialEq)]
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
 pub struct BusinessListing {
     pub uuid: Uuid,
     pub business_uuid: Uuid,
     pub seller_corporation_uuid: Option<Uuid>,
+    /// Fixed price of the business, or the reserve price if the listing is an auction.
     pub asking_price: i64,
+    /// Listings with a closing tick are sealed-bid auctions. Bids are collected until this tick
+    /// has been processed and the business goes to the highest one.
+    pub auction_closes_at_tick: Option<i64>,
+}
+
+impl BusinessListing {
+    pub fn is_auction(&self) -> bool {
+        self.auction_closes_at_tick.is_some()
+    }
 }
@@ -27,10 +27,12 @@ pub struct QueryBusinessListingsRequest {
     pub seller_corporation_uuid: Option<Uuid>,
     pub min_operational_expenses: Option<i64>,
     pub max_operational_expenses: Option<i64>,
+    pub is_auction: Option<bool>,
     pub sort_by: Option<DomainBusinessListingSortBy>,
     pub sort_direction: Option<DomainSortDirection>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    pub at_game_tick: Option<i64>,
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -42,6 +44,7 @@ pub struct BusinessListingDetails {
     pub business_name: String,
     pub seller_corporation_uuid: Option<Uuid>,
     pub asking_price: i64,
+    pub auction_closes_at_tick: Option<i64>,
     pub operational_expenses: i64,
     // Headquarter building information for map display
     pub headquarter_building_gml_id: String,
@@ -72,8 +75,15 @@ pub trait BusinessListingTxRepository: Send + Sync {
         business_listings: &[BusinessListing],
     ) -> RepositoryResult<()>;
 
+    async fn supersede_business_listings_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
     async fn delete_business_listings_before_tick(
         &mut self,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64>;
 }
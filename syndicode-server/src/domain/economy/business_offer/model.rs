@@ -1,10 +1,13 @@
+use bon::Builder;
 use uuid::Uuid;
 
-#[derive(Clone, Copy)]
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
 pub struct BusinessOffer {
     pub uuid: Uuid,
     pub business_uuid: Uuid,
     pub offering_corporation_uuid: Uuid,
     pub target_corporation_uuid: Option<Uuid>,
     pub offer_price: i64,
+    /// The offer is withdrawn automatically once this tick has been processed.
+    pub expires_at_tick: i64,
 }
@@ -1,6 +1,7 @@
 use super::model::BusinessOffer;
 use crate::domain::repository::RepositoryResult;
 use tonic::async_trait;
+use uuid::Uuid;
 
 #[async_trait]
 pub trait BusinessOfferRepository: Send + Sync {
@@ -18,6 +19,15 @@ pub trait BusinessOfferTxRepository: Send + Sync {
         business_offers: &[BusinessOffer],
     ) -> RepositoryResult<()>;
 
-    async fn delete_business_offers_before_tick(&mut self, game_tick: i64)
-        -> RepositoryResult<u64>;
+    async fn supersede_business_offers_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_business_offers_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
 }
@@ -0,0 +1,20 @@
+pub mod condition;
+
+use bon::Builder;
+use condition::ContractCondition;
+use uuid::Uuid;
+
+/// Cash a corporation has put into escrow to pay another corporation at a later tick.
+/// Once the due tick is processed, the payee receives the amount if the condition holds
+/// and the payer gets it back otherwise.
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
+pub struct Contract {
+    pub uuid: Uuid,
+    pub payer_corporation_uuid: Uuid,
+    pub payee_corporation_uuid: Uuid,
+    /// Amount held in escrow until the contract is settled.
+    pub amount: i64,
+    pub condition: ContractCondition,
+    pub created_at_tick: i64,
+    pub due_at_tick: i64,
+}
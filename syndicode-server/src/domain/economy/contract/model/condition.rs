@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use uuid::Uuid;
+
+/// What has to hold when a contract is due for the payee to be paid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ContractCondition {
+    /// The payee is paid in any case.
+    Unconditional,
+    /// The payee is paid if the payer owns the business by then, e.g. after the payee has sold it.
+    PayerOwnsBusiness { business_uuid: Uuid },
+    /// The payee is paid if an alliance or non-aggression pact between the parties is in effect.
+    PeaceHolds,
+}
+
+impl ContractCondition {
+    /// Stable code of the condition kind, as persisted in the database.
+    pub fn kind(&self) -> i16 {
+        match self {
+            ContractCondition::Unconditional => 1,
+            ContractCondition::PayerOwnsBusiness { .. } => 2,
+            ContractCondition::PeaceHolds => 3,
+        }
+    }
+
+    pub fn business_uuid(&self) -> Option<Uuid> {
+        match self {
+            ContractCondition::PayerOwnsBusiness { business_uuid } => Some(*business_uuid),
+            ContractCondition::Unconditional | ContractCondition::PeaceHolds => None,
+        }
+    }
+
+    /// Restores a condition from its kind and the business it refers to.
+    pub fn from_parts(kind: i16, business_uuid: Option<Uuid>) -> Result<Self, String> {
+        match (kind, business_uuid) {
+            (1, _) => Ok(Self::Unconditional),
+            (2, Some(business_uuid)) => Ok(Self::PayerOwnsBusiness { business_uuid }),
+            (2, None) => Err("Contract condition 'PayerOwnsBusiness' without business".to_string()),
+            (3, _) => Ok(Self::PeaceHolds),
+            _ => Err(format!("Unknown contract condition '{kind}'")),
+        }
+    }
+}
+
+impl Display for ContractCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContractCondition::Unconditional => write!(f, "Unconditional"),
+            ContractCondition::PayerOwnsBusiness { business_uuid } => {
+                write!(f, "PayerOwnsBusiness({business_uuid})")
+            }
+            ContractCondition::PeaceHolds => write!(f, "PeaceHolds"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_through_its_parts() {
+        let conditions = [
+            ContractCondition::Unconditional,
+            ContractCondition::PayerOwnsBusiness {
+                business_uuid: Uuid::now_v7(),
+            },
+            ContractCondition::PeaceHolds,
+        ];
+
+        for condition in conditions {
+            assert_eq!(
+                ContractCondition::from_parts(condition.kind(), condition.business_uuid()),
+                Ok(condition)
+            );
+        }
+        assert!(ContractCondition::from_parts(2, None).is_err());
+        assert!(ContractCondition::from_parts(0, None).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use super::model::Contract;
+use crate::domain::repository::RepositoryResult;
+use tonic::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ContractRepository: Send + Sync {
+    async fn list_contracts_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Contract>>;
+}
+
+#[async_trait]
+pub trait ContractTxRepository: Send + Sync {
+    async fn insert_contracts_in_tick(
+        &mut self,
+        game_tick: i64,
+        contracts: &[Contract],
+    ) -> RepositoryResult<()>;
+
+    async fn supersede_contracts_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_contracts_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
+}
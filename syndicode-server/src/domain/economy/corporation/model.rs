@@ -1,27 +1,30 @@
 pub mod name;
+pub mod visibility;
 
 use name::CorporationName;
 use serde::{Deserialize, Serialize};
-use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
 const DEFAULT_BALANCE: i64 = 1000000;
 
-#[derive(Serialize, Deserialize, Debug, Clone, FromRow)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash)]
 pub struct Corporation {
     pub uuid: Uuid,
     pub user_uuid: Uuid,
     pub name: CorporationName,
     pub cash_balance: i64,
+    /// Game tick since which the cash balance has been at or below zero.
+    pub insolvent_since_tick: Option<i64>,
 }
 
 impl Corporation {
-    pub fn new(user_uuid: Uuid, name: CorporationName) -> Self {
+    pub fn new(uuid: Uuid, user_uuid: Uuid, name: CorporationName) -> Self {
         Self {
-            uuid: Uuid::now_v7(),
+            uuid,
             user_uuid,
             name,
             cash_balance: DEFAULT_BALANCE,
+            insolvent_since_tick: None,
         }
     }
 }
@@ -2,7 +2,7 @@ use crate::application::error::{ApplicationError, ApplicationResult};
 use serde::{Deserialize, Serialize};
 use std::{fmt::Display, ops::Deref};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Hash)]
 pub struct CorporationName(String);
 
 impl CorporationName {
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// What a corporation can see of its rivals. Public facts are known to everyone, but precise
+/// figures are only in sight where the corporation has presence of its own, i.e. a business in
+/// the same market or buildings in the same ward, and everywhere in a rival it has planted a
+/// mole in or is allied with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Visibility {
+    /// The corporation of the requesting user, if it has one.
+    pub corporation_uuid: Option<Uuid>,
+    /// Markets the corporation runs businesses in.
+    pub market_uuids: HashSet<Uuid>,
+    /// City codes of the wards the corporation owns buildings in.
+    pub city_codes: HashSet<String>,
+    /// Rivals the corporation has planted a mole in.
+    pub infiltrated_corporation_uuids: HashSet<Uuid>,
+    /// Corporations the corporation has an alliance with.
+    pub allied_corporation_uuids: HashSet<Uuid>,
+}
+
+impl Visibility {
+    /// Whether everything about the given corporation is in sight, which is the case for the
+    /// corporation itself, its allies and the rivals it has infiltrated.
+    pub fn sees_corporation(&self, corporation_uuid: &Uuid) -> bool {
+        self.corporation_uuid.as_ref() == Some(corporation_uuid)
+            || self
+                .infiltrated_corporation_uuids
+                .contains(corporation_uuid)
+            || self.allied_corporation_uuids.contains(corporation_uuid)
+    }
+
+    pub fn sees_ward(&self, city_code: Option<&str>) -> bool {
+        city_code.is_some_and(|city_code| self.city_codes.contains(city_code))
+    }
+
+    /// Businesses without an owner keep no secrets.
+    pub fn sees_business(
+        &self,
+        owning_corporation_uuid: Option<&Uuid>,
+        market_uuid: &Uuid,
+        city_code: Option<&str>,
+    ) -> bool {
+        owning_corporation_uuid.is_none_or(|corporation_uuid| {
+            self.sees_corporation(corporation_uuid)
+                || self.market_uuids.contains(market_uuid)
+                || self.sees_ward(city_code)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_see_rival_businesses_only_with_presence_a_mole_or_an_alliance() {
+        let own_uuid = Uuid::now_v7();
+        let rival_uuid = Uuid::now_v7();
+        let infiltrated_uuid = Uuid::now_v7();
+        let ally_uuid = Uuid::now_v7();
+        let shared_market_uuid = Uuid::now_v7();
+        let other_market_uuid = Uuid::now_v7();
+
+        let visibility = Visibility {
+            corporation_uuid: Some(own_uuid),
+            market_uuids: HashSet::from([shared_market_uuid]),
+            city_codes: HashSet::from(["13101".to_string()]),
+            infiltrated_corporation_uuids: HashSet::from([infiltrated_uuid]),
+            allied_corporation_uuids: HashSet::from([ally_uuid]),
+        };
+
+        assert!(visibility.sees_business(Some(&own_uuid), &other_market_uuid, None));
+        assert!(visibility.sees_business(None, &other_market_uuid, None));
+        assert!(visibility.sees_business(Some(&infiltrated_uuid), &other_market_uuid, None));
+        assert!(visibility.sees_business(Some(&ally_uuid), &other_market_uuid, None));
+        assert!(visibility.sees_business(Some(&rival_uuid), &shared_market_uuid, None));
+        assert!(visibility.sees_business(Some(&rival_uuid), &other_market_uuid, Some("13101")));
+        assert!(!visibility.sees_business(Some(&rival_uuid), &other_market_uuid, Some("13102")));
+        assert!(!visibility.sees_business(Some(&rival_uuid), &other_market_uuid, None));
+    }
+
+    #[test]
+    fn should_see_nothing_private_without_a_corporation() {
+        let rival_uuid = Uuid::now_v7();
+        let visibility = Visibility::default();
+
+        assert!(!visibility.sees_corporation(&rival_uuid));
+        assert!(!visibility.sees_business(Some(&rival_uuid), &Uuid::now_v7(), Some("13101")));
+    }
+}
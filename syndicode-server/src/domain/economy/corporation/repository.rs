@@ -4,7 +4,7 @@ use mockall::{automock, predicate::*};
 use tonic::async_trait;
 use uuid::Uuid;
 
-use super::model::Corporation;
+use super::model::{visibility::Visibility, Corporation};
 use crate::domain::repository::RepositoryResult;
 
 pub struct GetCorporationOutcome {
@@ -18,6 +18,7 @@ pub trait CorporationRepository: Send + Sync {
     async fn get_corporation_by_user(
         &self,
         user_uuid: Uuid,
+        at_game_tick: Option<i64>,
     ) -> RepositoryResult<GetCorporationOutcome>;
 
     async fn get_corporation_by_name(
@@ -25,6 +26,14 @@ pub trait CorporationRepository: Send + Sync {
         corporation_name: String,
     ) -> RepositoryResult<Corporation>;
 
+    /// What the corporation of the user can see of its rivals at the given tick. Users without
+    /// a corporation see nothing but public facts.
+    async fn get_visibility_by_user(
+        &self,
+        user_uuid: Uuid,
+        game_tick: i64,
+    ) -> RepositoryResult<Visibility>;
+
     async fn list_corporations_in_tick(&self, game_tick: i64)
         -> RepositoryResult<Vec<Corporation>>;
 }
@@ -39,5 +48,15 @@ pub trait CorporationTxRepository: Send + Sync {
         corporations: &[Corporation],
     ) -> RepositoryResult<()>;
 
-    async fn delete_corporations_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64>;
+    async fn supersede_corporations_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_corporations_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
 }
@@ -0,0 +1,85 @@
+pub mod entry_kind;
+
+use bon::Builder;
+use entry_kind::LedgerEntryKind;
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::Uuid;
+
+/// A single movement of a corporation's cash within a tick.
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash, FromRow)]
+pub struct LedgerEntry {
+    pub game_tick: i64,
+    /// Position of the entry within its tick, in the order the cash moved.
+    pub sequence: i32,
+    pub corporation_uuid: Uuid,
+    #[sqlx(try_from = "i16")]
+    pub kind: LedgerEntryKind,
+    /// Change of the cash balance, negative for expenses.
+    pub amount: i64,
+    /// The business the cash movement relates to.
+    pub business_uuid: Option<Uuid>,
+}
+
+/// Totals of a corporation's ledger entries, grouped by what moved the cash.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LedgerSummary {
+    pub business_income: i64,
+    pub operational_expenses: i64,
+    pub acquisitions: i64,
+    pub sales: i64,
+    pub unit_spawns: i64,
+    /// Cash put into escrow for offers, bids and contracts minus the refunds of closed ones.
+    pub escrow: i64,
+    /// Cash paid out for loans minus the repayments.
+    pub loans: i64,
+    /// Prices and upkeep paid for buildings.
+    pub buildings: i64,
+    /// Territory tax and listing fees collected from rivals minus the ones paid to them.
+    pub territory_tax: i64,
+    /// Costs of espionage operations.
+    pub espionage: i64,
+    /// Cash received from other corporations minus the cash sent to them.
+    pub transfers: i64,
+}
+
+impl LedgerSummary {
+    pub fn add(&mut self, entry: &LedgerEntry) {
+        let total = match entry.kind {
+            LedgerEntryKind::BusinessIncome => &mut self.business_income,
+            LedgerEntryKind::OperationalExpenses => &mut self.operational_expenses,
+            LedgerEntryKind::BusinessAcquisition => &mut self.acquisitions,
+            LedgerEntryKind::BusinessSale => &mut self.sales,
+            LedgerEntryKind::UnitSpawn => &mut self.unit_spawns,
+            LedgerEntryKind::OfferEscrow
+            | LedgerEntryKind::OfferRefund
+            | LedgerEntryKind::AuctionEscrow
+            | LedgerEntryKind::AuctionRefund
+            | LedgerEntryKind::ContractEscrow
+            | LedgerEntryKind::ContractRefund => &mut self.escrow,
+            LedgerEntryKind::LoanDisbursement | LedgerEntryKind::LoanRepayment => &mut self.loans,
+            LedgerEntryKind::BuildingAcquisition | LedgerEntryKind::BuildingUpkeep => {
+                &mut self.buildings
+            }
+            LedgerEntryKind::TerritoryTax | LedgerEntryKind::ListingFee => &mut self.territory_tax,
+            LedgerEntryKind::Espionage => &mut self.espionage,
+            LedgerEntryKind::Transfer | LedgerEntryKind::ContractPayment => &mut self.transfers,
+        };
+        *total += entry.amount;
+    }
+
+    /// The change of the cash balance these entries add up to.
+    pub fn net_change(&self) -> i64 {
+        self.business_income
+            + self.operational_expenses
+            + self.acquisitions
+            + self.sales
+            + self.unit_spawns
+            + self.escrow
+            + self.loans
+            + self.buildings
+            + self.territory_tax
+            + self.espionage
+            + self.transfers
+    }
+}
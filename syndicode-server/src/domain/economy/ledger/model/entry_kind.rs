@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(i16)]
+pub enum LedgerEntryKind {
+    /// Share of the market volume earned by a business.
+    BusinessIncome,
+    /// Operational expenses paid for a business.
+    OperationalExpenses,
+    /// Price paid for a listed business.
+    BusinessAcquisition,
+    /// Price received for a sold business, either listed or through an accepted offer.
+    BusinessSale,
+    /// Cost of spawning a unit.
+    UnitSpawn,
+    /// Offer price held in escrow while a business offer is open.
+    OfferEscrow,
+    /// Escrowed offer price returned after an offer has been withdrawn, rejected or has expired.
+    OfferRefund,
+    /// Cash paid out by the bank for a loan.
+    LoanDisbursement,
+    /// Cash paid back to the bank for a loan.
+    LoanRepayment,
+    /// Bid held in escrow while an auction is open.
+    AuctionEscrow,
+    /// Escrowed bid returned after an auction has closed or has been cancelled.
+    AuctionRefund,
+    /// Price paid for a building.
+    BuildingAcquisition,
+    /// Upkeep paid for the buildings of a business besides its headquarter.
+    BuildingUpkeep,
+    /// Tax on business income collected by the corporation controlling the ward of the business.
+    TerritoryTax,
+    /// Cost of an espionage operation, paid whether it succeeds or not.
+    Espionage,
+    /// Cash sent to or received from another corporation.
+    Transfer,
+    /// Contract amount held in escrow until the contract is due.
+    ContractEscrow,
+    /// Escrowed contract amount returned to the payer because the condition did not hold.
+    ContractRefund,
+    /// Escrowed contract amount paid out to the payee.
+    ContractPayment,
+    /// Fee for listing a business, paid to the corporation controlling the ward of its headquarter.
+    ListingFee,
+}
+
+impl TryFrom<i16> for LedgerEntryKind {
+    type Error = String;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::BusinessIncome),
+            2 => Ok(Self::OperationalExpenses),
+            3 => Ok(Self::BusinessAcquisition),
+            4 => Ok(Self::BusinessSale),
+            5 => Ok(Self::UnitSpawn),
+            6 => Ok(Self::OfferEscrow),
+            7 => Ok(Self::OfferRefund),
+            8 => Ok(Self::LoanDisbursement),
+            9 => Ok(Self::LoanRepayment),
+            10 => Ok(Self::AuctionEscrow),
+            11 => Ok(Self::AuctionRefund),
+            12 => Ok(Self::BuildingAcquisition),
+            13 => Ok(Self::BuildingUpkeep),
+            14 => Ok(Self::TerritoryTax),
+            15 => Ok(Self::Espionage),
+            16 => Ok(Self::Transfer),
+            17 => Ok(Self::ContractEscrow),
+            18 => Ok(Self::ContractRefund),
+            19 => Ok(Self::ContractPayment),
+            20 => Ok(Self::ListingFee),
+            _ => Err(format!("Unknown ledger entry kind '{value}'")),
+        }
+    }
+}
+
+impl From<LedgerEntryKind> for i16 {
+    fn from(value: LedgerEntryKind) -> Self {
+        match value {
+            LedgerEntryKind::BusinessIncome => 1,
+            LedgerEntryKind::OperationalExpenses => 2,
+            LedgerEntryKind::BusinessAcquisition => 3,
+            LedgerEntryKind::BusinessSale => 4,
+            LedgerEntryKind::UnitSpawn => 5,
+            LedgerEntryKind::OfferEscrow => 6,
+            LedgerEntryKind::OfferRefund => 7,
+            LedgerEntryKind::LoanDisbursement => 8,
+            LedgerEntryKind::LoanRepayment => 9,
+            LedgerEntryKind::AuctionEscrow => 10,
+            LedgerEntryKind::AuctionRefund => 11,
+            LedgerEntryKind::BuildingAcquisition => 12,
+            LedgerEntryKind::BuildingUpkeep => 13,
+            LedgerEntryKind::TerritoryTax => 14,
+            LedgerEntryKind::Espionage => 15,
+            LedgerEntryKind::Transfer => 16,
+            LedgerEntryKind::ContractEscrow => 17,
+            LedgerEntryKind::ContractRefund => 18,
+            LedgerEntryKind::ContractPayment => 19,
+            LedgerEntryKind::ListingFee => 20,
+        }
+    }
+}
+
+impl Display for LedgerEntryKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LedgerEntryKind::BusinessIncome => write!(f, "Business Income"),
+            LedgerEntryKind::OperationalExpenses => write!(f, "Operational Expenses"),
+            LedgerEntryKind::BusinessAcquisition => write!(f, "Business Acquisition"),
+            LedgerEntryKind::BusinessSale => write!(f, "Business Sale"),
+            LedgerEntryKind::UnitSpawn => write!(f, "Unit Spawn"),
+            LedgerEntryKind::OfferEscrow => write!(f, "Offer Escrow"),
+            LedgerEntryKind::OfferRefund => write!(f, "Offer Refund"),
+            LedgerEntryKind::LoanDisbursement => write!(f, "Loan Disbursement"),
+            LedgerEntryKind::LoanRepayment => write!(f, "Loan Repayment"),
+            LedgerEntryKind::AuctionEscrow => write!(f, "Auction Escrow"),
+            LedgerEntryKind::AuctionRefund => write!(f, "Auction Refund"),
+            LedgerEntryKind::BuildingAcquisition => write!(f, "Building Acquisition"),
+            LedgerEntryKind::BuildingUpkeep => write!(f, "Building Upkeep"),
+            LedgerEntryKind::TerritoryTax => write!(f, "Territory Tax"),
+            LedgerEntryKind::Espionage => write!(f, "Espionage"),
+            LedgerEntryKind::Transfer => write!(f, "Transfer"),
+            LedgerEntryKind::ContractEscrow => write!(f, "Contract Escrow"),
+            LedgerEntryKind::ContractRefund => write!(f, "Contract Refund"),
+            LedgerEntryKind::ContractPayment => write!(f, "Contract Payment"),
+            LedgerEntryKind::ListingFee => write!(f, "Listing Fee"),
+        }
+    }
+}
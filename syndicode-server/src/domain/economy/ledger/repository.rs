@@ -0,0 +1,38 @@
+use super::model::LedgerEntry;
+use crate::domain::repository::RepositoryResult;
+use bon::Builder;
+use tonic::async_trait;
+use uuid::Uuid;
+
+#[derive(Builder, Clone, PartialEq)]
+pub struct QueryLedgerEntriesRequest {
+    pub corporation_uuid: Uuid,
+    /// First game tick to include (inclusive).
+    pub from_game_tick: Option<i64>,
+    /// Last game tick to include (inclusive).
+    pub to_game_tick: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[async_trait]
+pub trait LedgerRepository: Send + Sync {
+    /// Lists the matching entries, most recent first, along with the total number of matches.
+    async fn query_ledger_entries(
+        &self,
+        req: &QueryLedgerEntriesRequest,
+    ) -> RepositoryResult<(i64, Vec<LedgerEntry>)>;
+}
+
+#[async_trait]
+pub trait LedgerTxRepository: Send + Sync {
+    /// Appends the entries of a processed tick. Entries are never updated.
+    async fn insert_ledger_entries(&mut self, entries: &[LedgerEntry]) -> RepositoryResult<()>;
+
+    /// Deletes the entries of the ticks before the given one, except for the snapshot ticks.
+    async fn delete_ledger_entries_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
+}
@@ -0,0 +1,25 @@
+use bon::Builder;
+use uuid::Uuid;
+
+/// Debt of a corporation towards the NPC bank.
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
+pub struct Loan {
+    pub uuid: Uuid,
+    pub corporation_uuid: Uuid,
+    /// Amount paid out to the corporation when the loan was taken.
+    pub principal: i64,
+    /// Amount still owed, including the accrued interest.
+    pub outstanding_balance: i64,
+    /// Interest added to the outstanding balance every tick, in basis points.
+    pub interest_rate_bps: i64,
+    pub taken_at_tick: i64,
+    /// The bank collects the outstanding balance once this tick has been processed.
+    pub due_at_tick: i64,
+}
+
+impl Loan {
+    /// Interest accrued on the outstanding balance within one tick, rounded up.
+    pub fn interest_per_tick(&self) -> i64 {
+        (self.outstanding_balance * self.interest_rate_bps + 9_999) / 10_000
+    }
+}
@@ -0,0 +1,30 @@
+use super::model::Loan;
+use crate::domain::repository::RepositoryResult;
+use tonic::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait LoanRepository: Send + Sync {
+    async fn list_loans_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Loan>>;
+}
+
+#[async_trait]
+pub trait LoanTxRepository: Send + Sync {
+    async fn insert_loans_in_tick(
+        &mut self,
+        game_tick: i64,
+        loans: &[Loan],
+    ) -> RepositoryResult<()>;
+
+    async fn supersede_loans_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_loans_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
+}
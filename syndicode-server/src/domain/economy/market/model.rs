@@ -2,11 +2,26 @@ pub mod name;
 
 use bon::Builder;
 use name::MarketName;
+use sqlx::prelude::FromRow;
 use uuid::Uuid;
 
-#[derive(Builder, Clone)]
+#[derive(Builder, Clone, Hash)]
 pub struct Market {
     pub uuid: Uuid,
     pub name: MarketName,
     pub volume: i64,
 }
+
+/// A market as it was at the end of a tick, kept as the market's history.
+#[derive(Builder, Debug, Clone, Copy, PartialEq, FromRow)]
+pub struct MarketSnapshot {
+    pub game_tick: i64,
+    pub market_uuid: Uuid,
+    #[sqlx(try_from = "i16")]
+    pub market_name: MarketName,
+    pub volume: i64,
+    /// Operational expenses of all businesses in the market that are owned by a corporation.
+    pub invested_operational_expenses: i64,
+    /// Number of businesses in the market that are owned by a corporation.
+    pub active_businesses: i64,
+}
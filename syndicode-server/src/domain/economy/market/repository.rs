@@ -1,11 +1,30 @@
+use bon::Builder;
 use tonic::async_trait;
+use uuid::Uuid;
 
-use super::model::Market;
+use super::model::{Market, MarketSnapshot};
 use crate::domain::repository::RepositoryResult;
 
+#[derive(Builder, Clone, PartialEq)]
+pub struct QueryMarketsRequest {
+    pub market_uuid: Option<Uuid>,
+    /// First game tick to include (inclusive).
+    pub from_game_tick: Option<i64>,
+    /// Last game tick to include (inclusive).
+    pub to_game_tick: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 #[async_trait]
 pub trait MarketRepository: Send + Sync {
     async fn list_markets_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Market>>;
+
+    /// Lists the matching market snapshots, most recent first, along with the total number of matches.
+    async fn query_market_snapshots(
+        &self,
+        req: &QueryMarketsRequest,
+    ) -> RepositoryResult<(i64, Vec<MarketSnapshot>)>;
 }
 
 #[async_trait]
@@ -16,5 +35,21 @@ pub trait MarketTxRepository: Send + Sync {
         markets: &[Market],
     ) -> RepositoryResult<()>;
 
-    async fn delete_markets_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64>;
+    async fn supersede_markets_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_markets_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
+
+    /// Appends the market snapshots of a processed tick. Snapshots are never updated.
+    async fn insert_market_snapshots(
+        &mut self,
+        snapshots: &[MarketSnapshot],
+    ) -> RepositoryResult<()>;
 }
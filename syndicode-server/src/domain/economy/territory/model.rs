@@ -0,0 +1,156 @@
+use geo::Polygon;
+use uuid::Uuid;
+
+/// One of the special wards of Tokyo, identified by the city code of its buildings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ward {
+    pub city_code: String,
+    pub name: String,
+    /// Hull around the buildings of the ward.
+    pub boundary: Polygon,
+}
+
+/// Building volume a corporation owns within a ward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ControlShare {
+    pub corporation_uuid: Uuid,
+    pub volume: i64,
+    /// Part of the owned building volume of the ward, in basis points.
+    pub share_bps: i64,
+}
+
+/// Control of a ward, derived from the buildings the corporations own in it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Territory {
+    pub city_code: String,
+    /// Building volume owned by all corporations together.
+    pub owned_volume: i64,
+    /// Shares of the corporations, the largest first.
+    pub shares: Vec<ControlShare>,
+    /// The corporation holding more than the control threshold of the owned volume.
+    pub controlling_corporation_uuid: Option<Uuid>,
+}
+
+impl Territory {
+    /// Ranks the corporations by the volume they own in the ward. Ties are broken by UUID,
+    /// so the result does not depend on the order of the volumes.
+    pub fn from_volumes(
+        city_code: String,
+        volumes: impl IntoIterator<Item = (Uuid, i64)>,
+        control_threshold_bps: i64,
+    ) -> Self {
+        let mut volumes: Vec<(Uuid, i64)> = volumes
+            .into_iter()
+            .filter(|(_, volume)| *volume > 0)
+            .collect();
+        volumes.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let owned_volume: i64 = volumes.iter().map(|(_, volume)| volume).sum();
+
+        let shares: Vec<ControlShare> = volumes
+            .into_iter()
+            .map(|(corporation_uuid, volume)| ControlShare {
+                corporation_uuid,
+                volume,
+                share_bps: volume * 10_000 / owned_volume,
+            })
+            .collect();
+
+        let controlling_corporation_uuid = shares
+            .first()
+            .filter(|share| share.share_bps > control_threshold_bps)
+            .map(|share| share.corporation_uuid);
+
+        Self {
+            city_code,
+            owned_volume,
+            shares,
+            controlling_corporation_uuid,
+        }
+    }
+
+    pub fn is_controlled_by(&self, corporation_uuid: &Uuid) -> bool {
+        self.controlling_corporation_uuid.as_ref() == Some(corporation_uuid)
+    }
+
+    /// Only the controlling corporation has informants in every corner of the ward. Anybody
+    /// else merely learns who controls it and how much they own themselves.
+    pub fn redact_for(&mut self, corporation_uuid: Option<Uuid>) {
+        if let Some(corporation_uuid) = corporation_uuid {
+            if self.is_controlled_by(&corporation_uuid) {
+                return;
+            }
+        }
+
+        self.shares.retain(|share| {
+            Some(share.corporation_uuid) == corporation_uuid
+                || Some(share.corporation_uuid) == self.controlling_corporation_uuid
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_grant_control_above_the_threshold() {
+        let leader = Uuid::now_v7();
+        let runner_up = Uuid::now_v7();
+
+        let territory = Territory::from_volumes(
+            "13101".to_string(),
+            [(runner_up, 3_000), (leader, 7_000)],
+            5_000,
+        );
+
+        assert_eq!(territory.owned_volume, 10_000);
+        assert_eq!(territory.controlling_corporation_uuid, Some(leader));
+        assert_eq!(
+            territory
+                .shares
+                .iter()
+                .map(|share| (share.corporation_uuid, share.share_bps))
+                .collect::<Vec<_>>(),
+            vec![(leader, 7_000), (runner_up, 3_000)]
+        );
+    }
+
+    #[test]
+    fn should_leave_contested_ward_uncontrolled() {
+        let territory = Territory::from_volumes(
+            "13101".to_string(),
+            [(Uuid::now_v7(), 5_000), (Uuid::now_v7(), 5_000)],
+            5_000,
+        );
+
+        assert_eq!(territory.controlling_corporation_uuid, None);
+    }
+
+    #[test]
+    fn should_only_reveal_all_shares_to_the_controlling_corporation() {
+        let leader = Uuid::now_v7();
+        let runner_up = Uuid::now_v7();
+        let outsider = Uuid::now_v7();
+        let territory = Territory::from_volumes(
+            "13101".to_string(),
+            [(leader, 6_000), (runner_up, 3_000), (outsider, 1_000)],
+            5_000,
+        );
+
+        let mut seen_by_leader = territory.clone();
+        seen_by_leader.redact_for(Some(leader));
+        let mut seen_by_outsider = territory.clone();
+        seen_by_outsider.redact_for(Some(outsider));
+
+        assert_eq!(seen_by_leader, territory);
+        assert_eq!(
+            seen_by_outsider
+                .shares
+                .iter()
+                .map(|share| share.corporation_uuid)
+                .collect::<Vec<_>>(),
+            vec![leader, outsider]
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use super::model::Ward;
+use crate::domain::repository::RepositoryResult;
+use tonic::async_trait;
+use uuid::Uuid;
+
+/// Building volume a corporation owns within a ward.
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct WardVolume {
+    pub city_code: String,
+    pub corporation_uuid: Uuid,
+    pub volume: i64,
+}
+
+#[async_trait]
+pub trait TerritoryRepository: Send + Sync {
+    async fn list_wards(&self) -> RepositoryResult<Vec<Ward>>;
+
+    /// The owned building volume per ward and corporation at the given tick, or the latest one.
+    async fn list_ward_volumes(
+        &self,
+        at_game_tick: Option<i64>,
+    ) -> RepositoryResult<(i64, Vec<WardVolume>)>;
+}
+
+#[async_trait]
+pub trait TerritoryTxRepository: Send + Sync {
+    /// Derives the wards from the city codes and locations of the imported buildings.
+    async fn insert_wards_from_buildings(&mut self) -> RepositoryResult<()>;
+}
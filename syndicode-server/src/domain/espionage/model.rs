@@ -0,0 +1,14 @@
+pub mod intel;
+
+use bon::Builder;
+use uuid::Uuid;
+
+/// Informant a corporation has planted in a rival. Makes intel operations against the rival more
+/// likely to succeed and less likely to be noticed until it is exposed by counter-intelligence.
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
+pub struct Mole {
+    pub uuid: Uuid,
+    pub spy_corporation_uuid: Uuid,
+    pub target_corporation_uuid: Uuid,
+    pub planted_at_tick: i64,
+}
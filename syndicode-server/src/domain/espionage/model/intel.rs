@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use uuid::Uuid;
+
+/// What an intel operation reveals about its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IntelType {
+    /// The cash balance.
+    Balance,
+    /// The businesses owned.
+    Holdings,
+    /// The businesses listed for sale.
+    Listings,
+}
+
+impl Display for IntelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntelType::Balance => write!(f, "Balance"),
+            IntelType::Holdings => write!(f, "Holdings"),
+            IntelType::Listings => write!(f, "Listings"),
+        }
+    }
+}
+
+/// Snapshot of a rival taken by a successful intel operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IntelReport {
+    Balance { cash_balance: i64 },
+    Holdings { business_uuids: Vec<Uuid> },
+    Listings { listings: Vec<ListingIntel> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ListingIntel {
+    pub business_listing_uuid: Uuid,
+    pub business_uuid: Uuid,
+    pub asking_price: i64,
+    pub auction_closes_at_tick: Option<i64>,
+}
@@ -0,0 +1,30 @@
+use super::model::Mole;
+use crate::domain::repository::RepositoryResult;
+use tonic::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait MoleRepository: Send + Sync {
+    async fn list_moles_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Mole>>;
+}
+
+#[async_trait]
+pub trait MoleTxRepository: Send + Sync {
+    async fn insert_moles_in_tick(
+        &mut self,
+        game_tick: i64,
+        moles: &[Mole],
+    ) -> RepositoryResult<()>;
+
+    async fn supersede_moles_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_moles_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
+}
@@ -1,9 +1,17 @@
+use super::{
+    diplomacy::model::treaty_type::TreatyType,
+    economy::{contract::model::condition::ContractCondition, ledger::model::LedgerSummary},
+    espionage::model::intel::{IntelReport, IntelType},
+    unit::model::unit_type::UnitType,
+};
+use fnv::FnvHasher;
 use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
 use uuid::Uuid;
 
 /// Represents the specific outcome of processing a domain action,
 /// including data needed for the final response and routing.
-#[derive(Debug, Clone, Serialize, Deserialize)] // Serializable for Redis store
+#[derive(Debug, Clone, Hash, Serialize, Deserialize)] // Serializable for Redis store
 pub enum DomainActionOutcome {
     CorporationCreated {
         request_uuid: Uuid,
@@ -38,6 +46,377 @@ pub enum DomainActionOutcome {
         req_user_uuid: Uuid,
         corporation_uuid: Uuid,
         unit_uuid: Uuid,
+        unit_type: UnitType,
+        health: i32,
+        attack: i32,
+        defense: i32,
+        building_gml_id: String,
+        spawn_cost: i64,
+    },
+    UnitMoved {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        unit_uuid: Uuid,
+        unit_type: UnitType,
+        health: i32,
+        attack: i32,
+        defense: i32,
+        building_gml_id: String,
+        destination_building_gml_id: String,
+        arrival_tick: i64,
+    },
+    BuildingAttacked {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        building_gml_id: String,
+        unit_uuids: Vec<Uuid>,
+        arrival_tick: i64,
+    },
+    BusinessListed {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_listing_uuid: Uuid,
+        business_uuid: Uuid,
+        seller_corporation_uuid: Uuid,
+        asking_price: i64,
+        auction_closes_at_tick: Option<i64>,
+    },
+    BusinessListingCancelled {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_listing_uuid: Uuid,
+        business_uuid: Uuid,
+    },
+    BusinessOfferMade {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+        business_uuid: Uuid,
+        offering_corporation_uuid: Uuid,
+        target_corporation_uuid: Uuid,
+        offer_price: i64,
+        expires_at_tick: i64,
+    },
+    BusinessOfferAccepted {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+        business_uuid: Uuid,
+        market_uuid: Uuid,
+        owning_corporation_uuid: Uuid,
+        name: String,
+        operational_expenses: i64,
+        image_number: i16,
+        offer_price: i64,
+    },
+    BusinessOfferRejected {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+    },
+    BusinessOfferWithdrawn {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+        refunded_amount: i64,
+    },
+    OperationalExpensesSet {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_uuid: Uuid,
+        operational_expenses: i64,
+        previous_operational_expenses: i64,
+    },
+    LoanTaken {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        loan_uuid: Uuid,
+        principal: i64,
+        interest_rate_bps: i64,
+        due_at_tick: i64,
+    },
+    LoanRepaid {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        loan_uuid: Uuid,
+        amount: i64,
+        outstanding_balance: i64,
+    },
+    AuctionBidPlaced {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        auction_bid_uuid: Uuid,
+        business_listing_uuid: Uuid,
+        business_uuid: Uuid,
+        amount: i64,
+    },
+    BuildingAcquired {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_uuid: Uuid,
+        building_gml_id: String,
+        price: i64,
+        upkeep: i64,
+    },
+    IntelGathered {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        target_corporation_uuid: Uuid,
+        intel_type: IntelType,
+        cost: i64,
+        /// Only set if the operation succeeded.
+        report: Option<IntelReport>,
+        detected: bool,
+    },
+    MolePlanted {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        target_corporation_uuid: Uuid,
+        cost: i64,
+        /// Only set if the mole has been planted.
+        mole_uuid: Option<Uuid>,
+        detected: bool,
+    },
+    CounterIntelConducted {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        cost: i64,
+        exposed_moles: Vec<ExposedMole>,
+    },
+    TreatyProposed {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        treaty_uuid: Uuid,
+        treaty_type: TreatyType,
+        partner_corporation_uuid: Uuid,
+    },
+    TreatyAccepted {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        treaty_uuid: Uuid,
+        treaty_type: TreatyType,
+        proposer_corporation_uuid: Uuid,
+    },
+    TreatyBroken {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        treaty_uuid: Uuid,
+        treaty_type: TreatyType,
+        counterparty_corporation_uuid: Uuid,
+    },
+    FundsTransferred {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        recipient_corporation_uuid: Uuid,
+        amount: i64,
+    },
+    ContractCreated {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        contract_uuid: Uuid,
+        payer_corporation_uuid: Uuid,
+        payee_corporation_uuid: Uuid,
+        amount: i64,
+        condition: ContractCondition,
+        due_at_tick: i64,
+    },
+    /// Push Notifications (not triggered by an action of the receiving user,
+    /// `req_user_uuid` holds the recipient and `request_uuid` is generated by the server)
+    BusinessOfferReceived {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+        business_uuid: Uuid,
+        offering_corporation_uuid: Uuid,
+        target_corporation_uuid: Uuid,
+        offer_price: i64,
+        expires_at_tick: i64,
+    },
+    BusinessOfferClosed {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+        business_uuid: Uuid,
+        refunded_amount: i64,
+        reason: BusinessOfferCloseReason,
+    },
+    UnitArrived {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        unit_uuid: Uuid,
+        unit_type: UnitType,
+        health: i32,
+        attack: i32,
+        defense: i32,
+        building_gml_id: String,
+        attack_target_building_gml_id: Option<String>,
+    },
+    BattleReported {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        building_gml_id: String,
+        business_uuid: Uuid,
+        defender_corporation_uuid: Uuid,
+        attacker_corporation_uuids: Vec<Uuid>,
+        destroyed_units: Vec<DestroyedUnit>,
+        result: BattleResult,
+        captured_business_uuid: Option<Uuid>,
+        capturing_corporation_uuid: Option<Uuid>,
+    },
+    CorporationLedgerSummarized {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        summary: LedgerSummary,
+        cash_balance: i64,
+    },
+    CorporationInsolvent {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        cash_balance: i64,
+        bankruptcy_at_tick: i64,
+    },
+    CorporationBankrupt {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        liquidated_business_uuids: Vec<Uuid>,
+        disbanded_unit_uuids: Vec<Uuid>,
+    },
+    LoanDefaulted {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        loan_uuid: Uuid,
+        collected_amount: i64,
+        written_off_amount: i64,
+        liquidated_business_uuids: Vec<Uuid>,
+    },
+    AuctionClosed {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        business_listing_uuid: Uuid,
+        business_uuid: Uuid,
+        winning_corporation_uuid: Option<Uuid>,
+        winning_bid: Option<i64>,
+        refunded_amount: i64,
+        reason: AuctionCloseReason,
+    },
+    EspionageDetected {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        spy_corporation_uuid: Uuid,
+        operation: EspionageOperation,
+    },
+    MoleExposed {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        mole_uuid: Uuid,
+        target_corporation_uuid: Uuid,
+    },
+    TreatyProposalReceived {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        treaty_uuid: Uuid,
+        treaty_type: TreatyType,
+        proposer_corporation_uuid: Uuid,
+    },
+    TreatyConcluded {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        treaty_uuid: Uuid,
+        treaty_type: TreatyType,
+        partner_corporation_uuid: Uuid,
+    },
+    TreatyTerminated {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        treaty_uuid: Uuid,
+        treaty_type: TreatyType,
+        breaking_corporation_uuid: Uuid,
+        /// Whether the treaty was in effect or still a pending proposal.
+        was_active: bool,
+    },
+    FundsReceived {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        sender_corporation_uuid: Uuid,
+        amount: i64,
+    },
+    ContractReceived {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        contract_uuid: Uuid,
+        payer_corporation_uuid: Uuid,
+        payee_corporation_uuid: Uuid,
+        amount: i64,
+        condition: ContractCondition,
+        due_at_tick: i64,
+    },
+    /// Sent to both parties of a contract.
+    ContractSettled {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        contract_uuid: Uuid,
+        payer_corporation_uuid: Uuid,
+        payee_corporation_uuid: Uuid,
+        amount: i64,
+        /// Whether the payee has been paid. Otherwise the amount went back to the payer.
+        paid: bool,
+    },
+    /// Sent after every tick to each corporation whose own state has changed during it,
+    /// so clients don't have to query its businesses and units again.
+    /// The cash balance is part of the ledger summary instead.
+    CorporationStateChanged {
+        request_uuid: Uuid,
+        tick_effective: i64,
+        req_user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        businesses_gained: Vec<Uuid>,
+        businesses_lost: Vec<Uuid>,
+        listings_sold: Vec<Uuid>,
+        units_updated: Vec<ChangedUnit>,
+        units_removed: Vec<Uuid>,
     },
     /// Failure Cases (Reportable failures)
     ActionFailed {
@@ -45,9 +424,111 @@ pub enum DomainActionOutcome {
         req_user_uuid: Uuid,
         tick_processed: i64,
         reason: String,
+        code: ActionFailureCode,
+        details: Option<ActionFailureDetails>,
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+pub enum BusinessOfferCloseReason {
+    Rejected,
+    Expired,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+pub enum AuctionCloseReason {
+    Sold,
+    Unsold,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+pub enum EspionageOperation {
+    GatherIntel { intel_type: IntelType },
+    PlantMole,
+}
+
+/// Stable category of a failed action, so clients don't have to parse the reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ActionFailureCode {
+    Internal,
+    InvalidArgument,
+    NotFound,
+    NotOwner,
+    AlreadyOwned,
+    AlreadyExists,
+    InsufficientFunds,
+    CreditLimitExceeded,
+    Cooldown,
+    InvalidState,
+    ContestLost,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ActionFailureDetails {
+    InsufficientFunds {
+        corporation_uuid: Uuid,
+        required: i64,
+        available: i64,
+    },
+    InvalidValue {
+        provided: i64,
+        min: Option<i64>,
+        max: Option<i64>,
+    },
+    Entity {
+        entity_type: FailureEntityType,
+        entity_id: String,
+    },
+    Cooldown {
+        available_at_tick: i64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FailureEntityType {
+    Corporation,
+    Business,
+    BusinessListing,
+    BusinessOffer,
+    Unit,
+    Building,
+    Loan,
+    Treaty,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+pub enum BattleResult {
+    Ongoing,
+    AttackersWon,
+    DefendersWon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+pub struct DestroyedUnit {
+    pub unit_uuid: Uuid,
+    pub corporation_uuid: Uuid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ExposedMole {
+    pub mole_uuid: Uuid,
+    pub spy_corporation_uuid: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ChangedUnit {
+    pub unit_uuid: Uuid,
+    pub unit_type: UnitType,
+    pub health: i32,
+    pub attack: i32,
+    pub defense: i32,
+    pub building_gml_id: String,
+    pub destination_building_gml_id: Option<String>,
+    pub arrival_tick: Option<i64>,
+    pub attack_target_building_gml_id: Option<String>,
+}
+
 impl DomainActionOutcome {
     pub fn get_req_user_uuid(&self) -> Uuid {
         match self {
@@ -55,6 +536,47 @@ impl DomainActionOutcome {
             DomainActionOutcome::CorporationDeleted { req_user_uuid, .. } => *req_user_uuid,
             DomainActionOutcome::ListedBusinessAcquired { req_user_uuid, .. } => *req_user_uuid,
             DomainActionOutcome::UnitSpawned { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::UnitMoved { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BuildingAttacked { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessListed { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessListingCancelled { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessOfferMade { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessOfferAccepted { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessOfferRejected { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessOfferWithdrawn { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::OperationalExpensesSet { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::LoanTaken { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::LoanRepaid { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::AuctionBidPlaced { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BuildingAcquired { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::IntelGathered { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::MolePlanted { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::CounterIntelConducted { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::TreatyProposed { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::TreatyAccepted { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::TreatyBroken { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::FundsTransferred { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::ContractCreated { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessOfferReceived { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BusinessOfferClosed { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::UnitArrived { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::BattleReported { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::CorporationLedgerSummarized { req_user_uuid, .. } => {
+                *req_user_uuid
+            }
+            DomainActionOutcome::CorporationInsolvent { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::CorporationBankrupt { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::LoanDefaulted { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::AuctionClosed { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::EspionageDetected { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::MoleExposed { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::TreatyProposalReceived { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::TreatyConcluded { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::TreatyTerminated { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::FundsReceived { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::ContractReceived { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::ContractSettled { req_user_uuid, .. } => *req_user_uuid,
+            DomainActionOutcome::CorporationStateChanged { req_user_uuid, .. } => *req_user_uuid,
             DomainActionOutcome::ActionFailed { req_user_uuid, .. } => *req_user_uuid,
         }
     }
@@ -65,7 +587,74 @@ impl DomainActionOutcome {
             DomainActionOutcome::CorporationDeleted { request_uuid, .. } => *request_uuid,
             DomainActionOutcome::ListedBusinessAcquired { request_uuid, .. } => *request_uuid,
             DomainActionOutcome::UnitSpawned { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::UnitMoved { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BuildingAttacked { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessListed { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessListingCancelled { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessOfferMade { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessOfferAccepted { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessOfferRejected { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessOfferWithdrawn { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::OperationalExpensesSet { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::LoanTaken { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::LoanRepaid { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::AuctionBidPlaced { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BuildingAcquired { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::IntelGathered { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::MolePlanted { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::CounterIntelConducted { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::TreatyProposed { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::TreatyAccepted { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::TreatyBroken { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::FundsTransferred { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::ContractCreated { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessOfferReceived { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BusinessOfferClosed { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::UnitArrived { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::BattleReported { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::CorporationLedgerSummarized { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::CorporationInsolvent { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::CorporationBankrupt { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::LoanDefaulted { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::AuctionClosed { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::EspionageDetected { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::MoleExposed { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::TreatyProposalReceived { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::TreatyConcluded { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::TreatyTerminated { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::FundsReceived { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::ContractReceived { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::ContractSettled { request_uuid, .. } => *request_uuid,
+            DomainActionOutcome::CorporationStateChanged { request_uuid, .. } => *request_uuid,
             DomainActionOutcome::ActionFailed { request_uuid, .. } => *request_uuid,
         }
     }
 }
+
+/// Fingerprint of the outcomes of a tick, in the order they were produced.
+pub fn outcomes_checksum(outcomes: &[DomainActionOutcome]) -> u64 {
+    let mut hasher = FnvHasher::default();
+    outcomes.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_outcomes_checksum_stable() {
+        let outcomes = vec![DomainActionOutcome::CorporationCreated {
+            request_uuid: Uuid::from_u128(1),
+            tick_effective: 42,
+            req_user_uuid: Uuid::from_u128(2),
+            corporation_uuid: Uuid::from_u128(3),
+            user_uuid: Uuid::from_u128(2),
+            corporation_name: "Arasaka".to_string(),
+            corporation_balance: 1_000_000,
+        }];
+
+        // Changing the value breaks the replay of action logs recorded by the same checksum version.
+        assert_eq!(outcomes_checksum(&outcomes), 7390356070153547258);
+    }
+}
@@ -4,14 +4,19 @@ use crate::{
 };
 
 pub trait Simulationable: Send + Sync {
+    /// The seed all ids and randomness of the given tick are derived from.
+    fn tick_seed(&self, next_game_tick: i64) -> u64;
+
     /// Calculates the state for the next tick (N+1) based on
     /// the current state (N) and the actions submitted during tick N.
     /// It returns the new state and a vector of specific outcomes generated
     /// by processing the actions.
-    /// This MUST be deterministic.
+    /// This MUST be deterministic: the same seed, actions and state always
+    /// produce the same outcomes and the same new state.
     fn calculate_next_state(
         &self,
         next_game_tick: i64,
+        seed: u64,
         queued_actions: Vec<QueuedAction>,
         action_ids: &mut Vec<String>,
         state: &mut GameState,
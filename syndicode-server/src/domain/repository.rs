@@ -37,6 +37,31 @@ impl From<DomainSortDirection> for i16 {
     }
 }
 
+/// Which past game ticks keep a queryable state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickRetention {
+    /// Number of most recent ticks that stay queryable, including the latest one.
+    pub recent_ticks: i64,
+    /// Every tick divisible by this interval stays queryable as well. Disabled if not positive.
+    pub snapshot_interval: i64,
+}
+
+impl TickRetention {
+    /// The oldest tick of the recent window, given the latest processed tick.
+    pub fn keep_from_tick(&self, latest_game_tick: i64) -> i64 {
+        latest_game_tick - self.recent_ticks.max(1) + 1
+    }
+
+    pub fn is_retained(&self, game_tick: i64, latest_game_tick: i64) -> bool {
+        if game_tick < 0 || game_tick > latest_game_tick {
+            return false;
+        }
+
+        game_tick >= self.keep_from_tick(latest_game_tick)
+            || (self.snapshot_interval > 0 && game_tick % self.snapshot_interval == 0)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum RepositoryError {
     #[error("The provided user name has already been taken")]
@@ -48,6 +73,9 @@ pub enum RepositoryError {
     #[error("The database failed to return a result for the provided query")]
     NotFound,
 
+    #[error("The state of game tick {0} is not retained")]
+    GameTickNotRetained(i64),
+
     #[error(transparent)]
     Migration(#[from] sqlx::migrate::MigrateError),
 
@@ -57,3 +85,35 @@ pub enum RepositoryError {
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_retain_recent_ticks_and_snapshots() {
+        let retention = TickRetention {
+            recent_ticks: 3,
+            snapshot_interval: 10,
+        };
+
+        assert!(retention.is_retained(25, 25));
+        assert!(retention.is_retained(23, 25));
+        assert!(!retention.is_retained(22, 25));
+        assert!(retention.is_retained(20, 25));
+        assert!(retention.is_retained(0, 25));
+        assert!(!retention.is_retained(26, 25));
+    }
+
+    #[test]
+    fn should_retain_only_the_latest_tick_without_snapshots() {
+        let retention = TickRetention {
+            recent_ticks: 0,
+            snapshot_interval: 0,
+        };
+
+        assert!(retention.is_retained(7, 7));
+        assert!(!retention.is_retained(6, 7));
+        assert!(!retention.is_retained(0, 7));
+    }
+}
@@ -1,18 +1,50 @@
+pub mod changes;
+mod conflicts;
 pub mod game_state;
 mod handlers;
 mod processors;
+pub mod rng;
 mod saga;
+pub mod state_diff;
 
-use super::{outcome::DomainActionOutcome, ports::simulation::Simulationable};
-use crate::application::action::{ActionDetails, QueuedAction};
-use bon::builder;
+use super::{
+    diplomacy::model::treaty_type::TreatyType,
+    outcome::{ActionFailureCode, ActionFailureDetails, DomainActionOutcome, FailureEntityType},
+    ports::simulation::Simulationable,
+};
+use crate::{
+    application::action::{ActionDetails, QueuedAction},
+    config::ServerConfig,
+};
+use bon::{builder, Builder};
+use conflicts::order_actions;
 use game_state::GameState;
 use handlers::{
+    accept_business_offer::handle_accept_business_offer, accept_treaty::handle_accept_treaty,
+    acquire_building::handle_acquire_building,
     acquire_listed_business::handle_acquire_listed_business,
-    create_corporation::handle_create_corporation, delete_corporation::handle_delete_corporation,
-    spawn_unit::handle_spawn_unit,
+    attack_building::handle_attack_building, break_treaty::handle_break_treaty,
+    cancel_business_listing::handle_cancel_business_listing, counter_intel::handle_counter_intel,
+    create_contract::handle_create_contract, create_corporation::handle_create_corporation,
+    delete_corporation::handle_delete_corporation, gather_intel::handle_gather_intel,
+    list_business_for_sale::handle_list_business_for_sale,
+    make_business_offer::handle_make_business_offer, move_unit::handle_move_unit,
+    place_auction_bid::handle_place_auction_bid, plant_mole::handle_plant_mole,
+    propose_treaty::handle_propose_treaty, reject_business_offer::handle_reject_business_offer,
+    repay_loan::handle_repay_loan, set_operational_expenses::handle_set_operational_expenses,
+    spawn_unit::handle_spawn_unit, take_loan::handle_take_loan,
+    transfer_funds::handle_transfer_funds, withdraw_business_offer::handle_withdraw_business_offer,
+};
+use processors::{
+    building_upkeep::charge_building_upkeep, business_income::calculate_business_income,
+    business_offer_expiry::expire_business_offers, close_auctions::close_auctions,
+    combat::resolve_combat, contract_settlement::settle_contracts,
+    insolvency::resolve_insolvencies, ledger_summary::summarize_ledger, loans::service_loans,
+    market_dynamics::evolve_market_volumes, market_maker::make_market,
+    territory_tax::collect_territory_tax, unit_movement::advance_unit_movements,
 };
-use processors::business_income::calculate_business_income;
+use rng::SimulationRng;
+use std::{collections::HashSet, sync::Arc};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -30,9 +62,224 @@ pub enum ActionError {
     #[error("Unit '{unit_uuid}' not found.")]
     UnitNotFound { unit_uuid: Uuid },
 
+    #[error("Business '{business_uuid}' is not owned by corporation '{corporation_uuid}'.")]
+    BusinessNotOwnedByCorporation {
+        business_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Business '{business_uuid}' is already listed for sale.")]
+    BusinessAlreadyListed { business_uuid: Uuid },
+
+    #[error("Business listing '{listing_uuid}' is not owned by corporation '{corporation_uuid}'.")]
+    BusinessListingNotOwnedByCorporation {
+        listing_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Asking price must be greater than zero. Provided: {asking_price}.")]
+    InvalidAskingPrice { asking_price: i64 },
+
     #[error("Business offer '{offer_uuid}' not found.")]
     BusinessOfferNotFound { offer_uuid: Uuid },
 
+    #[error("Offer price must be greater than zero. Provided: {offer_price}.")]
+    InvalidOfferPrice { offer_price: i64 },
+
+    #[error("Business '{business_uuid}' has no owner to make an offer to.")]
+    BusinessHasNoOwner { business_uuid: Uuid },
+
+    #[error("Corporation '{corporation_uuid}' already owns business '{business_uuid}'.")]
+    BusinessAlreadyOwnedByCorporation {
+        business_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Corporation '{corporation_uuid}' already has an open offer for business '{business_uuid}'.")]
+    BusinessOfferAlreadyExists {
+        business_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Business offer '{offer_uuid}' is not addressed to corporation '{corporation_uuid}'.")]
+    BusinessOfferNotTargetedAtCorporation {
+        offer_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Business offer '{offer_uuid}' was not made by corporation '{corporation_uuid}'.")]
+    BusinessOfferNotOwnedByCorporation {
+        offer_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Building '{building_gml_id}' not found.")]
+    BuildingNotFound { building_gml_id: String },
+
+    #[error("Building '{building_gml_id}' is not owned by corporation '{corporation_uuid}'.")]
+    BuildingNotOwnedByCorporation {
+        building_gml_id: String,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Unit '{unit_uuid}' is not owned by corporation '{corporation_uuid}'.")]
+    UnitNotOwnedByCorporation {
+        unit_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Unit '{unit_uuid}' is already moving.")]
+    UnitAlreadyMoving { unit_uuid: Uuid },
+
+    #[error("Unit '{unit_uuid}' is already located at building '{building_gml_id}'.")]
+    UnitAlreadyAtBuilding {
+        unit_uuid: Uuid,
+        building_gml_id: String,
+    },
+
+    #[error("Building '{building_gml_id}' is not owned by any corporation and can't be attacked.")]
+    BuildingNotAttackable { building_gml_id: String },
+
+    #[error("Corporation '{corporation_uuid}' can't attack its own building '{building_gml_id}'.")]
+    CannotAttackOwnBuilding {
+        building_gml_id: String,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("At least one unit has to be committed to an attack.")]
+    NoUnitsCommitted,
+
+    #[error(
+        "Operational expenses must be between {min} and {max}. Provided: {operational_expenses}."
+    )]
+    InvalidOperationalExpenses {
+        operational_expenses: i64,
+        min: i64,
+        max: i64,
+    },
+
+    #[error("Operational expenses of business '{business_uuid}' can't be changed before tick {available_at_tick}.")]
+    OperationalExpensesOnCooldown {
+        business_uuid: Uuid,
+        available_at_tick: i64,
+    },
+
+    #[error("Loan amount must be greater than zero. Provided: {amount}.")]
+    InvalidLoanAmount { amount: i64 },
+
+    #[error("Loan of {requested} exceeds the available credit of corporation '{corporation_uuid}'. Available: {available}.")]
+    CreditLimitExceeded {
+        corporation_uuid: Uuid,
+        requested: i64,
+        available: i64,
+    },
+
+    #[error("Loan '{loan_uuid}' not found.")]
+    LoanNotFound { loan_uuid: Uuid },
+
+    #[error("Loan '{loan_uuid}' is not owed by corporation '{corporation_uuid}'.")]
+    LoanNotOwedByCorporation {
+        loan_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Auction duration must be between {min} and {max} ticks. Provided: {auction_duration_ticks}.")]
+    InvalidAuctionDuration {
+        auction_duration_ticks: i64,
+        min: i64,
+        max: i64,
+    },
+
+    #[error("Business listing '{listing_uuid}' is an auction and can only be bid on.")]
+    BusinessListingIsAuction { listing_uuid: Uuid },
+
+    #[error("Business listing '{listing_uuid}' is not an auction.")]
+    BusinessListingNotAuction { listing_uuid: Uuid },
+
+    #[error("Auction '{listing_uuid}' closed at tick {closed_at_tick}.")]
+    AuctionClosed {
+        listing_uuid: Uuid,
+        closed_at_tick: i64,
+    },
+
+    #[error("Bid of {amount} is below the reserve price of {reserve_price}.")]
+    AuctionBidTooLow { amount: i64, reserve_price: i64 },
+
+    #[error("Corporation '{corporation_uuid}' already placed a bid on auction '{listing_uuid}'.")]
+    AuctionBidAlreadyPlaced {
+        listing_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Business '{business_uuid}' was contested in this tick and went to another action.")]
+    ContestLost { business_uuid: Uuid },
+
+    #[error("Building '{building_gml_id}' is already owned by a business.")]
+    BuildingAlreadyOwned { building_gml_id: String },
+
+    #[error("Building '{building_gml_id}' is {distance_meters} m away from the headquarter of the business. The limit is {max_distance_meters} m.")]
+    BuildingOutOfRange {
+        building_gml_id: String,
+        distance_meters: i64,
+        max_distance_meters: i64,
+    },
+
+    #[error("Corporation '{corporation_uuid}' can't spy on itself.")]
+    CannotSpyOnOwnCorporation { corporation_uuid: Uuid },
+
+    #[error("Corporation '{corporation_uuid}' already has a mole in corporation '{target_corporation_uuid}'.")]
+    MoleAlreadyPlanted {
+        target_corporation_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Corporation '{corporation_uuid}' can't conclude a treaty with itself.")]
+    CannotTreatWithOwnCorporation { corporation_uuid: Uuid },
+
+    #[error("A {treaty_type} treaty '{treaty_uuid}' with corporation '{partner_corporation_uuid}' already exists.")]
+    TreatyAlreadyExists {
+        treaty_uuid: Uuid,
+        treaty_type: TreatyType,
+        partner_corporation_uuid: Uuid,
+    },
+
+    #[error("Treaty '{treaty_uuid}' not found.")]
+    TreatyNotFound { treaty_uuid: Uuid },
+
+    #[error("Treaty '{treaty_uuid}' was not proposed to corporation '{corporation_uuid}'.")]
+    TreatyNotProposedToCorporation {
+        treaty_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Treaty '{treaty_uuid}' was already accepted.")]
+    TreatyAlreadyAccepted { treaty_uuid: Uuid },
+
+    #[error("Corporation '{corporation_uuid}' is not a party to treaty '{treaty_uuid}'.")]
+    CorporationNotPartyToTreaty {
+        treaty_uuid: Uuid,
+        corporation_uuid: Uuid,
+    },
+
+    #[error("Building '{building_gml_id}' belongs to corporation '{defender_corporation_uuid}', which a treaty forbids to attack.")]
+    AttackForbiddenByTreaty {
+        building_gml_id: String,
+        defender_corporation_uuid: Uuid,
+    },
+
+    #[error("Transfer amount must be greater than zero. Provided: {amount}.")]
+    InvalidTransferAmount { amount: i64 },
+
+    #[error("Corporation '{corporation_uuid}' can't pay itself.")]
+    CannotPayOwnCorporation { corporation_uuid: Uuid },
+
+    #[error("Contract due tick must be between {min} and {max}. Provided: {due_at_tick}.")]
+    InvalidContractDueTick {
+        due_at_tick: i64,
+        min: i64,
+        max: i64,
+    },
+
     #[error("Corporation'{corporation_uuid}' not found.")]
     CorporationNotFound { corporation_uuid: Uuid },
 
@@ -63,39 +310,267 @@ pub enum ActionError {
     InternalError(String),
 }
 
-pub struct SimulationService;
+impl ActionError {
+    pub fn code(&self) -> ActionFailureCode {
+        match self {
+            ActionError::InvalidAskingPrice { .. }
+            | ActionError::InvalidOfferPrice { .. }
+            | ActionError::NoUnitsCommitted
+            | ActionError::InvalidOperationalExpenses { .. }
+            | ActionError::InvalidLoanAmount { .. }
+            | ActionError::InvalidAuctionDuration { .. }
+            | ActionError::InvalidTransferAmount { .. }
+            | ActionError::InvalidContractDueTick { .. }
+            | ActionError::AuctionBidTooLow { .. }
+            | ActionError::BuildingOutOfRange { .. } => ActionFailureCode::InvalidArgument,
+            ActionError::RequestingCorporationNotFoundByUser { .. }
+            | ActionError::BusinessNotFound { .. }
+            | ActionError::BusinessListingNotFound { .. }
+            | ActionError::UnitNotFound { .. }
+            | ActionError::BusinessOfferNotFound { .. }
+            | ActionError::BuildingNotFound { .. }
+            | ActionError::LoanNotFound { .. }
+            | ActionError::CorporationNotFound { .. }
+            | ActionError::TreatyNotFound { .. }
+            | ActionError::CorporationNotFoundDuringChecks { .. }
+            | ActionError::BusinessNotFoundDuringChecks { .. } => ActionFailureCode::NotFound,
+            ActionError::BusinessNotOwnedByCorporation { .. }
+            | ActionError::BusinessListingNotOwnedByCorporation { .. }
+            | ActionError::BusinessOfferNotTargetedAtCorporation { .. }
+            | ActionError::BusinessOfferNotOwnedByCorporation { .. }
+            | ActionError::BuildingNotOwnedByCorporation { .. }
+            | ActionError::UnitNotOwnedByCorporation { .. }
+            | ActionError::LoanNotOwedByCorporation { .. }
+            | ActionError::TreatyNotProposedToCorporation { .. }
+            | ActionError::CorporationNotPartyToTreaty { .. } => ActionFailureCode::NotOwner,
+            ActionError::BusinessAlreadyOwnedByCorporation { .. } => {
+                ActionFailureCode::AlreadyOwned
+            }
+            ActionError::BusinessAlreadyListed { .. }
+            | ActionError::BusinessOfferAlreadyExists { .. }
+            | ActionError::AuctionBidAlreadyPlaced { .. }
+            | ActionError::MoleAlreadyPlanted { .. }
+            | ActionError::TreatyAlreadyExists { .. } => ActionFailureCode::AlreadyExists,
+            ActionError::InsufficientFunds { .. } => ActionFailureCode::InsufficientFunds,
+            ActionError::CreditLimitExceeded { .. } => ActionFailureCode::CreditLimitExceeded,
+            ActionError::OperationalExpensesOnCooldown { .. } => ActionFailureCode::Cooldown,
+            ActionError::BusinessHasNoOwner { .. }
+            | ActionError::UnitAlreadyMoving { .. }
+            | ActionError::UnitAlreadyAtBuilding { .. }
+            | ActionError::BuildingNotAttackable { .. }
+            | ActionError::CannotAttackOwnBuilding { .. }
+            | ActionError::CannotSpyOnOwnCorporation { .. }
+            | ActionError::CannotTreatWithOwnCorporation { .. }
+            | ActionError::CannotPayOwnCorporation { .. }
+            | ActionError::TreatyAlreadyAccepted { .. }
+            | ActionError::AttackForbiddenByTreaty { .. }
+            | ActionError::BusinessListingIsAuction { .. }
+            | ActionError::BusinessListingNotAuction { .. }
+            | ActionError::AuctionClosed { .. }
+            | ActionError::CorporationNotCaptured { .. }
+            | ActionError::BuildingAlreadyOwned { .. } => ActionFailureCode::InvalidState,
+            ActionError::ContestLost { .. } => ActionFailureCode::ContestLost,
+            ActionError::SagaEntityMissing { .. } | ActionError::InternalError(_) => {
+                ActionFailureCode::Internal
+            }
+        }
+    }
+
+    pub fn details(&self) -> Option<ActionFailureDetails> {
+        let entity = |entity_type: FailureEntityType, entity_id: &dyn ToString| {
+            Some(ActionFailureDetails::Entity {
+                entity_type,
+                entity_id: entity_id.to_string(),
+            })
+        };
+        let invalid_value = |provided: i64, min: Option<i64>, max: Option<i64>| {
+            Some(ActionFailureDetails::InvalidValue { provided, min, max })
+        };
+
+        match self {
+            ActionError::BusinessNotFound { business_uuid }
+            | ActionError::BusinessNotOwnedByCorporation { business_uuid, .. }
+            | ActionError::BusinessAlreadyListed { business_uuid }
+            | ActionError::BusinessHasNoOwner { business_uuid }
+            | ActionError::BusinessAlreadyOwnedByCorporation { business_uuid, .. }
+            | ActionError::BusinessOfferAlreadyExists { business_uuid, .. }
+            | ActionError::ContestLost { business_uuid }
+            | ActionError::BusinessNotFoundDuringChecks { business_uuid } => {
+                entity(FailureEntityType::Business, business_uuid)
+            }
+            ActionError::BusinessListingNotFound { listing_uuid }
+            | ActionError::BusinessListingNotOwnedByCorporation { listing_uuid, .. }
+            | ActionError::BusinessListingIsAuction { listing_uuid }
+            | ActionError::BusinessListingNotAuction { listing_uuid }
+            | ActionError::AuctionClosed { listing_uuid, .. }
+            | ActionError::AuctionBidAlreadyPlaced { listing_uuid, .. } => {
+                entity(FailureEntityType::BusinessListing, listing_uuid)
+            }
+            ActionError::BusinessOfferNotFound { offer_uuid }
+            | ActionError::BusinessOfferNotTargetedAtCorporation { offer_uuid, .. }
+            | ActionError::BusinessOfferNotOwnedByCorporation { offer_uuid, .. } => {
+                entity(FailureEntityType::BusinessOffer, offer_uuid)
+            }
+            ActionError::UnitNotFound { unit_uuid }
+            | ActionError::UnitNotOwnedByCorporation { unit_uuid, .. }
+            | ActionError::UnitAlreadyMoving { unit_uuid }
+            | ActionError::UnitAlreadyAtBuilding { unit_uuid, .. } => {
+                entity(FailureEntityType::Unit, unit_uuid)
+            }
+            ActionError::BuildingNotFound { building_gml_id }
+            | ActionError::BuildingNotOwnedByCorporation {
+                building_gml_id, ..
+            }
+            | ActionError::BuildingNotAttackable { building_gml_id }
+            | ActionError::CannotAttackOwnBuilding {
+                building_gml_id, ..
+            }
+            | ActionError::AttackForbiddenByTreaty {
+                building_gml_id, ..
+            }
+            | ActionError::BuildingAlreadyOwned { building_gml_id } => {
+                entity(FailureEntityType::Building, building_gml_id)
+            }
+            ActionError::LoanNotFound { loan_uuid }
+            | ActionError::LoanNotOwedByCorporation { loan_uuid, .. } => {
+                entity(FailureEntityType::Loan, loan_uuid)
+            }
+            ActionError::CorporationNotFound { corporation_uuid }
+            | ActionError::CorporationNotCaptured { corporation_uuid }
+            | ActionError::CannotSpyOnOwnCorporation { corporation_uuid }
+            | ActionError::CannotTreatWithOwnCorporation { corporation_uuid }
+            | ActionError::CannotPayOwnCorporation { corporation_uuid }
+            | ActionError::MoleAlreadyPlanted {
+                target_corporation_uuid: corporation_uuid,
+                ..
+            }
+            | ActionError::CorporationNotFoundDuringChecks { corporation_uuid } => {
+                entity(FailureEntityType::Corporation, corporation_uuid)
+            }
+            ActionError::TreatyAlreadyExists { treaty_uuid, .. }
+            | ActionError::TreatyNotFound { treaty_uuid }
+            | ActionError::TreatyNotProposedToCorporation { treaty_uuid, .. }
+            | ActionError::TreatyAlreadyAccepted { treaty_uuid }
+            | ActionError::CorporationNotPartyToTreaty { treaty_uuid, .. } => {
+                entity(FailureEntityType::Treaty, treaty_uuid)
+            }
+            ActionError::InvalidAskingPrice {
+                asking_price: provided,
+            }
+            | ActionError::InvalidOfferPrice {
+                offer_price: provided,
+            }
+            | ActionError::InvalidLoanAmount { amount: provided }
+            | ActionError::InvalidTransferAmount { amount: provided } => {
+                invalid_value(*provided, Some(1), None)
+            }
+            ActionError::InvalidOperationalExpenses {
+                operational_expenses: provided,
+                min,
+                max,
+            }
+            | ActionError::InvalidAuctionDuration {
+                auction_duration_ticks: provided,
+                min,
+                max,
+            }
+            | ActionError::InvalidContractDueTick {
+                due_at_tick: provided,
+                min,
+                max,
+            } => invalid_value(*provided, Some(*min), Some(*max)),
+            ActionError::AuctionBidTooLow {
+                amount,
+                reserve_price,
+            } => invalid_value(*amount, Some(*reserve_price), None),
+            ActionError::BuildingOutOfRange {
+                distance_meters,
+                max_distance_meters,
+                ..
+            } => invalid_value(*distance_meters, None, Some(*max_distance_meters)),
+            ActionError::InsufficientFunds {
+                corporation_uuid,
+                required,
+                available,
+            }
+            | ActionError::CreditLimitExceeded {
+                corporation_uuid,
+                requested: required,
+                available,
+            } => Some(ActionFailureDetails::InsufficientFunds {
+                corporation_uuid: *corporation_uuid,
+                required: *required,
+                available: *available,
+            }),
+            ActionError::OperationalExpensesOnCooldown {
+                available_at_tick, ..
+            } => Some(ActionFailureDetails::Cooldown {
+                available_at_tick: *available_at_tick,
+            }),
+            ActionError::RequestingCorporationNotFoundByUser { .. }
+            | ActionError::NoUnitsCommitted
+            | ActionError::SagaEntityMissing { .. }
+            | ActionError::InternalError(_) => None,
+        }
+    }
+}
+
+#[derive(Builder)]
+pub struct SimulationService {
+    config: Arc<ServerConfig>,
+}
 
 impl Simulationable for SimulationService {
+    fn tick_seed(&self, next_game_tick: i64) -> u64 {
+        SimulationRng::tick_seed(self.config.game.world_seed, next_game_tick)
+    }
+
     fn calculate_next_state(
         &self,
         next_game_tick: i64,
-        mut queued_actions: Vec<QueuedAction>,
+        seed: u64,
+        queued_actions: Vec<QueuedAction>,
         action_ids: &mut Vec<String>,
         state: &mut GameState,
     ) -> Vec<DomainActionOutcome> {
+        let mut rng = SimulationRng::from_seed(seed);
+        // Journals of a tick that has not been persisted, e.g. while replaying, must not leak into this one
+        state.ledger_entries.clear();
+        state.market_snapshots.clear();
         let mut outcomes: Vec<DomainActionOutcome> = Vec::with_capacity(queued_actions.len());
 
-        // Sort the actions so that they are executed in the correct order
-        queued_actions.sort_by(|a_action, b_action| {
-            a_action
-                .payload
-                .details
-                .get_order()
-                .cmp(&b_action.payload.details.get_order())
-        });
+        // Sort the actions so that they are executed in the correct order and contests are decided fairly
+        let ordered_actions = order_actions(queued_actions, seed, state);
+        // Businesses that already went to one of the contending actions
+        let mut decided_contests: HashSet<Uuid> = HashSet::new();
 
-        for queued_action in queued_actions.into_iter() {
+        for (queued_action, contested_business_uuid) in ordered_actions.into_iter() {
             action_ids.push(queued_action.id.clone());
             let action_string = queued_action.payload.details.to_string();
             let req_user_uuid = queued_action.payload.req_user_uuid;
             let request_uuid = queued_action.payload.request_uuid;
 
+            if let Some(business_uuid) =
+                contested_business_uuid.filter(|uuid| decided_contests.contains(uuid))
+            {
+                failure_outcome()
+                    .outcomes(&mut outcomes)
+                    .req_user_uuid(req_user_uuid)
+                    .request_uuid(request_uuid)
+                    .action(action_string)
+                    .error(ActionError::ContestLost { business_uuid })
+                    .tick_processed(next_game_tick)
+                    .call();
+                continue;
+            }
+
             let result = match &queued_action.payload.details {
                 ActionDetails::CreateCorporation {
                     user_uuid,
                     corporation_name,
                 } => handle_create_corporation()
                     .state(state)
+                    .rng(&mut rng)
                     .corporation_name(corporation_name.to_owned())
                     .action_payload(&queued_action.payload)
                     .next_game_tick(next_game_tick)
@@ -111,9 +586,39 @@ impl Simulationable for SimulationService {
                         .req_user_uuid(req_user_uuid)
                         .call()
                 }
-                ActionDetails::SpawnUnit => handle_spawn_unit()
+                ActionDetails::SpawnUnit {
+                    unit_type,
+                    building_gml_id,
+                } => handle_spawn_unit()
+                    .state(state)
+                    .rng(&mut rng)
+                    .action_payload(&queued_action.payload)
+                    .unit_type(*unit_type)
+                    .building_gml_id(building_gml_id)
+                    .control_threshold_bps(self.config.territory.control_threshold_bps)
+                    .unit_spawn_discount_percent(self.config.territory.unit_spawn_discount_percent)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::MoveUnit {
+                    unit_uuid,
+                    destination_building_gml_id,
+                } => handle_move_unit()
                     .state(state)
                     .action_payload(&queued_action.payload)
+                    .unit_uuid(*unit_uuid)
+                    .destination_building_gml_id(destination_building_gml_id)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::AttackBuilding {
+                    building_gml_id,
+                    unit_uuids,
+                } => handle_attack_building()
+                    .state(state)
+                    .action_payload(&queued_action.payload)
+                    .building_gml_id(building_gml_id)
+                    .unit_uuids(unit_uuids)
                     .next_game_tick(next_game_tick)
                     .req_user_uuid(req_user_uuid)
                     .call(),
@@ -126,28 +631,289 @@ impl Simulationable for SimulationService {
                     .next_game_tick(next_game_tick)
                     .req_user_uuid(req_user_uuid)
                     .call(),
+                ActionDetails::ListBusinessForSale {
+                    business_uuid,
+                    asking_price,
+                    auction_duration_ticks,
+                } => handle_list_business_for_sale()
+                    .state(state)
+                    .rng(&mut rng)
+                    .action_payload(&queued_action.payload)
+                    .business_uuid(*business_uuid)
+                    .asking_price(*asking_price)
+                    .maybe_auction_duration_ticks(*auction_duration_ticks)
+                    .min_auction_duration_ticks(self.config.game.min_auction_duration_ticks)
+                    .max_auction_duration_ticks(self.config.game.max_auction_duration_ticks)
+                    .control_threshold_bps(self.config.territory.control_threshold_bps)
+                    .listing_fee_percent(self.config.territory.listing_fee_percent)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::CancelBusinessListing {
+                    business_listing_uuid,
+                } => handle_cancel_business_listing()
+                    .state(state)
+                    .action_payload(&queued_action.payload)
+                    .business_listing_uuid(*business_listing_uuid)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::MakeBusinessOffer {
+                    business_uuid,
+                    offer_price,
+                } => handle_make_business_offer()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .business_uuid(*business_uuid)
+                    .offer_price(*offer_price)
+                    .offer_expiry_ticks(self.config.game.business_offer_expiry_ticks)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::AcceptBusinessOffer {
+                    business_offer_uuid,
+                } => handle_accept_business_offer()
+                    .state(state)
+                    .action_payload(&queued_action.payload)
+                    .business_offer_uuid(*business_offer_uuid)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::RejectBusinessOffer {
+                    business_offer_uuid,
+                } => handle_reject_business_offer()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .business_offer_uuid(*business_offer_uuid)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::WithdrawBusinessOffer {
+                    business_offer_uuid,
+                } => handle_withdraw_business_offer()
+                    .state(state)
+                    .action_payload(&queued_action.payload)
+                    .business_offer_uuid(*business_offer_uuid)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::SetOperationalExpenses {
+                    business_uuid,
+                    operational_expenses,
+                } => handle_set_operational_expenses()
+                    .state(state)
+                    .action_payload(&queued_action.payload)
+                    .business_uuid(*business_uuid)
+                    .operational_expenses(*operational_expenses)
+                    .min_operational_expenses(self.config.game.min_operational_expenses)
+                    .max_operational_expenses(self.config.game.max_operational_expenses)
+                    .capacity_bonus(self.config.building.capacity_bonus)
+                    .cooldown_ticks(self.config.game.operational_expenses_cooldown_ticks)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::TakeLoan { amount } => handle_take_loan()
+                    .state(state)
+                    .rng(&mut rng)
+                    .action_payload(&queued_action.payload)
+                    .amount(*amount)
+                    .bank_config(&self.config.bank)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::RepayLoan { loan_uuid, amount } => handle_repay_loan()
+                    .state(state)
+                    .action_payload(&queued_action.payload)
+                    .loan_uuid(*loan_uuid)
+                    .amount(*amount)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::PlaceAuctionBid {
+                    business_listing_uuid,
+                    amount,
+                } => handle_place_auction_bid()
+                    .state(state)
+                    .rng(&mut rng)
+                    .action_payload(&queued_action.payload)
+                    .business_listing_uuid(*business_listing_uuid)
+                    .amount(*amount)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::AcquireBuilding {
+                    business_uuid,
+                    building_gml_id,
+                } => handle_acquire_building()
+                    .state(state)
+                    .action_payload(&queued_action.payload)
+                    .business_uuid(*business_uuid)
+                    .building_gml_id(building_gml_id)
+                    .acquisition_radius_meters(self.config.building.acquisition_radius_meters)
+                    .base_price(self.config.building.base_price)
+                    .price_per_volume(self.config.building.price_per_volume)
+                    .upkeep_per_volume(self.config.building.upkeep_per_volume)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::GatherIntel {
+                    target_corporation_uuid,
+                    intel_type,
+                } => handle_gather_intel()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .target_corporation_uuid(*target_corporation_uuid)
+                    .intel_type(*intel_type)
+                    .espionage_config(&self.config.espionage)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::PlantMole {
+                    target_corporation_uuid,
+                } => handle_plant_mole()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .target_corporation_uuid(*target_corporation_uuid)
+                    .espionage_config(&self.config.espionage)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::CounterIntel => handle_counter_intel()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .espionage_config(&self.config.espionage)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::ProposeTreaty {
+                    partner_corporation_uuid,
+                    treaty_type,
+                } => handle_propose_treaty()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .partner_corporation_uuid(*partner_corporation_uuid)
+                    .treaty_type(*treaty_type)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::AcceptTreaty { treaty_uuid } => handle_accept_treaty()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .treaty_uuid(*treaty_uuid)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::BreakTreaty { treaty_uuid } => handle_break_treaty()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .treaty_uuid(*treaty_uuid)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::TransferFunds {
+                    recipient_corporation_uuid,
+                    amount,
+                } => handle_transfer_funds()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .recipient_corporation_uuid(*recipient_corporation_uuid)
+                    .amount(*amount)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
+                ActionDetails::CreateContract {
+                    payee_corporation_uuid,
+                    amount,
+                    due_at_tick,
+                    condition,
+                } => handle_create_contract()
+                    .state(state)
+                    .rng(&mut rng)
+                    .outcomes(&mut outcomes)
+                    .action_payload(&queued_action.payload)
+                    .payee_corporation_uuid(*payee_corporation_uuid)
+                    .amount(*amount)
+                    .due_at_tick(*due_at_tick)
+                    .condition(*condition)
+                    .max_contract_duration_ticks(self.config.game.max_contract_duration_ticks)
+                    .next_game_tick(next_game_tick)
+                    .req_user_uuid(req_user_uuid)
+                    .call(),
             };
 
+            if let (Ok(_), Some(business_uuid)) = (&result, contested_business_uuid) {
+                decided_contests.insert(business_uuid);
+            }
+
             match result {
                 Ok(success_outcome) => {
                     outcomes.push(success_outcome);
                 }
-                // Match on the specific ActionError enum
                 Err(error) => {
-                    // Use the error's Display implementation for the reason string
                     failure_outcome()
                         .outcomes(&mut outcomes)
                         .req_user_uuid(req_user_uuid)
                         .request_uuid(request_uuid)
                         .action(action_string)
-                        .reason(error.to_string())
+                        .error(error)
                         .tick_processed(next_game_tick)
                         .call();
                 }
             }
         }
 
-        calculate_business_income(state);
+        outcomes.extend(advance_unit_movements(state, &mut rng, next_game_tick));
+        outcomes.extend(resolve_combat(state, &mut rng, next_game_tick));
+        outcomes.extend(expire_business_offers(state, &mut rng, next_game_tick));
+        outcomes.extend(close_auctions(state, &mut rng, next_game_tick));
+        outcomes.extend(settle_contracts(state, &mut rng, next_game_tick));
+
+        calculate_business_income(
+            state,
+            next_game_tick,
+            self.config.building.market_share_bonus_percent,
+        );
+        collect_territory_tax(state, next_game_tick, &self.config.territory);
+        charge_building_upkeep(
+            state,
+            next_game_tick,
+            self.config.building.upkeep_per_volume,
+        );
+        outcomes.extend(service_loans(
+            state,
+            &mut rng,
+            next_game_tick,
+            self.config.game.liquidation_asking_price,
+        ));
+        outcomes.extend(resolve_insolvencies(
+            state,
+            &mut rng,
+            next_game_tick,
+            self.config.game.insolvency_grace_ticks,
+            self.config.game.liquidation_asking_price,
+        ));
+        evolve_market_volumes(state, &mut rng, next_game_tick, &self.config.market);
+        make_market(state, &mut rng, next_game_tick, &self.config.market_maker);
+
+        outcomes.extend(summarize_ledger(state, &mut rng, next_game_tick));
 
         outcomes
     }
@@ -159,17 +925,324 @@ fn failure_outcome(
     req_user_uuid: Uuid,
     request_uuid: Uuid,
     action: String,
-    reason: String,
+    error: ActionError,
     tick_processed: i64,
 ) {
+    // Use the error's Display implementation for the reason string
+    let reason = error.to_string();
+    let code = error.code();
+
     // Log the failure - changed to warn level
-    tracing::warn!(%req_user_uuid, %request_uuid, %tick_processed, %action, %reason, "Action failed");
+    tracing::warn!(%req_user_uuid, %request_uuid, %tick_processed, %action, %reason, ?code, "Action failed");
 
     // Push the failure outcome into the outcomes vector
     outcomes.push(DomainActionOutcome::ActionFailed {
         req_user_uuid,
         request_uuid,
-        reason, // The reason is now the formatted error message
+        reason,
+        code,
+        details: error.details(),
         tick_processed,
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::action::QueuedActionPayload,
+        config::{
+            AuthConfig, BankConfig, BootstrapConfig, BuildingConfig, EmailConfig, EspionageConfig,
+            FogOfWarConfig, GameConfig, GeneralConfig, MarketConfig, MarketMakerConfig,
+            PostgresConfig, ProcessorConfig, RateLimiterConfig, StreamConfig, TerritoryConfig,
+            ValkeyConfig,
+        },
+        domain::{
+            economy::{
+                business::model::Business,
+                business_listing::model::BusinessListing,
+                corporation::model::{name::CorporationName, Corporation},
+            },
+            outcome::outcomes_checksum,
+        },
+    };
+
+    const TAKEN_NAME: &str = "Taken Corp";
+
+    /// The default email config reads the SMTP credentials from the environment, so the
+    /// config is put together from the other defaults.
+    fn simulation() -> SimulationService {
+        let config = ServerConfig::builder()
+            .general(GeneralConfig::default())
+            .auth(AuthConfig::default())
+            .bootstrap(BootstrapConfig::default())
+            .rate_limiter(RateLimiterConfig::default())
+            .processor(ProcessorConfig::default())
+            .game(GameConfig::default())
+            .market(MarketConfig::default())
+            .bank(BankConfig::default())
+            .market_maker(MarketMakerConfig::default())
+            .building(BuildingConfig::default())
+            .territory(TerritoryConfig::default())
+            .espionage(EspionageConfig::default())
+            .fog_of_war(FogOfWarConfig::default())
+            .stream(StreamConfig::default())
+            .postgres(PostgresConfig::default())
+            .valkey(ValkeyConfig::default())
+            .email(
+                EmailConfig::builder()
+                    .sender_email("noreply@syndicode.dev".to_string())
+                    .smtp_server(String::new())
+                    .smtp_username(String::new())
+                    .smtp_password(String::new())
+                    .build(),
+            )
+            .build();
+
+        SimulationService::builder()
+            .config(Arc::new(config))
+            .build()
+    }
+
+    fn setup_state(existing_corporation: &Corporation) -> GameState {
+        GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![existing_corporation.clone()])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call()
+    }
+
+    fn create_corporation_actions(user_uuids: &[Uuid]) -> Vec<QueuedAction> {
+        user_uuids
+            .iter()
+            .enumerate()
+            .map(|(index, user_uuid)| QueuedAction {
+                id: format!("{index}-0"),
+                payload: QueuedActionPayload {
+                    request_uuid: Uuid::now_v7(),
+                    req_user_uuid: *user_uuid,
+                    details: ActionDetails::CreateCorporation {
+                        user_uuid: *user_uuid,
+                        // Colliding names get a random suffix
+                        corporation_name: CorporationName::new(TAKEN_NAME.to_string()).unwrap(),
+                    },
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_produce_identical_results_for_same_seed_and_actions() {
+        let simulation = simulation();
+        let existing_corporation = Corporation::new(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            CorporationName::new(TAKEN_NAME.to_string()).unwrap(),
+        );
+        let actions = create_corporation_actions(&[Uuid::now_v7(), Uuid::now_v7()]);
+        let seed = simulation.tick_seed(1);
+
+        let mut first_state = setup_state(&existing_corporation);
+        let first_outcomes = simulation.calculate_next_state(
+            1,
+            seed,
+            actions.clone(),
+            &mut Vec::new(),
+            &mut first_state,
+        );
+
+        let mut second_state = setup_state(&existing_corporation);
+        let second_outcomes = simulation.calculate_next_state(
+            1,
+            seed,
+            actions.clone(),
+            &mut Vec::new(),
+            &mut second_state,
+        );
+
+        assert_eq!(first_state.corporations_map.len(), 3);
+        assert_eq!(first_state.checksum(), second_state.checksum());
+        assert_eq!(
+            outcomes_checksum(&first_outcomes),
+            outcomes_checksum(&second_outcomes)
+        );
+
+        // Another seed leads to other ids and suffixes
+        let mut third_state = setup_state(&existing_corporation);
+        simulation.calculate_next_state(
+            1,
+            seed.wrapping_add(1),
+            actions,
+            &mut Vec::new(),
+            &mut third_state,
+        );
+        assert_ne!(first_state.checksum(), third_state.checksum());
+    }
+
+    #[test]
+    fn should_award_contested_listing_independently_of_the_queue_order() {
+        let simulation = simulation();
+        let corporations: Vec<Corporation> = (0..3)
+            .map(|index| {
+                Corporation::new(
+                    Uuid::now_v7(),
+                    Uuid::now_v7(),
+                    CorporationName::new(format!("Buyer Corp {index}")).unwrap(),
+                )
+            })
+            .collect();
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: None,
+            name: "Contested Biz".to_string(),
+            operational_expenses: 0,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: business.uuid,
+            seller_corporation_uuid: None,
+            asking_price: 100,
+            auction_closes_at_tick: None,
+        };
+        let actions: Vec<QueuedAction> = corporations
+            .iter()
+            .enumerate()
+            .map(|(index, corporation)| QueuedAction {
+                id: format!("{index}-0"),
+                payload: QueuedActionPayload {
+                    request_uuid: Uuid::now_v7(),
+                    req_user_uuid: corporation.user_uuid,
+                    details: ActionDetails::AcquireListedBusiness {
+                        business_listing_uuid: listing.uuid,
+                    },
+                },
+            })
+            .collect();
+        let seed = simulation.tick_seed(1);
+
+        let run = |actions: Vec<QueuedAction>| {
+            let mut state = GameState::build()
+                .last_processed_tick(0)
+                .units_vec(vec![])
+                .corporations_vec(corporations.clone())
+                .markets_vec(vec![])
+                .businesses_vec(vec![business.clone()])
+                .business_listings_vec(vec![listing])
+                .business_offers_vec(vec![])
+                .building_ownerships_vec(vec![])
+                .call();
+            let outcomes =
+                simulation.calculate_next_state(1, seed, actions, &mut Vec::new(), &mut state);
+            let owner = state
+                .ref_business(&business.uuid)
+                .unwrap()
+                .owning_corporation_uuid;
+
+            (owner, outcomes)
+        };
+
+        let (first_owner, outcomes) = run(actions.clone());
+        let (second_owner, _) = run(actions.into_iter().rev().collect());
+
+        assert!(first_owner.is_some());
+        assert_eq!(first_owner, second_owner);
+
+        let expected_reason = ActionError::ContestLost {
+            business_uuid: business.uuid,
+        }
+        .to_string();
+        let expected_details = Some(ActionFailureDetails::Entity {
+            entity_type: FailureEntityType::Business,
+            entity_id: business.uuid.to_string(),
+        });
+        let lost_contests = outcomes
+            .iter()
+            .filter(|outcome| {
+                matches!(
+                    outcome,
+                    DomainActionOutcome::ActionFailed {
+                        reason,
+                        code: ActionFailureCode::ContestLost,
+                        details,
+                        ..
+                    } if *reason == expected_reason && *details == expected_details
+                )
+            })
+            .count();
+        assert_eq!(lost_contests, 2);
+    }
+
+    #[test]
+    fn should_expose_amounts_of_failed_payments() {
+        let corporation_uuid = Uuid::now_v7();
+        let insufficient_funds = ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: 500,
+            available: 200,
+        };
+        let credit_limit_exceeded = ActionError::CreditLimitExceeded {
+            corporation_uuid,
+            requested: 500,
+            available: 200,
+        };
+        let expected_details = Some(ActionFailureDetails::InsufficientFunds {
+            corporation_uuid,
+            required: 500,
+            available: 200,
+        });
+
+        assert_eq!(
+            insufficient_funds.code(),
+            ActionFailureCode::InsufficientFunds
+        );
+        assert_eq!(insufficient_funds.details(), expected_details);
+        assert_eq!(
+            credit_limit_exceeded.code(),
+            ActionFailureCode::CreditLimitExceeded
+        );
+        assert_eq!(credit_limit_exceeded.details(), expected_details);
+    }
+
+    #[test]
+    fn should_expose_when_a_cooldown_ends() {
+        let error = ActionError::OperationalExpensesOnCooldown {
+            business_uuid: Uuid::now_v7(),
+            available_at_tick: 12,
+        };
+
+        assert_eq!(error.code(), ActionFailureCode::Cooldown);
+        assert_eq!(
+            error.details(),
+            Some(ActionFailureDetails::Cooldown {
+                available_at_tick: 12
+            })
+        );
+    }
+
+    #[test]
+    fn should_expose_the_accepted_range_of_invalid_values() {
+        let error = ActionError::AuctionBidTooLow {
+            amount: 90,
+            reserve_price: 100,
+        };
+
+        assert_eq!(error.code(), ActionFailureCode::InvalidArgument);
+        assert_eq!(
+            error.details(),
+            Some(ActionFailureDetails::InvalidValue {
+                provided: 90,
+                min: Some(100),
+                max: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::domain::{
+    diplomacy::model::Treaty,
+    economy::{
+        auction_bid::model::AuctionBid, building_ownership::model::BuildingOwnership,
+        business::model::Business, business_listing::model::BusinessListing,
+        business_offer::model::BusinessOffer, contract::model::Contract,
+        corporation::model::Corporation, loan::model::Loan, market::model::Market,
+    },
+    espionage::model::Mole,
+    unit::model::Unit,
+};
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+use uuid::Uuid;
+
+/// Rows of one entity kind that differ from their last persisted version.
+pub struct EntityChanges<T> {
+    /// Entities that were added or modified.
+    pub upserted: Vec<T>,
+    /// Keys of the entities that were removed.
+    pub removed: Vec<Uuid>,
+}
+
+/// Everything that has to be written to persist the in-memory state.
+pub struct StateChanges {
+    pub units: EntityChanges<Unit>,
+    pub corporations: EntityChanges<Corporation>,
+    pub markets: EntityChanges<Market>,
+    pub businesses: EntityChanges<Business>,
+    pub business_listings: EntityChanges<BusinessListing>,
+    pub business_offers: EntityChanges<BusinessOffer>,
+    pub loans: EntityChanges<Loan>,
+    pub auction_bids: EntityChanges<AuctionBid>,
+    pub moles: EntityChanges<Mole>,
+    pub treaties: EntityChanges<Treaty>,
+    pub contracts: EntityChanges<Contract>,
+    pub building_ownerships: EntityChanges<BuildingOwnership>,
+}
+
+/// Fingerprints of the entities as they were last persisted, keyed like the state maps.
+///
+/// Handlers and processors mutate the state maps directly, so instead of flagging every
+/// mutation the dirty rows are found by comparing each entity against its fingerprint.
+#[derive(Default)]
+pub(super) struct PersistedFingerprints {
+    pub units: HashMap<Uuid, u64>,
+    pub corporations: HashMap<Uuid, u64>,
+    pub markets: HashMap<Uuid, u64>,
+    pub businesses: HashMap<Uuid, u64>,
+    pub business_listings: HashMap<Uuid, u64>,
+    pub business_offers: HashMap<Uuid, u64>,
+    pub loans: HashMap<Uuid, u64>,
+    pub auction_bids: HashMap<Uuid, u64>,
+    pub moles: HashMap<Uuid, u64>,
+    pub treaties: HashMap<Uuid, u64>,
+    pub contracts: HashMap<Uuid, u64>,
+    pub building_ownerships: HashMap<Uuid, u64>,
+}
+
+pub(super) fn fingerprint<V: Hash>(value: &V) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub(super) fn fingerprint_all<V: Hash>(map: &HashMap<Uuid, V>) -> HashMap<Uuid, u64> {
+    map.iter()
+        .map(|(uuid, value)| (*uuid, fingerprint(value)))
+        .collect()
+}
+
+pub(super) fn diff<V: Hash + Clone>(
+    map: &HashMap<Uuid, V>,
+    persisted: &HashMap<Uuid, u64>,
+) -> EntityChanges<V> {
+    let upserted = map
+        .iter()
+        .filter(|(uuid, value)| persisted.get(uuid) != Some(&fingerprint(value)))
+        .map(|(_, value)| value.clone())
+        .collect();
+    let removed = persisted
+        .keys()
+        .filter(|uuid| !map.contains_key(uuid))
+        .copied()
+        .collect();
+
+    EntityChanges { upserted, removed }
+}
+
+pub(super) fn apply<V: Hash>(
+    persisted: &mut HashMap<Uuid, u64>,
+    changes: &EntityChanges<V>,
+    key: impl Fn(&V) -> Uuid,
+) {
+    for uuid in &changes.removed {
+        persisted.remove(uuid);
+    }
+    for value in &changes.upserted {
+        persisted.insert(key(value), fingerprint(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        economy::corporation::model::name::CorporationName, simulation::game_state::GameState,
+        unit::model::unit_type::UnitType,
+    };
+
+    fn corporation(name: &str) -> Corporation {
+        Corporation::new(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            CorporationName::new(name.to_string()).unwrap(),
+        )
+    }
+
+    fn state(corporations: Vec<Corporation>, units: Vec<Unit>) -> GameState {
+        GameState::build()
+            .last_processed_tick(0)
+            .units_vec(units)
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call()
+    }
+
+    #[test]
+    fn should_report_no_changes_for_freshly_loaded_state() {
+        let corp = corporation("Loaded Corp");
+        let unit = Unit::spawn(
+            Uuid::now_v7(),
+            corp.uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        );
+        let state = state(vec![corp], vec![unit]);
+
+        let changes = state.changes();
+
+        assert!(changes.corporations.upserted.is_empty());
+        assert!(changes.corporations.removed.is_empty());
+        assert!(changes.units.upserted.is_empty());
+        assert!(changes.units.removed.is_empty());
+    }
+
+    #[test]
+    fn should_report_only_modified_added_and_removed_entities() {
+        let unchanged_corp = corporation("Unchanged Corp");
+        let modified_corp = corporation("Modified Corp");
+        let removed_unit = Unit::spawn(
+            Uuid::now_v7(),
+            modified_corp.uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        );
+        let modified_corp_uuid = modified_corp.uuid;
+        let removed_unit_uuid = removed_unit.uuid;
+        let mut state = state(vec![unchanged_corp, modified_corp], vec![removed_unit]);
+
+        state
+            .ref_mut_corporation(&modified_corp_uuid)
+            .unwrap()
+            .cash_balance -= 100;
+        state.remove_unit(&removed_unit_uuid);
+        let added_unit = Unit::spawn(
+            Uuid::now_v7(),
+            modified_corp_uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        );
+        let added_unit_uuid = added_unit.uuid;
+        state.add_unit(added_unit);
+
+        let changes = state.changes();
+
+        assert_eq!(changes.corporations.upserted.len(), 1);
+        assert_eq!(changes.corporations.upserted[0].uuid, modified_corp_uuid);
+        assert!(changes.corporations.removed.is_empty());
+        assert_eq!(changes.units.upserted.len(), 1);
+        assert_eq!(changes.units.upserted[0].uuid, added_unit_uuid);
+        assert_eq!(changes.units.removed, vec![removed_unit_uuid]);
+    }
+
+    #[test]
+    fn should_report_no_changes_after_marking_them_persisted() {
+        let corp = corporation("Persisted Corp");
+        let corp_uuid = corp.uuid;
+        let mut state = state(vec![corp], vec![]);
+        state.ref_mut_corporation(&corp_uuid).unwrap().cash_balance += 50;
+        state.add_unit(Unit::spawn(
+            Uuid::now_v7(),
+            corp_uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        ));
+
+        let changes = state.changes();
+        state.mark_persisted(&changes);
+
+        let changes = state.changes();
+        assert!(changes.corporations.upserted.is_empty());
+        assert!(changes.units.upserted.is_empty());
+        assert!(changes.units.removed.is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+//! Deterministic resolution of actions that compete for the same business within a tick.
+//!
+//! Actions run in the order of `ActionDetails::get_order`. Acquiring a listing and accepting an
+//! offer share their order, so all actions that contest the same business are ranked by a single
+//! lottery: every contender draws a priority from the tick seed, its user and the business.
+//! Neither the arrival order in the queue nor sending the same request twice changes the odds.
+//! Contenders run from the highest priority down and once one of them has succeeded, the others
+//! fail with `ActionError::ContestLost`. All remaining actions keep their queue order.
+
+use std::cmp::Reverse;
+
+use uuid::Uuid;
+
+use super::{game_state::GameState, rng::SimulationRng};
+use crate::application::action::{ActionDetails, QueuedAction};
+
+/// The business an action competes for with other actions of the same tick, if any.
+pub fn contested_business_uuid(details: &ActionDetails, state: &GameState) -> Option<Uuid> {
+    match details {
+        ActionDetails::AcquireListedBusiness {
+            business_listing_uuid,
+        } => state
+            .ref_business_listing(business_listing_uuid)
+            .map(|listing| listing.business_uuid),
+        ActionDetails::AcceptBusinessOffer {
+            business_offer_uuid,
+        } => state
+            .ref_business_offer(business_offer_uuid)
+            .map(|offer| offer.business_uuid),
+        _ => None,
+    }
+}
+
+/// Lottery draw of a user for a contested business. Higher draws go first.
+pub fn contest_priority(seed: u64, req_user_uuid: Uuid, business_uuid: Uuid) -> u64 {
+    let user = req_user_uuid.as_u64_pair();
+    let business = business_uuid.as_u64_pair();
+
+    [user.0, user.1, business.0, business.1]
+        .into_iter()
+        .fold(seed, |acc, part| SimulationRng::tick_seed(acc, part as i64))
+}
+
+/// Sorts the actions of a tick into execution order and pairs each one with the business it
+/// contests, determined against the state before any of them ran.
+pub fn order_actions(
+    queued_actions: Vec<QueuedAction>,
+    seed: u64,
+    state: &GameState,
+) -> Vec<(QueuedAction, Option<Uuid>)> {
+    let mut ordered: Vec<(QueuedAction, Option<Uuid>)> = queued_actions
+        .into_iter()
+        .map(|queued_action| {
+            let business_uuid = contested_business_uuid(&queued_action.payload.details, state);
+            (queued_action, business_uuid)
+        })
+        .collect();
+
+    // The sort is stable, so uncontested actions and the actions of the same user keep their queue order
+    ordered.sort_by_cached_key(|(queued_action, business_uuid)| {
+        (
+            queued_action.payload.details.get_order(),
+            business_uuid.map(|business_uuid| {
+                Reverse(contest_priority(
+                    seed,
+                    queued_action.payload.req_user_uuid,
+                    business_uuid,
+                ))
+            }),
+        )
+    });
+
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::action::QueuedActionPayload,
+        domain::economy::{
+            business::model::Business, business_listing::model::BusinessListing,
+            business_offer::model::BusinessOffer,
+        },
+    };
+
+    fn acquire_action(req_user_uuid: Uuid, business_listing_uuid: Uuid) -> QueuedAction {
+        QueuedAction {
+            id: req_user_uuid.to_string(),
+            payload: QueuedActionPayload {
+                request_uuid: Uuid::now_v7(),
+                req_user_uuid,
+                details: ActionDetails::AcquireListedBusiness {
+                    business_listing_uuid,
+                },
+            },
+        }
+    }
+
+    fn accept_action(req_user_uuid: Uuid, business_offer_uuid: Uuid) -> QueuedAction {
+        QueuedAction {
+            id: req_user_uuid.to_string(),
+            payload: QueuedActionPayload {
+                request_uuid: Uuid::now_v7(),
+                req_user_uuid,
+                details: ActionDetails::AcceptBusinessOffer {
+                    business_offer_uuid,
+                },
+            },
+        }
+    }
+
+    fn state_with_listing(listing: BusinessListing) -> GameState {
+        let business = Business {
+            uuid: listing.business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: None,
+            name: "Contested Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+
+        GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![listing])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call()
+    }
+
+    #[test]
+    fn should_rank_contenders_independently_of_the_queue_order() {
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: Uuid::now_v7(),
+            seller_corporation_uuid: None,
+            asking_price: 100,
+            auction_closes_at_tick: None,
+        };
+        let state = state_with_listing(listing);
+        let user_uuids: Vec<Uuid> = (0..5).map(|_| Uuid::now_v7()).collect();
+
+        let ordered_users = |queue_order: [usize; 5]| -> Vec<Uuid> {
+            let actions = queue_order
+                .into_iter()
+                .map(|index| acquire_action(user_uuids[index], listing.uuid))
+                .collect();
+
+            order_actions(actions, 7, &state)
+                .into_iter()
+                .map(|(action, _)| action.payload.req_user_uuid)
+                .collect()
+        };
+
+        let first_users = ordered_users([0, 1, 2, 3, 4]);
+        let second_users = ordered_users([4, 2, 0, 3, 1]);
+
+        assert_eq!(first_users, second_users);
+    }
+
+    #[test]
+    fn should_keep_the_queue_order_of_uncontested_actions() {
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: Uuid::now_v7(),
+            seller_corporation_uuid: None,
+            asking_price: 100,
+            auction_closes_at_tick: None,
+        };
+        let state = state_with_listing(listing);
+        // Listings that don't exist can't be contested
+        let actions: Vec<QueuedAction> = (0..5)
+            .map(|_| acquire_action(Uuid::now_v7(), Uuid::now_v7()))
+            .collect();
+        let ids: Vec<String> = actions.iter().map(|action| action.id.clone()).collect();
+
+        let ordered = order_actions(actions, 7, &state);
+
+        assert!(ordered
+            .iter()
+            .all(|(_, business_uuid)| business_uuid.is_none()));
+        assert_eq!(
+            ordered
+                .into_iter()
+                .map(|(action, _)| action.id)
+                .collect::<Vec<_>>(),
+            ids
+        );
+    }
+
+    #[test]
+    fn should_rank_offer_acceptances_and_acquisitions_in_one_lottery() {
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: Uuid::now_v7(),
+            seller_corporation_uuid: None,
+            asking_price: 100,
+            auction_closes_at_tick: None,
+        };
+        let offer = BusinessOffer {
+            uuid: Uuid::now_v7(),
+            business_uuid: listing.business_uuid,
+            offering_corporation_uuid: Uuid::now_v7(),
+            target_corporation_uuid: None,
+            offer_price: 100,
+            expires_at_tick: 10,
+        };
+        let mut state = state_with_listing(listing);
+        state.business_offers_map.insert(offer.uuid, offer);
+        let acquiring_user_uuid = Uuid::now_v7();
+        let accepting_user_uuid = Uuid::now_v7();
+
+        for seed in 0..16 {
+            let actions = vec![
+                acquire_action(acquiring_user_uuid, listing.uuid),
+                accept_action(accepting_user_uuid, offer.uuid),
+            ];
+            let ordered = order_actions(actions, seed, &state);
+
+            let expected_first_user_uuid = [acquiring_user_uuid, accepting_user_uuid]
+                .into_iter()
+                .max_by_key(|user_uuid| contest_priority(seed, *user_uuid, listing.business_uuid))
+                .unwrap();
+            assert_eq!(ordered[0].0.payload.req_user_uuid, expected_first_user_uuid);
+            assert!(ordered
+                .iter()
+                .all(|(_, business_uuid)| *business_uuid == Some(listing.business_uuid)));
+        }
+    }
+}
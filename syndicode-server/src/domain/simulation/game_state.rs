@@ -1,13 +1,33 @@
+use super::{
+    changes::{self, PersistedFingerprints, StateChanges},
+    state_diff::PersistedOwners,
+};
 use crate::domain::{
+    diplomacy::model::{treaty_type::TreatyType, Treaty},
     economy::{
-        building_ownership::model::BuildingOwnership, business::model::Business,
-        business_listing::model::BusinessListing, business_offer::model::BusinessOffer,
-        corporation::model::Corporation, market::model::Market,
+        auction_bid::model::AuctionBid,
+        building::model::{BuildingBonus, BuildingLocation},
+        building_ownership::model::BuildingOwnership,
+        business::model::Business,
+        business_listing::model::BusinessListing,
+        business_offer::model::BusinessOffer,
+        contract::model::Contract,
+        corporation::model::Corporation,
+        ledger::model::{entry_kind::LedgerEntryKind, LedgerEntry},
+        loan::model::Loan,
+        market::model::{Market, MarketSnapshot},
+        territory::model::Territory,
     },
-    unit::model::Unit,
+    espionage::model::Mole,
+    unit::model::{unit_type::UnitType, Unit},
 };
 use bon::bon;
-use std::collections::{HashMap, HashSet};
+use fnv::FnvHasher;
+use geo::{Distance, Haversine};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
 use uuid::Uuid;
 
 pub struct GameState {
@@ -20,10 +40,20 @@ pub struct GameState {
     pub businesses_map: HashMap<Uuid, Business>,
     pub business_listings_map: HashMap<Uuid, BusinessListing>,
     pub business_offers_map: HashMap<Uuid, BusinessOffer>,
+    pub loans_map: HashMap<Uuid, Loan>,
+    pub auction_bids_map: HashMap<Uuid, AuctionBid>,
+    pub moles_map: HashMap<Uuid, Mole>,
+    pub treaties_map: HashMap<Uuid, Treaty>,
+    pub contracts_map: HashMap<Uuid, Contract>,
     pub building_ownerships_map: HashMap<Uuid, BuildingOwnership>,
 
+    // Static World Data (not persisted per tick)
+    pub building_locations_map: HashMap<Uuid, BuildingLocation>,
+
     // Aggregates
     pub total_operation_expenses_by_market_uuid: HashMap<Uuid, i64>,
+    /// Whole cubic meters, summed as integers to stay independent of the iteration order
+    pub building_volume_by_business_uuid: HashMap<Uuid, i64>,
 
     // Indices
     pub business_uuids_by_market_uuid: HashMap<Uuid, Vec<Uuid>>,
@@ -32,7 +62,23 @@ pub struct GameState {
     pub business_uuids_by_corporation_uuid: HashMap<Uuid, Vec<Uuid>>,
     pub business_listing_uuids_by_corporation_uuid: HashMap<Uuid, Vec<Uuid>>,
     pub business_offer_uuids_by_corporation_uuid: HashMap<Uuid, Vec<Uuid>>,
+    pub loan_uuids_by_corporation_uuid: HashMap<Uuid, Vec<Uuid>>,
+    pub auction_bid_uuids_by_listing_uuid: HashMap<Uuid, Vec<Uuid>>,
+    pub mole_uuids_by_target_corporation_uuid: HashMap<Uuid, Vec<Uuid>>,
+    /// Treaties are indexed under both parties.
+    pub treaty_uuids_by_corporation_uuid: HashMap<Uuid, Vec<Uuid>>,
     pub unit_uuids_by_corporation_uuid: HashMap<Uuid, Vec<Uuid>>,
+    pub building_uuid_by_gml_id: HashMap<String, Uuid>,
+    pub building_uuids_by_business_uuid: HashMap<Uuid, Vec<Uuid>>,
+
+    // Change Tracking
+    pub(super) persisted_fingerprints: PersistedFingerprints,
+    pub(super) persisted_owners: PersistedOwners,
+
+    // Ledger of the tick being calculated (appended to the history, never loaded)
+    pub ledger_entries: Vec<LedgerEntry>,
+    // Market snapshots of the tick being calculated (appended to the history, never loaded)
+    pub market_snapshots: Vec<MarketSnapshot>,
 }
 
 #[bon]
@@ -46,7 +92,13 @@ impl GameState {
         businesses_vec: Vec<Business>,
         business_listings_vec: Vec<BusinessListing>,
         business_offers_vec: Vec<BusinessOffer>,
+        #[builder(default)] loans_vec: Vec<Loan>,
+        #[builder(default)] auction_bids_vec: Vec<AuctionBid>,
+        #[builder(default)] moles_vec: Vec<Mole>,
+        #[builder(default)] treaties_vec: Vec<Treaty>,
+        #[builder(default)] contracts_vec: Vec<Contract>,
         building_ownerships_vec: Vec<BuildingOwnership>,
+        #[builder(default)] building_locations_vec: Vec<BuildingLocation>,
     ) -> Self {
         // Game State Maps
         let mut units_map = HashMap::with_capacity(units_vec.len());
@@ -55,10 +107,17 @@ impl GameState {
         let mut businesses_map = HashMap::with_capacity(businesses_vec.len());
         let mut business_listings_map = HashMap::with_capacity(business_listings_vec.len());
         let mut business_offers_map = HashMap::with_capacity(business_offers_vec.len());
+        let mut loans_map = HashMap::with_capacity(loans_vec.len());
+        let mut auction_bids_map = HashMap::with_capacity(auction_bids_vec.len());
+        let mut moles_map = HashMap::with_capacity(moles_vec.len());
+        let mut treaties_map = HashMap::with_capacity(treaties_vec.len());
+        let mut contracts_map = HashMap::with_capacity(contracts_vec.len());
         let mut building_ownerships_map = HashMap::with_capacity(building_ownerships_vec.len());
+        let mut building_locations_map = HashMap::with_capacity(building_locations_vec.len());
 
         // Aggregates
         let mut total_operation_expenses_by_market_uuid = HashMap::with_capacity(markets_vec.len());
+        let mut building_volume_by_business_uuid = HashMap::with_capacity(businesses_vec.len());
 
         // Indices
         let mut business_uuids_by_market_uuid = HashMap::with_capacity(markets_vec.len());
@@ -69,7 +128,15 @@ impl GameState {
             HashMap::with_capacity(corporations_vec.len());
         let mut business_offer_uuids_by_corporation_uuid =
             HashMap::with_capacity(corporations_vec.len());
+        let mut loan_uuids_by_corporation_uuid = HashMap::with_capacity(corporations_vec.len());
+        let mut auction_bid_uuids_by_listing_uuid =
+            HashMap::with_capacity(business_listings_vec.len());
+        let mut mole_uuids_by_target_corporation_uuid =
+            HashMap::with_capacity(corporations_vec.len());
+        let mut treaty_uuids_by_corporation_uuid = HashMap::with_capacity(corporations_vec.len());
         let mut unit_uuids_by_corporation_uuid = HashMap::with_capacity(corporations_vec.len());
+        let mut building_uuid_by_gml_id = HashMap::with_capacity(building_locations_vec.len());
+        let mut building_uuids_by_business_uuid = HashMap::with_capacity(businesses_vec.len());
 
         for unit in units_vec {
             // unit_uuids_by_corporation_uuid
@@ -148,10 +215,112 @@ impl GameState {
             business_offers_map.insert(business_offer.uuid, business_offer);
         }
 
+        for loan in loans_vec {
+            // loan_uuids_by_corporation_uuid
+            let loan_uuids: &mut Vec<Uuid> = loan_uuids_by_corporation_uuid
+                .entry(loan.corporation_uuid)
+                .or_default();
+            loan_uuids.push(loan.uuid);
+
+            // loans_map
+            loans_map.insert(loan.uuid, loan);
+        }
+
+        for auction_bid in auction_bids_vec {
+            // auction_bid_uuids_by_listing_uuid
+            let auction_bid_uuids: &mut Vec<Uuid> = auction_bid_uuids_by_listing_uuid
+                .entry(auction_bid.business_listing_uuid)
+                .or_default();
+            auction_bid_uuids.push(auction_bid.uuid);
+
+            // auction_bids_map
+            auction_bids_map.insert(auction_bid.uuid, auction_bid);
+        }
+
+        for mole in moles_vec {
+            // mole_uuids_by_target_corporation_uuid
+            let mole_uuids: &mut Vec<Uuid> = mole_uuids_by_target_corporation_uuid
+                .entry(mole.target_corporation_uuid)
+                .or_default();
+            mole_uuids.push(mole.uuid);
+
+            // moles_map
+            moles_map.insert(mole.uuid, mole);
+        }
+
+        for treaty in treaties_vec {
+            // treaty_uuids_by_corporation_uuid
+            for corporation_uuid in [
+                treaty.proposer_corporation_uuid,
+                treaty.partner_corporation_uuid,
+            ] {
+                let treaty_uuids: &mut Vec<Uuid> = treaty_uuids_by_corporation_uuid
+                    .entry(corporation_uuid)
+                    .or_default();
+                treaty_uuids.push(treaty.uuid);
+            }
+
+            // treaties_map
+            treaties_map.insert(treaty.uuid, treaty);
+        }
+
+        for contract in contracts_vec {
+            contracts_map.insert(contract.uuid, contract);
+        }
+
         for building_ownership in building_ownerships_vec {
             building_ownerships_map.insert(building_ownership.building_uuid, building_ownership);
         }
 
+        for building_location in building_locations_vec {
+            // building_uuid_by_gml_id
+            building_uuid_by_gml_id
+                .insert(building_location.gml_id.clone(), building_location.uuid);
+
+            // building_locations_map
+            building_locations_map.insert(building_location.uuid, building_location);
+        }
+
+        for building_ownership in building_ownerships_map.values() {
+            // building_uuids_by_business_uuid
+            let building_uuids: &mut Vec<Uuid> = building_uuids_by_business_uuid
+                .entry(building_ownership.owning_business_uuid)
+                .or_default();
+            building_uuids.push(building_ownership.building_uuid);
+
+            // building_volume_by_business_uuid
+            if let Some(building_location) =
+                building_locations_map.get(&building_ownership.building_uuid)
+            {
+                let volume: &mut i64 = building_volume_by_business_uuid
+                    .entry(building_ownership.owning_business_uuid)
+                    .or_default();
+                *volume += building_location.volume.round() as i64;
+            }
+        }
+
+        // The state is built from what is persisted, so nothing is dirty yet
+        let persisted_fingerprints = PersistedFingerprints {
+            units: changes::fingerprint_all(&units_map),
+            corporations: changes::fingerprint_all(&corporations_map),
+            markets: changes::fingerprint_all(&markets_map),
+            businesses: changes::fingerprint_all(&businesses_map),
+            business_listings: changes::fingerprint_all(&business_listings_map),
+            business_offers: changes::fingerprint_all(&business_offers_map),
+            loans: changes::fingerprint_all(&loans_map),
+            auction_bids: changes::fingerprint_all(&auction_bids_map),
+            moles: changes::fingerprint_all(&moles_map),
+            treaties: changes::fingerprint_all(&treaties_map),
+            contracts: changes::fingerprint_all(&contracts_map),
+            building_ownerships: changes::fingerprint_all(&building_ownerships_map),
+        };
+        let persisted_owners = PersistedOwners::new(
+            &businesses_map,
+            &business_listings_map,
+            &units_map,
+            &building_ownerships_map,
+        );
+
         Self {
             last_processed_tick,
             units_map,
@@ -161,27 +330,320 @@ impl GameState {
             businesses_map,
             business_listings_map,
             business_offers_map,
+            loans_map,
+            auction_bids_map,
+            moles_map,
+            treaties_map,
+            contracts_map,
             building_ownerships_map,
+            building_locations_map,
             total_operation_expenses_by_market_uuid,
+            building_volume_by_business_uuid,
             corporation_uuid_by_user_uuid,
             business_uuids_by_market_uuid,
             business_uuids_by_corporation_uuid,
             business_listing_uuids_by_corporation_uuid,
             business_offer_uuids_by_corporation_uuid,
+            loan_uuids_by_corporation_uuid,
+            auction_bid_uuids_by_listing_uuid,
+            mole_uuids_by_target_corporation_uuid,
+            treaty_uuids_by_corporation_uuid,
             unit_uuids_by_corporation_uuid,
+            building_uuid_by_gml_id,
+            building_uuids_by_business_uuid,
+            persisted_fingerprints,
+            persisted_owners,
+            ledger_entries: Vec::new(),
+            market_snapshots: Vec::new(),
         }
     }
 
     // --- Mutators ---
     pub fn add_unit(&mut self, unit: Unit) {
+        let unit_uuids = self
+            .unit_uuids_by_corporation_uuid
+            .entry(unit.corporation_uuid)
+            .or_default();
+        if !unit_uuids.contains(&unit.uuid) {
+            unit_uuids.push(unit.uuid);
+        }
+
         self.units_map.insert(unit.uuid, unit);
     }
+    pub fn remove_unit(&mut self, uuid: &Uuid) -> Option<Unit> {
+        let unit = self.units_map.remove(uuid)?;
+
+        if let Some(unit_uuids) = self
+            .unit_uuids_by_corporation_uuid
+            .get_mut(&unit.corporation_uuid)
+        {
+            unit_uuids.retain(|unit_uuid| unit_uuid != uuid);
+        }
+
+        Some(unit)
+    }
     pub fn remove_business_listing(&mut self, uuid: &Uuid) -> Option<BusinessListing> {
-        self.business_listings_map.remove(uuid)
+        let listing = self.business_listings_map.remove(uuid)?;
+
+        if let Some(seller_corporation_uuid) = listing.seller_corporation_uuid {
+            if let Some(listing_uuids) = self
+                .business_listing_uuids_by_corporation_uuid
+                .get_mut(&seller_corporation_uuid)
+            {
+                listing_uuids.retain(|listing_uuid| listing_uuid != uuid);
+            }
+        }
+
+        Some(listing)
     }
     pub fn add_business_listing(&mut self, listing: BusinessListing) {
+        if let Some(seller_corporation_uuid) = listing.seller_corporation_uuid {
+            let listing_uuids = self
+                .business_listing_uuids_by_corporation_uuid
+                .entry(seller_corporation_uuid)
+                .or_default();
+            if !listing_uuids.contains(&listing.uuid) {
+                listing_uuids.push(listing.uuid);
+            }
+        }
+
         self.business_listings_map.insert(listing.uuid, listing);
     }
+    pub fn add_business_offer(&mut self, offer: BusinessOffer) {
+        let offer_uuids = self
+            .business_offer_uuids_by_corporation_uuid
+            .entry(offer.offering_corporation_uuid)
+            .or_default();
+        if !offer_uuids.contains(&offer.uuid) {
+            offer_uuids.push(offer.uuid);
+        }
+
+        self.business_offers_map.insert(offer.uuid, offer);
+    }
+    pub fn remove_business_offer(&mut self, uuid: &Uuid) -> Option<BusinessOffer> {
+        let offer = self.business_offers_map.remove(uuid)?;
+
+        if let Some(offer_uuids) = self
+            .business_offer_uuids_by_corporation_uuid
+            .get_mut(&offer.offering_corporation_uuid)
+        {
+            offer_uuids.retain(|offer_uuid| offer_uuid != uuid);
+        }
+
+        Some(offer)
+    }
+    pub fn add_loan(&mut self, loan: Loan) {
+        let loan_uuids = self
+            .loan_uuids_by_corporation_uuid
+            .entry(loan.corporation_uuid)
+            .or_default();
+        if !loan_uuids.contains(&loan.uuid) {
+            loan_uuids.push(loan.uuid);
+        }
+
+        self.loans_map.insert(loan.uuid, loan);
+    }
+    pub fn remove_loan(&mut self, uuid: &Uuid) -> Option<Loan> {
+        let loan = self.loans_map.remove(uuid)?;
+
+        if let Some(loan_uuids) = self
+            .loan_uuids_by_corporation_uuid
+            .get_mut(&loan.corporation_uuid)
+        {
+            loan_uuids.retain(|loan_uuid| loan_uuid != uuid);
+        }
+
+        Some(loan)
+    }
+    pub fn add_auction_bid(&mut self, bid: AuctionBid) {
+        let bid_uuids = self
+            .auction_bid_uuids_by_listing_uuid
+            .entry(bid.business_listing_uuid)
+            .or_default();
+        if !bid_uuids.contains(&bid.uuid) {
+            bid_uuids.push(bid.uuid);
+        }
+
+        self.auction_bids_map.insert(bid.uuid, bid);
+    }
+    pub fn remove_auction_bid(&mut self, uuid: &Uuid) -> Option<AuctionBid> {
+        let bid = self.auction_bids_map.remove(uuid)?;
+
+        if let Some(bid_uuids) = self
+            .auction_bid_uuids_by_listing_uuid
+            .get_mut(&bid.business_listing_uuid)
+        {
+            bid_uuids.retain(|bid_uuid| bid_uuid != uuid);
+        }
+
+        Some(bid)
+    }
+    pub fn add_mole(&mut self, mole: Mole) {
+        let mole_uuids = self
+            .mole_uuids_by_target_corporation_uuid
+            .entry(mole.target_corporation_uuid)
+            .or_default();
+        if !mole_uuids.contains(&mole.uuid) {
+            mole_uuids.push(mole.uuid);
+        }
+
+        self.moles_map.insert(mole.uuid, mole);
+    }
+    pub fn remove_mole(&mut self, uuid: &Uuid) -> Option<Mole> {
+        let mole = self.moles_map.remove(uuid)?;
+
+        if let Some(mole_uuids) = self
+            .mole_uuids_by_target_corporation_uuid
+            .get_mut(&mole.target_corporation_uuid)
+        {
+            mole_uuids.retain(|mole_uuid| mole_uuid != uuid);
+        }
+
+        Some(mole)
+    }
+    pub fn add_treaty(&mut self, treaty: Treaty) {
+        for corporation_uuid in [
+            treaty.proposer_corporation_uuid,
+            treaty.partner_corporation_uuid,
+        ] {
+            let treaty_uuids = self
+                .treaty_uuids_by_corporation_uuid
+                .entry(corporation_uuid)
+                .or_default();
+            if !treaty_uuids.contains(&treaty.uuid) {
+                treaty_uuids.push(treaty.uuid);
+            }
+        }
+
+        self.treaties_map.insert(treaty.uuid, treaty);
+    }
+    pub fn remove_treaty(&mut self, uuid: &Uuid) -> Option<Treaty> {
+        let treaty = self.treaties_map.remove(uuid)?;
+
+        for corporation_uuid in [
+            treaty.proposer_corporation_uuid,
+            treaty.partner_corporation_uuid,
+        ] {
+            if let Some(treaty_uuids) = self
+                .treaty_uuids_by_corporation_uuid
+                .get_mut(&corporation_uuid)
+            {
+                treaty_uuids.retain(|treaty_uuid| treaty_uuid != uuid);
+            }
+        }
+
+        Some(treaty)
+    }
+    pub fn add_contract(&mut self, contract: Contract) {
+        self.contracts_map.insert(contract.uuid, contract);
+    }
+    pub fn remove_contract(&mut self, uuid: &Uuid) -> Option<Contract> {
+        self.contracts_map.remove(uuid)
+    }
+    /// Assigns a building to a business and keeps the index and the volume aggregate in sync.
+    pub fn add_building_ownership(&mut self, ownership: BuildingOwnership) {
+        let building_uuids = self
+            .building_uuids_by_business_uuid
+            .entry(ownership.owning_business_uuid)
+            .or_default();
+        if building_uuids.contains(&ownership.building_uuid) {
+            return;
+        }
+        building_uuids.push(ownership.building_uuid);
+
+        if let Some(building_location) = self.building_locations_map.get(&ownership.building_uuid) {
+            *self
+                .building_volume_by_business_uuid
+                .entry(ownership.owning_business_uuid)
+                .or_default() += building_location.volume.round() as i64;
+        }
+
+        self.building_ownerships_map
+            .insert(ownership.building_uuid, ownership);
+    }
+    /// Sets the owner of a business and keeps the ownership index in sync.
+    /// Returns the previous owner if the business exists.
+    pub fn set_business_owner(
+        &mut self,
+        business_uuid: &Uuid,
+        new_owner_uuid: Option<Uuid>,
+    ) -> Option<Option<Uuid>> {
+        let business = self.businesses_map.get_mut(business_uuid)?;
+        let previous_owner_uuid = business.owning_corporation_uuid;
+        business.owning_corporation_uuid = new_owner_uuid;
+
+        if let Some(previous_owner_uuid) = previous_owner_uuid {
+            if let Some(business_uuids) = self
+                .business_uuids_by_corporation_uuid
+                .get_mut(&previous_owner_uuid)
+            {
+                business_uuids.retain(|uuid| uuid != business_uuid);
+            }
+        }
+
+        if let Some(new_owner_uuid) = new_owner_uuid {
+            self.business_uuids_by_corporation_uuid
+                .entry(new_owner_uuid)
+                .or_default()
+                .push(*business_uuid);
+        }
+
+        Some(previous_owner_uuid)
+    }
+    /// Sets the operational expenses of a business and keeps the market total in sync.
+    /// Returns the previous operational expenses if the business exists.
+    pub fn set_business_operational_expenses(
+        &mut self,
+        business_uuid: &Uuid,
+        operational_expenses: i64,
+        game_tick: i64,
+    ) -> Option<i64> {
+        let business = self.businesses_map.get_mut(business_uuid)?;
+        let previous_operational_expenses = business.operational_expenses;
+        business.operational_expenses = operational_expenses;
+        business.operational_expenses_changed_at_tick = Some(game_tick);
+
+        *self
+            .total_operation_expenses_by_market_uuid
+            .entry(business.market_uuid)
+            .or_default() += operational_expenses - previous_operational_expenses;
+
+        Some(previous_operational_expenses)
+    }
+
+    /// Records a movement of a corporation's cash in the ledger of the tick being calculated.
+    /// Movements of zero are left out.
+    #[builder]
+    pub fn record_ledger_entry(
+        &mut self,
+        game_tick: i64,
+        corporation_uuid: Uuid,
+        kind: LedgerEntryKind,
+        amount: i64,
+        business_uuid: Option<Uuid>,
+    ) {
+        if amount == 0 {
+            return;
+        }
+
+        let sequence = self.ledger_entries.len() as i32;
+        self.ledger_entries.push(LedgerEntry {
+            game_tick,
+            sequence,
+            corporation_uuid,
+            kind,
+            amount,
+            business_uuid,
+        });
+    }
+    /// Hands over the recorded ledger entries, so they are only persisted once.
+    pub fn take_ledger_entries(&mut self) -> Vec<LedgerEntry> {
+        std::mem::take(&mut self.ledger_entries)
+    }
+    /// Hands over the recorded market snapshots, so they are only persisted once.
+    pub fn take_market_snapshots(&mut self) -> Vec<MarketSnapshot> {
+        std::mem::take(&mut self.market_snapshots)
+    }
 
     // --- Mutable Accessors ---
     pub fn ref_mut_corporation(&mut self, uuid: &Uuid) -> Option<&mut Corporation> {
@@ -190,9 +652,18 @@ impl GameState {
     pub fn ref_mut_business(&mut self, uuid: &Uuid) -> Option<&mut Business> {
         self.businesses_map.get_mut(uuid)
     }
+    pub fn ref_mut_loan(&mut self, uuid: &Uuid) -> Option<&mut Loan> {
+        self.loans_map.get_mut(uuid)
+    }
+    pub fn ref_mut_unit(&mut self, uuid: &Uuid) -> Option<&mut Unit> {
+        self.units_map.get_mut(uuid)
+    }
     pub fn ref_mut_business_listing(&mut self, uuid: &Uuid) -> Option<&mut BusinessListing> {
         self.business_listings_map.get_mut(uuid)
     }
+    pub fn ref_mut_treaty(&mut self, uuid: &Uuid) -> Option<&mut Treaty> {
+        self.treaties_map.get_mut(uuid)
+    }
 
     // --- Immutable Accessors ---
     pub fn get_corporation_uuid_by_user(&self, user_uuid: &Uuid) -> Option<&Uuid> {
@@ -207,4 +678,356 @@ impl GameState {
     pub fn ref_business_listing(&self, uuid: &Uuid) -> Option<&BusinessListing> {
         self.business_listings_map.get(uuid)
     }
+    pub fn ref_business_offer(&self, uuid: &Uuid) -> Option<&BusinessOffer> {
+        self.business_offers_map.get(uuid)
+    }
+    pub fn ref_loan(&self, uuid: &Uuid) -> Option<&Loan> {
+        self.loans_map.get(uuid)
+    }
+    pub fn ref_auction_bid(&self, uuid: &Uuid) -> Option<&AuctionBid> {
+        self.auction_bids_map.get(uuid)
+    }
+    pub fn ref_mole(&self, uuid: &Uuid) -> Option<&Mole> {
+        self.moles_map.get(uuid)
+    }
+    /// The mole the spying corporation has planted in the target corporation, if any.
+    pub fn get_mole(
+        &self,
+        spy_corporation_uuid: &Uuid,
+        target_corporation_uuid: &Uuid,
+    ) -> Option<&Mole> {
+        self.mole_uuids_by_target_corporation_uuid
+            .get(target_corporation_uuid)
+            .into_iter()
+            .flatten()
+            .filter_map(|mole_uuid| self.ref_mole(mole_uuid))
+            .find(|mole| mole.spy_corporation_uuid == *spy_corporation_uuid)
+    }
+    pub fn ref_treaty(&self, uuid: &Uuid) -> Option<&Treaty> {
+        self.treaties_map.get(uuid)
+    }
+    pub fn ref_contract(&self, uuid: &Uuid) -> Option<&Contract> {
+        self.contracts_map.get(uuid)
+    }
+    /// Pending and active treaties between the two corporations.
+    pub fn get_treaties_between(
+        &self,
+        corporation_uuid: &Uuid,
+        other_corporation_uuid: &Uuid,
+    ) -> impl Iterator<Item = &Treaty> + '_ {
+        let (corporation_uuid, other_corporation_uuid) =
+            (*corporation_uuid, *other_corporation_uuid);
+        self.treaty_uuids_by_corporation_uuid
+            .get(&corporation_uuid)
+            .into_iter()
+            .flatten()
+            .filter_map(|treaty_uuid| self.ref_treaty(treaty_uuid))
+            .filter(move |treaty| treaty.is_between(&corporation_uuid, &other_corporation_uuid))
+    }
+    /// The pending or active treaty of the given type between the two corporations, if any.
+    pub fn get_treaty(
+        &self,
+        corporation_uuid: &Uuid,
+        other_corporation_uuid: &Uuid,
+        treaty_type: TreatyType,
+    ) -> Option<&Treaty> {
+        self.get_treaties_between(corporation_uuid, other_corporation_uuid)
+            .find(|treaty| treaty.treaty_type == treaty_type)
+    }
+    /// Whether an active alliance or non-aggression pact keeps the corporations from attacking each other.
+    pub fn is_attack_forbidden(
+        &self,
+        corporation_uuid: &Uuid,
+        other_corporation_uuid: &Uuid,
+    ) -> bool {
+        self.get_treaties_between(corporation_uuid, other_corporation_uuid)
+            .any(|treaty| treaty.is_active() && treaty.treaty_type.forbids_attacks())
+    }
+    /// Whether an active trade pact waives the listing fee between the corporations.
+    pub fn is_listing_fee_waived(
+        &self,
+        corporation_uuid: &Uuid,
+        other_corporation_uuid: &Uuid,
+    ) -> bool {
+        self.get_treaties_between(corporation_uuid, other_corporation_uuid)
+            .any(|treaty| treaty.is_active() && treaty.treaty_type.waives_listing_fee())
+    }
+    pub fn ref_unit(&self, uuid: &Uuid) -> Option<&Unit> {
+        self.units_map.get(uuid)
+    }
+    pub fn ref_building_location(&self, uuid: &Uuid) -> Option<&BuildingLocation> {
+        self.building_locations_map.get(uuid)
+    }
+    pub fn get_building_uuid_by_gml_id(&self, gml_id: &str) -> Option<&Uuid> {
+        self.building_uuid_by_gml_id.get(gml_id)
+    }
+    /// Makes a building known to the state, e.g. the target of an action that wasn't referenced before.
+    pub fn add_building_location(&mut self, building_location: BuildingLocation) {
+        self.building_uuid_by_gml_id
+            .insert(building_location.gml_id.clone(), building_location.uuid);
+        self.building_locations_map
+            .insert(building_location.uuid, building_location);
+    }
+    /// Number of ticks a unit of the given type needs to travel between two buildings.
+    pub fn get_travel_ticks(
+        &self,
+        unit_type: UnitType,
+        from_building_uuid: &Uuid,
+        to_building_uuid: &Uuid,
+    ) -> Option<i64> {
+        let distance = self.get_building_distance(from_building_uuid, to_building_uuid)?;
+
+        Some(unit_type.travel_ticks(distance))
+    }
+    /// Distance between two buildings in meters.
+    pub fn get_building_distance(
+        &self,
+        from_building_uuid: &Uuid,
+        to_building_uuid: &Uuid,
+    ) -> Option<f64> {
+        let from = self.building_locations_map.get(from_building_uuid)?;
+        let to = self.building_locations_map.get(to_building_uuid)?;
+
+        Some(Haversine.distance(from.center(), to.center()))
+    }
+    /// Number of buildings of a business, its headquarter included, that grant the given bonus.
+    pub fn count_building_bonuses(&self, business_uuid: &Uuid, bonus: BuildingBonus) -> i64 {
+        self.building_uuids_by_business_uuid
+            .get(business_uuid)
+            .into_iter()
+            .flatten()
+            .filter_map(|building_uuid| self.building_locations_map.get(building_uuid))
+            .filter(|building_location| building_location.bonus() == Some(bonus))
+            .count() as i64
+    }
+    /// Upkeep a business pays per tick for the buildings it owns besides its headquarter.
+    pub fn building_upkeep(&self, business_uuid: &Uuid, upkeep_per_volume: f64) -> i64 {
+        let headquarter_building_uuid = self
+            .businesses_map
+            .get(business_uuid)
+            .map(|business| business.headquarter_building_uuid);
+
+        self.building_uuids_by_business_uuid
+            .get(business_uuid)
+            .into_iter()
+            .flatten()
+            .filter(|building_uuid| Some(**building_uuid) != headquarter_building_uuid)
+            .filter_map(|building_uuid| self.building_locations_map.get(building_uuid))
+            .map(|building_location| building_location.upkeep(upkeep_per_volume))
+            .sum()
+    }
+    /// Control of every ward in which corporations own buildings, ordered by city code.
+    pub fn territories(&self, control_threshold_bps: i64) -> Vec<Territory> {
+        self.ward_volumes(None)
+            .into_iter()
+            .map(|(city_code, volumes)| {
+                Territory::from_volumes(city_code, volumes, control_threshold_bps)
+            })
+            .collect()
+    }
+    /// The corporation controlling the ward a building is located in.
+    pub fn get_ward_controller(
+        &self,
+        building_uuid: &Uuid,
+        control_threshold_bps: i64,
+    ) -> Option<Uuid> {
+        let city_code = self
+            .building_locations_map
+            .get(building_uuid)?
+            .city_code
+            .as_deref()?;
+
+        self.ward_volumes(Some(city_code))
+            .into_iter()
+            .next()
+            .and_then(|(city_code, volumes)| {
+                Territory::from_volumes(city_code, volumes, control_threshold_bps)
+                    .controlling_corporation_uuid
+            })
+    }
+    /// Building volume every corporation owns per ward, optionally limited to a single ward.
+    fn ward_volumes(&self, only_city_code: Option<&str>) -> BTreeMap<String, BTreeMap<Uuid, i64>> {
+        let mut volumes: BTreeMap<String, BTreeMap<Uuid, i64>> = BTreeMap::new();
+
+        for (building_uuid, ownership) in &self.building_ownerships_map {
+            let Some(building_location) = self.building_locations_map.get(building_uuid) else {
+                continue;
+            };
+            let Some(city_code) = building_location.city_code.as_deref() else {
+                continue;
+            };
+            if only_city_code.is_some_and(|only_city_code| only_city_code != city_code) {
+                continue;
+            }
+            let Some(corporation_uuid) = self
+                .businesses_map
+                .get(&ownership.owning_business_uuid)
+                .and_then(|business| business.owning_corporation_uuid)
+            else {
+                continue;
+            };
+
+            *volumes
+                .entry(city_code.to_string())
+                .or_default()
+                .entry(corporation_uuid)
+                .or_default() += building_location.volume.round() as i64;
+        }
+
+        volumes
+    }
+    /// The corporation owning the business a building belongs to.
+    pub fn get_building_owner(&self, building_uuid: &Uuid) -> Option<Uuid> {
+        let ownership = self.building_ownerships_map.get(building_uuid)?;
+        self.businesses_map
+            .get(&ownership.owning_business_uuid)?
+            .owning_corporation_uuid
+    }
+    /// A building belongs to a corporation if it is owned by one of the corporation's businesses.
+    pub fn is_building_owned_by(&self, building_uuid: &Uuid, corporation_uuid: &Uuid) -> bool {
+        self.building_ownerships_map
+            .get(building_uuid)
+            .is_some_and(|ownership| {
+                self.is_business_owned_by(&ownership.owning_business_uuid, corporation_uuid)
+            })
+    }
+    pub fn is_business_owned_by(&self, business_uuid: &Uuid, corporation_uuid: &Uuid) -> bool {
+        self.business_uuids_by_corporation_uuid
+            .get(corporation_uuid)
+            .is_some_and(|business_uuids| business_uuids.contains(business_uuid))
+    }
+    /// Income a business earns per tick at its current operational expenses and market volume.
+    pub fn expected_business_income(&self, business_uuid: &Uuid) -> i64 {
+        let Some(business) = self.businesses_map.get(business_uuid) else {
+            return 0;
+        };
+        let Some(market) = self.markets_map.get(&business.market_uuid) else {
+            return 0;
+        };
+        let total_expenses = self
+            .total_operation_expenses_by_market_uuid
+            .get(&business.market_uuid)
+            .copied()
+            .unwrap_or_default();
+
+        if total_expenses <= 0 {
+            return 0;
+        }
+
+        (market.volume as f64 * business.operational_expenses as f64 / total_expenses as f64)
+            .round() as i64
+    }
+    /// Sum of the outstanding balances of all loans of a corporation.
+    pub fn outstanding_debt(&self, corporation_uuid: &Uuid) -> i64 {
+        self.loan_uuids_by_corporation_uuid
+            .get(corporation_uuid)
+            .into_iter()
+            .flatten()
+            .filter_map(|loan_uuid| self.loans_map.get(loan_uuid))
+            .map(|loan| loan.outstanding_balance)
+            .sum()
+    }
+    pub fn is_business_listed(&self, business_uuid: &Uuid) -> bool {
+        self.business_listings_map
+            .values()
+            .any(|listing| listing.business_uuid == *business_uuid)
+    }
+
+    // --- Change Tracking ---
+    /// The entities that were added, modified or removed since the state was last persisted.
+    pub fn changes(&self) -> StateChanges {
+        let persisted = &self.persisted_fingerprints;
+        StateChanges {
+            units: changes::diff(&self.units_map, &persisted.units),
+            corporations: changes::diff(&self.corporations_map, &persisted.corporations),
+            markets: changes::diff(&self.markets_map, &persisted.markets),
+            businesses: changes::diff(&self.businesses_map, &persisted.businesses),
+            business_listings: changes::diff(
+                &self.business_listings_map,
+                &persisted.business_listings,
+            ),
+            business_offers: changes::diff(&self.business_offers_map, &persisted.business_offers),
+            loans: changes::diff(&self.loans_map, &persisted.loans),
+            auction_bids: changes::diff(&self.auction_bids_map, &persisted.auction_bids),
+            moles: changes::diff(&self.moles_map, &persisted.moles),
+            treaties: changes::diff(&self.treaties_map, &persisted.treaties),
+            contracts: changes::diff(&self.contracts_map, &persisted.contracts),
+            building_ownerships: changes::diff(
+                &self.building_ownerships_map,
+                &persisted.building_ownerships,
+            ),
+        }
+    }
+    /// Records that the given changes have been written, so they are no longer reported as dirty.
+    pub fn mark_persisted(&mut self, state_changes: &StateChanges) {
+        let persisted = &mut self.persisted_fingerprints;
+        changes::apply(&mut persisted.units, &state_changes.units, |u| u.uuid);
+        changes::apply(
+            &mut persisted.corporations,
+            &state_changes.corporations,
+            |c| c.uuid,
+        );
+        changes::apply(&mut persisted.markets, &state_changes.markets, |m| m.uuid);
+        changes::apply(&mut persisted.businesses, &state_changes.businesses, |b| {
+            b.uuid
+        });
+        changes::apply(
+            &mut persisted.business_listings,
+            &state_changes.business_listings,
+            |bl| bl.uuid,
+        );
+        changes::apply(
+            &mut persisted.business_offers,
+            &state_changes.business_offers,
+            |bo| bo.uuid,
+        );
+        changes::apply(&mut persisted.loans, &state_changes.loans, |l| l.uuid);
+        changes::apply(
+            &mut persisted.auction_bids,
+            &state_changes.auction_bids,
+            |ab| ab.uuid,
+        );
+        changes::apply(&mut persisted.moles, &state_changes.moles, |m| m.uuid);
+        changes::apply(&mut persisted.treaties, &state_changes.treaties, |t| t.uuid);
+        changes::apply(&mut persisted.contracts, &state_changes.contracts, |c| {
+            c.uuid
+        });
+        changes::apply(
+            &mut persisted.building_ownerships,
+            &state_changes.building_ownerships,
+            |bo| bo.building_uuid,
+        );
+        self.persisted_owners.apply(state_changes);
+    }
+
+    /// Fingerprint of the simulated state that doesn't depend on the iteration order of the maps.
+    /// Static world data and the tick number are left out.
+    /// The value follows the derived `Hash` impls and the platform's integer width and byte order,
+    /// so checksums are only comparable between runs of the same build.
+    pub fn checksum(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hash_sorted(&self.units_map, &mut hasher);
+        hash_sorted(&self.corporations_map, &mut hasher);
+        hash_sorted(&self.markets_map, &mut hasher);
+        hash_sorted(&self.businesses_map, &mut hasher);
+        hash_sorted(&self.business_listings_map, &mut hasher);
+        hash_sorted(&self.business_offers_map, &mut hasher);
+        hash_sorted(&self.loans_map, &mut hasher);
+        hash_sorted(&self.auction_bids_map, &mut hasher);
+        hash_sorted(&self.moles_map, &mut hasher);
+        hash_sorted(&self.treaties_map, &mut hasher);
+        hash_sorted(&self.contracts_map, &mut hasher);
+        hash_sorted(&self.building_ownerships_map, &mut hasher);
+        hasher.finish()
+    }
+}
+
+fn hash_sorted<V: Hash>(map: &HashMap<Uuid, V>, hasher: &mut FnvHasher) {
+    let mut keys: Vec<&Uuid> = map.keys().collect();
+    keys.sort();
+    keys.len().hash(hasher);
+    for key in keys {
+        key.hash(hasher);
+        map[key].hash(hasher);
+    }
 }
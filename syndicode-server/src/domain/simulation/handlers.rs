@@ -1,4 +1,25 @@
+pub(super) mod accept_business_offer;
+pub(super) mod accept_treaty;
+pub(super) mod acquire_building;
 pub(super) mod acquire_listed_business;
+pub(super) mod attack_building;
+pub(super) mod break_treaty;
+pub(super) mod cancel_business_listing;
+pub(super) mod counter_intel;
+pub(super) mod create_contract;
 pub(super) mod create_corporation;
 pub(super) mod delete_corporation;
+pub(super) mod gather_intel;
+pub(super) mod list_business_for_sale;
+pub(super) mod make_business_offer;
+pub(super) mod move_unit;
+pub(super) mod place_auction_bid;
+pub(super) mod plant_mole;
+pub(super) mod propose_treaty;
+pub(super) mod reject_business_offer;
+pub(super) mod repay_loan;
+pub(super) mod set_operational_expenses;
 pub(super) mod spawn_unit;
+pub(super) mod take_loan;
+pub(super) mod transfer_funds;
+pub(super) mod withdraw_business_offer;
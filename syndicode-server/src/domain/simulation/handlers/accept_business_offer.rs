@@ -0,0 +1,415 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::{
+            business_listing::model::BusinessListing, ledger::model::entry_kind::LedgerEntryKind,
+        },
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_accept_business_offer(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    business_offer_uuid: Uuid,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    // --- 1. Pre-Saga Checks and Data Gathering (Immutable) ---
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let original_offer = *state.ref_business_offer(&business_offer_uuid).ok_or(
+        ActionError::BusinessOfferNotFound {
+            offer_uuid: business_offer_uuid,
+        },
+    )?;
+
+    if original_offer.target_corporation_uuid != Some(req_corporation_uuid) {
+        return Err(ActionError::BusinessOfferNotTargetedAtCorporation {
+            offer_uuid: business_offer_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    let business_uuid = original_offer.business_uuid;
+    let offer_price = original_offer.offer_price;
+    let buyer_corporation_uuid = original_offer.offering_corporation_uuid;
+
+    if state.ref_business(&business_uuid).is_none() {
+        return Err(ActionError::BusinessNotFoundDuringChecks { business_uuid });
+    }
+
+    // The business might have changed hands since the offer was made
+    if !state.is_business_owned_by(&business_uuid, &req_corporation_uuid) {
+        return Err(ActionError::BusinessNotOwnedByCorporation {
+            business_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    if state.ref_corporation(&buyer_corporation_uuid).is_none() {
+        return Err(ActionError::CorporationNotFoundDuringChecks {
+            corporation_uuid: buyer_corporation_uuid,
+        });
+    }
+
+    // A listing of the sold business would otherwise stay open for the new owner
+    let original_listing: Option<BusinessListing> = state
+        .business_listings_map
+        .values()
+        .find(|listing| listing.business_uuid == business_uuid)
+        .copied();
+
+    // --- 2. Define and Execute the Saga ---
+    let mut executor = SagaExecutor::new(state);
+
+    // --- Step 1: Release escrow to the seller ---
+    saga_step!(
+        executor,
+        "Credit Seller",
+        |state: &mut GameState| {
+            let corp = state.ref_mut_corporation(&req_corporation_uuid).ok_or(
+                ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: req_corporation_uuid,
+                    step_description: "Credit Seller",
+                },
+            )?;
+            corp.cash_balance += offer_price;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corp) = state.ref_mut_corporation(&req_corporation_uuid) {
+                corp.cash_balance -= offer_price;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Credit Seller) - Failed to find selling corporation {} to debit back.", req_corporation_uuid);
+            }
+        }
+    );
+
+    // --- Step 2: Change Ownership ---
+    saga_step!(
+        executor,
+        "Transfer Ownership",
+        |state: &mut GameState| {
+            state
+                .set_business_owner(&business_uuid, Some(buyer_corporation_uuid))
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "Business",
+                    entity_id: business_uuid,
+                    step_description: "Transfer Ownership",
+                })
+                .map(|_| ())
+        },
+        |state: &mut GameState| {
+            if state
+                .set_business_owner(&business_uuid, Some(req_corporation_uuid))
+                .is_none()
+            {
+                tracing::error!("CRITICAL: Saga Rollback (Transfer Ownership) - Failed to find business {} to restore ownership.", business_uuid);
+            }
+        }
+    );
+
+    // --- Step 3: Remove Offer ---
+    saga_step!(
+        executor,
+        "Remove Offer",
+        |state: &mut GameState| {
+            state
+                .remove_business_offer(&business_offer_uuid)
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "BusinessOffer",
+                    entity_id: business_offer_uuid,
+                    step_description: "Remove Offer",
+                })
+                .map(|_| ())
+        },
+        move |state: &mut GameState| {
+            state.add_business_offer(original_offer);
+        }
+    );
+
+    // --- Step 4: Remove Listing (if the business was listed) ---
+    if let Some(original_listing) = original_listing {
+        saga_step!(
+            executor,
+            "Remove Listing",
+            move |state: &mut GameState| {
+                state
+                    .remove_business_listing(&original_listing.uuid)
+                    .ok_or(ActionError::SagaEntityMissing {
+                        entity_type: "BusinessListing",
+                        entity_id: original_listing.uuid,
+                        step_description: "Remove Listing",
+                    })
+                    .map(|_| ())
+            },
+            move |state: &mut GameState| {
+                state.add_business_listing(original_listing);
+            }
+        );
+    }
+
+    executor.execute()?;
+
+    // The escrow is released into the purchase, so the buyer's acquisition shows up like a listed one
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(buyer_corporation_uuid)
+        .kind(LedgerEntryKind::OfferRefund)
+        .amount(offer_price)
+        .business_uuid(business_uuid)
+        .call();
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(buyer_corporation_uuid)
+        .kind(LedgerEntryKind::BusinessAcquisition)
+        .amount(-offer_price)
+        .business_uuid(business_uuid)
+        .call();
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(req_corporation_uuid)
+        .kind(LedgerEntryKind::BusinessSale)
+        .amount(offer_price)
+        .business_uuid(business_uuid)
+        .call();
+
+    // --- 3. Post-Saga: Construct Success Outcome ---
+    let final_business = state.ref_business(&business_uuid).ok_or_else(|| {
+        ActionError::InternalError(format!(
+            "CRITICAL: Business {business_uuid} disappeared after successful saga execution!"
+        ))
+    })?;
+
+    Ok(DomainActionOutcome::BusinessOfferAccepted {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_offer_uuid,
+        business_uuid,
+        market_uuid: final_business.market_uuid,
+        owning_corporation_uuid: buyer_corporation_uuid,
+        name: final_business.name.clone(),
+        operational_expenses: final_business.operational_expenses,
+        image_number: final_business.image_number,
+        offer_price,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::business_offer::model::BusinessOffer;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        buyer_corp_uuid: Uuid,
+        seller_user_uuid: Uuid,
+        seller_corp_uuid: Uuid,
+        business_uuid: Uuid,
+        offer_uuid: Uuid,
+        listing_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let buyer_corp_uuid = Uuid::now_v7();
+        let seller_user_uuid = Uuid::now_v7();
+        let seller_corp_uuid = Uuid::now_v7();
+        let business_uuid = Uuid::now_v7();
+        let offer_uuid = Uuid::now_v7();
+        let listing_uuid = Uuid::now_v7();
+
+        let buyer_corp = Corporation {
+            uuid: buyer_corp_uuid,
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::new("Buyer Corp".to_string()).unwrap(),
+            // The offer price has already been moved into escrow
+            cash_balance: 6000,
+            insolvent_since_tick: None,
+        };
+        let seller_corp = Corporation {
+            uuid: seller_corp_uuid,
+            user_uuid: seller_user_uuid,
+            name: CorporationName::new("Seller Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(seller_corp_uuid),
+            name: "Test Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let offer = BusinessOffer {
+            uuid: offer_uuid,
+            business_uuid,
+            offering_corporation_uuid: buyer_corp_uuid,
+            target_corporation_uuid: Some(seller_corp_uuid),
+            offer_price: 4000,
+            expires_at_tick: 20,
+        };
+        let listing = BusinessListing {
+            uuid: listing_uuid,
+            business_uuid,
+            seller_corporation_uuid: Some(seller_corp_uuid),
+            asking_price: 9000,
+            auction_closes_at_tick: None,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![buyer_corp, seller_corp])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![listing])
+            .business_offers_vec(vec![offer])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            buyer_corp_uuid,
+            seller_user_uuid,
+            seller_corp_uuid,
+            business_uuid,
+            offer_uuid,
+            listing_uuid,
+        }
+    }
+
+    fn accept(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::AcceptBusinessOffer {
+                business_offer_uuid,
+            },
+        };
+
+        handle_accept_business_offer()
+            .state(state)
+            .action_payload(&action)
+            .business_offer_uuid(business_offer_uuid)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_accept_success() {
+        let mut setup = setup_test_state();
+
+        let result = accept(&mut setup.state, setup.seller_user_uuid, setup.offer_uuid);
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::BusinessOfferAccepted { owning_corporation_uuid, .. }) if owning_corporation_uuid == setup.buyer_corp_uuid
+        ));
+
+        // Escrow is released to the seller, the buyer is not charged twice
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.seller_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            9000
+        );
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.buyer_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            6000
+        );
+
+        assert!(setup
+            .state
+            .is_business_owned_by(&setup.business_uuid, &setup.buyer_corp_uuid));
+        assert!(!setup
+            .state
+            .is_business_owned_by(&setup.business_uuid, &setup.seller_corp_uuid));
+        assert!(setup.state.ref_business_offer(&setup.offer_uuid).is_none());
+        assert!(setup
+            .state
+            .ref_business_listing(&setup.listing_uuid)
+            .is_none());
+    }
+
+    #[test]
+    fn test_accept_fail_not_target() {
+        let mut setup = setup_test_state();
+        let other_user_uuid = Uuid::now_v7();
+        let other_corp_uuid = Uuid::now_v7();
+        setup
+            .state
+            .corporation_uuid_by_user_uuid
+            .insert(other_user_uuid, other_corp_uuid);
+
+        let result = accept(&mut setup.state, other_user_uuid, setup.offer_uuid);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessOfferNotTargetedAtCorporation {
+                offer_uuid: setup.offer_uuid,
+                corporation_uuid: other_corp_uuid,
+            }
+        );
+        assert!(setup.state.ref_business_offer(&setup.offer_uuid).is_some());
+    }
+
+    #[test]
+    fn test_accept_fail_business_changed_owner() {
+        let mut setup = setup_test_state();
+        setup
+            .state
+            .set_business_owner(&setup.business_uuid, None)
+            .unwrap();
+
+        let result = accept(&mut setup.state, setup.seller_user_uuid, setup.offer_uuid);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessNotOwnedByCorporation {
+                business_uuid: setup.business_uuid,
+                corporation_uuid: setup.seller_corp_uuid,
+            }
+        );
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.seller_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            5000
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Accepts a treaty proposed to the requesting corporation. If the treaty forbids attacks, the
+/// units of both parties call off their attacks on each other.
+#[builder]
+pub fn handle_accept_treaty(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    treaty_uuid: Uuid,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let treaty = *state
+        .ref_treaty(&treaty_uuid)
+        .ok_or(ActionError::TreatyNotFound { treaty_uuid })?;
+
+    if treaty.partner_corporation_uuid != corporation_uuid {
+        return Err(ActionError::TreatyNotProposedToCorporation {
+            treaty_uuid,
+            corporation_uuid,
+        });
+    }
+
+    if treaty.is_active() {
+        return Err(ActionError::TreatyAlreadyAccepted { treaty_uuid });
+    }
+
+    let proposer_corporation_uuid = treaty.proposer_corporation_uuid;
+    let proposer_user_uuid = state
+        .ref_corporation(&proposer_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks {
+            corporation_uuid: proposer_corporation_uuid,
+        })?
+        .user_uuid;
+
+    let accepted_treaty = state
+        .ref_mut_treaty(&treaty_uuid)
+        .ok_or(ActionError::TreatyNotFound { treaty_uuid })?;
+    accepted_treaty.accepted_at_tick = Some(next_game_tick);
+
+    if treaty.treaty_type.forbids_attacks() {
+        call_off_attacks(state, &corporation_uuid, &proposer_corporation_uuid);
+        call_off_attacks(state, &proposer_corporation_uuid, &corporation_uuid);
+    }
+
+    outcomes.push(DomainActionOutcome::TreatyConcluded {
+        request_uuid: rng.next_uuid(),
+        tick_effective: next_game_tick,
+        req_user_uuid: proposer_user_uuid,
+        treaty_uuid,
+        treaty_type: treaty.treaty_type,
+        partner_corporation_uuid: corporation_uuid,
+    });
+
+    Ok(DomainActionOutcome::TreatyAccepted {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        treaty_uuid,
+        treaty_type: treaty.treaty_type,
+        proposer_corporation_uuid,
+    })
+}
+
+/// Withdraws the attack orders of the attacker's units that target buildings of the defender.
+/// Units on the march keep moving to their destination.
+fn call_off_attacks(state: &mut GameState, attacker_uuid: &Uuid, defender_uuid: &Uuid) {
+    let unit_uuids = state
+        .unit_uuids_by_corporation_uuid
+        .get(attacker_uuid)
+        .cloned()
+        .unwrap_or_default();
+
+    for unit_uuid in unit_uuids {
+        let Some(target_building_uuid) = state
+            .ref_unit(&unit_uuid)
+            .and_then(|unit| unit.attack_target_building_uuid)
+        else {
+            continue;
+        };
+
+        if state.is_building_owned_by(&target_building_uuid, defender_uuid) {
+            if let Some(unit) = state.ref_mut_unit(&unit_uuid) {
+                unit.attack_target_building_uuid = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::diplomacy::model::{treaty_type::TreatyType, Treaty};
+    use crate::domain::economy::building_ownership::model::BuildingOwnership;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+    use crate::domain::unit::model::{unit_type::UnitType, Unit};
+
+    struct TestSetup {
+        state: GameState,
+        proposer_user_uuid: Uuid,
+        partner_user_uuid: Uuid,
+        treaty_uuid: Uuid,
+        attacking_unit_uuid: Uuid,
+    }
+
+    fn setup_test_state(treaty_type: TreatyType) -> TestSetup {
+        let proposer_user_uuid = Uuid::now_v7();
+        let proposer_corp_uuid = Uuid::now_v7();
+        let partner_user_uuid = Uuid::now_v7();
+        let partner_corp_uuid = Uuid::now_v7();
+        let partner_business_uuid = Uuid::now_v7();
+        let partner_building_uuid = Uuid::now_v7();
+        let treaty_uuid = Uuid::now_v7();
+        let attacking_unit_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: proposer_corp_uuid,
+                user_uuid: proposer_user_uuid,
+                name: CorporationName::new("Proposer Corp".to_string()).unwrap(),
+                cash_balance: 10_000,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: partner_corp_uuid,
+                user_uuid: partner_user_uuid,
+                name: CorporationName::new("Partner Corp".to_string()).unwrap(),
+                cash_balance: 10_000,
+                insolvent_since_tick: None,
+            },
+        ];
+        let business = Business {
+            uuid: partner_business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(partner_corp_uuid),
+            name: "Partner Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: partner_building_uuid,
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let ownership = BuildingOwnership {
+            building_uuid: partner_building_uuid,
+            owning_business_uuid: partner_business_uuid,
+        };
+        let mut unit = Unit::spawn(
+            attacking_unit_uuid,
+            proposer_corp_uuid,
+            UnitType::Enforcer,
+            partner_building_uuid,
+        );
+        unit.attack_target_building_uuid = Some(partner_building_uuid);
+        let treaty = Treaty {
+            uuid: treaty_uuid,
+            treaty_type,
+            proposer_corporation_uuid: proposer_corp_uuid,
+            partner_corporation_uuid: partner_corp_uuid,
+            proposed_at_tick: 0,
+            accepted_at_tick: None,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![unit])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .treaties_vec(vec![treaty])
+            .building_ownerships_vec(vec![ownership])
+            .call();
+
+        TestSetup {
+            state,
+            proposer_user_uuid,
+            partner_user_uuid,
+            treaty_uuid,
+            attacking_unit_uuid,
+        }
+    }
+
+    fn accept(
+        state: &mut GameState,
+        outcomes: &mut Vec<DomainActionOutcome>,
+        user_uuid: Uuid,
+        treaty_uuid: Uuid,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::AcceptTreaty { treaty_uuid },
+        };
+
+        handle_accept_treaty()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(5))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .treaty_uuid(treaty_uuid)
+            .next_game_tick(2)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_accept_treaty_calls_off_attacks() {
+        let TestSetup {
+            mut state,
+            proposer_user_uuid,
+            partner_user_uuid,
+            treaty_uuid,
+            attacking_unit_uuid,
+        } = setup_test_state(TreatyType::NonAggression);
+        let mut outcomes = Vec::new();
+
+        let result = accept(&mut state, &mut outcomes, partner_user_uuid, treaty_uuid);
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::TreatyAccepted { .. })
+        ));
+        assert_eq!(
+            state.ref_treaty(&treaty_uuid).unwrap().accepted_at_tick,
+            Some(2)
+        );
+        assert_eq!(
+            state
+                .ref_unit(&attacking_unit_uuid)
+                .unwrap()
+                .attack_target_building_uuid,
+            None
+        );
+        assert!(matches!(
+            outcomes.as_slice(),
+            [DomainActionOutcome::TreatyConcluded { req_user_uuid, .. }]
+                if *req_user_uuid == proposer_user_uuid
+        ));
+    }
+
+    #[test]
+    fn test_accept_trade_pact_keeps_attacks() {
+        let TestSetup {
+            mut state,
+            partner_user_uuid,
+            treaty_uuid,
+            attacking_unit_uuid,
+            ..
+        } = setup_test_state(TreatyType::TradePact);
+        let mut outcomes = Vec::new();
+
+        accept(&mut state, &mut outcomes, partner_user_uuid, treaty_uuid).unwrap();
+
+        assert!(state
+            .ref_unit(&attacking_unit_uuid)
+            .unwrap()
+            .attack_target_building_uuid
+            .is_some());
+    }
+
+    #[test]
+    fn test_accept_treaty_fail_proposer() {
+        let TestSetup {
+            mut state,
+            proposer_user_uuid,
+            treaty_uuid,
+            ..
+        } = setup_test_state(TreatyType::Alliance);
+        let mut outcomes = Vec::new();
+
+        let result = accept(&mut state, &mut outcomes, proposer_user_uuid, treaty_uuid);
+
+        assert!(matches!(
+            result,
+            Err(ActionError::TreatyNotProposedToCorporation { .. })
+        ));
+        assert!(!state.ref_treaty(&treaty_uuid).unwrap().is_active());
+        assert!(outcomes.is_empty());
+    }
+}
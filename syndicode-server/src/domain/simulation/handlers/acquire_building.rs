@@ -0,0 +1,347 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::{
+            building_ownership::model::BuildingOwnership,
+            ledger::model::entry_kind::LedgerEntryKind,
+        },
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_acquire_building(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    business_uuid: Uuid,
+    building_gml_id: &str,
+    acquisition_radius_meters: f64,
+    base_price: i64,
+    price_per_volume: f64,
+    upkeep_per_volume: f64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let building_uuid = *state.get_building_uuid_by_gml_id(building_gml_id).ok_or(
+        ActionError::BuildingNotFound {
+            building_gml_id: building_gml_id.to_string(),
+        },
+    )?;
+
+    let Some(business) = state.ref_business(&business_uuid) else {
+        return Err(ActionError::BusinessNotFound { business_uuid });
+    };
+
+    if business.owning_corporation_uuid != Some(corporation_uuid) {
+        return Err(ActionError::BusinessNotOwnedByCorporation {
+            business_uuid,
+            corporation_uuid,
+        });
+    }
+
+    if state.building_ownerships_map.contains_key(&building_uuid) {
+        return Err(ActionError::BuildingAlreadyOwned {
+            building_gml_id: building_gml_id.to_string(),
+        });
+    }
+
+    // Buildings can only be bought in the neighbourhood of the business
+    let distance = state
+        .get_building_distance(&business.headquarter_building_uuid, &building_uuid)
+        .ok_or_else(|| {
+            ActionError::InternalError(format!(
+                "Failed to locate the headquarter of business '{business_uuid}'"
+            ))
+        })?;
+
+    if distance > acquisition_radius_meters {
+        return Err(ActionError::BuildingOutOfRange {
+            building_gml_id: building_gml_id.to_string(),
+            distance_meters: distance.round() as i64,
+            max_distance_meters: acquisition_radius_meters.round() as i64,
+        });
+    }
+
+    let (price, upkeep) = state
+        .ref_building_location(&building_uuid)
+        .map(|location| {
+            (
+                location.price(base_price, price_per_volume),
+                location.upkeep(upkeep_per_volume),
+            )
+        })
+        .ok_or_else(|| ActionError::BuildingNotFound {
+            building_gml_id: building_gml_id.to_string(),
+        })?;
+
+    let corporation = state
+        .ref_mut_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < price {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: price,
+            available: corporation.cash_balance,
+        });
+    }
+
+    corporation.cash_balance -= price;
+
+    state.add_building_ownership(BuildingOwnership {
+        building_uuid,
+        owning_business_uuid: business_uuid,
+    });
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::BuildingAcquisition)
+        .amount(-price)
+        .business_uuid(business_uuid)
+        .call();
+
+    Ok(DomainActionOutcome::BuildingAcquired {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_uuid,
+        building_gml_id: building_gml_id.to_string(),
+        price,
+        upkeep,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::building::model::BuildingLocation;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    const HEADQUARTER_GML_ID: &str = "BLD_HQ";
+    const NEARBY_GML_ID: &str = "BLD_NEARBY";
+    const DISTANT_GML_ID: &str = "BLD_DISTANT";
+    const RADIUS: f64 = 500.0;
+    const BASE_PRICE: i64 = 1000;
+    const PRICE_PER_VOLUME: f64 = 0.05;
+    const UPKEEP_PER_VOLUME: f64 = 0.0002;
+
+    struct Setup {
+        state: GameState,
+        user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        business_uuid: Uuid,
+        nearby_building_uuid: Uuid,
+    }
+
+    fn location(gml_id: &str, longitude: f64) -> BuildingLocation {
+        BuildingLocation {
+            uuid: Uuid::now_v7(),
+            gml_id: gml_id.to_string(),
+            longitude,
+            latitude: 35.68,
+            volume: 10_000.0,
+            usage_code: Some("402".to_string()),
+            city_code: None,
+        }
+    }
+
+    fn setup(cash_balance: i64) -> Setup {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        // Roughly 90 m and 1.8 km away from the headquarter
+        let headquarter = location(HEADQUARTER_GML_ID, 139.76);
+        let nearby = location(NEARBY_GML_ID, 139.761);
+        let distant = location(DISTANT_GML_ID, 139.78);
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Landlord Corp".to_string()).unwrap(),
+            cash_balance,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(corporation_uuid),
+            name: "Test Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: headquarter.uuid,
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let ownership = BuildingOwnership {
+            building_uuid: headquarter.uuid,
+            owning_business_uuid: business.uuid,
+        };
+        let business_uuid = business.uuid;
+        let nearby_building_uuid = nearby.uuid;
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![ownership])
+            .building_locations_vec(vec![headquarter, nearby, distant])
+            .call();
+
+        Setup {
+            state,
+            user_uuid,
+            corporation_uuid,
+            business_uuid,
+            nearby_building_uuid,
+        }
+    }
+
+    fn acquire(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        business_uuid: Uuid,
+        building_gml_id: &str,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::AcquireBuilding {
+                business_uuid,
+                building_gml_id: building_gml_id.to_string(),
+            },
+        };
+
+        handle_acquire_building()
+            .state(state)
+            .action_payload(&action)
+            .business_uuid(business_uuid)
+            .building_gml_id(building_gml_id)
+            .acquisition_radius_meters(RADIUS)
+            .base_price(BASE_PRICE)
+            .price_per_volume(PRICE_PER_VOLUME)
+            .upkeep_per_volume(UPKEEP_PER_VOLUME)
+            .next_game_tick(1)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn should_acquire_nearby_building() {
+        let Setup {
+            mut state,
+            user_uuid,
+            corporation_uuid,
+            business_uuid,
+            nearby_building_uuid,
+        } = setup(10_000);
+
+        let result = acquire(&mut state, user_uuid, business_uuid, NEARBY_GML_ID);
+
+        let Ok(DomainActionOutcome::BuildingAcquired { price, upkeep, .. }) = result else {
+            panic!("Expected BuildingAcquired outcome, got {result:?}");
+        };
+        assert_eq!(price, 1500);
+        assert_eq!(upkeep, 2);
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            8500
+        );
+        assert_eq!(
+            state
+                .building_ownerships_map
+                .get(&nearby_building_uuid)
+                .map(|ownership| ownership.owning_business_uuid),
+            Some(business_uuid)
+        );
+        assert_eq!(
+            state.building_volume_by_business_uuid.get(&business_uuid),
+            Some(&20_000)
+        );
+        assert_eq!(state.building_upkeep(&business_uuid, UPKEEP_PER_VOLUME), 2);
+        assert_eq!(state.ledger_entries.len(), 1);
+        assert_eq!(
+            state.ledger_entries[0].kind,
+            LedgerEntryKind::BuildingAcquisition
+        );
+    }
+
+    #[test]
+    fn should_fail_if_building_is_out_of_range() {
+        let Setup {
+            mut state,
+            user_uuid,
+            business_uuid,
+            ..
+        } = setup(10_000);
+
+        let result = acquire(&mut state, user_uuid, business_uuid, DISTANT_GML_ID);
+
+        assert!(matches!(
+            result,
+            Err(ActionError::BuildingOutOfRange { max_distance_meters: 500, distance_meters, .. })
+                if distance_meters > 1000
+        ));
+    }
+
+    #[test]
+    fn should_fail_if_building_is_already_owned() {
+        let Setup {
+            mut state,
+            user_uuid,
+            business_uuid,
+            ..
+        } = setup(10_000);
+
+        let result = acquire(&mut state, user_uuid, business_uuid, HEADQUARTER_GML_ID);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BuildingAlreadyOwned {
+                building_gml_id: HEADQUARTER_GML_ID.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_fail_with_insufficient_funds() {
+        let Setup {
+            mut state,
+            user_uuid,
+            corporation_uuid,
+            business_uuid,
+            nearby_building_uuid,
+        } = setup(1000);
+
+        let result = acquire(&mut state, user_uuid, business_uuid, NEARBY_GML_ID);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InsufficientFunds {
+                corporation_uuid,
+                required: 1500,
+                available: 1000,
+            }
+        );
+        assert!(!state
+            .building_ownerships_map
+            .contains_key(&nearby_building_uuid));
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
     application::action::QueuedActionPayload,
     domain::{
-        economy::business_listing::model::BusinessListing, // Import needed for compensation
+        economy::{
+            business_listing::model::BusinessListing, // Import needed for compensation
+            ledger::model::entry_kind::LedgerEntryKind,
+        },
         outcome::DomainActionOutcome,
         simulation::{game_state::GameState, saga::SagaExecutor, ActionError},
     },
@@ -33,6 +36,13 @@ pub fn handle_acquire_listed_business(
         },
     )?; // Clone here
 
+    // Auctioned businesses go to the highest bid when the auction closes
+    if original_listing.is_auction() {
+        return Err(ActionError::BusinessListingIsAuction {
+            listing_uuid: business_listing_uuid,
+        });
+    }
+
     let listing_price = original_listing.asking_price;
     let business_uuid = original_listing.business_uuid;
     let seller_corporation_uuid = original_listing.seller_corporation_uuid; // Option<Uuid>
@@ -134,23 +144,22 @@ pub fn handle_acquire_listed_business(
         "Transfer Ownership",
         // Forward -> Result<(), ActionError>
         |state: &mut GameState| {
-            let business =
-                state
-                    .ref_mut_business(&business_uuid)
-                    .ok_or(ActionError::SagaEntityMissing {
-                        // Specific saga error
-                        entity_type: "Business",
-                        entity_id: business_uuid,
-                        step_description: "Transfer Ownership",
-                    })?;
-            business.owning_corporation_uuid = Some(req_corporation_uuid);
-            Ok(())
+            state
+                .set_business_owner(&business_uuid, Some(req_corporation_uuid))
+                .ok_or(ActionError::SagaEntityMissing {
+                    // Specific saga error
+                    entity_type: "Business",
+                    entity_id: business_uuid,
+                    step_description: "Transfer Ownership",
+                })
+                .map(|_| ())
         },
         // Compensate (needs original_owner_uuid)
         move |state: &mut GameState| {
-            if let Some(business) = state.ref_mut_business(&business_uuid) {
-                business.owning_corporation_uuid = original_owner_uuid_clone;
-            } else {
+            if state
+                .set_business_owner(&business_uuid, original_owner_uuid_clone)
+                .is_none()
+            {
                 // Log critical error, compensation failed
                 tracing::error!("CRITICAL: Saga Rollback (Transfer Ownership) - Failed to find business {} to restore ownership.", business_uuid);
             }
@@ -185,6 +194,26 @@ pub fn handle_acquire_listed_business(
     // --- Execute the Saga ---
     executor.execute()?;
 
+    // The saga has been committed, so the cash movements are final
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(req_corporation_uuid)
+        .kind(LedgerEntryKind::BusinessAcquisition)
+        .amount(-listing_price)
+        .business_uuid(business_uuid)
+        .call();
+    if let Some(seller_uuid) = seller_corporation_uuid {
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(seller_uuid)
+            .kind(LedgerEntryKind::BusinessSale)
+            .amount(listing_price)
+            .business_uuid(business_uuid)
+            .call();
+    }
+
     // --- 3. Post-Saga: Construct Success Outcome ---
     // If execute() returned Ok, the state changes are committed.
     // Fetch final state if needed for the outcome.
@@ -238,12 +267,14 @@ mod tests {
             user_uuid: buyer_user_uuid,
             name: CorporationName::new("Buyer Corp".to_string()).unwrap(),
             cash_balance: 10000,
+            insolvent_since_tick: None,
         };
         let seller_corp = Corporation {
             uuid: seller_corp_uuid,
             user_uuid: seller_user_uuid,
             name: CorporationName::new("Seller Corp".to_string()).unwrap(),
             cash_balance: 5000,
+            insolvent_since_tick: None,
         };
         let business = Business {
             uuid: business_uuid,
@@ -253,12 +284,14 @@ mod tests {
             operational_expenses: 100,
             headquarter_building_uuid: Uuid::now_v7(),
             image_number: 5, // Test with a fixed image number
+            operational_expenses_changed_at_tick: None,
         };
         let listing = BusinessListing {
             uuid: listing_uuid,
             business_uuid,
             seller_corporation_uuid: Some(seller_corp_uuid),
             asking_price: 7500,
+            auction_closes_at_tick: None,
         };
 
         let state = GameState::build()
@@ -360,6 +393,28 @@ mod tests {
             state.ref_business_listing(&listing_uuid).is_none(),
             "Listing should be removed"
         );
+
+        // Assert ledger entries
+        let ledger: Vec<(Uuid, LedgerEntryKind, i64)> = state
+            .ledger_entries
+            .iter()
+            .map(|entry| (entry.corporation_uuid, entry.kind, entry.amount))
+            .collect();
+        assert_eq!(
+            ledger,
+            vec![
+                (
+                    buyer_corp_uuid,
+                    LedgerEntryKind::BusinessAcquisition,
+                    -listing_price
+                ),
+                (
+                    seller_corp_uuid,
+                    LedgerEntryKind::BusinessSale,
+                    listing_price
+                ),
+            ]
+        );
     }
 
     #[test]
@@ -445,6 +500,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_acquire_fail_auction() {
+        let (mut state, buyer_user_uuid, buyer_corp_uuid, _, listing_uuid, _) = setup_test_state();
+        state
+            .ref_mut_business_listing(&listing_uuid)
+            .unwrap()
+            .auction_closes_at_tick = Some(20);
+        let action = create_test_action(buyer_user_uuid, listing_uuid);
+
+        let result = handle_acquire_listed_business()
+            .state(&mut state)
+            .action_payload(&action)
+            .business_listing_uuid(listing_uuid)
+            .next_game_tick(10)
+            .req_user_uuid(buyer_user_uuid)
+            .call();
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessListingIsAuction { listing_uuid }
+        );
+        assert_eq!(
+            state
+                .ref_corporation(&buyer_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            10000
+        );
+    }
+
     #[test]
     fn test_acquire_fail_buyer_corp_not_found_by_user() {
         let (mut state, _, _, _, listing_uuid, business_uuid) = setup_test_state();
@@ -529,6 +614,10 @@ mod tests {
             initial_listing.as_ref().unwrap(),
             "Listing not rolled back (should exist and match original)"
         );
+        assert!(
+            state.ledger_entries.is_empty(),
+            "Rolled back saga should not be recorded in the ledger"
+        );
     }
 
     #[test]
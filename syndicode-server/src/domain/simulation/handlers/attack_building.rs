@@ -0,0 +1,369 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, ActionError},
+        unit::model::Unit,
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_attack_building(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    building_gml_id: &str,
+    unit_uuids: &[Uuid],
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    // --- 1. Checks and Data Gathering (Immutable) ---
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let mut unit_uuids = unit_uuids.to_vec();
+    unit_uuids.sort();
+    unit_uuids.dedup();
+
+    if unit_uuids.is_empty() {
+        return Err(ActionError::NoUnitsCommitted);
+    }
+
+    let building_uuid = *state.get_building_uuid_by_gml_id(building_gml_id).ok_or(
+        ActionError::BuildingNotFound {
+            building_gml_id: building_gml_id.to_string(),
+        },
+    )?;
+
+    let defender_corporation_uuid =
+        state
+            .get_building_owner(&building_uuid)
+            .ok_or(ActionError::BuildingNotAttackable {
+                building_gml_id: building_gml_id.to_string(),
+            })?;
+
+    if defender_corporation_uuid == corporation_uuid {
+        return Err(ActionError::CannotAttackOwnBuilding {
+            building_gml_id: building_gml_id.to_string(),
+            corporation_uuid,
+        });
+    }
+
+    if state.is_attack_forbidden(&corporation_uuid, &defender_corporation_uuid) {
+        return Err(ActionError::AttackForbiddenByTreaty {
+            building_gml_id: building_gml_id.to_string(),
+            defender_corporation_uuid,
+        });
+    }
+
+    // Validate every unit before touching any of them, so the action is all or nothing
+    let mut committed_units: Vec<Unit> = Vec::with_capacity(unit_uuids.len());
+    let mut arrival_tick = next_game_tick;
+
+    for unit_uuid in unit_uuids.iter().copied() {
+        let mut unit = *state
+            .ref_unit(&unit_uuid)
+            .ok_or(ActionError::UnitNotFound { unit_uuid })?;
+
+        if unit.corporation_uuid != corporation_uuid {
+            return Err(ActionError::UnitNotOwnedByCorporation {
+                unit_uuid,
+                corporation_uuid,
+            });
+        }
+
+        if unit.is_moving() {
+            return Err(ActionError::UnitAlreadyMoving { unit_uuid });
+        }
+
+        // Units stationed elsewhere march to the building and join the fight on arrival
+        if unit.building_uuid != building_uuid {
+            let travel_ticks = state
+                .get_travel_ticks(unit.unit_type, &unit.building_uuid, &building_uuid)
+                .ok_or(ActionError::InternalError(format!(
+                    "Building '{}' of unit '{}' has no known location",
+                    unit.building_uuid, unit_uuid
+                )))?;
+
+            unit.destination_building_uuid = Some(building_uuid);
+            unit.arrival_tick = Some(next_game_tick + travel_ticks);
+            arrival_tick = arrival_tick.max(next_game_tick + travel_ticks);
+        }
+
+        unit.attack_target_building_uuid = Some(building_uuid);
+        committed_units.push(unit);
+    }
+
+    // --- 2. Commit the units ---
+    for unit in committed_units {
+        state.add_unit(unit);
+    }
+
+    Ok(DomainActionOutcome::BuildingAttacked {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        building_gml_id: building_gml_id.to_string(),
+        unit_uuids,
+        arrival_tick,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::diplomacy::model::{treaty_type::TreatyType, Treaty};
+    use crate::domain::economy::building::model::BuildingLocation;
+    use crate::domain::economy::building_ownership::model::BuildingOwnership;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+    use crate::domain::unit::model::unit_type::UnitType;
+
+    const ATTACKER_GML_ID: &str = "DEBY_ATTACKER";
+    const DEFENDER_GML_ID: &str = "DEBY_DEFENDER";
+
+    struct TestSetup {
+        state: GameState,
+        attacker_user_uuid: Uuid,
+        attacker_corp_uuid: Uuid,
+        defender_corp_uuid: Uuid,
+        defender_building_uuid: Uuid,
+        local_unit_uuid: Uuid,
+        remote_unit_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let attacker_user_uuid = Uuid::now_v7();
+        let attacker_corp_uuid = Uuid::now_v7();
+        let defender_corp_uuid = Uuid::now_v7();
+        let attacker_building_uuid = Uuid::now_v7();
+        let defender_building_uuid = Uuid::now_v7();
+        let defender_business_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: attacker_corp_uuid,
+                user_uuid: attacker_user_uuid,
+                name: CorporationName::new("Attacker Corp".to_string()).unwrap(),
+                cash_balance: 1000,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: defender_corp_uuid,
+                user_uuid: Uuid::now_v7(),
+                name: CorporationName::new("Defender Corp".to_string()).unwrap(),
+                cash_balance: 1000,
+                insolvent_since_tick: None,
+            },
+        ];
+        let business = Business {
+            uuid: defender_business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(defender_corp_uuid),
+            name: "Defender Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: defender_building_uuid,
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let ownership = BuildingOwnership {
+            building_uuid: defender_building_uuid,
+            owning_business_uuid: defender_business_uuid,
+        };
+        let locations = vec![
+            BuildingLocation {
+                uuid: attacker_building_uuid,
+                gml_id: ATTACKER_GML_ID.to_string(),
+                longitude: 11.57,
+                latitude: 48.13,
+                volume: 1000.0,
+                usage_code: None,
+                city_code: None,
+            },
+            BuildingLocation {
+                uuid: defender_building_uuid,
+                gml_id: DEFENDER_GML_ID.to_string(),
+                longitude: 11.57,
+                latitude: 48.14,
+                volume: 1000.0,
+                usage_code: None,
+                city_code: None,
+            },
+        ];
+        let local_unit = Unit::spawn(
+            Uuid::now_v7(),
+            attacker_corp_uuid,
+            UnitType::Enforcer,
+            defender_building_uuid,
+        );
+        let remote_unit = Unit::spawn(
+            Uuid::now_v7(),
+            attacker_corp_uuid,
+            UnitType::Enforcer,
+            attacker_building_uuid,
+        );
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![local_unit, remote_unit])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![ownership])
+            .building_locations_vec(locations)
+            .call();
+
+        TestSetup {
+            state,
+            attacker_user_uuid,
+            attacker_corp_uuid,
+            defender_corp_uuid,
+            defender_building_uuid,
+            local_unit_uuid: local_unit.uuid,
+            remote_unit_uuid: remote_unit.uuid,
+        }
+    }
+
+    fn attack(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        building_gml_id: &str,
+        unit_uuids: Vec<Uuid>,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::AttackBuilding {
+                building_gml_id: building_gml_id.to_string(),
+                unit_uuids: unit_uuids.clone(),
+            },
+        };
+
+        handle_attack_building()
+            .state(state)
+            .action_payload(&action)
+            .building_gml_id(building_gml_id)
+            .unit_uuids(&unit_uuids)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_attack_commits_local_and_remote_units() {
+        let mut setup = setup_test_state();
+
+        let result = attack(
+            &mut setup.state,
+            setup.attacker_user_uuid,
+            DEFENDER_GML_ID,
+            vec![setup.local_unit_uuid, setup.remote_unit_uuid],
+        );
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::BuildingAttacked { arrival_tick: 15, ref unit_uuids, .. }) if unit_uuids.len() == 2
+        ));
+
+        let local_unit = setup.state.ref_unit(&setup.local_unit_uuid).unwrap();
+        assert!(local_unit.is_attacking(&setup.defender_building_uuid));
+
+        let remote_unit = setup.state.ref_unit(&setup.remote_unit_uuid).unwrap();
+        assert_eq!(
+            remote_unit.destination_building_uuid,
+            Some(setup.defender_building_uuid)
+        );
+        assert_eq!(remote_unit.arrival_tick, Some(15));
+        assert_eq!(
+            remote_unit.attack_target_building_uuid,
+            Some(setup.defender_building_uuid)
+        );
+    }
+
+    #[test]
+    fn test_attack_fail_own_building() {
+        let mut setup = setup_test_state();
+
+        let result = attack(
+            &mut setup.state,
+            setup.attacker_user_uuid,
+            ATTACKER_GML_ID,
+            vec![setup.local_unit_uuid],
+        );
+
+        // The attacker's building is not owned by any business
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BuildingNotAttackable {
+                building_gml_id: ATTACKER_GML_ID.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_attack_fail_is_all_or_nothing() {
+        let mut setup = setup_test_state();
+        let unknown_unit_uuid = Uuid::now_v7();
+
+        let result = attack(
+            &mut setup.state,
+            setup.attacker_user_uuid,
+            DEFENDER_GML_ID,
+            vec![setup.remote_unit_uuid, unknown_unit_uuid],
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::UnitNotFound {
+                unit_uuid: unknown_unit_uuid
+            }
+        );
+        let remote_unit = setup.state.ref_unit(&setup.remote_unit_uuid).unwrap();
+        assert!(!remote_unit.is_moving());
+        assert_eq!(remote_unit.attack_target_building_uuid, None);
+        assert_eq!(
+            setup
+                .state
+                .get_corporation_uuid_by_user(&setup.attacker_user_uuid),
+            Some(&setup.attacker_corp_uuid)
+        );
+    }
+
+    #[test]
+    fn test_attack_fail_forbidden_by_treaty() {
+        let mut setup = setup_test_state();
+        setup.state.add_treaty(Treaty {
+            uuid: Uuid::now_v7(),
+            treaty_type: TreatyType::NonAggression,
+            proposer_corporation_uuid: setup.defender_corp_uuid,
+            partner_corporation_uuid: setup.attacker_corp_uuid,
+            proposed_at_tick: 1,
+            accepted_at_tick: Some(2),
+        });
+
+        let result = attack(
+            &mut setup.state,
+            setup.attacker_user_uuid,
+            DEFENDER_GML_ID,
+            vec![setup.local_unit_uuid],
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::AttackForbiddenByTreaty {
+                building_gml_id: DEFENDER_GML_ID.to_string(),
+                defender_corporation_uuid: setup.defender_corp_uuid,
+            }
+        );
+        let local_unit = setup.state.ref_unit(&setup.local_unit_uuid).unwrap();
+        assert_eq!(local_unit.attack_target_building_uuid, None);
+    }
+}
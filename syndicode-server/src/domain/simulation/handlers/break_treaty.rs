@@ -0,0 +1,225 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Ends a treaty the requesting corporation is a party to. Breaking a pending proposal withdraws
+/// it as the proposer or declines it as the partner.
+#[builder]
+pub fn handle_break_treaty(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    treaty_uuid: Uuid,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let treaty = *state
+        .ref_treaty(&treaty_uuid)
+        .ok_or(ActionError::TreatyNotFound { treaty_uuid })?;
+
+    let counterparty_corporation_uuid =
+        treaty
+            .counterparty(&corporation_uuid)
+            .ok_or(ActionError::CorporationNotPartyToTreaty {
+                treaty_uuid,
+                corporation_uuid,
+            })?;
+
+    state.remove_treaty(&treaty_uuid);
+
+    if let Some(counterparty) = state.ref_corporation(&counterparty_corporation_uuid) {
+        outcomes.push(DomainActionOutcome::TreatyTerminated {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: counterparty.user_uuid,
+            treaty_uuid,
+            treaty_type: treaty.treaty_type,
+            breaking_corporation_uuid: corporation_uuid,
+            was_active: treaty.is_active(),
+        });
+    }
+
+    Ok(DomainActionOutcome::TreatyBroken {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        treaty_uuid,
+        treaty_type: treaty.treaty_type,
+        counterparty_corporation_uuid,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::diplomacy::model::{treaty_type::TreatyType, Treaty};
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        proposer_user_uuid: Uuid,
+        proposer_corp_uuid: Uuid,
+        partner_user_uuid: Uuid,
+        partner_corp_uuid: Uuid,
+        outsider_user_uuid: Uuid,
+        outsider_corp_uuid: Uuid,
+        treaty_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let proposer_user_uuid = Uuid::now_v7();
+        let proposer_corp_uuid = Uuid::now_v7();
+        let partner_user_uuid = Uuid::now_v7();
+        let partner_corp_uuid = Uuid::now_v7();
+        let outsider_user_uuid = Uuid::now_v7();
+        let outsider_corp_uuid = Uuid::now_v7();
+        let treaty_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: proposer_corp_uuid,
+                user_uuid: proposer_user_uuid,
+                name: CorporationName::new("Proposer Corp".to_string()).unwrap(),
+                cash_balance: 10_000,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: partner_corp_uuid,
+                user_uuid: partner_user_uuid,
+                name: CorporationName::new("Partner Corp".to_string()).unwrap(),
+                cash_balance: 10_000,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: outsider_corp_uuid,
+                user_uuid: outsider_user_uuid,
+                name: CorporationName::new("Outsider Corp".to_string()).unwrap(),
+                cash_balance: 10_000,
+                insolvent_since_tick: None,
+            },
+        ];
+        let treaty = Treaty {
+            uuid: treaty_uuid,
+            treaty_type: TreatyType::NonAggression,
+            proposer_corporation_uuid: proposer_corp_uuid,
+            partner_corporation_uuid: partner_corp_uuid,
+            proposed_at_tick: 0,
+            accepted_at_tick: Some(1),
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .treaties_vec(vec![treaty])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            proposer_user_uuid,
+            proposer_corp_uuid,
+            partner_user_uuid,
+            partner_corp_uuid,
+            outsider_user_uuid,
+            outsider_corp_uuid,
+            treaty_uuid,
+        }
+    }
+
+    fn break_treaty(
+        state: &mut GameState,
+        outcomes: &mut Vec<DomainActionOutcome>,
+        user_uuid: Uuid,
+        treaty_uuid: Uuid,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::BreakTreaty { treaty_uuid },
+        };
+
+        handle_break_treaty()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(9))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .treaty_uuid(treaty_uuid)
+            .next_game_tick(3)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_break_treaty_success() {
+        let TestSetup {
+            mut state,
+            proposer_user_uuid,
+            proposer_corp_uuid,
+            partner_user_uuid,
+            partner_corp_uuid,
+            treaty_uuid,
+            ..
+        } = setup_test_state();
+        let mut outcomes = Vec::new();
+        assert!(state.is_attack_forbidden(&proposer_corp_uuid, &partner_corp_uuid));
+
+        let result = break_treaty(&mut state, &mut outcomes, partner_user_uuid, treaty_uuid);
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::TreatyBroken { counterparty_corporation_uuid, .. })
+                if counterparty_corporation_uuid == proposer_corp_uuid
+        ));
+        assert!(state.ref_treaty(&treaty_uuid).is_none());
+        assert!(!state.is_attack_forbidden(&proposer_corp_uuid, &partner_corp_uuid));
+        assert!(matches!(
+            outcomes.as_slice(),
+            [DomainActionOutcome::TreatyTerminated { req_user_uuid, was_active: true, .. }]
+                if *req_user_uuid == proposer_user_uuid
+        ));
+    }
+
+    #[test]
+    fn test_break_treaty_fail_outsider() {
+        let TestSetup {
+            mut state,
+            outsider_user_uuid,
+            outsider_corp_uuid,
+            treaty_uuid,
+            ..
+        } = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = break_treaty(&mut state, &mut outcomes, outsider_user_uuid, treaty_uuid);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::CorporationNotPartyToTreaty {
+                treaty_uuid,
+                corporation_uuid: outsider_corp_uuid,
+            }
+        );
+        assert!(state.ref_treaty(&treaty_uuid).is_some());
+        assert!(outcomes.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_cancel_business_listing(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    business_listing_uuid: Uuid,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let listing = state.ref_business_listing(&business_listing_uuid).ok_or(
+        ActionError::BusinessListingNotFound {
+            listing_uuid: business_listing_uuid,
+        },
+    )?;
+
+    if listing.seller_corporation_uuid != Some(req_corporation_uuid) {
+        return Err(ActionError::BusinessListingNotOwnedByCorporation {
+            listing_uuid: business_listing_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    let removed_listing = state
+        .remove_business_listing(&business_listing_uuid)
+        .ok_or(ActionError::BusinessListingNotFound {
+            listing_uuid: business_listing_uuid,
+        })?;
+
+    Ok(DomainActionOutcome::BusinessListingCancelled {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_listing_uuid,
+        business_uuid: removed_listing.business_uuid,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business_listing::model::BusinessListing;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    fn setup_test_state() -> (GameState, Uuid, Uuid, Uuid) {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        let listing_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Seller Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let listing = BusinessListing {
+            uuid: listing_uuid,
+            business_uuid: Uuid::now_v7(),
+            seller_corporation_uuid: Some(corporation_uuid),
+            asking_price: 2500,
+            auction_closes_at_tick: None,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![listing])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        (state, user_uuid, corporation_uuid, listing_uuid)
+    }
+
+    fn cancel(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        business_listing_uuid: Uuid,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::CancelBusinessListing {
+                business_listing_uuid,
+            },
+        };
+
+        handle_cancel_business_listing()
+            .state(state)
+            .action_payload(&action)
+            .business_listing_uuid(business_listing_uuid)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_cancel_success() {
+        let (mut state, user_uuid, corporation_uuid, listing_uuid) = setup_test_state();
+
+        let result = cancel(&mut state, user_uuid, listing_uuid);
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::BusinessListingCancelled { business_listing_uuid, .. }) if business_listing_uuid == listing_uuid
+        ));
+        assert!(state.ref_business_listing(&listing_uuid).is_none());
+        assert_eq!(
+            state
+                .business_listing_uuids_by_corporation_uuid
+                .get(&corporation_uuid),
+            Some(&vec![])
+        );
+    }
+
+    #[test]
+    fn test_cancel_fail_not_seller() {
+        let (mut state, _, _, listing_uuid) = setup_test_state();
+        let other_user_uuid = Uuid::now_v7();
+        let other_corporation_uuid = Uuid::now_v7();
+        state
+            .corporation_uuid_by_user_uuid
+            .insert(other_user_uuid, other_corporation_uuid);
+
+        let result = cancel(&mut state, other_user_uuid, listing_uuid);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessListingNotOwnedByCorporation {
+                listing_uuid,
+                corporation_uuid: other_corporation_uuid,
+            }
+        );
+        assert!(state.ref_business_listing(&listing_uuid).is_some());
+    }
+
+    #[test]
+    fn test_cancel_fail_listing_not_found() {
+        let (mut state, user_uuid, _, _) = setup_test_state();
+        let non_existent_listing_uuid = Uuid::now_v7();
+
+        let result = cancel(&mut state, user_uuid, non_existent_listing_uuid);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessListingNotFound {
+                listing_uuid: non_existent_listing_uuid
+            }
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    config::EspionageConfig,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
+        outcome::{DomainActionOutcome, ExposedMole},
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Sweeps the corporation for moles. Every exposed mole is removed and the corporation that
+/// planted it learns that it has been lost.
+#[builder]
+pub fn handle_counter_intel(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    espionage_config: &EspionageConfig,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let cost = espionage_config.counter_intel_cost;
+    let corporation = state
+        .ref_mut_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < cost {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: cost,
+            available: corporation.cash_balance,
+        });
+    }
+
+    corporation.cash_balance -= cost;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::Espionage)
+        .amount(-cost)
+        .call();
+
+    let mut mole_uuids = state
+        .mole_uuids_by_target_corporation_uuid
+        .get(&corporation_uuid)
+        .cloned()
+        .unwrap_or_default();
+    mole_uuids.sort();
+
+    let mut exposed_moles = Vec::new();
+    for mole_uuid in mole_uuids {
+        if !rng.chance_percent(espionage_config.counter_intel_exposure_percent) {
+            continue;
+        }
+
+        let Some(mole) = state.remove_mole(&mole_uuid) else {
+            continue;
+        };
+
+        exposed_moles.push(ExposedMole {
+            mole_uuid,
+            spy_corporation_uuid: mole.spy_corporation_uuid,
+        });
+
+        if let Some(spy_corporation) = state.ref_corporation(&mole.spy_corporation_uuid) {
+            outcomes.push(DomainActionOutcome::MoleExposed {
+                request_uuid: rng.next_uuid(),
+                tick_effective: next_game_tick,
+                req_user_uuid: spy_corporation.user_uuid,
+                mole_uuid,
+                target_corporation_uuid: corporation_uuid,
+            });
+        }
+    }
+
+    Ok(DomainActionOutcome::CounterIntelConducted {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        cost,
+        exposed_moles,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+    use crate::domain::espionage::model::Mole;
+
+    fn corporation(name: &str) -> Corporation {
+        Corporation {
+            uuid: Uuid::now_v7(),
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::new(name.to_string()).unwrap(),
+            cash_balance: 10_000,
+            insolvent_since_tick: None,
+        }
+    }
+
+    #[test]
+    fn test_counter_intel_exposes_moles() {
+        let target = corporation("Target Corp");
+        let spy = corporation("Spy Corp");
+        let mole = Mole {
+            uuid: Uuid::now_v7(),
+            spy_corporation_uuid: spy.uuid,
+            target_corporation_uuid: target.uuid,
+            planted_at_tick: 0,
+        };
+        // Moles planted in other corporations are out of reach
+        let foreign_mole = Mole {
+            uuid: Uuid::now_v7(),
+            spy_corporation_uuid: target.uuid,
+            target_corporation_uuid: spy.uuid,
+            planted_at_tick: 0,
+        };
+
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![target.clone(), spy.clone()])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .moles_vec(vec![mole, foreign_mole])
+            .building_ownerships_vec(vec![])
+            .call();
+        let espionage_config = EspionageConfig {
+            counter_intel_cost: 3_000,
+            counter_intel_exposure_percent: 100,
+            ..EspionageConfig::default()
+        };
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: target.user_uuid,
+            details: ActionDetails::CounterIntel,
+        };
+        let mut outcomes = Vec::new();
+
+        let result = handle_counter_intel()
+            .state(&mut state)
+            .rng(&mut SimulationRng::from_seed(7))
+            .outcomes(&mut outcomes)
+            .action_payload(&action)
+            .espionage_config(&espionage_config)
+            .next_game_tick(1)
+            .req_user_uuid(target.user_uuid)
+            .call();
+
+        let Ok(DomainActionOutcome::CounterIntelConducted { exposed_moles, .. }) = result else {
+            panic!("Expected CounterIntelConducted outcome, got {result:?}");
+        };
+        assert_eq!(
+            exposed_moles,
+            vec![ExposedMole {
+                mole_uuid: mole.uuid,
+                spy_corporation_uuid: spy.uuid,
+            }]
+        );
+        assert!(state.ref_mole(&mole.uuid).is_none());
+        assert!(state.ref_mole(&foreign_mole.uuid).is_some());
+        assert_eq!(
+            state.ref_corporation(&target.uuid).unwrap().cash_balance,
+            7_000
+        );
+        assert!(matches!(
+            outcomes[..],
+            [DomainActionOutcome::MoleExposed {
+                req_user_uuid,
+                target_corporation_uuid,
+                ..
+            }] if req_user_uuid == spy.user_uuid && target_corporation_uuid == target.uuid
+        ));
+    }
+}
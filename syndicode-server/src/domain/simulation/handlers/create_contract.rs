@@ -0,0 +1,380 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::{
+            contract::model::{condition::ContractCondition, Contract},
+            ledger::model::entry_kind::LedgerEntryKind,
+        },
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Moves the contract amount into escrow until the contract is due.
+/// The payee can't withdraw from it, so it can rely on being paid if the condition holds.
+#[builder]
+pub fn handle_create_contract(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    payee_corporation_uuid: Uuid,
+    amount: i64,
+    due_at_tick: i64,
+    condition: ContractCondition,
+    max_contract_duration_ticks: i64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    // --- 1. Pre-Saga Checks ---
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if amount <= 0 {
+        return Err(ActionError::InvalidTransferAmount { amount });
+    }
+
+    // A contract is settled at the end of its due tick, so it can't be due in the tick it is created in
+    let min_due_at_tick = next_game_tick + 1;
+    let max_due_at_tick = next_game_tick + max_contract_duration_ticks;
+    if !(min_due_at_tick..=max_due_at_tick).contains(&due_at_tick) {
+        return Err(ActionError::InvalidContractDueTick {
+            due_at_tick,
+            min: min_due_at_tick,
+            max: max_due_at_tick,
+        });
+    }
+
+    if payee_corporation_uuid == corporation_uuid {
+        return Err(ActionError::CannotPayOwnCorporation { corporation_uuid });
+    }
+
+    let payee_user_uuid = state
+        .ref_corporation(&payee_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFound {
+            corporation_uuid: payee_corporation_uuid,
+        })?
+        .user_uuid;
+
+    if let ContractCondition::PayerOwnsBusiness { business_uuid } = condition {
+        if state.ref_business(&business_uuid).is_none() {
+            return Err(ActionError::BusinessNotFound { business_uuid });
+        }
+    }
+
+    let corporation = state
+        .ref_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < amount {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: amount,
+            available: corporation.cash_balance,
+        });
+    }
+
+    let contract = Contract::builder()
+        .uuid(rng.next_uuid())
+        .payer_corporation_uuid(corporation_uuid)
+        .payee_corporation_uuid(payee_corporation_uuid)
+        .amount(amount)
+        .condition(condition)
+        .created_at_tick(next_game_tick)
+        .due_at_tick(due_at_tick)
+        .build();
+
+    // --- 2. Define and Execute the Saga ---
+    let mut executor = SagaExecutor::new(state);
+
+    // --- Step 1: Move the contract amount into escrow ---
+    saga_step!(
+        executor,
+        "Escrow Contract Amount",
+        |state: &mut GameState| {
+            let corporation = state.ref_mut_corporation(&corporation_uuid).ok_or(
+                ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: corporation_uuid,
+                    step_description: "Escrow Contract Amount",
+                },
+            )?;
+            corporation.cash_balance -= amount;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corporation) = state.ref_mut_corporation(&corporation_uuid) {
+                corporation.cash_balance += amount;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Escrow Contract Amount) - Failed to find paying corporation {} to release escrow.", corporation_uuid);
+            }
+        }
+    );
+
+    // --- Step 2: Register Contract ---
+    saga_step!(
+        executor,
+        "Register Contract",
+        |state: &mut GameState| {
+            state.add_contract(contract);
+            Ok(())
+        },
+        |state: &mut GameState| {
+            state.remove_contract(&contract.uuid);
+        }
+    );
+
+    executor.execute()?;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::ContractEscrow)
+        .amount(-amount)
+        .maybe_business_uuid(condition.business_uuid())
+        .call();
+
+    // --- 3. Post-Saga: Notify the payee ---
+    outcomes.push(DomainActionOutcome::ContractReceived {
+        request_uuid: rng.next_uuid(),
+        tick_effective: next_game_tick,
+        req_user_uuid: payee_user_uuid,
+        contract_uuid: contract.uuid,
+        payer_corporation_uuid: corporation_uuid,
+        payee_corporation_uuid,
+        amount,
+        condition,
+        due_at_tick,
+    });
+
+    Ok(DomainActionOutcome::ContractCreated {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        contract_uuid: contract.uuid,
+        payer_corporation_uuid: corporation_uuid,
+        payee_corporation_uuid,
+        amount,
+        condition,
+        due_at_tick,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        payer_user_uuid: Uuid,
+        payer_corp_uuid: Uuid,
+        payee_user_uuid: Uuid,
+        payee_corp_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let payer_user_uuid = Uuid::now_v7();
+        let payer_corp_uuid = Uuid::now_v7();
+        let payee_user_uuid = Uuid::now_v7();
+        let payee_corp_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: payer_corp_uuid,
+                user_uuid: payer_user_uuid,
+                name: CorporationName::new("Payer Corp".to_string()).unwrap(),
+                cash_balance: 1000,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: payee_corp_uuid,
+                user_uuid: payee_user_uuid,
+                name: CorporationName::new("Payee Corp".to_string()).unwrap(),
+                cash_balance: 500,
+                insolvent_since_tick: None,
+            },
+        ];
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            payer_user_uuid,
+            payer_corp_uuid,
+            payee_user_uuid,
+            payee_corp_uuid,
+        }
+    }
+
+    fn create(
+        setup: &mut TestSetup,
+        amount: i64,
+        due_at_tick: i64,
+        condition: ContractCondition,
+        outcomes: &mut Vec<DomainActionOutcome>,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: setup.payer_user_uuid,
+            details: ActionDetails::CreateContract {
+                payee_corporation_uuid: setup.payee_corp_uuid,
+                amount,
+                due_at_tick,
+                condition,
+            },
+        };
+
+        handle_create_contract()
+            .state(&mut setup.state)
+            .rng(&mut SimulationRng::from_seed(0))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .payee_corporation_uuid(setup.payee_corp_uuid)
+            .amount(amount)
+            .due_at_tick(due_at_tick)
+            .condition(condition)
+            .max_contract_duration_ticks(100)
+            .next_game_tick(1)
+            .req_user_uuid(setup.payer_user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_create_contract_success() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = create(
+            &mut setup,
+            400,
+            10,
+            ContractCondition::PeaceHolds,
+            &mut outcomes,
+        );
+
+        let Ok(DomainActionOutcome::ContractCreated { contract_uuid, .. }) = result else {
+            panic!("Expected ContractCreated, got {:?}", result);
+        };
+        let contract = setup.state.ref_contract(&contract_uuid).unwrap();
+        assert_eq!(contract.payer_corporation_uuid, setup.payer_corp_uuid);
+        assert_eq!(contract.payee_corporation_uuid, setup.payee_corp_uuid);
+        assert_eq!(contract.amount, 400);
+        assert_eq!(contract.due_at_tick, 10);
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.payer_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            600
+        );
+        // The payee only receives the cash once the contract is settled
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.payee_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            500
+        );
+        assert!(matches!(
+            outcomes.as_slice(),
+            [DomainActionOutcome::ContractReceived { req_user_uuid, amount: 400, .. }]
+                if *req_user_uuid == setup.payee_user_uuid
+        ));
+        assert_eq!(setup.state.ledger_entries.len(), 1);
+        assert_eq!(
+            setup.state.ledger_entries[0].kind,
+            LedgerEntryKind::ContractEscrow
+        );
+        assert_eq!(setup.state.ledger_entries[0].amount, -400);
+    }
+
+    #[test]
+    fn test_create_contract_fail_due_tick_out_of_range() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = create(
+            &mut setup,
+            400,
+            1,
+            ContractCondition::Unconditional,
+            &mut outcomes,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InvalidContractDueTick {
+                due_at_tick: 1,
+                min: 2,
+                max: 101,
+            }
+        );
+        assert!(setup.state.contracts_map.is_empty());
+    }
+
+    #[test]
+    fn test_create_contract_fail_unknown_business() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+        let business_uuid = Uuid::now_v7();
+
+        let result = create(
+            &mut setup,
+            400,
+            10,
+            ContractCondition::PayerOwnsBusiness { business_uuid },
+            &mut outcomes,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessNotFound { business_uuid }
+        );
+    }
+
+    #[test]
+    fn test_create_contract_fail_insufficient_funds() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = create(
+            &mut setup,
+            5000,
+            10,
+            ContractCondition::Unconditional,
+            &mut outcomes,
+        );
+
+        assert!(matches!(
+            result,
+            Err(ActionError::InsufficientFunds {
+                required: 5000,
+                available: 1000,
+                ..
+            })
+        ));
+        assert!(setup.state.contracts_map.is_empty());
+        assert!(outcomes.is_empty());
+    }
+}
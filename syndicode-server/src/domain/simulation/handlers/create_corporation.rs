@@ -7,13 +7,14 @@ use crate::{
     domain::{
         economy::corporation::model::{name::CorporationName, Corporation},
         outcome::DomainActionOutcome,
-        simulation::{game_state::GameState, ActionError},
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
     },
 };
 
 #[builder]
 pub fn handle_create_corporation(
     state: &mut GameState,
+    rng: &mut SimulationRng,
     action_payload: &QueuedActionPayload,
     next_game_tick: i64,
     user_uuid: Uuid,
@@ -22,7 +23,7 @@ pub fn handle_create_corporation(
 ) -> Result<DomainActionOutcome, ActionError> {
     // If the corporation name is already taken, generate a random suffix for it
     while state.corporation_names.contains(corporation_name.as_str()) {
-        let suffix: String = rand::rng()
+        let suffix: String = (&mut *rng)
             .sample_iter(&Alphanumeric)
             .take(3)
             .map(char::from)
@@ -33,7 +34,7 @@ pub fn handle_create_corporation(
 
     let corporation_name_string = corporation_name.to_string();
 
-    let corporation = Corporation::new(user_uuid, corporation_name);
+    let corporation = Corporation::new(rng.next_uuid(), user_uuid, corporation_name);
 
     let outcome = DomainActionOutcome::CorporationCreated {
         corporation_uuid: corporation.uuid,
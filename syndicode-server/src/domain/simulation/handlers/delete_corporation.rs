@@ -6,7 +6,12 @@ use uuid::Uuid;
 use crate::{
     application::action::QueuedActionPayload,
     domain::{
-        economy::{business_offer::model::BusinessOffer, corporation::model::Corporation},
+        diplomacy::model::Treaty,
+        economy::{
+            business_offer::model::BusinessOffer, corporation::model::Corporation,
+            loan::model::Loan,
+        },
+        espionage::model::Mole,
         outcome::DomainActionOutcome,
         simulation::{game_state::GameState, saga::SagaExecutor, ActionError},
         unit::model::Unit,
@@ -22,6 +27,24 @@ pub fn handle_delete_corporation(
     corporation_uuid: Uuid,
     req_user_uuid: Uuid,
 ) -> Result<DomainActionOutcome, ActionError> {
+    let corporation = dissolve_corporation(state, corporation_uuid)?;
+
+    Ok(DomainActionOutcome::CorporationDeleted {
+        corporation_uuid,
+        user_uuid: corporation.user_uuid,
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+    })
+}
+
+/// Removes a corporation together with its units, offers, loans, treaties and the moles planted
+/// by or in it and releases its businesses and listings.
+/// Either everything is unwound or, if a step fails, nothing is. Returns the removed corporation.
+pub fn dissolve_corporation(
+    state: &mut GameState,
+    corporation_uuid: Uuid,
+) -> Result<Corporation, ActionError> {
     let mut executor = SagaExecutor::new(state);
 
     // Step1: Change owner in businesses
@@ -211,7 +234,117 @@ pub fn handle_delete_corporation(
         }
     );
 
-    // Step5: Delete corporation
+    // Step5: Write off loans
+    let captured_loan_uuids: Rc<RefCell<Vec<Uuid>>> = Rc::new(RefCell::new(Vec::new()));
+    let captured_loans: Rc<RefCell<Vec<Loan>>> = Rc::new(RefCell::new(Vec::new()));
+
+    saga_step!(
+        executor,
+        "Write off loans",
+        {
+            let captured_loan_uuids = Rc::clone(&captured_loan_uuids);
+            let captured_loans = Rc::clone(&captured_loans);
+
+            move |state: &mut GameState| {
+                if let Some(loan_uuids) = state
+                    .loan_uuids_by_corporation_uuid
+                    .remove(&corporation_uuid)
+                {
+                    captured_loan_uuids.borrow_mut().extend(loan_uuids.iter());
+
+                    for loan_uuid in loan_uuids {
+                        let Some(loan) = state.loans_map.remove(&loan_uuid) else {
+                            return Err(ActionError::LoanNotFound { loan_uuid });
+                        };
+
+                        captured_loans.borrow_mut().push(loan);
+                    }
+                }
+                Ok(())
+            }
+        },
+        move |state: &mut GameState| {
+            for loan in captured_loans.take().iter() {
+                state.loans_map.insert(loan.uuid, *loan);
+            }
+
+            let captured_loan_uuids = captured_loan_uuids.take();
+
+            state
+                .loan_uuids_by_corporation_uuid
+                .insert(corporation_uuid, captured_loan_uuids);
+        }
+    );
+
+    // Step6: Remove moles planted by or in the corporation
+    let captured_moles: Rc<RefCell<Vec<Mole>>> = Rc::new(RefCell::new(Vec::new()));
+
+    saga_step!(
+        executor,
+        "Remove moles",
+        {
+            let captured_moles = Rc::clone(&captured_moles);
+
+            move |state: &mut GameState| {
+                let mut mole_uuids: Vec<Uuid> = state
+                    .moles_map
+                    .values()
+                    .filter(|mole| {
+                        mole.spy_corporation_uuid == corporation_uuid
+                            || mole.target_corporation_uuid == corporation_uuid
+                    })
+                    .map(|mole| mole.uuid)
+                    .collect();
+                mole_uuids.sort();
+
+                for mole_uuid in mole_uuids {
+                    if let Some(mole) = state.remove_mole(&mole_uuid) {
+                        captured_moles.borrow_mut().push(mole);
+                    }
+                }
+                Ok(())
+            }
+        },
+        move |state: &mut GameState| {
+            for mole in captured_moles.take() {
+                state.add_mole(mole);
+            }
+        }
+    );
+
+    // Step7: Remove treaties the corporation is party to
+    let captured_treaties: Rc<RefCell<Vec<Treaty>>> = Rc::new(RefCell::new(Vec::new()));
+
+    saga_step!(
+        executor,
+        "Remove treaties",
+        {
+            let captured_treaties = Rc::clone(&captured_treaties);
+
+            move |state: &mut GameState| {
+                let mut treaty_uuids = state
+                    .treaty_uuids_by_corporation_uuid
+                    .get(&corporation_uuid)
+                    .cloned()
+                    .unwrap_or_default();
+                treaty_uuids.sort();
+
+                for treaty_uuid in treaty_uuids {
+                    if let Some(treaty) = state.remove_treaty(&treaty_uuid) {
+                        captured_treaties.borrow_mut().push(treaty);
+                    }
+                }
+                Ok(())
+            }
+        },
+        move |state: &mut GameState| {
+            for treaty in captured_treaties.take() {
+                state.add_treaty(treaty);
+            }
+        }
+    );
+
+    // Step8: Delete corporation
     let captured_corporation: Rc<RefCell<Option<Corporation>>> = Rc::new(RefCell::new(None));
 
     saga_step!(
@@ -257,15 +390,7 @@ pub fn handle_delete_corporation(
     // Execute the saga
     executor.execute()?;
 
-    let Some(corporation) = captured_corporation.take() else {
-        return Err(ActionError::CorporationNotCaptured { corporation_uuid });
-    };
-
-    Ok(DomainActionOutcome::CorporationDeleted {
-        corporation_uuid,
-        user_uuid: corporation.user_uuid,
-        request_uuid: action_payload.request_uuid,
-        tick_effective: next_game_tick,
-        req_user_uuid,
-    })
+    captured_corporation
+        .take()
+        .ok_or(ActionError::CorporationNotCaptured { corporation_uuid })
 }
@@ -0,0 +1,421 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    config::EspionageConfig,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
+        espionage::model::intel::{IntelReport, IntelType, ListingIntel},
+        outcome::{DomainActionOutcome, EspionageOperation},
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Spies on a rival corporation. The cost is due whether the operation succeeds or not.
+/// A mole planted in the rival raises the chance of success and lowers the chance of detection.
+#[builder]
+pub fn handle_gather_intel(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    target_corporation_uuid: Uuid,
+    intel_type: IntelType,
+    espionage_config: &EspionageConfig,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if target_corporation_uuid == corporation_uuid {
+        return Err(ActionError::CannotSpyOnOwnCorporation { corporation_uuid });
+    }
+
+    let target_user_uuid = state
+        .ref_corporation(&target_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFound {
+            corporation_uuid: target_corporation_uuid,
+        })?
+        .user_uuid;
+
+    let cost = espionage_config.gather_intel_cost;
+    let corporation = state
+        .ref_mut_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < cost {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: cost,
+            available: corporation.cash_balance,
+        });
+    }
+
+    corporation.cash_balance -= cost;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::Espionage)
+        .amount(-cost)
+        .call();
+
+    let (success_percent, detection_percent) =
+        match state.get_mole(&corporation_uuid, &target_corporation_uuid) {
+            Some(_) => (
+                espionage_config.gather_intel_success_percent
+                    + espionage_config.mole_success_bonus_percent,
+                espionage_config.mole_detection_percent,
+            ),
+            None => (
+                espionage_config.gather_intel_success_percent,
+                espionage_config.detection_percent,
+            ),
+        };
+
+    // Both rolls are always drawn so the outcome of one doesn't shift the other
+    let succeeded = rng.chance_percent(success_percent);
+    let detected = rng.chance_percent(detection_percent);
+
+    let report = succeeded.then(|| intel_report(state, &target_corporation_uuid, intel_type));
+
+    if detected {
+        outcomes.push(DomainActionOutcome::EspionageDetected {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: target_user_uuid,
+            spy_corporation_uuid: corporation_uuid,
+            operation: EspionageOperation::GatherIntel { intel_type },
+        });
+    }
+
+    Ok(DomainActionOutcome::IntelGathered {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        target_corporation_uuid,
+        intel_type,
+        cost,
+        report,
+        detected,
+    })
+}
+
+fn intel_report(
+    state: &GameState,
+    target_corporation_uuid: &Uuid,
+    intel_type: IntelType,
+) -> IntelReport {
+    match intel_type {
+        IntelType::Balance => IntelReport::Balance {
+            cash_balance: state
+                .ref_corporation(target_corporation_uuid)
+                .map(|corporation| corporation.cash_balance)
+                .unwrap_or_default(),
+        },
+        IntelType::Holdings => {
+            let mut business_uuids = state
+                .business_uuids_by_corporation_uuid
+                .get(target_corporation_uuid)
+                .cloned()
+                .unwrap_or_default();
+            business_uuids.sort();
+
+            IntelReport::Holdings { business_uuids }
+        }
+        IntelType::Listings => {
+            let mut listings: Vec<ListingIntel> = state
+                .business_listing_uuids_by_corporation_uuid
+                .get(target_corporation_uuid)
+                .into_iter()
+                .flatten()
+                .filter_map(|listing_uuid| state.ref_business_listing(listing_uuid))
+                .map(|listing| ListingIntel {
+                    business_listing_uuid: listing.uuid,
+                    business_uuid: listing.business_uuid,
+                    asking_price: listing.asking_price,
+                    auction_closes_at_tick: listing.auction_closes_at_tick,
+                })
+                .collect();
+            listings.sort_by_key(|listing| listing.business_listing_uuid);
+
+            IntelReport::Listings { listings }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+    use crate::domain::espionage::model::Mole;
+
+    struct TestSetup {
+        state: GameState,
+        spy_user_uuid: Uuid,
+        spy_corp_uuid: Uuid,
+        target_user_uuid: Uuid,
+        target_corp_uuid: Uuid,
+        business_uuid: Uuid,
+    }
+
+    fn setup_test_state(
+        moles: Vec<Mole>,
+        spy_corp_uuid: Uuid,
+        target_corp_uuid: Uuid,
+    ) -> TestSetup {
+        let spy_user_uuid = Uuid::now_v7();
+        let target_user_uuid = Uuid::now_v7();
+
+        let spy_corp = Corporation {
+            uuid: spy_corp_uuid,
+            user_uuid: spy_user_uuid,
+            name: CorporationName::new("Spy Corp".to_string()).unwrap(),
+            cash_balance: 10_000,
+            insolvent_since_tick: None,
+        };
+        let target_corp = Corporation {
+            uuid: target_corp_uuid,
+            user_uuid: target_user_uuid,
+            name: CorporationName::new("Target Corp".to_string()).unwrap(),
+            cash_balance: 77_000,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(target_corp_uuid),
+            name: "Target Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let business_uuid = business.uuid;
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![spy_corp, target_corp])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .moles_vec(moles)
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            target_user_uuid,
+            target_corp_uuid,
+            business_uuid,
+        }
+    }
+
+    fn config(success_percent: i64, detection_percent: i64) -> EspionageConfig {
+        EspionageConfig {
+            gather_intel_cost: 1_000,
+            gather_intel_success_percent: success_percent,
+            detection_percent,
+            mole_success_bonus_percent: 100,
+            mole_detection_percent: 0,
+            ..EspionageConfig::default()
+        }
+    }
+
+    fn gather(
+        state: &mut GameState,
+        outcomes: &mut Vec<DomainActionOutcome>,
+        user_uuid: Uuid,
+        target_corporation_uuid: Uuid,
+        intel_type: IntelType,
+        espionage_config: &EspionageConfig,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::GatherIntel {
+                target_corporation_uuid,
+                intel_type,
+            },
+        };
+
+        handle_gather_intel()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(7))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .target_corporation_uuid(target_corporation_uuid)
+            .intel_type(intel_type)
+            .espionage_config(espionage_config)
+            .next_game_tick(1)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_gather_intel_success_reveals_holdings() {
+        let TestSetup {
+            mut state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            target_corp_uuid,
+            business_uuid,
+            ..
+        } = setup_test_state(vec![], Uuid::now_v7(), Uuid::now_v7());
+        let mut outcomes = Vec::new();
+
+        let result = gather(
+            &mut state,
+            &mut outcomes,
+            spy_user_uuid,
+            target_corp_uuid,
+            IntelType::Holdings,
+            &config(100, 0),
+        );
+
+        let Ok(DomainActionOutcome::IntelGathered {
+            report, detected, ..
+        }) = result
+        else {
+            panic!("Expected IntelGathered outcome, got {result:?}");
+        };
+        assert_eq!(
+            report,
+            Some(IntelReport::Holdings {
+                business_uuids: vec![business_uuid]
+            })
+        );
+        assert!(!detected);
+        assert!(outcomes.is_empty());
+        assert_eq!(
+            state.ref_corporation(&spy_corp_uuid).unwrap().cash_balance,
+            9_000
+        );
+        assert_eq!(state.ledger_entries[0].kind, LedgerEntryKind::Espionage);
+    }
+
+    #[test]
+    fn test_gather_intel_failure_is_charged_and_detected() {
+        let TestSetup {
+            mut state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            target_user_uuid,
+            target_corp_uuid,
+            ..
+        } = setup_test_state(vec![], Uuid::now_v7(), Uuid::now_v7());
+        let mut outcomes = Vec::new();
+
+        let result = gather(
+            &mut state,
+            &mut outcomes,
+            spy_user_uuid,
+            target_corp_uuid,
+            IntelType::Balance,
+            &config(0, 100),
+        );
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::IntelGathered {
+                report: None,
+                detected: true,
+                ..
+            })
+        ));
+        assert_eq!(
+            state.ref_corporation(&spy_corp_uuid).unwrap().cash_balance,
+            9_000
+        );
+        assert_eq!(outcomes.len(), 1);
+        assert!(matches!(
+            outcomes[0],
+            DomainActionOutcome::EspionageDetected {
+                req_user_uuid,
+                spy_corporation_uuid,
+                operation: EspionageOperation::GatherIntel {
+                    intel_type: IntelType::Balance
+                },
+                ..
+            } if req_user_uuid == target_user_uuid && spy_corporation_uuid == spy_corp_uuid
+        ));
+    }
+
+    #[test]
+    fn test_gather_intel_with_mole_succeeds_undetected() {
+        let spy_corp_uuid = Uuid::now_v7();
+        let target_corp_uuid = Uuid::now_v7();
+        let mole = Mole {
+            uuid: Uuid::now_v7(),
+            spy_corporation_uuid: spy_corp_uuid,
+            target_corporation_uuid: target_corp_uuid,
+            planted_at_tick: 0,
+        };
+        let TestSetup {
+            mut state,
+            spy_user_uuid,
+            ..
+        } = setup_test_state(vec![mole], spy_corp_uuid, target_corp_uuid);
+        let mut outcomes = Vec::new();
+
+        let result = gather(
+            &mut state,
+            &mut outcomes,
+            spy_user_uuid,
+            target_corp_uuid,
+            IntelType::Balance,
+            &config(0, 100),
+        );
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::IntelGathered {
+                report: Some(IntelReport::Balance {
+                    cash_balance: 77_000
+                }),
+                detected: false,
+                ..
+            })
+        ));
+        assert!(outcomes.is_empty());
+    }
+
+    #[test]
+    fn test_gather_intel_fail_on_own_corporation() {
+        let TestSetup {
+            mut state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            ..
+        } = setup_test_state(vec![], Uuid::now_v7(), Uuid::now_v7());
+
+        let result = gather(
+            &mut state,
+            &mut Vec::new(),
+            spy_user_uuid,
+            spy_corp_uuid,
+            IntelType::Balance,
+            &config(100, 0),
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::CannotSpyOnOwnCorporation {
+                corporation_uuid: spy_corp_uuid
+            }
+        );
+        assert!(state.ledger_entries.is_empty());
+    }
+}
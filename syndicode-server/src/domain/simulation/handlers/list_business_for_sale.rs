@@ -0,0 +1,492 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::{
+            business_listing::model::BusinessListing, ledger::model::entry_kind::LedgerEntryKind,
+        },
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_list_business_for_sale(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    action_payload: &QueuedActionPayload,
+    business_uuid: Uuid,
+    asking_price: i64,
+    auction_duration_ticks: Option<i64>,
+    min_auction_duration_ticks: i64,
+    max_auction_duration_ticks: i64,
+    control_threshold_bps: i64,
+    listing_fee_percent: i64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if asking_price <= 0 {
+        return Err(ActionError::InvalidAskingPrice { asking_price });
+    }
+
+    if let Some(auction_duration_ticks) = auction_duration_ticks {
+        if !(min_auction_duration_ticks..=max_auction_duration_ticks)
+            .contains(&auction_duration_ticks)
+        {
+            return Err(ActionError::InvalidAuctionDuration {
+                auction_duration_ticks,
+                min: min_auction_duration_ticks,
+                max: max_auction_duration_ticks,
+            });
+        }
+    }
+
+    let Some(headquarter_building_uuid) = state
+        .ref_business(&business_uuid)
+        .map(|business| business.headquarter_building_uuid)
+    else {
+        return Err(ActionError::BusinessNotFound { business_uuid });
+    };
+
+    if !state.is_business_owned_by(&business_uuid, &req_corporation_uuid) {
+        return Err(ActionError::BusinessNotOwnedByCorporation {
+            business_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    if state.is_business_listed(&business_uuid) {
+        return Err(ActionError::BusinessAlreadyListed { business_uuid });
+    }
+
+    // The controller of the ward charges rivals for listing there, unless they have a trade pact
+    let fee_collector_uuid = state
+        .get_ward_controller(&headquarter_building_uuid, control_threshold_bps)
+        .filter(|controller_uuid| {
+            *controller_uuid != req_corporation_uuid
+                && !state.is_listing_fee_waived(controller_uuid, &req_corporation_uuid)
+        });
+    let listing_fee = asking_price * listing_fee_percent / 100;
+
+    if let Some(fee_collector_uuid) = fee_collector_uuid.filter(|_| listing_fee > 0) {
+        if state.ref_corporation(&fee_collector_uuid).is_none() {
+            return Err(ActionError::CorporationNotFoundDuringChecks {
+                corporation_uuid: fee_collector_uuid,
+            });
+        }
+
+        let seller = state.ref_mut_corporation(&req_corporation_uuid).ok_or(
+            ActionError::CorporationNotFoundDuringChecks {
+                corporation_uuid: req_corporation_uuid,
+            },
+        )?;
+
+        if seller.cash_balance < listing_fee {
+            return Err(ActionError::InsufficientFunds {
+                corporation_uuid: req_corporation_uuid,
+                required: listing_fee,
+                available: seller.cash_balance,
+            });
+        }
+
+        seller.cash_balance -= listing_fee;
+
+        let fee_collector = state.ref_mut_corporation(&fee_collector_uuid).ok_or(
+            ActionError::CorporationNotFoundDuringChecks {
+                corporation_uuid: fee_collector_uuid,
+            },
+        )?;
+        fee_collector.cash_balance += listing_fee;
+
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(req_corporation_uuid)
+            .kind(LedgerEntryKind::ListingFee)
+            .amount(-listing_fee)
+            .business_uuid(business_uuid)
+            .call();
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(fee_collector_uuid)
+            .kind(LedgerEntryKind::ListingFee)
+            .amount(listing_fee)
+            .business_uuid(business_uuid)
+            .call();
+    }
+
+    let listing = BusinessListing::builder()
+        .uuid(rng.next_uuid())
+        .business_uuid(business_uuid)
+        .seller_corporation_uuid(req_corporation_uuid)
+        .asking_price(asking_price)
+        .maybe_auction_closes_at_tick(auction_duration_ticks.map(|ticks| next_game_tick + ticks))
+        .build();
+
+    state.add_business_listing(listing);
+
+    Ok(DomainActionOutcome::BusinessListed {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_listing_uuid: listing.uuid,
+        business_uuid,
+        seller_corporation_uuid: req_corporation_uuid,
+        asking_price,
+        auction_closes_at_tick: listing.auction_closes_at_tick,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::diplomacy::model::{treaty_type::TreatyType, Treaty};
+    use crate::domain::economy::building::model::BuildingLocation;
+    use crate::domain::economy::building_ownership::model::BuildingOwnership;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    fn setup_test_state() -> (GameState, Uuid, Uuid, Uuid) {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        let business_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Seller Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(corporation_uuid),
+            name: "Test Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        (state, user_uuid, corporation_uuid, business_uuid)
+    }
+
+    fn create_test_action(user_uuid: Uuid, business_uuid: Uuid) -> QueuedActionPayload {
+        QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::ListBusinessForSale {
+                business_uuid,
+                asking_price: 2500,
+                auction_duration_ticks: None,
+            },
+        }
+    }
+
+    fn list(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        business_uuid: Uuid,
+        asking_price: i64,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        list_with_auction(state, user_uuid, business_uuid, asking_price, None)
+    }
+
+    fn list_with_auction(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        business_uuid: Uuid,
+        asking_price: i64,
+        auction_duration_ticks: Option<i64>,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = create_test_action(user_uuid, business_uuid);
+
+        handle_list_business_for_sale()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(0))
+            .action_payload(&action)
+            .business_uuid(business_uuid)
+            .asking_price(asking_price)
+            .maybe_auction_duration_ticks(auction_duration_ticks)
+            .min_auction_duration_ticks(5)
+            .max_auction_duration_ticks(100)
+            .control_threshold_bps(5000)
+            .listing_fee_percent(2)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_list_success() {
+        let (mut state, user_uuid, corporation_uuid, business_uuid) = setup_test_state();
+
+        let result = list(&mut state, user_uuid, business_uuid, 2500);
+
+        let Ok(DomainActionOutcome::BusinessListed {
+            business_listing_uuid,
+            seller_corporation_uuid,
+            asking_price,
+            ..
+        }) = result
+        else {
+            panic!("Expected BusinessListed outcome, got {result:?}");
+        };
+
+        assert_eq!(seller_corporation_uuid, corporation_uuid);
+        assert_eq!(asking_price, 2500);
+
+        let listing = state.ref_business_listing(&business_listing_uuid).unwrap();
+        assert_eq!(listing.business_uuid, business_uuid);
+        assert_eq!(listing.seller_corporation_uuid, Some(corporation_uuid));
+        assert_eq!(
+            state
+                .business_listing_uuids_by_corporation_uuid
+                .get(&corporation_uuid),
+            Some(&vec![business_listing_uuid])
+        );
+    }
+
+    /// Puts the headquarter of the listed business into a ward controlled by a landlord.
+    fn setup_ward(state: &mut GameState, business_uuid: Uuid) -> Uuid {
+        let landlord_user_uuid = Uuid::now_v7();
+        let landlord_uuid = Uuid::now_v7();
+        let landlord = Corporation {
+            uuid: landlord_uuid,
+            user_uuid: landlord_user_uuid,
+            name: CorporationName::new("Landlord Corp".to_string()).unwrap(),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        };
+        let landlord_business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(landlord_uuid),
+            name: "Landlord Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let location = |uuid: Uuid, volume: f64| BuildingLocation {
+            uuid,
+            gml_id: uuid.to_string(),
+            longitude: 139.7,
+            latitude: 35.69,
+            volume,
+            usage_code: None,
+            city_code: Some("13104".to_string()),
+        };
+        let headquarter_building_uuid = state
+            .ref_business(&business_uuid)
+            .unwrap()
+            .headquarter_building_uuid;
+
+        state.add_building_location(location(
+            landlord_business.headquarter_building_uuid,
+            30_000.0,
+        ));
+        state.add_building_location(location(headquarter_building_uuid, 10_000.0));
+        state.add_building_ownership(BuildingOwnership {
+            building_uuid: landlord_business.headquarter_building_uuid,
+            owning_business_uuid: landlord_business.uuid,
+        });
+        state.add_building_ownership(BuildingOwnership {
+            building_uuid: headquarter_building_uuid,
+            owning_business_uuid: business_uuid,
+        });
+        state
+            .corporation_uuid_by_user_uuid
+            .insert(landlord_user_uuid, landlord_uuid);
+        state.corporations_map.insert(landlord_uuid, landlord);
+        state
+            .businesses_map
+            .insert(landlord_business.uuid, landlord_business);
+
+        landlord_uuid
+    }
+
+    #[test]
+    fn test_list_pays_fee_to_ward_controller() {
+        let (mut state, user_uuid, corporation_uuid, business_uuid) = setup_test_state();
+        let landlord_uuid = setup_ward(&mut state, business_uuid);
+
+        list(&mut state, user_uuid, business_uuid, 2500).unwrap();
+
+        let cash_balance = |uuid: &Uuid| state.ref_corporation(uuid).unwrap().cash_balance;
+        assert_eq!(cash_balance(&corporation_uuid), 4950);
+        assert_eq!(cash_balance(&landlord_uuid), 1050);
+        assert_eq!(
+            state
+                .ledger_entries
+                .iter()
+                .map(|entry| (entry.corporation_uuid, entry.kind, entry.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (corporation_uuid, LedgerEntryKind::ListingFee, -50),
+                (landlord_uuid, LedgerEntryKind::ListingFee, 50),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_fee_waived_by_trade_pact() {
+        let (mut state, user_uuid, corporation_uuid, business_uuid) = setup_test_state();
+        let landlord_uuid = setup_ward(&mut state, business_uuid);
+        state.add_treaty(Treaty {
+            uuid: Uuid::now_v7(),
+            treaty_type: TreatyType::TradePact,
+            proposer_corporation_uuid: corporation_uuid,
+            partner_corporation_uuid: landlord_uuid,
+            proposed_at_tick: 0,
+            accepted_at_tick: Some(0),
+        });
+
+        list(&mut state, user_uuid, business_uuid, 2500).unwrap();
+
+        let cash_balance = |uuid: &Uuid| state.ref_corporation(uuid).unwrap().cash_balance;
+        assert_eq!(cash_balance(&corporation_uuid), 5000);
+        assert_eq!(cash_balance(&landlord_uuid), 1000);
+        assert!(state.ledger_entries.is_empty());
+    }
+
+    #[test]
+    fn test_list_fail_insufficient_funds_for_fee() {
+        let (mut state, user_uuid, corporation_uuid, business_uuid) = setup_test_state();
+        setup_ward(&mut state, business_uuid);
+
+        let result = list(&mut state, user_uuid, business_uuid, 500_000);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InsufficientFunds {
+                corporation_uuid,
+                required: 10_000,
+                available: 5000,
+            }
+        );
+        assert!(state.business_listings_map.is_empty());
+    }
+
+    #[test]
+    fn test_list_auction_success() {
+        let (mut state, user_uuid, _, business_uuid) = setup_test_state();
+
+        let result = list_with_auction(&mut state, user_uuid, business_uuid, 2500, Some(20));
+
+        let Ok(DomainActionOutcome::BusinessListed {
+            business_listing_uuid,
+            auction_closes_at_tick,
+            ..
+        }) = result
+        else {
+            panic!("Expected BusinessListed outcome, got {result:?}");
+        };
+
+        assert_eq!(auction_closes_at_tick, Some(30));
+        let listing = state.ref_business_listing(&business_listing_uuid).unwrap();
+        assert!(listing.is_auction());
+        assert_eq!(listing.asking_price, 2500);
+    }
+
+    #[test]
+    fn test_list_auction_fail_invalid_duration() {
+        let (mut state, user_uuid, _, business_uuid) = setup_test_state();
+
+        let result = list_with_auction(&mut state, user_uuid, business_uuid, 2500, Some(1));
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InvalidAuctionDuration {
+                auction_duration_ticks: 1,
+                min: 5,
+                max: 100,
+            }
+        );
+        assert!(state.business_listings_map.is_empty());
+    }
+
+    #[test]
+    fn test_list_fail_not_owner() {
+        let (mut state, _, _, business_uuid) = setup_test_state();
+        let other_user_uuid = Uuid::now_v7();
+        let other_corporation_uuid = Uuid::now_v7();
+        state
+            .corporation_uuid_by_user_uuid
+            .insert(other_user_uuid, other_corporation_uuid);
+
+        let result = list(&mut state, other_user_uuid, business_uuid, 2500);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessNotOwnedByCorporation {
+                business_uuid,
+                corporation_uuid: other_corporation_uuid,
+            }
+        );
+        assert!(state.business_listings_map.is_empty());
+    }
+
+    #[test]
+    fn test_list_fail_already_listed() {
+        let (mut state, user_uuid, _, business_uuid) = setup_test_state();
+
+        list(&mut state, user_uuid, business_uuid, 2500).unwrap();
+        let result = list(&mut state, user_uuid, business_uuid, 3000);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessAlreadyListed { business_uuid }
+        );
+        assert_eq!(state.business_listings_map.len(), 1);
+    }
+
+    #[test]
+    fn test_list_fail_invalid_asking_price() {
+        let (mut state, user_uuid, _, business_uuid) = setup_test_state();
+
+        let result = list(&mut state, user_uuid, business_uuid, 0);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InvalidAskingPrice { asking_price: 0 }
+        );
+    }
+
+    #[test]
+    fn test_list_fail_business_not_found() {
+        let (mut state, user_uuid, _, _) = setup_test_state();
+        let non_existent_business_uuid = Uuid::now_v7();
+
+        let result = list(&mut state, user_uuid, non_existent_business_uuid, 2500);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessNotFound {
+                business_uuid: non_existent_business_uuid
+            }
+        );
+    }
+}
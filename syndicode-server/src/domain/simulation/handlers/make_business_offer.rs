@@ -0,0 +1,411 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::{
+            business_offer::model::BusinessOffer, ledger::model::entry_kind::LedgerEntryKind,
+        },
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_make_business_offer(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    business_uuid: Uuid,
+    offer_price: i64,
+    offer_expiry_ticks: i64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    // --- 1. Pre-Saga Checks ---
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if offer_price <= 0 {
+        return Err(ActionError::InvalidOfferPrice { offer_price });
+    }
+
+    let business = state
+        .ref_business(&business_uuid)
+        .ok_or(ActionError::BusinessNotFound { business_uuid })?;
+
+    let target_corporation_uuid = business
+        .owning_corporation_uuid
+        .ok_or(ActionError::BusinessHasNoOwner { business_uuid })?;
+
+    if target_corporation_uuid == req_corporation_uuid {
+        return Err(ActionError::BusinessAlreadyOwnedByCorporation {
+            business_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    let has_open_offer = state
+        .business_offer_uuids_by_corporation_uuid
+        .get(&req_corporation_uuid)
+        .into_iter()
+        .flatten()
+        .filter_map(|offer_uuid| state.ref_business_offer(offer_uuid))
+        .any(|offer| offer.business_uuid == business_uuid);
+
+    if has_open_offer {
+        return Err(ActionError::BusinessOfferAlreadyExists {
+            business_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    let req_corporation = state.ref_corporation(&req_corporation_uuid).ok_or(
+        ActionError::CorporationNotFoundDuringChecks {
+            corporation_uuid: req_corporation_uuid,
+        },
+    )?;
+
+    if req_corporation.cash_balance < offer_price {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid: req_corporation_uuid,
+            required: offer_price,
+            available: req_corporation.cash_balance,
+        });
+    }
+
+    let target_user_uuid = state
+        .ref_corporation(&target_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks {
+            corporation_uuid: target_corporation_uuid,
+        })?
+        .user_uuid;
+
+    let offer = BusinessOffer::builder()
+        .uuid(rng.next_uuid())
+        .business_uuid(business_uuid)
+        .offering_corporation_uuid(req_corporation_uuid)
+        .target_corporation_uuid(target_corporation_uuid)
+        .offer_price(offer_price)
+        .expires_at_tick(next_game_tick + offer_expiry_ticks)
+        .build();
+
+    // --- 2. Define and Execute the Saga ---
+    let mut executor = SagaExecutor::new(state);
+
+    // --- Step 1: Move the offered cash into escrow ---
+    saga_step!(
+        executor,
+        "Escrow Offer Price",
+        |state: &mut GameState| {
+            let corp = state.ref_mut_corporation(&req_corporation_uuid).ok_or(
+                ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: req_corporation_uuid,
+                    step_description: "Escrow Offer Price",
+                },
+            )?;
+            corp.cash_balance -= offer_price;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corp) = state.ref_mut_corporation(&req_corporation_uuid) {
+                corp.cash_balance += offer_price;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Escrow Offer Price) - Failed to find offering corporation {} to release escrow.", req_corporation_uuid);
+            }
+        }
+    );
+
+    // --- Step 2: Register Offer ---
+    saga_step!(
+        executor,
+        "Register Offer",
+        |state: &mut GameState| {
+            state.add_business_offer(offer);
+            Ok(())
+        },
+        |state: &mut GameState| {
+            state.remove_business_offer(&offer.uuid);
+        }
+    );
+
+    executor.execute()?;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(req_corporation_uuid)
+        .kind(LedgerEntryKind::OfferEscrow)
+        .amount(-offer_price)
+        .business_uuid(business_uuid)
+        .call();
+
+    // --- 3. Post-Saga: Notify the owner of the business ---
+    outcomes.push(DomainActionOutcome::BusinessOfferReceived {
+        request_uuid: rng.next_uuid(),
+        tick_effective: next_game_tick,
+        req_user_uuid: target_user_uuid,
+        business_offer_uuid: offer.uuid,
+        business_uuid,
+        offering_corporation_uuid: req_corporation_uuid,
+        target_corporation_uuid,
+        offer_price,
+        expires_at_tick: offer.expires_at_tick,
+    });
+
+    Ok(DomainActionOutcome::BusinessOfferMade {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_offer_uuid: offer.uuid,
+        business_uuid,
+        offering_corporation_uuid: req_corporation_uuid,
+        target_corporation_uuid,
+        offer_price,
+        expires_at_tick: offer.expires_at_tick,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        buyer_user_uuid: Uuid,
+        buyer_corp_uuid: Uuid,
+        seller_user_uuid: Uuid,
+        seller_corp_uuid: Uuid,
+        business_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let buyer_user_uuid = Uuid::now_v7();
+        let buyer_corp_uuid = Uuid::now_v7();
+        let seller_user_uuid = Uuid::now_v7();
+        let seller_corp_uuid = Uuid::now_v7();
+        let business_uuid = Uuid::now_v7();
+
+        let buyer_corp = Corporation {
+            uuid: buyer_corp_uuid,
+            user_uuid: buyer_user_uuid,
+            name: CorporationName::new("Buyer Corp".to_string()).unwrap(),
+            cash_balance: 10000,
+            insolvent_since_tick: None,
+        };
+        let seller_corp = Corporation {
+            uuid: seller_corp_uuid,
+            user_uuid: seller_user_uuid,
+            name: CorporationName::new("Seller Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(seller_corp_uuid),
+            name: "Test Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![buyer_corp, seller_corp])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            buyer_user_uuid,
+            buyer_corp_uuid,
+            seller_user_uuid,
+            seller_corp_uuid,
+            business_uuid,
+        }
+    }
+
+    fn make_offer(
+        state: &mut GameState,
+        outcomes: &mut Vec<DomainActionOutcome>,
+        user_uuid: Uuid,
+        business_uuid: Uuid,
+        offer_price: i64,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::MakeBusinessOffer {
+                business_uuid,
+                offer_price,
+            },
+        };
+
+        handle_make_business_offer()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(0))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .business_uuid(business_uuid)
+            .offer_price(offer_price)
+            .offer_expiry_ticks(5)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_make_offer_success() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = make_offer(
+            &mut setup.state,
+            &mut outcomes,
+            setup.buyer_user_uuid,
+            setup.business_uuid,
+            4000,
+        );
+
+        let Ok(DomainActionOutcome::BusinessOfferMade {
+            business_offer_uuid,
+            target_corporation_uuid,
+            expires_at_tick,
+            ..
+        }) = result
+        else {
+            panic!("Expected BusinessOfferMade outcome, got {result:?}");
+        };
+
+        assert_eq!(target_corporation_uuid, setup.seller_corp_uuid);
+        assert_eq!(expires_at_tick, 15);
+
+        // Offered cash is held in escrow
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.buyer_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            6000
+        );
+
+        let offer = setup
+            .state
+            .ref_business_offer(&business_offer_uuid)
+            .unwrap();
+        assert_eq!(offer.offering_corporation_uuid, setup.buyer_corp_uuid);
+        assert_eq!(
+            setup
+                .state
+                .business_offer_uuids_by_corporation_uuid
+                .get(&setup.buyer_corp_uuid),
+            Some(&vec![business_offer_uuid])
+        );
+
+        // The owner gets notified
+        assert!(matches!(
+            outcomes.as_slice(),
+            [DomainActionOutcome::BusinessOfferReceived { req_user_uuid, business_offer_uuid: notified_offer_uuid, .. }]
+                if *req_user_uuid == setup.seller_user_uuid && *notified_offer_uuid == business_offer_uuid
+        ));
+    }
+
+    #[test]
+    fn test_make_offer_fail_insufficient_funds() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = make_offer(
+            &mut setup.state,
+            &mut outcomes,
+            setup.buyer_user_uuid,
+            setup.business_uuid,
+            20000,
+        );
+
+        assert!(matches!(
+            result,
+            Err(ActionError::InsufficientFunds { corporation_uuid, .. }) if corporation_uuid == setup.buyer_corp_uuid
+        ));
+        assert!(setup.state.business_offers_map.is_empty());
+        assert!(outcomes.is_empty());
+    }
+
+    #[test]
+    fn test_make_offer_fail_own_business() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = make_offer(
+            &mut setup.state,
+            &mut outcomes,
+            setup.seller_user_uuid,
+            setup.business_uuid,
+            1000,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessAlreadyOwnedByCorporation {
+                business_uuid: setup.business_uuid,
+                corporation_uuid: setup.seller_corp_uuid,
+            }
+        );
+    }
+
+    #[test]
+    fn test_make_offer_fail_duplicate() {
+        let mut setup = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        make_offer(
+            &mut setup.state,
+            &mut outcomes,
+            setup.buyer_user_uuid,
+            setup.business_uuid,
+            1000,
+        )
+        .unwrap();
+        let result = make_offer(
+            &mut setup.state,
+            &mut outcomes,
+            setup.buyer_user_uuid,
+            setup.business_uuid,
+            2000,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessOfferAlreadyExists {
+                business_uuid: setup.business_uuid,
+                corporation_uuid: setup.buyer_corp_uuid,
+            }
+        );
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.buyer_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            9000
+        );
+    }
+}
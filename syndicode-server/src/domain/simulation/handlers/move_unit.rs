@@ -0,0 +1,278 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_move_unit(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    unit_uuid: Uuid,
+    destination_building_gml_id: &str,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    // --- 1. Checks and Data Gathering (Immutable) ---
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let unit = *state
+        .ref_unit(&unit_uuid)
+        .ok_or(ActionError::UnitNotFound { unit_uuid })?;
+
+    if unit.corporation_uuid != corporation_uuid {
+        return Err(ActionError::UnitNotOwnedByCorporation {
+            unit_uuid,
+            corporation_uuid,
+        });
+    }
+
+    if unit.is_moving() {
+        return Err(ActionError::UnitAlreadyMoving { unit_uuid });
+    }
+
+    let destination_building_uuid = *state
+        .get_building_uuid_by_gml_id(destination_building_gml_id)
+        .ok_or(ActionError::BuildingNotFound {
+            building_gml_id: destination_building_gml_id.to_string(),
+        })?;
+
+    if destination_building_uuid == unit.building_uuid {
+        return Err(ActionError::UnitAlreadyAtBuilding {
+            unit_uuid,
+            building_gml_id: destination_building_gml_id.to_string(),
+        });
+    }
+
+    let origin_gml_id = state
+        .ref_building_location(&unit.building_uuid)
+        .map(|location| location.gml_id.clone())
+        .unwrap_or_default();
+
+    let travel_ticks = state
+        .get_travel_ticks(
+            unit.unit_type,
+            &unit.building_uuid,
+            &destination_building_uuid,
+        )
+        .ok_or(ActionError::InternalError(format!(
+            "Building '{}' of unit '{}' has no known location",
+            unit.building_uuid, unit_uuid
+        )))?;
+    let arrival_tick = next_game_tick + travel_ticks;
+
+    // --- 2. Send the unit on its way, this calls off any attack it was committed to ---
+    let unit = state
+        .ref_mut_unit(&unit_uuid)
+        .ok_or(ActionError::UnitNotFound { unit_uuid })?;
+    unit.destination_building_uuid = Some(destination_building_uuid);
+    unit.arrival_tick = Some(arrival_tick);
+    unit.attack_target_building_uuid = None;
+
+    Ok(DomainActionOutcome::UnitMoved {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        corporation_uuid,
+        unit_uuid,
+        unit_type: unit.unit_type,
+        health: unit.health,
+        attack: unit.attack,
+        defense: unit.defense,
+        building_gml_id: origin_gml_id,
+        destination_building_gml_id: destination_building_gml_id.to_string(),
+        arrival_tick,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::building::model::BuildingLocation;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+    use crate::domain::unit::model::{unit_type::UnitType, Unit};
+
+    const ORIGIN_GML_ID: &str = "DEBY_ORIGIN";
+    const DESTINATION_GML_ID: &str = "DEBY_DESTINATION";
+
+    struct TestSetup {
+        state: GameState,
+        user_uuid: Uuid,
+        unit_uuid: Uuid,
+        destination_building_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        let origin_building_uuid = Uuid::now_v7();
+        let destination_building_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Unit Corp".to_string()).unwrap(),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        };
+        let unit = Unit::spawn(
+            Uuid::now_v7(),
+            corporation_uuid,
+            UnitType::Enforcer,
+            origin_building_uuid,
+        );
+        // Roughly 1.1km apart (0.01 degrees of latitude)
+        let locations = vec![
+            BuildingLocation {
+                uuid: origin_building_uuid,
+                gml_id: ORIGIN_GML_ID.to_string(),
+                longitude: 11.57,
+                latitude: 48.13,
+                volume: 1000.0,
+                usage_code: None,
+                city_code: None,
+            },
+            BuildingLocation {
+                uuid: destination_building_uuid,
+                gml_id: DESTINATION_GML_ID.to_string(),
+                longitude: 11.57,
+                latitude: 48.14,
+                volume: 1000.0,
+                usage_code: None,
+                city_code: None,
+            },
+        ];
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![unit])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .building_locations_vec(locations)
+            .call();
+
+        TestSetup {
+            state,
+            user_uuid,
+            unit_uuid: unit.uuid,
+            destination_building_uuid,
+        }
+    }
+
+    fn move_unit(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        unit_uuid: Uuid,
+        destination_building_gml_id: &str,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::MoveUnit {
+                unit_uuid,
+                destination_building_gml_id: destination_building_gml_id.to_string(),
+            },
+        };
+
+        handle_move_unit()
+            .state(state)
+            .action_payload(&action)
+            .unit_uuid(unit_uuid)
+            .destination_building_gml_id(destination_building_gml_id)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_move_success() {
+        let mut setup = setup_test_state();
+
+        let result = move_unit(
+            &mut setup.state,
+            setup.user_uuid,
+            setup.unit_uuid,
+            DESTINATION_GML_ID,
+        );
+
+        // 1112m at 250m per tick
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::UnitMoved { arrival_tick: 15, ref building_gml_id, .. }) if building_gml_id == ORIGIN_GML_ID
+        ));
+
+        let unit = setup.state.ref_unit(&setup.unit_uuid).unwrap();
+        assert_eq!(
+            unit.destination_building_uuid,
+            Some(setup.destination_building_uuid)
+        );
+        assert_eq!(unit.arrival_tick, Some(15));
+    }
+
+    #[test]
+    fn test_move_fail_already_moving() {
+        let mut setup = setup_test_state();
+
+        move_unit(
+            &mut setup.state,
+            setup.user_uuid,
+            setup.unit_uuid,
+            DESTINATION_GML_ID,
+        )
+        .unwrap();
+        let result = move_unit(
+            &mut setup.state,
+            setup.user_uuid,
+            setup.unit_uuid,
+            ORIGIN_GML_ID,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::UnitAlreadyMoving {
+                unit_uuid: setup.unit_uuid
+            }
+        );
+    }
+
+    #[test]
+    fn test_move_fail_not_owner() {
+        let mut setup = setup_test_state();
+        let other_user_uuid = Uuid::now_v7();
+        let other_corp_uuid = Uuid::now_v7();
+        setup
+            .state
+            .corporation_uuid_by_user_uuid
+            .insert(other_user_uuid, other_corp_uuid);
+
+        let result = move_unit(
+            &mut setup.state,
+            other_user_uuid,
+            setup.unit_uuid,
+            DESTINATION_GML_ID,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::UnitNotOwnedByCorporation {
+                unit_uuid: setup.unit_uuid,
+                corporation_uuid: other_corp_uuid,
+            }
+        );
+        assert!(!setup.state.ref_unit(&setup.unit_uuid).unwrap().is_moving());
+    }
+}
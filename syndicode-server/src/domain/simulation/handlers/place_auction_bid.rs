@@ -0,0 +1,430 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::{auction_bid::model::AuctionBid, ledger::model::entry_kind::LedgerEntryKind},
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_place_auction_bid(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    action_payload: &QueuedActionPayload,
+    business_listing_uuid: Uuid,
+    amount: i64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    // --- 1. Pre-Saga Checks ---
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let listing = *state.ref_business_listing(&business_listing_uuid).ok_or(
+        ActionError::BusinessListingNotFound {
+            listing_uuid: business_listing_uuid,
+        },
+    )?;
+
+    let closes_at_tick =
+        listing
+            .auction_closes_at_tick
+            .ok_or(ActionError::BusinessListingNotAuction {
+                listing_uuid: business_listing_uuid,
+            })?;
+
+    if closes_at_tick < next_game_tick {
+        return Err(ActionError::AuctionClosed {
+            listing_uuid: business_listing_uuid,
+            closed_at_tick: closes_at_tick,
+        });
+    }
+
+    if listing.seller_corporation_uuid == Some(req_corporation_uuid)
+        || state.is_business_owned_by(&listing.business_uuid, &req_corporation_uuid)
+    {
+        return Err(ActionError::BusinessAlreadyOwnedByCorporation {
+            business_uuid: listing.business_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    if amount < listing.asking_price {
+        return Err(ActionError::AuctionBidTooLow {
+            amount,
+            reserve_price: listing.asking_price,
+        });
+    }
+
+    // Bids are sealed, so each corporation gets a single one per auction
+    let has_placed_bid = state
+        .auction_bid_uuids_by_listing_uuid
+        .get(&business_listing_uuid)
+        .into_iter()
+        .flatten()
+        .filter_map(|bid_uuid| state.ref_auction_bid(bid_uuid))
+        .any(|bid| bid.bidding_corporation_uuid == req_corporation_uuid);
+
+    if has_placed_bid {
+        return Err(ActionError::AuctionBidAlreadyPlaced {
+            listing_uuid: business_listing_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    let req_corporation = state.ref_corporation(&req_corporation_uuid).ok_or(
+        ActionError::CorporationNotFoundDuringChecks {
+            corporation_uuid: req_corporation_uuid,
+        },
+    )?;
+
+    if req_corporation.cash_balance < amount {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid: req_corporation_uuid,
+            required: amount,
+            available: req_corporation.cash_balance,
+        });
+    }
+
+    let bid = AuctionBid::builder()
+        .uuid(rng.next_uuid())
+        .business_listing_uuid(business_listing_uuid)
+        .business_uuid(listing.business_uuid)
+        .bidding_corporation_uuid(req_corporation_uuid)
+        .amount(amount)
+        .placed_at_tick(next_game_tick)
+        .build();
+
+    // --- 2. Define and Execute the Saga ---
+    let mut executor = SagaExecutor::new(state);
+
+    // --- Step 1: Move the bid into escrow ---
+    saga_step!(
+        executor,
+        "Escrow Bid",
+        |state: &mut GameState| {
+            let corp = state.ref_mut_corporation(&req_corporation_uuid).ok_or(
+                ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: req_corporation_uuid,
+                    step_description: "Escrow Bid",
+                },
+            )?;
+            corp.cash_balance -= amount;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corp) = state.ref_mut_corporation(&req_corporation_uuid) {
+                corp.cash_balance += amount;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Escrow Bid) - Failed to find bidding corporation {} to release escrow.", req_corporation_uuid);
+            }
+        }
+    );
+
+    // --- Step 2: Register Bid ---
+    saga_step!(
+        executor,
+        "Register Bid",
+        |state: &mut GameState| {
+            state.add_auction_bid(bid);
+            Ok(())
+        },
+        |state: &mut GameState| {
+            state.remove_auction_bid(&bid.uuid);
+        }
+    );
+
+    executor.execute()?;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(req_corporation_uuid)
+        .kind(LedgerEntryKind::AuctionEscrow)
+        .amount(-amount)
+        .business_uuid(listing.business_uuid)
+        .call();
+
+    Ok(DomainActionOutcome::AuctionBidPlaced {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        auction_bid_uuid: bid.uuid,
+        business_listing_uuid,
+        business_uuid: listing.business_uuid,
+        amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::business_listing::model::BusinessListing;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        bidder_user_uuid: Uuid,
+        bidder_corp_uuid: Uuid,
+        seller_user_uuid: Uuid,
+        seller_corp_uuid: Uuid,
+        business_uuid: Uuid,
+        listing_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let bidder_user_uuid = Uuid::now_v7();
+        let bidder_corp_uuid = Uuid::now_v7();
+        let seller_user_uuid = Uuid::now_v7();
+        let seller_corp_uuid = Uuid::now_v7();
+        let business_uuid = Uuid::now_v7();
+        let listing_uuid = Uuid::now_v7();
+
+        let bidder_corp = Corporation {
+            uuid: bidder_corp_uuid,
+            user_uuid: bidder_user_uuid,
+            name: CorporationName::new("Bidder Corp".to_string()).unwrap(),
+            cash_balance: 10000,
+            insolvent_since_tick: None,
+        };
+        let seller_corp = Corporation {
+            uuid: seller_corp_uuid,
+            user_uuid: seller_user_uuid,
+            name: CorporationName::new("Seller Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(seller_corp_uuid),
+            name: "Test Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let listing = BusinessListing {
+            uuid: listing_uuid,
+            business_uuid,
+            seller_corporation_uuid: Some(seller_corp_uuid),
+            asking_price: 2000,
+            auction_closes_at_tick: Some(20),
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![bidder_corp, seller_corp])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![listing])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            bidder_user_uuid,
+            bidder_corp_uuid,
+            seller_user_uuid,
+            seller_corp_uuid,
+            business_uuid,
+            listing_uuid,
+        }
+    }
+
+    fn place_bid(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        listing_uuid: Uuid,
+        amount: i64,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::PlaceAuctionBid {
+                business_listing_uuid: listing_uuid,
+                amount,
+            },
+        };
+
+        handle_place_auction_bid()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(0))
+            .action_payload(&action)
+            .business_listing_uuid(listing_uuid)
+            .amount(amount)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_place_bid_success() {
+        let mut setup = setup_test_state();
+
+        let result = place_bid(
+            &mut setup.state,
+            setup.bidder_user_uuid,
+            setup.listing_uuid,
+            3000,
+        );
+
+        let Ok(DomainActionOutcome::AuctionBidPlaced {
+            auction_bid_uuid,
+            business_uuid,
+            amount,
+            ..
+        }) = result
+        else {
+            panic!("Expected AuctionBidPlaced outcome, got {result:?}");
+        };
+
+        assert_eq!(business_uuid, setup.business_uuid);
+        assert_eq!(amount, 3000);
+
+        // The bid is held in escrow
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.bidder_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            7000
+        );
+
+        let bid = setup.state.ref_auction_bid(&auction_bid_uuid).unwrap();
+        assert_eq!(bid.bidding_corporation_uuid, setup.bidder_corp_uuid);
+        assert_eq!(bid.placed_at_tick, 10);
+        assert_eq!(
+            setup
+                .state
+                .auction_bid_uuids_by_listing_uuid
+                .get(&setup.listing_uuid),
+            Some(&vec![auction_bid_uuid])
+        );
+    }
+
+    #[test]
+    fn test_place_bid_fail_below_reserve() {
+        let mut setup = setup_test_state();
+
+        let result = place_bid(
+            &mut setup.state,
+            setup.bidder_user_uuid,
+            setup.listing_uuid,
+            1999,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::AuctionBidTooLow {
+                amount: 1999,
+                reserve_price: 2000,
+            }
+        );
+        assert!(setup.state.auction_bids_map.is_empty());
+    }
+
+    #[test]
+    fn test_place_bid_fail_second_bid() {
+        let mut setup = setup_test_state();
+
+        place_bid(
+            &mut setup.state,
+            setup.bidder_user_uuid,
+            setup.listing_uuid,
+            2000,
+        )
+        .unwrap();
+        let result = place_bid(
+            &mut setup.state,
+            setup.bidder_user_uuid,
+            setup.listing_uuid,
+            4000,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::AuctionBidAlreadyPlaced {
+                listing_uuid: setup.listing_uuid,
+                corporation_uuid: setup.bidder_corp_uuid,
+            }
+        );
+        assert_eq!(setup.state.auction_bids_map.len(), 1);
+    }
+
+    #[test]
+    fn test_place_bid_fail_own_listing() {
+        let mut setup = setup_test_state();
+
+        let result = place_bid(
+            &mut setup.state,
+            setup.seller_user_uuid,
+            setup.listing_uuid,
+            3000,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessAlreadyOwnedByCorporation {
+                business_uuid: setup.business_uuid,
+                corporation_uuid: setup.seller_corp_uuid,
+            }
+        );
+    }
+
+    #[test]
+    fn test_place_bid_fail_not_auction() {
+        let mut setup = setup_test_state();
+        setup
+            .state
+            .ref_mut_business_listing(&setup.listing_uuid)
+            .unwrap()
+            .auction_closes_at_tick = None;
+
+        let result = place_bid(
+            &mut setup.state,
+            setup.bidder_user_uuid,
+            setup.listing_uuid,
+            3000,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessListingNotAuction {
+                listing_uuid: setup.listing_uuid,
+            }
+        );
+    }
+
+    #[test]
+    fn test_place_bid_fail_insufficient_funds() {
+        let mut setup = setup_test_state();
+
+        let result = place_bid(
+            &mut setup.state,
+            setup.bidder_user_uuid,
+            setup.listing_uuid,
+            20000,
+        );
+
+        assert!(matches!(
+            result,
+            Err(ActionError::InsufficientFunds { corporation_uuid, .. }) if corporation_uuid == setup.bidder_corp_uuid
+        ));
+        assert!(setup.state.auction_bids_map.is_empty());
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    config::EspionageConfig,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
+        espionage::model::Mole,
+        outcome::{DomainActionOutcome, EspionageOperation},
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Tries to plant a mole in a rival corporation. The cost is due whether the attempt succeeds or
+/// not. A detected attempt always fails.
+#[builder]
+pub fn handle_plant_mole(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    target_corporation_uuid: Uuid,
+    espionage_config: &EspionageConfig,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if target_corporation_uuid == corporation_uuid {
+        return Err(ActionError::CannotSpyOnOwnCorporation { corporation_uuid });
+    }
+
+    let target_user_uuid = state
+        .ref_corporation(&target_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFound {
+            corporation_uuid: target_corporation_uuid,
+        })?
+        .user_uuid;
+
+    if state
+        .get_mole(&corporation_uuid, &target_corporation_uuid)
+        .is_some()
+    {
+        return Err(ActionError::MoleAlreadyPlanted {
+            target_corporation_uuid,
+            corporation_uuid,
+        });
+    }
+
+    let cost = espionage_config.plant_mole_cost;
+    let corporation = state
+        .ref_mut_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < cost {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: cost,
+            available: corporation.cash_balance,
+        });
+    }
+
+    corporation.cash_balance -= cost;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::Espionage)
+        .amount(-cost)
+        .call();
+
+    // Both rolls are always drawn so the outcome of one doesn't shift the other
+    let succeeded = rng.chance_percent(espionage_config.plant_mole_success_percent);
+    let detected = rng.chance_percent(espionage_config.detection_percent);
+
+    let mole_uuid = (succeeded && !detected).then(|| {
+        let mole = Mole::builder()
+            .uuid(rng.next_uuid())
+            .spy_corporation_uuid(corporation_uuid)
+            .target_corporation_uuid(target_corporation_uuid)
+            .planted_at_tick(next_game_tick)
+            .build();
+        state.add_mole(mole);
+
+        mole.uuid
+    });
+
+    if detected {
+        outcomes.push(DomainActionOutcome::EspionageDetected {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: target_user_uuid,
+            spy_corporation_uuid: corporation_uuid,
+            operation: EspionageOperation::PlantMole,
+        });
+    }
+
+    Ok(DomainActionOutcome::MolePlanted {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        target_corporation_uuid,
+        cost,
+        mole_uuid,
+        detected,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        spy_user_uuid: Uuid,
+        spy_corp_uuid: Uuid,
+        target_corp_uuid: Uuid,
+    }
+
+    fn setup_test_state(cash_balance: i64) -> TestSetup {
+        let spy_user_uuid = Uuid::now_v7();
+        let spy_corp_uuid = Uuid::now_v7();
+        let target_corp_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: spy_corp_uuid,
+                user_uuid: spy_user_uuid,
+                name: CorporationName::new("Spy Corp".to_string()).unwrap(),
+                cash_balance,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: target_corp_uuid,
+                user_uuid: Uuid::now_v7(),
+                name: CorporationName::new("Target Corp".to_string()).unwrap(),
+                cash_balance: 50_000,
+                insolvent_since_tick: None,
+            },
+        ];
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            target_corp_uuid,
+        }
+    }
+
+    fn config(detection_percent: i64) -> EspionageConfig {
+        EspionageConfig {
+            plant_mole_cost: 2_000,
+            plant_mole_success_percent: 100,
+            detection_percent,
+            ..EspionageConfig::default()
+        }
+    }
+
+    fn plant(
+        state: &mut GameState,
+        outcomes: &mut Vec<DomainActionOutcome>,
+        user_uuid: Uuid,
+        target_corporation_uuid: Uuid,
+        espionage_config: &EspionageConfig,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::PlantMole {
+                target_corporation_uuid,
+            },
+        };
+
+        handle_plant_mole()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(7))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .target_corporation_uuid(target_corporation_uuid)
+            .espionage_config(espionage_config)
+            .next_game_tick(1)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_plant_mole_success() {
+        let TestSetup {
+            mut state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            target_corp_uuid,
+        } = setup_test_state(10_000);
+        let mut outcomes = Vec::new();
+
+        let result = plant(
+            &mut state,
+            &mut outcomes,
+            spy_user_uuid,
+            target_corp_uuid,
+            &config(0),
+        );
+
+        let Ok(DomainActionOutcome::MolePlanted {
+            mole_uuid: Some(mole_uuid),
+            detected: false,
+            ..
+        }) = result
+        else {
+            panic!("Expected a planted mole, got {result:?}");
+        };
+        assert_eq!(
+            state
+                .get_mole(&spy_corp_uuid, &target_corp_uuid)
+                .map(|mole| mole.uuid),
+            Some(mole_uuid)
+        );
+        assert_eq!(
+            state.ref_corporation(&spy_corp_uuid).unwrap().cash_balance,
+            8_000
+        );
+        assert!(outcomes.is_empty());
+
+        // A second mole in the same corporation is refused
+        let result = plant(
+            &mut state,
+            &mut outcomes,
+            spy_user_uuid,
+            target_corp_uuid,
+            &config(0),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::MoleAlreadyPlanted {
+                target_corporation_uuid: target_corp_uuid,
+                corporation_uuid: spy_corp_uuid,
+            }
+        );
+    }
+
+    #[test]
+    fn test_plant_mole_detected_attempt_fails() {
+        let TestSetup {
+            mut state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            target_corp_uuid,
+        } = setup_test_state(10_000);
+        let mut outcomes = Vec::new();
+
+        let result = plant(
+            &mut state,
+            &mut outcomes,
+            spy_user_uuid,
+            target_corp_uuid,
+            &config(100),
+        );
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::MolePlanted {
+                mole_uuid: None,
+                detected: true,
+                ..
+            })
+        ));
+        assert!(state.moles_map.is_empty());
+        assert_eq!(
+            state.ref_corporation(&spy_corp_uuid).unwrap().cash_balance,
+            8_000
+        );
+        assert!(matches!(
+            outcomes[..],
+            [DomainActionOutcome::EspionageDetected {
+                operation: EspionageOperation::PlantMole,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_plant_mole_fail_insufficient_funds() {
+        let TestSetup {
+            mut state,
+            spy_user_uuid,
+            spy_corp_uuid,
+            target_corp_uuid,
+        } = setup_test_state(1_000);
+
+        let result = plant(
+            &mut state,
+            &mut Vec::new(),
+            spy_user_uuid,
+            target_corp_uuid,
+            &config(0),
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InsufficientFunds {
+                corporation_uuid: spy_corp_uuid,
+                required: 2_000,
+                available: 1_000,
+            }
+        );
+        assert!(state.moles_map.is_empty());
+    }
+}
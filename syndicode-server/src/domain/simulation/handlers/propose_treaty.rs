@@ -0,0 +1,263 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        diplomacy::model::{treaty_type::TreatyType, Treaty},
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Proposes a treaty to another corporation. It takes effect once the partner accepts it.
+#[builder]
+pub fn handle_propose_treaty(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    partner_corporation_uuid: Uuid,
+    treaty_type: TreatyType,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if partner_corporation_uuid == corporation_uuid {
+        return Err(ActionError::CannotTreatWithOwnCorporation { corporation_uuid });
+    }
+
+    let partner_user_uuid = state
+        .ref_corporation(&partner_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFound {
+            corporation_uuid: partner_corporation_uuid,
+        })?
+        .user_uuid;
+
+    // Covers pending proposals in both directions as well as treaties already in effect
+    if let Some(treaty) =
+        state.get_treaty(&corporation_uuid, &partner_corporation_uuid, treaty_type)
+    {
+        return Err(ActionError::TreatyAlreadyExists {
+            treaty_uuid: treaty.uuid,
+            treaty_type,
+            partner_corporation_uuid,
+        });
+    }
+
+    let treaty = Treaty::builder()
+        .uuid(rng.next_uuid())
+        .treaty_type(treaty_type)
+        .proposer_corporation_uuid(corporation_uuid)
+        .partner_corporation_uuid(partner_corporation_uuid)
+        .proposed_at_tick(next_game_tick)
+        .build();
+    state.add_treaty(treaty);
+
+    outcomes.push(DomainActionOutcome::TreatyProposalReceived {
+        request_uuid: rng.next_uuid(),
+        tick_effective: next_game_tick,
+        req_user_uuid: partner_user_uuid,
+        treaty_uuid: treaty.uuid,
+        treaty_type,
+        proposer_corporation_uuid: corporation_uuid,
+    });
+
+    Ok(DomainActionOutcome::TreatyProposed {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        treaty_uuid: treaty.uuid,
+        treaty_type,
+        partner_corporation_uuid,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        proposer_user_uuid: Uuid,
+        proposer_corp_uuid: Uuid,
+        partner_user_uuid: Uuid,
+        partner_corp_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let proposer_user_uuid = Uuid::now_v7();
+        let proposer_corp_uuid = Uuid::now_v7();
+        let partner_user_uuid = Uuid::now_v7();
+        let partner_corp_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: proposer_corp_uuid,
+                user_uuid: proposer_user_uuid,
+                name: CorporationName::new("Proposer Corp".to_string()).unwrap(),
+                cash_balance: 10_000,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: partner_corp_uuid,
+                user_uuid: partner_user_uuid,
+                name: CorporationName::new("Partner Corp".to_string()).unwrap(),
+                cash_balance: 10_000,
+                insolvent_since_tick: None,
+            },
+        ];
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            proposer_user_uuid,
+            proposer_corp_uuid,
+            partner_user_uuid,
+            partner_corp_uuid,
+        }
+    }
+
+    fn propose(
+        state: &mut GameState,
+        outcomes: &mut Vec<DomainActionOutcome>,
+        user_uuid: Uuid,
+        partner_corporation_uuid: Uuid,
+        treaty_type: TreatyType,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::ProposeTreaty {
+                partner_corporation_uuid,
+                treaty_type,
+            },
+        };
+
+        handle_propose_treaty()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(3))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .partner_corporation_uuid(partner_corporation_uuid)
+            .treaty_type(treaty_type)
+            .next_game_tick(1)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_propose_treaty_success() {
+        let TestSetup {
+            mut state,
+            proposer_user_uuid,
+            proposer_corp_uuid,
+            partner_user_uuid,
+            partner_corp_uuid,
+        } = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = propose(
+            &mut state,
+            &mut outcomes,
+            proposer_user_uuid,
+            partner_corp_uuid,
+            TreatyType::Alliance,
+        );
+
+        let Ok(DomainActionOutcome::TreatyProposed { treaty_uuid, .. }) = result else {
+            panic!("Expected TreatyProposed outcome, got {result:?}");
+        };
+        let treaty = state.ref_treaty(&treaty_uuid).unwrap();
+        assert!(!treaty.is_active());
+        assert!(!state.is_attack_forbidden(&proposer_corp_uuid, &partner_corp_uuid));
+        assert!(matches!(
+            outcomes.as_slice(),
+            [DomainActionOutcome::TreatyProposalReceived { req_user_uuid, proposer_corporation_uuid, .. }]
+                if *req_user_uuid == partner_user_uuid && *proposer_corporation_uuid == proposer_corp_uuid
+        ));
+    }
+
+    #[test]
+    fn test_propose_treaty_fail_counter_proposal() {
+        let TestSetup {
+            mut state,
+            proposer_user_uuid,
+            proposer_corp_uuid,
+            partner_user_uuid,
+            partner_corp_uuid,
+        } = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        propose(
+            &mut state,
+            &mut outcomes,
+            proposer_user_uuid,
+            partner_corp_uuid,
+            TreatyType::TradePact,
+        )
+        .unwrap();
+        outcomes.clear();
+
+        let result = propose(
+            &mut state,
+            &mut outcomes,
+            partner_user_uuid,
+            proposer_corp_uuid,
+            TreatyType::TradePact,
+        );
+
+        assert!(matches!(
+            result,
+            Err(ActionError::TreatyAlreadyExists {
+                treaty_type: TreatyType::TradePact,
+                ..
+            })
+        ));
+        assert!(outcomes.is_empty());
+    }
+
+    #[test]
+    fn test_propose_treaty_fail_own_corporation() {
+        let TestSetup {
+            mut state,
+            proposer_user_uuid,
+            proposer_corp_uuid,
+            ..
+        } = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = propose(
+            &mut state,
+            &mut outcomes,
+            proposer_user_uuid,
+            proposer_corp_uuid,
+            TreatyType::NonAggression,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::CannotTreatWithOwnCorporation {
+                corporation_uuid: proposer_corp_uuid
+            }
+        );
+        assert!(state.treaties_map.is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
+        outcome::{BusinessOfferCloseReason, DomainActionOutcome},
+        simulation::{game_state::GameState, rng::SimulationRng, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_reject_business_offer(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    business_offer_uuid: Uuid,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let original_offer = *state.ref_business_offer(&business_offer_uuid).ok_or(
+        ActionError::BusinessOfferNotFound {
+            offer_uuid: business_offer_uuid,
+        },
+    )?;
+
+    if original_offer.target_corporation_uuid != Some(req_corporation_uuid) {
+        return Err(ActionError::BusinessOfferNotTargetedAtCorporation {
+            offer_uuid: business_offer_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    let offering_corporation_uuid = original_offer.offering_corporation_uuid;
+    let offer_price = original_offer.offer_price;
+
+    let offering_user_uuid = state
+        .ref_corporation(&offering_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks {
+            corporation_uuid: offering_corporation_uuid,
+        })?
+        .user_uuid;
+
+    let mut executor = SagaExecutor::new(state);
+
+    // --- Step 1: Refund the escrow to the offering corporation ---
+    saga_step!(
+        executor,
+        "Refund Offerer",
+        |state: &mut GameState| {
+            let corp = state
+                .ref_mut_corporation(&offering_corporation_uuid)
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: offering_corporation_uuid,
+                    step_description: "Refund Offerer",
+                })?;
+            corp.cash_balance += offer_price;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corp) = state.ref_mut_corporation(&offering_corporation_uuid) {
+                corp.cash_balance -= offer_price;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Refund Offerer) - Failed to find offering corporation {} to debit back.", offering_corporation_uuid);
+            }
+        }
+    );
+
+    // --- Step 2: Remove Offer ---
+    saga_step!(
+        executor,
+        "Remove Offer",
+        |state: &mut GameState| {
+            state
+                .remove_business_offer(&business_offer_uuid)
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "BusinessOffer",
+                    entity_id: business_offer_uuid,
+                    step_description: "Remove Offer",
+                })
+                .map(|_| ())
+        },
+        move |state: &mut GameState| {
+            state.add_business_offer(original_offer);
+        }
+    );
+
+    executor.execute()?;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(offering_corporation_uuid)
+        .kind(LedgerEntryKind::OfferRefund)
+        .amount(offer_price)
+        .business_uuid(original_offer.business_uuid)
+        .call();
+
+    // Let the offering corporation know that it got its money back
+    outcomes.push(DomainActionOutcome::BusinessOfferClosed {
+        request_uuid: rng.next_uuid(),
+        tick_effective: next_game_tick,
+        req_user_uuid: offering_user_uuid,
+        business_offer_uuid,
+        business_uuid: original_offer.business_uuid,
+        refunded_amount: offer_price,
+        reason: BusinessOfferCloseReason::Rejected,
+    });
+
+    Ok(DomainActionOutcome::BusinessOfferRejected {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_offer_uuid,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business_offer::model::BusinessOffer;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    fn setup_test_state() -> (GameState, Uuid, Uuid, Uuid, Uuid) {
+        let buyer_user_uuid = Uuid::now_v7();
+        let buyer_corp_uuid = Uuid::now_v7();
+        let seller_user_uuid = Uuid::now_v7();
+        let seller_corp_uuid = Uuid::now_v7();
+        let offer_uuid = Uuid::now_v7();
+
+        let buyer_corp = Corporation {
+            uuid: buyer_corp_uuid,
+            user_uuid: buyer_user_uuid,
+            name: CorporationName::new("Buyer Corp".to_string()).unwrap(),
+            cash_balance: 6000,
+            insolvent_since_tick: None,
+        };
+        let seller_corp = Corporation {
+            uuid: seller_corp_uuid,
+            user_uuid: seller_user_uuid,
+            name: CorporationName::new("Seller Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let offer = BusinessOffer {
+            uuid: offer_uuid,
+            business_uuid: Uuid::now_v7(),
+            offering_corporation_uuid: buyer_corp_uuid,
+            target_corporation_uuid: Some(seller_corp_uuid),
+            offer_price: 4000,
+            expires_at_tick: 20,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![buyer_corp, seller_corp])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![offer])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        (
+            state,
+            buyer_user_uuid,
+            buyer_corp_uuid,
+            seller_user_uuid,
+            offer_uuid,
+        )
+    }
+
+    fn reject(
+        state: &mut GameState,
+        outcomes: &mut Vec<DomainActionOutcome>,
+        user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::RejectBusinessOffer {
+                business_offer_uuid,
+            },
+        };
+
+        handle_reject_business_offer()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(0))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .business_offer_uuid(business_offer_uuid)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_reject_success() {
+        let (mut state, buyer_user_uuid, buyer_corp_uuid, seller_user_uuid, offer_uuid) =
+            setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = reject(&mut state, &mut outcomes, seller_user_uuid, offer_uuid);
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::BusinessOfferRejected { .. })
+        ));
+        assert!(state.ref_business_offer(&offer_uuid).is_none());
+        assert_eq!(
+            state
+                .ref_corporation(&buyer_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            10000
+        );
+        assert!(matches!(
+            outcomes.as_slice(),
+            [DomainActionOutcome::BusinessOfferClosed { req_user_uuid, refunded_amount: 4000, reason: BusinessOfferCloseReason::Rejected, .. }]
+                if *req_user_uuid == buyer_user_uuid
+        ));
+    }
+
+    #[test]
+    fn test_reject_fail_not_target() {
+        let (mut state, buyer_user_uuid, buyer_corp_uuid, _, offer_uuid) = setup_test_state();
+        let mut outcomes = Vec::new();
+
+        let result = reject(&mut state, &mut outcomes, buyer_user_uuid, offer_uuid);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessOfferNotTargetedAtCorporation {
+                offer_uuid,
+                corporation_uuid: buyer_corp_uuid,
+            }
+        );
+        assert!(state.ref_business_offer(&offer_uuid).is_some());
+        assert!(outcomes.is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_repay_loan(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    loan_uuid: Uuid,
+    amount: i64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if amount <= 0 {
+        return Err(ActionError::InvalidLoanAmount { amount });
+    }
+
+    let Some(loan) = state.ref_loan(&loan_uuid) else {
+        return Err(ActionError::LoanNotFound { loan_uuid });
+    };
+
+    if loan.corporation_uuid != corporation_uuid {
+        return Err(ActionError::LoanNotOwedByCorporation {
+            loan_uuid,
+            corporation_uuid,
+        });
+    }
+
+    // Paying more than is owed only settles the loan
+    let amount = amount.min(loan.outstanding_balance);
+
+    let corporation = state
+        .ref_mut_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < amount {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: amount,
+            available: corporation.cash_balance,
+        });
+    }
+
+    corporation.cash_balance -= amount;
+
+    let loan = state
+        .ref_mut_loan(&loan_uuid)
+        .ok_or(ActionError::LoanNotFound { loan_uuid })?;
+    loan.outstanding_balance -= amount;
+    let outstanding_balance = loan.outstanding_balance;
+
+    if outstanding_balance == 0 {
+        state.remove_loan(&loan_uuid);
+    }
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::LoanRepayment)
+        .amount(-amount)
+        .call();
+
+    Ok(DomainActionOutcome::LoanRepaid {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        loan_uuid,
+        amount,
+        outstanding_balance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+    use crate::domain::economy::loan::model::Loan;
+
+    fn setup_test_state(cash_balance: i64) -> (GameState, Uuid, Uuid, Uuid) {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Repaying Corp".to_string()).unwrap(),
+            cash_balance,
+            insolvent_since_tick: None,
+        };
+        let loan = Loan {
+            uuid: Uuid::now_v7(),
+            corporation_uuid,
+            principal: 1000,
+            outstanding_balance: 1200,
+            interest_rate_bps: 10,
+            taken_at_tick: 1,
+            due_at_tick: 100,
+        };
+        let loan_uuid = loan.uuid;
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .loans_vec(vec![loan])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        (state, user_uuid, corporation_uuid, loan_uuid)
+    }
+
+    fn repay(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        loan_uuid: Uuid,
+        amount: i64,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::RepayLoan { loan_uuid, amount },
+        };
+
+        handle_repay_loan()
+            .state(state)
+            .action_payload(&action)
+            .loan_uuid(loan_uuid)
+            .amount(amount)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_repay_partially() {
+        let (mut state, user_uuid, corporation_uuid, loan_uuid) = setup_test_state(5000);
+
+        repay(&mut state, user_uuid, loan_uuid, 200).unwrap();
+
+        assert_eq!(
+            state.ref_loan(&loan_uuid).unwrap().outstanding_balance,
+            1000
+        );
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            4800
+        );
+    }
+
+    #[test]
+    fn test_repay_more_than_owed_settles_loan() {
+        let (mut state, user_uuid, corporation_uuid, loan_uuid) = setup_test_state(5000);
+
+        let result = repay(&mut state, user_uuid, loan_uuid, 3000);
+
+        let Ok(DomainActionOutcome::LoanRepaid {
+            amount,
+            outstanding_balance,
+            ..
+        }) = result
+        else {
+            panic!("Expected LoanRepaid outcome, got {result:?}");
+        };
+        assert_eq!(amount, 1200);
+        assert_eq!(outstanding_balance, 0);
+        assert!(state.ref_loan(&loan_uuid).is_none());
+        assert!(state.loan_uuids_by_corporation_uuid[&corporation_uuid].is_empty());
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            3800
+        );
+    }
+
+    #[test]
+    fn test_repay_fail_insufficient_funds() {
+        let (mut state, user_uuid, corporation_uuid, loan_uuid) = setup_test_state(100);
+
+        let result = repay(&mut state, user_uuid, loan_uuid, 200);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InsufficientFunds {
+                corporation_uuid,
+                required: 200,
+                available: 100,
+            }
+        );
+        assert_eq!(
+            state.ref_loan(&loan_uuid).unwrap().outstanding_balance,
+            1200
+        );
+    }
+}
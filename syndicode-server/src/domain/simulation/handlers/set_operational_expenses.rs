@@ -0,0 +1,307 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::building::model::BuildingBonus,
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_set_operational_expenses(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    business_uuid: Uuid,
+    operational_expenses: i64,
+    min_operational_expenses: i64,
+    max_operational_expenses: i64,
+    capacity_bonus: i64,
+    cooldown_ticks: i64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    // Offices, warehouses and factories let a business put more expenses to use
+    let max_operational_expenses = max_operational_expenses
+        + capacity_bonus * state.count_building_bonuses(&business_uuid, BuildingBonus::Capacity);
+
+    if !(min_operational_expenses..=max_operational_expenses).contains(&operational_expenses) {
+        return Err(ActionError::InvalidOperationalExpenses {
+            operational_expenses,
+            min: min_operational_expenses,
+            max: max_operational_expenses,
+        });
+    }
+
+    let Some(business) = state.ref_business(&business_uuid) else {
+        return Err(ActionError::BusinessNotFound { business_uuid });
+    };
+
+    if business.owning_corporation_uuid != Some(req_corporation_uuid) {
+        return Err(ActionError::BusinessNotOwnedByCorporation {
+            business_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    if let Some(changed_at_tick) = business.operational_expenses_changed_at_tick {
+        let available_at_tick = changed_at_tick + cooldown_ticks;
+        if next_game_tick < available_at_tick {
+            return Err(ActionError::OperationalExpensesOnCooldown {
+                business_uuid,
+                available_at_tick,
+            });
+        }
+    }
+
+    let previous_operational_expenses = state
+        .set_business_operational_expenses(&business_uuid, operational_expenses, next_game_tick)
+        .ok_or(ActionError::BusinessNotFound { business_uuid })?;
+
+    Ok(DomainActionOutcome::OperationalExpensesSet {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_uuid,
+        operational_expenses,
+        previous_operational_expenses,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::building::model::BuildingLocation;
+    use crate::domain::economy::building_ownership::model::BuildingOwnership;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    const MIN: i64 = 0;
+    const MAX: i64 = 10_000;
+    const COOLDOWN: i64 = 5;
+    const CAPACITY_BONUS: i64 = 1000;
+
+    fn setup_test_state() -> (GameState, Uuid, Uuid, Uuid) {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        let market_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Spending Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid,
+            owning_corporation_uuid: Some(corporation_uuid),
+            name: "Test Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let rival_business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid,
+            owning_corporation_uuid: None,
+            name: "Rival Biz".to_string(),
+            operational_expenses: 300,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 2,
+            operational_expenses_changed_at_tick: None,
+        };
+        let business_uuid = business.uuid;
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business, rival_business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        (state, user_uuid, business_uuid, market_uuid)
+    }
+
+    fn set(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        business_uuid: Uuid,
+        operational_expenses: i64,
+        next_game_tick: i64,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::SetOperationalExpenses {
+                business_uuid,
+                operational_expenses,
+            },
+        };
+
+        handle_set_operational_expenses()
+            .state(state)
+            .action_payload(&action)
+            .business_uuid(business_uuid)
+            .operational_expenses(operational_expenses)
+            .min_operational_expenses(MIN)
+            .max_operational_expenses(MAX)
+            .capacity_bonus(CAPACITY_BONUS)
+            .cooldown_ticks(COOLDOWN)
+            .next_game_tick(next_game_tick)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_set_success_updates_market_total() {
+        let (mut state, user_uuid, business_uuid, market_uuid) = setup_test_state();
+
+        let result = set(&mut state, user_uuid, business_uuid, 700, 10);
+
+        let Ok(DomainActionOutcome::OperationalExpensesSet {
+            operational_expenses,
+            previous_operational_expenses,
+            ..
+        }) = result
+        else {
+            panic!("Expected OperationalExpensesSet outcome, got {result:?}");
+        };
+        assert_eq!(operational_expenses, 700);
+        assert_eq!(previous_operational_expenses, 100);
+
+        let business = state.ref_business(&business_uuid).unwrap();
+        assert_eq!(business.operational_expenses, 700);
+        assert_eq!(business.operational_expenses_changed_at_tick, Some(10));
+        assert_eq!(
+            state.total_operation_expenses_by_market_uuid[&market_uuid],
+            1000
+        );
+    }
+
+    #[test]
+    fn test_set_fail_out_of_bounds() {
+        let (mut state, user_uuid, business_uuid, _) = setup_test_state();
+
+        let result = set(&mut state, user_uuid, business_uuid, MAX + 1, 10);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InvalidOperationalExpenses {
+                operational_expenses: MAX + 1,
+                min: MIN,
+                max: MAX,
+            }
+        );
+        assert_eq!(
+            state
+                .ref_business(&business_uuid)
+                .unwrap()
+                .operational_expenses,
+            100
+        );
+    }
+
+    #[test]
+    fn test_set_fail_on_cooldown() {
+        let (mut state, user_uuid, business_uuid, _) = setup_test_state();
+
+        set(&mut state, user_uuid, business_uuid, 700, 10).unwrap();
+        let result = set(&mut state, user_uuid, business_uuid, 900, 14);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::OperationalExpensesOnCooldown {
+                business_uuid,
+                available_at_tick: 15,
+            }
+        );
+
+        set(&mut state, user_uuid, business_uuid, 900, 15).unwrap();
+    }
+
+    #[test]
+    fn test_set_fail_not_owner() {
+        let (mut state, _, business_uuid, _) = setup_test_state();
+        let other_user_uuid = Uuid::now_v7();
+        let other_corporation_uuid = Uuid::now_v7();
+        state
+            .corporation_uuid_by_user_uuid
+            .insert(other_user_uuid, other_corporation_uuid);
+
+        let result = set(&mut state, other_user_uuid, business_uuid, 700, 10);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessNotOwnedByCorporation {
+                business_uuid,
+                corporation_uuid: other_corporation_uuid,
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_above_max_with_capacity_bonus() {
+        let (mut state, user_uuid, business_uuid, _) = setup_test_state();
+        let office = BuildingLocation {
+            uuid: Uuid::now_v7(),
+            gml_id: "BLD_OFFICE".to_string(),
+            longitude: 139.76,
+            latitude: 35.68,
+            volume: 1000.0,
+            usage_code: Some("401".to_string()),
+            city_code: None,
+        };
+        state
+            .building_locations_map
+            .insert(office.uuid, office.clone());
+        state.add_building_ownership(BuildingOwnership {
+            building_uuid: office.uuid,
+            owning_business_uuid: business_uuid,
+        });
+
+        let result = set(
+            &mut state,
+            user_uuid,
+            business_uuid,
+            MAX + CAPACITY_BONUS + 1,
+            1,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InvalidOperationalExpenses {
+                operational_expenses: MAX + CAPACITY_BONUS + 1,
+                min: MIN,
+                max: MAX + CAPACITY_BONUS,
+            }
+        );
+
+        let result = set(
+            &mut state,
+            user_uuid,
+            business_uuid,
+            MAX + CAPACITY_BONUS,
+            1,
+        );
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::OperationalExpensesSet { operational_expenses, .. })
+                if operational_expenses == MAX + CAPACITY_BONUS
+        ));
+    }
+}
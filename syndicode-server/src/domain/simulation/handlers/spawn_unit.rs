@@ -1,9 +1,10 @@
 use crate::{
     application::action::QueuedActionPayload,
     domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
         outcome::DomainActionOutcome,
-        simulation::{game_state::GameState, ActionError},
-        unit::model::Unit,
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+        unit::model::{unit_type::UnitType, Unit},
     },
 };
 use bon::builder;
@@ -12,32 +13,271 @@ use uuid::Uuid;
 #[builder]
 pub fn handle_spawn_unit(
     state: &mut GameState,
+    rng: &mut SimulationRng,
     action_payload: &QueuedActionPayload,
+    unit_type: UnitType,
+    building_gml_id: &str,
+    control_threshold_bps: i64,
+    unit_spawn_discount_percent: i64,
     next_game_tick: i64,
     req_user_uuid: Uuid,
 ) -> Result<DomainActionOutcome, ActionError> {
-    let unit_uuid = Uuid::now_v7();
-
-    let corporation_uuid = *state
-        .corporation_uuid_by_user_uuid
-        .get(&req_user_uuid)
-        .ok_or(ActionError::RequestingCorporationNotFoundByUser {
-            // Use specific error
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
             user_uuid: req_user_uuid,
-        })?;
+        },
+    )?;
 
-    let unit = Unit {
-        uuid: unit_uuid,
-        corporation_uuid,
-    };
+    let building_uuid = *state.get_building_uuid_by_gml_id(building_gml_id).ok_or(
+        ActionError::BuildingNotFound {
+            building_gml_id: building_gml_id.to_string(),
+        },
+    )?;
+
+    // Units can only be deployed from the corporation's own premises
+    if !state.is_building_owned_by(&building_uuid, &corporation_uuid) {
+        return Err(ActionError::BuildingNotOwnedByCorporation {
+            building_gml_id: building_gml_id.to_string(),
+            corporation_uuid,
+        });
+    }
+
+    let mut spawn_cost = unit_type.spawn_cost();
+
+    // Recruiting is cheaper on home turf
+    if state.get_ward_controller(&building_uuid, control_threshold_bps) == Some(corporation_uuid) {
+        spawn_cost -= spawn_cost * unit_spawn_discount_percent / 100;
+    }
+
+    let corporation = state
+        .ref_mut_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < spawn_cost {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: spawn_cost,
+            available: corporation.cash_balance,
+        });
+    }
+
+    corporation.cash_balance -= spawn_cost;
+
+    let unit = Unit::spawn(rng.next_uuid(), corporation_uuid, unit_type, building_uuid);
 
     state.add_unit(unit);
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::UnitSpawn)
+        .amount(-spawn_cost)
+        .call();
 
     Ok(DomainActionOutcome::UnitSpawned {
         req_user_uuid,
         request_uuid: action_payload.request_uuid,
         corporation_uuid,
-        unit_uuid,
+        unit_uuid: unit.uuid,
+        unit_type: unit.unit_type,
+        health: unit.health,
+        attack: unit.attack,
+        defense: unit.defense,
+        building_gml_id: building_gml_id.to_string(),
+        spawn_cost,
         tick_effective: next_game_tick,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::building::model::BuildingLocation;
+    use crate::domain::economy::building_ownership::model::BuildingOwnership;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    const OWNED_GML_ID: &str = "DEBY_OWNED";
+    const FOREIGN_GML_ID: &str = "DEBY_FOREIGN";
+
+    fn setup_test_state(cash_balance: i64) -> (GameState, Uuid, Uuid) {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        let business_uuid = Uuid::now_v7();
+        let owned_building_uuid = Uuid::now_v7();
+        let foreign_building_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Unit Corp".to_string()).unwrap(),
+            cash_balance,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(corporation_uuid),
+            name: "Test Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: owned_building_uuid,
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let ownership = BuildingOwnership {
+            building_uuid: owned_building_uuid,
+            owning_business_uuid: business_uuid,
+        };
+        let locations = vec![
+            BuildingLocation {
+                uuid: owned_building_uuid,
+                gml_id: OWNED_GML_ID.to_string(),
+                longitude: 11.57,
+                latitude: 48.13,
+                volume: 1000.0,
+                usage_code: None,
+                city_code: None,
+            },
+            BuildingLocation {
+                uuid: foreign_building_uuid,
+                gml_id: FOREIGN_GML_ID.to_string(),
+                longitude: 11.58,
+                latitude: 48.14,
+                volume: 1000.0,
+                usage_code: None,
+                city_code: None,
+            },
+        ];
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![ownership])
+            .building_locations_vec(locations)
+            .call();
+
+        (state, user_uuid, corporation_uuid)
+    }
+
+    fn spawn(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        unit_type: UnitType,
+        building_gml_id: &str,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::SpawnUnit {
+                unit_type,
+                building_gml_id: building_gml_id.to_string(),
+            },
+        };
+
+        handle_spawn_unit()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(0))
+            .action_payload(&action)
+            .unit_type(unit_type)
+            .building_gml_id(building_gml_id)
+            .control_threshold_bps(5000)
+            .unit_spawn_discount_percent(25)
+            .next_game_tick(1)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_spawn_success() {
+        let (mut state, user_uuid, corporation_uuid) = setup_test_state(100_000);
+
+        let result = spawn(&mut state, user_uuid, UnitType::Enforcer, OWNED_GML_ID);
+
+        let Ok(DomainActionOutcome::UnitSpawned {
+            unit_uuid,
+            spawn_cost,
+            ..
+        }) = result
+        else {
+            panic!("Expected UnitSpawned outcome, got {result:?}");
+        };
+
+        assert_eq!(spawn_cost, UnitType::Enforcer.spawn_cost());
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            100_000 - spawn_cost
+        );
+
+        let unit = state.ref_unit(&unit_uuid).unwrap();
+        assert_eq!(unit.health, UnitType::Enforcer.base_health());
+        assert!(!unit.is_moving());
+        assert_eq!(
+            state.unit_uuids_by_corporation_uuid.get(&corporation_uuid),
+            Some(&vec![unit_uuid])
+        );
+    }
+
+    #[test]
+    fn test_spawn_discount_in_controlled_ward() {
+        let (mut state, user_uuid, _) = setup_test_state(100_000);
+        let owned_building_uuid = *state.get_building_uuid_by_gml_id(OWNED_GML_ID).unwrap();
+        state
+            .building_locations_map
+            .get_mut(&owned_building_uuid)
+            .unwrap()
+            .city_code = Some("13101".to_string());
+
+        let result = spawn(&mut state, user_uuid, UnitType::Enforcer, OWNED_GML_ID);
+
+        let Ok(DomainActionOutcome::UnitSpawned { spawn_cost, .. }) = result else {
+            panic!("Expected UnitSpawned outcome, got {result:?}");
+        };
+        assert_eq!(spawn_cost, UnitType::Enforcer.spawn_cost() * 3 / 4);
+    }
+
+    #[test]
+    fn test_spawn_fail_building_not_owned() {
+        let (mut state, user_uuid, corporation_uuid) = setup_test_state(100_000);
+
+        let result = spawn(&mut state, user_uuid, UnitType::Enforcer, FOREIGN_GML_ID);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BuildingNotOwnedByCorporation {
+                building_gml_id: FOREIGN_GML_ID.to_string(),
+                corporation_uuid,
+            }
+        );
+        assert!(state.units_map.is_empty());
+    }
+
+    #[test]
+    fn test_spawn_fail_insufficient_funds() {
+        let (mut state, user_uuid, corporation_uuid) = setup_test_state(1_000);
+
+        let result = spawn(&mut state, user_uuid, UnitType::Mech, OWNED_GML_ID);
+
+        assert!(matches!(
+            result,
+            Err(ActionError::InsufficientFunds { corporation_uuid: c, .. }) if c == corporation_uuid
+        ));
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            1_000
+        );
+        assert!(state.units_map.is_empty());
+    }
+}
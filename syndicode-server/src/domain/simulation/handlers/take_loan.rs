@@ -0,0 +1,248 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    config::BankConfig,
+    domain::{
+        economy::{ledger::model::entry_kind::LedgerEntryKind, loan::model::Loan},
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, ActionError},
+    },
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_take_loan(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    action_payload: &QueuedActionPayload,
+    amount: i64,
+    bank_config: &BankConfig,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if amount <= 0 {
+        return Err(ActionError::InvalidLoanAmount { amount });
+    }
+
+    let available = available_credit(state, &corporation_uuid, bank_config);
+    if amount > available {
+        return Err(ActionError::CreditLimitExceeded {
+            corporation_uuid,
+            requested: amount,
+            available,
+        });
+    }
+
+    let corporation = state
+        .ref_mut_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    corporation.cash_balance += amount;
+
+    let loan = Loan::builder()
+        .uuid(rng.next_uuid())
+        .corporation_uuid(corporation_uuid)
+        .principal(amount)
+        .outstanding_balance(amount)
+        .interest_rate_bps(bank_config.interest_rate_bps)
+        .taken_at_tick(next_game_tick)
+        .due_at_tick(next_game_tick + bank_config.loan_term_ticks)
+        .build();
+
+    state.add_loan(loan);
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::LoanDisbursement)
+        .amount(amount)
+        .call();
+
+    Ok(DomainActionOutcome::LoanTaken {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        loan_uuid: loan.uuid,
+        principal: loan.principal,
+        interest_rate_bps: loan.interest_rate_bps,
+        due_at_tick: loan.due_at_tick,
+    })
+}
+
+/// The bank lends up to a share of the value of the businesses a corporation owns,
+/// where a business is worth its expected income over a number of ticks.
+fn available_credit(state: &GameState, corporation_uuid: &Uuid, bank_config: &BankConfig) -> i64 {
+    let business_value: i64 = state
+        .business_uuids_by_corporation_uuid
+        .get(corporation_uuid)
+        .into_iter()
+        .flatten()
+        .map(|business_uuid| {
+            state.expected_business_income(business_uuid) * bank_config.valuation_ticks
+        })
+        .sum();
+
+    let credit_limit = (business_value as f64 * bank_config.loan_to_value.clamp(0., 1.)) as i64;
+
+    (credit_limit - state.outstanding_debt(corporation_uuid)).max(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+    use crate::domain::economy::market::model::{name::MarketName, Market};
+
+    fn setup_test_state() -> (GameState, Uuid, Uuid) {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Borrowing Corp".to_string()).unwrap(),
+            cash_balance: 5000,
+            insolvent_since_tick: None,
+        };
+        let market = Market {
+            uuid: Uuid::now_v7(),
+            name: MarketName::CyberbrainInterfaces,
+            volume: 1000,
+        };
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: market.uuid,
+            owning_corporation_uuid: Some(corporation_uuid),
+            name: "Collateral Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let rival_business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: market.uuid,
+            owning_corporation_uuid: None,
+            name: "Rival Biz".to_string(),
+            operational_expenses: 300,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 2,
+            operational_expenses_changed_at_tick: None,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![market])
+            .businesses_vec(vec![business, rival_business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        (state, user_uuid, corporation_uuid)
+    }
+
+    fn take(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        amount: i64,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::TakeLoan { amount },
+        };
+        let bank_config = BankConfig {
+            interest_rate_bps: 10,
+            loan_term_ticks: 100,
+            valuation_ticks: 100,
+            loan_to_value: 0.5,
+        };
+
+        handle_take_loan()
+            .state(state)
+            .rng(&mut SimulationRng::from_seed(1))
+            .action_payload(&action)
+            .amount(amount)
+            .bank_config(&bank_config)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_take_loan_success() {
+        let (mut state, user_uuid, corporation_uuid) = setup_test_state();
+
+        let result = take(&mut state, user_uuid, 10_000);
+
+        let Ok(DomainActionOutcome::LoanTaken {
+            loan_uuid,
+            principal,
+            due_at_tick,
+            ..
+        }) = result
+        else {
+            panic!("Expected LoanTaken outcome, got {result:?}");
+        };
+        assert_eq!(principal, 10_000);
+        assert_eq!(due_at_tick, 110);
+
+        let loan = state.ref_loan(&loan_uuid).unwrap();
+        assert_eq!(loan.outstanding_balance, 10_000);
+        assert_eq!(loan.corporation_uuid, corporation_uuid);
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            15_000
+        );
+        assert_eq!(state.ledger_entries.len(), 1);
+        assert_eq!(
+            state.ledger_entries[0].kind,
+            LedgerEntryKind::LoanDisbursement
+        );
+    }
+
+    #[test]
+    fn test_take_loan_fail_credit_limit_includes_outstanding_debt() {
+        let (mut state, user_uuid, corporation_uuid) = setup_test_state();
+
+        // Expected income 1000 * 100 / 400 = 250, valued at 25_000, half of it can be borrowed
+        take(&mut state, user_uuid, 10_000).unwrap();
+        let result = take(&mut state, user_uuid, 2_501);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::CreditLimitExceeded {
+                corporation_uuid,
+                requested: 2_501,
+                available: 2_500,
+            }
+        );
+    }
+
+    #[test]
+    fn test_take_loan_fail_invalid_amount() {
+        let (mut state, user_uuid, _) = setup_test_state();
+
+        let result = take(&mut state, user_uuid, 0);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InvalidLoanAmount { amount: 0 }
+        );
+    }
+}
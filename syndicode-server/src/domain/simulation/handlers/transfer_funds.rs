@@ -0,0 +1,327 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+use bon::builder;
+use uuid::Uuid;
+
+/// Sends cash to another corporation, e.g. to pay tribute to an ally.
+#[builder]
+pub fn handle_transfer_funds(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    outcomes: &mut Vec<DomainActionOutcome>,
+    action_payload: &QueuedActionPayload,
+    recipient_corporation_uuid: Uuid,
+    amount: i64,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    if amount <= 0 {
+        return Err(ActionError::InvalidTransferAmount { amount });
+    }
+
+    if recipient_corporation_uuid == corporation_uuid {
+        return Err(ActionError::CannotPayOwnCorporation { corporation_uuid });
+    }
+
+    let recipient_user_uuid = state
+        .ref_corporation(&recipient_corporation_uuid)
+        .ok_or(ActionError::CorporationNotFound {
+            corporation_uuid: recipient_corporation_uuid,
+        })?
+        .user_uuid;
+
+    let corporation = state
+        .ref_corporation(&corporation_uuid)
+        .ok_or(ActionError::CorporationNotFoundDuringChecks { corporation_uuid })?;
+
+    if corporation.cash_balance < amount {
+        return Err(ActionError::InsufficientFunds {
+            corporation_uuid,
+            required: amount,
+            available: corporation.cash_balance,
+        });
+    }
+
+    let mut executor = SagaExecutor::new(state);
+
+    // Step1: Debit sender
+    saga_step!(
+        executor,
+        "Debit Sender",
+        |state: &mut GameState| {
+            let corporation = state.ref_mut_corporation(&corporation_uuid).ok_or(
+                ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: corporation_uuid,
+                    step_description: "Debit Sender",
+                },
+            )?;
+            corporation.cash_balance -= amount;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corporation) = state.ref_mut_corporation(&corporation_uuid) {
+                corporation.cash_balance += amount;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Debit Sender) - Failed to find sending corporation {} to credit back.", corporation_uuid);
+            }
+        }
+    );
+
+    // Step2: Credit recipient
+    saga_step!(
+        executor,
+        "Credit Recipient",
+        |state: &mut GameState| {
+            let corporation = state
+                .ref_mut_corporation(&recipient_corporation_uuid)
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: recipient_corporation_uuid,
+                    step_description: "Credit Recipient",
+                })?;
+            corporation.cash_balance += amount;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corporation) = state.ref_mut_corporation(&recipient_corporation_uuid) {
+                corporation.cash_balance -= amount;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Credit Recipient) - Failed to find receiving corporation {} to debit back.", recipient_corporation_uuid);
+            }
+        }
+    );
+
+    executor.execute()?;
+
+    // The saga has been committed, so the cash movements are final
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(corporation_uuid)
+        .kind(LedgerEntryKind::Transfer)
+        .amount(-amount)
+        .call();
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(recipient_corporation_uuid)
+        .kind(LedgerEntryKind::Transfer)
+        .amount(amount)
+        .call();
+
+    outcomes.push(DomainActionOutcome::FundsReceived {
+        request_uuid: rng.next_uuid(),
+        tick_effective: next_game_tick,
+        req_user_uuid: recipient_user_uuid,
+        sender_corporation_uuid: corporation_uuid,
+        amount,
+    });
+
+    Ok(DomainActionOutcome::FundsTransferred {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        recipient_corporation_uuid,
+        amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    struct TestSetup {
+        state: GameState,
+        sender_user_uuid: Uuid,
+        sender_corp_uuid: Uuid,
+        recipient_user_uuid: Uuid,
+        recipient_corp_uuid: Uuid,
+    }
+
+    fn setup_test_state() -> TestSetup {
+        let sender_user_uuid = Uuid::now_v7();
+        let sender_corp_uuid = Uuid::now_v7();
+        let recipient_user_uuid = Uuid::now_v7();
+        let recipient_corp_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: sender_corp_uuid,
+                user_uuid: sender_user_uuid,
+                name: CorporationName::new("Sender Corp".to_string()).unwrap(),
+                cash_balance: 1000,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: recipient_corp_uuid,
+                user_uuid: recipient_user_uuid,
+                name: CorporationName::new("Recipient Corp".to_string()).unwrap(),
+                cash_balance: 500,
+                insolvent_since_tick: None,
+            },
+        ];
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            sender_user_uuid,
+            sender_corp_uuid,
+            recipient_user_uuid,
+            recipient_corp_uuid,
+        }
+    }
+
+    fn transfer(
+        setup: &mut TestSetup,
+        recipient_corporation_uuid: Uuid,
+        amount: i64,
+        outcomes: &mut Vec<DomainActionOutcome>,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: setup.sender_user_uuid,
+            details: ActionDetails::TransferFunds {
+                recipient_corporation_uuid,
+                amount,
+            },
+        };
+
+        handle_transfer_funds()
+            .state(&mut setup.state)
+            .rng(&mut SimulationRng::from_seed(0))
+            .outcomes(outcomes)
+            .action_payload(&action)
+            .recipient_corporation_uuid(recipient_corporation_uuid)
+            .amount(amount)
+            .next_game_tick(1)
+            .req_user_uuid(setup.sender_user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_transfer_success() {
+        let mut setup = setup_test_state();
+        let recipient_corp_uuid = setup.recipient_corp_uuid;
+        let mut outcomes = Vec::new();
+
+        let result = transfer(&mut setup, recipient_corp_uuid, 300, &mut outcomes);
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::FundsTransferred { amount: 300, recipient_corporation_uuid, .. })
+                if recipient_corporation_uuid == recipient_corp_uuid
+        ));
+        assert!(matches!(
+            outcomes.as_slice(),
+            [DomainActionOutcome::FundsReceived { req_user_uuid, sender_corporation_uuid, amount: 300, .. }]
+                if *req_user_uuid == setup.recipient_user_uuid && *sender_corporation_uuid == setup.sender_corp_uuid
+        ));
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&setup.sender_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            700
+        );
+        assert_eq!(
+            setup
+                .state
+                .ref_corporation(&recipient_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            800
+        );
+
+        let ledger_amounts: Vec<(Uuid, LedgerEntryKind, i64)> = setup
+            .state
+            .ledger_entries
+            .iter()
+            .map(|entry| (entry.corporation_uuid, entry.kind, entry.amount))
+            .collect();
+        assert_eq!(
+            ledger_amounts,
+            vec![
+                (setup.sender_corp_uuid, LedgerEntryKind::Transfer, -300),
+                (recipient_corp_uuid, LedgerEntryKind::Transfer, 300),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transfer_fail_insufficient_funds() {
+        let mut setup = setup_test_state();
+        let recipient_corp_uuid = setup.recipient_corp_uuid;
+        let mut outcomes = Vec::new();
+
+        let result = transfer(&mut setup, recipient_corp_uuid, 1001, &mut outcomes);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InsufficientFunds {
+                corporation_uuid: setup.sender_corp_uuid,
+                required: 1001,
+                available: 1000,
+            }
+        );
+        assert!(outcomes.is_empty());
+        assert!(setup.state.ledger_entries.is_empty());
+    }
+
+    #[test]
+    fn test_transfer_fail_own_corporation() {
+        let mut setup = setup_test_state();
+        let sender_corp_uuid = setup.sender_corp_uuid;
+        let mut outcomes = Vec::new();
+
+        let result = transfer(&mut setup, sender_corp_uuid, 100, &mut outcomes);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::CannotPayOwnCorporation {
+                corporation_uuid: sender_corp_uuid
+            }
+        );
+    }
+
+    #[test]
+    fn test_transfer_fail_invalid_amount() {
+        let mut setup = setup_test_state();
+        let recipient_corp_uuid = setup.recipient_corp_uuid;
+        let mut outcomes = Vec::new();
+
+        let result = transfer(&mut setup, recipient_corp_uuid, 0, &mut outcomes);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::InvalidTransferAmount { amount: 0 }
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    application::action::QueuedActionPayload,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind,
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+use bon::builder;
+use uuid::Uuid;
+
+#[builder]
+pub fn handle_withdraw_business_offer(
+    state: &mut GameState,
+    action_payload: &QueuedActionPayload,
+    business_offer_uuid: Uuid,
+    next_game_tick: i64,
+    req_user_uuid: Uuid,
+) -> Result<DomainActionOutcome, ActionError> {
+    let req_corporation_uuid = *state.get_corporation_uuid_by_user(&req_user_uuid).ok_or(
+        ActionError::RequestingCorporationNotFoundByUser {
+            user_uuid: req_user_uuid,
+        },
+    )?;
+
+    let original_offer = *state.ref_business_offer(&business_offer_uuid).ok_or(
+        ActionError::BusinessOfferNotFound {
+            offer_uuid: business_offer_uuid,
+        },
+    )?;
+
+    if original_offer.offering_corporation_uuid != req_corporation_uuid {
+        return Err(ActionError::BusinessOfferNotOwnedByCorporation {
+            offer_uuid: business_offer_uuid,
+            corporation_uuid: req_corporation_uuid,
+        });
+    }
+
+    let offer_price = original_offer.offer_price;
+
+    let mut executor = SagaExecutor::new(state);
+
+    // --- Step 1: Refund the escrow ---
+    saga_step!(
+        executor,
+        "Refund Offerer",
+        |state: &mut GameState| {
+            let corp = state.ref_mut_corporation(&req_corporation_uuid).ok_or(
+                ActionError::SagaEntityMissing {
+                    entity_type: "Corporation",
+                    entity_id: req_corporation_uuid,
+                    step_description: "Refund Offerer",
+                },
+            )?;
+            corp.cash_balance += offer_price;
+            Ok(())
+        },
+        |state: &mut GameState| {
+            if let Some(corp) = state.ref_mut_corporation(&req_corporation_uuid) {
+                corp.cash_balance -= offer_price;
+            } else {
+                tracing::error!("CRITICAL: Saga Rollback (Refund Offerer) - Failed to find offering corporation {} to debit back.", req_corporation_uuid);
+            }
+        }
+    );
+
+    // --- Step 2: Remove Offer ---
+    saga_step!(
+        executor,
+        "Remove Offer",
+        |state: &mut GameState| {
+            state
+                .remove_business_offer(&business_offer_uuid)
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "BusinessOffer",
+                    entity_id: business_offer_uuid,
+                    step_description: "Remove Offer",
+                })
+                .map(|_| ())
+        },
+        move |state: &mut GameState| {
+            state.add_business_offer(original_offer);
+        }
+    );
+
+    executor.execute()?;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(req_corporation_uuid)
+        .kind(LedgerEntryKind::OfferRefund)
+        .amount(offer_price)
+        .business_uuid(original_offer.business_uuid)
+        .call();
+
+    Ok(DomainActionOutcome::BusinessOfferWithdrawn {
+        request_uuid: action_payload.request_uuid,
+        tick_effective: next_game_tick,
+        req_user_uuid,
+        business_offer_uuid,
+        refunded_amount: offer_price,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::action::ActionDetails;
+    use crate::domain::economy::business_offer::model::BusinessOffer;
+    use crate::domain::economy::corporation::model::name::CorporationName;
+    use crate::domain::economy::corporation::model::Corporation;
+
+    fn setup_test_state() -> (GameState, Uuid, Uuid, Uuid) {
+        let buyer_user_uuid = Uuid::now_v7();
+        let buyer_corp_uuid = Uuid::now_v7();
+        let offer_uuid = Uuid::now_v7();
+
+        let buyer_corp = Corporation {
+            uuid: buyer_corp_uuid,
+            user_uuid: buyer_user_uuid,
+            name: CorporationName::new("Buyer Corp".to_string()).unwrap(),
+            cash_balance: 6000,
+            insolvent_since_tick: None,
+        };
+        let offer = BusinessOffer {
+            uuid: offer_uuid,
+            business_uuid: Uuid::now_v7(),
+            offering_corporation_uuid: buyer_corp_uuid,
+            target_corporation_uuid: Some(Uuid::now_v7()),
+            offer_price: 4000,
+            expires_at_tick: 20,
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![buyer_corp])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![offer])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        (state, buyer_user_uuid, buyer_corp_uuid, offer_uuid)
+    }
+
+    fn withdraw(
+        state: &mut GameState,
+        user_uuid: Uuid,
+        business_offer_uuid: Uuid,
+    ) -> Result<DomainActionOutcome, ActionError> {
+        let action = QueuedActionPayload {
+            request_uuid: Uuid::now_v7(),
+            req_user_uuid: user_uuid,
+            details: ActionDetails::WithdrawBusinessOffer {
+                business_offer_uuid,
+            },
+        };
+
+        handle_withdraw_business_offer()
+            .state(state)
+            .action_payload(&action)
+            .business_offer_uuid(business_offer_uuid)
+            .next_game_tick(10)
+            .req_user_uuid(user_uuid)
+            .call()
+    }
+
+    #[test]
+    fn test_withdraw_success() {
+        let (mut state, buyer_user_uuid, buyer_corp_uuid, offer_uuid) = setup_test_state();
+
+        let result = withdraw(&mut state, buyer_user_uuid, offer_uuid);
+
+        assert!(matches!(
+            result,
+            Ok(DomainActionOutcome::BusinessOfferWithdrawn {
+                refunded_amount: 4000,
+                ..
+            })
+        ));
+        assert!(state.ref_business_offer(&offer_uuid).is_none());
+        assert_eq!(
+            state
+                .business_offer_uuids_by_corporation_uuid
+                .get(&buyer_corp_uuid),
+            Some(&vec![])
+        );
+        assert_eq!(
+            state
+                .ref_corporation(&buyer_corp_uuid)
+                .unwrap()
+                .cash_balance,
+            10000
+        );
+    }
+
+    #[test]
+    fn test_withdraw_fail_not_offerer() {
+        let (mut state, _, _, offer_uuid) = setup_test_state();
+        let other_user_uuid = Uuid::now_v7();
+        let other_corp_uuid = Uuid::now_v7();
+        state
+            .corporation_uuid_by_user_uuid
+            .insert(other_user_uuid, other_corp_uuid);
+
+        let result = withdraw(&mut state, other_user_uuid, offer_uuid);
+
+        assert_eq!(
+            result.unwrap_err(),
+            ActionError::BusinessOfferNotOwnedByCorporation {
+                offer_uuid,
+                corporation_uuid: other_corp_uuid,
+            }
+        );
+        assert!(state.ref_business_offer(&offer_uuid).is_some());
+    }
+}
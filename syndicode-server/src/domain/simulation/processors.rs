@@ -1 +1,13 @@
+pub(super) mod building_upkeep;
 pub(super) mod business_income;
+pub(super) mod business_offer_expiry;
+pub(super) mod close_auctions;
+pub(super) mod combat;
+pub(super) mod contract_settlement;
+pub(super) mod insolvency;
+pub(super) mod ledger_summary;
+pub(super) mod loans;
+pub(super) mod market_dynamics;
+pub(super) mod market_maker;
+pub(super) mod territory_tax;
+pub(super) mod unit_movement;
@@ -0,0 +1,152 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    economy::ledger::model::entry_kind::LedgerEntryKind, simulation::game_state::GameState,
+};
+
+/// Charges the owners of businesses for the upkeep of the buildings acquired besides the
+/// headquarter. Unlike operational expenses, upkeep is due in full and can drive a corporation
+/// into insolvency.
+pub fn charge_building_upkeep(state: &mut GameState, next_game_tick: i64, upkeep_per_volume: f64) {
+    let mut business_uuids: Vec<Uuid> = state
+        .building_uuids_by_business_uuid
+        .keys()
+        .copied()
+        .collect();
+    business_uuids.sort();
+
+    for business_uuid in business_uuids {
+        let Some(owning_corporation_uuid) = state
+            .ref_business(&business_uuid)
+            .and_then(|business| business.owning_corporation_uuid)
+        else {
+            continue;
+        };
+
+        let upkeep = state.building_upkeep(&business_uuid, upkeep_per_volume);
+        if upkeep <= 0 {
+            continue;
+        }
+
+        let Some(corporation) = state.ref_mut_corporation(&owning_corporation_uuid) else {
+            tracing::error!(
+                "Failed to retrieve owning corporation with UUID '{}' for mutation",
+                owning_corporation_uuid
+            );
+            continue;
+        };
+
+        corporation.cash_balance -= upkeep;
+
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(owning_corporation_uuid)
+            .kind(LedgerEntryKind::BuildingUpkeep)
+            .amount(-upkeep)
+            .business_uuid(business_uuid)
+            .call();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::{
+        building::model::BuildingLocation,
+        building_ownership::model::BuildingOwnership,
+        business::model::Business,
+        corporation::model::{name::CorporationName, Corporation},
+    };
+
+    fn location(gml_id: &str) -> BuildingLocation {
+        BuildingLocation {
+            uuid: Uuid::now_v7(),
+            gml_id: gml_id.to_string(),
+            longitude: 139.76,
+            latitude: 35.68,
+            volume: 10_000.0,
+            usage_code: None,
+            city_code: None,
+        }
+    }
+
+    #[test]
+    fn should_charge_upkeep_for_all_buildings_but_the_headquarter() {
+        let corporation = Corporation {
+            uuid: Uuid::now_v7(),
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::unchecked("Landlord Corp".to_string()),
+            cash_balance: 100,
+            insolvent_since_tick: None,
+        };
+        let headquarter = location("BLD_HQ");
+        let warehouse = location("BLD_WAREHOUSE");
+        let orphaned_headquarter = location("BLD_ORPHANED_HQ");
+        let orphaned_warehouse = location("BLD_ORPHANED_WAREHOUSE");
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(corporation.uuid),
+            name: "Owned Biz".to_string(),
+            operational_expenses: 0,
+            headquarter_building_uuid: headquarter.uuid,
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        // Businesses without an owner don't pay anything
+        let orphaned_business = Business {
+            uuid: Uuid::now_v7(),
+            owning_corporation_uuid: None,
+            name: "Orphaned Biz".to_string(),
+            headquarter_building_uuid: orphaned_headquarter.uuid,
+            ..business.clone()
+        };
+        let ownerships = vec![
+            (headquarter.uuid, business.uuid),
+            (warehouse.uuid, business.uuid),
+            (orphaned_headquarter.uuid, orphaned_business.uuid),
+            (orphaned_warehouse.uuid, orphaned_business.uuid),
+        ]
+        .into_iter()
+        .map(|(building_uuid, owning_business_uuid)| BuildingOwnership {
+            building_uuid,
+            owning_business_uuid,
+        })
+        .collect();
+
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation.clone()])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business.clone(), orphaned_business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(ownerships)
+            .building_locations_vec(vec![
+                headquarter,
+                warehouse,
+                orphaned_headquarter,
+                orphaned_warehouse,
+            ])
+            .call();
+
+        charge_building_upkeep(&mut state, 1, 0.0002);
+
+        assert_eq!(
+            state
+                .ref_corporation(&corporation.uuid)
+                .unwrap()
+                .cash_balance,
+            98
+        );
+        assert_eq!(state.ledger_entries.len(), 1);
+        assert_eq!(
+            state.ledger_entries[0].kind,
+            LedgerEntryKind::BuildingUpkeep
+        );
+        assert_eq!(state.ledger_entries[0].amount, -2);
+        assert_eq!(state.ledger_entries[0].business_uuid, Some(business.uuid));
+    }
+}
@@ -1,20 +1,53 @@
-use uuid::Uuid;
+use std::collections::BTreeMap;
 
-use crate::domain::simulation::game_state::GameState;
+use uuid::Uuid;
 
-pub fn calculate_business_income(state: &mut GameState) {
-    let market_uuids: Vec<Uuid> = state
+use crate::domain::{
+    economy::{building::model::BuildingBonus, ledger::model::entry_kind::LedgerEntryKind},
+    simulation::game_state::GameState,
+};
+
+/// Pays every owned business its share of the market volume and charges its operational expenses.
+///
+/// The share is weighted by the operational expenses. Every commercial building of a business
+/// adds `market_share_bonus_percent` of its expenses to its weight.
+/// The income is recorded per business, so the territory tax can find the ward it was earned
+/// in, while the operational expenses are recorded as a single entry per corporation.
+pub fn calculate_business_income(
+    state: &mut GameState,
+    next_game_tick: i64,
+    market_share_bonus_percent: i64,
+) {
+    let mut market_uuids: Vec<Uuid> = state
         .markets_map
         .values()
         .map(|market| market.uuid)
         .collect();
 
+    // Expenses are capped by the remaining cash balance, so the order matters for determinism
+    market_uuids.sort();
+
+    // Operational expenses per corporation, ordered for a deterministic ledger
+    let mut op_exp_by_corporation_uuid: BTreeMap<Uuid, i64> = BTreeMap::new();
+
     for market_uuid in market_uuids {
-        let business_uuids: Vec<Uuid> = state
+        let mut business_uuids: Vec<Uuid> = state
             .business_uuids_by_market_uuid
             .get(&market_uuid)
             .cloned()
             .unwrap_or_default();
+        business_uuids.sort();
+
+        // The bonuses are added to the total as well, so the shares still add up to the volume
+        let total_bonus: i64 = business_uuids
+            .iter()
+            .filter_map(|business_uuid| state.businesses_map.get(business_uuid))
+            .map(|business| {
+                market_share_bonus(state, &business.uuid, market_share_bonus_percent)
+                    * business.operational_expenses
+                    / 100
+            })
+            .sum();
 
         'for_business: for business_uuid in business_uuids {
             let Some(business) = state.businesses_map.get(&business_uuid) else {
@@ -40,6 +73,10 @@ pub fn calculate_business_income(state: &mut GameState) {
             };
 
             let real_op_exp = business.operational_expenses.min(corporation_cash_balance);
+            let weighted_op_exp = real_op_exp
+                + market_share_bonus(state, &business_uuid, market_share_bonus_percent)
+                    * real_op_exp
+                    / 100;
 
             let Some(total_expenses) = state
                 .total_operation_expenses_by_market_uuid
@@ -51,10 +88,11 @@ pub fn calculate_business_income(state: &mut GameState) {
                 );
                 continue 'for_business;
             };
+            let total_expenses = total_expenses + total_bonus;
 
-            let market_share: f64 = if *total_expenses > 0 {
+            let market_share: f64 = if total_expenses > 0 {
                 // The common, safe case
-                real_op_exp as f64 / *total_expenses as f64
+                weighted_op_exp as f64 / total_expenses as f64
             } else {
                 // The total_expenses is 0
                 if real_op_exp == 0 {
@@ -101,8 +139,42 @@ pub fn calculate_business_income(state: &mut GameState) {
 
             // Deduct the operating expense
             corporation.cash_balance -= real_op_exp;
+
+            *op_exp_by_corporation_uuid
+                .entry(owning_corporation_uuid)
+                .or_default() += real_op_exp;
+
+            // Record the income, so the corporation can see why its balance changed
+            state
+                .record_ledger_entry()
+                .game_tick(next_game_tick)
+                .corporation_uuid(owning_corporation_uuid)
+                .kind(LedgerEntryKind::BusinessIncome)
+                .amount(business_income)
+                .business_uuid(business_uuid)
+                .call();
         }
     }
+
+    for (corporation_uuid, op_exp) in op_exp_by_corporation_uuid {
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(corporation_uuid)
+            .kind(LedgerEntryKind::OperationalExpenses)
+            .amount(-op_exp)
+            .call();
+    }
+}
+
+/// Weight a business gains from its commercial buildings, in percent of its operational expenses.
+fn market_share_bonus(
+    state: &GameState,
+    business_uuid: &Uuid,
+    market_share_bonus_percent: i64,
+) -> i64 {
+    market_share_bonus_percent
+        * state.count_building_bonuses(business_uuid, BuildingBonus::MarketShare)
 }
 
 #[cfg(test)]
@@ -113,8 +185,11 @@ mod tests {
 
     use crate::domain::{
         economy::{
+            building::model::BuildingLocation,
+            building_ownership::model::BuildingOwnership,
             business::model::Business,
             corporation::model::{name::CorporationName, Corporation},
+            ledger::model::entry_kind::LedgerEntryKind,
             market::model::{name::MarketName, Market},
         },
         simulation::game_state::GameState,
@@ -132,6 +207,7 @@ mod tests {
             user_uuid: Uuid::now_v7(),
             name: corporation_name,
             cash_balance: 100,
+            insolvent_since_tick: None,
         };
         let mut corporations_map = HashMap::new();
         corporations_map.insert(corporation_uuid, corporation);
@@ -156,6 +232,7 @@ mod tests {
             operational_expenses: business_one_op_exp,
             headquarter_building_uuid: Uuid::now_v7(),
             image_number: 1,
+            operational_expenses_changed_at_tick: None,
         };
 
         let business_two_uuid = Uuid::now_v7();
@@ -168,6 +245,7 @@ mod tests {
             operational_expenses: business_two_op_exp,
             headquarter_building_uuid: Uuid::now_v7(),
             image_number: 2,
+            operational_expenses_changed_at_tick: None,
         };
         let mut businesses_map = HashMap::new();
         businesses_map.insert(business_one.uuid, business_one);
@@ -190,17 +268,34 @@ mod tests {
             building_ownerships_map: HashMap::new(),
             business_listings_map: HashMap::new(),
             business_offers_map: HashMap::new(),
+            loans_map: HashMap::new(),
+            auction_bids_map: HashMap::new(),
+            moles_map: HashMap::new(),
+            treaties_map: HashMap::new(),
+            contracts_map: HashMap::new(),
+            building_locations_map: HashMap::new(),
             total_operation_expenses_by_market_uuid,
+            building_volume_by_business_uuid: HashMap::new(),
             business_uuids_by_market_uuid,
             corporation_uuid_by_user_uuid: HashMap::new(),
             corporation_names: HashSet::new(),
             business_uuids_by_corporation_uuid: HashMap::new(),
             business_listing_uuids_by_corporation_uuid: HashMap::new(),
             business_offer_uuids_by_corporation_uuid: HashMap::new(),
+            loan_uuids_by_corporation_uuid: HashMap::new(),
+            auction_bid_uuids_by_listing_uuid: HashMap::new(),
+            mole_uuids_by_target_corporation_uuid: HashMap::new(),
+            treaty_uuids_by_corporation_uuid: HashMap::new(),
             unit_uuids_by_corporation_uuid: HashMap::new(),
+            building_uuid_by_gml_id: HashMap::new(),
+            building_uuids_by_business_uuid: HashMap::new(),
+            persisted_fingerprints: Default::default(),
+            persisted_owners: Default::default(),
+            ledger_entries: Vec::new(),
+            market_snapshots: Vec::new(),
         };
 
-        calculate_business_income(&mut state);
+        calculate_business_income(&mut state, 1, 10);
 
         let updated_corporation = state
             .ref_corporation(&corporation_uuid)
@@ -209,5 +304,149 @@ mod tests {
         let expected_cash_balance = 140_i64;
 
         assert_eq!(expected_cash_balance, updated_corporation.cash_balance);
+
+        let ledger: Vec<(LedgerEntryKind, i64, Option<Uuid>)> = state
+            .ledger_entries
+            .iter()
+            .map(|entry| (entry.kind, entry.amount, entry.business_uuid))
+            .collect();
+        assert_eq!(
+            ledger,
+            vec![
+                (LedgerEntryKind::BusinessIncome, 50, Some(business_one_uuid)),
+                (LedgerEntryKind::OperationalExpenses, -10, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_weight_market_share_with_commercial_buildings() {
+        let market = Market {
+            uuid: Uuid::now_v7(),
+            name: MarketName::NeurochemicalAdjustments,
+            volume: 1000,
+        };
+        let corporations: Vec<Corporation> = (0..2)
+            .map(|index| Corporation {
+                uuid: Uuid::now_v7(),
+                user_uuid: Uuid::now_v7(),
+                name: CorporationName::unchecked(format!("corporation-{index}")),
+                cash_balance: 1000,
+                insolvent_since_tick: None,
+            })
+            .collect();
+        let shop = BuildingLocation {
+            uuid: Uuid::now_v7(),
+            gml_id: "BLD_SHOP".to_string(),
+            longitude: 139.76,
+            latitude: 35.68,
+            volume: 1000.0,
+            usage_code: Some("402".to_string()),
+            city_code: None,
+        };
+        let businesses: Vec<Business> = corporations
+            .iter()
+            .enumerate()
+            .map(|(index, corporation)| Business {
+                uuid: Uuid::now_v7(),
+                market_uuid: market.uuid,
+                owning_corporation_uuid: Some(corporation.uuid),
+                name: format!("business-{index}"),
+                operational_expenses: 100,
+                headquarter_building_uuid: Uuid::now_v7(),
+                image_number: 1,
+                operational_expenses_changed_at_tick: None,
+            })
+            .collect();
+        let ownership = BuildingOwnership {
+            building_uuid: shop.uuid,
+            owning_business_uuid: businesses[0].uuid,
+        };
+
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(corporations.clone())
+            .markets_vec(vec![market])
+            .businesses_vec(businesses.clone())
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![ownership])
+            .building_locations_vec(vec![shop])
+            .call();
+
+        calculate_business_income(&mut state, 1, 10);
+
+        let incomes: HashMap<Uuid, i64> = state
+            .ledger_entries
+            .iter()
+            .filter(|entry| entry.kind == LedgerEntryKind::BusinessIncome)
+            .filter_map(|entry| Some((entry.business_uuid?, entry.amount)))
+            .collect();
+        assert_eq!(incomes[&businesses[0].uuid], 524);
+        assert_eq!(incomes[&businesses[1].uuid], 476);
+    }
+
+    #[test]
+    fn should_record_one_expenses_entry_per_corporation() {
+        let market = Market {
+            uuid: Uuid::now_v7(),
+            name: MarketName::NeurochemicalAdjustments,
+            volume: 1000,
+        };
+        let corporation = Corporation {
+            uuid: Uuid::now_v7(),
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::unchecked("corporation".to_string()),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        };
+        let businesses: Vec<Business> = (0..2)
+            .map(|index| Business {
+                uuid: Uuid::now_v7(),
+                market_uuid: market.uuid,
+                owning_corporation_uuid: Some(corporation.uuid),
+                name: format!("business-{index}"),
+                operational_expenses: 100,
+                headquarter_building_uuid: Uuid::now_v7(),
+                image_number: 1,
+                operational_expenses_changed_at_tick: None,
+            })
+            .collect();
+
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![market])
+            .businesses_vec(businesses.clone())
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        calculate_business_income(&mut state, 1, 10);
+
+        let ledger: Vec<(LedgerEntryKind, i64, Option<Uuid>)> = state
+            .ledger_entries
+            .iter()
+            .map(|entry| (entry.kind, entry.amount, entry.business_uuid))
+            .collect();
+        assert_eq!(
+            ledger,
+            vec![
+                (
+                    LedgerEntryKind::BusinessIncome,
+                    500,
+                    Some(businesses[0].uuid)
+                ),
+                (
+                    LedgerEntryKind::BusinessIncome,
+                    500,
+                    Some(businesses[1].uuid)
+                ),
+                (LedgerEntryKind::OperationalExpenses, -200, None),
+            ]
+        );
     }
 }
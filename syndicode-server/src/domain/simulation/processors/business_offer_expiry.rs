@@ -0,0 +1,135 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    economy::ledger::model::entry_kind::LedgerEntryKind,
+    outcome::{BusinessOfferCloseReason, DomainActionOutcome},
+    simulation::{game_state::GameState, rng::SimulationRng},
+};
+
+/// Removes all offers whose expiry tick has been reached and refunds the escrowed cash
+/// to the offering corporations.
+pub fn expire_business_offers(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    let mut expired_offer_uuids: Vec<Uuid> = state
+        .business_offers_map
+        .values()
+        .filter(|offer| offer.expires_at_tick <= next_game_tick)
+        .map(|offer| offer.uuid)
+        .collect();
+
+    // Process in a stable order to keep the simulation deterministic
+    expired_offer_uuids.sort();
+
+    let mut notifications = Vec::with_capacity(expired_offer_uuids.len());
+
+    for offer_uuid in expired_offer_uuids {
+        let Some(offer) = state.remove_business_offer(&offer_uuid) else {
+            continue;
+        };
+
+        // The offering corporation might have been deleted in the meantime
+        let Some(corporation) = state.ref_mut_corporation(&offer.offering_corporation_uuid) else {
+            tracing::warn!(
+                "Offering corporation '{}' of expired business offer '{}' not found",
+                offer.offering_corporation_uuid,
+                offer.uuid
+            );
+            continue;
+        };
+
+        corporation.cash_balance += offer.offer_price;
+        let user_uuid = corporation.user_uuid;
+
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(offer.offering_corporation_uuid)
+            .kind(LedgerEntryKind::OfferRefund)
+            .amount(offer.offer_price)
+            .business_uuid(offer.business_uuid)
+            .call();
+
+        notifications.push(DomainActionOutcome::BusinessOfferClosed {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: user_uuid,
+            business_offer_uuid: offer.uuid,
+            business_uuid: offer.business_uuid,
+            refunded_amount: offer.offer_price,
+            reason: BusinessOfferCloseReason::Expired,
+        });
+    }
+
+    notifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::{
+        business_offer::model::BusinessOffer,
+        corporation::model::{name::CorporationName, Corporation},
+    };
+
+    #[test]
+    fn should_refund_and_remove_expired_offers_only() {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        let expired_offer_uuid = Uuid::now_v7();
+        let open_offer_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Buyer Corp".to_string()).unwrap(),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        };
+        let expired_offer = BusinessOffer {
+            uuid: expired_offer_uuid,
+            business_uuid: Uuid::now_v7(),
+            offering_corporation_uuid: corporation_uuid,
+            target_corporation_uuid: Some(Uuid::now_v7()),
+            offer_price: 500,
+            expires_at_tick: 10,
+        };
+        let open_offer = BusinessOffer {
+            uuid: open_offer_uuid,
+            expires_at_tick: 11,
+            ..expired_offer
+        };
+
+        let mut state = GameState::build()
+            .last_processed_tick(9)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![expired_offer, open_offer])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        let notifications =
+            expire_business_offers(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert_eq!(notifications.len(), 1);
+        assert!(matches!(
+            notifications[0],
+            DomainActionOutcome::BusinessOfferClosed { req_user_uuid, business_offer_uuid, refunded_amount: 500, reason: BusinessOfferCloseReason::Expired, .. }
+                if req_user_uuid == user_uuid && business_offer_uuid == expired_offer_uuid
+        ));
+        assert!(state.ref_business_offer(&expired_offer_uuid).is_none());
+        assert!(state.ref_business_offer(&open_offer_uuid).is_some());
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            1500
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    economy::{auction_bid::model::AuctionBid, ledger::model::entry_kind::LedgerEntryKind},
+    outcome::{AuctionCloseReason, DomainActionOutcome},
+    simulation::{game_state::GameState, rng::SimulationRng},
+};
+
+/// Awards every auction whose bidding window has ended to the highest bid and refunds the
+/// escrowed bids of the losers. Bids on listings that have been removed before their auction
+/// closed, e.g. cancelled or sold through an offer, are refunded as well.
+pub fn close_auctions(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    let mut notifications = Vec::new();
+
+    // --- Refund bids of listings that are gone ---
+    let mut orphaned_listing_uuids: Vec<Uuid> = state
+        .auction_bids_map
+        .values()
+        .filter(|bid| {
+            !state
+                .ref_business_listing(&bid.business_listing_uuid)
+                .is_some_and(|listing| listing.is_auction())
+        })
+        .map(|bid| bid.business_listing_uuid)
+        .collect();
+
+    // Process in a stable order to keep the simulation deterministic
+    orphaned_listing_uuids.sort();
+    orphaned_listing_uuids.dedup();
+
+    for listing_uuid in orphaned_listing_uuids {
+        for bid in take_bids(state, &listing_uuid) {
+            let Some(user_uuid) = refund_bid(state, &bid, next_game_tick) else {
+                continue;
+            };
+
+            notifications.push(DomainActionOutcome::AuctionClosed {
+                request_uuid: rng.next_uuid(),
+                tick_effective: next_game_tick,
+                req_user_uuid: user_uuid,
+                business_listing_uuid: listing_uuid,
+                business_uuid: bid.business_uuid,
+                winning_corporation_uuid: None,
+                winning_bid: None,
+                refunded_amount: bid.amount,
+                reason: AuctionCloseReason::Cancelled,
+            });
+        }
+    }
+
+    // --- Close auctions whose bidding window has ended ---
+    let mut closing_listing_uuids: Vec<Uuid> = state
+        .business_listings_map
+        .values()
+        .filter(|listing| {
+            listing
+                .auction_closes_at_tick
+                .is_some_and(|closes_at_tick| closes_at_tick <= next_game_tick)
+        })
+        .map(|listing| listing.uuid)
+        .collect();
+
+    closing_listing_uuids.sort();
+
+    for listing_uuid in closing_listing_uuids {
+        let Some(listing) = state.remove_business_listing(&listing_uuid) else {
+            continue;
+        };
+        let bids = take_bids(state, &listing_uuid);
+
+        // Bids of corporations that no longer exist can neither win nor be refunded
+        let winning_bid = bids
+            .iter()
+            .find(|bid| {
+                state
+                    .ref_corporation(&bid.bidding_corporation_uuid)
+                    .is_some()
+            })
+            .copied();
+
+        if let Some(winning_bid) = winning_bid {
+            award_business(
+                state,
+                &winning_bid,
+                listing.seller_corporation_uuid,
+                next_game_tick,
+            );
+        }
+
+        let reason = if winning_bid.is_some() {
+            AuctionCloseReason::Sold
+        } else {
+            AuctionCloseReason::Unsold
+        };
+
+        let mut closed_notification =
+            |req_user_uuid: Uuid, refunded_amount: i64| DomainActionOutcome::AuctionClosed {
+                request_uuid: rng.next_uuid(),
+                tick_effective: next_game_tick,
+                req_user_uuid,
+                business_listing_uuid: listing_uuid,
+                business_uuid: listing.business_uuid,
+                winning_corporation_uuid: winning_bid.map(|bid| bid.bidding_corporation_uuid),
+                winning_bid: winning_bid.map(|bid| bid.amount),
+                refunded_amount,
+                reason,
+            };
+
+        for bid in bids {
+            if Some(bid.uuid) == winning_bid.map(|bid| bid.uuid) {
+                if let Some(corporation) = state.ref_corporation(&bid.bidding_corporation_uuid) {
+                    notifications.push(closed_notification(corporation.user_uuid, 0));
+                }
+                continue;
+            }
+
+            if let Some(user_uuid) = refund_bid(state, &bid, next_game_tick) {
+                notifications.push(closed_notification(user_uuid, bid.amount));
+            }
+        }
+
+        if let Some(seller) = listing
+            .seller_corporation_uuid
+            .and_then(|seller_uuid| state.ref_corporation(&seller_uuid))
+        {
+            notifications.push(closed_notification(seller.user_uuid, 0));
+        }
+    }
+
+    notifications
+}
+
+/// Removes all bids of a listing, ordered from the best to the worst one.
+fn take_bids(state: &mut GameState, listing_uuid: &Uuid) -> Vec<AuctionBid> {
+    let bid_uuids = state
+        .auction_bid_uuids_by_listing_uuid
+        .remove(listing_uuid)
+        .unwrap_or_default();
+
+    let mut bids: Vec<AuctionBid> = bid_uuids
+        .iter()
+        .filter_map(|bid_uuid| state.remove_auction_bid(bid_uuid))
+        .collect();
+
+    bids.sort_by(|a, b| {
+        b.amount
+            .cmp(&a.amount)
+            .then(a.placed_at_tick.cmp(&b.placed_at_tick))
+            .then(a.uuid.cmp(&b.uuid))
+    });
+
+    bids
+}
+
+/// Returns the escrowed amount to the bidder and the user to notify, if the bidder still exists.
+fn refund_bid(state: &mut GameState, bid: &AuctionBid, next_game_tick: i64) -> Option<Uuid> {
+    let Some(corporation) = state.ref_mut_corporation(&bid.bidding_corporation_uuid) else {
+        tracing::warn!(
+            "Bidding corporation '{}' of auction bid '{}' not found",
+            bid.bidding_corporation_uuid,
+            bid.uuid
+        );
+        return None;
+    };
+
+    corporation.cash_balance += bid.amount;
+    let user_uuid = corporation.user_uuid;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(bid.bidding_corporation_uuid)
+        .kind(LedgerEntryKind::AuctionRefund)
+        .amount(bid.amount)
+        .business_uuid(bid.business_uuid)
+        .call();
+
+    Some(user_uuid)
+}
+
+/// Hands the business over to the winner and pays the escrowed bid out to the seller.
+fn award_business(
+    state: &mut GameState,
+    bid: &AuctionBid,
+    seller_corporation_uuid: Option<Uuid>,
+    next_game_tick: i64,
+) {
+    if state
+        .set_business_owner(&bid.business_uuid, Some(bid.bidding_corporation_uuid))
+        .is_none()
+    {
+        tracing::error!(
+            "Auctioned business '{}' not found, the winning bid '{}' is lost",
+            bid.business_uuid,
+            bid.uuid
+        );
+        return;
+    }
+
+    // The bid leaves escrow and pays for the acquisition
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(bid.bidding_corporation_uuid)
+        .kind(LedgerEntryKind::AuctionRefund)
+        .amount(bid.amount)
+        .business_uuid(bid.business_uuid)
+        .call();
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(bid.bidding_corporation_uuid)
+        .kind(LedgerEntryKind::BusinessAcquisition)
+        .amount(-bid.amount)
+        .business_uuid(bid.business_uuid)
+        .call();
+
+    let Some(seller_corporation_uuid) = seller_corporation_uuid else {
+        return;
+    };
+    let Some(seller) = state.ref_mut_corporation(&seller_corporation_uuid) else {
+        return;
+    };
+    seller.cash_balance += bid.amount;
+
+    state
+        .record_ledger_entry()
+        .game_tick(next_game_tick)
+        .corporation_uuid(seller_corporation_uuid)
+        .kind(LedgerEntryKind::BusinessSale)
+        .amount(bid.amount)
+        .business_uuid(bid.business_uuid)
+        .call();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::{
+        business::model::Business,
+        business_listing::model::BusinessListing,
+        corporation::model::{name::CorporationName, Corporation},
+    };
+
+    struct Setup {
+        state: GameState,
+        seller_corp_uuid: Uuid,
+        bidder_corp_uuids: [Uuid; 2],
+        business_uuid: Uuid,
+        listing_uuid: Uuid,
+    }
+
+    fn corporation(name: &str) -> Corporation {
+        Corporation {
+            uuid: Uuid::now_v7(),
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::new(name.to_string()).unwrap(),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        }
+    }
+
+    fn setup(bids: &[(usize, i64, i64)]) -> Setup {
+        let seller = corporation("Seller Corp");
+        let bidders = [corporation("First Bidder"), corporation("Second Bidder")];
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(seller.uuid),
+            name: "Auctioned Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: business.uuid,
+            seller_corporation_uuid: Some(seller.uuid),
+            asking_price: 100,
+            auction_closes_at_tick: Some(10),
+        };
+        // Escrowed amounts have already been taken from the bidders
+        let auction_bids: Vec<AuctionBid> = bids
+            .iter()
+            .map(|(bidder, amount, placed_at_tick)| AuctionBid {
+                uuid: Uuid::now_v7(),
+                business_listing_uuid: listing.uuid,
+                business_uuid: business.uuid,
+                bidding_corporation_uuid: bidders[*bidder].uuid,
+                amount: *amount,
+                placed_at_tick: *placed_at_tick,
+            })
+            .collect();
+
+        Setup {
+            seller_corp_uuid: seller.uuid,
+            bidder_corp_uuids: [bidders[0].uuid, bidders[1].uuid],
+            business_uuid: business.uuid,
+            listing_uuid: listing.uuid,
+            state: GameState::build()
+                .last_processed_tick(9)
+                .units_vec(vec![])
+                .corporations_vec(vec![seller, bidders[0].clone(), bidders[1].clone()])
+                .markets_vec(vec![])
+                .businesses_vec(vec![business])
+                .business_listings_vec(vec![listing])
+                .business_offers_vec(vec![])
+                .auction_bids_vec(auction_bids)
+                .building_ownerships_vec(vec![])
+                .call(),
+        }
+    }
+
+    fn cash(state: &GameState, corporation_uuid: &Uuid) -> i64 {
+        state
+            .ref_corporation(corporation_uuid)
+            .unwrap()
+            .cash_balance
+    }
+
+    #[test]
+    fn should_award_the_highest_bid_and_refund_the_others() {
+        let Setup {
+            mut state,
+            seller_corp_uuid,
+            bidder_corp_uuids,
+            business_uuid,
+            listing_uuid,
+        } = setup(&[(0, 300, 5), (1, 500, 8)]);
+
+        let notifications = close_auctions(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert_eq!(
+            state
+                .ref_business(&business_uuid)
+                .unwrap()
+                .owning_corporation_uuid,
+            Some(bidder_corp_uuids[1])
+        );
+        assert!(state.ref_business_listing(&listing_uuid).is_none());
+        assert!(state.auction_bids_map.is_empty());
+        assert_eq!(cash(&state, &seller_corp_uuid), 1500);
+        assert_eq!(cash(&state, &bidder_corp_uuids[0]), 1300);
+        assert_eq!(cash(&state, &bidder_corp_uuids[1]), 1000);
+
+        // Both bidders and the seller are notified
+        assert_eq!(notifications.len(), 3);
+        assert!(notifications.iter().all(|notification| matches!(
+            notification,
+            DomainActionOutcome::AuctionClosed { winning_corporation_uuid, winning_bid: Some(500), reason: AuctionCloseReason::Sold, .. }
+                if *winning_corporation_uuid == Some(bidder_corp_uuids[1])
+        )));
+    }
+
+    #[test]
+    fn should_award_equal_bids_to_the_earliest_one() {
+        let Setup {
+            mut state,
+            bidder_corp_uuids,
+            business_uuid,
+            ..
+        } = setup(&[(1, 400, 7), (0, 400, 3)]);
+
+        close_auctions(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert_eq!(
+            state
+                .ref_business(&business_uuid)
+                .unwrap()
+                .owning_corporation_uuid,
+            Some(bidder_corp_uuids[0])
+        );
+        assert_eq!(cash(&state, &bidder_corp_uuids[1]), 1400);
+    }
+
+    #[test]
+    fn should_keep_auctions_open_until_their_closing_tick() {
+        let Setup {
+            mut state,
+            listing_uuid,
+            ..
+        } = setup(&[(0, 300, 5)]);
+
+        let notifications = close_auctions(&mut state, &mut SimulationRng::from_seed(0), 9);
+
+        assert!(notifications.is_empty());
+        assert!(state.ref_business_listing(&listing_uuid).is_some());
+        assert_eq!(state.auction_bids_map.len(), 1);
+    }
+
+    #[test]
+    fn should_return_unsold_businesses_to_the_seller() {
+        let Setup {
+            mut state,
+            seller_corp_uuid,
+            business_uuid,
+            listing_uuid,
+            ..
+        } = setup(&[]);
+
+        let notifications = close_auctions(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert_eq!(
+            state
+                .ref_business(&business_uuid)
+                .unwrap()
+                .owning_corporation_uuid,
+            Some(seller_corp_uuid)
+        );
+        assert!(state.ref_business_listing(&listing_uuid).is_none());
+        assert!(matches!(
+            notifications.as_slice(),
+            [DomainActionOutcome::AuctionClosed {
+                winning_corporation_uuid: None,
+                reason: AuctionCloseReason::Unsold,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn should_refund_bids_of_cancelled_auctions() {
+        let Setup {
+            mut state,
+            bidder_corp_uuids,
+            listing_uuid,
+            ..
+        } = setup(&[(0, 300, 5)]);
+        state.remove_business_listing(&listing_uuid);
+
+        let notifications = close_auctions(&mut state, &mut SimulationRng::from_seed(0), 6);
+
+        assert!(state.auction_bids_map.is_empty());
+        assert_eq!(cash(&state, &bidder_corp_uuids[0]), 1300);
+        assert!(matches!(
+            notifications.as_slice(),
+            [DomainActionOutcome::AuctionClosed {
+                refunded_amount: 300,
+                reason: AuctionCloseReason::Cancelled,
+                ..
+            }]
+        ));
+    }
+}
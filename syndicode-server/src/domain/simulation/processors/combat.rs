@@ -0,0 +1,539 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        economy::business_listing::model::BusinessListing,
+        outcome::{BattleResult, DestroyedUnit, DomainActionOutcome},
+        simulation::{game_state::GameState, rng::SimulationRng, saga::SagaExecutor, ActionError},
+        unit::model::Unit,
+    },
+    saga_step,
+};
+
+/// Resolves one round of combat at every building that is under attack.
+///
+/// Both sides deal damage simultaneously. The damage a side receives is the
+/// opposing side's total attack, reduced by its own total defense. Damage is
+/// spread over the weakest units first, so casualties are deterministic.
+/// Once all defenders have fallen, a siege of a headquarter building hands
+/// the business over to the strongest surviving attacker.
+pub fn resolve_combat(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    let contested_building_uuids: BTreeSet<Uuid> = state
+        .units_map
+        .values()
+        .filter_map(|unit| unit.attack_target_building_uuid)
+        .collect();
+
+    let mut notifications = Vec::new();
+
+    for building_uuid in contested_building_uuids {
+        notifications.extend(resolve_battle(state, rng, building_uuid, next_game_tick));
+    }
+
+    notifications
+}
+
+fn resolve_battle(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    building_uuid: Uuid,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    let Some(business_uuid) = state
+        .building_ownerships_map
+        .get(&building_uuid)
+        .map(|ownership| ownership.owning_business_uuid)
+    else {
+        return Vec::new();
+    };
+
+    // Stand down attackers whose target no longer belongs to a rival,
+    // e.g. because another attacker captured it in the meantime
+    let Some(defender_corporation_uuid) = state.get_building_owner(&building_uuid) else {
+        clear_attack_target(state, building_uuid, |_| true);
+        return Vec::new();
+    };
+    clear_attack_target(state, building_uuid, |unit| {
+        unit.corporation_uuid == defender_corporation_uuid
+    });
+
+    let mut attackers = sorted_units(state, |unit| unit.is_attacking(&building_uuid));
+    if attackers.is_empty() {
+        // Committed units are still on their way
+        return Vec::new();
+    }
+    let mut defenders = sorted_units(state, |unit| {
+        unit.corporation_uuid == defender_corporation_uuid
+            && unit.building_uuid == building_uuid
+            && !unit.is_moving()
+    });
+
+    let attacker_corporation_uuids: Vec<Uuid> = attackers
+        .iter()
+        .map(|unit| unit.corporation_uuid)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    // --- 1. Exchange damage simultaneously ---
+    let damage_to_defenders = calculate_damage(&attackers, &defenders);
+    let damage_to_attackers = calculate_damage(&defenders, &attackers);
+    apply_damage(&mut defenders, damage_to_defenders);
+    apply_damage(&mut attackers, damage_to_attackers);
+
+    let mut destroyed_units = Vec::new();
+    for unit in defenders.iter().chain(attackers.iter()) {
+        if unit.health > 0 {
+            state.add_unit(*unit);
+        } else {
+            state.remove_unit(&unit.uuid);
+            destroyed_units.push(DestroyedUnit {
+                unit_uuid: unit.uuid,
+                corporation_uuid: unit.corporation_uuid,
+            });
+        }
+    }
+    attackers.retain(|unit| unit.health > 0);
+    defenders.retain(|unit| unit.health > 0);
+
+    // --- 2. Determine the outcome ---
+    let mut captured_business_uuid = None;
+    let mut capturing_corporation_uuid = None;
+
+    let result = if attackers.is_empty() {
+        BattleResult::DefendersWon
+    } else if defenders.is_empty() {
+        let is_headquarter = state
+            .ref_business(&business_uuid)
+            .is_some_and(|business| business.headquarter_building_uuid == building_uuid);
+
+        if is_headquarter {
+            let winner_uuid = strongest_corporation(&attackers);
+            match capture_business(state, business_uuid, defender_corporation_uuid, winner_uuid) {
+                Ok(()) => {
+                    captured_business_uuid = Some(business_uuid);
+                    capturing_corporation_uuid = Some(winner_uuid);
+                }
+                Err(err) => {
+                    tracing::error!(
+                        "Failed to transfer business '{}' after siege of building '{}': {}",
+                        business_uuid,
+                        building_uuid,
+                        err
+                    );
+                }
+            }
+        }
+
+        clear_attack_target(state, building_uuid, |_| true);
+        BattleResult::AttackersWon
+    } else {
+        BattleResult::Ongoing
+    };
+
+    // --- 3. Report to everyone involved ---
+    let building_gml_id = state
+        .ref_building_location(&building_uuid)
+        .map(|location| location.gml_id.clone())
+        .unwrap_or_default();
+
+    let involved_corporation_uuids: BTreeSet<Uuid> = attacker_corporation_uuids
+        .iter()
+        .copied()
+        .chain(std::iter::once(defender_corporation_uuid))
+        .collect();
+
+    involved_corporation_uuids
+        .into_iter()
+        .filter_map(|corporation_uuid| {
+            let user_uuid = state
+                .ref_corporation(&corporation_uuid)
+                .map(|corporation| corporation.user_uuid);
+            if user_uuid.is_none() {
+                tracing::warn!(
+                    "Corporation '{}' involved in battle at building '{}' not found",
+                    corporation_uuid,
+                    building_uuid
+                );
+            }
+            user_uuid
+        })
+        .map(|user_uuid| DomainActionOutcome::BattleReported {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: user_uuid,
+            building_gml_id: building_gml_id.clone(),
+            business_uuid,
+            defender_corporation_uuid,
+            attacker_corporation_uuids: attacker_corporation_uuids.clone(),
+            destroyed_units: destroyed_units.clone(),
+            result,
+            captured_business_uuid,
+            capturing_corporation_uuid,
+        })
+        .collect()
+}
+
+/// Units matching the filter, ordered by (health, uuid) so the weakest take damage first.
+fn sorted_units(state: &GameState, filter: impl Fn(&Unit) -> bool) -> Vec<Unit> {
+    let mut units: Vec<Unit> = state
+        .units_map
+        .values()
+        .filter(|unit| filter(unit))
+        .copied()
+        .collect();
+    units.sort_by_key(|unit| (unit.health, unit.uuid));
+    units
+}
+
+fn calculate_damage(dealing: &[Unit], receiving: &[Unit]) -> i32 {
+    let total_attack: i64 = dealing.iter().map(|unit| i64::from(unit.attack)).sum();
+    let total_defense: i64 = receiving.iter().map(|unit| i64::from(unit.defense)).sum();
+
+    i32::try_from(total_attack * 100 / (100 + total_defense)).unwrap_or(i32::MAX)
+}
+
+fn apply_damage(units: &mut [Unit], mut damage: i32) {
+    for unit in units.iter_mut() {
+        if damage <= 0 {
+            break;
+        }
+        let dealt = damage.min(unit.health);
+        unit.health -= dealt;
+        damage -= dealt;
+    }
+}
+
+/// The attacking corporation with the highest surviving attack; ties go to the lowest uuid.
+fn strongest_corporation(attackers: &[Unit]) -> Uuid {
+    let mut attack_by_corporation: BTreeMap<Uuid, i64> = BTreeMap::new();
+    for unit in attackers {
+        *attack_by_corporation
+            .entry(unit.corporation_uuid)
+            .or_default() += i64::from(unit.attack);
+    }
+
+    attack_by_corporation
+        .into_iter()
+        .max_by(|(uuid_a, attack_a), (uuid_b, attack_b)| {
+            attack_a.cmp(attack_b).then_with(|| uuid_b.cmp(uuid_a))
+        })
+        .map(|(corporation_uuid, _)| corporation_uuid)
+        .unwrap_or_default()
+}
+
+fn clear_attack_target(state: &mut GameState, building_uuid: Uuid, filter: impl Fn(&Unit) -> bool) {
+    for unit in state.units_map.values_mut() {
+        if unit.attack_target_building_uuid == Some(building_uuid) && filter(unit) {
+            unit.attack_target_building_uuid = None;
+        }
+    }
+}
+
+/// Transfers a besieged business to the winning corporation, taking it off the market.
+fn capture_business(
+    state: &mut GameState,
+    business_uuid: Uuid,
+    previous_owner_uuid: Uuid,
+    new_owner_uuid: Uuid,
+) -> Result<(), ActionError> {
+    let listing: Option<BusinessListing> = state
+        .business_listings_map
+        .values()
+        .find(|listing| listing.business_uuid == business_uuid)
+        .copied();
+
+    let mut executor = SagaExecutor::new(state);
+
+    // --- Step 1: Change Ownership ---
+    saga_step!(
+        executor,
+        "Transfer Ownership",
+        move |state: &mut GameState| {
+            state
+                .set_business_owner(&business_uuid, Some(new_owner_uuid))
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "Business",
+                    entity_id: business_uuid,
+                    step_description: "Transfer Ownership",
+                })
+                .map(|_| ())
+        },
+        move |state: &mut GameState| {
+            if state
+                .set_business_owner(&business_uuid, Some(previous_owner_uuid))
+                .is_none()
+            {
+                tracing::error!("CRITICAL: Saga Rollback (Transfer Ownership) - Failed to find business {} to restore ownership.", business_uuid);
+            }
+        }
+    );
+
+    // --- Step 2: Remove Listing ---
+    if let Some(listing) = listing {
+        saga_step!(
+            executor,
+            "Remove Listing",
+            move |state: &mut GameState| {
+                state
+                    .remove_business_listing(&listing.uuid)
+                    .ok_or(ActionError::SagaEntityMissing {
+                        entity_type: "BusinessListing",
+                        entity_id: listing.uuid,
+                        step_description: "Remove Listing",
+                    })
+                    .map(|_| ())
+            },
+            move |state: &mut GameState| {
+                state.add_business_listing(listing);
+            }
+        );
+    }
+
+    executor.execute()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::building::model::BuildingLocation;
+    use crate::domain::economy::building_ownership::model::BuildingOwnership;
+    use crate::domain::economy::business::model::Business;
+    use crate::domain::economy::corporation::model::{name::CorporationName, Corporation};
+    use crate::domain::unit::model::unit_type::UnitType;
+
+    struct TestSetup {
+        attacker_user_uuid: Uuid,
+        attacker_corp_uuid: Uuid,
+        defender_user_uuid: Uuid,
+        defender_corp_uuid: Uuid,
+        building_uuid: Uuid,
+        business_uuid: Uuid,
+    }
+
+    impl TestSetup {
+        fn new() -> Self {
+            Self {
+                attacker_user_uuid: Uuid::now_v7(),
+                attacker_corp_uuid: Uuid::now_v7(),
+                defender_user_uuid: Uuid::now_v7(),
+                defender_corp_uuid: Uuid::now_v7(),
+                building_uuid: Uuid::now_v7(),
+                business_uuid: Uuid::now_v7(),
+            }
+        }
+
+        fn attacker(&self, unit_type: UnitType) -> Unit {
+            Unit {
+                attack_target_building_uuid: Some(self.building_uuid),
+                ..Unit::spawn(
+                    Uuid::now_v7(),
+                    self.attacker_corp_uuid,
+                    unit_type,
+                    self.building_uuid,
+                )
+            }
+        }
+
+        fn defender(&self, unit_type: UnitType) -> Unit {
+            Unit::spawn(
+                Uuid::now_v7(),
+                self.defender_corp_uuid,
+                unit_type,
+                self.building_uuid,
+            )
+        }
+
+        fn state(&self, units: Vec<Unit>, listings: Vec<BusinessListing>) -> GameState {
+            let corporations = vec![
+                Corporation {
+                    uuid: self.attacker_corp_uuid,
+                    user_uuid: self.attacker_user_uuid,
+                    name: CorporationName::new("Attacker Corp".to_string()).unwrap(),
+                    cash_balance: 1000,
+                    insolvent_since_tick: None,
+                },
+                Corporation {
+                    uuid: self.defender_corp_uuid,
+                    user_uuid: self.defender_user_uuid,
+                    name: CorporationName::new("Defender Corp".to_string()).unwrap(),
+                    cash_balance: 1000,
+                    insolvent_since_tick: None,
+                },
+            ];
+            let business = Business {
+                uuid: self.business_uuid,
+                market_uuid: Uuid::now_v7(),
+                owning_corporation_uuid: Some(self.defender_corp_uuid),
+                name: "Defender Biz".to_string(),
+                operational_expenses: 100,
+                headquarter_building_uuid: self.building_uuid,
+                image_number: 1,
+                operational_expenses_changed_at_tick: None,
+            };
+            let ownership = BuildingOwnership {
+                building_uuid: self.building_uuid,
+                owning_business_uuid: self.business_uuid,
+            };
+            let location = BuildingLocation {
+                uuid: self.building_uuid,
+                gml_id: "DEBY_HQ".to_string(),
+                longitude: 11.57,
+                latitude: 48.13,
+                volume: 1000.0,
+                usage_code: None,
+                city_code: None,
+            };
+
+            GameState::build()
+                .last_processed_tick(9)
+                .units_vec(units)
+                .corporations_vec(corporations)
+                .markets_vec(vec![])
+                .businesses_vec(vec![business])
+                .business_listings_vec(listings)
+                .business_offers_vec(vec![])
+                .building_ownerships_vec(vec![ownership])
+                .building_locations_vec(vec![location])
+                .call()
+        }
+    }
+
+    #[test]
+    fn should_transfer_business_when_headquarter_falls() {
+        let setup = TestSetup::new();
+        let attackers = [
+            setup.attacker(UnitType::Mech),
+            setup.attacker(UnitType::Mech),
+        ];
+        let defender = setup.defender(UnitType::Drone);
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: setup.business_uuid,
+            seller_corporation_uuid: Some(setup.defender_corp_uuid),
+            asking_price: 5000,
+            auction_closes_at_tick: None,
+        };
+        let mut state = setup.state(vec![attackers[0], attackers[1], defender], vec![listing]);
+
+        let notifications = resolve_combat(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        // Both corporations receive the same report
+        assert_eq!(notifications.len(), 2);
+        for notification in &notifications {
+            let DomainActionOutcome::BattleReported {
+                result,
+                destroyed_units,
+                captured_business_uuid,
+                capturing_corporation_uuid,
+                ..
+            } = notification
+            else {
+                panic!("Expected BattleReported, got {notification:?}");
+            };
+            assert_eq!(*result, BattleResult::AttackersWon);
+            assert_eq!(
+                destroyed_units.as_slice(),
+                [DestroyedUnit {
+                    unit_uuid: defender.uuid,
+                    corporation_uuid: setup.defender_corp_uuid,
+                }]
+            );
+            assert_eq!(*captured_business_uuid, Some(setup.business_uuid));
+            assert_eq!(*capturing_corporation_uuid, Some(setup.attacker_corp_uuid));
+        }
+        let recipients: BTreeSet<Uuid> = notifications
+            .iter()
+            .map(|notification| notification.get_req_user_uuid())
+            .collect();
+        assert!(recipients.contains(&setup.attacker_user_uuid));
+        assert!(recipients.contains(&setup.defender_user_uuid));
+
+        assert!(state.is_business_owned_by(&setup.business_uuid, &setup.attacker_corp_uuid));
+        assert!(!state.is_business_owned_by(&setup.business_uuid, &setup.defender_corp_uuid));
+        assert!(state.ref_business_listing(&listing.uuid).is_none());
+        assert!(state.ref_unit(&defender.uuid).is_none());
+
+        // Surviving attackers stand down and take their losses
+        for attacker in attackers {
+            let survivor = state.ref_unit(&attacker.uuid).unwrap();
+            assert_eq!(survivor.attack_target_building_uuid, None);
+        }
+        let total_health: i32 = attackers
+            .iter()
+            .map(|attacker| state.ref_unit(&attacker.uuid).unwrap().health)
+            .sum();
+        assert_eq!(total_health, 600 - 4);
+    }
+
+    #[test]
+    fn should_keep_business_when_defenders_win() {
+        let setup = TestSetup::new();
+        let attacker = Unit {
+            health: 10,
+            ..setup.attacker(UnitType::Drone)
+        };
+        let defender = setup.defender(UnitType::Mech);
+        let mut state = setup.state(vec![attacker, defender], vec![]);
+
+        let notifications = resolve_combat(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert!(matches!(
+            notifications.first(),
+            Some(DomainActionOutcome::BattleReported {
+                result: BattleResult::DefendersWon,
+                captured_business_uuid: None,
+                ..
+            })
+        ));
+        assert!(state.ref_unit(&attacker.uuid).is_none());
+        assert!(state.is_business_owned_by(&setup.business_uuid, &setup.defender_corp_uuid));
+        assert!(state.ref_unit(&defender.uuid).unwrap().health < 300);
+    }
+
+    #[test]
+    fn should_continue_siege_while_both_sides_stand() {
+        let setup = TestSetup::new();
+        let attacker = setup.attacker(UnitType::Enforcer);
+        let defender = setup.defender(UnitType::Enforcer);
+        let mut state = setup.state(vec![attacker, defender], vec![]);
+
+        let notifications = resolve_combat(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert!(matches!(
+            notifications.first(),
+            Some(DomainActionOutcome::BattleReported {
+                result: BattleResult::Ongoing,
+                ..
+            })
+        ));
+        assert_eq!(state.ref_unit(&attacker.uuid).unwrap().health, 90);
+        assert_eq!(state.ref_unit(&defender.uuid).unwrap().health, 90);
+        assert!(state
+            .ref_unit(&attacker.uuid)
+            .unwrap()
+            .is_attacking(&setup.building_uuid));
+    }
+
+    #[test]
+    fn should_ignore_attackers_still_on_their_way() {
+        let setup = TestSetup::new();
+        let attacker = Unit {
+            destination_building_uuid: Some(setup.building_uuid),
+            arrival_tick: Some(12),
+            ..setup.attacker(UnitType::Mech)
+        };
+        let mut state = setup.state(vec![attacker], vec![]);
+
+        let notifications = resolve_combat(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert!(notifications.is_empty());
+        assert!(state.is_business_owned_by(&setup.business_uuid, &setup.defender_corp_uuid));
+    }
+}
@@ -0,0 +1,323 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        economy::{
+            contract::model::{condition::ContractCondition, Contract},
+            ledger::model::entry_kind::LedgerEntryKind,
+        },
+        outcome::DomainActionOutcome,
+        simulation::{game_state::GameState, rng::SimulationRng, saga::SagaExecutor, ActionError},
+    },
+    saga_step,
+};
+
+/// Settles all contracts whose due tick has been reached. The escrowed amount goes to the payee
+/// if the condition holds and back to the payer otherwise.
+pub fn settle_contracts(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    let mut due_contract_uuids: Vec<Uuid> = state
+        .contracts_map
+        .values()
+        .filter(|contract| contract.due_at_tick <= next_game_tick)
+        .map(|contract| contract.uuid)
+        .collect();
+
+    // Process in a stable order to keep the simulation deterministic
+    due_contract_uuids.sort();
+
+    let mut notifications = Vec::with_capacity(due_contract_uuids.len() * 2);
+
+    for contract_uuid in due_contract_uuids {
+        let Some(contract) = state.ref_contract(&contract_uuid).copied() else {
+            continue;
+        };
+
+        // A party might have been dissolved in the meantime, the cash goes to whoever is left
+        let payee_exists = state
+            .ref_corporation(&contract.payee_corporation_uuid)
+            .is_some();
+        let paid = payee_exists && is_condition_met(state, &contract);
+        let recipient_corporation_uuid = if paid {
+            Some(contract.payee_corporation_uuid)
+        } else {
+            state
+                .ref_corporation(&contract.payer_corporation_uuid)
+                .map(|corporation| corporation.uuid)
+        };
+
+        if let Err(err) = release_escrow(state, &contract, recipient_corporation_uuid) {
+            tracing::error!("Failed to settle contract '{}': {}", contract.uuid, err);
+            continue;
+        }
+
+        if let Some(recipient_corporation_uuid) = recipient_corporation_uuid {
+            state
+                .record_ledger_entry()
+                .game_tick(next_game_tick)
+                .corporation_uuid(recipient_corporation_uuid)
+                .kind(if paid {
+                    LedgerEntryKind::ContractPayment
+                } else {
+                    LedgerEntryKind::ContractRefund
+                })
+                .amount(contract.amount)
+                .maybe_business_uuid(contract.condition.business_uuid())
+                .call();
+        }
+
+        for corporation_uuid in [
+            contract.payer_corporation_uuid,
+            contract.payee_corporation_uuid,
+        ] {
+            let Some(corporation) = state.ref_corporation(&corporation_uuid) else {
+                continue;
+            };
+
+            notifications.push(DomainActionOutcome::ContractSettled {
+                request_uuid: rng.next_uuid(),
+                tick_effective: next_game_tick,
+                req_user_uuid: corporation.user_uuid,
+                contract_uuid: contract.uuid,
+                payer_corporation_uuid: contract.payer_corporation_uuid,
+                payee_corporation_uuid: contract.payee_corporation_uuid,
+                amount: contract.amount,
+                paid,
+            });
+        }
+    }
+
+    notifications
+}
+
+fn is_condition_met(state: &GameState, contract: &Contract) -> bool {
+    match contract.condition {
+        ContractCondition::Unconditional => true,
+        ContractCondition::PayerOwnsBusiness { business_uuid } => {
+            state.ref_business(&business_uuid).is_some_and(|business| {
+                business.owning_corporation_uuid == Some(contract.payer_corporation_uuid)
+            })
+        }
+        ContractCondition::PeaceHolds => state.is_attack_forbidden(
+            &contract.payer_corporation_uuid,
+            &contract.payee_corporation_uuid,
+        ),
+    }
+}
+
+/// Removes the contract and credits the escrowed amount to the recipient, if there is one left.
+fn release_escrow(
+    state: &mut GameState,
+    contract: &Contract,
+    recipient_corporation_uuid: Option<Uuid>,
+) -> Result<(), ActionError> {
+    let contract = *contract;
+    let mut executor = SagaExecutor::new(state);
+
+    // Step1: Remove contract
+    saga_step!(
+        executor,
+        "Remove Contract",
+        |state: &mut GameState| {
+            state
+                .remove_contract(&contract.uuid)
+                .ok_or(ActionError::SagaEntityMissing {
+                    entity_type: "Contract",
+                    entity_id: contract.uuid,
+                    step_description: "Remove Contract",
+                })
+                .map(|_| ())
+        },
+        |state: &mut GameState| {
+            state.add_contract(contract);
+        }
+    );
+
+    // Step2: Credit recipient
+    if let Some(recipient_corporation_uuid) = recipient_corporation_uuid {
+        saga_step!(
+            executor,
+            "Credit Recipient",
+            move |state: &mut GameState| {
+                let corporation = state
+                    .ref_mut_corporation(&recipient_corporation_uuid)
+                    .ok_or(ActionError::SagaEntityMissing {
+                        entity_type: "Corporation",
+                        entity_id: recipient_corporation_uuid,
+                        step_description: "Credit Recipient",
+                    })?;
+                corporation.cash_balance += contract.amount;
+                Ok(())
+            },
+            move |state: &mut GameState| {
+                if let Some(corporation) = state.ref_mut_corporation(&recipient_corporation_uuid) {
+                    corporation.cash_balance -= contract.amount;
+                } else {
+                    tracing::error!("CRITICAL: Saga Rollback (Credit Recipient) - Failed to find corporation {} to debit back.", recipient_corporation_uuid);
+                }
+            }
+        );
+    }
+
+    executor.execute()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        diplomacy::model::{treaty_type::TreatyType, Treaty},
+        economy::corporation::model::{name::CorporationName, Corporation},
+    };
+
+    struct TestSetup {
+        state: GameState,
+        payer_user_uuid: Uuid,
+        payer_corp_uuid: Uuid,
+        payee_user_uuid: Uuid,
+        payee_corp_uuid: Uuid,
+    }
+
+    fn setup_test_state(condition: ContractCondition) -> TestSetup {
+        let payer_user_uuid = Uuid::now_v7();
+        let payer_corp_uuid = Uuid::now_v7();
+        let payee_user_uuid = Uuid::now_v7();
+        let payee_corp_uuid = Uuid::now_v7();
+
+        let corporations = vec![
+            Corporation {
+                uuid: payer_corp_uuid,
+                user_uuid: payer_user_uuid,
+                name: CorporationName::new("Payer Corp".to_string()).unwrap(),
+                cash_balance: 600,
+                insolvent_since_tick: None,
+            },
+            Corporation {
+                uuid: payee_corp_uuid,
+                user_uuid: payee_user_uuid,
+                name: CorporationName::new("Payee Corp".to_string()).unwrap(),
+                cash_balance: 500,
+                insolvent_since_tick: None,
+            },
+        ];
+        let due_contract = Contract {
+            uuid: Uuid::now_v7(),
+            payer_corporation_uuid: payer_corp_uuid,
+            payee_corporation_uuid: payee_corp_uuid,
+            amount: 400,
+            condition,
+            created_at_tick: 1,
+            due_at_tick: 10,
+        };
+        let open_contract = Contract {
+            uuid: Uuid::now_v7(),
+            due_at_tick: 11,
+            ..due_contract
+        };
+
+        let state = GameState::build()
+            .last_processed_tick(9)
+            .units_vec(vec![])
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .contracts_vec(vec![due_contract, open_contract])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        TestSetup {
+            state,
+            payer_user_uuid,
+            payer_corp_uuid,
+            payee_user_uuid,
+            payee_corp_uuid,
+        }
+    }
+
+    fn cash_balance(setup: &TestSetup, corporation_uuid: &Uuid) -> i64 {
+        setup
+            .state
+            .ref_corporation(corporation_uuid)
+            .unwrap()
+            .cash_balance
+    }
+
+    #[test]
+    fn should_pay_the_payee_of_due_contracts_only() {
+        let mut setup = setup_test_state(ContractCondition::Unconditional);
+
+        let notifications =
+            settle_contracts(&mut setup.state, &mut SimulationRng::from_seed(0), 10);
+
+        assert_eq!(setup.state.contracts_map.len(), 1);
+        assert_eq!(cash_balance(&setup, &setup.payer_corp_uuid), 600);
+        assert_eq!(cash_balance(&setup, &setup.payee_corp_uuid), 900);
+        assert_eq!(notifications.len(), 2);
+        assert!(notifications.iter().all(|notification| matches!(
+            notification,
+            DomainActionOutcome::ContractSettled {
+                paid: true,
+                amount: 400,
+                ..
+            }
+        )));
+        assert_eq!(
+            notifications
+                .iter()
+                .map(|notification| notification.get_req_user_uuid())
+                .collect::<Vec<_>>(),
+            vec![setup.payer_user_uuid, setup.payee_user_uuid]
+        );
+        assert_eq!(setup.state.ledger_entries.len(), 1);
+        assert_eq!(
+            setup.state.ledger_entries[0].corporation_uuid,
+            setup.payee_corp_uuid
+        );
+        assert_eq!(
+            setup.state.ledger_entries[0].kind,
+            LedgerEntryKind::ContractPayment
+        );
+    }
+
+    #[test]
+    fn should_refund_the_payer_if_the_condition_does_not_hold() {
+        let mut setup = setup_test_state(ContractCondition::PeaceHolds);
+
+        let notifications =
+            settle_contracts(&mut setup.state, &mut SimulationRng::from_seed(0), 10);
+
+        assert_eq!(cash_balance(&setup, &setup.payer_corp_uuid), 1000);
+        assert_eq!(cash_balance(&setup, &setup.payee_corp_uuid), 500);
+        assert!(notifications.iter().all(|notification| matches!(
+            notification,
+            DomainActionOutcome::ContractSettled { paid: false, .. }
+        )));
+        assert_eq!(
+            setup.state.ledger_entries[0].kind,
+            LedgerEntryKind::ContractRefund
+        );
+    }
+
+    #[test]
+    fn should_pay_the_payee_while_peace_holds() {
+        let mut setup = setup_test_state(ContractCondition::PeaceHolds);
+        setup.state.add_treaty(Treaty {
+            uuid: Uuid::now_v7(),
+            treaty_type: TreatyType::NonAggression,
+            proposer_corporation_uuid: setup.payee_corp_uuid,
+            partner_corporation_uuid: setup.payer_corp_uuid,
+            proposed_at_tick: 1,
+            accepted_at_tick: Some(2),
+        });
+
+        settle_contracts(&mut setup.state, &mut SimulationRng::from_seed(0), 10);
+
+        assert_eq!(cash_balance(&setup, &setup.payer_corp_uuid), 600);
+        assert_eq!(cash_balance(&setup, &setup.payee_corp_uuid), 900);
+    }
+}
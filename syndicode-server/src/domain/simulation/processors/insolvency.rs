@@ -0,0 +1,311 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    economy::business_listing::model::BusinessListing,
+    outcome::DomainActionOutcome,
+    simulation::{
+        game_state::GameState, handlers::delete_corporation::dissolve_corporation,
+        rng::SimulationRng,
+    },
+};
+
+/// Tracks corporations whose cash balance is at or below zero. A corporation that stays insolvent
+/// for `grace_ticks` goes bankrupt: its units are disbanded, its businesses are put up for sale
+/// without any proceeds for it and the corporation itself is dissolved.
+pub fn resolve_insolvencies(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+    grace_ticks: i64,
+    liquidation_asking_price: i64,
+) -> Vec<DomainActionOutcome> {
+    let mut corporation_uuids: Vec<Uuid> = state.corporations_map.keys().copied().collect();
+
+    // Process in a stable order to keep the simulation deterministic
+    corporation_uuids.sort();
+
+    let mut notifications = Vec::new();
+    let mut bankrupt_corporation_uuids = Vec::new();
+
+    for corporation_uuid in corporation_uuids {
+        let Some(corporation) = state.ref_mut_corporation(&corporation_uuid) else {
+            continue;
+        };
+
+        if corporation.cash_balance > 0 {
+            corporation.insolvent_since_tick = None;
+            continue;
+        }
+
+        let insolvent_since_tick = match corporation.insolvent_since_tick {
+            Some(insolvent_since_tick) => insolvent_since_tick,
+            None => {
+                corporation.insolvent_since_tick = Some(next_game_tick);
+
+                notifications.push(DomainActionOutcome::CorporationInsolvent {
+                    request_uuid: rng.next_uuid(),
+                    tick_effective: next_game_tick,
+                    req_user_uuid: corporation.user_uuid,
+                    corporation_uuid,
+                    cash_balance: corporation.cash_balance,
+                    bankruptcy_at_tick: next_game_tick + grace_ticks,
+                });
+
+                next_game_tick
+            }
+        };
+
+        if insolvent_since_tick + grace_ticks <= next_game_tick {
+            bankrupt_corporation_uuids.push(corporation_uuid);
+        }
+    }
+
+    for corporation_uuid in bankrupt_corporation_uuids {
+        let mut liquidated_business_uuids = state
+            .business_uuids_by_corporation_uuid
+            .get(&corporation_uuid)
+            .cloned()
+            .unwrap_or_default();
+        let mut disbanded_unit_uuids = state
+            .unit_uuids_by_corporation_uuid
+            .get(&corporation_uuid)
+            .cloned()
+            .unwrap_or_default();
+
+        liquidated_business_uuids.sort();
+        disbanded_unit_uuids.sort();
+
+        let corporation = match dissolve_corporation(state, corporation_uuid) {
+            Ok(corporation) => corporation,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to dissolve bankrupt corporation '{}': {}",
+                    corporation_uuid,
+                    err
+                );
+                continue;
+            }
+        };
+
+        for business_uuid in &liquidated_business_uuids {
+            liquidate_business(state, rng, *business_uuid, liquidation_asking_price);
+        }
+
+        notifications.push(DomainActionOutcome::CorporationBankrupt {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: corporation.user_uuid,
+            corporation_uuid,
+            liquidated_business_uuids,
+            disbanded_unit_uuids,
+        });
+    }
+
+    notifications
+}
+
+/// Takes a business away from its owner and puts it up for sale without a seller,
+/// so nobody receives the proceeds. An existing listing keeps its asking price.
+pub(super) fn liquidate_business(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    business_uuid: Uuid,
+    asking_price: i64,
+) {
+    if state.set_business_owner(&business_uuid, None).is_none() {
+        tracing::error!("Failed to liquidate business with UUID '{}'", business_uuid);
+        return;
+    }
+
+    let existing_listing = state
+        .business_listings_map
+        .values()
+        .find(|listing| listing.business_uuid == business_uuid)
+        .copied();
+
+    let listing = match existing_listing {
+        Some(listing) if listing.seller_corporation_uuid.is_none() => return,
+        Some(listing) => {
+            state.remove_business_listing(&listing.uuid);
+            BusinessListing {
+                seller_corporation_uuid: None,
+                ..listing
+            }
+        }
+        None => BusinessListing::builder()
+            .uuid(rng.next_uuid())
+            .business_uuid(business_uuid)
+            .asking_price(asking_price)
+            .build(),
+    };
+    state.add_business_listing(listing);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        economy::{
+            business::model::Business,
+            corporation::model::{name::CorporationName, Corporation},
+        },
+        unit::model::{unit_type::UnitType, Unit},
+    };
+
+    const GRACE_TICKS: i64 = 3;
+    const LIQUIDATION_ASKING_PRICE: i64 = 1000;
+
+    struct Setup {
+        user_uuid: Uuid,
+        corporation_uuid: Uuid,
+        business_uuid: Uuid,
+        unit_uuid: Uuid,
+        state: GameState,
+    }
+
+    fn setup(cash_balance: i64) -> Setup {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Broke Corp".to_string()).unwrap(),
+            cash_balance,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(corporation_uuid),
+            name: "Broke Biz".to_string(),
+            operational_expenses: 500,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let unit = Unit::spawn(
+            Uuid::now_v7(),
+            corporation_uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        );
+        let business_uuid = business.uuid;
+        let unit_uuid = unit.uuid;
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![unit])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        Setup {
+            user_uuid,
+            corporation_uuid,
+            business_uuid,
+            unit_uuid,
+            state,
+        }
+    }
+
+    fn resolve(state: &mut GameState, next_game_tick: i64) -> Vec<DomainActionOutcome> {
+        resolve_insolvencies(
+            state,
+            &mut SimulationRng::from_seed(1),
+            next_game_tick,
+            GRACE_TICKS,
+            LIQUIDATION_ASKING_PRICE,
+        )
+    }
+
+    #[test]
+    fn should_notify_once_and_clear_insolvency_when_recovered() {
+        let Setup {
+            user_uuid,
+            corporation_uuid,
+            mut state,
+            ..
+        } = setup(0);
+
+        let notifications = resolve(&mut state, 5);
+
+        assert_eq!(notifications.len(), 1);
+        let DomainActionOutcome::CorporationInsolvent {
+            req_user_uuid,
+            bankruptcy_at_tick,
+            ..
+        } = notifications[0]
+        else {
+            panic!("Expected CorporationInsolvent, got {:?}", notifications[0]);
+        };
+        assert_eq!(req_user_uuid, user_uuid);
+        assert_eq!(bankruptcy_at_tick, 5 + GRACE_TICKS);
+
+        assert!(resolve(&mut state, 6).is_empty());
+        let corporation = state.ref_mut_corporation(&corporation_uuid).unwrap();
+        assert_eq!(corporation.insolvent_since_tick, Some(5));
+
+        corporation.cash_balance = 100;
+        assert!(resolve(&mut state, 7).is_empty());
+        assert_eq!(
+            state
+                .ref_corporation(&corporation_uuid)
+                .unwrap()
+                .insolvent_since_tick,
+            None
+        );
+    }
+
+    #[test]
+    fn should_liquidate_corporation_after_grace_period() {
+        let Setup {
+            user_uuid,
+            corporation_uuid,
+            business_uuid,
+            unit_uuid,
+            mut state,
+        } = setup(-200);
+
+        resolve(&mut state, 5);
+        assert!(resolve(&mut state, 5 + GRACE_TICKS - 1).is_empty());
+        let notifications = resolve(&mut state, 5 + GRACE_TICKS);
+
+        assert_eq!(notifications.len(), 1);
+        let DomainActionOutcome::CorporationBankrupt {
+            req_user_uuid,
+            liquidated_business_uuids,
+            disbanded_unit_uuids,
+            ..
+        } = &notifications[0]
+        else {
+            panic!("Expected CorporationBankrupt, got {:?}", notifications[0]);
+        };
+        assert_eq!(*req_user_uuid, user_uuid);
+        assert_eq!(liquidated_business_uuids, &vec![business_uuid]);
+        assert_eq!(disbanded_unit_uuids, &vec![unit_uuid]);
+
+        assert!(state.ref_corporation(&corporation_uuid).is_none());
+        assert!(state.get_corporation_uuid_by_user(&user_uuid).is_none());
+        assert!(state.ref_unit(&unit_uuid).is_none());
+        assert_eq!(
+            state
+                .ref_business(&business_uuid)
+                .unwrap()
+                .owning_corporation_uuid,
+            None
+        );
+
+        let listing = state
+            .business_listings_map
+            .values()
+            .find(|listing| listing.business_uuid == business_uuid)
+            .unwrap();
+        assert_eq!(listing.seller_corporation_uuid, None);
+        assert_eq!(listing.asking_price, LIQUIDATION_ASKING_PRICE);
+    }
+}
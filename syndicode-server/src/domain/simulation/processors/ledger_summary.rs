@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    economy::ledger::model::LedgerSummary,
+    outcome::DomainActionOutcome,
+    simulation::{game_state::GameState, rng::SimulationRng},
+};
+
+/// Sums up the ledger of the tick per corporation and notifies every corporation
+/// whose cash has moved. Runs last, so the reported balance is the final one of the tick.
+pub fn summarize_ledger(
+    state: &GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    // Ordered by corporation to keep the simulation deterministic
+    let mut summaries: BTreeMap<Uuid, LedgerSummary> = BTreeMap::new();
+    for entry in &state.ledger_entries {
+        summaries
+            .entry(entry.corporation_uuid)
+            .or_default()
+            .add(entry);
+    }
+
+    let mut notifications = Vec::with_capacity(summaries.len());
+
+    for (corporation_uuid, summary) in summaries {
+        // The corporation might have been deleted later in the tick
+        let Some(corporation) = state.ref_corporation(&corporation_uuid) else {
+            continue;
+        };
+
+        notifications.push(DomainActionOutcome::CorporationLedgerSummarized {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: corporation.user_uuid,
+            corporation_uuid,
+            summary,
+            cash_balance: corporation.cash_balance,
+        });
+    }
+
+    notifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::{
+        corporation::model::{name::CorporationName, Corporation},
+        ledger::model::entry_kind::LedgerEntryKind,
+    };
+
+    #[test]
+    fn should_summarize_ledger_per_corporation() {
+        let corporation = Corporation {
+            uuid: Uuid::now_v7(),
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::new("Ledger Corp".to_string()).unwrap(),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        };
+        let idle_corporation = Corporation {
+            uuid: Uuid::now_v7(),
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::new("Idle Corp".to_string()).unwrap(),
+            cash_balance: 500,
+            insolvent_since_tick: None,
+        };
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation.clone(), idle_corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        for (kind, amount) in [
+            (LedgerEntryKind::BusinessIncome, 300),
+            (LedgerEntryKind::OperationalExpenses, -120),
+            (LedgerEntryKind::UnitSpawn, -80),
+            (LedgerEntryKind::OfferEscrow, -50),
+            (LedgerEntryKind::OfferRefund, 20),
+        ] {
+            state
+                .record_ledger_entry()
+                .game_tick(1)
+                .corporation_uuid(corporation.uuid)
+                .kind(kind)
+                .amount(amount)
+                .call();
+        }
+
+        let notifications = summarize_ledger(&state, &mut SimulationRng::from_seed(1), 1);
+
+        assert_eq!(notifications.len(), 1);
+        let DomainActionOutcome::CorporationLedgerSummarized {
+            req_user_uuid,
+            summary,
+            cash_balance,
+            ..
+        } = &notifications[0]
+        else {
+            panic!("Expected a ledger summary");
+        };
+        assert_eq!(*req_user_uuid, corporation.user_uuid);
+        assert_eq!(summary.business_income, 300);
+        assert_eq!(summary.operational_expenses, -120);
+        assert_eq!(summary.unit_spawns, -80);
+        assert_eq!(summary.escrow, -30);
+        assert_eq!(summary.net_change(), 70);
+        assert_eq!(*cash_balance, 1000);
+    }
+}
@@ -0,0 +1,250 @@
+use uuid::Uuid;
+
+use super::insolvency::liquidate_business;
+use crate::domain::{
+    economy::ledger::model::entry_kind::LedgerEntryKind,
+    outcome::DomainActionOutcome,
+    simulation::{game_state::GameState, rng::SimulationRng},
+};
+
+/// Adds the interest of the tick to every loan and collects the loans that are due.
+/// If the cash of a corporation doesn't cover a due loan, the loan defaults: the rest is
+/// written off and the bank seizes all businesses of the corporation and puts them up for sale.
+pub fn service_loans(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+    liquidation_asking_price: i64,
+) -> Vec<DomainActionOutcome> {
+    let mut loan_uuids: Vec<Uuid> = state.loans_map.keys().copied().collect();
+
+    // Loans of the same corporation compete for its cash, so the order matters for determinism
+    loan_uuids.sort();
+
+    let mut notifications = Vec::new();
+
+    for loan_uuid in loan_uuids {
+        let Some(loan) = state.ref_mut_loan(&loan_uuid) else {
+            continue;
+        };
+
+        if loan.due_at_tick > next_game_tick {
+            loan.outstanding_balance += loan.interest_per_tick();
+            continue;
+        }
+
+        let loan = *loan;
+        let Some(corporation) = state.ref_mut_corporation(&loan.corporation_uuid) else {
+            tracing::warn!(
+                "Corporation '{}' of due loan '{}' not found",
+                loan.corporation_uuid,
+                loan.uuid
+            );
+            state.remove_loan(&loan_uuid);
+            continue;
+        };
+
+        let collected_amount = corporation.cash_balance.clamp(0, loan.outstanding_balance);
+        corporation.cash_balance -= collected_amount;
+        let user_uuid = corporation.user_uuid;
+
+        state.remove_loan(&loan_uuid);
+
+        if collected_amount > 0 {
+            state
+                .record_ledger_entry()
+                .game_tick(next_game_tick)
+                .corporation_uuid(loan.corporation_uuid)
+                .kind(LedgerEntryKind::LoanRepayment)
+                .amount(-collected_amount)
+                .call();
+        }
+
+        if collected_amount == loan.outstanding_balance {
+            notifications.push(DomainActionOutcome::LoanRepaid {
+                request_uuid: rng.next_uuid(),
+                tick_effective: next_game_tick,
+                req_user_uuid: user_uuid,
+                loan_uuid,
+                amount: collected_amount,
+                outstanding_balance: 0,
+            });
+            continue;
+        }
+
+        let mut liquidated_business_uuids = state
+            .business_uuids_by_corporation_uuid
+            .get(&loan.corporation_uuid)
+            .cloned()
+            .unwrap_or_default();
+        liquidated_business_uuids.sort();
+
+        for business_uuid in &liquidated_business_uuids {
+            liquidate_business(state, rng, *business_uuid, liquidation_asking_price);
+        }
+
+        notifications.push(DomainActionOutcome::LoanDefaulted {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: user_uuid,
+            loan_uuid,
+            collected_amount,
+            written_off_amount: loan.outstanding_balance - collected_amount,
+            liquidated_business_uuids,
+        });
+    }
+
+    notifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::{
+        business::model::Business,
+        business_listing::model::BusinessListing,
+        corporation::model::{name::CorporationName, Corporation},
+        loan::model::Loan,
+    };
+
+    struct Setup {
+        corporation_uuid: Uuid,
+        business_uuid: Uuid,
+        loan: Loan,
+    }
+
+    fn setup() -> Setup {
+        let corporation_uuid = Uuid::now_v7();
+        let loan = Loan {
+            uuid: Uuid::now_v7(),
+            corporation_uuid,
+            principal: 10_000,
+            outstanding_balance: 10_000,
+            interest_rate_bps: 15,
+            taken_at_tick: 1,
+            due_at_tick: 10,
+        };
+
+        Setup {
+            corporation_uuid,
+            business_uuid: Uuid::now_v7(),
+            loan,
+        }
+    }
+
+    fn state(setup: &Setup, cash_balance: i64, listings: Vec<BusinessListing>) -> GameState {
+        let corporation = Corporation {
+            uuid: setup.corporation_uuid,
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::new("Indebted Corp".to_string()).unwrap(),
+            cash_balance,
+            insolvent_since_tick: None,
+        };
+        let business = Business {
+            uuid: setup.business_uuid,
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(setup.corporation_uuid),
+            name: "Pledged Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+
+        GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![business])
+            .business_listings_vec(listings)
+            .business_offers_vec(vec![])
+            .loans_vec(vec![setup.loan])
+            .building_ownerships_vec(vec![])
+            .call()
+    }
+
+    #[test]
+    fn should_accrue_interest_rounded_up_before_due() {
+        let setup = setup();
+        let mut state = state(&setup, 0, vec![]);
+
+        let notifications = service_loans(&mut state, &mut SimulationRng::from_seed(1), 9, 1000);
+
+        assert!(notifications.is_empty());
+        // 10_000 * 0.15% = 15
+        assert_eq!(
+            state
+                .ref_loan(&setup.loan.uuid)
+                .unwrap()
+                .outstanding_balance,
+            10_015
+        );
+    }
+
+    #[test]
+    fn should_collect_due_loan_from_cash() {
+        let setup = setup();
+        let mut state = state(&setup, 50_000, vec![]);
+
+        let notifications = service_loans(&mut state, &mut SimulationRng::from_seed(1), 10, 1000);
+
+        assert!(matches!(
+            notifications[..],
+            [DomainActionOutcome::LoanRepaid { amount: 10_000, .. }]
+        ));
+        assert!(state.ref_loan(&setup.loan.uuid).is_none());
+        assert_eq!(
+            state
+                .ref_corporation(&setup.corporation_uuid)
+                .unwrap()
+                .cash_balance,
+            40_000
+        );
+        assert!(state.is_business_owned_by(&setup.business_uuid, &setup.corporation_uuid));
+    }
+
+    #[test]
+    fn should_seize_businesses_on_default() {
+        let setup = setup();
+        // The business is listed by its owner, the bank takes the listing over
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: setup.business_uuid,
+            seller_corporation_uuid: Some(setup.corporation_uuid),
+            asking_price: 7_000,
+            auction_closes_at_tick: None,
+        };
+        let mut state = state(&setup, 4_000, vec![listing]);
+
+        let notifications = service_loans(&mut state, &mut SimulationRng::from_seed(1), 10, 1000);
+
+        let [DomainActionOutcome::LoanDefaulted {
+            collected_amount,
+            written_off_amount,
+            liquidated_business_uuids,
+            ..
+        }] = &notifications[..]
+        else {
+            panic!("Expected LoanDefaulted, got {notifications:?}");
+        };
+        assert_eq!(*collected_amount, 4_000);
+        assert_eq!(*written_off_amount, 6_000);
+        assert_eq!(liquidated_business_uuids, &vec![setup.business_uuid]);
+
+        assert!(state.ref_loan(&setup.loan.uuid).is_none());
+        assert_eq!(
+            state
+                .ref_business(&setup.business_uuid)
+                .unwrap()
+                .owning_corporation_uuid,
+            None
+        );
+        let listing = state.ref_business_listing(&listing.uuid).unwrap();
+        assert_eq!(listing.seller_corporation_uuid, None);
+        assert_eq!(listing.asking_price, 7_000);
+        assert!(
+            state.business_listing_uuids_by_corporation_uuid[&setup.corporation_uuid].is_empty()
+        );
+    }
+}
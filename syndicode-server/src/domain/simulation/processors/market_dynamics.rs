@@ -0,0 +1,177 @@
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::{
+    config::MarketConfig,
+    domain::{
+        economy::market::model::MarketSnapshot,
+        simulation::{game_state::GameState, rng::SimulationRng},
+    },
+};
+
+/// Moves the volume of every market towards a target driven by what corporations invest in it,
+/// then lets random shocks hit single markets. Runs after the business income, so the new
+/// volumes pay out from the next tick on. Every market is recorded as a snapshot of the tick.
+pub fn evolve_market_volumes(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+    config: &MarketConfig,
+) {
+    let mut market_uuids: Vec<Uuid> = state.markets_map.keys().copied().collect();
+
+    // Shocks are drawn per market, so the order matters for determinism
+    market_uuids.sort();
+
+    for market_uuid in market_uuids {
+        let mut invested_operational_expenses: i64 = 0;
+        let mut active_businesses: i64 = 0;
+
+        for business_uuid in state
+            .business_uuids_by_market_uuid
+            .get(&market_uuid)
+            .into_iter()
+            .flatten()
+        {
+            let Some(business) = state.businesses_map.get(business_uuid) else {
+                tracing::error!("Failed to retrieve business with UUID '{}'", business_uuid);
+                continue;
+            };
+
+            // Businesses without an owner are not operated by anyone
+            if business.owning_corporation_uuid.is_some() {
+                invested_operational_expenses += business.operational_expenses;
+                active_businesses += 1;
+            }
+        }
+
+        let target_volume = config.base_volume as f64
+            + config.investment_weight * invested_operational_expenses as f64
+            + (config.business_weight * active_businesses) as f64;
+
+        let Some(market) = state.markets_map.get_mut(&market_uuid) else {
+            continue;
+        };
+
+        let mut volume = market.volume as f64;
+        volume += (target_volume - volume) * config.adjustment_rate.clamp(0., 1.);
+
+        let max_shock = config.max_shock.abs();
+        if max_shock > 0. && rng.random_bool(config.shock_probability.clamp(0., 1.)) {
+            volume *= 1. + rng.random_range(-max_shock..=max_shock);
+        }
+
+        market.volume = (volume.round() as i64).clamp(config.min_volume, config.max_volume);
+
+        let snapshot = MarketSnapshot::builder()
+            .game_tick(next_game_tick)
+            .market_uuid(market_uuid)
+            .market_name(market.name)
+            .volume(market.volume)
+            .invested_operational_expenses(invested_operational_expenses)
+            .active_businesses(active_businesses)
+            .build();
+        state.market_snapshots.push(snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::{
+        business::model::Business,
+        market::model::{name::MarketName, Market},
+    };
+
+    fn business(market_uuid: Uuid, owner: Option<Uuid>, operational_expenses: i64) -> Business {
+        Business {
+            uuid: Uuid::now_v7(),
+            market_uuid,
+            owning_corporation_uuid: owner,
+            name: "Test Business".to_string(),
+            operational_expenses,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        }
+    }
+
+    #[test]
+    fn should_grow_invested_markets_and_shrink_idle_ones() {
+        let invested_market = Market {
+            uuid: Uuid::now_v7(),
+            name: MarketName::CyberbrainInterfaces,
+            volume: 1000,
+        };
+        let idle_market = Market {
+            uuid: Uuid::now_v7(),
+            name: MarketName::OrganFabrications,
+            volume: 5000,
+        };
+        let owner = Uuid::now_v7();
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![])
+            .markets_vec(vec![invested_market.clone(), idle_market.clone()])
+            .businesses_vec(vec![
+                business(invested_market.uuid, Some(owner), 2000),
+                business(invested_market.uuid, None, 3000),
+                business(idle_market.uuid, None, 0),
+            ])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+        let config = MarketConfig {
+            shock_probability: 0.,
+            ..Default::default()
+        };
+
+        evolve_market_volumes(&mut state, &mut SimulationRng::from_seed(1), 1, &config);
+
+        // Target 1000 + 0.5 * 2000 + 50 * 1 = 2050, a tenth of the gap is closed
+        assert_eq!(state.markets_map[&invested_market.uuid].volume, 1105);
+        // Target 1000, a tenth of the gap is closed
+        assert_eq!(state.markets_map[&idle_market.uuid].volume, 4600);
+
+        assert_eq!(state.market_snapshots.len(), 2);
+        let snapshot = state
+            .market_snapshots
+            .iter()
+            .find(|s| s.market_uuid == invested_market.uuid)
+            .unwrap();
+        assert_eq!(snapshot.game_tick, 1);
+        assert_eq!(snapshot.invested_operational_expenses, 2000);
+        assert_eq!(snapshot.active_businesses, 1);
+    }
+
+    #[test]
+    fn should_keep_shocked_volumes_within_bounds() {
+        let market = Market {
+            uuid: Uuid::now_v7(),
+            name: MarketName::BarrierSecurity,
+            volume: 150,
+        };
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![])
+            .markets_vec(vec![market.clone()])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+        let config = MarketConfig {
+            base_volume: 0,
+            adjustment_rate: 1.,
+            shock_probability: 1.,
+            ..Default::default()
+        };
+
+        evolve_market_volumes(&mut state, &mut SimulationRng::from_seed(1), 1, &config);
+
+        assert_eq!(state.markets_map[&market.uuid].volume, config.min_volume);
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::{
+    config::MarketMakerConfig,
+    domain::{
+        economy::{business::model::Business, business_listing::model::BusinessListing},
+        simulation::{game_state::GameState, rng::SimulationRng},
+    },
+};
+
+/// Acts as the seller of last resort. Every `interval_ticks` it lists the businesses nobody owns
+/// at their fair price and lowers the asking price of its listings that did not sell, down to a
+/// floor relative to the fair price. High-value businesses are auctioned with that floor as the
+/// reserve price instead, so they don't go to whoever happens to act first.
+pub fn make_market(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+    config: &MarketMakerConfig,
+) {
+    if config.interval_ticks <= 0 || next_game_tick % config.interval_ticks != 0 {
+        return;
+    }
+
+    let listing_uuid_by_business_uuid: HashMap<Uuid, Uuid> = state
+        .business_listings_map
+        .values()
+        .map(|listing| (listing.business_uuid, listing.uuid))
+        .collect();
+
+    let mut business_uuids: Vec<Uuid> = state
+        .businesses_map
+        .values()
+        .filter(|business| business.owning_corporation_uuid.is_none())
+        .map(|business| business.uuid)
+        .collect();
+
+    // New listings draw their UUID from the rng, so the order matters for determinism
+    business_uuids.sort();
+
+    for business_uuid in business_uuids {
+        let Some(business) = state.businesses_map.get(&business_uuid) else {
+            continue;
+        };
+        let fair_price = fair_price(state, business, config);
+        let min_price = (fair_price as f64 * config.min_price_ratio.clamp(0., 1.)).round() as i64;
+
+        let Some(listing_uuid) = listing_uuid_by_business_uuid.get(&business_uuid) else {
+            let is_auction = config.auction_min_price > 0 && fair_price >= config.auction_min_price;
+            let listing = if is_auction {
+                BusinessListing::builder()
+                    .uuid(rng.next_uuid())
+                    .business_uuid(business_uuid)
+                    .asking_price(min_price)
+                    .auction_closes_at_tick(next_game_tick + config.auction_duration_ticks)
+                    .build()
+            } else {
+                BusinessListing::builder()
+                    .uuid(rng.next_uuid())
+                    .business_uuid(business_uuid)
+                    .asking_price(fair_price)
+                    .build()
+            };
+            state.add_business_listing(listing);
+            continue;
+        };
+
+        let Some(listing) = state.ref_mut_business_listing(listing_uuid) else {
+            continue;
+        };
+
+        // Only fixed-price listings of the market maker itself are repriced
+        if listing.seller_corporation_uuid.is_some() || listing.is_auction() {
+            continue;
+        }
+
+        let decayed_price =
+            (listing.asking_price as f64 * (1. - config.price_decay.clamp(0., 1.))).round() as i64;
+        let asking_price = decayed_price.max(min_price);
+
+        // Unsold listings only ever get cheaper
+        if asking_price < listing.asking_price {
+            listing.asking_price = asking_price;
+        }
+    }
+}
+
+/// Values a business by its share of the market volume and the volume of its buildings.
+fn fair_price(state: &GameState, business: &Business, config: &MarketMakerConfig) -> i64 {
+    let total_expenses = state
+        .total_operation_expenses_by_market_uuid
+        .get(&business.market_uuid)
+        .copied()
+        .unwrap_or_default();
+
+    let market_share = if total_expenses > 0 {
+        business.operational_expenses as f64 / total_expenses as f64
+    } else {
+        // Nobody invests in the market, so every business gets an even share
+        let business_count = state
+            .business_uuids_by_market_uuid
+            .get(&business.market_uuid)
+            .map_or(0, Vec::len);
+        if business_count > 0 {
+            1. / business_count as f64
+        } else {
+            0.
+        }
+    };
+
+    let market_volume = state
+        .markets_map
+        .get(&business.market_uuid)
+        .map_or(0, |market| market.volume);
+    let building_volume = state
+        .building_volume_by_business_uuid
+        .get(&business.uuid)
+        .copied()
+        .unwrap_or_default();
+
+    (config.base_price as f64
+        + market_volume as f64 * market_share * config.valuation_ticks as f64
+        + building_volume as f64 * config.price_per_building_volume)
+        .round() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::{
+        building::model::BuildingLocation,
+        building_ownership::model::BuildingOwnership,
+        market::model::{name::MarketName, Market},
+    };
+
+    struct Setup {
+        orphaned_business_uuid: Uuid,
+        owned_business_uuid: Uuid,
+        state: GameState,
+    }
+
+    fn setup() -> Setup {
+        let market = Market {
+            uuid: Uuid::now_v7(),
+            name: MarketName::MemoryExperiences,
+            volume: 1000,
+        };
+        let building = BuildingLocation {
+            uuid: Uuid::now_v7(),
+            gml_id: "gml_orphan".to_string(),
+            longitude: 11.57,
+            latitude: 48.13,
+            volume: 20_000.,
+            usage_code: None,
+            city_code: None,
+        };
+        let orphaned_business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: market.uuid,
+            owning_corporation_uuid: None,
+            name: "Orphaned Biz".to_string(),
+            operational_expenses: 100,
+            headquarter_building_uuid: building.uuid,
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        };
+        let owned_business = Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: market.uuid,
+            owning_corporation_uuid: Some(Uuid::now_v7()),
+            name: "Owned Biz".to_string(),
+            operational_expenses: 300,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 2,
+            operational_expenses_changed_at_tick: None,
+        };
+        let ownership = BuildingOwnership {
+            building_uuid: building.uuid,
+            owning_business_uuid: orphaned_business.uuid,
+        };
+        let orphaned_business_uuid = orphaned_business.uuid;
+        let owned_business_uuid = owned_business.uuid;
+
+        let state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![])
+            .markets_vec(vec![market])
+            .businesses_vec(vec![orphaned_business, owned_business])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![ownership])
+            .building_locations_vec(vec![building])
+            .call();
+
+        Setup {
+            orphaned_business_uuid,
+            owned_business_uuid,
+            state,
+        }
+    }
+
+    fn listing_of(state: &GameState, business_uuid: Uuid) -> Option<BusinessListing> {
+        state
+            .business_listings_map
+            .values()
+            .find(|listing| listing.business_uuid == business_uuid)
+            .copied()
+    }
+
+    #[test]
+    fn should_list_unowned_businesses_at_their_fair_price() {
+        let Setup {
+            orphaned_business_uuid,
+            owned_business_uuid,
+            mut state,
+        } = setup();
+        let config = MarketMakerConfig::default();
+
+        make_market(&mut state, &mut SimulationRng::from_seed(1), 10, &config);
+
+        // 500 + 1000 * 0.25 * 100 + 20_000 * 0.01
+        let listing = listing_of(&state, orphaned_business_uuid).unwrap();
+        assert_eq!(listing.asking_price, 25_700);
+        assert_eq!(listing.seller_corporation_uuid, None);
+        assert!(listing_of(&state, owned_business_uuid).is_none());
+    }
+
+    #[test]
+    fn should_auction_high_value_businesses_at_the_floor_price() {
+        let Setup {
+            orphaned_business_uuid,
+            mut state,
+            ..
+        } = setup();
+        let config = MarketMakerConfig {
+            auction_min_price: 20_000,
+            ..Default::default()
+        };
+
+        make_market(&mut state, &mut SimulationRng::from_seed(1), 10, &config);
+        make_market(&mut state, &mut SimulationRng::from_seed(1), 20, &config);
+
+        // A quarter of the fair price of 25_700, never decayed
+        let listing = listing_of(&state, orphaned_business_uuid).unwrap();
+        assert_eq!(listing.asking_price, 6425);
+        assert_eq!(listing.auction_closes_at_tick, Some(30));
+    }
+
+    #[test]
+    fn should_only_run_on_interval_ticks() {
+        let Setup {
+            orphaned_business_uuid,
+            mut state,
+            ..
+        } = setup();
+        let config = MarketMakerConfig::default();
+
+        make_market(&mut state, &mut SimulationRng::from_seed(1), 11, &config);
+
+        assert!(listing_of(&state, orphaned_business_uuid).is_none());
+    }
+
+    #[test]
+    fn should_decay_unsold_listings_down_to_the_floor() {
+        let Setup {
+            orphaned_business_uuid,
+            mut state,
+            ..
+        } = setup();
+        let config = MarketMakerConfig {
+            price_decay: 0.5,
+            ..Default::default()
+        };
+        let mut rng = SimulationRng::from_seed(1);
+
+        make_market(&mut state, &mut rng, 10, &config);
+        make_market(&mut state, &mut rng, 20, &config);
+        assert_eq!(
+            listing_of(&state, orphaned_business_uuid)
+                .unwrap()
+                .asking_price,
+            12_850
+        );
+
+        make_market(&mut state, &mut rng, 30, &config);
+        make_market(&mut state, &mut rng, 40, &config);
+        // A quarter of the fair price of 25_700
+        assert_eq!(
+            listing_of(&state, orphaned_business_uuid)
+                .unwrap()
+                .asking_price,
+            6425
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::{
+    config::TerritoryConfig,
+    domain::{
+        economy::ledger::model::entry_kind::LedgerEntryKind, simulation::game_state::GameState,
+    },
+};
+
+/// Lets the corporation controlling a ward collect a tax on the income its rivals earned this
+/// tick with businesses headquartered in the ward. Runs after the business income has been paid
+/// out and taxes the income entries of the tick in the order they were recorded.
+pub fn collect_territory_tax(state: &mut GameState, next_game_tick: i64, config: &TerritoryConfig) {
+    if config.tax_percent <= 0 {
+        return;
+    }
+
+    let controller_by_city_code: HashMap<String, Uuid> = state
+        .territories(config.control_threshold_bps)
+        .into_iter()
+        .filter_map(|territory| {
+            territory
+                .controlling_corporation_uuid
+                .map(|corporation_uuid| (territory.city_code, corporation_uuid))
+        })
+        .collect();
+
+    if controller_by_city_code.is_empty() {
+        return;
+    }
+
+    let taxable_incomes: Vec<(Uuid, Uuid, i64)> = state
+        .ledger_entries
+        .iter()
+        .filter(|entry| {
+            entry.game_tick == next_game_tick
+                && entry.kind == LedgerEntryKind::BusinessIncome
+                && entry.amount > 0
+        })
+        .filter_map(|entry| Some((entry.corporation_uuid, entry.business_uuid?, entry.amount)))
+        .collect();
+
+    for (payer_corporation_uuid, business_uuid, income) in taxable_incomes {
+        let Some(controller_corporation_uuid) = state
+            .ref_business(&business_uuid)
+            .and_then(|business| state.ref_building_location(&business.headquarter_building_uuid))
+            .and_then(|location| location.city_code.as_ref())
+            .and_then(|city_code| controller_by_city_code.get(city_code))
+            .copied()
+        else {
+            continue;
+        };
+
+        // A corporation doesn't tax itself
+        if controller_corporation_uuid == payer_corporation_uuid {
+            continue;
+        }
+
+        let tax = income * config.tax_percent / 100;
+        if tax <= 0 {
+            continue;
+        }
+
+        let Some(payer) = state.ref_mut_corporation(&payer_corporation_uuid) else {
+            tracing::error!(
+                "Failed to retrieve taxed corporation with UUID '{}' for mutation",
+                payer_corporation_uuid
+            );
+            continue;
+        };
+        payer.cash_balance -= tax;
+
+        let Some(controller) = state.ref_mut_corporation(&controller_corporation_uuid) else {
+            tracing::error!(
+                "Failed to retrieve controlling corporation with UUID '{}' for mutation",
+                controller_corporation_uuid
+            );
+            continue;
+        };
+        controller.cash_balance += tax;
+
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(payer_corporation_uuid)
+            .kind(LedgerEntryKind::TerritoryTax)
+            .amount(-tax)
+            .business_uuid(business_uuid)
+            .call();
+        state
+            .record_ledger_entry()
+            .game_tick(next_game_tick)
+            .corporation_uuid(controller_corporation_uuid)
+            .kind(LedgerEntryKind::TerritoryTax)
+            .amount(tax)
+            .business_uuid(business_uuid)
+            .call();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::diplomacy::model::{treaty_type::TreatyType, Treaty};
+    use crate::domain::economy::{
+        building::model::BuildingLocation,
+        building_ownership::model::BuildingOwnership,
+        business::model::Business,
+        corporation::model::{name::CorporationName, Corporation},
+    };
+
+    const WARD: &str = "13104";
+
+    fn corporation(name: &str) -> Corporation {
+        Corporation {
+            uuid: Uuid::now_v7(),
+            user_uuid: Uuid::now_v7(),
+            name: CorporationName::unchecked(name.to_string()),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        }
+    }
+
+    fn location(volume: f64) -> BuildingLocation {
+        BuildingLocation {
+            uuid: Uuid::now_v7(),
+            gml_id: Uuid::now_v7().to_string(),
+            longitude: 139.7,
+            latitude: 35.69,
+            volume,
+            usage_code: None,
+            city_code: Some(WARD.to_string()),
+        }
+    }
+
+    fn business(owner: &Corporation, headquarter: &BuildingLocation) -> Business {
+        Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(owner.uuid),
+            name: "Ward Biz".to_string(),
+            operational_expenses: 0,
+            headquarter_building_uuid: headquarter.uuid,
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        }
+    }
+
+    /// A landlord controlling the ward and a tenant, both with an income of 200 in tick 1.
+    fn setup_ward() -> (GameState, Corporation, Corporation) {
+        let landlord = corporation("Landlord Corp");
+        let tenant = corporation("Tenant Corp");
+        let landlord_headquarter = location(30_000.0);
+        let tenant_headquarter = location(10_000.0);
+        let landlord_business = business(&landlord, &landlord_headquarter);
+        let tenant_business = business(&tenant, &tenant_headquarter);
+        let ownerships = vec![
+            BuildingOwnership {
+                building_uuid: landlord_headquarter.uuid,
+                owning_business_uuid: landlord_business.uuid,
+            },
+            BuildingOwnership {
+                building_uuid: tenant_headquarter.uuid,
+                owning_business_uuid: tenant_business.uuid,
+            },
+        ];
+
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![landlord.clone(), tenant.clone()])
+            .markets_vec(vec![])
+            .businesses_vec(vec![landlord_business.clone(), tenant_business.clone()])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(ownerships)
+            .building_locations_vec(vec![landlord_headquarter, tenant_headquarter])
+            .call();
+
+        for (corporation_uuid, business_uuid) in [
+            (landlord.uuid, landlord_business.uuid),
+            (tenant.uuid, tenant_business.uuid),
+        ] {
+            state
+                .record_ledger_entry()
+                .game_tick(1)
+                .corporation_uuid(corporation_uuid)
+                .kind(LedgerEntryKind::BusinessIncome)
+                .amount(200)
+                .business_uuid(business_uuid)
+                .call();
+        }
+
+        (state, landlord, tenant)
+    }
+
+    #[test]
+    fn should_tax_rivals_in_a_controlled_ward() {
+        let (mut state, landlord, tenant) = setup_ward();
+
+        collect_territory_tax(&mut state, 1, &TerritoryConfig::default());
+
+        let cash_balance = |uuid: &Uuid| state.ref_corporation(uuid).unwrap().cash_balance;
+        assert_eq!(cash_balance(&landlord.uuid), 1010);
+        assert_eq!(cash_balance(&tenant.uuid), 990);
+        assert_eq!(
+            state
+                .ledger_entries
+                .iter()
+                .filter(|entry| entry.kind == LedgerEntryKind::TerritoryTax)
+                .map(|entry| (entry.corporation_uuid, entry.amount))
+                .collect::<Vec<_>>(),
+            vec![(tenant.uuid, -10), (landlord.uuid, 10)]
+        );
+    }
+
+    #[test]
+    fn should_tax_trade_pact_members() {
+        let (mut state, landlord, tenant) = setup_ward();
+        state.add_treaty(Treaty {
+            uuid: Uuid::now_v7(),
+            treaty_type: TreatyType::TradePact,
+            proposer_corporation_uuid: tenant.uuid,
+            partner_corporation_uuid: landlord.uuid,
+            proposed_at_tick: 0,
+            accepted_at_tick: Some(0),
+        });
+
+        collect_territory_tax(&mut state, 1, &TerritoryConfig::default());
+
+        let cash_balance = |uuid: &Uuid| state.ref_corporation(uuid).unwrap().cash_balance;
+        assert_eq!(cash_balance(&landlord.uuid), 1010);
+        assert_eq!(cash_balance(&tenant.uuid), 990);
+    }
+}
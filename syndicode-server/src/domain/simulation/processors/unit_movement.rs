@@ -0,0 +1,147 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    outcome::DomainActionOutcome,
+    simulation::{game_state::GameState, rng::SimulationRng},
+};
+
+/// Moves all units whose arrival tick has been reached to their destination
+/// and notifies the owning corporations.
+pub fn advance_unit_movements(
+    state: &mut GameState,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    let mut arrived_unit_uuids: Vec<Uuid> = state
+        .units_map
+        .values()
+        .filter(|unit| {
+            unit.arrival_tick
+                .is_some_and(|arrival_tick| arrival_tick <= next_game_tick)
+        })
+        .map(|unit| unit.uuid)
+        .collect();
+
+    // Process in a stable order to keep the simulation deterministic
+    arrived_unit_uuids.sort();
+
+    let mut notifications = Vec::with_capacity(arrived_unit_uuids.len());
+
+    for unit_uuid in arrived_unit_uuids {
+        let Some(unit) = state.ref_mut_unit(&unit_uuid) else {
+            continue;
+        };
+        let Some(destination_building_uuid) = unit.destination_building_uuid.take() else {
+            unit.arrival_tick = None;
+            continue;
+        };
+
+        unit.building_uuid = destination_building_uuid;
+        unit.arrival_tick = None;
+        let unit = *unit;
+
+        let Some(user_uuid) = state
+            .ref_corporation(&unit.corporation_uuid)
+            .map(|corporation| corporation.user_uuid)
+        else {
+            tracing::warn!(
+                "Owning corporation '{}' of unit '{}' not found",
+                unit.corporation_uuid,
+                unit.uuid
+            );
+            continue;
+        };
+
+        let building_gml_id = state
+            .ref_building_location(&destination_building_uuid)
+            .map(|location| location.gml_id.clone())
+            .unwrap_or_default();
+        let attack_target_building_gml_id = unit
+            .attack_target_building_uuid
+            .and_then(|building_uuid| state.ref_building_location(&building_uuid))
+            .map(|location| location.gml_id.clone());
+
+        notifications.push(DomainActionOutcome::UnitArrived {
+            request_uuid: rng.next_uuid(),
+            tick_effective: next_game_tick,
+            req_user_uuid: user_uuid,
+            corporation_uuid: unit.corporation_uuid,
+            unit_uuid: unit.uuid,
+            unit_type: unit.unit_type,
+            health: unit.health,
+            attack: unit.attack,
+            defense: unit.defense,
+            building_gml_id,
+            attack_target_building_gml_id,
+        });
+    }
+
+    notifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::economy::corporation::model::{name::CorporationName, Corporation};
+    use crate::domain::unit::model::{unit_type::UnitType, Unit};
+
+    #[test]
+    fn should_only_move_units_that_have_arrived() {
+        let user_uuid = Uuid::now_v7();
+        let corporation_uuid = Uuid::now_v7();
+        let origin_building_uuid = Uuid::now_v7();
+        let destination_building_uuid = Uuid::now_v7();
+
+        let corporation = Corporation {
+            uuid: corporation_uuid,
+            user_uuid,
+            name: CorporationName::new("Unit Corp".to_string()).unwrap(),
+            cash_balance: 1000,
+            insolvent_since_tick: None,
+        };
+        let arriving_unit = Unit {
+            destination_building_uuid: Some(destination_building_uuid),
+            arrival_tick: Some(10),
+            ..Unit::spawn(
+                Uuid::now_v7(),
+                corporation_uuid,
+                UnitType::Drone,
+                origin_building_uuid,
+            )
+        };
+        let travelling_unit = Unit {
+            uuid: Uuid::now_v7(),
+            arrival_tick: Some(11),
+            ..arriving_unit
+        };
+
+        let mut state = GameState::build()
+            .last_processed_tick(9)
+            .units_vec(vec![arriving_unit, travelling_unit])
+            .corporations_vec(vec![corporation])
+            .markets_vec(vec![])
+            .businesses_vec(vec![])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call();
+
+        let notifications =
+            advance_unit_movements(&mut state, &mut SimulationRng::from_seed(0), 10);
+
+        assert!(matches!(
+            notifications.as_slice(),
+            [DomainActionOutcome::UnitArrived { req_user_uuid, unit_uuid, .. }]
+                if *req_user_uuid == user_uuid && *unit_uuid == arriving_unit.uuid
+        ));
+
+        let arrived = state.ref_unit(&arriving_unit.uuid).unwrap();
+        assert_eq!(arrived.building_uuid, destination_building_uuid);
+        assert!(!arrived.is_moving());
+        assert_eq!(arrived.arrival_tick, None);
+
+        let travelling = state.ref_unit(&travelling_unit.uuid).unwrap();
+        assert_eq!(travelling.building_uuid, origin_building_uuid);
+        assert!(travelling.is_moving());
+    }
+}
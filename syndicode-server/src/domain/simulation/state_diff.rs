@@ -0,0 +1,595 @@
+use super::{changes::StateChanges, game_state::GameState, rng::SimulationRng};
+use crate::domain::{
+    economy::{
+        building_ownership::model::BuildingOwnership, business::model::Business,
+        business_listing::model::BusinessListing,
+    },
+    outcome::{ChangedUnit, DomainActionOutcome},
+    unit::model::Unit,
+    world_event::model::WorldEvent,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use uuid::Uuid;
+
+/// Owners of the entities players are told about, as they were last persisted.
+/// Kept up to date with the written changes, so the diffs of a tick only have to look at the
+/// entities that changed within it.
+#[derive(Default)]
+pub(super) struct PersistedOwners {
+    business_owners: HashMap<Uuid, Uuid>,
+    /// Seller and business of each listing.
+    listings: HashMap<Uuid, (Option<Uuid>, Uuid)>,
+    unit_owners: HashMap<Uuid, Uuid>,
+    /// Business each building belongs to.
+    building_businesses: HashMap<Uuid, Uuid>,
+}
+
+impl PersistedOwners {
+    pub(super) fn new(
+        businesses_map: &HashMap<Uuid, Business>,
+        business_listings_map: &HashMap<Uuid, BusinessListing>,
+        units_map: &HashMap<Uuid, Unit>,
+        building_ownerships_map: &HashMap<Uuid, BuildingOwnership>,
+    ) -> Self {
+        let mut owners = Self::default();
+        owners.insert_businesses(businesses_map.values());
+        owners.insert_listings(business_listings_map.values());
+        owners.insert_units(units_map.values());
+        owners.insert_building_ownerships(building_ownerships_map.values());
+        owners
+    }
+
+    /// Records that the given changes have been written.
+    pub(super) fn apply(&mut self, changes: &StateChanges) {
+        for business_uuid in &changes.businesses.removed {
+            self.business_owners.remove(business_uuid);
+        }
+        self.insert_businesses(&changes.businesses.upserted);
+        for listing_uuid in &changes.business_listings.removed {
+            self.listings.remove(listing_uuid);
+        }
+        self.insert_listings(&changes.business_listings.upserted);
+        for unit_uuid in &changes.units.removed {
+            self.unit_owners.remove(unit_uuid);
+        }
+        self.insert_units(&changes.units.upserted);
+        for building_uuid in &changes.building_ownerships.removed {
+            self.building_businesses.remove(building_uuid);
+        }
+        self.insert_building_ownerships(&changes.building_ownerships.upserted);
+    }
+
+    fn insert_businesses<'a>(&mut self, businesses: impl IntoIterator<Item = &'a Business>) {
+        for business in businesses {
+            match business.owning_corporation_uuid {
+                Some(corporation_uuid) => {
+                    self.business_owners.insert(business.uuid, corporation_uuid)
+                }
+                None => self.business_owners.remove(&business.uuid),
+            };
+        }
+    }
+
+    fn insert_listings<'a>(&mut self, listings: impl IntoIterator<Item = &'a BusinessListing>) {
+        for listing in listings {
+            self.listings.insert(
+                listing.uuid,
+                (listing.seller_corporation_uuid, listing.business_uuid),
+            );
+        }
+    }
+
+    fn insert_units<'a>(&mut self, units: impl IntoIterator<Item = &'a Unit>) {
+        for unit in units {
+            self.unit_owners.insert(unit.uuid, unit.corporation_uuid);
+        }
+    }
+
+    fn insert_building_ownerships<'a>(
+        &mut self,
+        ownerships: impl IntoIterator<Item = &'a BuildingOwnership>,
+    ) {
+        for ownership in ownerships {
+            self.building_businesses
+                .insert(ownership.building_uuid, ownership.owning_business_uuid);
+        }
+    }
+
+    fn building_owner(&self, building_uuid: &Uuid) -> Option<Uuid> {
+        let business_uuid = self.building_businesses.get(building_uuid)?;
+        self.business_owners.get(business_uuid).copied()
+    }
+}
+
+#[derive(Default)]
+struct CorporationDiff {
+    businesses_gained: Vec<Uuid>,
+    businesses_lost: Vec<Uuid>,
+    listings_sold: Vec<Uuid>,
+    units_updated: Vec<ChangedUnit>,
+    units_removed: Vec<Uuid>,
+}
+
+/// Collects what has changed for each corporation during the tick, one outcome per corporation.
+/// Corporations without changes and dissolved corporations are left out. Changes of the cash
+/// balance are left to the ledger summary.
+///
+/// Has to be called before the changes are marked as persisted.
+pub fn corporation_state_diffs(
+    state: &GameState,
+    changes: &StateChanges,
+    rng: &mut SimulationRng,
+    next_game_tick: i64,
+) -> Vec<DomainActionOutcome> {
+    let persisted = &state.persisted_owners;
+    let mut diffs: BTreeMap<Uuid, CorporationDiff> = BTreeMap::new();
+
+    for business in &changes.businesses.upserted {
+        let previous_owner = persisted.business_owners.get(&business.uuid).copied();
+        if previous_owner == business.owning_corporation_uuid {
+            continue;
+        }
+        if let Some(corporation_uuid) = previous_owner {
+            diffs
+                .entry(corporation_uuid)
+                .or_default()
+                .businesses_lost
+                .push(business.uuid);
+        }
+        if let Some(corporation_uuid) = business.owning_corporation_uuid {
+            diffs
+                .entry(corporation_uuid)
+                .or_default()
+                .businesses_gained
+                .push(business.uuid);
+        }
+    }
+    for business_uuid in &changes.businesses.removed {
+        if let Some(corporation_uuid) = persisted.business_owners.get(business_uuid) {
+            diffs
+                .entry(*corporation_uuid)
+                .or_default()
+                .businesses_lost
+                .push(*business_uuid);
+        }
+    }
+
+    // A closed listing has been sold if another corporation owns the business now,
+    // otherwise it has been cancelled or the auction went without a winner
+    for listing_uuid in &changes.business_listings.removed {
+        let Some((Some(seller_corporation_uuid), business_uuid)) =
+            persisted.listings.get(listing_uuid)
+        else {
+            continue;
+        };
+        let sold = state.ref_business(business_uuid).is_some_and(|business| {
+            business
+                .owning_corporation_uuid
+                .is_some_and(|owner| owner != *seller_corporation_uuid)
+        });
+        if sold {
+            diffs
+                .entry(*seller_corporation_uuid)
+                .or_default()
+                .listings_sold
+                .push(*listing_uuid);
+        }
+    }
+
+    for unit in &changes.units.upserted {
+        diffs
+            .entry(unit.corporation_uuid)
+            .or_default()
+            .units_updated
+            .push(changed_unit(state, unit));
+    }
+    for unit_uuid in &changes.units.removed {
+        if let Some(corporation_uuid) = persisted.unit_owners.get(unit_uuid) {
+            diffs
+                .entry(*corporation_uuid)
+                .or_default()
+                .units_removed
+                .push(*unit_uuid);
+        }
+    }
+
+    diffs
+        .into_iter()
+        .filter_map(|(corporation_uuid, mut diff)| {
+            let corporation = state.ref_corporation(&corporation_uuid)?;
+
+            // The changes are collected from hash maps, sort them to keep the updates stable
+            diff.businesses_gained.sort();
+            diff.businesses_lost.sort();
+            diff.listings_sold.sort();
+            diff.units_updated.sort_by_key(|unit| unit.unit_uuid);
+            diff.units_removed.sort();
+
+            Some(DomainActionOutcome::CorporationStateChanged {
+                request_uuid: rng.next_uuid(),
+                tick_effective: next_game_tick,
+                req_user_uuid: corporation.user_uuid,
+                corporation_uuid,
+                businesses_gained: diff.businesses_gained,
+                businesses_lost: diff.businesses_lost,
+                listings_sold: diff.listings_sold,
+                units_updated: diff.units_updated,
+                units_removed: diff.units_removed,
+            })
+        })
+        .collect()
+}
+
+/// Collects the events of the tick every player may subscribe to: new listings and buildings
+/// that changed owner, either directly or along with the business they belong to.
+///
+/// Has to be called before the changes are marked as persisted.
+pub fn world_events(state: &GameState, changes: &StateChanges) -> Vec<WorldEvent> {
+    let persisted = &state.persisted_owners;
+    let mut events = Vec::new();
+
+    let mut new_listings: Vec<&BusinessListing> = changes
+        .business_listings
+        .upserted
+        .iter()
+        .filter(|listing| !persisted.listings.contains_key(&listing.uuid))
+        .collect();
+    new_listings.sort_by_key(|listing| listing.uuid);
+    for listing in new_listings {
+        let Some(business) = state.ref_business(&listing.business_uuid) else {
+            continue;
+        };
+        events.push(WorldEvent::BusinessListed {
+            listing_uuid: listing.uuid,
+            business_uuid: listing.business_uuid,
+            market_uuid: business.market_uuid,
+            seller_corporation_uuid: listing.seller_corporation_uuid,
+            asking_price: listing.asking_price,
+            auction_closes_at_tick: listing.auction_closes_at_tick,
+        });
+    }
+
+    // A building can only change its owner along with its ownership or its business
+    let mut building_uuids: BTreeSet<Uuid> = changes
+        .building_ownerships
+        .upserted
+        .iter()
+        .map(|ownership| ownership.building_uuid)
+        .chain(changes.building_ownerships.removed.iter().copied())
+        .collect();
+    for business in &changes.businesses.upserted {
+        if let Some(business_building_uuids) =
+            state.building_uuids_by_business_uuid.get(&business.uuid)
+        {
+            building_uuids.extend(business_building_uuids);
+        }
+    }
+    for building_uuid in building_uuids {
+        let previous_owner = persisted.building_owner(&building_uuid);
+        let owner = state.get_building_owner(&building_uuid);
+        if previous_owner == owner {
+            continue;
+        }
+        let Some(location) = state.ref_building_location(&building_uuid) else {
+            continue;
+        };
+        events.push(WorldEvent::BuildingOwnerChanged {
+            building_gml_id: location.gml_id.clone(),
+            longitude: location.longitude,
+            latitude: location.latitude,
+            previous_owner_corporation_uuid: previous_owner,
+            owning_corporation_uuid: owner,
+        });
+    }
+
+    events
+}
+
+fn changed_unit(state: &GameState, unit: &Unit) -> ChangedUnit {
+    let gml_id = |building_uuid: &Uuid| {
+        state
+            .ref_building_location(building_uuid)
+            .map(|location| location.gml_id.clone())
+    };
+
+    ChangedUnit {
+        unit_uuid: unit.uuid,
+        unit_type: unit.unit_type,
+        health: unit.health,
+        attack: unit.attack,
+        defense: unit.defense,
+        building_gml_id: gml_id(&unit.building_uuid).unwrap_or_default(),
+        destination_building_gml_id: unit.destination_building_uuid.as_ref().and_then(gml_id),
+        arrival_tick: unit.arrival_tick,
+        attack_target_building_gml_id: unit.attack_target_building_uuid.as_ref().and_then(gml_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        economy::{
+            building::model::BuildingLocation,
+            building_ownership::model::BuildingOwnership,
+            business::model::Business,
+            corporation::model::{name::CorporationName, Corporation},
+        },
+        unit::model::unit_type::UnitType,
+    };
+
+    fn corporation(name: &str) -> Corporation {
+        Corporation::new(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            CorporationName::new(name.to_string()).unwrap(),
+        )
+    }
+
+    fn business(owning_corporation_uuid: Uuid) -> Business {
+        Business {
+            uuid: Uuid::now_v7(),
+            market_uuid: Uuid::now_v7(),
+            owning_corporation_uuid: Some(owning_corporation_uuid),
+            name: "Corner Shop".to_string(),
+            operational_expenses: 10,
+            headquarter_building_uuid: Uuid::now_v7(),
+            image_number: 1,
+            operational_expenses_changed_at_tick: None,
+        }
+    }
+
+    fn state(
+        corporations: Vec<Corporation>,
+        businesses: Vec<Business>,
+        listings: Vec<BusinessListing>,
+        units: Vec<Unit>,
+    ) -> GameState {
+        GameState::build()
+            .last_processed_tick(0)
+            .units_vec(units)
+            .corporations_vec(corporations)
+            .markets_vec(vec![])
+            .businesses_vec(businesses)
+            .business_listings_vec(listings)
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![])
+            .call()
+    }
+
+    #[test]
+    fn should_report_a_sold_listing_to_both_corporations() {
+        let seller = corporation("Seller Corp");
+        let buyer = corporation("Buyer Corp");
+        let bystander = corporation("Bystander Corp");
+        let sold_business = business(seller.uuid);
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: sold_business.uuid,
+            seller_corporation_uuid: Some(seller.uuid),
+            asking_price: 300,
+            auction_closes_at_tick: None,
+        };
+        let (seller_uuid, buyer_uuid) = (seller.uuid, buyer.uuid);
+        let mut state = state(
+            vec![seller, buyer, bystander],
+            vec![sold_business.clone()],
+            vec![listing],
+            vec![],
+        );
+
+        state.business_listings_map.remove(&listing.uuid);
+        state
+            .businesses_map
+            .get_mut(&sold_business.uuid)
+            .unwrap()
+            .owning_corporation_uuid = Some(buyer_uuid);
+
+        let diffs = corporation_state_diffs(
+            &state,
+            &state.changes(),
+            &mut SimulationRng::from_seed(0),
+            1,
+        );
+
+        assert_eq!(diffs.len(), 2);
+        for diff in diffs {
+            let DomainActionOutcome::CorporationStateChanged {
+                corporation_uuid,
+                businesses_gained,
+                businesses_lost,
+                listings_sold,
+                ..
+            } = diff
+            else {
+                panic!("Expected CorporationStateChanged, got {:?}", diff);
+            };
+
+            if corporation_uuid == seller_uuid {
+                assert_eq!(businesses_lost, vec![sold_business.uuid]);
+                assert_eq!(listings_sold, vec![listing.uuid]);
+                assert!(businesses_gained.is_empty());
+            } else {
+                assert_eq!(corporation_uuid, buyer_uuid);
+                assert_eq!(businesses_gained, vec![sold_business.uuid]);
+                assert!(businesses_lost.is_empty());
+                assert!(listings_sold.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn should_diff_against_the_last_persisted_tick() {
+        let seller = corporation("Seller Corp");
+        let buyer = corporation("Buyer Corp");
+        let sold_business = business(seller.uuid);
+        let (seller_uuid, buyer_uuid) = (seller.uuid, buyer.uuid);
+        let mut state = state(
+            vec![seller, buyer],
+            vec![sold_business.clone()],
+            vec![],
+            vec![],
+        );
+        let diffs = |state: &GameState| {
+            corporation_state_diffs(state, &state.changes(), &mut SimulationRng::from_seed(0), 1)
+        };
+
+        state
+            .businesses_map
+            .get_mut(&sold_business.uuid)
+            .unwrap()
+            .owning_corporation_uuid = Some(buyer_uuid);
+        let first_diffs = diffs(&state);
+        assert_eq!(first_diffs.len(), 2);
+        // The ids are drawn from the generator, so a replayed tick pushes the same diffs
+        assert_eq!(
+            first_diffs
+                .iter()
+                .map(DomainActionOutcome::get_request_uuid)
+                .collect::<Vec<_>>(),
+            diffs(&state)
+                .iter()
+                .map(DomainActionOutcome::get_request_uuid)
+                .collect::<Vec<_>>()
+        );
+
+        state.mark_persisted(&state.changes());
+        assert!(diffs(&state).is_empty());
+
+        state
+            .businesses_map
+            .get_mut(&sold_business.uuid)
+            .unwrap()
+            .owning_corporation_uuid = Some(seller_uuid);
+        let second_diffs = diffs(&state);
+        let lost_by: Vec<Uuid> = second_diffs
+            .iter()
+            .filter_map(|diff| match diff {
+                DomainActionOutcome::CorporationStateChanged {
+                    corporation_uuid,
+                    businesses_lost,
+                    ..
+                } if !businesses_lost.is_empty() => Some(*corporation_uuid),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(lost_by, vec![buyer_uuid]);
+    }
+
+    #[test]
+    fn should_report_updated_and_removed_units() {
+        let owner = corporation("Owner Corp");
+        let moved_unit = Unit::spawn(
+            Uuid::now_v7(),
+            owner.uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        );
+        let destroyed_unit = Unit::spawn(
+            Uuid::now_v7(),
+            owner.uuid,
+            UnitType::Enforcer,
+            Uuid::now_v7(),
+        );
+        let owner_uuid = owner.uuid;
+        let mut state = state(
+            vec![owner],
+            vec![],
+            vec![],
+            vec![moved_unit, destroyed_unit],
+        );
+
+        state.units_map.get_mut(&moved_unit.uuid).unwrap().health -= 10;
+        state.units_map.remove(&destroyed_unit.uuid);
+
+        let diffs = corporation_state_diffs(
+            &state,
+            &state.changes(),
+            &mut SimulationRng::from_seed(0),
+            1,
+        );
+
+        let [DomainActionOutcome::CorporationStateChanged {
+            corporation_uuid,
+            units_updated,
+            units_removed,
+            ..
+        }] = diffs.as_slice()
+        else {
+            panic!("Expected a single CorporationStateChanged, got {:?}", diffs);
+        };
+        assert_eq!(*corporation_uuid, owner_uuid);
+        assert_eq!(units_updated.len(), 1);
+        assert_eq!(units_updated[0].unit_uuid, moved_unit.uuid);
+        assert_eq!(units_updated[0].health, moved_unit.health - 10);
+        assert_eq!(*units_removed, vec![destroyed_unit.uuid]);
+    }
+
+    #[test]
+    fn should_collect_new_listings_and_buildings_that_changed_owner() {
+        let seller = corporation("Seller Corp");
+        let buyer = corporation("Buyer Corp");
+        let sold_business = business(seller.uuid);
+        let location = BuildingLocation {
+            uuid: Uuid::now_v7(),
+            gml_id: "bldg_1".to_string(),
+            longitude: 139.75,
+            latitude: 35.65,
+            volume: 1_000.0,
+            usage_code: None,
+            city_code: None,
+        };
+        let (seller_uuid, buyer_uuid) = (seller.uuid, buyer.uuid);
+        let mut state = GameState::build()
+            .last_processed_tick(0)
+            .units_vec(vec![])
+            .corporations_vec(vec![seller, buyer])
+            .markets_vec(vec![])
+            .businesses_vec(vec![sold_business.clone()])
+            .business_listings_vec(vec![])
+            .business_offers_vec(vec![])
+            .building_ownerships_vec(vec![BuildingOwnership {
+                building_uuid: location.uuid,
+                owning_business_uuid: sold_business.uuid,
+            }])
+            .building_locations_vec(vec![location.clone()])
+            .call();
+
+        let listing = BusinessListing {
+            uuid: Uuid::now_v7(),
+            business_uuid: sold_business.uuid,
+            seller_corporation_uuid: Some(buyer_uuid),
+            asking_price: 500,
+            auction_closes_at_tick: None,
+        };
+        state.business_listings_map.insert(listing.uuid, listing);
+        state
+            .businesses_map
+            .get_mut(&sold_business.uuid)
+            .unwrap()
+            .owning_corporation_uuid = Some(buyer_uuid);
+
+        let events = world_events(&state, &state.changes());
+
+        assert_eq!(
+            events,
+            vec![
+                WorldEvent::BusinessListed {
+                    listing_uuid: listing.uuid,
+                    business_uuid: sold_business.uuid,
+                    market_uuid: sold_business.market_uuid,
+                    seller_corporation_uuid: Some(buyer_uuid),
+                    asking_price: 500,
+                    auction_closes_at_tick: None,
+                },
+                WorldEvent::BuildingOwnerChanged {
+                    building_gml_id: location.gml_id,
+                    longitude: location.longitude,
+                    latitude: location.latitude,
+                    previous_owner_corporation_uuid: Some(seller_uuid),
+                    owning_corporation_uuid: Some(buyer_uuid),
+                },
+            ]
+        );
+    }
+}
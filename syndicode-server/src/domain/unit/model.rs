@@ -1,8 +1,78 @@
-use sqlx::prelude::FromRow;
+pub mod unit_type;
+
+use bon::Builder;
+use unit_type::UnitType;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Copy, FromRow)]
+#[derive(Builder, Debug, Clone, Copy, PartialEq, Hash)]
 pub struct Unit {
     pub uuid: Uuid,
     pub corporation_uuid: Uuid,
+    pub unit_type: UnitType,
+    pub health: i32,
+    pub attack: i32,
+    pub defense: i32,
+    /// The building the unit is stationed at, or departed from while it is moving.
+    pub building_uuid: Uuid,
+    pub destination_building_uuid: Option<Uuid>,
+    pub arrival_tick: Option<i64>,
+    /// The building the unit has been committed to attack.
+    pub attack_target_building_uuid: Option<Uuid>,
+}
+
+impl Unit {
+    /// Creates a fresh unit with the base stats of its type.
+    pub fn spawn(
+        uuid: Uuid,
+        corporation_uuid: Uuid,
+        unit_type: UnitType,
+        building_uuid: Uuid,
+    ) -> Self {
+        Self {
+            uuid,
+            corporation_uuid,
+            unit_type,
+            health: unit_type.base_health(),
+            attack: unit_type.base_attack(),
+            defense: unit_type.base_defense(),
+            building_uuid,
+            destination_building_uuid: None,
+            arrival_tick: None,
+            attack_target_building_uuid: None,
+        }
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.destination_building_uuid.is_some()
+    }
+
+    /// Whether the unit has arrived at the building it has been committed to attack.
+    pub fn is_attacking(&self, building_uuid: &Uuid) -> bool {
+        self.health > 0
+            && !self.is_moving()
+            && self.building_uuid == *building_uuid
+            && self.attack_target_building_uuid == Some(*building_uuid)
+    }
+}
+
+/// A unit together with the GML IDs of the buildings it is related to.
+#[derive(Debug, Clone)]
+pub struct UnitDetails {
+    pub unit: Unit,
+    pub building_gml_id: String,
+    pub destination_building_gml_id: Option<String>,
+    pub attack_target_building_gml_id: Option<String>,
+    /// City code of the ward of the building the unit is stationed at.
+    pub city_code: Option<String>,
+}
+
+impl UnitDetails {
+    /// Hides where a rival unit is headed and what it has been committed to attack.
+    pub fn redact_orders(&mut self) {
+        self.unit.destination_building_uuid = None;
+        self.unit.arrival_tick = None;
+        self.unit.attack_target_building_uuid = None;
+        self.destination_building_gml_id = None;
+        self.attack_target_building_gml_id = None;
+    }
 }
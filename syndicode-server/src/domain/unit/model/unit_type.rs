@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(i16)]
+pub enum UnitType {
+    Enforcer,
+    Drone,
+    Mech,
+}
+
+impl UnitType {
+    pub fn base_health(&self) -> i32 {
+        match self {
+            UnitType::Enforcer => 100,
+            UnitType::Drone => 40,
+            UnitType::Mech => 300,
+        }
+    }
+
+    pub fn base_attack(&self) -> i32 {
+        match self {
+            UnitType::Enforcer => 12,
+            UnitType::Drone => 6,
+            UnitType::Mech => 30,
+        }
+    }
+
+    pub fn base_defense(&self) -> i32 {
+        match self {
+            UnitType::Enforcer => 10,
+            UnitType::Drone => 4,
+            UnitType::Mech => 25,
+        }
+    }
+
+    /// Distance in meters the unit covers within a single tick.
+    pub fn speed_meters_per_tick(&self) -> f64 {
+        match self {
+            UnitType::Enforcer => 250.0,
+            UnitType::Drone => 800.0,
+            UnitType::Mech => 120.0,
+        }
+    }
+
+    /// Number of ticks needed to travel the given distance. Every journey takes at least one tick.
+    pub fn travel_ticks(&self, distance_meters: f64) -> i64 {
+        (distance_meters / self.speed_meters_per_tick())
+            .ceil()
+            .max(1.0) as i64
+    }
+
+    /// Cash deducted from the corporation when spawning a unit of this type.
+    pub fn spawn_cost(&self) -> i64 {
+        match self {
+            UnitType::Enforcer => 25_000,
+            UnitType::Drone => 10_000,
+            UnitType::Mech => 120_000,
+        }
+    }
+}
+
+impl TryFrom<i16> for UnitType {
+    type Error = String;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Enforcer),
+            2 => Ok(Self::Drone),
+            3 => Ok(Self::Mech),
+            _ => Err(format!("Unknown unit type '{value}'")),
+        }
+    }
+}
+
+impl From<UnitType> for i16 {
+    fn from(value: UnitType) -> Self {
+        match value {
+            UnitType::Enforcer => 1,
+            UnitType::Drone => 2,
+            UnitType::Mech => 3,
+        }
+    }
+}
+
+impl Display for UnitType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnitType::Enforcer => write!(f, "Enforcer"),
+            UnitType::Drone => write!(f, "Drone"),
+            UnitType::Mech => write!(f, "Mech"),
+        }
+    }
+}
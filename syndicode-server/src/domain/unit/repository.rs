@@ -1,14 +1,14 @@
 #[cfg(test)]
 use mockall::{automock, predicate::*};
 
-use super::model::Unit;
+use super::model::{Unit, UnitDetails};
 use crate::domain::repository::RepositoryResult;
 use tonic::async_trait;
 use uuid::Uuid;
 
 pub struct ListUnitsOutcome {
     pub game_tick: i64,
-    pub units: Vec<Unit>,
+    pub units: Vec<UnitDetails>,
 }
 
 #[cfg_attr(test, automock)]
@@ -29,5 +29,15 @@ pub trait UnitTxRespository: Send + Sync {
         units: &[Unit],
     ) -> RepositoryResult<()>;
 
-    async fn delete_units_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64>;
+    async fn supersede_units_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64>;
+
+    async fn delete_units_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64>;
 }
@@ -0,0 +1,33 @@
+pub mod topic;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Something that has happened in the world within a tick and may concern every player,
+/// not only the corporations involved. Players receive the events of the topics they have
+/// subscribed to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WorldEvent {
+    BusinessListed {
+        listing_uuid: Uuid,
+        business_uuid: Uuid,
+        market_uuid: Uuid,
+        seller_corporation_uuid: Option<Uuid>,
+        asking_price: i64,
+        auction_closes_at_tick: Option<i64>,
+    },
+    BuildingOwnerChanged {
+        building_gml_id: String,
+        longitude: f64,
+        latitude: f64,
+        previous_owner_corporation_uuid: Option<Uuid>,
+        owning_corporation_uuid: Option<Uuid>,
+    },
+}
+
+/// The world events of one tick, as published to the servers that filter them for their players.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldEventBatch {
+    pub game_tick: i64,
+    pub events: Vec<WorldEvent>,
+}
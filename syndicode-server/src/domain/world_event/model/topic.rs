@@ -0,0 +1,114 @@
+use super::WorldEvent;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Filter for the world events a player is interested in.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WorldEventTopic {
+    /// New listings, optionally only the ones in a market or up to an asking price.
+    BusinessListings {
+        market_uuid: Option<Uuid>,
+        max_asking_price: Option<i64>,
+    },
+    /// Buildings within the bounding box that changed owner.
+    BuildingOwnership {
+        min_longitude: f64,
+        min_latitude: f64,
+        max_longitude: f64,
+        max_latitude: f64,
+    },
+}
+
+impl WorldEventTopic {
+    pub fn matches(&self, event: &WorldEvent) -> bool {
+        match (self, event) {
+            (
+                WorldEventTopic::BusinessListings {
+                    market_uuid,
+                    max_asking_price,
+                },
+                WorldEvent::BusinessListed {
+                    market_uuid: listed_market_uuid,
+                    asking_price,
+                    ..
+                },
+            ) => {
+                market_uuid.is_none_or(|market_uuid| market_uuid == *listed_market_uuid)
+                    && max_asking_price
+                        .is_none_or(|max_asking_price| *asking_price <= max_asking_price)
+            }
+            (
+                WorldEventTopic::BuildingOwnership {
+                    min_longitude,
+                    min_latitude,
+                    max_longitude,
+                    max_latitude,
+                },
+                WorldEvent::BuildingOwnerChanged {
+                    longitude,
+                    latitude,
+                    ..
+                },
+            ) => {
+                (min_longitude..=max_longitude).contains(&longitude)
+                    && (min_latitude..=max_latitude).contains(&latitude)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listed(market_uuid: Uuid, asking_price: i64) -> WorldEvent {
+        WorldEvent::BusinessListed {
+            listing_uuid: Uuid::now_v7(),
+            business_uuid: Uuid::now_v7(),
+            market_uuid,
+            seller_corporation_uuid: None,
+            asking_price,
+            auction_closes_at_tick: None,
+        }
+    }
+
+    #[test]
+    fn should_match_listings_by_market_and_price() {
+        let market_uuid = Uuid::now_v7();
+        let topic = WorldEventTopic::BusinessListings {
+            market_uuid: Some(market_uuid),
+            max_asking_price: Some(1_000),
+        };
+
+        assert!(topic.matches(&listed(market_uuid, 1_000)));
+        assert!(!topic.matches(&listed(market_uuid, 1_001)));
+        assert!(!topic.matches(&listed(Uuid::now_v7(), 500)));
+        assert!(WorldEventTopic::BusinessListings {
+            market_uuid: None,
+            max_asking_price: None,
+        }
+        .matches(&listed(Uuid::now_v7(), 1_000_000)));
+    }
+
+    #[test]
+    fn should_match_buildings_within_the_bounding_box() {
+        let topic = WorldEventTopic::BuildingOwnership {
+            min_longitude: 139.7,
+            min_latitude: 35.6,
+            max_longitude: 139.8,
+            max_latitude: 35.7,
+        };
+        let changed = |longitude, latitude| WorldEvent::BuildingOwnerChanged {
+            building_gml_id: "bldg_1".to_string(),
+            longitude,
+            latitude,
+            previous_owner_corporation_uuid: None,
+            owning_corporation_uuid: Some(Uuid::now_v7()),
+        };
+
+        assert!(topic.matches(&changed(139.75, 35.65)));
+        assert!(!topic.matches(&changed(139.85, 35.65)));
+        assert!(!topic.matches(&listed(Uuid::now_v7(), 0)));
+    }
+}
@@ -1,4 +1,7 @@
+pub mod action_log;
+pub mod diplomacy;
 pub mod economy;
+pub mod espionage;
 pub mod game_tick;
 pub mod init;
 pub mod migration;
@@ -10,11 +13,26 @@ pub mod user_verify;
 use std::sync::Arc;
 
 use crate::config::ServerConfig;
-use sqlx::{pool::PoolOptions, PgPool};
+use sqlx::{pool::PoolOptions, PgPool, Postgres, QueryBuilder};
 
 // The SRID (Spatial Reference ID). 4326 is the standard for GPS (WGS 84).
 pub(super) const SRID: i32 = 4326;
 
+/// Restricts a state table, referenced by its alias, to the row versions visible at the given game tick.
+pub(super) fn push_visible_in_tick(
+    qb: &mut QueryBuilder<'_, Postgres>,
+    alias: &str,
+    game_tick: i64,
+) {
+    qb.push(format!("{alias}.game_tick <= "));
+    qb.push_bind(game_tick);
+    qb.push(format!(
+        " AND ({alias}.superseded_at_tick IS NULL OR {alias}.superseded_at_tick > "
+    ));
+    qb.push_bind(game_tick);
+    qb.push(")");
+}
+
 #[derive(Debug)]
 pub struct PostgresDatabase {
     pub pool: PgPool,
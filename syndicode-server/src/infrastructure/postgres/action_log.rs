@@ -0,0 +1,152 @@
+use super::{uow::PgTransactionContext, PostgresDatabase};
+use crate::{
+    application::{
+        action::{ActionLogEntry, QueuedAction},
+        ports::action_log::{ActionLogRepository, ActionLogTxRepository},
+    },
+    domain::repository::{RepositoryError, RepositoryResult},
+};
+use sqlx::Postgres;
+use std::sync::Arc;
+
+struct ActionLogRow {
+    game_tick: i64,
+    seed: i64,
+    actions: Vec<u8>,
+    state_checksum: i64,
+    outcomes_checksum: i64,
+    checksum_version: String,
+}
+
+impl TryFrom<ActionLogRow> for ActionLogEntry {
+    type Error = RepositoryError;
+
+    fn try_from(row: ActionLogRow) -> Result<Self, Self::Error> {
+        let actions = rmp_serde::from_slice::<Vec<QueuedAction>>(&row.actions).map_err(|err| {
+            anyhow::anyhow!(
+                "Failed to deserialize actions of tick {}: {}",
+                row.game_tick,
+                err
+            )
+        })?;
+
+        // The unsigned values are stored bit for bit in signed columns
+        Ok(Self {
+            game_tick: row.game_tick,
+            seed: row.seed as u64,
+            actions,
+            state_checksum: row.state_checksum as u64,
+            outcomes_checksum: row.outcomes_checksum as u64,
+            checksum_version: row.checksum_version,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct PgActionLogRepository;
+
+impl PgActionLogRepository {
+    pub async fn append_action_log_entry(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        entry: &ActionLogEntry,
+    ) -> RepositoryResult<()> {
+        let actions = rmp_serde::to_vec(&entry.actions).map_err(|err| {
+            anyhow::anyhow!(
+                "Failed to serialize actions of tick {}: {}",
+                entry.game_tick,
+                err
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO action_log (
+                game_tick,
+                seed,
+                actions,
+                state_checksum,
+                outcomes_checksum,
+                checksum_version
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(entry.game_tick)
+        .bind(entry.seed as i64)
+        .bind(actions)
+        .bind(entry.state_checksum as i64)
+        .bind(entry.outcomes_checksum as i64)
+        .bind(&entry.checksum_version)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_action_log_entries(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        from_game_tick: i64,
+        to_game_tick: i64,
+    ) -> RepositoryResult<Vec<ActionLogEntry>> {
+        let rows = sqlx::query_as!(
+            ActionLogRow,
+            r#"
+            SELECT
+                game_tick,
+                seed,
+                actions,
+                state_checksum,
+                outcomes_checksum,
+                checksum_version
+            FROM action_log
+            WHERE
+                game_tick BETWEEN $1 AND $2
+            ORDER BY game_tick
+            "#,
+            from_game_tick,
+            to_game_tick,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter().map(ActionLogEntry::try_from).collect()
+    }
+}
+
+pub struct PgActionLogService {
+    pg_db: Arc<PostgresDatabase>,
+    action_log_repo: PgActionLogRepository,
+}
+
+impl PgActionLogService {
+    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+        Self {
+            pg_db,
+            action_log_repo: PgActionLogRepository,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ActionLogRepository for PgActionLogService {
+    async fn list_action_log_entries(
+        &self,
+        from_game_tick: i64,
+        to_game_tick: i64,
+    ) -> RepositoryResult<Vec<ActionLogEntry>> {
+        self.action_log_repo
+            .list_action_log_entries(&self.pg_db.pool, from_game_tick, to_game_tick)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl ActionLogTxRepository for PgTransactionContext<'_, '_> {
+    async fn append_action_log_entry(&mut self, entry: &ActionLogEntry) -> RepositoryResult<()> {
+        self.action_log_repo
+            .append_action_log_entry(&mut **self.tx, entry)
+            .await
+    }
+}
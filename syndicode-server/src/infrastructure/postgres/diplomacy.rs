@@ -0,0 +1,249 @@
+use super::{uow::PgTransactionContext, PostgresDatabase};
+use crate::domain::{
+    diplomacy::{
+        model::{treaty_type::TreatyType, Treaty},
+        repository::{TreatyRepository, TreatyTxRepository},
+    },
+    repository::{RepositoryError, RepositoryResult},
+};
+use sqlx::Postgres;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// The treaty type is stored as its numeric code.
+struct TreatyRow {
+    uuid: Uuid,
+    treaty_type: i16,
+    proposer_corporation_uuid: Uuid,
+    partner_corporation_uuid: Uuid,
+    proposed_at_tick: i64,
+    accepted_at_tick: Option<i64>,
+}
+
+impl TryFrom<TreatyRow> for Treaty {
+    type Error = RepositoryError;
+
+    fn try_from(row: TreatyRow) -> Result<Self, Self::Error> {
+        let treaty_type = TreatyType::try_from(row.treaty_type)
+            .map_err(|err| anyhow::anyhow!("Invalid treaty '{}': {}", row.uuid, err))?;
+
+        Ok(Self {
+            uuid: row.uuid,
+            treaty_type,
+            proposer_corporation_uuid: row.proposer_corporation_uuid,
+            partner_corporation_uuid: row.partner_corporation_uuid,
+            proposed_at_tick: row.proposed_at_tick,
+            accepted_at_tick: row.accepted_at_tick,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct PgTreatyRepository;
+
+impl PgTreatyRepository {
+    /// Inserts new versions of the given treaties, visible from the given game tick on.
+    /// The current versions of these treaties are superseded in the same statement.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
+    pub async fn insert_treaties_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        treaties: &[Treaty],
+        game_tick: i64,
+    ) -> RepositoryResult<()> {
+        if treaties.is_empty() {
+            return Ok(());
+        }
+
+        // Prepare separate vectors for each column to be bulk inserted.
+        // Pre-allocate capacity for efficiency.
+        let count = treaties.len();
+        let mut uuids = Vec::with_capacity(count);
+        let mut treaty_types = Vec::with_capacity(count);
+        let mut proposer_corporation_uuids = Vec::with_capacity(count);
+        let mut partner_corporation_uuids = Vec::with_capacity(count);
+        let mut proposed_at_ticks = Vec::with_capacity(count);
+        let mut accepted_at_ticks: Vec<Option<i64>> = Vec::with_capacity(count);
+
+        for treaty in treaties {
+            uuids.push(treaty.uuid);
+            treaty_types.push(i16::from(treaty.treaty_type));
+            proposer_corporation_uuids.push(treaty.proposer_corporation_uuid);
+            partner_corporation_uuids.push(treaty.partner_corporation_uuid);
+            proposed_at_ticks.push(treaty.proposed_at_tick);
+            accepted_at_ticks.push(treaty.accepted_at_tick);
+        }
+
+        // Execute the bulk insert query using UNNEST
+        sqlx::query(
+            r#"
+            WITH superseded AS (
+                UPDATE treaties
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
+            INSERT INTO treaties (
+                game_tick,
+                uuid,
+                treaty_type,
+                proposer_corporation_uuid,
+                partner_corporation_uuid,
+                proposed_at_tick,
+                accepted_at_tick
+            )
+            SELECT $1, u.*
+            FROM unnest($2::UUID[], $3::SMALLINT[], $4::UUID[], $5::UUID[], $6::BIGINT[], $7::BIGINT[])
+            AS u(uuid, treaty_type, proposer_corporation_uuid, partner_corporation_uuid, proposed_at_tick, accepted_at_tick)
+            "#,
+        )
+        .bind(game_tick) // Binds to game_tick column via $1
+        .bind(&uuids) // Binds to $2 -> u.uuid -> uuid column
+        .bind(&treaty_types) // Binds to $3 -> u.treaty_type -> treaty_type column
+        .bind(&proposer_corporation_uuids) // Binds to $4 -> u.proposer_corporation_uuid -> proposer_corporation_uuid column
+        .bind(&partner_corporation_uuids) // Binds to $5 -> u.partner_corporation_uuid -> partner_corporation_uuid column
+        .bind(&proposed_at_ticks) // Binds to $6 -> u.proposed_at_tick -> proposed_at_tick column
+        .bind(&accepted_at_ticks) // Binds to $7 -> u.accepted_at_tick -> accepted_at_tick column
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_treaties_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<Treaty>> {
+        let rows = sqlx::query_as!(
+            TreatyRow,
+            r#"
+            SELECT
+                uuid,
+                treaty_type,
+                proposer_corporation_uuid,
+                partner_corporation_uuid,
+                proposed_at_tick,
+                accepted_at_tick
+            FROM treaties
+            WHERE
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+            "#,
+            game_tick,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter().map(Treaty::try_from).collect()
+    }
+
+    /// Ends the current versions of the given treaties at the given game tick.
+    pub async fn supersede_treaties_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE treaties
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the treaty versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
+    pub async fn delete_treaties_before_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM treaties
+            WHERE
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
+            "#,
+            game_tick,
+            snapshot_interval,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+pub struct PgTreatyService {
+    pg_db: Arc<PostgresDatabase>,
+    treaty_repo: PgTreatyRepository,
+}
+
+impl PgTreatyService {
+    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+        Self {
+            pg_db,
+            treaty_repo: PgTreatyRepository,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl TreatyRepository for PgTreatyService {
+    async fn list_treaties_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Treaty>> {
+        self.treaty_repo
+            .list_treaties_in_tick(&self.pg_db.pool, game_tick)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl TreatyTxRepository for PgTransactionContext<'_, '_> {
+    async fn insert_treaties_in_tick(
+        &mut self,
+        game_tick: i64,
+        treaties: &[Treaty],
+    ) -> RepositoryResult<()> {
+        self.treaty_repo
+            .insert_treaties_in_tick(&mut **self.tx, treaties, game_tick)
+            .await
+    }
+
+    async fn supersede_treaties_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.treaty_repo
+            .supersede_treaties_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_treaties_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        self.treaty_repo
+            .delete_treaties_before_tick(&mut **self.tx, game_tick, snapshot_interval)
+            .await
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    domain::{
+        economy::auction_bid::{
+            model::AuctionBid,
+            repository::{AuctionBidRepository, AuctionBidTxRepository},
+        },
+        repository::RepositoryResult,
+    },
+    infrastructure::postgres::{uow::PgTransactionContext, PostgresDatabase},
+};
+use sqlx::Postgres;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PgAuctionBidRepository;
+
+impl PgAuctionBidRepository {
+    /// Inserts new versions of the given auction bids, visible from the given game tick on.
+    /// The current versions of these auction bids are superseded in the same statement.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
+    pub async fn insert_auction_bids_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        auction_bids: &[AuctionBid],
+        game_tick: i64,
+    ) -> RepositoryResult<()> {
+        if auction_bids.is_empty() {
+            return Ok(());
+        }
+
+        // Prepare separate vectors for each column to be bulk inserted.
+        // Pre-allocate capacity for efficiency.
+        let count = auction_bids.len();
+        let mut uuids = Vec::with_capacity(count);
+        let mut business_listing_uuids = Vec::with_capacity(count);
+        let mut business_uuids = Vec::with_capacity(count);
+        let mut bidding_corporation_uuids = Vec::with_capacity(count);
+        let mut amounts = Vec::with_capacity(count);
+        let mut placed_at_ticks = Vec::with_capacity(count);
+
+        for bid in auction_bids {
+            uuids.push(bid.uuid);
+            business_listing_uuids.push(bid.business_listing_uuid);
+            business_uuids.push(bid.business_uuid);
+            bidding_corporation_uuids.push(bid.bidding_corporation_uuid);
+            amounts.push(bid.amount);
+            placed_at_ticks.push(bid.placed_at_tick);
+        }
+
+        // Execute the bulk insert query using UNNEST
+        sqlx::query(
+            r#"
+            WITH superseded AS (
+                UPDATE auction_bids
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
+            INSERT INTO auction_bids (
+                game_tick,
+                uuid,
+                business_listing_uuid,
+                business_uuid,
+                bidding_corporation_uuid,
+                amount,
+                placed_at_tick
+            )
+            SELECT $1, u.*
+            FROM unnest($2::UUID[], $3::UUID[], $4::UUID[], $5::UUID[], $6::BIGINT[], $7::BIGINT[])
+            AS u(uuid, business_listing_uuid, business_uuid, bidding_corporation_uuid, amount, placed_at_tick)
+            "#,
+        )
+        .bind(game_tick) // Binds to game_tick column via $1
+        .bind(&uuids) // Binds to $2 -> u.uuid -> uuid column
+        .bind(&business_listing_uuids) // Binds to $3 -> u.business_listing_uuid -> business_listing_uuid column
+        .bind(&business_uuids) // Binds to $4 -> u.business_uuid -> business_uuid column
+        .bind(&bidding_corporation_uuids) // Binds to $5 -> u.bidding_corporation_uuid -> bidding_corporation_uuid column
+        .bind(&amounts) // Binds to $6 -> u.amount -> amount column
+        .bind(&placed_at_ticks) // Binds to $7 -> u.placed_at_tick -> placed_at_tick column
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_auction_bids_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<AuctionBid>> {
+        let auction_bids = sqlx::query_as!(
+            AuctionBid,
+            r#"
+            SELECT
+                uuid,
+                business_listing_uuid,
+                business_uuid,
+                bidding_corporation_uuid,
+                amount,
+                placed_at_tick
+            FROM auction_bids
+            WHERE
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+            "#,
+            game_tick,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(auction_bids)
+    }
+
+    /// Ends the current versions of the given auction bids at the given game tick.
+    pub async fn supersede_auction_bids_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE auction_bids
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the auction bid versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
+    pub async fn delete_auction_bids_before_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM auction_bids
+            WHERE
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
+            "#,
+            game_tick,
+            snapshot_interval,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+pub struct PgAuctionBidService {
+    pg_db: Arc<PostgresDatabase>,
+    auction_bid_repo: PgAuctionBidRepository,
+}
+
+impl PgAuctionBidService {
+    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+        Self {
+            pg_db,
+            auction_bid_repo: PgAuctionBidRepository,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl AuctionBidRepository for PgAuctionBidService {
+    async fn list_auction_bids_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<AuctionBid>> {
+        self.auction_bid_repo
+            .list_auction_bids_in_tick(&self.pg_db.pool, game_tick)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl AuctionBidTxRepository for PgTransactionContext<'_, '_> {
+    async fn insert_auction_bids_in_tick(
+        &mut self,
+        game_tick: i64,
+        auction_bids: &[AuctionBid],
+    ) -> RepositoryResult<()> {
+        self.auction_bid_repo
+            .insert_auction_bids_in_tick(&mut **self.tx, auction_bids, game_tick)
+            .await
+    }
+
+    async fn supersede_auction_bids_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.auction_bid_repo
+            .supersede_auction_bids_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_auction_bids_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        self.auction_bid_repo
+            .delete_auction_bids_before_tick(&mut **self.tx, game_tick, snapshot_interval)
+            .await
+    }
+}
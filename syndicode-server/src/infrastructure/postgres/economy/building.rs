@@ -3,15 +3,16 @@ use std::sync::Arc;
 use crate::{
     domain::{
         economy::building::{
-            model::Building,
+            model::{Building, BuildingLocation},
             repository::{
                 BuildingDetails, BuildingRepository, BuildingTxRepository, QueryBuildingsRequest,
             },
         },
-        repository::RepositoryResult,
+        repository::{RepositoryResult, TickRetention},
     },
     infrastructure::postgres::{
-        game_tick::PgGameTickRepository, uow::PgTransactionContext, PostgresDatabase, SRID,
+        game_tick::PgGameTickRepository, push_visible_in_tick, uow::PgTransactionContext,
+        PostgresDatabase, SRID,
     },
 };
 use sqlx::{Executor, Postgres, QueryBuilder};
@@ -131,21 +132,28 @@ impl PgBuildingRepository {
         SELECT
             bui.gml_id,
             ST_X(bui.center) as longitude,
-            ST_Y(bui.center) as latitude
-        FROM building_ownerships bo
-        JOIN businesses b ON b.uuid = bo.owning_business_uuid AND b.game_tick = 
+            ST_Y(bui.center) as latitude,
+            bui.volume,
+            bui.usage_code,
+            bo.owning_business_uuid
+        FROM buildings bui
+        LEFT JOIN building_ownerships bo ON bo.building_uuid = bui.uuid AND 
         "#,
         );
-        qb.push_bind(game_tick);
-
         // The 'buildings' table is static and does not have a game_tick column.
-        qb.push(" JOIN buildings bui ON bui.uuid = bo.building_uuid ");
+        push_visible_in_tick(&mut qb, "bo", game_tick);
 
-        qb.push(" WHERE bo.game_tick = ");
-        qb.push_bind(game_tick);
+        qb.push(" LEFT JOIN businesses b ON b.uuid = bo.owning_business_uuid AND ");
+        push_visible_in_tick(&mut qb, "b", game_tick);
 
         // --- Build WHERE clause dynamically based on optional request parameters ---
 
+        if req.is_owned.unwrap_or(true) {
+            qb.push(" WHERE bo.building_uuid IS NOT NULL ");
+        } else {
+            qb.push(" WHERE bo.building_uuid IS NULL ");
+        }
+
         if let Some(corp_uuid) = req.owning_corporation_uuid {
             qb.push(" AND b.owning_corporation_uuid = ");
             qb.push_bind(corp_uuid);
@@ -185,20 +193,96 @@ impl PgBuildingRepository {
 
         Ok(ownership_details)
     }
+
+    /// Lists the locations of the buildings the game state refers to at the given game tick:
+    /// owned buildings, headquarters of businesses and the buildings units are at or heading to.
+    pub async fn list_building_locations_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<BuildingLocation>> {
+        let locations = sqlx::query_as!(
+            BuildingLocation,
+            r#"
+            SELECT
+                uuid,
+                gml_id,
+                ST_X(center) as "longitude!",
+                ST_Y(center) as "latitude!",
+                volume,
+                usage_code,
+                city_code
+            FROM buildings
+            WHERE uuid IN (
+                SELECT building_uuid
+                FROM building_ownerships
+                WHERE
+                    game_tick <= $1
+                    AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+                UNION
+                SELECT headquarter_building_uuid
+                FROM businesses
+                WHERE
+                    game_tick <= $1
+                    AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+                UNION
+                SELECT unnest(ARRAY[building_uuid, destination_building_uuid, attack_target_building_uuid])
+                FROM units
+                WHERE
+                    game_tick <= $1
+                    AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+            )
+            "#,
+            game_tick
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(locations)
+    }
+
+    pub async fn list_building_locations_by_gml_ids(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        gml_ids: &[String],
+    ) -> RepositoryResult<Vec<BuildingLocation>> {
+        let locations = sqlx::query_as!(
+            BuildingLocation,
+            r#"
+            SELECT
+                uuid,
+                gml_id,
+                ST_X(center) as "longitude!",
+                ST_Y(center) as "latitude!",
+                volume,
+                usage_code,
+                city_code
+            FROM buildings
+            WHERE gml_id = ANY($1)
+            "#,
+            gml_ids
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(locations)
+    }
 }
 
 pub struct PgBuildingService {
     pg_db: Arc<PostgresDatabase>,
     game_tick_repo: PgGameTickRepository,
     building_repo: PgBuildingRepository,
+    tick_retention: TickRetention,
 }
 
 impl PgBuildingService {
-    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+    pub fn new(pg_db: Arc<PostgresDatabase>, tick_retention: TickRetention) -> Self {
         Self {
             pg_db,
             game_tick_repo: PgGameTickRepository,
             building_repo: PgBuildingRepository,
+            tick_retention,
         }
     }
 }
@@ -211,7 +295,7 @@ impl BuildingRepository for PgBuildingService {
     ) -> RepositoryResult<(i64, Vec<BuildingDetails>)> {
         let game_tick = self
             .game_tick_repo
-            .get_current_game_tick(&self.pg_db.pool)
+            .resolve_game_tick(&self.pg_db.pool, req.at_game_tick, self.tick_retention)
             .await?;
 
         let result = self
@@ -221,6 +305,24 @@ impl BuildingRepository for PgBuildingService {
 
         Ok((game_tick, result))
     }
+
+    async fn list_building_locations(
+        &self,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<BuildingLocation>> {
+        self.building_repo
+            .list_building_locations_in_tick(&self.pg_db.pool, game_tick)
+            .await
+    }
+
+    async fn list_building_locations_by_gml_ids(
+        &self,
+        gml_ids: Vec<String>,
+    ) -> RepositoryResult<Vec<BuildingLocation>> {
+        self.building_repo
+            .list_building_locations_by_gml_ids(&self.pg_db.pool, &gml_ids)
+            .await
+    }
 }
 
 #[tonic::async_trait]
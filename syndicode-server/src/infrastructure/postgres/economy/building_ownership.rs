@@ -11,11 +11,14 @@ use crate::{
     infrastructure::postgres::{uow::PgTransactionContext, PostgresDatabase},
 };
 use sqlx::Postgres;
+use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct PgBuildingOwnershipRepository;
 
 impl PgBuildingOwnershipRepository {
+    /// Inserts new versions of the given building ownerships, visible from the given game tick on.
+    /// The current versions of the ownerships of these buildings are superseded in the same statement.
     /// This leverages PostgreSQL's UNNEST function for efficiency.
     /// CARE: This is not compile time checked
     pub async fn insert_building_ownerships_in_tick(
@@ -40,6 +43,13 @@ impl PgBuildingOwnershipRepository {
 
         sqlx::query(
             r#"
+            WITH superseded AS (
+                UPDATE building_ownerships
+                SET superseded_at_tick = $1
+                WHERE
+                    building_uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
             INSERT INTO building_ownerships (
                 game_tick,
                 building_uuid,
@@ -70,44 +80,70 @@ impl PgBuildingOwnershipRepository {
         executor: impl sqlx::Executor<'_, Database = Postgres>,
         game_tick: i64,
     ) -> RepositoryResult<Vec<BuildingOwnership>> {
-        let records = sqlx::query!(
+        let building_ownerships = sqlx::query_as!(
+            BuildingOwnership,
             r#"
             SELECT
-                game_tick,
                 building_uuid,
                 owning_business_uuid
             FROM building_ownerships
             WHERE
-                game_tick = $1
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
             "#,
-            game_tick
+            game_tick,
         )
         .fetch_all(executor)
         .await?;
 
-        let mut building_ownerships = Vec::with_capacity(records.len());
-        for record in records {
-            building_ownerships.push(BuildingOwnership {
-                building_uuid: record.building_uuid,
-                owning_business_uuid: record.owning_business_uuid,
-            });
+        Ok(building_ownerships)
+    }
+
+    /// Ends the current ownerships of the given buildings at the given game tick.
+    pub async fn supersede_building_ownerships_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        building_uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if building_uuids.is_empty() {
+            return Ok(0);
         }
 
-        Ok(building_ownerships)
+        let result = sqlx::query!(
+            r#"
+            UPDATE building_ownerships
+            SET superseded_at_tick = $1
+            WHERE
+                building_uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            building_uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
     }
 
+    /// Deletes the building ownership versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
     pub async fn delete_building_ownerships_before_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         let result = sqlx::query!(
             r#"
             DELETE FROM building_ownerships
             WHERE
-                game_tick < $1
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
             "#,
-            game_tick
+            game_tick,
+            snapshot_interval,
         )
         .execute(executor)
         .await?;
@@ -154,12 +190,23 @@ impl BuildingOwnershipTxRepository for PgTransactionContext<'_, '_> {
             .await
     }
 
+    async fn supersede_building_ownerships_in_tick(
+        &mut self,
+        game_tick: i64,
+        building_uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.building_ownerships_repo
+            .supersede_building_ownerships_in_tick(&mut **self.tx, building_uuids, game_tick)
+            .await
+    }
+
     async fn delete_building_ownerships_before_tick(
         &mut self,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         self.building_ownerships_repo
-            .delete_building_ownerships_before_tick(&mut **self.tx, game_tick)
+            .delete_building_ownerships_before_tick(&mut **self.tx, game_tick, snapshot_interval)
             .await
     }
 }
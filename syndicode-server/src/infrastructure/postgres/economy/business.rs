@@ -7,15 +7,17 @@ use crate::{
                 model::Business,
                 repository::{
                     BusinessDetails, BusinessRepository, BusinessTxRepository,
-                    DomainBusinessSortBy, QueryBusinessesRequest,
+                    DomainBusinessSortBy, QueryBusinessesOutcome, QueryBusinessesRequest,
                 },
             },
+            corporation::model::visibility::Visibility,
             market::model::name::MarketName,
         },
-        repository::RepositoryResult,
+        repository::{RepositoryResult, TickRetention},
     },
     infrastructure::postgres::{
-        game_tick::PgGameTickRepository, uow::PgTransactionContext, PostgresDatabase,
+        economy::corporation::PgCorporationRepository, game_tick::PgGameTickRepository,
+        push_visible_in_tick, uow::PgTransactionContext, PostgresDatabase,
     },
 };
 use sqlx::{Execute, Postgres, QueryBuilder, Row};
@@ -25,6 +27,8 @@ use uuid::Uuid;
 pub struct PgBusinessRepository;
 
 impl PgBusinessRepository {
+    /// Inserts new versions of the given businesses, visible from the given game tick on.
+    /// The current versions of these businesses are superseded in the same statement.
     /// This leverages PostgreSQL's UNNEST function for efficiency.
     /// CARE: This is not compile time checked
     pub async fn insert_businesses_in_tick(
@@ -45,6 +49,8 @@ impl PgBusinessRepository {
         let mut operational_expenses_vec = Vec::with_capacity(count);
         let mut headquarter_business_uuids: Vec<Uuid> = Vec::with_capacity(count);
         let mut image_numbers_vec = Vec::with_capacity(count);
+        let mut operational_expenses_changed_at_ticks_vec: Vec<Option<i64>> =
+            Vec::with_capacity(count);
 
         for business in businesses {
             uuids_vec.push(business.uuid);
@@ -54,10 +60,19 @@ impl PgBusinessRepository {
             operational_expenses_vec.push(business.operational_expenses);
             headquarter_business_uuids.push(business.headquarter_building_uuid);
             image_numbers_vec.push(business.image_number);
+            operational_expenses_changed_at_ticks_vec
+                .push(business.operational_expenses_changed_at_tick);
         }
 
         sqlx::query(
             r#"
+            WITH superseded AS (
+                UPDATE businesses
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
             INSERT INTO businesses (
                 game_tick,
                 uuid,
@@ -66,7 +81,8 @@ impl PgBusinessRepository {
                 name,
                 operational_expenses,
                 headquarter_building_uuid,
-                image_number
+                image_number,
+                operational_expenses_changed_at_tick
             )
             SELECT
                 $1,
@@ -76,7 +92,8 @@ impl PgBusinessRepository {
                 u.name,
                 u.operational_expenses,
                 u.headquarter_building_uuid,
-                u.image_number
+                u.image_number,
+                u.operational_expenses_changed_at_tick
             FROM unnest(
                 $2::UUID[],
                 $3::UUID[],
@@ -84,7 +101,8 @@ impl PgBusinessRepository {
                 $5::TEXT[],
                 $6::BIGINT[],
                 $7::UUID[],
-                $8::SMALLINT[]
+                $8::SMALLINT[],
+                $9::BIGINT[]
             )
             AS u(
                 uuid,
@@ -93,7 +111,8 @@ impl PgBusinessRepository {
                 name,
                 operational_expenses,
                 headquarter_building_uuid,
-                image_number
+                image_number,
+                operational_expenses_changed_at_tick
             )
             "#,
         )
@@ -105,6 +124,7 @@ impl PgBusinessRepository {
         .bind(&operational_expenses_vec)
         .bind(&headquarter_business_uuids)
         .bind(&image_numbers_vec)
+        .bind(&operational_expenses_changed_at_ticks_vec)
         .execute(executor)
         .await?;
 
@@ -125,10 +145,12 @@ impl PgBusinessRepository {
             name,
             operational_expenses,
             headquarter_building_uuid,
-            image_number
+            image_number,
+            operational_expenses_changed_at_tick
         FROM businesses
         WHERE
-            game_tick = $1
+            game_tick <= $1
+            AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
         "#,
         )
         .bind(game_tick)
@@ -143,73 +165,9 @@ impl PgBusinessRepository {
         executor: impl sqlx::Executor<'_, Database = Postgres> + Copy,
         game_tick: i64,
         req: &QueryBusinessesRequest,
+        visibility: &Visibility,
     ) -> RepositoryResult<Vec<BusinessDetails>> {
-        let mut qb = QueryBuilder::new(
-            r#"
-            SELECT
-                b.uuid AS business_uuid,
-                b.name AS business_name,
-                b.owning_corporation_uuid,
-                b.market_uuid,
-                m.name AS market_name_i16,
-                b.operational_expenses,
-                b.headquarter_building_uuid,
-                bui.gml_id AS headquarter_building_gml_id,
-                ST_X(bui.center) AS headquarter_longitude,
-                ST_Y(bui.center) AS headquarter_latitude,
-                m.volume AS market_volume,
-                b.image_number
-            FROM businesses b
-            JOIN markets m ON b.market_uuid = m.uuid AND m.game_tick = "#,
-        );
-        qb.push_bind(game_tick);
-        qb.push(" JOIN buildings bui ON b.headquarter_building_uuid = bui.uuid");
-        qb.push(" WHERE b.game_tick = ");
-        qb.push_bind(game_tick);
-
-        // Build WHERE clause dynamically
-        if let Some(owning_corporation_uuid) = &req.owning_corporation_uuid {
-            qb.push(" AND b.owning_corporation_uuid = ");
-            qb.push_bind(owning_corporation_uuid);
-        }
-
-        if let Some(market_uuid) = &req.market_uuid {
-            qb.push(" AND b.market_uuid = ");
-            qb.push_bind(market_uuid);
-        }
-
-        if let Some(min_op_ex) = req.min_operational_expenses {
-            qb.push(" AND b.operational_expenses >= ");
-            qb.push_bind(min_op_ex);
-        }
-
-        if let Some(max_op_ex) = req.max_operational_expenses {
-            qb.push(" AND b.operational_expenses <= ");
-            qb.push_bind(max_op_ex);
-        }
-
-        // --- Add Sorting ---
-        let sort_column = match req.sort_by.as_ref().unwrap_or_default() {
-            DomainBusinessSortBy::Name => "b.name",
-            DomainBusinessSortBy::OperationExpenses => "b.operational_expenses",
-            DomainBusinessSortBy::MarketVolume => "m.volume",
-        };
-
-        let sort_direction = req.sort_direction.unwrap_or_default().to_string();
-
-        qb.push(format!(" ORDER BY {sort_column} {sort_direction}"));
-
-        // --- Add Pagination ---
-        let limit = req.limit.unwrap_or(10).min(100);
-        qb.push(" LIMIT ");
-        qb.push_bind(limit);
-
-        if let Some(offset_val) = req.offset {
-            if offset_val > 0 {
-                qb.push(" OFFSET ");
-                qb.push_bind(offset_val);
-            }
-        }
+        let mut qb = build_query_businesses(game_tick, req, visibility);
 
         // --- Execute Query ---
         let query = qb.build();
@@ -233,8 +191,10 @@ impl PgBusinessRepository {
                     headquarter_building_gml_id: row.get("headquarter_building_gml_id"),
                     headquarter_longitude: row.get("headquarter_longitude"),
                     headquarter_latitude: row.get("headquarter_latitude"),
+                    headquarter_city_code: row.get("headquarter_city_code"),
                     image_number: row.get("image_number"),
                     market_number: market_name_i16,
+                    operational_expenses_estimated: false,
                 }
             })
             .collect();
@@ -242,18 +202,51 @@ impl PgBusinessRepository {
         Ok(businesses)
     }
 
+    /// Ends the current versions of the given businesses at the given game tick.
+    pub async fn supersede_businesses_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE businesses
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the business versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
     pub async fn delete_businesses_before_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         let result = sqlx::query!(
             r#"
             DELETE FROM businesses
             WHERE
-                game_tick < $1
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
             "#,
-            game_tick
+            game_tick,
+            snapshot_interval,
         )
         .execute(executor)
         .await?;
@@ -262,18 +255,150 @@ impl PgBusinessRepository {
     }
 }
 
+/// Builds the business query with its filters, sorting and pagination.
+fn build_query_businesses<'a>(
+    game_tick: i64,
+    req: &'a QueryBusinessesRequest,
+    visibility: &Visibility,
+) -> QueryBuilder<'a, Postgres> {
+    let mut qb = QueryBuilder::new(
+        r#"
+        SELECT
+            b.uuid AS business_uuid,
+            b.name AS business_name,
+            b.owning_corporation_uuid,
+            b.market_uuid,
+            m.name AS market_name_i16,
+            b.operational_expenses,
+            b.headquarter_building_uuid,
+            bui.gml_id AS headquarter_building_gml_id,
+            ST_X(bui.center) AS headquarter_longitude,
+            ST_Y(bui.center) AS headquarter_latitude,
+            bui.city_code AS headquarter_city_code,
+            m.volume AS market_volume,
+            b.image_number
+        FROM businesses b
+        JOIN markets m ON b.market_uuid = m.uuid AND "#,
+    );
+    push_visible_in_tick(&mut qb, "m", game_tick);
+    qb.push(" JOIN buildings bui ON b.headquarter_building_uuid = bui.uuid");
+    qb.push(" WHERE ");
+    push_visible_in_tick(&mut qb, "b", game_tick);
+
+    // Build WHERE clause dynamically
+    if let Some(owning_corporation_uuid) = &req.owning_corporation_uuid {
+        qb.push(" AND b.owning_corporation_uuid = ");
+        qb.push_bind(owning_corporation_uuid);
+    }
+
+    if let Some(market_uuid) = &req.market_uuid {
+        qb.push(" AND b.market_uuid = ");
+        qb.push_bind(market_uuid);
+    }
+
+    // Operational expenses out of sight are compared by their rounded value, if a precision is set
+    let precision = req
+        .operational_expenses_precision
+        .filter(|precision| *precision > 1);
+
+    if let Some(min_op_ex) = req.min_operational_expenses {
+        qb.push(" AND ");
+        push_operational_expenses(&mut qb, visibility, precision);
+        qb.push(" >= ");
+        qb.push_bind(min_op_ex);
+    }
+
+    if let Some(max_op_ex) = req.max_operational_expenses {
+        qb.push(" AND ");
+        push_operational_expenses(&mut qb, visibility, precision);
+        qb.push(" <= ");
+        qb.push_bind(max_op_ex);
+    }
+
+    // --- Add Sorting ---
+    qb.push(" ORDER BY ");
+    match req.sort_by.as_ref().unwrap_or_default() {
+        DomainBusinessSortBy::Name => {
+            qb.push("b.name");
+        }
+        DomainBusinessSortBy::OperationExpenses => {
+            push_operational_expenses(&mut qb, visibility, precision);
+        }
+        DomainBusinessSortBy::MarketVolume => {
+            qb.push("m.volume");
+        }
+    }
+
+    let sort_direction = req.sort_direction.unwrap_or_default().to_string();
+
+    // Ties are broken by UUID, so the order doesn't reveal the precise figures either
+    qb.push(format!(" {sort_direction}, b.uuid"));
+
+    // --- Add Pagination ---
+    let limit = req.limit.unwrap_or(10).min(100);
+    qb.push(" LIMIT ");
+    qb.push_bind(limit);
+
+    if let Some(offset_val) = req.offset {
+        if offset_val > 0 {
+            qb.push(" OFFSET ");
+            qb.push_bind(offset_val);
+        }
+    }
+
+    qb
+}
+
+/// Pushes the operational expenses of a business as the requesting corporation sees them, i.e.
+/// rounded down to the given precision unless the business is in sight, the same way
+/// `BusinessDetails::redact_for` does.
+fn push_operational_expenses(
+    qb: &mut QueryBuilder<'_, Postgres>,
+    visibility: &Visibility,
+    precision: Option<i64>,
+) {
+    let Some(precision) = precision else {
+        qb.push("b.operational_expenses");
+        return;
+    };
+
+    let corporation_uuids: Vec<Uuid> = visibility
+        .corporation_uuid
+        .iter()
+        .chain(&visibility.infiltrated_corporation_uuids)
+        .chain(&visibility.allied_corporation_uuids)
+        .copied()
+        .collect();
+    let market_uuids: Vec<Uuid> = visibility.market_uuids.iter().copied().collect();
+    let city_codes: Vec<String> = visibility.city_codes.iter().cloned().collect();
+
+    qb.push("(CASE WHEN b.owning_corporation_uuid IS NULL OR b.owning_corporation_uuid = ANY(");
+    qb.push_bind(corporation_uuids);
+    qb.push(") OR b.market_uuid = ANY(");
+    qb.push_bind(market_uuids);
+    qb.push(") OR bui.city_code = ANY(");
+    qb.push_bind(city_codes);
+    qb.push(format!(
+        ") THEN b.operational_expenses ELSE b.operational_expenses / {precision} * {precision} END)"
+    ));
+}
+
 pub struct PgBusinessService {
     pg_db: Arc<PostgresDatabase>,
     game_tick_repo: PgGameTickRepository,
     business_repo: PgBusinessRepository,
+    corporation_repo: PgCorporationRepository,
+    tick_retention: TickRetention,
 }
 
 impl PgBusinessService {
-    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+    pub fn new(pg_db: Arc<PostgresDatabase>, tick_retention: TickRetention) -> Self {
         Self {
             pg_db,
             game_tick_repo: PgGameTickRepository,
             business_repo: PgBusinessRepository,
+            corporation_repo: PgCorporationRepository,
+            tick_retention,
         }
     }
 }
@@ -283,18 +408,27 @@ impl BusinessRepository for PgBusinessService {
     async fn query_businesses(
         &self,
         req: &QueryBusinessesRequest,
-    ) -> RepositoryResult<(i64, Vec<BusinessDetails>)> {
+    ) -> RepositoryResult<QueryBusinessesOutcome> {
         let game_tick = self
             .game_tick_repo
-            .get_current_game_tick(&self.pg_db.pool)
+            .resolve_game_tick(&self.pg_db.pool, req.at_game_tick, self.tick_retention)
+            .await?;
+
+        let visibility = self
+            .corporation_repo
+            .get_visibility_by_user_at_tick(&self.pg_db.pool, req.req_user_uuid, game_tick)
             .await?;
 
-        let result = self
+        let businesses = self
             .business_repo
-            .query_businesses(&self.pg_db.pool, game_tick, req)
+            .query_businesses(&self.pg_db.pool, game_tick, req, &visibility)
             .await?;
 
-        Ok((game_tick, result))
+        Ok(QueryBusinessesOutcome {
+            game_tick,
+            visibility,
+            businesses,
+        })
     }
 
     async fn list_businesses_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Business>> {
@@ -316,9 +450,67 @@ impl BusinessTxRepository for PgTransactionContext<'_, '_> {
             .await
     }
 
-    async fn delete_businesses_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64> {
+    async fn supersede_businesses_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.business_repo
+            .supersede_businesses_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_businesses_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
         self.business_repo
-            .delete_businesses_before_tick(&mut **self.tx, game_tick)
+            .delete_businesses_before_tick(&mut **self.tx, game_tick, snapshot_interval)
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_filter_own_businesses_by_their_precise_operational_expenses() {
+        let own_uuid = Uuid::now_v7();
+        let req = QueryBusinessesRequest::builder()
+            .req_user_uuid(Uuid::now_v7())
+            .owning_corporation_uuid(own_uuid)
+            .min_operational_expenses(12_345)
+            .sort_by(DomainBusinessSortBy::OperationExpenses)
+            .operational_expenses_precision(1_000)
+            .build();
+        let visibility = Visibility {
+            corporation_uuid: Some(own_uuid),
+            ..Visibility::default()
+        };
+
+        let sql = build_query_businesses(10, &req, &visibility).into_sql();
+
+        let operational_expenses = "(CASE WHEN b.owning_corporation_uuid IS NULL \
+            OR b.owning_corporation_uuid = ANY($6) OR b.market_uuid = ANY($7) \
+            OR bui.city_code = ANY($8) THEN b.operational_expenses \
+            ELSE b.operational_expenses / 1000 * 1000 END)";
+        assert!(sql.contains(&format!("AND {operational_expenses} >= $9")));
+        assert!(sql.contains("ORDER BY (CASE WHEN b.owning_corporation_uuid IS NULL"));
+    }
+
+    #[test]
+    fn should_compare_precise_operational_expenses_without_precision() {
+        let req = QueryBusinessesRequest::builder()
+            .req_user_uuid(Uuid::now_v7())
+            .min_operational_expenses(12_345)
+            .maybe_operational_expenses_precision(None)
+            .build();
+
+        let sql = build_query_businesses(10, &req, &Visibility::default()).into_sql();
+
+        assert!(sql.contains("AND b.operational_expenses >= $5"));
+        assert!(!sql.contains("CASE"));
+    }
+}
@@ -10,10 +10,11 @@ use crate::{
             },
             market::model::name::MarketName,
         },
-        repository::RepositoryResult,
+        repository::{RepositoryResult, TickRetention},
     },
     infrastructure::postgres::{
-        game_tick::PgGameTickRepository, uow::PgTransactionContext, PostgresDatabase,
+        game_tick::PgGameTickRepository, push_visible_in_tick, uow::PgTransactionContext,
+        PostgresDatabase,
     },
 };
 use sqlx::{Execute, Postgres, QueryBuilder, Row};
@@ -24,6 +25,8 @@ use uuid::Uuid;
 pub struct PgBusinessListingRepository;
 
 impl PgBusinessListingRepository {
+    /// Inserts new versions of the given business listings, visible from the given game tick on.
+    /// The current versions of these business listings are superseded in the same statement.
     /// This leverages PostgreSQL's UNNEST function for efficiency.
     pub async fn insert_business_listings_in_tick(
         &self,
@@ -42,27 +45,37 @@ impl PgBusinessListingRepository {
         let mut business_uuids = Vec::with_capacity(count);
         let mut seller_corporation_uuids = Vec::with_capacity(count);
         let mut asking_prices = Vec::with_capacity(count);
+        let mut auction_closes_at_ticks = Vec::with_capacity(count);
 
         for bl in business_listings {
             uuids.push(bl.uuid);
             business_uuids.push(bl.business_uuid);
             seller_corporation_uuids.push(bl.seller_corporation_uuid);
             asking_prices.push(bl.asking_price);
+            auction_closes_at_ticks.push(bl.auction_closes_at_tick);
         }
 
         // Execute the bulk insert query using UNNEST
         sqlx::query(
             r#"
+            WITH superseded AS (
+                UPDATE business_listings
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
             INSERT INTO business_listings (
                 game_tick,
                 uuid,
                 business_uuid,
                 seller_corporation_uuid,
-                asking_price
+                asking_price,
+                auction_closes_at_tick
             )
             SELECT $1, u.*
-            FROM unnest($2::UUID[], $3::UUID[], $4::UUID[], $5::BIGINT[])
-            AS u(uuid, business_uuid, seller_corporation_uuid, asking_price)
+            FROM unnest($2::UUID[], $3::UUID[], $4::UUID[], $5::BIGINT[], $6::BIGINT[])
+            AS u(uuid, business_uuid, seller_corporation_uuid, asking_price, auction_closes_at_tick)
             "#,
         )
         .bind(game_tick) // Binds to $1 -> game_tick column
@@ -70,6 +83,7 @@ impl PgBusinessListingRepository {
         .bind(&business_uuids) // Binds to $3 -> u.business_uuid -> business_uuid column
         .bind(&seller_corporation_uuids) // Binds to $4 -> u.seller_corporation_uuid -> seller_corporation_uuid column
         .bind(&asking_prices) // Binds to $5 -> u.asking_price -> asking_price column
+        .bind(&auction_closes_at_ticks) // Binds to $6 -> u.auction_closes_at_tick -> auction_closes_at_tick column
         .execute(executor)
         .await?;
 
@@ -92,6 +106,7 @@ impl PgBusinessListingRepository {
                 m.name AS market_name_i16,
                 bl.seller_corporation_uuid,
                 bl.asking_price,
+                bl.auction_closes_at_tick,
                 b.operational_expenses,
                 hb.gml_id AS headquarter_building_gml_id,
                 ST_X(hb.center) AS headquarter_longitude,
@@ -99,14 +114,14 @@ impl PgBusinessListingRepository {
                 b.image_number,
                 m.name AS market_number
             FROM business_listings bl
-            JOIN businesses b ON bl.business_uuid = b.uuid AND b.game_tick = "#,
+            JOIN businesses b ON bl.business_uuid = b.uuid AND "#,
         );
-        qb.push_bind(game_tick);
-        qb.push(" JOIN markets m ON b.market_uuid = m.uuid AND m.game_tick = ");
-        qb.push_bind(game_tick);
+        push_visible_in_tick(&mut qb, "b", game_tick);
+        qb.push(" JOIN markets m ON b.market_uuid = m.uuid AND ");
+        push_visible_in_tick(&mut qb, "m", game_tick);
         qb.push(" JOIN buildings hb ON b.headquarter_building_uuid = hb.uuid");
-        qb.push(" WHERE bl.game_tick = ");
-        qb.push_bind(game_tick);
+        qb.push(" WHERE ");
+        push_visible_in_tick(&mut qb, "bl", game_tick);
 
         // Build WHERE clause dynamically
         if let Some(min_price) = req.min_asking_price {
@@ -144,6 +159,13 @@ impl PgBusinessListingRepository {
             qb.push(" AND b.operational_expenses <= ");
             qb.push_bind(max_op_ex);
         }
+        if let Some(is_auction) = req.is_auction {
+            if is_auction {
+                qb.push(" AND bl.auction_closes_at_tick IS NOT NULL");
+            } else {
+                qb.push(" AND bl.auction_closes_at_tick IS NULL");
+            }
+        }
 
         // --- Clone the builder for COUNT(*) query before adding ORDER BY, LIMIT, OFFSET ---
         // --- Add Sorting ---
@@ -191,6 +213,7 @@ impl PgBusinessListingRepository {
                     market_uuid: row.get("market_uuid"),
                     market_name,
                     asking_price: row.get("asking_price"),
+                    auction_closes_at_tick: row.get("auction_closes_at_tick"),
                     operational_expenses: row.get("operational_expenses"),
                     headquarter_building_gml_id: row.get("headquarter_building_gml_id"),
                     headquarter_longitude: row.get("headquarter_longitude"),
@@ -215,13 +238,15 @@ impl PgBusinessListingRepository {
             SELECT
                 uuid,
                 business_uuid,
-                seller_corporation_uuid as "seller_corporation_uuid?",
-                asking_price
+                seller_corporation_uuid,
+                asking_price,
+                auction_closes_at_tick
             FROM business_listings
             WHERE
-                game_tick = $1
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
             "#,
-            game_tick
+            game_tick,
         )
         .fetch_all(executor)
         .await?;
@@ -229,18 +254,51 @@ impl PgBusinessListingRepository {
         Ok(business_listings)
     }
 
+    /// Ends the current versions of the given business listings at the given game tick.
+    pub async fn supersede_business_listings_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE business_listings
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the business listing versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
     pub async fn delete_business_listings_before_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         let result = sqlx::query!(
             r#"
             DELETE FROM business_listings
             WHERE
-                game_tick < $1
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
             "#,
-            game_tick
+            game_tick,
+            snapshot_interval,
         )
         .execute(executor)
         .await?;
@@ -253,14 +311,16 @@ pub struct PgBusinessListingService {
     pg_db: Arc<PostgresDatabase>,
     game_tick_repo: PgGameTickRepository,
     business_listing_repo: PgBusinessListingRepository,
+    tick_retention: TickRetention,
 }
 
 impl PgBusinessListingService {
-    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+    pub fn new(pg_db: Arc<PostgresDatabase>, tick_retention: TickRetention) -> Self {
         Self {
             pg_db,
             game_tick_repo: PgGameTickRepository,
             business_listing_repo: PgBusinessListingRepository,
+            tick_retention,
         }
     }
 }
@@ -282,7 +342,7 @@ impl BusinessListingRepository for PgBusinessListingService {
     ) -> RepositoryResult<(i64, Vec<BusinessListingDetails>)> {
         let game_tick = self
             .game_tick_repo
-            .get_current_game_tick(&self.pg_db.pool)
+            .resolve_game_tick(&self.pg_db.pool, req.at_game_tick, self.tick_retention)
             .await?;
 
         let result = self
@@ -306,12 +366,23 @@ impl BusinessListingTxRepository for PgTransactionContext<'_, '_> {
             .await
     }
 
+    async fn supersede_business_listings_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.business_listing_repo
+            .supersede_business_listings_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
     async fn delete_business_listings_before_tick(
         &mut self,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         self.business_listing_repo
-            .delete_business_listings_before_tick(&mut **self.tx, game_tick)
+            .delete_business_listings_before_tick(&mut **self.tx, game_tick, snapshot_interval)
             .await
     }
 }
@@ -10,11 +10,14 @@ use crate::{
 };
 use sqlx::Postgres;
 use std::sync::Arc;
+use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct PgBusinessOfferRepository;
 
 impl PgBusinessOfferRepository {
+    /// Inserts new versions of the given business offers, visible from the given game tick on.
+    /// The current versions of these business offers are superseded in the same statement.
     /// This leverages PostgreSQL's UNNEST function for efficiency.
     pub async fn insert_business_offers_in_tick(
         &self,
@@ -34,6 +37,7 @@ impl PgBusinessOfferRepository {
         let mut offering_corporation_uuids = Vec::with_capacity(count);
         let mut target_corporation_uuids = Vec::with_capacity(count);
         let mut offer_prices = Vec::with_capacity(count);
+        let mut expires_at_ticks = Vec::with_capacity(count);
 
         for bo in business_offers {
             uuids.push(bo.uuid);
@@ -41,30 +45,40 @@ impl PgBusinessOfferRepository {
             offering_corporation_uuids.push(bo.offering_corporation_uuid);
             target_corporation_uuids.push(bo.target_corporation_uuid);
             offer_prices.push(bo.offer_price);
+            expires_at_ticks.push(bo.expires_at_tick);
         }
 
         // Execute the bulk insert query using UNNEST
         sqlx::query(
             r#"
-            INSERT INTO business_listings (
+            WITH superseded AS (
+                UPDATE business_offers
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
+            INSERT INTO business_offers (
                 game_tick,
                 uuid,
                 business_uuid,
                 offering_corporation_uuid,
                 target_corporation_uuid,
-                offer_price
+                offer_price,
+                expires_at_tick
             )
             SELECT $1, u.*
-            FROM unnest($2::UUID[], $3::UUID[], $4::UUID[], $5::UUID[],  $6::BIGINT[])
-            AS u(uuid, business_uuid, offering_corporation_uuid, target_corporation_uuid, offer_price)
+            FROM unnest($2::UUID[], $3::UUID[], $4::UUID[], $5::UUID[], $6::BIGINT[], $7::BIGINT[])
+            AS u(uuid, business_uuid, offering_corporation_uuid, target_corporation_uuid, offer_price, expires_at_tick)
             "#,
         )
-        .bind(game_tick) // Binds to tick_number column via $1
+        .bind(game_tick) // Binds to game_tick column via $1
         .bind(&uuids) // Binds to $2 -> u.uuid -> uuid column
-        .bind(&business_uuids) // Binds to $3 -> u.market_uuid -> market_uuid column
-        .bind(&offering_corporation_uuids) // Binds to $4 -> u.owning_corporation_uuid -> owning_corporation_uuid column
-        .bind(&target_corporation_uuids) // Binds to $4 -> u.owning_corporation_uuid -> owning_corporation_uuid column
-        .bind(&offer_prices) // Binds to $6 -> u.operational_expenses -> operational_expenses column
+        .bind(&business_uuids) // Binds to $3 -> u.business_uuid -> business_uuid column
+        .bind(&offering_corporation_uuids) // Binds to $4 -> u.offering_corporation_uuid -> offering_corporation_uuid column
+        .bind(&target_corporation_uuids) // Binds to $5 -> u.target_corporation_uuid -> target_corporation_uuid column
+        .bind(&offer_prices) // Binds to $6 -> u.offer_price -> offer_price column
+        .bind(&expires_at_ticks) // Binds to $7 -> u.expires_at_tick -> expires_at_tick column
         .execute(executor)
         .await?;
 
@@ -84,10 +98,12 @@ impl PgBusinessOfferRepository {
                 business_uuid,
                 offering_corporation_uuid,
                 target_corporation_uuid,
-                offer_price
+                offer_price,
+                expires_at_tick
             FROM business_offers
             WHERE
-                game_tick = $1
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
             "#,
             game_tick
         )
@@ -97,18 +113,51 @@ impl PgBusinessOfferRepository {
         Ok(business_offers)
     }
 
+    /// Ends the current versions of the given business offers at the given game tick.
+    pub async fn supersede_business_offers_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE business_offers
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the business offer versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
     pub async fn delete_business_offers_before_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         let result = sqlx::query!(
             r#"
             DELETE FROM business_offers
             WHERE
-                game_tick < $1
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
             "#,
-            game_tick
+            game_tick,
+            snapshot_interval,
         )
         .execute(executor)
         .await?;
@@ -155,12 +204,23 @@ impl BusinessOfferTxRepository for PgTransactionContext<'_, '_> {
             .await
     }
 
+    async fn supersede_business_offers_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.business_offer_repo
+            .supersede_business_offers_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
     async fn delete_business_offers_before_tick(
         &mut self,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         self.business_offer_repo
-            .delete_business_offers_before_tick(&mut **self.tx, game_tick)
+            .delete_business_offers_before_tick(&mut **self.tx, game_tick, snapshot_interval)
             .await
     }
 }
@@ -0,0 +1,265 @@
+use crate::{
+    domain::{
+        economy::contract::{
+            model::{condition::ContractCondition, Contract},
+            repository::{ContractRepository, ContractTxRepository},
+        },
+        repository::{RepositoryError, RepositoryResult},
+    },
+    infrastructure::postgres::{uow::PgTransactionContext, PostgresDatabase},
+};
+use sqlx::Postgres;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// The condition of a contract is stored as its kind and the business it refers to.
+struct ContractRow {
+    uuid: Uuid,
+    payer_corporation_uuid: Uuid,
+    payee_corporation_uuid: Uuid,
+    amount: i64,
+    condition_kind: i16,
+    condition_business_uuid: Option<Uuid>,
+    created_at_tick: i64,
+    due_at_tick: i64,
+}
+
+impl TryFrom<ContractRow> for Contract {
+    type Error = RepositoryError;
+
+    fn try_from(row: ContractRow) -> Result<Self, Self::Error> {
+        let condition =
+            ContractCondition::from_parts(row.condition_kind, row.condition_business_uuid)
+                .map_err(|err| anyhow::anyhow!("Invalid contract '{}': {}", row.uuid, err))?;
+
+        Ok(Self {
+            uuid: row.uuid,
+            payer_corporation_uuid: row.payer_corporation_uuid,
+            payee_corporation_uuid: row.payee_corporation_uuid,
+            amount: row.amount,
+            condition,
+            created_at_tick: row.created_at_tick,
+            due_at_tick: row.due_at_tick,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct PgContractRepository;
+
+impl PgContractRepository {
+    /// Inserts new versions of the given contracts, visible from the given game tick on.
+    /// The current versions of these contracts are superseded in the same statement.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
+    pub async fn insert_contracts_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        contracts: &[Contract],
+        game_tick: i64,
+    ) -> RepositoryResult<()> {
+        if contracts.is_empty() {
+            return Ok(());
+        }
+
+        // Prepare separate vectors for each column to be bulk inserted.
+        // Pre-allocate capacity for efficiency.
+        let count = contracts.len();
+        let mut uuids = Vec::with_capacity(count);
+        let mut payer_corporation_uuids = Vec::with_capacity(count);
+        let mut payee_corporation_uuids = Vec::with_capacity(count);
+        let mut amounts = Vec::with_capacity(count);
+        let mut condition_kinds = Vec::with_capacity(count);
+        let mut condition_business_uuids: Vec<Option<Uuid>> = Vec::with_capacity(count);
+        let mut created_at_ticks = Vec::with_capacity(count);
+        let mut due_at_ticks = Vec::with_capacity(count);
+
+        for contract in contracts {
+            uuids.push(contract.uuid);
+            payer_corporation_uuids.push(contract.payer_corporation_uuid);
+            payee_corporation_uuids.push(contract.payee_corporation_uuid);
+            amounts.push(contract.amount);
+            condition_kinds.push(contract.condition.kind());
+            condition_business_uuids.push(contract.condition.business_uuid());
+            created_at_ticks.push(contract.created_at_tick);
+            due_at_ticks.push(contract.due_at_tick);
+        }
+
+        // Execute the bulk insert query using UNNEST
+        sqlx::query(
+            r#"
+            WITH superseded AS (
+                UPDATE contracts
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
+            INSERT INTO contracts (
+                game_tick,
+                uuid,
+                payer_corporation_uuid,
+                payee_corporation_uuid,
+                amount,
+                condition_kind,
+                condition_business_uuid,
+                created_at_tick,
+                due_at_tick
+            )
+            SELECT $1, u.*
+            FROM unnest($2::UUID[], $3::UUID[], $4::UUID[], $5::BIGINT[], $6::SMALLINT[], $7::UUID[], $8::BIGINT[], $9::BIGINT[])
+            AS u(uuid, payer_corporation_uuid, payee_corporation_uuid, amount, condition_kind, condition_business_uuid, created_at_tick, due_at_tick)
+            "#,
+        )
+        .bind(game_tick) // Binds to game_tick column via $1
+        .bind(&uuids) // Binds to $2 -> u.uuid -> uuid column
+        .bind(&payer_corporation_uuids) // Binds to $3 -> u.payer_corporation_uuid -> payer_corporation_uuid column
+        .bind(&payee_corporation_uuids) // Binds to $4 -> u.payee_corporation_uuid -> payee_corporation_uuid column
+        .bind(&amounts) // Binds to $5 -> u.amount -> amount column
+        .bind(&condition_kinds) // Binds to $6 -> u.condition_kind -> condition_kind column
+        .bind(&condition_business_uuids) // Binds to $7 -> u.condition_business_uuid -> condition_business_uuid column
+        .bind(&created_at_ticks) // Binds to $8 -> u.created_at_tick -> created_at_tick column
+        .bind(&due_at_ticks) // Binds to $9 -> u.due_at_tick -> due_at_tick column
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_contracts_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<Contract>> {
+        let rows = sqlx::query_as!(
+            ContractRow,
+            r#"
+            SELECT
+                uuid,
+                payer_corporation_uuid,
+                payee_corporation_uuid,
+                amount,
+                condition_kind,
+                condition_business_uuid,
+                created_at_tick,
+                due_at_tick
+            FROM contracts
+            WHERE
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+            "#,
+            game_tick,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        rows.into_iter().map(Contract::try_from).collect()
+    }
+
+    /// Ends the current versions of the given contracts at the given game tick.
+    pub async fn supersede_contracts_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE contracts
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the contract versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
+    pub async fn delete_contracts_before_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM contracts
+            WHERE
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
+            "#,
+            game_tick,
+            snapshot_interval,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+pub struct PgContractService {
+    pg_db: Arc<PostgresDatabase>,
+    contract_repo: PgContractRepository,
+}
+
+impl PgContractService {
+    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+        Self {
+            pg_db,
+            contract_repo: PgContractRepository,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ContractRepository for PgContractService {
+    async fn list_contracts_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Contract>> {
+        self.contract_repo
+            .list_contracts_in_tick(&self.pg_db.pool, game_tick)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl ContractTxRepository for PgTransactionContext<'_, '_> {
+    async fn insert_contracts_in_tick(
+        &mut self,
+        game_tick: i64,
+        contracts: &[Contract],
+    ) -> RepositoryResult<()> {
+        self.contract_repo
+            .insert_contracts_in_tick(&mut **self.tx, contracts, game_tick)
+            .await
+    }
+
+    async fn supersede_contracts_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.contract_repo
+            .supersede_contracts_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_contracts_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        self.contract_repo
+            .delete_contracts_before_tick(&mut **self.tx, game_tick, snapshot_interval)
+            .await
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
     domain::{
+        diplomacy::model::treaty_type::TreatyType,
         economy::corporation::{
-            model::Corporation,
+            model::{visibility::Visibility, Corporation},
             repository::{CorporationRepository, CorporationTxRepository, GetCorporationOutcome},
         },
-        repository::{RepositoryError, RepositoryResult},
+        repository::{RepositoryError, RepositoryResult, TickRetention},
     },
     infrastructure::postgres::{
         game_tick::PgGameTickRepository, uow::PgTransactionContext, PostgresDatabase,
@@ -14,6 +15,14 @@ use sqlx::Postgres;
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Reports a missing corporation as not found instead of a database failure.
+fn map_row_not_found(err: sqlx::Error) -> RepositoryError {
+    match err {
+        sqlx::Error::RowNotFound => RepositoryError::NotFound,
+        _ => RepositoryError::from(err),
+    }
+}
+
 #[derive(Clone)]
 pub struct PgCorporationRepository;
 
@@ -53,8 +62,8 @@ impl PgCorporationRepository {
         Ok(())
     }
 
-    /// Inserts the new state corporations at a specific game tick.
-    /// This is used when advancing the game state from N to N+1.
+    /// Inserts new versions of the given corporations, visible from the given game tick on.
+    /// The current versions of these corporations are superseded in the same statement.
     pub async fn insert_corporations_in_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
@@ -73,36 +82,57 @@ impl PgCorporationRepository {
         let mut user_uuids = Vec::with_capacity(count);
         let mut names = Vec::with_capacity(count);
         let mut cash_balances = Vec::with_capacity(count);
+        let mut insolvent_since_ticks: Vec<Option<i64>> = Vec::with_capacity(count);
 
         for corp in corporations {
             uuids.push(corp.uuid);
             user_uuids.push(corp.user_uuid);
             names.push(corp.name.to_string());
             cash_balances.push(corp.cash_balance);
+            insolvent_since_ticks.push(corp.insolvent_since_tick);
         }
 
         // Execute the bulk insert query using UNNEST
         sqlx::query!(
             r#"
+            WITH superseded AS (
+                UPDATE corporations
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
             INSERT INTO corporations (
                 game_tick,
                 uuid,
                 user_uuid,
                 name,
-                cash_balance
+                cash_balance,
+                insolvent_since_tick
+            )
+            SELECT $1, u.*
+            FROM unnest(
+                $2::UUID[],
+                $3::UUID[],
+                $4::TEXT[],
+                $5::BIGINT[],
+                $6::BIGINT[]
+            )
+            AS u(
+                uuid,
+                user_uuid,
+                name,
+                cash_balance,
+                insolvent_since_tick
             )
-            SELECT
-                $1 as game_tick,
-                unnest($2::UUID[]) as uuid,
-                unnest($3::UUID[]) as user_uuid,
-                unnest($4::TEXT[]) as name,
-                unnest($5::BIGINT[]) as cash_balance
             "#,
             game_tick,
             &uuids,
             &user_uuids,
             &names,
-            &cash_balances
+            &cash_balances,
+            // Nullable arrays cannot be checked by the macro.
+            &insolvent_since_ticks as _,
         )
         .execute(executor)
         .await?;
@@ -125,21 +155,20 @@ impl PgCorporationRepository {
                 uuid,
                 user_uuid,
                 name,
-                cash_balance
+                cash_balance,
+                insolvent_since_tick
             FROM corporations
             WHERE
                 user_uuid = $1
-                AND game_tick = $2
+                AND game_tick <= $2
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $2)
             "#,
             user_uuid,
-            game_tick
+            game_tick,
         )
         .fetch_one(executor)
         .await
-        .map_err(|err| match err {
-            sqlx::Error::RowNotFound => todo!(),
-            _ => RepositoryError::from(err),
-        })?;
+        .map_err(map_row_not_found)?;
 
         Ok(corporation)
     }
@@ -159,21 +188,20 @@ impl PgCorporationRepository {
                 uuid,
                 user_uuid,
                 name,
-                cash_balance
+                cash_balance,
+                insolvent_since_tick
             FROM corporations
             WHERE
                 name = $1
-                AND game_tick = $2
+                AND game_tick <= $2
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $2)
             "#,
             corporation_name,
-            game_tick
+            game_tick,
         )
         .fetch_one(executor)
         .await
-        .map_err(|err| match err {
-            sqlx::Error::RowNotFound => RepositoryError::NotFound,
-            _ => RepositoryError::from(err),
-        })?;
+        .map_err(map_row_not_found)?;
 
         Ok(corporation)
     }
@@ -190,12 +218,14 @@ impl PgCorporationRepository {
                 uuid,
                 user_uuid,
                 name,
-                cash_balance
+                cash_balance,
+                insolvent_since_tick
             FROM corporations
             WHERE
-                game_tick = $1
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
             "#,
-            game_tick
+            game_tick,
         )
         .fetch_all(executor)
         .await?;
@@ -203,18 +233,51 @@ impl PgCorporationRepository {
         Ok(corporations)
     }
 
+    /// Ends the current versions of the given corporations at the given game tick.
+    pub async fn supersede_corporations_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE corporations
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the corporation versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
     pub async fn delete_corporations_before_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         let result = sqlx::query!(
             r#"
             DELETE FROM corporations
             WHERE
-                game_tick < $1
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
             "#,
-            game_tick
+            game_tick,
+            snapshot_interval,
         )
         .execute(executor)
         .await?;
@@ -223,18 +286,103 @@ impl PgCorporationRepository {
     }
 }
 
+impl PgCorporationRepository {
+    /// Collects where the corporation of the user has presence and which rivals it has
+    /// infiltrated at a given game tick.
+    pub async fn get_visibility_by_user_at_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        user_uuid: Uuid,
+        game_tick: i64,
+    ) -> RepositoryResult<Visibility> {
+        let row = sqlx::query!(
+            r#"
+            WITH corporation AS (
+                SELECT uuid
+                FROM corporations
+                WHERE
+                    user_uuid = $1
+                    AND game_tick <= $2
+                    AND (superseded_at_tick IS NULL OR superseded_at_tick > $2)
+            ),
+            owned_businesses AS (
+                SELECT b.uuid, b.market_uuid
+                FROM businesses b
+                JOIN corporation c ON c.uuid = b.owning_corporation_uuid
+                WHERE
+                    b.game_tick <= $2
+                    AND (b.superseded_at_tick IS NULL OR b.superseded_at_tick > $2)
+            )
+            SELECT
+                (SELECT uuid FROM corporation) AS "corporation_uuid?",
+                ARRAY(
+                    SELECT DISTINCT market_uuid FROM owned_businesses
+                ) AS "market_uuids!",
+                ARRAY(
+                    SELECT DISTINCT bui.city_code
+                    FROM building_ownerships bo
+                    JOIN owned_businesses ob ON ob.uuid = bo.owning_business_uuid
+                    JOIN buildings bui ON bui.uuid = bo.building_uuid
+                    WHERE
+                        bui.city_code IS NOT NULL
+                        AND bo.game_tick <= $2
+                        AND (bo.superseded_at_tick IS NULL OR bo.superseded_at_tick > $2)
+                ) AS "city_codes!",
+                ARRAY(
+                    SELECT m.target_corporation_uuid
+                    FROM moles m
+                    JOIN corporation c ON c.uuid = m.spy_corporation_uuid
+                    WHERE
+                        m.game_tick <= $2
+                        AND (m.superseded_at_tick IS NULL OR m.superseded_at_tick > $2)
+                ) AS "infiltrated_corporation_uuids!",
+                ARRAY(
+                    SELECT
+                        CASE
+                            WHEN t.proposer_corporation_uuid = c.uuid THEN t.partner_corporation_uuid
+                            ELSE t.proposer_corporation_uuid
+                        END
+                    FROM treaties t
+                    JOIN corporation c
+                        ON c.uuid IN (t.proposer_corporation_uuid, t.partner_corporation_uuid)
+                    WHERE
+                        t.treaty_type = $3
+                        AND t.accepted_at_tick IS NOT NULL
+                        AND t.game_tick <= $2
+                        AND (t.superseded_at_tick IS NULL OR t.superseded_at_tick > $2)
+                ) AS "allied_corporation_uuids!"
+            "#,
+            user_uuid,
+            game_tick,
+            i16::from(TreatyType::Alliance),
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(Visibility {
+            corporation_uuid: row.corporation_uuid,
+            market_uuids: row.market_uuids.into_iter().collect(),
+            city_codes: row.city_codes.into_iter().collect(),
+            infiltrated_corporation_uuids: row.infiltrated_corporation_uuids.into_iter().collect(),
+            allied_corporation_uuids: row.allied_corporation_uuids.into_iter().collect(),
+        })
+    }
+}
+
 pub struct PgCorporationService {
     pg_db: Arc<PostgresDatabase>,
     game_tick_repo: PgGameTickRepository,
     corporation_repo: PgCorporationRepository,
+    tick_retention: TickRetention,
 }
 
 impl PgCorporationService {
-    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+    pub fn new(pg_db: Arc<PostgresDatabase>, tick_retention: TickRetention) -> Self {
         Self {
             pg_db,
             game_tick_repo: PgGameTickRepository,
             corporation_repo: PgCorporationRepository,
+            tick_retention,
         }
     }
 }
@@ -244,10 +392,11 @@ impl CorporationRepository for PgCorporationService {
     async fn get_corporation_by_user(
         &self,
         user_uuid: Uuid,
+        at_game_tick: Option<i64>,
     ) -> RepositoryResult<GetCorporationOutcome> {
         let game_tick = self
             .game_tick_repo
-            .get_current_game_tick(&self.pg_db.pool)
+            .resolve_game_tick(&self.pg_db.pool, at_game_tick, self.tick_retention)
             .await?;
 
         let corporation = self
@@ -274,6 +423,16 @@ impl CorporationRepository for PgCorporationService {
             .await
     }
 
+    async fn get_visibility_by_user(
+        &self,
+        user_uuid: Uuid,
+        game_tick: i64,
+    ) -> RepositoryResult<Visibility> {
+        self.corporation_repo
+            .get_visibility_by_user_at_tick(&self.pg_db.pool, user_uuid, game_tick)
+            .await
+    }
+
     async fn list_corporations_in_tick(
         &self,
         game_tick: i64,
@@ -307,9 +466,45 @@ impl CorporationTxRepository for PgTransactionContext<'_, '_> {
             .await
     }
 
-    async fn delete_corporations_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64> {
+    async fn supersede_corporations_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.corporation_repo
+            .supersede_corporations_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_corporations_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
         self.corporation_repo
-            .delete_corporations_before_tick(&mut **self.tx, game_tick)
+            .delete_corporations_before_tick(&mut **self.tx, game_tick, snapshot_interval)
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_map_missing_row_to_not_found() {
+        let err = map_row_not_found(sqlx::Error::RowNotFound);
+
+        assert!(matches!(err, RepositoryError::NotFound));
+    }
+
+    #[test]
+    fn should_keep_other_database_errors() {
+        let err = map_row_not_found(sqlx::Error::PoolTimedOut);
+
+        assert!(matches!(
+            err,
+            RepositoryError::Sqlx(sqlx::Error::PoolTimedOut)
+        ));
+    }
+}
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        economy::ledger::{
+            model::LedgerEntry,
+            repository::{LedgerRepository, LedgerTxRepository, QueryLedgerEntriesRequest},
+        },
+        repository::RepositoryResult,
+    },
+    infrastructure::postgres::{uow::PgTransactionContext, PostgresDatabase},
+};
+use sqlx::{Execute, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PgLedgerRepository;
+
+impl PgLedgerRepository {
+    /// Inserts the ledger entries of a tick.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
+    /// CARE: This is not compile time checked
+    pub async fn insert_ledger_entries(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        entries: &[LedgerEntry],
+    ) -> RepositoryResult<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let count = entries.len();
+        let mut game_ticks_vec = Vec::with_capacity(count);
+        let mut sequences_vec = Vec::with_capacity(count);
+        let mut corporation_uuids_vec = Vec::with_capacity(count);
+        let mut kinds_vec: Vec<i16> = Vec::with_capacity(count);
+        let mut amounts_vec = Vec::with_capacity(count);
+        let mut business_uuids_vec: Vec<Option<Uuid>> = Vec::with_capacity(count);
+
+        for entry in entries {
+            game_ticks_vec.push(entry.game_tick);
+            sequences_vec.push(entry.sequence);
+            corporation_uuids_vec.push(entry.corporation_uuid);
+            kinds_vec.push(entry.kind.into());
+            amounts_vec.push(entry.amount);
+            business_uuids_vec.push(entry.business_uuid);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO corporation_ledger_entries (
+                game_tick,
+                sequence,
+                corporation_uuid,
+                kind,
+                amount,
+                business_uuid
+            )
+            SELECT * FROM unnest(
+                $1::BIGINT[],
+                $2::INTEGER[],
+                $3::UUID[],
+                $4::SMALLINT[],
+                $5::BIGINT[],
+                $6::UUID[]
+            )
+            "#,
+        )
+        .bind(&game_ticks_vec)
+        .bind(&sequences_vec)
+        .bind(&corporation_uuids_vec)
+        .bind(&kinds_vec)
+        .bind(&amounts_vec)
+        .bind(&business_uuids_vec)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the entries of the ticks before the given game tick,
+    /// except for the ones of ticks divisible by the snapshot interval.
+    pub async fn delete_ledger_entries_before_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM corporation_ledger_entries
+            WHERE
+                game_tick < $1
+                AND ($2::BIGINT <= 0 OR game_tick % $2 <> 0)
+            "#,
+            game_tick,
+            snapshot_interval,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn query_ledger_entries(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres> + Copy,
+        req: &QueryLedgerEntriesRequest,
+    ) -> RepositoryResult<(i64, Vec<LedgerEntry>)> {
+        // The filters are shared by the count and the page query
+        let push_filters = |qb: &mut QueryBuilder<'_, Postgres>| {
+            qb.push(" WHERE corporation_uuid = ");
+            qb.push_bind(req.corporation_uuid);
+
+            if let Some(from_game_tick) = req.from_game_tick {
+                qb.push(" AND game_tick >= ");
+                qb.push_bind(from_game_tick);
+            }
+
+            if let Some(to_game_tick) = req.to_game_tick {
+                qb.push(" AND game_tick <= ");
+                qb.push_bind(to_game_tick);
+            }
+        };
+
+        let mut count_qb = QueryBuilder::new("SELECT COUNT(*) FROM corporation_ledger_entries");
+        push_filters(&mut count_qb);
+        let total_count: i64 = count_qb.build_query_scalar().fetch_one(executor).await?;
+
+        let mut qb = QueryBuilder::new(
+            r#"
+            SELECT
+                game_tick,
+                sequence,
+                corporation_uuid,
+                kind,
+                amount,
+                business_uuid
+            FROM corporation_ledger_entries"#,
+        );
+        push_filters(&mut qb);
+        qb.push(" ORDER BY game_tick DESC, sequence DESC");
+
+        // --- Add Pagination ---
+        let limit = req.limit.unwrap_or(10).min(100);
+        qb.push(" LIMIT ");
+        qb.push_bind(limit);
+
+        if let Some(offset_val) = req.offset {
+            if offset_val > 0 {
+                qb.push(" OFFSET ");
+                qb.push_bind(offset_val);
+            }
+        }
+
+        // --- Execute Query ---
+        let query = qb.build_query_as::<LedgerEntry>();
+        tracing::debug!("Executing query: {}", query.sql());
+        let entries = query.fetch_all(executor).await?;
+
+        Ok((total_count, entries))
+    }
+}
+
+pub struct PgLedgerService {
+    pg_db: Arc<PostgresDatabase>,
+    ledger_repo: PgLedgerRepository,
+}
+
+impl PgLedgerService {
+    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+        Self {
+            pg_db,
+            ledger_repo: PgLedgerRepository,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl LedgerRepository for PgLedgerService {
+    async fn query_ledger_entries(
+        &self,
+        req: &QueryLedgerEntriesRequest,
+    ) -> RepositoryResult<(i64, Vec<LedgerEntry>)> {
+        self.ledger_repo
+            .query_ledger_entries(&self.pg_db.pool, req)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl LedgerTxRepository for PgTransactionContext<'_, '_> {
+    async fn insert_ledger_entries(&mut self, entries: &[LedgerEntry]) -> RepositoryResult<()> {
+        self.ledger_repo
+            .insert_ledger_entries(&mut **self.tx, entries)
+            .await
+    }
+
+    async fn delete_ledger_entries_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        self.ledger_repo
+            .delete_ledger_entries_before_tick(&mut **self.tx, game_tick, snapshot_interval)
+            .await
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{
+    domain::{
+        economy::loan::{
+            model::Loan,
+            repository::{LoanRepository, LoanTxRepository},
+        },
+        repository::RepositoryResult,
+    },
+    infrastructure::postgres::{uow::PgTransactionContext, PostgresDatabase},
+};
+use sqlx::Postgres;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PgLoanRepository;
+
+impl PgLoanRepository {
+    /// Inserts new versions of the given loans, visible from the given game tick on.
+    /// The current versions of these loans are superseded in the same statement.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
+    pub async fn insert_loans_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        loans: &[Loan],
+        game_tick: i64,
+    ) -> RepositoryResult<()> {
+        if loans.is_empty() {
+            return Ok(());
+        }
+
+        // Prepare separate vectors for each column to be bulk inserted.
+        // Pre-allocate capacity for efficiency.
+        let count = loans.len();
+        let mut uuids = Vec::with_capacity(count);
+        let mut corporation_uuids = Vec::with_capacity(count);
+        let mut principals = Vec::with_capacity(count);
+        let mut outstanding_balances = Vec::with_capacity(count);
+        let mut interest_rates_bps = Vec::with_capacity(count);
+        let mut taken_at_ticks = Vec::with_capacity(count);
+        let mut due_at_ticks = Vec::with_capacity(count);
+
+        for loan in loans {
+            uuids.push(loan.uuid);
+            corporation_uuids.push(loan.corporation_uuid);
+            principals.push(loan.principal);
+            outstanding_balances.push(loan.outstanding_balance);
+            interest_rates_bps.push(loan.interest_rate_bps);
+            taken_at_ticks.push(loan.taken_at_tick);
+            due_at_ticks.push(loan.due_at_tick);
+        }
+
+        // Execute the bulk insert query using UNNEST
+        sqlx::query(
+            r#"
+            WITH superseded AS (
+                UPDATE loans
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
+            INSERT INTO loans (
+                game_tick,
+                uuid,
+                corporation_uuid,
+                principal,
+                outstanding_balance,
+                interest_rate_bps,
+                taken_at_tick,
+                due_at_tick
+            )
+            SELECT $1, u.*
+            FROM unnest($2::UUID[], $3::UUID[], $4::BIGINT[], $5::BIGINT[], $6::BIGINT[], $7::BIGINT[], $8::BIGINT[])
+            AS u(uuid, corporation_uuid, principal, outstanding_balance, interest_rate_bps, taken_at_tick, due_at_tick)
+            "#,
+        )
+        .bind(game_tick) // Binds to game_tick column via $1
+        .bind(&uuids) // Binds to $2 -> u.uuid -> uuid column
+        .bind(&corporation_uuids) // Binds to $3 -> u.corporation_uuid -> corporation_uuid column
+        .bind(&principals) // Binds to $4 -> u.principal -> principal column
+        .bind(&outstanding_balances) // Binds to $5 -> u.outstanding_balance -> outstanding_balance column
+        .bind(&interest_rates_bps) // Binds to $6 -> u.interest_rate_bps -> interest_rate_bps column
+        .bind(&taken_at_ticks) // Binds to $7 -> u.taken_at_tick -> taken_at_tick column
+        .bind(&due_at_ticks) // Binds to $8 -> u.due_at_tick -> due_at_tick column
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_loans_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<Loan>> {
+        let loans = sqlx::query_as!(
+            Loan,
+            r#"
+            SELECT
+                uuid,
+                corporation_uuid,
+                principal,
+                outstanding_balance,
+                interest_rate_bps,
+                taken_at_tick,
+                due_at_tick
+            FROM loans
+            WHERE
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+            "#,
+            game_tick,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(loans)
+    }
+
+    /// Ends the current versions of the given loans at the given game tick.
+    pub async fn supersede_loans_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE loans
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the loan versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
+    pub async fn delete_loans_before_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM loans
+            WHERE
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
+            "#,
+            game_tick,
+            snapshot_interval,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+pub struct PgLoanService {
+    pg_db: Arc<PostgresDatabase>,
+    loan_repo: PgLoanRepository,
+}
+
+impl PgLoanService {
+    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+        Self {
+            pg_db,
+            loan_repo: PgLoanRepository,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl LoanRepository for PgLoanService {
+    async fn list_loans_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Loan>> {
+        self.loan_repo
+            .list_loans_in_tick(&self.pg_db.pool, game_tick)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl LoanTxRepository for PgTransactionContext<'_, '_> {
+    async fn insert_loans_in_tick(
+        &mut self,
+        game_tick: i64,
+        loans: &[Loan],
+    ) -> RepositoryResult<()> {
+        self.loan_repo
+            .insert_loans_in_tick(&mut **self.tx, loans, game_tick)
+            .await
+    }
+
+    async fn supersede_loans_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.loan_repo
+            .supersede_loans_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_loans_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        self.loan_repo
+            .delete_loans_before_tick(&mut **self.tx, game_tick, snapshot_interval)
+            .await
+    }
+}
@@ -3,19 +3,22 @@ use std::sync::Arc;
 use crate::{
     domain::{
         economy::market::{
-            model::Market,
-            repository::{MarketRepository, MarketTxRepository},
+            model::{Market, MarketSnapshot},
+            repository::{MarketRepository, MarketTxRepository, QueryMarketsRequest},
         },
         repository::RepositoryResult,
     },
     infrastructure::postgres::{uow::PgTransactionContext, PostgresDatabase},
 };
-use sqlx::Postgres;
+use sqlx::{Execute, Postgres, QueryBuilder};
+use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct PgMarketRepository;
 
 impl PgMarketRepository {
+    /// Inserts new versions of the given markets, visible from the given game tick on.
+    /// The current versions of these markets are superseded in the same statement.
     /// This leverages PostgreSQL's UNNEST function for efficiency.
     pub async fn insert_markets_in_tick(
         &self,
@@ -43,22 +46,35 @@ impl PgMarketRepository {
         // Execute the bulk insert query using UNNEST
         sqlx::query!(
             r#"
+            WITH superseded AS (
+                UPDATE markets
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
             INSERT INTO markets (
                 game_tick,
                 uuid,
                 name,
                 volume
             )
-            SELECT
-                $1 as game_tick,
-                unnest($2::UUID[]) as uuid,
-                unnest($3::SMALLINT[]) as name,
-                unnest($4::BIGINT[]) as volume
+            SELECT $1, u.*
+            FROM unnest(
+                $2::UUID[],
+                $3::SMALLINT[],
+                $4::BIGINT[]
+            )
+            AS u(
+                uuid,
+                name,
+                volume
+            )
             "#,
             game_tick,
             &uuids,
             &names,
-            &volumes
+            &volumes,
         )
         .execute(executor)
         .await?;
@@ -80,9 +96,10 @@ impl PgMarketRepository {
                 volume
             FROM markets
             WHERE
-                game_tick = $1
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
             "#,
-            game_tick
+            game_tick,
         )
         .fetch_all(executor)
         .await?;
@@ -90,24 +107,181 @@ impl PgMarketRepository {
         Ok(markets)
     }
 
+    /// Ends the current versions of the given markets at the given game tick.
+    pub async fn supersede_markets_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE markets
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the market versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
     pub async fn delete_markets_before_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         let result = sqlx::query!(
             r#"
             DELETE FROM markets
             WHERE
-                game_tick < $1
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
             "#,
-            game_tick
+            game_tick,
+            snapshot_interval,
         )
         .execute(executor)
         .await?;
 
         Ok(result.rows_affected())
     }
+
+    /// Inserts the market snapshots of a tick.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
+    /// CARE: This is not compile time checked
+    pub async fn insert_market_snapshots(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        snapshots: &[MarketSnapshot],
+    ) -> RepositoryResult<()> {
+        if snapshots.is_empty() {
+            return Ok(());
+        }
+
+        let count = snapshots.len();
+        let mut game_ticks_vec = Vec::with_capacity(count);
+        let mut market_uuids_vec = Vec::with_capacity(count);
+        let mut market_names_vec: Vec<i16> = Vec::with_capacity(count);
+        let mut volumes_vec = Vec::with_capacity(count);
+        let mut invested_operational_expenses_vec = Vec::with_capacity(count);
+        let mut active_businesses_vec = Vec::with_capacity(count);
+
+        for snapshot in snapshots {
+            game_ticks_vec.push(snapshot.game_tick);
+            market_uuids_vec.push(snapshot.market_uuid);
+            market_names_vec.push(snapshot.market_name.into());
+            volumes_vec.push(snapshot.volume);
+            invested_operational_expenses_vec.push(snapshot.invested_operational_expenses);
+            active_businesses_vec.push(snapshot.active_businesses);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO market_snapshots (
+                game_tick,
+                market_uuid,
+                market_name,
+                volume,
+                invested_operational_expenses,
+                active_businesses
+            )
+            SELECT * FROM unnest(
+                $1::BIGINT[],
+                $2::UUID[],
+                $3::SMALLINT[],
+                $4::BIGINT[],
+                $5::BIGINT[],
+                $6::BIGINT[]
+            )
+            "#,
+        )
+        .bind(&game_ticks_vec)
+        .bind(&market_uuids_vec)
+        .bind(&market_names_vec)
+        .bind(&volumes_vec)
+        .bind(&invested_operational_expenses_vec)
+        .bind(&active_businesses_vec)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn query_market_snapshots(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres> + Copy,
+        req: &QueryMarketsRequest,
+    ) -> RepositoryResult<(i64, Vec<MarketSnapshot>)> {
+        // The filters are shared by the count and the page query
+        let push_filters = |qb: &mut QueryBuilder<'_, Postgres>| {
+            qb.push(" WHERE TRUE");
+
+            if let Some(market_uuid) = req.market_uuid {
+                qb.push(" AND market_uuid = ");
+                qb.push_bind(market_uuid);
+            }
+
+            if let Some(from_game_tick) = req.from_game_tick {
+                qb.push(" AND game_tick >= ");
+                qb.push_bind(from_game_tick);
+            }
+
+            if let Some(to_game_tick) = req.to_game_tick {
+                qb.push(" AND game_tick <= ");
+                qb.push_bind(to_game_tick);
+            }
+        };
+
+        let mut count_qb = QueryBuilder::new("SELECT COUNT(*) FROM market_snapshots");
+        push_filters(&mut count_qb);
+        let total_count: i64 = count_qb.build_query_scalar().fetch_one(executor).await?;
+
+        let mut qb = QueryBuilder::new(
+            r#"
+            SELECT
+                game_tick,
+                market_uuid,
+                market_name,
+                volume,
+                invested_operational_expenses,
+                active_businesses
+            FROM market_snapshots"#,
+        );
+        push_filters(&mut qb);
+        qb.push(" ORDER BY game_tick DESC, market_name ASC");
+
+        // --- Add Pagination ---
+        let limit = req.limit.unwrap_or(10).min(100);
+        qb.push(" LIMIT ");
+        qb.push_bind(limit);
+
+        if let Some(offset_val) = req.offset {
+            if offset_val > 0 {
+                qb.push(" OFFSET ");
+                qb.push_bind(offset_val);
+            }
+        }
+
+        // --- Execute Query ---
+        let query = qb.build_query_as::<MarketSnapshot>();
+        tracing::debug!("Executing query: {}", query.sql());
+        let snapshots = query.fetch_all(executor).await?;
+
+        Ok((total_count, snapshots))
+    }
 }
 
 pub struct PgMarketService {
@@ -131,6 +305,15 @@ impl MarketRepository for PgMarketService {
             .list_markets_at_tick(&self.pg_db.pool, game_tick)
             .await
     }
+
+    async fn query_market_snapshots(
+        &self,
+        req: &QueryMarketsRequest,
+    ) -> RepositoryResult<(i64, Vec<MarketSnapshot>)> {
+        self.market_repo
+            .query_market_snapshots(&self.pg_db.pool, req)
+            .await
+    }
 }
 
 #[tonic::async_trait]
@@ -145,9 +328,32 @@ impl MarketTxRepository for PgTransactionContext<'_, '_> {
             .await
     }
 
-    async fn delete_markets_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64> {
+    async fn supersede_markets_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.market_repo
+            .supersede_markets_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_markets_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        self.market_repo
+            .delete_markets_before_tick(&mut **self.tx, game_tick, snapshot_interval)
+            .await
+    }
+
+    async fn insert_market_snapshots(
+        &mut self,
+        snapshots: &[MarketSnapshot],
+    ) -> RepositoryResult<()> {
         self.market_repo
-            .delete_markets_before_tick(&mut **self.tx, game_tick)
+            .insert_market_snapshots(&mut **self.tx, snapshots)
             .await
     }
 }
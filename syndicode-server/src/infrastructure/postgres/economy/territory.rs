@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        economy::territory::{
+            model::Ward,
+            repository::{TerritoryRepository, TerritoryTxRepository, WardVolume},
+        },
+        repository::{RepositoryError, RepositoryResult, TickRetention},
+    },
+    infrastructure::postgres::{
+        game_tick::PgGameTickRepository, push_visible_in_tick, uow::PgTransactionContext,
+        PostgresDatabase, SRID,
+    },
+};
+use geo::Polygon;
+use sqlx::{Executor, Postgres, QueryBuilder};
+use wkt::TryFromWkt;
+
+struct WardRecord {
+    city_code: String,
+    name: String,
+    boundary: String,
+}
+
+impl TryFrom<WardRecord> for Ward {
+    type Error = RepositoryError;
+
+    fn try_from(record: WardRecord) -> Result<Self, Self::Error> {
+        let boundary = Polygon::try_from_wkt_str(&record.boundary).map_err(|err| {
+            anyhow::anyhow!(
+                "Failed to parse the boundary of ward '{}': {err}",
+                record.city_code
+            )
+        })?;
+
+        Ok(Self {
+            city_code: record.city_code,
+            name: record.name,
+            boundary,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct PgTerritoryRepository;
+
+impl PgTerritoryRepository {
+    pub async fn insert_wards_from_buildings(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+    ) -> RepositoryResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO wards (city_code, name, boundary)
+            SELECT city_code, name, hull
+            FROM (
+                SELECT
+                    city_code,
+                    MAX(city) AS name,
+                    ST_SetSRID(ST_ConcaveHull(ST_Collect(center), 0.1), $1) AS hull
+                FROM buildings
+                WHERE city_code IS NOT NULL
+                GROUP BY city_code
+            ) AS hulls
+            -- Wards with too few buildings to span an area are left out
+            WHERE GeometryType(hull) = 'POLYGON'
+            ON CONFLICT (city_code) DO NOTHING
+            "#,
+        )
+        .bind(SRID)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_wards(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+    ) -> RepositoryResult<Vec<Ward>> {
+        let records = sqlx::query_as!(
+            WardRecord,
+            r#"
+            SELECT city_code, name, ST_AsText(boundary) AS "boundary!"
+            FROM wards
+            ORDER BY city_code
+            "#,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        records.into_iter().map(Ward::try_from).collect()
+    }
+
+    pub async fn list_ward_volumes(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<WardVolume>> {
+        let mut qb = QueryBuilder::new(
+            r#"
+            SELECT
+                bui.city_code,
+                b.owning_corporation_uuid AS corporation_uuid,
+                SUM(ROUND(bui.volume))::BIGINT AS volume
+            FROM building_ownerships bo
+            JOIN buildings bui ON bui.uuid = bo.building_uuid
+            JOIN businesses b ON b.uuid = bo.owning_business_uuid AND 
+            "#,
+        );
+        push_visible_in_tick(&mut qb, "b", game_tick);
+        qb.push(" WHERE ");
+        push_visible_in_tick(&mut qb, "bo", game_tick);
+        qb.push(
+            r#"
+            AND bui.city_code IS NOT NULL
+            AND b.owning_corporation_uuid IS NOT NULL
+            GROUP BY bui.city_code, b.owning_corporation_uuid
+            "#,
+        );
+
+        let volumes = qb
+            .build_query_as::<WardVolume>()
+            .fetch_all(executor)
+            .await?;
+
+        Ok(volumes)
+    }
+}
+
+pub struct PgTerritoryService {
+    pg_db: Arc<PostgresDatabase>,
+    game_tick_repo: PgGameTickRepository,
+    territory_repo: PgTerritoryRepository,
+    tick_retention: TickRetention,
+}
+
+impl PgTerritoryService {
+    pub fn new(pg_db: Arc<PostgresDatabase>, tick_retention: TickRetention) -> Self {
+        Self {
+            pg_db,
+            game_tick_repo: PgGameTickRepository,
+            territory_repo: PgTerritoryRepository,
+            tick_retention,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl TerritoryRepository for PgTerritoryService {
+    async fn list_wards(&self) -> RepositoryResult<Vec<Ward>> {
+        self.territory_repo.list_wards(&self.pg_db.pool).await
+    }
+
+    async fn list_ward_volumes(
+        &self,
+        at_game_tick: Option<i64>,
+    ) -> RepositoryResult<(i64, Vec<WardVolume>)> {
+        let game_tick = self
+            .game_tick_repo
+            .resolve_game_tick(&self.pg_db.pool, at_game_tick, self.tick_retention)
+            .await?;
+
+        let volumes = self
+            .territory_repo
+            .list_ward_volumes(&self.pg_db.pool, game_tick)
+            .await?;
+
+        Ok((game_tick, volumes))
+    }
+}
+
+#[tonic::async_trait]
+impl TerritoryTxRepository for PgTransactionContext<'_, '_> {
+    async fn insert_wards_from_buildings(&mut self) -> RepositoryResult<()> {
+        self.territory_repo
+            .insert_wards_from_buildings(&mut **self.tx)
+            .await
+    }
+}
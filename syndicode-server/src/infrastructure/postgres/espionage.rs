@@ -0,0 +1,211 @@
+use super::{uow::PgTransactionContext, PostgresDatabase};
+use crate::domain::{
+    espionage::{
+        model::Mole,
+        repository::{MoleRepository, MoleTxRepository},
+    },
+    repository::RepositoryResult,
+};
+use sqlx::Postgres;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PgMoleRepository;
+
+impl PgMoleRepository {
+    /// Inserts new versions of the given moles, visible from the given game tick on.
+    /// The current versions of these moles are superseded in the same statement.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
+    pub async fn insert_moles_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        moles: &[Mole],
+        game_tick: i64,
+    ) -> RepositoryResult<()> {
+        if moles.is_empty() {
+            return Ok(());
+        }
+
+        // Prepare separate vectors for each column to be bulk inserted.
+        // Pre-allocate capacity for efficiency.
+        let count = moles.len();
+        let mut uuids = Vec::with_capacity(count);
+        let mut spy_corporation_uuids = Vec::with_capacity(count);
+        let mut target_corporation_uuids = Vec::with_capacity(count);
+        let mut planted_at_ticks = Vec::with_capacity(count);
+
+        for mole in moles {
+            uuids.push(mole.uuid);
+            spy_corporation_uuids.push(mole.spy_corporation_uuid);
+            target_corporation_uuids.push(mole.target_corporation_uuid);
+            planted_at_ticks.push(mole.planted_at_tick);
+        }
+
+        // Execute the bulk insert query using UNNEST
+        sqlx::query(
+            r#"
+            WITH superseded AS (
+                UPDATE moles
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
+            INSERT INTO moles (
+                game_tick,
+                uuid,
+                spy_corporation_uuid,
+                target_corporation_uuid,
+                planted_at_tick
+            )
+            SELECT $1, u.*
+            FROM unnest($2::UUID[], $3::UUID[], $4::UUID[], $5::BIGINT[])
+            AS u(uuid, spy_corporation_uuid, target_corporation_uuid, planted_at_tick)
+            "#,
+        )
+        .bind(game_tick) // Binds to game_tick column via $1
+        .bind(&uuids) // Binds to $2 -> u.uuid -> uuid column
+        .bind(&spy_corporation_uuids) // Binds to $3 -> u.spy_corporation_uuid -> spy_corporation_uuid column
+        .bind(&target_corporation_uuids) // Binds to $4 -> u.target_corporation_uuid -> target_corporation_uuid column
+        .bind(&planted_at_ticks) // Binds to $5 -> u.planted_at_tick -> planted_at_tick column
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_moles_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+    ) -> RepositoryResult<Vec<Mole>> {
+        let moles = sqlx::query_as!(
+            Mole,
+            r#"
+            SELECT
+                uuid,
+                spy_corporation_uuid,
+                target_corporation_uuid,
+                planted_at_tick
+            FROM moles
+            WHERE
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
+            "#,
+            game_tick,
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(moles)
+    }
+
+    /// Ends the current versions of the given moles at the given game tick.
+    pub async fn supersede_moles_in_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE moles
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Deletes the mole versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
+    pub async fn delete_moles_before_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM moles
+            WHERE
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
+            "#,
+            game_tick,
+            snapshot_interval,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+pub struct PgMoleService {
+    pg_db: Arc<PostgresDatabase>,
+    mole_repo: PgMoleRepository,
+}
+
+impl PgMoleService {
+    pub fn new(pg_db: Arc<PostgresDatabase>) -> Self {
+        Self {
+            pg_db,
+            mole_repo: PgMoleRepository,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl MoleRepository for PgMoleService {
+    async fn list_moles_in_tick(&self, game_tick: i64) -> RepositoryResult<Vec<Mole>> {
+        self.mole_repo
+            .list_moles_in_tick(&self.pg_db.pool, game_tick)
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl MoleTxRepository for PgTransactionContext<'_, '_> {
+    async fn insert_moles_in_tick(
+        &mut self,
+        game_tick: i64,
+        moles: &[Mole],
+    ) -> RepositoryResult<()> {
+        self.mole_repo
+            .insert_moles_in_tick(&mut **self.tx, moles, game_tick)
+            .await
+    }
+
+    async fn supersede_moles_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.mole_repo
+            .supersede_moles_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_moles_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
+        self.mole_repo
+            .delete_moles_before_tick(&mut **self.tx, game_tick, snapshot_interval)
+            .await
+    }
+}
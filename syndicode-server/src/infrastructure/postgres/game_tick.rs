@@ -1,7 +1,7 @@
 use super::{uow::PgTransactionContext, PostgresDatabase};
 use crate::{
     application::ports::game_tick::{GameTickRepository, GameTickTxRepository},
-    domain::repository::RepositoryResult,
+    domain::repository::{RepositoryError, RepositoryResult, TickRetention},
 };
 use sqlx::Postgres;
 use std::sync::Arc;
@@ -27,6 +27,25 @@ impl PgGameTickRepository {
         Ok(record.current_game_tick)
     }
 
+    /// Resolves the game tick a query reads the state of.
+    /// Without an explicit tick the current one is used, otherwise the tick has to be retained.
+    pub async fn resolve_game_tick(
+        &self,
+        executor: impl sqlx::Executor<'_, Database = Postgres>,
+        at_game_tick: Option<i64>,
+        tick_retention: TickRetention,
+    ) -> RepositoryResult<i64> {
+        let current_game_tick = self.get_current_game_tick(executor).await?;
+
+        match at_game_tick {
+            None => Ok(current_game_tick),
+            Some(game_tick) if tick_retention.is_retained(game_tick, current_game_tick) => {
+                Ok(game_tick)
+            }
+            Some(game_tick) => Err(RepositoryError::GameTickNotRetained(game_tick)),
+        }
+    }
+
     /// Updates the current game tick pointer.
     /// This should ONLY be called by the Leader process within the atomic transaction
     /// that also inserts the new state rows.
@@ -1,8 +1,8 @@
 use super::{game_tick::PgGameTickRepository, uow::PgTransactionContext, PostgresDatabase};
 use crate::domain::{
-    repository::RepositoryResult,
+    repository::{RepositoryError, RepositoryResult},
     unit::{
-        model::Unit,
+        model::{unit_type::UnitType, Unit, UnitDetails},
         repository::{ListUnitsOutcome, UnitRepository, UnitTxRespository},
     },
 };
@@ -10,10 +10,93 @@ use sqlx::{Executor, Postgres};
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// The unit type is stored as its numeric code.
+struct UnitRow {
+    uuid: Uuid,
+    corporation_uuid: Uuid,
+    unit_type: i16,
+    health: i32,
+    attack: i32,
+    defense: i32,
+    building_uuid: Uuid,
+    destination_building_uuid: Option<Uuid>,
+    arrival_tick: Option<i64>,
+    attack_target_building_uuid: Option<Uuid>,
+}
+
+impl TryFrom<UnitRow> for Unit {
+    type Error = RepositoryError;
+
+    fn try_from(row: UnitRow) -> Result<Self, Self::Error> {
+        let unit_type = UnitType::try_from(row.unit_type)
+            .map_err(|err| anyhow::anyhow!("Invalid unit '{}': {}", row.uuid, err))?;
+
+        Ok(Self {
+            uuid: row.uuid,
+            corporation_uuid: row.corporation_uuid,
+            unit_type,
+            health: row.health,
+            attack: row.attack,
+            defense: row.defense,
+            building_uuid: row.building_uuid,
+            destination_building_uuid: row.destination_building_uuid,
+            arrival_tick: row.arrival_tick,
+            attack_target_building_uuid: row.attack_target_building_uuid,
+        })
+    }
+}
+
+struct UnitDetailsRow {
+    uuid: Uuid,
+    corporation_uuid: Uuid,
+    unit_type: i16,
+    health: i32,
+    attack: i32,
+    defense: i32,
+    building_uuid: Uuid,
+    destination_building_uuid: Option<Uuid>,
+    arrival_tick: Option<i64>,
+    attack_target_building_uuid: Option<Uuid>,
+    building_gml_id: String,
+    destination_building_gml_id: Option<String>,
+    attack_target_building_gml_id: Option<String>,
+    city_code: Option<String>,
+}
+
+impl TryFrom<UnitDetailsRow> for UnitDetails {
+    type Error = RepositoryError;
+
+    fn try_from(row: UnitDetailsRow) -> Result<Self, Self::Error> {
+        let unit = Unit::try_from(UnitRow {
+            uuid: row.uuid,
+            corporation_uuid: row.corporation_uuid,
+            unit_type: row.unit_type,
+            health: row.health,
+            attack: row.attack,
+            defense: row.defense,
+            building_uuid: row.building_uuid,
+            destination_building_uuid: row.destination_building_uuid,
+            arrival_tick: row.arrival_tick,
+            attack_target_building_uuid: row.attack_target_building_uuid,
+        })?;
+
+        Ok(Self {
+            unit,
+            building_gml_id: row.building_gml_id,
+            destination_building_gml_id: row.destination_building_gml_id,
+            attack_target_building_gml_id: row.attack_target_building_gml_id,
+            city_code: row.city_code,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct PgUnitRepository;
 
 impl PgUnitRepository {
+    /// Inserts new versions of the given units, visible from the given game tick on.
+    /// The current versions of these units are superseded in the same statement.
+    /// This leverages PostgreSQL's UNNEST function for efficiency.
     pub async fn insert_units_in_tick(
         &self,
         executor: impl sqlx::Executor<'_, Database = Postgres>,
@@ -30,28 +113,89 @@ impl PgUnitRepository {
         let count = units.len();
         let mut uuids = Vec::with_capacity(count);
         let mut corporation_uuids = Vec::with_capacity(count);
+        let mut unit_types: Vec<i16> = Vec::with_capacity(count);
+        let mut healths = Vec::with_capacity(count);
+        let mut attacks = Vec::with_capacity(count);
+        let mut defenses = Vec::with_capacity(count);
+        let mut building_uuids = Vec::with_capacity(count);
+        let mut destination_building_uuids = Vec::with_capacity(count);
+        let mut arrival_ticks = Vec::with_capacity(count);
+        let mut attack_target_building_uuids = Vec::with_capacity(count);
 
         for unit in units {
             uuids.push(unit.uuid);
             corporation_uuids.push(unit.corporation_uuid);
+            unit_types.push(unit.unit_type.into());
+            healths.push(unit.health);
+            attacks.push(unit.attack);
+            defenses.push(unit.defense);
+            building_uuids.push(unit.building_uuid);
+            destination_building_uuids.push(unit.destination_building_uuid);
+            arrival_ticks.push(unit.arrival_tick);
+            attack_target_building_uuids.push(unit.attack_target_building_uuid);
         }
 
         // Execute the bulk insert query using UNNEST
         sqlx::query!(
             r#"
+            WITH superseded AS (
+                UPDATE units
+                SET superseded_at_tick = $1
+                WHERE
+                    uuid = ANY($2)
+                    AND superseded_at_tick IS NULL
+            )
             INSERT INTO units (
                 game_tick,
                 uuid,
-                corporation_uuid
+                corporation_uuid,
+                unit_type,
+                health,
+                attack,
+                defense,
+                building_uuid,
+                destination_building_uuid,
+                arrival_tick,
+                attack_target_building_uuid
+            )
+            SELECT $1, u.*
+            FROM unnest(
+                $2::UUID[],
+                $3::UUID[],
+                $4::SMALLINT[],
+                $5::INTEGER[],
+                $6::INTEGER[],
+                $7::INTEGER[],
+                $8::UUID[],
+                $9::UUID[],
+                $10::BIGINT[],
+                $11::UUID[]
+            )
+            AS u(
+                uuid,
+                corporation_uuid,
+                unit_type,
+                health,
+                attack,
+                defense,
+                building_uuid,
+                destination_building_uuid,
+                arrival_tick,
+                attack_target_building_uuid
             )
-            SELECT
-                $1 as game_tick,
-                unnest($2::UUID[]) as uuid,
-                unnest($3::UUID[]) as corporation_uuid
             "#,
             game_tick,
             &uuids,
             &corporation_uuids,
+            &unit_types,
+            &healths,
+            &attacks,
+            &defenses,
+            &building_uuids,
+            // Nullable arrays cannot be checked by the macro.
+            &destination_building_uuids as _,
+            &arrival_ticks as _,
+            &attack_target_building_uuids as _,
         )
         .execute(executor)
         .await?;
@@ -64,42 +208,67 @@ impl PgUnitRepository {
         executor: impl Executor<'_, Database = Postgres>,
         game_tick: i64,
     ) -> RepositoryResult<Vec<Unit>> {
-        let units = sqlx::query_as!(
-            Unit,
+        let rows = sqlx::query_as!(
+            UnitRow,
             r#"
             SELECT
                 uuid,
-                corporation_uuid
+                corporation_uuid,
+                unit_type,
+                health,
+                attack,
+                defense,
+                building_uuid,
+                destination_building_uuid,
+                arrival_tick,
+                attack_target_building_uuid
             FROM units
             WHERE
-                game_tick = $1
+                game_tick <= $1
+                AND (superseded_at_tick IS NULL OR superseded_at_tick > $1)
             "#,
             game_tick
         )
         .fetch_all(executor)
         .await?;
 
-        Ok(units)
+        rows.into_iter().map(Unit::try_from).collect()
     }
 
-    /// Retrieves all units (UUID and owner UUID) belonging to a specific corporation
-    /// at a given game tick.
+    /// Retrieves all units belonging to a specific corporation at a given game tick,
+    /// together with the GML IDs of the buildings they are located at or moving to.
     pub async fn list_corporation_units_at_tick(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
         corporation_uuid: Uuid,
         game_tick: i64,
-    ) -> RepositoryResult<Vec<Unit>> {
-        let units = sqlx::query_as!(
-            Unit,
+    ) -> RepositoryResult<Vec<UnitDetails>> {
+        let rows = sqlx::query_as!(
+            UnitDetailsRow,
             r#"
             SELECT
-                uuid,
-                corporation_uuid
-            FROM units
+                u.uuid,
+                u.corporation_uuid,
+                u.unit_type,
+                u.health,
+                u.attack,
+                u.defense,
+                u.building_uuid,
+                u.destination_building_uuid,
+                u.arrival_tick,
+                u.attack_target_building_uuid,
+                bui.gml_id as building_gml_id,
+                dest.gml_id as "destination_building_gml_id?",
+                target.gml_id as "attack_target_building_gml_id?",
+                bui.city_code
+            FROM units u
+            JOIN buildings bui ON bui.uuid = u.building_uuid
+            LEFT JOIN buildings dest ON dest.uuid = u.destination_building_uuid
+            LEFT JOIN buildings target ON target.uuid = u.attack_target_building_uuid
             WHERE
-                corporation_uuid = $1
-                AND game_tick = $2
+                u.corporation_uuid = $1
+                AND u.game_tick <= $2
+                AND (u.superseded_at_tick IS NULL OR u.superseded_at_tick > $2)
             "#,
             corporation_uuid,
             game_tick
@@ -107,21 +276,54 @@ impl PgUnitRepository {
         .fetch_all(executor)
         .await?;
 
-        Ok(units)
+        rows.into_iter().map(UnitDetails::try_from).collect()
+    }
+
+    /// Ends the current versions of the given units at the given game tick.
+    pub async fn supersede_units_in_tick(
+        &self,
+        executor: impl Executor<'_, Database = Postgres>,
+        uuids: &[Uuid],
+        game_tick: i64,
+    ) -> RepositoryResult<u64> {
+        if uuids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE units
+            SET superseded_at_tick = $1
+            WHERE
+                uuid = ANY($2)
+                AND superseded_at_tick IS NULL
+            "#,
+            game_tick,
+            uuids,
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
     }
 
+    /// Deletes the unit versions that are no longer visible at the given game tick or later,
+    /// except for the ones visible at a tick divisible by the snapshot interval.
     pub async fn delete_units_before_tick(
         &self,
         executor: impl Executor<'_, Database = Postgres>,
         game_tick: i64,
+        snapshot_interval: i64,
     ) -> RepositoryResult<u64> {
         let result = sqlx::query!(
             r#"
              DELETE FROM units
              WHERE
-                game_tick < $1
+                superseded_at_tick <= $1
+                AND ($2::BIGINT <= 0 OR (superseded_at_tick - 1) / $2 * $2 < game_tick)
              "#,
-            game_tick
+            game_tick,
+            snapshot_interval,
         )
         .execute(executor)
         .await?;
@@ -184,9 +386,23 @@ impl UnitTxRespository for PgTransactionContext<'_, '_> {
             .await
     }
 
-    async fn delete_units_before_tick(&mut self, game_tick: i64) -> RepositoryResult<u64> {
+    async fn supersede_units_in_tick(
+        &mut self,
+        game_tick: i64,
+        uuids: &[Uuid],
+    ) -> RepositoryResult<u64> {
+        self.unit_repo
+            .supersede_units_in_tick(&mut **self.tx, uuids, game_tick)
+            .await
+    }
+
+    async fn delete_units_before_tick(
+        &mut self,
+        game_tick: i64,
+        snapshot_interval: i64,
+    ) -> RepositoryResult<u64> {
         self.unit_repo
-            .delete_units_before_tick(&mut **self.tx, game_tick)
+            .delete_units_before_tick(&mut **self.tx, game_tick, snapshot_interval)
             .await
     }
 }
@@ -1,10 +1,18 @@
+use super::action_log::PgActionLogRepository;
+use super::diplomacy::PgTreatyRepository;
+use super::economy::auction_bid::PgAuctionBidRepository;
 use super::economy::building::PgBuildingRepository;
 use super::economy::building_ownership::PgBuildingOwnershipRepository;
 use super::economy::business::PgBusinessRepository;
 use super::economy::business_listing::PgBusinessListingRepository;
 use super::economy::business_offer::PgBusinessOfferRepository;
+use super::economy::contract::PgContractRepository;
 use super::economy::corporation::PgCorporationRepository;
+use super::economy::ledger::PgLedgerRepository;
+use super::economy::loan::PgLoanRepository;
 use super::economy::market::PgMarketRepository;
+use super::economy::territory::PgTerritoryRepository;
+use super::espionage::PgMoleRepository;
 use super::game_tick::PgGameTickRepository;
 use super::init::PgInitializationRepository;
 use super::unit::PgUnitRepository;
@@ -24,17 +32,25 @@ where
 {
     pub tx: &'a mut Transaction<'tx, Postgres>,
     pub game_tick_repo: &'a PgGameTickRepository,
+    pub action_log_repo: &'a PgActionLogRepository,
     pub init_repo: &'a PgInitializationRepository,
     pub user_repo: &'a PgUserRepository,
     pub user_verify_repo: &'a PgUserVerificationRepository,
     pub corporation_repo: &'a PgCorporationRepository,
+    pub ledger_repo: &'a PgLedgerRepository,
     pub business_repo: &'a PgBusinessRepository,
     pub market_repo: &'a PgMarketRepository,
     pub unit_repo: &'a PgUnitRepository,
     pub business_listing_repo: &'a PgBusinessListingRepository,
     pub business_offer_repo: &'a PgBusinessOfferRepository,
+    pub loan_repo: &'a PgLoanRepository,
+    pub auction_bid_repo: &'a PgAuctionBidRepository,
     pub building_repo: &'a PgBuildingRepository,
     pub building_ownerships_repo: &'a PgBuildingOwnershipRepository,
+    pub territory_repo: &'a PgTerritoryRepository,
+    pub mole_repo: &'a PgMoleRepository,
+    pub treaty_repo: &'a PgTreatyRepository,
+    pub contract_repo: &'a PgContractRepository,
 }
 
 // Implement the marker trait. Note the lifetimes match the struct.
@@ -45,17 +61,25 @@ impl<'a> TransactionalContext<'a> for PgTransactionContext<'a, '_> {}
 pub struct PostgresUnitOfWork {
     pg_db: Arc<PostgresDatabase>,
     game_tick: PgGameTickRepository,
+    action_log_repo: PgActionLogRepository,
     init_repo: PgInitializationRepository,
     user_repo: PgUserRepository,
     user_verify_repo: PgUserVerificationRepository,
     corporation_repo: PgCorporationRepository,
+    ledger_repo: PgLedgerRepository,
     business_repo: PgBusinessRepository,
     market_repo: PgMarketRepository,
     unit_repo: PgUnitRepository,
     business_listing_repo: PgBusinessListingRepository,
     business_offer_repo: PgBusinessOfferRepository,
+    loan_repo: PgLoanRepository,
+    auction_bid_repo: PgAuctionBidRepository,
     building_repo: PgBuildingRepository,
     building_ownership_repo: PgBuildingOwnershipRepository,
+    territory_repo: PgTerritoryRepository,
+    mole_repo: PgMoleRepository,
+    treaty_repo: PgTreatyRepository,
+    contract_repo: PgContractRepository,
 }
 
 impl PostgresUnitOfWork {
@@ -63,17 +87,25 @@ impl PostgresUnitOfWork {
         Self {
             pg_db,
             game_tick: PgGameTickRepository,
+            action_log_repo: PgActionLogRepository,
             init_repo: PgInitializationRepository,
             user_repo: PgUserRepository,
             user_verify_repo: PgUserVerificationRepository,
             corporation_repo: PgCorporationRepository,
+            ledger_repo: PgLedgerRepository,
             business_repo: PgBusinessRepository,
             market_repo: PgMarketRepository,
             unit_repo: PgUnitRepository,
             business_listing_repo: PgBusinessListingRepository,
             business_offer_repo: PgBusinessOfferRepository,
+            loan_repo: PgLoanRepository,
+            auction_bid_repo: PgAuctionBidRepository,
             building_repo: PgBuildingRepository,
             building_ownership_repo: PgBuildingOwnershipRepository,
+            territory_repo: PgTerritoryRepository,
+            mole_repo: PgMoleRepository,
+            treaty_repo: PgTreatyRepository,
+            contract_repo: PgContractRepository,
         }
     }
 }
@@ -105,17 +137,25 @@ impl UnitOfWork for PostgresUnitOfWork {
             let mut context = PgTransactionContext {
                 tx: &mut tx,
                 game_tick_repo: &self.game_tick,
+                action_log_repo: &self.action_log_repo,
                 init_repo: &self.init_repo,
                 user_repo: &self.user_repo,
                 user_verify_repo: &self.user_verify_repo,
                 corporation_repo: &self.corporation_repo,
+                ledger_repo: &self.ledger_repo,
                 business_repo: &self.business_repo,
                 market_repo: &self.market_repo,
                 unit_repo: &self.unit_repo,
                 business_listing_repo: &self.business_listing_repo,
                 business_offer_repo: &self.business_offer_repo,
+                loan_repo: &self.loan_repo,
+                auction_bid_repo: &self.auction_bid_repo,
                 building_repo: &self.building_repo,
                 building_ownerships_repo: &self.building_ownership_repo,
+                territory_repo: &self.territory_repo,
+                mole_repo: &self.mole_repo,
+                treaty_repo: &self.treaty_repo,
+                contract_repo: &self.contract_repo,
             };
 
             // Execute the closure, await the future INSIDE the scope.
@@ -1,17 +1,22 @@
-use super::ValkeyStore;
+use super::{ValkeyStore, PAYLOAD_FIELD};
 use crate::application::ports::outcome::{
     OutcomeError, OutcomeNotifier, OutcomeResult, OutcomeStoreReader, OutcomeStoreWriter,
 };
-use redis::AsyncCommands;
-use std::time::Duration;
+use redis::{
+    streams::{StreamMaxlen, StreamRangeReply},
+    AsyncCommands,
+};
+use std::{collections::BTreeSet, time::Duration};
 use uuid::Uuid;
 
 const PAYLOAD_KEY: &str = "syndicode:outcomes:payload";
 const CLIENT_KEY: &str = "syndicode:outcomes:client";
+const BACKLOG_KEY: &str = "syndicode:outcomes:backlog";
 
 const OUTCOME_TTL: Duration = Duration::from_secs(60);
 
 pub const GAME_TICK_NOTIFICATION_CHANNEL: &str = "syndicode:game_tick";
+pub const WORLD_EVENT_CHANNEL: &str = "syndicode:world_events";
 
 #[tonic::async_trait]
 impl OutcomeStoreWriter for ValkeyStore {
@@ -28,6 +33,46 @@ impl OutcomeStoreWriter for ValkeyStore {
 
         Ok(())
     }
+
+    /// Appends the outcomes to the backlog streams of their users in a single pipeline.
+    /// The streams are trimmed to the configured size and expire once the user hasn't received
+    /// any outcomes for a while.
+    ///
+    /// The entry IDs start with the game tick, so the backlog can be read from a tick onwards.
+    async fn append_to_backlog(
+        &self,
+        game_tick: i64,
+        outcomes: &[(Uuid, &[u8])],
+    ) -> OutcomeResult<()> {
+        let entry_id = format!("{game_tick}-*");
+        let user_uuids: BTreeSet<Uuid> = outcomes.iter().map(|(user_uuid, _)| *user_uuid).collect();
+
+        let mut pipe = redis::pipe();
+        for (user_uuid, payload) in outcomes {
+            pipe.xadd_maxlen(
+                create_backlog_key(*user_uuid),
+                StreamMaxlen::Equals(self.config.stream.outcome_backlog_size),
+                &entry_id,
+                &[(PAYLOAD_FIELD, *payload)],
+            )
+            .ignore();
+        }
+        for user_uuid in user_uuids {
+            pipe.expire(
+                create_backlog_key(user_uuid),
+                self.config.stream.outcome_backlog_ttl_secs as i64,
+            )
+            .ignore();
+        }
+
+        let mut conn = self.conn.clone();
+
+        pipe.query_async::<()>(&mut conn)
+            .await
+            .map_err(|err| OutcomeError::EnqueueFailed(err.to_string()))?;
+
+        Ok(())
+    }
 }
 
 #[tonic::async_trait]
@@ -56,6 +101,30 @@ impl OutcomeStoreReader for ValkeyStore {
 
         Ok(())
     }
+
+    async fn retrieve_backlog(
+        &self,
+        user_uuid: Uuid,
+        after_game_tick: i64,
+    ) -> OutcomeResult<Vec<Vec<u8>>> {
+        let key = create_backlog_key(user_uuid);
+
+        let mut conn = self.conn.clone();
+
+        // The entry IDs start with the game tick, the first entry of a tick has the sequence 0
+        let reply: StreamRangeReply = conn
+            .xrange(key, format!("{}-0", after_game_tick + 1), "+")
+            .await
+            .map_err(|err| OutcomeError::DequeueFailed(err.to_string()))?;
+
+        let payloads = reply
+            .ids
+            .into_iter()
+            .filter_map(|entry| entry.get::<Vec<u8>>(PAYLOAD_FIELD))
+            .collect();
+
+        Ok(payloads)
+    }
 }
 
 #[tonic::async_trait]
@@ -81,8 +150,22 @@ impl OutcomeNotifier for ValkeyStore {
 
         Ok(())
     }
+
+    async fn notify_world_events(&self, payload: &[u8]) -> OutcomeResult<()> {
+        let mut conn = self.conn.clone();
+
+        conn.publish::<_, _, usize>(WORLD_EVENT_CHANNEL, payload)
+            .await
+            .map_err(|err| OutcomeError::PublishingWorldEventsFailed(err.to_string()))?;
+
+        Ok(())
+    }
 }
 
 pub fn create_notification_channel(user_uuid: Uuid) -> String {
     format!("{CLIENT_KEY}:{user_uuid}")
 }
+
+fn create_backlog_key(user_uuid: Uuid) -> String {
+    format!("{BACKLOG_KEY}:{user_uuid}")
+}
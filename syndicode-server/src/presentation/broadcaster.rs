@@ -1,12 +1,17 @@
 use crate::{
-    infrastructure::valkey::outcome::GAME_TICK_NOTIFICATION_CHANNEL, utils::timestamp_now,
+    domain::world_event::model::WorldEventBatch,
+    infrastructure::valkey::outcome::{GAME_TICK_NOTIFICATION_CHANNEL, WORLD_EVENT_CHANNEL},
+    utils::timestamp_now,
 };
 use bon::Builder;
 use futures::stream::{iter, StreamExt};
 use std::sync::Arc;
 use syndicode_proto::syndicode_interface_v1::{game_update::Update, GameUpdate, TickNotification};
 
-use super::game::user_channel_guard::UserChannels;
+use super::game::{
+    subscriptions::{world_event_to_grpc_update, UserSubscriptions},
+    user_channel_guard::UserChannels,
+};
 
 // Configuration for concurrency
 const MAX_CONCURRENT_TICK_BROADCASTS: usize = 50;
@@ -15,15 +20,18 @@ const MAX_CONCURRENT_TICK_BROADCASTS: usize = 50;
 pub struct GameTickBroadcaster {
     pub valkey_client: redis::Client,
     pub user_channels: UserChannels,
+    pub user_subscriptions: UserSubscriptions,
 }
 
 impl GameTickBroadcaster {
     /// Spawns a background task to listen for tick notifications and broadcast them concurrently.
+    /// World events are forwarded to the users with matching subscriptions on the same task.
     /// Returns immediately after spawning. Errors during setup or runtime are logged internally.
     pub fn spawn_listen_and_broadcast_task(&self) {
         // Clone resources needed for the background task
         let valkey_game_tick_clone = self.valkey_client.clone();
         let user_channels_clone = Arc::clone(&self.user_channels);
+        let user_subscriptions_clone = Arc::clone(&self.user_subscriptions);
 
         tokio::spawn(async move {
             // --- Pub/Sub Setup ---
@@ -48,10 +56,25 @@ impl GameTickBroadcaster {
                 channel_name
             );
 
+            if let Err(err) = pubsub_conn.subscribe(WORLD_EVENT_CHANNEL).await {
+                tracing::error!(error=%err, "Broadcaster: Failed to subscribe to world event channel '{}'. Task exiting.", WORLD_EVENT_CHANNEL);
+                return;
+            }
+
             let mut message_stream = pubsub_conn.on_message();
 
             // --- Main Listener Loop ---
             while let Some(msg) = message_stream.next().await {
+                if msg.get_channel_name() == WORLD_EVENT_CHANNEL {
+                    forward_world_events(
+                        msg.get_payload_bytes(),
+                        &user_channels_clone,
+                        &user_subscriptions_clone,
+                    )
+                    .await;
+                    continue;
+                }
+
                 let game_tick: i64 = match msg.get_payload() {
                     Ok(gt) => gt,
                     Err(err) => {
@@ -110,3 +133,46 @@ impl GameTickBroadcaster {
         }); // End tokio::spawn
     }
 }
+
+/// Sends every world event of the batch to the connected users with at least one matching subscription.
+async fn forward_world_events(
+    payload: &[u8],
+    user_channels: &UserChannels,
+    user_subscriptions: &UserSubscriptions,
+) {
+    let batch = match rmp_serde::from_slice::<WorldEventBatch>(payload) {
+        Ok(batch) => batch,
+        Err(err) => {
+            tracing::error!(error = %err, "Broadcaster: Failed to deserialize world event batch. Skipping.");
+            return;
+        }
+    };
+
+    // Collect the updates first to not hold any map locks across awaits
+    let deliveries: Vec<_> = user_subscriptions
+        .iter()
+        .filter_map(|entry| {
+            let user_tx = user_channels.get(entry.key())?.value().clone();
+            let updates: Vec<_> = batch
+                .events
+                .iter()
+                .filter_map(|event| {
+                    world_event_to_grpc_update(batch.game_tick, event, entry.value())
+                })
+                .collect();
+
+            (!updates.is_empty()).then_some((user_tx, updates))
+        })
+        .collect();
+
+    iter(deliveries)
+        .for_each_concurrent(MAX_CONCURRENT_TICK_BROADCASTS, |(user_tx, updates)| async move {
+            for update in updates {
+                if let Err(err) = user_tx.send(Ok(update)).await {
+                    tracing::warn!(error=%err, tick=%batch.game_tick, "Broadcaster: Failed to send WorldEventNotification update to a client channel.");
+                    break;
+                }
+            }
+        })
+        .await;
+}
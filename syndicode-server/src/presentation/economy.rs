@@ -3,10 +3,17 @@ use std::sync::Arc;
 use bon::Builder;
 use syndicode_proto::{
     syndicode_economy_v1::{
-        AcquireListedBusinessRequest, BuildingDetails, BusinessDetails, BusinessListingDetails,
-        BusinessListingSortBy, BusinessSortBy, GetCorporationRequest, QueryBuildingsRequest,
+        AcceptBusinessOfferRequest, AcquireBuildingRequest, AcquireListedBusinessRequest,
+        BuildingDetails, BusinessDetails, BusinessListingDetails, BusinessListingSortBy,
+        BusinessSortBy, CancelBusinessListingRequest, GeoPoint, GetCorporationLedgerRequest,
+        GetCorporationLedgerResponse, GetCorporationRequest, LedgerEntry,
+        LedgerEntryKind as ProtoLedgerEntryKind, ListBusinessForSaleRequest,
+        MakeBusinessOfferRequest, MarketSnapshot, PlaceAuctionBidRequest, QueryBuildingsRequest,
         QueryBuildingsResponse, QueryBusinessListingsRequest, QueryBusinessListingsResponse,
-        QueryBusinessesRequest, QueryBusinessesResponse,
+        QueryBusinessesRequest, QueryBusinessesResponse, QueryMarketsRequest, QueryMarketsResponse,
+        QueryTerritoriesRequest, QueryTerritoriesResponse, RejectBusinessOfferRequest,
+        RepayLoanRequest, SetOperationalExpensesRequest, TakeLoanRequest, Territory,
+        TerritoryControlShare, WithdrawBusinessOfferRequest,
     },
     syndicode_interface_v1::{
         economy_service_server::EconomyService, ActionInitResponse, SortDirection,
@@ -17,10 +24,21 @@ use tonic::Response;
 use crate::{
     application::{
         economy::{
+            accept_business_offer::AcceptBusinessOfferUseCase,
+            acquire_building::AcquireBuildingUseCase,
             acquire_listed_business::AcquireListedBusinessUseCase,
-            get_corporation::GetCorporationUseCase, query_buildings::QueryBuildingsUseCase,
+            cancel_business_listing::CancelBusinessListingUseCase,
+            get_corporation::GetCorporationUseCase,
+            get_corporation_ledger::GetCorporationLedgerUseCase,
+            list_business_for_sale::ListBusinessForSaleUseCase,
+            make_business_offer::MakeBusinessOfferUseCase,
+            place_auction_bid::PlaceAuctionBidUseCase, query_buildings::QueryBuildingsUseCase,
             query_business_listings::QueryBusinessListingsUseCase,
-            query_businesses::QueryBusinessesUseCase,
+            query_businesses::QueryBusinessesUseCase, query_markets::QueryMarketsUseCase,
+            query_territories::QueryTerritoriesUseCase,
+            reject_business_offer::RejectBusinessOfferUseCase, repay_loan::RepayLoanUseCase,
+            set_operational_expenses::SetOperationalExpensesUseCase, take_loan::TakeLoanUseCase,
+            withdraw_business_offer::WithdrawBusinessOfferUseCase,
         },
         ports::{
             game_tick::GameTickRepository,
@@ -36,6 +54,9 @@ use crate::{
                 BusinessListingRepository, DomainBusinessListingSortBy,
             },
             corporation::repository::CorporationRepository,
+            ledger::{model::entry_kind::LedgerEntryKind, repository::LedgerRepository},
+            market::repository::MarketRepository,
+            territory::repository::TerritoryRepository,
         },
         repository::DomainSortDirection,
     },
@@ -48,7 +69,7 @@ use super::{
 use uuid::Uuid;
 
 #[derive(Builder)]
-pub struct EconomyPresenter<R, BUI, CRP, B, BL, Q, GTR>
+pub struct EconomyPresenter<R, BUI, CRP, B, BL, Q, GTR, LED, MRK, TER>
 where
     R: RateLimitEnforcer,
     BUI: BuildingRepository,
@@ -57,17 +78,35 @@ where
     BL: BusinessListingRepository,
     Q: ActionQueueable,
     GTR: GameTickRepository + 'static,
+    LED: LedgerRepository,
+    MRK: MarketRepository,
+    TER: TerritoryRepository,
 {
     pub limit: Arc<R>,
     pub query_buildings_uc: Arc<QueryBuildingsUseCase<BUI>>,
     pub get_corporation_uc: Arc<GetCorporationUseCase<CRP>>,
+    pub get_corporation_ledger_uc: Arc<GetCorporationLedgerUseCase<CRP, LED>>,
     pub query_businesses_uc: Arc<QueryBusinessesUseCase<B>>,
     pub query_business_listings_uc: Arc<QueryBusinessListingsUseCase<BL>>,
+    pub query_markets_uc: Arc<QueryMarketsUseCase<MRK>>,
+    pub query_territories_uc: Arc<QueryTerritoriesUseCase<CRP, TER>>,
     pub acquire_listed_business_uc: Arc<AcquireListedBusinessUseCase<Q, GTR>>,
+    pub list_business_for_sale_uc: Arc<ListBusinessForSaleUseCase<Q, GTR>>,
+    pub cancel_business_listing_uc: Arc<CancelBusinessListingUseCase<Q, GTR>>,
+    pub make_business_offer_uc: Arc<MakeBusinessOfferUseCase<Q, GTR>>,
+    pub accept_business_offer_uc: Arc<AcceptBusinessOfferUseCase<Q, GTR>>,
+    pub reject_business_offer_uc: Arc<RejectBusinessOfferUseCase<Q, GTR>>,
+    pub withdraw_business_offer_uc: Arc<WithdrawBusinessOfferUseCase<Q, GTR>>,
+    pub set_operational_expenses_uc: Arc<SetOperationalExpensesUseCase<Q, GTR>>,
+    pub take_loan_uc: Arc<TakeLoanUseCase<Q, GTR>>,
+    pub repay_loan_uc: Arc<RepayLoanUseCase<Q, GTR>>,
+    pub place_auction_bid_uc: Arc<PlaceAuctionBidUseCase<Q, GTR>>,
+    pub acquire_building_uc: Arc<AcquireBuildingUseCase<Q, GTR>>,
 }
 
 #[tonic::async_trait]
-impl<R, BUI, CRP, B, BL, Q, GTR> EconomyService for EconomyPresenter<R, BUI, CRP, B, BL, Q, GTR>
+impl<R, BUI, CRP, B, BL, Q, GTR, LED, MRK, TER> EconomyService
+    for EconomyPresenter<R, BUI, CRP, B, BL, Q, GTR, LED, MRK, TER>
 where
     R: RateLimitEnforcer + 'static,
     BUI: BuildingRepository + 'static,
@@ -76,6 +115,9 @@ where
     BL: BusinessListingRepository + 'static,
     Q: ActionQueueable + 'static,
     GTR: GameTickRepository + 'static,
+    LED: LedgerRepository + 'static,
+    MRK: MarketRepository + 'static,
+    TER: TerritoryRepository + 'static,
 {
     async fn query_buildings(
         &self,
@@ -109,6 +151,8 @@ where
             .maybe_min_lat(request.min_lat)
             .maybe_max_lat(request.max_lat)
             .maybe_limit(request.limit)
+            .maybe_at_game_tick(request.at_game_tick)
+            .maybe_is_owned(request.is_owned)
             .call()
             .await
             .map_err(PresentationError::from)?;
@@ -121,6 +165,9 @@ where
                 gml_id: o.gml_id,
                 longitude: o.longitude,
                 latitude: o.latitude,
+                volume: o.volume,
+                usage_code: o.usage_code,
+                owning_business_uuid: o.owning_business_uuid.map(|uuid| uuid.to_string()),
             });
         }
 
@@ -131,6 +178,70 @@ where
         }))
     }
 
+    async fn query_territories(
+        &self,
+        request: tonic::Request<QueryTerritoriesRequest>,
+    ) -> Result<tonic::Response<QueryTerritoriesResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+
+        let (game_tick, domain_territories) = self
+            .query_territories_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .maybe_at_game_tick(request.at_game_tick)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        let territories = domain_territories
+            .into_iter()
+            .map(|(ward, territory)| Territory {
+                city_code: ward.city_code,
+                name: ward.name,
+                boundary: ward
+                    .boundary
+                    .exterior()
+                    .points()
+                    .map(|point| GeoPoint {
+                        longitude: point.x(),
+                        latitude: point.y(),
+                    })
+                    .collect(),
+                owned_volume: territory.owned_volume,
+                controlling_corporation_uuid: territory
+                    .controlling_corporation_uuid
+                    .map(|uuid| uuid.to_string()),
+                shares: territory
+                    .shares
+                    .into_iter()
+                    .map(|share| TerritoryControlShare {
+                        corporation_uuid: share.corporation_uuid.to_string(),
+                        volume: share.volume,
+                        share_bps: share.share_bps,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Response::new(QueryTerritoriesResponse {
+            game_tick,
+            territories,
+        }))
+    }
+
     async fn get_current_corporation(
         &self,
         request: tonic::Request<GetCorporationRequest>,
@@ -151,9 +262,11 @@ where
             Err(status) => return Err(*status),
         };
 
+        let at_game_tick = request.into_inner().at_game_tick;
+
         let outcome = self
             .get_corporation_uc
-            .execute(req_user_uuid)
+            .execute(req_user_uuid, at_game_tick)
             .await
             .map_err(PresentationError::from)?;
 
@@ -167,6 +280,54 @@ where
         Ok(Response::new(corporation))
     }
 
+    async fn get_corporation_ledger(
+        &self,
+        request: tonic::Request<GetCorporationLedgerRequest>,
+    ) -> Result<tonic::Response<GetCorporationLedgerResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+
+        let (total_count, domain_entries) = self
+            .get_corporation_ledger_uc
+            .execute()
+            .user_uuid(req_user_uuid)
+            .maybe_from_game_tick(request.from_game_tick)
+            .maybe_to_game_tick(request.to_game_tick)
+            .maybe_limit(request.limit)
+            .maybe_offset(request.offset)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        let entries = domain_entries
+            .into_iter()
+            .map(|entry| LedgerEntry {
+                game_tick: entry.game_tick,
+                sequence: entry.sequence,
+                kind: ledger_entry_kind_to_proto(entry.kind).into(),
+                amount: entry.amount,
+                business_uuid: entry.business_uuid.map(|uuid| uuid.to_string()),
+            })
+            .collect();
+
+        Ok(Response::new(GetCorporationLedgerResponse {
+            entries,
+            total_count,
+        }))
+    }
+
     async fn query_businesses(
         &self,
         request: tonic::Request<QueryBusinessesRequest>,
@@ -179,6 +340,11 @@ where
         .await
         .map_err(|status| *status)?;
 
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
         let request = request.into_inner();
 
         let owning_corporation_uuid =
@@ -214,6 +380,7 @@ where
         let (_, domain_businesses) = self
             .query_businesses_uc
             .execute()
+            .req_user_uuid(req_user_uuid)
             .maybe_owning_corporation_uuid(owning_corporation_uuid)
             .maybe_market_uuid(market_uuid)
             .maybe_min_operational_expenses(request.min_operational_expenses)
@@ -222,6 +389,7 @@ where
             .maybe_sort_direction(maybe_domain_sort_direction)
             .maybe_limit(request.limit)
             .maybe_offset(request.offset)
+            .maybe_at_game_tick(request.at_game_tick)
             .call()
             .await
             .map_err(PresentationError::from)?;
@@ -243,6 +411,7 @@ where
                 headquarter_latitude: b.headquarter_latitude,
                 image_number: b.image_number as i32,
                 market_number: b.market_number as i32,
+                operational_expenses_estimated: b.operational_expenses_estimated,
             });
         }
 
@@ -307,10 +476,12 @@ where
             .maybe_seller_corporation_uuid(seller_corporation_uuid)
             .maybe_min_operational_expenses(request.min_operational_expenses)
             .maybe_max_operational_expenses(request.max_operational_expenses)
+            .maybe_is_auction(request.is_auction)
             .maybe_sort_by(maybe_sort_by)
             .maybe_sort_direction(maybe_domain_sort_direction)
             .maybe_limit(request.limit)
             .maybe_offset(request.offset)
+            .maybe_at_game_tick(request.at_game_tick)
             .call()
             .await
             .map_err(PresentationError::from)?;
@@ -335,6 +506,7 @@ where
                 headquarter_latitude: listing.headquarter_latitude,
                 image_number: listing.image_number as i32,
                 market_number: listing.market_number as i32,
+                auction_closes_at_tick: listing.auction_closes_at_tick,
             });
         }
 
@@ -345,6 +517,54 @@ where
         }))
     }
 
+    async fn query_markets(
+        &self,
+        request: tonic::Request<QueryMarketsRequest>,
+    ) -> Result<tonic::Response<QueryMarketsResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let request = request.into_inner();
+
+        let market_uuid =
+            parse_maybe_uuid(request.market_uuid, "market uuid").map_err(|status| *status)?;
+
+        let (total_count, domain_snapshots) = self
+            .query_markets_uc
+            .execute()
+            .maybe_market_uuid(market_uuid)
+            .maybe_from_game_tick(request.from_game_tick)
+            .maybe_to_game_tick(request.to_game_tick)
+            .maybe_limit(request.limit)
+            .maybe_offset(request.offset)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        let snapshots = domain_snapshots
+            .into_iter()
+            .map(|snapshot| MarketSnapshot {
+                game_tick: snapshot.game_tick,
+                market_uuid: snapshot.market_uuid.to_string(),
+                market_name: snapshot.market_name.to_string(),
+                market_number: i16::from(snapshot.market_name) as i32,
+                volume: snapshot.volume,
+                invested_operational_expenses: snapshot.invested_operational_expenses,
+                active_businesses: snapshot.active_businesses,
+            })
+            .collect();
+
+        Ok(Response::new(QueryMarketsResponse {
+            snapshots,
+            total_count,
+        }))
+    }
+
     async fn acquire_listed_business(
         &self,
         request: tonic::Request<AcquireListedBusinessRequest>,
@@ -382,4 +602,440 @@ where
             request_uuid: request_uuid.to_string(),
         }))
     }
+
+    async fn list_business_for_sale(
+        &self,
+        request: tonic::Request<ListBusinessForSaleRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_uuid = Uuid::parse_str(&request.business_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business UUID"))?;
+
+        self.list_business_for_sale_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_uuid(business_uuid)
+            .asking_price(request.asking_price)
+            .maybe_auction_duration_ticks(request.auction_duration_ticks)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn cancel_business_listing(
+        &self,
+        request: tonic::Request<CancelBusinessListingRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_listing_uuid = Uuid::parse_str(&request.business_listing_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business listing UUID"))?;
+
+        self.cancel_business_listing_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_listing_uuid(business_listing_uuid)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn make_business_offer(
+        &self,
+        request: tonic::Request<MakeBusinessOfferRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_uuid = Uuid::parse_str(&request.business_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business UUID"))?;
+
+        self.make_business_offer_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_uuid(business_uuid)
+            .offer_price(request.offer_price)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn accept_business_offer(
+        &self,
+        request: tonic::Request<AcceptBusinessOfferRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_offer_uuid = Uuid::parse_str(&request.business_offer_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business offer UUID"))?;
+
+        self.accept_business_offer_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_offer_uuid(business_offer_uuid)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn reject_business_offer(
+        &self,
+        request: tonic::Request<RejectBusinessOfferRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_offer_uuid = Uuid::parse_str(&request.business_offer_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business offer UUID"))?;
+
+        self.reject_business_offer_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_offer_uuid(business_offer_uuid)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn withdraw_business_offer(
+        &self,
+        request: tonic::Request<WithdrawBusinessOfferRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_offer_uuid = Uuid::parse_str(&request.business_offer_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business offer UUID"))?;
+
+        self.withdraw_business_offer_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_offer_uuid(business_offer_uuid)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn set_operational_expenses(
+        &self,
+        request: tonic::Request<SetOperationalExpensesRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_uuid = Uuid::parse_str(&request.business_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business UUID"))?;
+
+        self.set_operational_expenses_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_uuid(business_uuid)
+            .operational_expenses(request.operational_expenses)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn take_loan(
+        &self,
+        request: tonic::Request<TakeLoanRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        self.take_loan_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .amount(request.amount)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn repay_loan(
+        &self,
+        request: tonic::Request<RepayLoanRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let loan_uuid = Uuid::parse_str(&request.loan_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid loan UUID"))?;
+
+        self.repay_loan_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .loan_uuid(loan_uuid)
+            .amount(request.amount)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn place_auction_bid(
+        &self,
+        request: tonic::Request<PlaceAuctionBidRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_listing_uuid = Uuid::parse_str(&request.business_listing_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business listing UUID"))?;
+
+        self.place_auction_bid_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_listing_uuid(business_listing_uuid)
+            .amount(request.amount)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+
+    async fn acquire_building(
+        &self,
+        request: tonic::Request<AcquireBuildingRequest>,
+    ) -> Result<tonic::Response<ActionInitResponse>, tonic::Status> {
+        check_rate_limit(
+            self.limit.clone(),
+            request.metadata(),
+            LimiterCategory::Game,
+        )
+        .await
+        .map_err(|status| *status)?;
+
+        let req_user_uuid = match uuid_from_metadata(request.metadata()) {
+            Ok(uuid) => uuid,
+            Err(status) => return Err(*status),
+        };
+
+        let request = request.into_inner();
+        let request_uuid = Uuid::now_v7();
+
+        let business_uuid = Uuid::parse_str(&request.business_uuid)
+            .map_err(|_| tonic::Status::invalid_argument("Invalid business UUID"))?;
+
+        self.acquire_building_uc
+            .execute()
+            .req_user_uuid(req_user_uuid)
+            .request_uuid(request_uuid)
+            .business_uuid(business_uuid)
+            .building_gml_id(request.building_gml_id)
+            .call()
+            .await
+            .map_err(PresentationError::from)?;
+
+        Ok(Response::new(ActionInitResponse {
+            request_uuid: request_uuid.to_string(),
+        }))
+    }
+}
+
+fn ledger_entry_kind_to_proto(kind: LedgerEntryKind) -> ProtoLedgerEntryKind {
+    match kind {
+        LedgerEntryKind::BusinessIncome => ProtoLedgerEntryKind::BusinessIncome,
+        LedgerEntryKind::OperationalExpenses => ProtoLedgerEntryKind::OperationalExpenses,
+        LedgerEntryKind::BusinessAcquisition => ProtoLedgerEntryKind::BusinessAcquisition,
+        LedgerEntryKind::BusinessSale => ProtoLedgerEntryKind::BusinessSale,
+        LedgerEntryKind::UnitSpawn => ProtoLedgerEntryKind::UnitSpawn,
+        LedgerEntryKind::OfferEscrow => ProtoLedgerEntryKind::OfferEscrow,
+        LedgerEntryKind::OfferRefund => ProtoLedgerEntryKind::OfferRefund,
+        LedgerEntryKind::LoanDisbursement => ProtoLedgerEntryKind::LoanDisbursement,
+        LedgerEntryKind::LoanRepayment => ProtoLedgerEntryKind::LoanRepayment,
+        LedgerEntryKind::AuctionEscrow => ProtoLedgerEntryKind::AuctionEscrow,
+        LedgerEntryKind::AuctionRefund => ProtoLedgerEntryKind::AuctionRefund,
+        LedgerEntryKind::BuildingAcquisition => ProtoLedgerEntryKind::BuildingAcquisition,
+        LedgerEntryKind::BuildingUpkeep => ProtoLedgerEntryKind::BuildingUpkeep,
+        LedgerEntryKind::TerritoryTax => ProtoLedgerEntryKind::TerritoryTax,
+        LedgerEntryKind::Espionage => ProtoLedgerEntryKind::Espionage,
+        LedgerEntryKind::Transfer => ProtoLedgerEntryKind::Transfer,
+        LedgerEntryKind::ContractEscrow => ProtoLedgerEntryKind::ContractEscrow,
+        LedgerEntryKind::ContractRefund => ProtoLedgerEntryKind::ContractRefund,
+        LedgerEntryKind::ContractPayment => ProtoLedgerEntryKind::ContractPayment,
+        LedgerEntryKind::ListingFee => ProtoLedgerEntryKind::ListingFee,
+    }
 }
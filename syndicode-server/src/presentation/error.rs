@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use syndicode_proto::syndicode_interface_v1::{
-    game_update::Update, ActionFailedResponse, GameUpdate,
+    game_update::Update, ActionFailedResponse, ActionFailureCode, GameUpdate,
 };
 use tonic::{Code, Status};
 use tonic_types::{ErrorDetails, StatusExt};
@@ -72,6 +72,7 @@ impl From<ApplicationError> for PresentationError {
             | ApplicationError::UserNameTooShort(_)
             | ApplicationError::CorporationNameTooShort(_)
             | ApplicationError::CorporationNameTooLong(_)
+            | ApplicationError::GameTickNotRetained(_)
             | ApplicationError::VerificationCodeFalse => Self::InvalidArgument(err.to_string()),
             ApplicationError::UserNameAlreadyTaken => Self::AlreadyExists {
                 field: "user_name".to_string(),
@@ -183,7 +184,28 @@ impl PresentationError {
             update: Some(Update::ActionFailedResponse(ActionFailedResponse {
                 request_uuid,
                 reason: self.to_string(),
+                code: self.failure_code().into(),
+                details: None,
             })),
         }
     }
+
+    fn failure_code(&self) -> ActionFailureCode {
+        match self {
+            Self::Unknown => ActionFailureCode::Unspecified,
+            Self::InvalidArgument(_) | Self::OutOfRange => ActionFailureCode::InvalidArgument,
+            Self::NotFound => ActionFailureCode::NotFound,
+            Self::AlreadyExists { .. } => ActionFailureCode::AlreadyExists,
+            Self::PermissionDenied | Self::Unauthenticated => ActionFailureCode::PermissionDenied,
+            Self::ResourceExhausted(_) => ActionFailureCode::RateLimited,
+            Self::FailedPrecondition(_) => ActionFailureCode::InvalidState,
+            Self::Cancelled
+            | Self::DeadlineExceeded
+            | Self::Aborted
+            | Self::Unimplemented
+            | Self::Internal
+            | Self::Unavailable
+            | Self::DataLoss => ActionFailureCode::Internal,
+        }
+    }
 }
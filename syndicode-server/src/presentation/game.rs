@@ -1,4 +1,7 @@
+mod diplomacy;
 mod economy;
+mod espionage;
+pub mod subscriptions;
 pub mod user_channel_guard;
 mod warfare;
 
@@ -8,11 +11,29 @@ use super::{
 };
 use crate::{
     application::{
+        diplomacy::{
+            accept_treaty::AcceptTreatyUseCase, break_treaty::BreakTreatyUseCase,
+            propose_treaty::ProposeTreatyUseCase,
+        },
         economy::{
+            accept_business_offer::AcceptBusinessOfferUseCase,
+            acquire_building::AcquireBuildingUseCase,
             acquire_listed_business::AcquireListedBusinessUseCase,
-            get_corporation::GetCorporationUseCase,
+            cancel_business_listing::CancelBusinessListingUseCase,
+            create_contract::CreateContractUseCase, get_corporation::GetCorporationUseCase,
+            list_business_for_sale::ListBusinessForSaleUseCase,
+            make_business_offer::MakeBusinessOfferUseCase,
+            place_auction_bid::PlaceAuctionBidUseCase,
             query_business_listings::QueryBusinessListingsUseCase,
             query_businesses::QueryBusinessesUseCase,
+            reject_business_offer::RejectBusinessOfferUseCase, repay_loan::RepayLoanUseCase,
+            set_operational_expenses::SetOperationalExpensesUseCase, take_loan::TakeLoanUseCase,
+            transfer_funds::TransferFundsUseCase,
+            withdraw_business_offer::WithdrawBusinessOfferUseCase,
+        },
+        espionage::{
+            counter_intel::CounterIntelUseCase, gather_intel::GatherIntelUseCase,
+            plant_mole::PlantMoleUseCase,
         },
         game::get_game_tick::GetGameTickUseCase,
         ports::{
@@ -22,7 +43,9 @@ use crate::{
             queuer::ActionQueueable,
         },
         warfare::{
-            list_units_by_corporation::ListUnitsByCorporationUseCase, spawn_unit::SpawnUnitUseCase,
+            attack_building::AttackBuildingUseCase,
+            list_units_by_corporation::ListUnitsByCorporationUseCase, move_unit::MoveUnitUseCase,
+            spawn_unit::SpawnUnitUseCase,
         },
     },
     domain::{
@@ -31,37 +54,77 @@ use crate::{
             business_listing::repository::BusinessListingRepository,
             corporation::repository::CorporationRepository,
         },
-        outcome::DomainActionOutcome,
+        outcome::{
+            ActionFailureCode, ActionFailureDetails, AuctionCloseReason, BattleResult,
+            BusinessOfferCloseReason, DomainActionOutcome, EspionageOperation, FailureEntityType,
+        },
         unit::repository::UnitRepository,
     },
     infrastructure::valkey::outcome::create_notification_channel,
 };
 use bon::{builder, Builder};
+use diplomacy::{accept_treaty, break_treaty, propose_treaty, treaty_type_to_proto};
 use economy::{
-    acquire_listed_business, get_corporation, query_business_listings, query_businesses,
+    accept_business_offer, acquire_building, acquire_listed_business, cancel_business_listing,
+    contract_to_proto, create_contract, get_corporation, list_business_for_sale,
+    make_business_offer, place_auction_bid, query_business_listings, query_businesses,
+    reject_business_offer, repay_loan, set_operational_expenses, take_loan, transfer_funds,
+    withdraw_business_offer,
+};
+use espionage::{
+    counter_intel, gather_intel, intel_report_to_proto, intel_type_to_proto, plant_mole,
 };
-use std::{pin::Pin, str::FromStr, sync::Arc};
+use std::{collections::HashSet, pin::Pin, str::FromStr, sync::Arc};
+use subscriptions::{subscribe, unsubscribe, UserSubscriptions};
 use syndicode_proto::{
+    syndicode_diplomacy_v1::{
+        AcceptTreatyResponse, BreakTreatyResponse, ProposeTreatyResponse,
+        TreatyConcludedNotification, TreatyProposalReceivedNotification,
+        TreatyTerminatedNotification,
+    },
     syndicode_economy_v1::{
-        AcquireListedBusinessResponse, Business, CreateCorporationResponse,
-        DeleteCorporationResponse,
+        AcceptBusinessOfferResponse, AcquireBuildingResponse, AcquireListedBusinessResponse,
+        AuctionCloseReason as ProtoAuctionCloseReason, AuctionClosedNotification, Business,
+        BusinessListing, BusinessOffer, BusinessOfferCloseReason as ProtoBusinessOfferCloseReason,
+        BusinessOfferClosedNotification, BusinessOfferReceivedNotification,
+        CancelBusinessListingResponse, ContractReceivedNotification, ContractSettledNotification,
+        CorporationBankruptNotification, CorporationInsolventNotification,
+        CorporationLedgerSummaryNotification, CreateContractResponse, CreateCorporationResponse,
+        DeleteCorporationResponse, FundsReceivedNotification, ListBusinessForSaleResponse, Loan,
+        LoanDefaultedNotification, MakeBusinessOfferResponse, PlaceAuctionBidResponse,
+        RejectBusinessOfferResponse, RepayLoanResponse, SetOperationalExpensesResponse,
+        TakeLoanResponse, TransferFundsResponse, WithdrawBusinessOfferResponse,
+    },
+    syndicode_espionage_v1::{
+        CounterIntelResponse, EspionageDetectedNotification,
+        EspionageOperation as ProtoEspionageOperation, ExposedMole, GatherIntelResponse,
+        IntelType as ProtoIntelType, MoleExposedNotification, PlantMoleResponse,
     },
     syndicode_interface_v1::{
-        game_service_server::GameService, game_update::Update, player_action::Action,
-        ActionFailedResponse, GameUpdate, PlayerAction, RateLimitExceededNotification,
+        action_failed_response::Details, game_service_server::GameService, game_update::Update,
+        player_action::Action, ActionFailedResponse, ActionFailureCode as ProtoActionFailureCode,
+        CooldownDetails, CorporationStateDiffNotification, EntityDetails,
+        FailureEntityType as ProtoFailureEntityType, GameUpdate, InsufficientFundsDetails,
+        InvalidValueDetails, PlayerAction, RateLimitExceededNotification,
+    },
+    syndicode_warfare_v1::{
+        AttackBuildingResponse, BattleReportNotification, BattleResult as ProtoBattleResult,
+        DestroyedUnit, MoveUnitResponse, SpawnUnitResponse, Unit, UnitArrivedNotification,
     },
-    syndicode_warfare_v1::{SpawnUnitResponse, Unit},
 };
 use tokio::sync::mpsc::{self, error::SendError};
 use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
-use tonic::{Code, Request, Response, Status, Streaming};
+use tonic::{metadata::MetadataMap, Code, Request, Response, Status, Streaming};
 use user_channel_guard::{UserChannelGuard, UserChannels, UserTx};
 use uuid::Uuid;
-use warfare::{list_units, spawn_unit};
+use warfare::{attack_building, list_units, move_unit, spawn_unit, unit_type_to_proto};
 
 // Buffer for server-to-client MPSC channels.
 const MPSC_CHANNEL_BUFFER_SIZE: usize = 128;
 
+// Metadata key with the last game tick a resuming client has seen.
+pub const LAST_SEEN_GAME_TICK_KEY: &str = "last-seen-game-tick";
+
 #[derive(Builder)]
 pub struct GamePresenter<R, Q, UNT, CRP, OSR, GTR, BL, B>
 where
@@ -78,11 +141,34 @@ where
     pub limit: Arc<R>,
     pub outcome_store_reader: Arc<OSR>,
     pub user_channels: UserChannels,
+    pub user_subscriptions: UserSubscriptions,
+    pub max_subscriptions_per_user: usize,
     pub get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
     pub get_corporation_uc: Arc<GetCorporationUseCase<CRP>>,
-    pub list_units_by_corporation_uc: Arc<ListUnitsByCorporationUseCase<UNT>>,
+    pub list_units_by_corporation_uc: Arc<ListUnitsByCorporationUseCase<UNT, CRP>>,
     pub spawn_unit_uc: Arc<SpawnUnitUseCase<Q, GTR>>,
+    pub move_unit_uc: Arc<MoveUnitUseCase<Q, GTR>>,
+    pub attack_building_uc: Arc<AttackBuildingUseCase<Q, GTR>>,
     pub acquire_listed_business_uc: Arc<AcquireListedBusinessUseCase<Q, GTR>>,
+    pub list_business_for_sale_uc: Arc<ListBusinessForSaleUseCase<Q, GTR>>,
+    pub cancel_business_listing_uc: Arc<CancelBusinessListingUseCase<Q, GTR>>,
+    pub make_business_offer_uc: Arc<MakeBusinessOfferUseCase<Q, GTR>>,
+    pub accept_business_offer_uc: Arc<AcceptBusinessOfferUseCase<Q, GTR>>,
+    pub reject_business_offer_uc: Arc<RejectBusinessOfferUseCase<Q, GTR>>,
+    pub withdraw_business_offer_uc: Arc<WithdrawBusinessOfferUseCase<Q, GTR>>,
+    pub set_operational_expenses_uc: Arc<SetOperationalExpensesUseCase<Q, GTR>>,
+    pub take_loan_uc: Arc<TakeLoanUseCase<Q, GTR>>,
+    pub repay_loan_uc: Arc<RepayLoanUseCase<Q, GTR>>,
+    pub transfer_funds_uc: Arc<TransferFundsUseCase<Q, GTR>>,
+    pub create_contract_uc: Arc<CreateContractUseCase<Q, GTR>>,
+    pub place_auction_bid_uc: Arc<PlaceAuctionBidUseCase<Q, GTR>>,
+    pub acquire_building_uc: Arc<AcquireBuildingUseCase<Q, GTR>>,
+    pub gather_intel_uc: Arc<GatherIntelUseCase<Q, GTR>>,
+    pub plant_mole_uc: Arc<PlantMoleUseCase<Q, GTR>>,
+    pub counter_intel_uc: Arc<CounterIntelUseCase<Q, GTR>>,
+    pub propose_treaty_uc: Arc<ProposeTreatyUseCase<Q, GTR>>,
+    pub accept_treaty_uc: Arc<AcceptTreatyUseCase<Q, GTR>>,
+    pub break_treaty_uc: Arc<BreakTreatyUseCase<Q, GTR>>,
     pub query_business_listings_uc: Arc<QueryBusinessListingsUseCase<BL>>,
     pub query_businesses_uc: Arc<QueryBusinessesUseCase<B>>,
 }
@@ -109,6 +195,9 @@ where
 
         let user_uuid = uuid_from_metadata(request.metadata()).map_err(|status| *status)?;
 
+        let last_seen_game_tick =
+            last_seen_game_tick_from_metadata(request.metadata()).map_err(|status| *status)?;
+
         // Setup Communication Channel
         let (tx_raw, rx) = mpsc::channel(MPSC_CHANNEL_BUFFER_SIZE);
         let user_channel_tx_arc = Arc::new(tx_raw); // Wrap the sender in an Arc
@@ -131,12 +220,35 @@ where
         let get_corporation_uc = Arc::clone(&self.get_corporation_uc);
         let list_units_by_corporation_uc = Arc::clone(&self.list_units_by_corporation_uc);
         let acquire_listed_business_uc = Arc::clone(&self.acquire_listed_business_uc);
+        let list_business_for_sale_uc = Arc::clone(&self.list_business_for_sale_uc);
+        let cancel_business_listing_uc = Arc::clone(&self.cancel_business_listing_uc);
+        let make_business_offer_uc = Arc::clone(&self.make_business_offer_uc);
+        let accept_business_offer_uc = Arc::clone(&self.accept_business_offer_uc);
+        let reject_business_offer_uc = Arc::clone(&self.reject_business_offer_uc);
+        let withdraw_business_offer_uc = Arc::clone(&self.withdraw_business_offer_uc);
+        let set_operational_expenses_uc = Arc::clone(&self.set_operational_expenses_uc);
+        let take_loan_uc = Arc::clone(&self.take_loan_uc);
+        let repay_loan_uc = Arc::clone(&self.repay_loan_uc);
+        let transfer_funds_uc = Arc::clone(&self.transfer_funds_uc);
+        let create_contract_uc = Arc::clone(&self.create_contract_uc);
+        let place_auction_bid_uc = Arc::clone(&self.place_auction_bid_uc);
+        let acquire_building_uc = Arc::clone(&self.acquire_building_uc);
+        let gather_intel_uc = Arc::clone(&self.gather_intel_uc);
+        let plant_mole_uc = Arc::clone(&self.plant_mole_uc);
+        let counter_intel_uc = Arc::clone(&self.counter_intel_uc);
+        let propose_treaty_uc = Arc::clone(&self.propose_treaty_uc);
+        let accept_treaty_uc = Arc::clone(&self.accept_treaty_uc);
+        let break_treaty_uc = Arc::clone(&self.break_treaty_uc);
         let query_business_listings_uc = Arc::clone(&self.query_business_listings_uc);
         let query_businesses_uc = Arc::clone(&self.query_businesses_uc);
         let spawn_unit_uc = Arc::clone(&self.spawn_unit_uc);
+        let move_unit_uc = Arc::clone(&self.move_unit_uc);
+        let attack_building_uc = Arc::clone(&self.attack_building_uc);
 
         let limit = Arc::clone(&self.limit);
         let user_channels_clone_for_guard = Arc::clone(&self.user_channels);
+        let user_subscriptions = Arc::clone(&self.user_subscriptions);
+        let max_subscriptions_per_user = self.max_subscriptions_per_user;
         let outcome_reader_clone = Arc::clone(&self.outcome_store_reader);
 
         let user_channel_tx_arc_for_action_task = user_channel_tx_arc.clone();
@@ -147,6 +259,7 @@ where
             let _channel_guard = UserChannelGuard {
                 user_id: user_uuid,
                 channels: user_channels_clone_for_guard,
+                subscriptions: user_subscriptions.clone(),
                 channel_instance: user_channel_tx_arc.clone(), // Guard is responsible for this specific Arc<UserTx>
             };
 
@@ -181,9 +294,32 @@ where
                                 .get_corporation_uc(get_corporation_uc.clone())
                                 .list_units_by_corporation_uc(list_units_by_corporation_uc.clone())
                                 .spawn_unit_uc(spawn_unit_uc.clone())
+                                .move_unit_uc(move_unit_uc.clone())
+                                .attack_building_uc(attack_building_uc.clone())
                                 .acquire_listed_business_uc(acquire_listed_business_uc.clone())
+                                .list_business_for_sale_uc(list_business_for_sale_uc.clone())
+                                .cancel_business_listing_uc(cancel_business_listing_uc.clone())
+                                .make_business_offer_uc(make_business_offer_uc.clone())
+                                .accept_business_offer_uc(accept_business_offer_uc.clone())
+                                .reject_business_offer_uc(reject_business_offer_uc.clone())
+                                .withdraw_business_offer_uc(withdraw_business_offer_uc.clone())
+                                .set_operational_expenses_uc(set_operational_expenses_uc.clone())
+                                .take_loan_uc(take_loan_uc.clone())
+                                .repay_loan_uc(repay_loan_uc.clone())
+                                .transfer_funds_uc(transfer_funds_uc.clone())
+                                .create_contract_uc(create_contract_uc.clone())
+                                .place_auction_bid_uc(place_auction_bid_uc.clone())
+                                .acquire_building_uc(acquire_building_uc.clone())
+                                .gather_intel_uc(gather_intel_uc.clone())
+                                .plant_mole_uc(plant_mole_uc.clone())
+                                .counter_intel_uc(counter_intel_uc.clone())
+                                .propose_treaty_uc(propose_treaty_uc.clone())
+                                .accept_treaty_uc(accept_treaty_uc.clone())
+                                .break_treaty_uc(break_treaty_uc.clone())
                                 .query_business_listings_uc(query_business_listings_uc.clone())
                                 .query_businesses_uc(query_businesses_uc.clone())
+                                .user_subscriptions(user_subscriptions.clone())
+                                .max_subscriptions_per_user(max_subscriptions_per_user)
                                 .request_uuid(player_action.request_uuid)
                                 .call()
                                 .await;
@@ -259,6 +395,29 @@ where
             tracing::info!(user_uuid=%user_uuid, channel=%channel_name, "Subscribed to outcome notifications");
             let mut message_stream = pubsub_conn.on_message();
 
+            // Outcomes that are published while the backlog is replayed are delivered only once
+            let mut replayed_request_uuids = HashSet::new();
+            let mut replayed_until_game_tick = 0;
+            if let Some(last_seen_game_tick) = last_seen_game_tick {
+                match replay_backlog(
+                    &user_channel_tx_arc_for_outcome_task,
+                    outcome_reader_clone.as_ref(),
+                    user_uuid,
+                    last_seen_game_tick,
+                )
+                .await
+                {
+                    Ok((request_uuids, game_tick)) => {
+                        replayed_request_uuids = request_uuids;
+                        replayed_until_game_tick = game_tick;
+                    }
+                    Err(_) => {
+                        tracing::warn!(user_uuid=%user_uuid, "Failed to replay backlog, client likely disconnected. Terminating outcome listener for this user.");
+                        return;
+                    }
+                }
+            }
+
             'while_msg: while let Some(msg) = message_stream.next().await {
                 let request_uuid_str: String = match msg.get_payload() {
                     Ok(id) => id,
@@ -278,6 +437,10 @@ where
 
                 tracing::debug!(user_uuid=%user_uuid, request_uuid=%request_uuid, "Received outcome notification");
 
+                if replayed_request_uuids.remove(&request_uuid) {
+                    continue 'while_msg;
+                }
+
                 match outcome_reader_clone.retrieve_outcome(request_uuid).await {
                     Ok(Some(payload_bytes)) => {
                         let send_res = send_specific_result(
@@ -287,12 +450,18 @@ where
                         )
                         .await;
 
-                        if send_res.is_err() {
+                        let Ok(game_tick) = send_res else {
                             tracing::warn!(user_uuid=%user_uuid, request_uuid=%request_uuid, "Failed to send final outcome, client likely disconnected. Terminating outcome listener for this user.");
                             break 'while_msg; // Client disconnected, stop listening for outcomes for this user
-                        } else if let Err(err) =
-                            outcome_reader_clone.delete_outcome(request_uuid).await
-                        {
+                        };
+
+                        // Outcomes are published in tick order, so none of the replayed ones
+                        // can follow once a live outcome is past the replayed ticks
+                        if game_tick.is_some_and(|game_tick| game_tick > replayed_until_game_tick) {
+                            replayed_request_uuids = HashSet::new();
+                        }
+
+                        if let Err(err) = outcome_reader_clone.delete_outcome(request_uuid).await {
                             tracing::warn!(user_uuid=%user_uuid, request_uuid=%request_uuid, error=%err, "Failed to delete outcome from store");
                         }
                     }
@@ -323,11 +492,34 @@ async fn process_stream_action<Q, UNT, CRP, GTR, BL, B>(
     user_uuid: Uuid,
     get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
     get_corporation_uc: Arc<GetCorporationUseCase<CRP>>,
-    list_units_by_corporation_uc: Arc<ListUnitsByCorporationUseCase<UNT>>,
+    list_units_by_corporation_uc: Arc<ListUnitsByCorporationUseCase<UNT, CRP>>,
     spawn_unit_uc: Arc<SpawnUnitUseCase<Q, GTR>>,
+    move_unit_uc: Arc<MoveUnitUseCase<Q, GTR>>,
+    attack_building_uc: Arc<AttackBuildingUseCase<Q, GTR>>,
     acquire_listed_business_uc: Arc<AcquireListedBusinessUseCase<Q, GTR>>,
+    list_business_for_sale_uc: Arc<ListBusinessForSaleUseCase<Q, GTR>>,
+    cancel_business_listing_uc: Arc<CancelBusinessListingUseCase<Q, GTR>>,
+    make_business_offer_uc: Arc<MakeBusinessOfferUseCase<Q, GTR>>,
+    accept_business_offer_uc: Arc<AcceptBusinessOfferUseCase<Q, GTR>>,
+    reject_business_offer_uc: Arc<RejectBusinessOfferUseCase<Q, GTR>>,
+    withdraw_business_offer_uc: Arc<WithdrawBusinessOfferUseCase<Q, GTR>>,
+    set_operational_expenses_uc: Arc<SetOperationalExpensesUseCase<Q, GTR>>,
+    take_loan_uc: Arc<TakeLoanUseCase<Q, GTR>>,
+    repay_loan_uc: Arc<RepayLoanUseCase<Q, GTR>>,
+    transfer_funds_uc: Arc<TransferFundsUseCase<Q, GTR>>,
+    create_contract_uc: Arc<CreateContractUseCase<Q, GTR>>,
+    place_auction_bid_uc: Arc<PlaceAuctionBidUseCase<Q, GTR>>,
+    acquire_building_uc: Arc<AcquireBuildingUseCase<Q, GTR>>,
+    gather_intel_uc: Arc<GatherIntelUseCase<Q, GTR>>,
+    plant_mole_uc: Arc<PlantMoleUseCase<Q, GTR>>,
+    counter_intel_uc: Arc<CounterIntelUseCase<Q, GTR>>,
+    propose_treaty_uc: Arc<ProposeTreatyUseCase<Q, GTR>>,
+    accept_treaty_uc: Arc<AcceptTreatyUseCase<Q, GTR>>,
+    break_treaty_uc: Arc<BreakTreatyUseCase<Q, GTR>>,
     query_business_listings_uc: Arc<QueryBusinessListingsUseCase<BL>>,
     query_businesses_uc: Arc<QueryBusinessesUseCase<B>>,
+    user_subscriptions: UserSubscriptions,
+    max_subscriptions_per_user: usize,
 ) -> Result<(), SendError<Result<GameUpdate, Status>>>
 where
     Q: ActionQueueable,
@@ -347,21 +539,107 @@ where
     };
 
     let result = match action {
-        Action::GetCorporation(_) => {
+        Action::GetCorporation(req) => {
             get_corporation()
                 .get_game_tick_uc(get_game_tick_uc)
                 .get_corporation_uc(get_corporation_uc)
                 .user_uuid(user_uuid)
                 .request_uuid(request_uuid)
+                .maybe_at_game_tick(req.at_game_tick)
                 .call()
                 .await
         }
-        Action::SpawnUnit(_) => {
+        Action::SpawnUnit(req) => {
             spawn_unit()
                 .get_game_tick_uc(get_game_tick_uc)
                 .request_uuid(request_uuid)
                 .req_user_uuid(user_uuid)
                 .spawn_unit_uc(spawn_unit_uc)
+                .unit_type(req.unit_type)
+                .building_gml_id(req.building_gml_id)
+                .call()
+                .await
+        }
+        Action::GatherIntel(req) => {
+            gather_intel()
+                .get_game_tick_uc(get_game_tick_uc)
+                .gather_intel_uc(gather_intel_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .target_corporation_uuid(req.target_corporation_uuid)
+                .intel_type(req.intel_type)
+                .call()
+                .await
+        }
+        Action::PlantMole(req) => {
+            plant_mole()
+                .get_game_tick_uc(get_game_tick_uc)
+                .plant_mole_uc(plant_mole_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .target_corporation_uuid(req.target_corporation_uuid)
+                .call()
+                .await
+        }
+        Action::CounterIntel(_) => {
+            counter_intel()
+                .get_game_tick_uc(get_game_tick_uc)
+                .counter_intel_uc(counter_intel_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .call()
+                .await
+        }
+        Action::ProposeTreaty(req) => {
+            propose_treaty()
+                .get_game_tick_uc(get_game_tick_uc)
+                .propose_treaty_uc(propose_treaty_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .partner_corporation_uuid(req.partner_corporation_uuid)
+                .treaty_type(req.treaty_type)
+                .call()
+                .await
+        }
+        Action::AcceptTreaty(req) => {
+            accept_treaty()
+                .get_game_tick_uc(get_game_tick_uc)
+                .accept_treaty_uc(accept_treaty_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .treaty_uuid(req.treaty_uuid)
+                .call()
+                .await
+        }
+        Action::BreakTreaty(req) => {
+            break_treaty()
+                .get_game_tick_uc(get_game_tick_uc)
+                .break_treaty_uc(break_treaty_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .treaty_uuid(req.treaty_uuid)
+                .call()
+                .await
+        }
+        Action::MoveUnit(req) => {
+            move_unit()
+                .get_game_tick_uc(get_game_tick_uc)
+                .move_unit_uc(move_unit_uc)
+                .request_uuid(request_uuid)
+                .req_user_uuid(user_uuid)
+                .unit_uuid(req.unit_uuid)
+                .destination_building_gml_id(req.destination_building_gml_id)
+                .call()
+                .await
+        }
+        Action::AttackBuilding(req) => {
+            attack_building()
+                .get_game_tick_uc(get_game_tick_uc)
+                .attack_building_uc(attack_building_uc)
+                .request_uuid(request_uuid)
+                .req_user_uuid(user_uuid)
+                .building_gml_id(req.building_gml_id)
+                .unit_uuids(req.unit_uuids)
                 .call()
                 .await
         }
@@ -370,6 +648,7 @@ where
                 .get_game_tick_uc(get_game_tick_uc)
                 .corporation_uuid(req.corporation_uuid)
                 .request_uuid(request_uuid)
+                .req_user_uuid(user_uuid)
                 .list_units_by_corporation_uc(list_units_by_corporation_uc)
                 .call()
                 .await
@@ -384,6 +663,148 @@ where
                 .call()
                 .await
         }
+        Action::ListBusinessForSale(req) => {
+            list_business_for_sale()
+                .get_game_tick_uc(get_game_tick_uc)
+                .list_business_for_sale_uc(list_business_for_sale_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_uuid(req.business_uuid)
+                .asking_price(req.asking_price)
+                .maybe_auction_duration_ticks(req.auction_duration_ticks)
+                .call()
+                .await
+        }
+        Action::CancelBusinessListing(req) => {
+            cancel_business_listing()
+                .get_game_tick_uc(get_game_tick_uc)
+                .cancel_business_listing_uc(cancel_business_listing_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_listing_uuid(req.business_listing_uuid)
+                .call()
+                .await
+        }
+        Action::MakeBusinessOffer(req) => {
+            make_business_offer()
+                .get_game_tick_uc(get_game_tick_uc)
+                .make_business_offer_uc(make_business_offer_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_uuid(req.business_uuid)
+                .offer_price(req.offer_price)
+                .call()
+                .await
+        }
+        Action::AcceptBusinessOffer(req) => {
+            accept_business_offer()
+                .get_game_tick_uc(get_game_tick_uc)
+                .accept_business_offer_uc(accept_business_offer_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_offer_uuid(req.business_offer_uuid)
+                .call()
+                .await
+        }
+        Action::RejectBusinessOffer(req) => {
+            reject_business_offer()
+                .get_game_tick_uc(get_game_tick_uc)
+                .reject_business_offer_uc(reject_business_offer_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_offer_uuid(req.business_offer_uuid)
+                .call()
+                .await
+        }
+        Action::WithdrawBusinessOffer(req) => {
+            withdraw_business_offer()
+                .get_game_tick_uc(get_game_tick_uc)
+                .withdraw_business_offer_uc(withdraw_business_offer_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_offer_uuid(req.business_offer_uuid)
+                .call()
+                .await
+        }
+        Action::SetOperationalExpenses(req) => {
+            set_operational_expenses()
+                .get_game_tick_uc(get_game_tick_uc)
+                .set_operational_expenses_uc(set_operational_expenses_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_uuid(req.business_uuid)
+                .operational_expenses(req.operational_expenses)
+                .call()
+                .await
+        }
+        Action::TakeLoan(req) => {
+            take_loan()
+                .get_game_tick_uc(get_game_tick_uc)
+                .take_loan_uc(take_loan_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .amount(req.amount)
+                .call()
+                .await
+        }
+        Action::RepayLoan(req) => {
+            repay_loan()
+                .get_game_tick_uc(get_game_tick_uc)
+                .repay_loan_uc(repay_loan_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .loan_uuid(req.loan_uuid)
+                .amount(req.amount)
+                .call()
+                .await
+        }
+        Action::TransferFunds(req) => {
+            transfer_funds()
+                .get_game_tick_uc(get_game_tick_uc)
+                .transfer_funds_uc(transfer_funds_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .recipient_corporation_uuid(req.recipient_corporation_uuid)
+                .amount(req.amount)
+                .call()
+                .await
+        }
+        Action::CreateContract(req) => {
+            create_contract()
+                .get_game_tick_uc(get_game_tick_uc)
+                .create_contract_uc(create_contract_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .payee_corporation_uuid(req.payee_corporation_uuid)
+                .amount(req.amount)
+                .due_at_tick(req.due_at_tick)
+                .condition_type(req.condition_type)
+                .maybe_business_uuid(req.business_uuid)
+                .call()
+                .await
+        }
+        Action::PlaceAuctionBid(req) => {
+            place_auction_bid()
+                .get_game_tick_uc(get_game_tick_uc)
+                .place_auction_bid_uc(place_auction_bid_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_listing_uuid(req.business_listing_uuid)
+                .amount(req.amount)
+                .call()
+                .await
+        }
+        Action::AcquireBuilding(req) => {
+            acquire_building()
+                .get_game_tick_uc(get_game_tick_uc)
+                .acquire_building_uc(acquire_building_uc)
+                .req_user_uuid(user_uuid)
+                .request_uuid(request_uuid)
+                .business_uuid(req.business_uuid)
+                .building_gml_id(req.building_gml_id)
+                .call()
+                .await
+        }
         Action::QueryBusinessListings(req) => {
             query_business_listings()
                 .get_game_tick_uc(get_game_tick_uc)
@@ -398,29 +819,113 @@ where
                 .get_game_tick_uc(get_game_tick_uc)
                 .req(req)
                 .request_uuid(request_uuid)
+                .req_user_uuid(user_uuid)
                 .query_businesses_uc(query_businesses_uc)
                 .call()
                 .await
         }
+        Action::Subscribe(req) => {
+            subscribe()
+                .get_game_tick_uc(get_game_tick_uc)
+                .user_subscriptions(user_subscriptions)
+                .max_subscriptions_per_user(max_subscriptions_per_user)
+                .request_uuid(request_uuid)
+                .req_user_uuid(user_uuid)
+                .maybe_topic(req.topic)
+                .call()
+                .await
+        }
+        Action::Unsubscribe(req) => {
+            unsubscribe()
+                .get_game_tick_uc(get_game_tick_uc)
+                .user_subscriptions(user_subscriptions)
+                .request_uuid(request_uuid)
+                .req_user_uuid(user_uuid)
+                .subscription_uuid(req.subscription_uuid)
+                .call()
+                .await
+        }
     };
 
     tx.send(result).await
 }
 
+/// Sends the backlogged outcomes after the last seen tick in order and returns their request UUIDs
+/// along with the last tick they belong to.
+async fn replay_backlog<OSR>(
+    tx: &UserTx,
+    outcome_reader: &OSR,
+    user_uuid: Uuid,
+    last_seen_game_tick: i64,
+) -> Result<(HashSet<Uuid>, i64), SendError<Result<GameUpdate, Status>>>
+where
+    OSR: OutcomeStoreReader,
+{
+    let payloads = match outcome_reader
+        .retrieve_backlog(user_uuid, last_seen_game_tick)
+        .await
+    {
+        Ok(payloads) => payloads,
+        Err(err) => {
+            tracing::error!(user_uuid=%user_uuid, error=%err, "Failed to retrieve outcome backlog");
+            return Ok((HashSet::new(), last_seen_game_tick));
+        }
+    };
+
+    tracing::debug!(user_uuid=%user_uuid, last_seen_game_tick, num_outcomes = payloads.len(), "Replaying outcome backlog");
+
+    let mut request_uuids = HashSet::with_capacity(payloads.len());
+    let mut replayed_until_game_tick = last_seen_game_tick;
+    for payload_bytes in payloads {
+        match rmp_serde::from_slice::<DomainActionOutcome>(&payload_bytes) {
+            Ok(outcome) => {
+                request_uuids.insert(outcome.get_request_uuid());
+                let game_update = outcome_to_grpc_update(outcome);
+                replayed_until_game_tick = replayed_until_game_tick.max(game_update.game_tick);
+                tx.send(Ok(game_update)).await?;
+            }
+            Err(err) => {
+                tracing::error!(user_uuid=%user_uuid, error=%err, "Failed to deserialize backlogged outcome");
+            }
+        }
+    }
+
+    Ok((request_uuids, replayed_until_game_tick))
+}
+
+fn last_seen_game_tick_from_metadata(metadata: &MetadataMap) -> Result<Option<i64>, Box<Status>> {
+    let Some(value) = metadata.get(LAST_SEEN_GAME_TICK_KEY) else {
+        return Ok(None);
+    };
+
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.parse::<i64>().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            Box::new(Status::invalid_argument(
+                "Failed to parse last seen game tick",
+            ))
+        })
+}
+
+/// Returns the tick of the sent update, if the payload could be deserialized.
 async fn send_specific_result(
     tx: &UserTx, // Takes a reference to the mpsc::Sender
     payload_bytes: &[u8],
     user_uuid: Uuid,
-) -> Result<(), SendError<Result<GameUpdate, Status>>> {
+) -> Result<Option<i64>, SendError<Result<GameUpdate, Status>>> {
     match rmp_serde::from_slice::<DomainActionOutcome>(payload_bytes) {
         Ok(outcome) => {
             let game_update = outcome_to_grpc_update(outcome);
+            let game_tick = game_update.game_tick;
 
-            tx.send(Ok(game_update)).await
+            tx.send(Ok(game_update)).await.map(|_| Some(game_tick))
         }
         Err(err) => {
             tracing::error!(user_uuid=%user_uuid, error=%err, "Failed to deserialize result payload for outcome delivery");
-            Ok(())
+            Ok(None)
         }
     }
 }
@@ -430,6 +935,12 @@ fn outcome_to_grpc_update(outcome: DomainActionOutcome) -> GameUpdate {
         DomainActionOutcome::UnitSpawned {
             unit_uuid,
             corporation_uuid,
+            unit_type,
+            health,
+            attack,
+            defense,
+            building_gml_id,
+            spawn_cost,
             tick_effective,
             request_uuid,
             ..
@@ -439,10 +950,132 @@ fn outcome_to_grpc_update(outcome: DomainActionOutcome) -> GameUpdate {
                 unit: Some(Unit {
                     uuid: unit_uuid.to_string(),
                     corporation_uuid: corporation_uuid.to_string(),
+                    unit_type: unit_type_to_proto(unit_type).into(),
+                    health,
+                    attack,
+                    defense,
+                    building_gml_id,
+                    destination_building_gml_id: None,
+                    arrival_tick: None,
+                    attack_target_building_gml_id: None,
                 }),
+                spawn_cost,
             };
             (Update::SpawnUnit(response), tick_effective)
         }
+        DomainActionOutcome::UnitMoved {
+            unit_uuid,
+            corporation_uuid,
+            unit_type,
+            health,
+            attack,
+            defense,
+            building_gml_id,
+            destination_building_gml_id,
+            arrival_tick,
+            tick_effective,
+            request_uuid,
+            ..
+        } => {
+            let response = MoveUnitResponse {
+                request_uuid: request_uuid.to_string(),
+                unit: Some(Unit {
+                    uuid: unit_uuid.to_string(),
+                    corporation_uuid: corporation_uuid.to_string(),
+                    unit_type: unit_type_to_proto(unit_type).into(),
+                    health,
+                    attack,
+                    defense,
+                    building_gml_id,
+                    destination_building_gml_id: Some(destination_building_gml_id),
+                    arrival_tick: Some(arrival_tick),
+                    attack_target_building_gml_id: None,
+                }),
+            };
+            (Update::MoveUnit(response), tick_effective)
+        }
+        DomainActionOutcome::UnitArrived {
+            unit_uuid,
+            corporation_uuid,
+            unit_type,
+            health,
+            attack,
+            defense,
+            building_gml_id,
+            attack_target_building_gml_id,
+            tick_effective,
+            ..
+        } => {
+            let notification = UnitArrivedNotification {
+                unit: Some(Unit {
+                    uuid: unit_uuid.to_string(),
+                    corporation_uuid: corporation_uuid.to_string(),
+                    unit_type: unit_type_to_proto(unit_type).into(),
+                    health,
+                    attack,
+                    defense,
+                    building_gml_id,
+                    destination_building_gml_id: None,
+                    arrival_tick: None,
+                    attack_target_building_gml_id,
+                }),
+            };
+            (Update::UnitArrived(notification), tick_effective)
+        }
+        DomainActionOutcome::BuildingAttacked {
+            request_uuid,
+            tick_effective,
+            building_gml_id,
+            unit_uuids,
+            arrival_tick,
+            ..
+        } => {
+            let response = AttackBuildingResponse {
+                request_uuid: request_uuid.to_string(),
+                building_gml_id,
+                unit_uuids: unit_uuids.iter().map(|uuid| uuid.to_string()).collect(),
+                arrival_tick,
+            };
+            (Update::AttackBuilding(response), tick_effective)
+        }
+        DomainActionOutcome::BattleReported {
+            tick_effective,
+            building_gml_id,
+            business_uuid,
+            defender_corporation_uuid,
+            attacker_corporation_uuids,
+            destroyed_units,
+            result,
+            captured_business_uuid,
+            capturing_corporation_uuid,
+            ..
+        } => {
+            let result = match result {
+                BattleResult::Ongoing => ProtoBattleResult::Ongoing,
+                BattleResult::AttackersWon => ProtoBattleResult::AttackersWon,
+                BattleResult::DefendersWon => ProtoBattleResult::DefendersWon,
+            };
+            let notification = BattleReportNotification {
+                building_gml_id,
+                business_uuid: business_uuid.to_string(),
+                defender_corporation_uuid: defender_corporation_uuid.to_string(),
+                attacker_corporation_uuids: attacker_corporation_uuids
+                    .iter()
+                    .map(|uuid| uuid.to_string())
+                    .collect(),
+                destroyed_units: destroyed_units
+                    .into_iter()
+                    .map(|unit| DestroyedUnit {
+                        unit_uuid: unit.unit_uuid.to_string(),
+                        corporation_uuid: unit.corporation_uuid.to_string(),
+                    })
+                    .collect(),
+                result: result.into(),
+                captured_business_uuid: captured_business_uuid.map(|uuid| uuid.to_string()),
+                capturing_corporation_uuid: capturing_corporation_uuid.map(|uuid| uuid.to_string()),
+            };
+            (Update::BattleReport(notification), tick_effective)
+        }
         DomainActionOutcome::ListedBusinessAcquired {
             request_uuid,
             tick_effective,
@@ -467,6 +1100,662 @@ fn outcome_to_grpc_update(outcome: DomainActionOutcome) -> GameUpdate {
             };
             (Update::AcquireListedBusiness(response), tick_effective)
         }
+        DomainActionOutcome::BusinessListed {
+            request_uuid,
+            tick_effective,
+            business_listing_uuid,
+            business_uuid,
+            seller_corporation_uuid,
+            asking_price,
+            auction_closes_at_tick,
+            ..
+        } => {
+            let response = ListBusinessForSaleResponse {
+                request_uuid: request_uuid.to_string(),
+                listing: Some(BusinessListing {
+                    uuid: business_listing_uuid.to_string(),
+                    business_uuid: business_uuid.to_string(),
+                    seller_corporation_uuid: Some(seller_corporation_uuid.to_string()),
+                    asking_price,
+                    auction_closes_at_tick,
+                }),
+            };
+            (Update::ListBusinessForSale(response), tick_effective)
+        }
+        DomainActionOutcome::BusinessListingCancelled {
+            request_uuid,
+            tick_effective,
+            business_listing_uuid,
+            business_uuid,
+            ..
+        } => {
+            let response = CancelBusinessListingResponse {
+                request_uuid: request_uuid.to_string(),
+                business_listing_uuid: business_listing_uuid.to_string(),
+                business_uuid: business_uuid.to_string(),
+            };
+            (Update::CancelBusinessListing(response), tick_effective)
+        }
+        DomainActionOutcome::BusinessOfferMade {
+            request_uuid,
+            tick_effective,
+            business_offer_uuid,
+            business_uuid,
+            offering_corporation_uuid,
+            target_corporation_uuid,
+            offer_price,
+            expires_at_tick,
+            ..
+        } => {
+            let response = MakeBusinessOfferResponse {
+                request_uuid: request_uuid.to_string(),
+                offer: Some(BusinessOffer {
+                    uuid: business_offer_uuid.to_string(),
+                    business_uuid: business_uuid.to_string(),
+                    offering_corporation_uuid: offering_corporation_uuid.to_string(),
+                    target_corporation_uuid: Some(target_corporation_uuid.to_string()),
+                    offer_price,
+                    expires_at_tick,
+                }),
+            };
+            (Update::MakeBusinessOffer(response), tick_effective)
+        }
+        DomainActionOutcome::BusinessOfferReceived {
+            tick_effective,
+            business_offer_uuid,
+            business_uuid,
+            offering_corporation_uuid,
+            target_corporation_uuid,
+            offer_price,
+            expires_at_tick,
+            ..
+        } => {
+            let notification = BusinessOfferReceivedNotification {
+                offer: Some(BusinessOffer {
+                    uuid: business_offer_uuid.to_string(),
+                    business_uuid: business_uuid.to_string(),
+                    offering_corporation_uuid: offering_corporation_uuid.to_string(),
+                    target_corporation_uuid: Some(target_corporation_uuid.to_string()),
+                    offer_price,
+                    expires_at_tick,
+                }),
+            };
+            (Update::BusinessOfferReceived(notification), tick_effective)
+        }
+        DomainActionOutcome::BusinessOfferAccepted {
+            request_uuid,
+            tick_effective,
+            business_offer_uuid,
+            business_uuid,
+            market_uuid,
+            owning_corporation_uuid,
+            name,
+            operational_expenses,
+            image_number,
+            offer_price,
+            ..
+        } => {
+            let response = AcceptBusinessOfferResponse {
+                request_uuid: request_uuid.to_string(),
+                business_offer_uuid: business_offer_uuid.to_string(),
+                business: Some(Business {
+                    uuid: business_uuid.to_string(),
+                    market_uuid: market_uuid.to_string(),
+                    owning_corporation_uuid: owning_corporation_uuid.to_string(),
+                    name,
+                    operational_expenses,
+                    image_number: image_number as i32,
+                }),
+                offer_price,
+            };
+            (Update::AcceptBusinessOffer(response), tick_effective)
+        }
+        DomainActionOutcome::BusinessOfferRejected {
+            request_uuid,
+            tick_effective,
+            business_offer_uuid,
+            ..
+        } => {
+            let response = RejectBusinessOfferResponse {
+                request_uuid: request_uuid.to_string(),
+                business_offer_uuid: business_offer_uuid.to_string(),
+            };
+            (Update::RejectBusinessOffer(response), tick_effective)
+        }
+        DomainActionOutcome::BusinessOfferWithdrawn {
+            request_uuid,
+            tick_effective,
+            business_offer_uuid,
+            refunded_amount,
+            ..
+        } => {
+            let response = WithdrawBusinessOfferResponse {
+                request_uuid: request_uuid.to_string(),
+                business_offer_uuid: business_offer_uuid.to_string(),
+                refunded_amount,
+            };
+            (Update::WithdrawBusinessOffer(response), tick_effective)
+        }
+        DomainActionOutcome::OperationalExpensesSet {
+            request_uuid,
+            tick_effective,
+            business_uuid,
+            operational_expenses,
+            previous_operational_expenses,
+            ..
+        } => {
+            let response = SetOperationalExpensesResponse {
+                request_uuid: request_uuid.to_string(),
+                business_uuid: business_uuid.to_string(),
+                operational_expenses,
+                previous_operational_expenses,
+            };
+            (Update::SetOperationalExpenses(response), tick_effective)
+        }
+        DomainActionOutcome::LoanTaken {
+            request_uuid,
+            tick_effective,
+            loan_uuid,
+            principal,
+            interest_rate_bps,
+            due_at_tick,
+            ..
+        } => {
+            let response = TakeLoanResponse {
+                request_uuid: request_uuid.to_string(),
+                loan: Some(Loan {
+                    uuid: loan_uuid.to_string(),
+                    principal,
+                    interest_rate_bps,
+                    due_at_tick,
+                }),
+            };
+            (Update::TakeLoan(response), tick_effective)
+        }
+        DomainActionOutcome::LoanRepaid {
+            request_uuid,
+            tick_effective,
+            loan_uuid,
+            amount,
+            outstanding_balance,
+            ..
+        } => {
+            let response = RepayLoanResponse {
+                request_uuid: request_uuid.to_string(),
+                loan_uuid: loan_uuid.to_string(),
+                amount,
+                outstanding_balance,
+            };
+            (Update::RepayLoan(response), tick_effective)
+        }
+        DomainActionOutcome::FundsTransferred {
+            request_uuid,
+            tick_effective,
+            recipient_corporation_uuid,
+            amount,
+            ..
+        } => {
+            let response = TransferFundsResponse {
+                request_uuid: request_uuid.to_string(),
+                recipient_corporation_uuid: recipient_corporation_uuid.to_string(),
+                amount,
+            };
+            (Update::TransferFunds(response), tick_effective)
+        }
+        DomainActionOutcome::ContractCreated {
+            request_uuid,
+            tick_effective,
+            contract_uuid,
+            payer_corporation_uuid,
+            payee_corporation_uuid,
+            amount,
+            condition,
+            due_at_tick,
+            ..
+        } => {
+            let response = CreateContractResponse {
+                request_uuid: request_uuid.to_string(),
+                contract: Some(contract_to_proto(
+                    contract_uuid,
+                    payer_corporation_uuid,
+                    payee_corporation_uuid,
+                    amount,
+                    condition,
+                    due_at_tick,
+                )),
+            };
+            (Update::CreateContract(response), tick_effective)
+        }
+        DomainActionOutcome::AuctionBidPlaced {
+            request_uuid,
+            tick_effective,
+            auction_bid_uuid,
+            business_listing_uuid,
+            business_uuid,
+            amount,
+            ..
+        } => {
+            let response = PlaceAuctionBidResponse {
+                request_uuid: request_uuid.to_string(),
+                auction_bid_uuid: auction_bid_uuid.to_string(),
+                business_listing_uuid: business_listing_uuid.to_string(),
+                business_uuid: business_uuid.to_string(),
+                amount,
+            };
+            (Update::PlaceAuctionBid(response), tick_effective)
+        }
+        DomainActionOutcome::BuildingAcquired {
+            request_uuid,
+            tick_effective,
+            business_uuid,
+            building_gml_id,
+            price,
+            upkeep,
+            ..
+        } => {
+            let response = AcquireBuildingResponse {
+                request_uuid: request_uuid.to_string(),
+                business_uuid: business_uuid.to_string(),
+                building_gml_id,
+                price,
+                upkeep,
+            };
+            (Update::AcquireBuilding(response), tick_effective)
+        }
+        DomainActionOutcome::IntelGathered {
+            request_uuid,
+            tick_effective,
+            target_corporation_uuid,
+            intel_type,
+            cost,
+            report,
+            detected,
+            ..
+        } => {
+            let response = GatherIntelResponse {
+                request_uuid: request_uuid.to_string(),
+                target_corporation_uuid: target_corporation_uuid.to_string(),
+                intel_type: intel_type_to_proto(intel_type).into(),
+                cost,
+                detected,
+                report: report.map(intel_report_to_proto),
+            };
+            (Update::GatherIntel(response), tick_effective)
+        }
+        DomainActionOutcome::MolePlanted {
+            request_uuid,
+            tick_effective,
+            target_corporation_uuid,
+            cost,
+            mole_uuid,
+            detected,
+            ..
+        } => {
+            let response = PlantMoleResponse {
+                request_uuid: request_uuid.to_string(),
+                target_corporation_uuid: target_corporation_uuid.to_string(),
+                cost,
+                detected,
+                mole_uuid: mole_uuid.map(|uuid| uuid.to_string()),
+            };
+            (Update::PlantMole(response), tick_effective)
+        }
+        DomainActionOutcome::CounterIntelConducted {
+            request_uuid,
+            tick_effective,
+            cost,
+            exposed_moles,
+            ..
+        } => {
+            let response = CounterIntelResponse {
+                request_uuid: request_uuid.to_string(),
+                cost,
+                exposed_moles: exposed_moles
+                    .into_iter()
+                    .map(|mole| ExposedMole {
+                        mole_uuid: mole.mole_uuid.to_string(),
+                        spy_corporation_uuid: mole.spy_corporation_uuid.to_string(),
+                    })
+                    .collect(),
+            };
+            (Update::CounterIntel(response), tick_effective)
+        }
+        DomainActionOutcome::TreatyProposed {
+            request_uuid,
+            tick_effective,
+            treaty_uuid,
+            treaty_type,
+            partner_corporation_uuid,
+            ..
+        } => {
+            let response = ProposeTreatyResponse {
+                request_uuid: request_uuid.to_string(),
+                treaty_uuid: treaty_uuid.to_string(),
+                treaty_type: treaty_type_to_proto(treaty_type).into(),
+                partner_corporation_uuid: partner_corporation_uuid.to_string(),
+            };
+            (Update::ProposeTreaty(response), tick_effective)
+        }
+        DomainActionOutcome::TreatyAccepted {
+            request_uuid,
+            tick_effective,
+            treaty_uuid,
+            treaty_type,
+            proposer_corporation_uuid,
+            ..
+        } => {
+            let response = AcceptTreatyResponse {
+                request_uuid: request_uuid.to_string(),
+                treaty_uuid: treaty_uuid.to_string(),
+                treaty_type: treaty_type_to_proto(treaty_type).into(),
+                proposer_corporation_uuid: proposer_corporation_uuid.to_string(),
+            };
+            (Update::AcceptTreaty(response), tick_effective)
+        }
+        DomainActionOutcome::TreatyBroken {
+            request_uuid,
+            tick_effective,
+            treaty_uuid,
+            treaty_type,
+            counterparty_corporation_uuid,
+            ..
+        } => {
+            let response = BreakTreatyResponse {
+                request_uuid: request_uuid.to_string(),
+                treaty_uuid: treaty_uuid.to_string(),
+                treaty_type: treaty_type_to_proto(treaty_type).into(),
+                counterparty_corporation_uuid: counterparty_corporation_uuid.to_string(),
+            };
+            (Update::BreakTreaty(response), tick_effective)
+        }
+        DomainActionOutcome::BusinessOfferClosed {
+            tick_effective,
+            business_offer_uuid,
+            business_uuid,
+            refunded_amount,
+            reason,
+            ..
+        } => {
+            let reason = match reason {
+                BusinessOfferCloseReason::Rejected => ProtoBusinessOfferCloseReason::Rejected,
+                BusinessOfferCloseReason::Expired => ProtoBusinessOfferCloseReason::Expired,
+            };
+            let notification = BusinessOfferClosedNotification {
+                business_offer_uuid: business_offer_uuid.to_string(),
+                business_uuid: business_uuid.to_string(),
+                refunded_amount,
+                reason: reason.into(),
+            };
+            (Update::BusinessOfferClosed(notification), tick_effective)
+        }
+        DomainActionOutcome::CorporationLedgerSummarized {
+            tick_effective,
+            corporation_uuid,
+            summary,
+            cash_balance,
+            ..
+        } => {
+            let notification = CorporationLedgerSummaryNotification {
+                corporation_uuid: corporation_uuid.to_string(),
+                business_income: summary.business_income,
+                operational_expenses: summary.operational_expenses,
+                acquisitions: summary.acquisitions,
+                sales: summary.sales,
+                unit_spawns: summary.unit_spawns,
+                escrow: summary.escrow,
+                loans: summary.loans,
+                buildings: summary.buildings,
+                territory_tax: summary.territory_tax,
+                espionage: summary.espionage,
+                transfers: summary.transfers,
+                net_change: summary.net_change(),
+                balance: cash_balance,
+            };
+            (
+                Update::CorporationLedgerSummary(notification),
+                tick_effective,
+            )
+        }
+        DomainActionOutcome::CorporationInsolvent {
+            tick_effective,
+            corporation_uuid,
+            cash_balance,
+            bankruptcy_at_tick,
+            ..
+        } => {
+            let notification = CorporationInsolventNotification {
+                corporation_uuid: corporation_uuid.to_string(),
+                balance: cash_balance,
+                bankruptcy_at_tick,
+            };
+            (Update::CorporationInsolvent(notification), tick_effective)
+        }
+        DomainActionOutcome::CorporationBankrupt {
+            tick_effective,
+            corporation_uuid,
+            liquidated_business_uuids,
+            disbanded_unit_uuids,
+            ..
+        } => {
+            let notification = CorporationBankruptNotification {
+                corporation_uuid: corporation_uuid.to_string(),
+                liquidated_business_uuids: liquidated_business_uuids
+                    .iter()
+                    .map(Uuid::to_string)
+                    .collect(),
+                disbanded_unit_uuids: disbanded_unit_uuids.iter().map(Uuid::to_string).collect(),
+            };
+            (Update::CorporationBankrupt(notification), tick_effective)
+        }
+        DomainActionOutcome::LoanDefaulted {
+            tick_effective,
+            loan_uuid,
+            collected_amount,
+            written_off_amount,
+            liquidated_business_uuids,
+            ..
+        } => {
+            let notification = LoanDefaultedNotification {
+                loan_uuid: loan_uuid.to_string(),
+                collected_amount,
+                written_off_amount,
+                liquidated_business_uuids: liquidated_business_uuids
+                    .iter()
+                    .map(Uuid::to_string)
+                    .collect(),
+            };
+            (Update::LoanDefaulted(notification), tick_effective)
+        }
+        DomainActionOutcome::FundsReceived {
+            tick_effective,
+            sender_corporation_uuid,
+            amount,
+            ..
+        } => {
+            let notification = FundsReceivedNotification {
+                sender_corporation_uuid: sender_corporation_uuid.to_string(),
+                amount,
+            };
+            (Update::FundsReceived(notification), tick_effective)
+        }
+        DomainActionOutcome::ContractReceived {
+            tick_effective,
+            contract_uuid,
+            payer_corporation_uuid,
+            payee_corporation_uuid,
+            amount,
+            condition,
+            due_at_tick,
+            ..
+        } => {
+            let notification = ContractReceivedNotification {
+                contract: Some(contract_to_proto(
+                    contract_uuid,
+                    payer_corporation_uuid,
+                    payee_corporation_uuid,
+                    amount,
+                    condition,
+                    due_at_tick,
+                )),
+            };
+            (Update::ContractReceived(notification), tick_effective)
+        }
+        DomainActionOutcome::ContractSettled {
+            tick_effective,
+            contract_uuid,
+            payer_corporation_uuid,
+            payee_corporation_uuid,
+            amount,
+            paid,
+            ..
+        } => {
+            let notification = ContractSettledNotification {
+                contract_uuid: contract_uuid.to_string(),
+                payer_corporation_uuid: payer_corporation_uuid.to_string(),
+                payee_corporation_uuid: payee_corporation_uuid.to_string(),
+                amount,
+                paid,
+            };
+            (Update::ContractSettled(notification), tick_effective)
+        }
+        DomainActionOutcome::CorporationStateChanged {
+            tick_effective,
+            corporation_uuid,
+            businesses_gained,
+            businesses_lost,
+            listings_sold,
+            units_updated,
+            units_removed,
+            ..
+        } => {
+            let notification = CorporationStateDiffNotification {
+                corporation_uuid: corporation_uuid.to_string(),
+                businesses_gained: businesses_gained.iter().map(Uuid::to_string).collect(),
+                businesses_lost: businesses_lost.iter().map(Uuid::to_string).collect(),
+                listings_sold: listings_sold.iter().map(Uuid::to_string).collect(),
+                units_updated: units_updated
+                    .into_iter()
+                    .map(|unit| Unit {
+                        uuid: unit.unit_uuid.to_string(),
+                        corporation_uuid: corporation_uuid.to_string(),
+                        unit_type: unit_type_to_proto(unit.unit_type).into(),
+                        health: unit.health,
+                        attack: unit.attack,
+                        defense: unit.defense,
+                        building_gml_id: unit.building_gml_id,
+                        destination_building_gml_id: unit.destination_building_gml_id,
+                        arrival_tick: unit.arrival_tick,
+                        attack_target_building_gml_id: unit.attack_target_building_gml_id,
+                    })
+                    .collect(),
+                units_removed: units_removed.iter().map(Uuid::to_string).collect(),
+            };
+            (Update::CorporationStateDiff(notification), tick_effective)
+        }
+        DomainActionOutcome::AuctionClosed {
+            tick_effective,
+            business_listing_uuid,
+            business_uuid,
+            winning_corporation_uuid,
+            winning_bid,
+            refunded_amount,
+            reason,
+            ..
+        } => {
+            let reason = match reason {
+                AuctionCloseReason::Sold => ProtoAuctionCloseReason::Sold,
+                AuctionCloseReason::Unsold => ProtoAuctionCloseReason::Unsold,
+                AuctionCloseReason::Cancelled => ProtoAuctionCloseReason::Cancelled,
+            };
+            let notification = AuctionClosedNotification {
+                business_listing_uuid: business_listing_uuid.to_string(),
+                business_uuid: business_uuid.to_string(),
+                winning_corporation_uuid: winning_corporation_uuid.map(|uuid| uuid.to_string()),
+                winning_bid,
+                refunded_amount,
+                reason: reason.into(),
+            };
+            (Update::AuctionClosed(notification), tick_effective)
+        }
+        DomainActionOutcome::EspionageDetected {
+            tick_effective,
+            spy_corporation_uuid,
+            operation,
+            ..
+        } => {
+            let (operation, intel_type) = match operation {
+                EspionageOperation::GatherIntel { intel_type } => (
+                    ProtoEspionageOperation::GatherIntel,
+                    intel_type_to_proto(intel_type),
+                ),
+                EspionageOperation::PlantMole => (
+                    ProtoEspionageOperation::PlantMole,
+                    ProtoIntelType::Unspecified,
+                ),
+            };
+            let notification = EspionageDetectedNotification {
+                spy_corporation_uuid: spy_corporation_uuid.to_string(),
+                operation: operation.into(),
+                intel_type: intel_type.into(),
+            };
+            (Update::EspionageDetected(notification), tick_effective)
+        }
+        DomainActionOutcome::MoleExposed {
+            tick_effective,
+            mole_uuid,
+            target_corporation_uuid,
+            ..
+        } => {
+            let notification = MoleExposedNotification {
+                mole_uuid: mole_uuid.to_string(),
+                target_corporation_uuid: target_corporation_uuid.to_string(),
+            };
+            (Update::MoleExposed(notification), tick_effective)
+        }
+        DomainActionOutcome::TreatyProposalReceived {
+            tick_effective,
+            treaty_uuid,
+            treaty_type,
+            proposer_corporation_uuid,
+            ..
+        } => {
+            let notification = TreatyProposalReceivedNotification {
+                treaty_uuid: treaty_uuid.to_string(),
+                treaty_type: treaty_type_to_proto(treaty_type).into(),
+                proposer_corporation_uuid: proposer_corporation_uuid.to_string(),
+            };
+            (Update::TreatyProposalReceived(notification), tick_effective)
+        }
+        DomainActionOutcome::TreatyConcluded {
+            tick_effective,
+            treaty_uuid,
+            treaty_type,
+            partner_corporation_uuid,
+            ..
+        } => {
+            let notification = TreatyConcludedNotification {
+                treaty_uuid: treaty_uuid.to_string(),
+                treaty_type: treaty_type_to_proto(treaty_type).into(),
+                partner_corporation_uuid: partner_corporation_uuid.to_string(),
+            };
+            (Update::TreatyConcluded(notification), tick_effective)
+        }
+        DomainActionOutcome::TreatyTerminated {
+            tick_effective,
+            treaty_uuid,
+            treaty_type,
+            breaking_corporation_uuid,
+            was_active,
+            ..
+        } => {
+            let notification = TreatyTerminatedNotification {
+                treaty_uuid: treaty_uuid.to_string(),
+                treaty_type: treaty_type_to_proto(treaty_type).into(),
+                breaking_corporation_uuid: breaking_corporation_uuid.to_string(),
+                was_active,
+            };
+            (Update::TreatyTerminated(notification), tick_effective)
+        }
         DomainActionOutcome::CorporationCreated {
             request_uuid,
             tick_effective,
@@ -504,6 +1793,8 @@ fn outcome_to_grpc_update(outcome: DomainActionOutcome) -> GameUpdate {
         }
         DomainActionOutcome::ActionFailed {
             reason,
+            code,
+            details,
             tick_processed,
             request_uuid,
             ..
@@ -511,6 +1802,8 @@ fn outcome_to_grpc_update(outcome: DomainActionOutcome) -> GameUpdate {
             Update::ActionFailedResponse(ActionFailedResponse {
                 request_uuid: request_uuid.to_string(),
                 reason,
+                code: action_failure_code_to_proto(code).into(),
+                details: details.map(action_failure_details_to_proto),
             }),
             tick_processed,
         ),
@@ -522,6 +1815,61 @@ fn outcome_to_grpc_update(outcome: DomainActionOutcome) -> GameUpdate {
     }
 }
 
+fn action_failure_code_to_proto(code: ActionFailureCode) -> ProtoActionFailureCode {
+    match code {
+        ActionFailureCode::Internal => ProtoActionFailureCode::Internal,
+        ActionFailureCode::InvalidArgument => ProtoActionFailureCode::InvalidArgument,
+        ActionFailureCode::NotFound => ProtoActionFailureCode::NotFound,
+        ActionFailureCode::NotOwner => ProtoActionFailureCode::NotOwner,
+        ActionFailureCode::AlreadyOwned => ProtoActionFailureCode::AlreadyOwned,
+        ActionFailureCode::AlreadyExists => ProtoActionFailureCode::AlreadyExists,
+        ActionFailureCode::InsufficientFunds => ProtoActionFailureCode::InsufficientFunds,
+        ActionFailureCode::CreditLimitExceeded => ProtoActionFailureCode::CreditLimitExceeded,
+        ActionFailureCode::Cooldown => ProtoActionFailureCode::Cooldown,
+        ActionFailureCode::InvalidState => ProtoActionFailureCode::InvalidState,
+        ActionFailureCode::ContestLost => ProtoActionFailureCode::ContestLost,
+    }
+}
+
+fn action_failure_details_to_proto(details: ActionFailureDetails) -> Details {
+    match details {
+        ActionFailureDetails::InsufficientFunds {
+            corporation_uuid,
+            required,
+            available,
+        } => Details::InsufficientFunds(InsufficientFundsDetails {
+            corporation_uuid: corporation_uuid.to_string(),
+            required,
+            available,
+        }),
+        ActionFailureDetails::InvalidValue { provided, min, max } => {
+            Details::InvalidValue(InvalidValueDetails { provided, min, max })
+        }
+        ActionFailureDetails::Entity {
+            entity_type,
+            entity_id,
+        } => {
+            let entity_type = match entity_type {
+                FailureEntityType::Corporation => ProtoFailureEntityType::Corporation,
+                FailureEntityType::Business => ProtoFailureEntityType::Business,
+                FailureEntityType::BusinessListing => ProtoFailureEntityType::BusinessListing,
+                FailureEntityType::BusinessOffer => ProtoFailureEntityType::BusinessOffer,
+                FailureEntityType::Unit => ProtoFailureEntityType::Unit,
+                FailureEntityType::Building => ProtoFailureEntityType::Building,
+                FailureEntityType::Loan => ProtoFailureEntityType::Loan,
+                FailureEntityType::Treaty => ProtoFailureEntityType::Treaty,
+            };
+            Details::Entity(EntityDetails {
+                entity_type: entity_type.into(),
+                entity_id,
+            })
+        }
+        ActionFailureDetails::Cooldown { available_at_tick } => {
+            Details::Cooldown(CooldownDetails { available_at_tick })
+        }
+    }
+}
+
 pub(super) async fn limitation_error_into_result<GTR>(
     err: LimitationError,
     get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
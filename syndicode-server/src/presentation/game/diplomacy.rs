@@ -0,0 +1,181 @@
+use crate::{
+    application::{
+        diplomacy::{
+            accept_treaty::AcceptTreatyUseCase, break_treaty::BreakTreatyUseCase,
+            propose_treaty::ProposeTreatyUseCase,
+        },
+        game::get_game_tick::GetGameTickUseCase,
+        ports::{game_tick::GameTickRepository, queuer::ActionQueueable},
+    },
+    domain::diplomacy::model::treaty_type::TreatyType,
+    presentation::error::PresentationError,
+};
+use bon::builder;
+use std::sync::Arc;
+use syndicode_proto::{
+    syndicode_diplomacy_v1::TreatyType as ProtoTreatyType,
+    syndicode_interface_v1::{game_update::Update, ActionInitResponse, GameUpdate},
+};
+use tonic::{Result, Status};
+use uuid::Uuid;
+
+#[builder]
+pub async fn propose_treaty<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    propose_treaty_uc: Arc<ProposeTreatyUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    partner_corporation_uuid: String,
+    treaty_type: i32,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(partner_corporation_uuid) = Uuid::parse_str(&partner_corporation_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid partner corporation UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    let Some(treaty_type) = ProtoTreatyType::try_from(treaty_type)
+        .ok()
+        .and_then(treaty_type_from_proto)
+    else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid treaty type".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match propose_treaty_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .partner_corporation_uuid(partner_corporation_uuid)
+        .treaty_type(treaty_type)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn accept_treaty<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    accept_treaty_uc: Arc<AcceptTreatyUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    treaty_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(treaty_uuid) = Uuid::parse_str(&treaty_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid treaty UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match accept_treaty_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .treaty_uuid(treaty_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn break_treaty<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    break_treaty_uc: Arc<BreakTreatyUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    treaty_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(treaty_uuid) = Uuid::parse_str(&treaty_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid treaty UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match break_treaty_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .treaty_uuid(treaty_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+fn treaty_type_from_proto(treaty_type: ProtoTreatyType) -> Option<TreatyType> {
+    match treaty_type {
+        ProtoTreatyType::Unspecified => None,
+        ProtoTreatyType::Alliance => Some(TreatyType::Alliance),
+        ProtoTreatyType::NonAggression => Some(TreatyType::NonAggression),
+        ProtoTreatyType::TradePact => Some(TreatyType::TradePact),
+    }
+}
+
+pub(super) fn treaty_type_to_proto(treaty_type: TreatyType) -> ProtoTreatyType {
+    match treaty_type {
+        TreatyType::Alliance => ProtoTreatyType::Alliance,
+        TreatyType::NonAggression => ProtoTreatyType::NonAggression,
+        TreatyType::TradePact => ProtoTreatyType::TradePact,
+    }
+}
@@ -1,10 +1,20 @@
 use crate::{
     application::{
         economy::{
+            accept_business_offer::AcceptBusinessOfferUseCase,
+            acquire_building::AcquireBuildingUseCase,
             acquire_listed_business::AcquireListedBusinessUseCase,
-            get_corporation::GetCorporationUseCase,
+            cancel_business_listing::CancelBusinessListingUseCase,
+            create_contract::CreateContractUseCase, get_corporation::GetCorporationUseCase,
+            list_business_for_sale::ListBusinessForSaleUseCase,
+            make_business_offer::MakeBusinessOfferUseCase,
+            place_auction_bid::PlaceAuctionBidUseCase,
             query_business_listings::QueryBusinessListingsUseCase,
             query_businesses::QueryBusinessesUseCase,
+            reject_business_offer::RejectBusinessOfferUseCase, repay_loan::RepayLoanUseCase,
+            set_operational_expenses::SetOperationalExpensesUseCase, take_loan::TakeLoanUseCase,
+            transfer_funds::TransferFundsUseCase,
+            withdraw_business_offer::WithdrawBusinessOfferUseCase,
         },
         game::get_game_tick::GetGameTickUseCase,
         ports::{game_tick::GameTickRepository, queuer::ActionQueueable},
@@ -15,6 +25,7 @@ use crate::{
             business_listing::repository::{
                 BusinessListingRepository, DomainBusinessListingSortBy,
             },
+            contract::model::condition::ContractCondition,
             corporation::repository::CorporationRepository,
         },
         repository::DomainSortDirection,
@@ -25,8 +36,8 @@ use bon::builder;
 use std::sync::Arc;
 use syndicode_proto::{
     syndicode_economy_v1::{
-        BusinessDetails, BusinessListingDetails, BusinessListingSortBy, BusinessSortBy,
-        Corporation, GetCorporationResponse, QueryBusinessListingsRequest,
+        BusinessDetails, BusinessListingDetails, BusinessListingSortBy, BusinessSortBy, Contract,
+        ContractConditionType, Corporation, GetCorporationResponse, QueryBusinessListingsRequest,
         QueryBusinessListingsResponse, QueryBusinessesRequest, QueryBusinessesResponse,
     },
     syndicode_interface_v1::{game_update::Update, ActionInitResponse, GameUpdate, SortDirection},
@@ -40,12 +51,13 @@ pub async fn get_corporation<GTR, CRP>(
     get_corporation_uc: Arc<GetCorporationUseCase<CRP>>,
     user_uuid: Uuid,
     request_uuid: Uuid,
+    at_game_tick: Option<i64>,
 ) -> Result<GameUpdate, Status>
 where
     GTR: GameTickRepository,
     CRP: CorporationRepository,
 {
-    match get_corporation_uc.execute(user_uuid).await {
+    match get_corporation_uc.execute(user_uuid, at_game_tick).await {
         Ok(outcome) => Ok(GameUpdate {
             game_tick: outcome.game_tick,
             update: Some(Update::GetCorporation(GetCorporationResponse {
@@ -110,6 +122,646 @@ where
     }
 }
 
+#[builder]
+pub async fn list_business_for_sale<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    list_business_for_sale_uc: Arc<ListBusinessForSaleUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_uuid: String,
+    asking_price: i64,
+    auction_duration_ticks: Option<i64>,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_uuid) = Uuid::parse_str(&business_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid business UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match list_business_for_sale_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_uuid(business_uuid)
+        .asking_price(asking_price)
+        .maybe_auction_duration_ticks(auction_duration_ticks)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn cancel_business_listing<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    cancel_business_listing_uc: Arc<CancelBusinessListingUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_listing_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_listing_uuid) = Uuid::parse_str(&business_listing_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid business listing UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match cancel_business_listing_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_listing_uuid(business_listing_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn make_business_offer<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    make_business_offer_uc: Arc<MakeBusinessOfferUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_uuid: String,
+    offer_price: i64,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_uuid) = Uuid::parse_str(&business_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid business UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match make_business_offer_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_uuid(business_uuid)
+        .offer_price(offer_price)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn accept_business_offer<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    accept_business_offer_uc: Arc<AcceptBusinessOfferUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_offer_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_offer_uuid) = Uuid::parse_str(&business_offer_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid business offer UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match accept_business_offer_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_offer_uuid(business_offer_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn reject_business_offer<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    reject_business_offer_uc: Arc<RejectBusinessOfferUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_offer_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_offer_uuid) = Uuid::parse_str(&business_offer_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid business offer UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match reject_business_offer_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_offer_uuid(business_offer_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn withdraw_business_offer<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    withdraw_business_offer_uc: Arc<WithdrawBusinessOfferUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_offer_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_offer_uuid) = Uuid::parse_str(&business_offer_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid business offer UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match withdraw_business_offer_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_offer_uuid(business_offer_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn set_operational_expenses<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    set_operational_expenses_uc: Arc<SetOperationalExpensesUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_uuid: String,
+    operational_expenses: i64,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_uuid) = Uuid::parse_str(&business_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid business UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match set_operational_expenses_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_uuid(business_uuid)
+        .operational_expenses(operational_expenses)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn take_loan<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    take_loan_uc: Arc<TakeLoanUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    amount: i64,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    match take_loan_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .amount(amount)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn repay_loan<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    repay_loan_uc: Arc<RepayLoanUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    loan_uuid: String,
+    amount: i64,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(loan_uuid) = Uuid::parse_str(&loan_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid loan UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match repay_loan_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .loan_uuid(loan_uuid)
+        .amount(amount)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn place_auction_bid<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    place_auction_bid_uc: Arc<PlaceAuctionBidUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_listing_uuid: String,
+    amount: i64,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_listing_uuid) = Uuid::parse_str(&business_listing_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid business listing UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match place_auction_bid_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_listing_uuid(business_listing_uuid)
+        .amount(amount)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn acquire_building<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    acquire_building_uc: Arc<AcquireBuildingUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    business_uuid: String,
+    building_gml_id: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(business_uuid) = Uuid::parse_str(&business_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid business UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match acquire_building_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .business_uuid(business_uuid)
+        .building_gml_id(building_gml_id)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn transfer_funds<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    transfer_funds_uc: Arc<TransferFundsUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    recipient_corporation_uuid: String,
+    amount: i64,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(recipient_corporation_uuid) = Uuid::parse_str(&recipient_corporation_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid recipient corporation UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match transfer_funds_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .recipient_corporation_uuid(recipient_corporation_uuid)
+        .amount(amount)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn create_contract<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    create_contract_uc: Arc<CreateContractUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    payee_corporation_uuid: String,
+    amount: i64,
+    due_at_tick: i64,
+    condition_type: i32,
+    business_uuid: Option<String>,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(payee_corporation_uuid) = Uuid::parse_str(&payee_corporation_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid payee corporation UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    let Some(condition) = contract_condition_from_proto(condition_type, business_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid contract condition".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match create_contract_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .payee_corporation_uuid(payee_corporation_uuid)
+        .amount(amount)
+        .due_at_tick(due_at_tick)
+        .condition(condition)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+/// Only the condition that refers to a business may and must carry its UUID.
+fn contract_condition_from_proto(
+    condition_type: i32,
+    business_uuid: Option<String>,
+) -> Option<ContractCondition> {
+    let business_uuid = match business_uuid {
+        Some(business_uuid) => Some(Uuid::parse_str(&business_uuid).ok()?),
+        None => None,
+    };
+
+    match (
+        ContractConditionType::try_from(condition_type).ok()?,
+        business_uuid,
+    ) {
+        (ContractConditionType::Unconditional, None) => Some(ContractCondition::Unconditional),
+        (ContractConditionType::PayerOwnsBusiness, Some(business_uuid)) => {
+            Some(ContractCondition::PayerOwnsBusiness { business_uuid })
+        }
+        (ContractConditionType::PeaceHolds, None) => Some(ContractCondition::PeaceHolds),
+        _ => None,
+    }
+}
+
+pub(super) fn contract_to_proto(
+    contract_uuid: Uuid,
+    payer_corporation_uuid: Uuid,
+    payee_corporation_uuid: Uuid,
+    amount: i64,
+    condition: ContractCondition,
+    due_at_tick: i64,
+) -> Contract {
+    let condition_type = match condition {
+        ContractCondition::Unconditional => ContractConditionType::Unconditional,
+        ContractCondition::PayerOwnsBusiness { .. } => ContractConditionType::PayerOwnsBusiness,
+        ContractCondition::PeaceHolds => ContractConditionType::PeaceHolds,
+    };
+
+    Contract {
+        uuid: contract_uuid.to_string(),
+        payer_corporation_uuid: payer_corporation_uuid.to_string(),
+        payee_corporation_uuid: payee_corporation_uuid.to_string(),
+        amount,
+        condition_type: condition_type.into(),
+        business_uuid: condition
+            .business_uuid()
+            .map(|business_uuid| business_uuid.to_string()),
+        due_at_tick,
+    }
+}
+
 #[builder]
 pub async fn query_business_listings<GTR, BL>(
     get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
@@ -156,12 +808,14 @@ where
         .maybe_seller_corporation_uuid(seller_corporation_uuid)
         .maybe_min_operational_expenses(req.min_operational_expenses)
         .maybe_max_operational_expenses(req.max_operational_expenses)
+        .maybe_is_auction(req.is_auction)
         .maybe_min_asking_price(req.min_asking_price)
         .maybe_max_asking_price(req.max_asking_price)
         .maybe_sort_by(maybe_sort_by)
         .maybe_sort_direction(maybe_domain_sort_direction)
         .maybe_limit(req.limit)
         .maybe_offset(req.offset)
+        .maybe_at_game_tick(req.at_game_tick)
         .call()
         .await
     {
@@ -184,6 +838,7 @@ where
                     headquarter_latitude: l.headquarter_latitude,
                     image_number: l.image_number as i32,
                     market_number: l.market_number as i32,
+                    auction_closes_at_tick: l.auction_closes_at_tick,
                 };
 
                 listings.push(listing);
@@ -214,6 +869,7 @@ pub async fn query_businesses<GTR, B>(
     query_businesses_uc: Arc<QueryBusinessesUseCase<B>>,
     req: QueryBusinessesRequest,
     request_uuid: Uuid,
+    req_user_uuid: Uuid,
 ) -> Result<GameUpdate, Status>
 where
     GTR: GameTickRepository,
@@ -247,6 +903,7 @@ where
 
     match query_businesses_uc
         .execute()
+        .req_user_uuid(req_user_uuid)
         .maybe_owning_corporation_uuid(owning_corporation_uuid)
         .maybe_market_uuid(market_uuid)
         .maybe_min_operational_expenses(req.min_operational_expenses)
@@ -255,6 +912,7 @@ where
         .maybe_sort_direction(maybe_domain_sort_direction)
         .maybe_limit(req.limit)
         .maybe_offset(req.offset)
+        .maybe_at_game_tick(req.at_game_tick)
         .call()
         .await
     {
@@ -276,6 +934,7 @@ where
                     headquarter_latitude: b.headquarter_latitude,
                     image_number: b.image_number as i32,
                     market_number: b.market_number as i32,
+                    operational_expenses_estimated: b.operational_expenses_estimated,
                 };
 
                 businesses.push(business);
@@ -0,0 +1,201 @@
+use crate::{
+    application::{
+        espionage::{
+            counter_intel::CounterIntelUseCase, gather_intel::GatherIntelUseCase,
+            plant_mole::PlantMoleUseCase,
+        },
+        game::get_game_tick::GetGameTickUseCase,
+        ports::{game_tick::GameTickRepository, queuer::ActionQueueable},
+    },
+    domain::espionage::model::intel::{IntelReport, IntelType},
+    presentation::error::PresentationError,
+};
+use bon::builder;
+use std::sync::Arc;
+use syndicode_proto::{
+    syndicode_espionage_v1::{
+        intel_report::Report, BalanceIntel, HoldingsIntel, IntelReport as ProtoIntelReport,
+        IntelType as ProtoIntelType, ListingIntel, ListingsIntel,
+    },
+    syndicode_interface_v1::{game_update::Update, ActionInitResponse, GameUpdate},
+};
+use tonic::{Result, Status};
+use uuid::Uuid;
+
+#[builder]
+pub async fn gather_intel<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    gather_intel_uc: Arc<GatherIntelUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    target_corporation_uuid: String,
+    intel_type: i32,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(target_corporation_uuid) = Uuid::parse_str(&target_corporation_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid target corporation UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    let Some(intel_type) = ProtoIntelType::try_from(intel_type)
+        .ok()
+        .and_then(intel_type_from_proto)
+    else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid intel type".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match gather_intel_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .target_corporation_uuid(target_corporation_uuid)
+        .intel_type(intel_type)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn plant_mole<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    plant_mole_uc: Arc<PlantMoleUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    target_corporation_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(target_corporation_uuid) = Uuid::parse_str(&target_corporation_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update =
+            PresentationError::InvalidArgument("Invalid target corporation UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match plant_mole_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .target_corporation_uuid(target_corporation_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn counter_intel<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    counter_intel_uc: Arc<CounterIntelUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    match counter_intel_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+fn intel_type_from_proto(intel_type: ProtoIntelType) -> Option<IntelType> {
+    match intel_type {
+        ProtoIntelType::Unspecified => None,
+        ProtoIntelType::Balance => Some(IntelType::Balance),
+        ProtoIntelType::Holdings => Some(IntelType::Holdings),
+        ProtoIntelType::Listings => Some(IntelType::Listings),
+    }
+}
+
+pub(super) fn intel_type_to_proto(intel_type: IntelType) -> ProtoIntelType {
+    match intel_type {
+        IntelType::Balance => ProtoIntelType::Balance,
+        IntelType::Holdings => ProtoIntelType::Holdings,
+        IntelType::Listings => ProtoIntelType::Listings,
+    }
+}
+
+pub(super) fn intel_report_to_proto(report: IntelReport) -> ProtoIntelReport {
+    let report = match report {
+        IntelReport::Balance { cash_balance } => Report::Balance(BalanceIntel { cash_balance }),
+        IntelReport::Holdings { business_uuids } => Report::Holdings(HoldingsIntel {
+            business_uuids: business_uuids
+                .into_iter()
+                .map(|uuid| uuid.to_string())
+                .collect(),
+        }),
+        IntelReport::Listings { listings } => Report::Listings(ListingsIntel {
+            listings: listings
+                .into_iter()
+                .map(|listing| ListingIntel {
+                    business_listing_uuid: listing.business_listing_uuid.to_string(),
+                    business_uuid: listing.business_uuid.to_string(),
+                    asking_price: listing.asking_price,
+                    auction_closes_at_tick: listing.auction_closes_at_tick,
+                })
+                .collect(),
+        }),
+    };
+
+    ProtoIntelReport {
+        report: Some(report),
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    application::{game::get_game_tick::GetGameTickUseCase, ports::game_tick::GameTickRepository},
+    domain::world_event::model::{topic::WorldEventTopic, WorldEvent},
+    presentation::error::PresentationError,
+};
+use bon::builder;
+use dashmap::DashMap;
+use std::sync::Arc;
+use syndicode_proto::syndicode_interface_v1::{
+    game_update::Update, world_event_notification::Event, world_event_topic::Topic,
+    BuildingOwnerChangedEvent, BuildingOwnershipTopic, BusinessListedEvent, BusinessListingsTopic,
+    GameUpdate, SubscribeResponse, UnsubscribeResponse, WorldEventNotification,
+    WorldEventTopic as ProtoWorldEventTopic,
+};
+use tonic::{Result, Status};
+use uuid::Uuid;
+
+// Type alias for the map storing the world event subscriptions of the connected users, keyed by user UUID.
+pub type UserSubscriptions = Arc<DashMap<Uuid, Vec<Subscription>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Subscription {
+    pub uuid: Uuid,
+    pub topic: WorldEventTopic,
+}
+
+#[builder]
+pub async fn subscribe<GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    user_subscriptions: UserSubscriptions,
+    max_subscriptions_per_user: usize,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    topic: Option<ProtoWorldEventTopic>,
+) -> Result<GameUpdate, Status>
+where
+    GTR: GameTickRepository,
+{
+    let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+    let topic = match topic_from_proto(topic) {
+        Ok(topic) => topic,
+        Err(err) => return Ok(err.into_game_update(game_tick, request_uuid.to_string())),
+    };
+
+    let subscription = Subscription {
+        uuid: Uuid::now_v7(),
+        topic,
+    };
+
+    {
+        let mut subscriptions = user_subscriptions.entry(req_user_uuid).or_default();
+
+        if subscriptions.len() >= max_subscriptions_per_user {
+            let err = PresentationError::ResourceExhausted(format!(
+                "Only {max_subscriptions_per_user} subscriptions are allowed per user"
+            ));
+
+            return Ok(err.into_game_update(game_tick, request_uuid.to_string()));
+        }
+
+        subscriptions.push(subscription);
+    }
+
+    Ok(GameUpdate {
+        game_tick,
+        update: Some(Update::Subscribe(SubscribeResponse {
+            request_uuid: request_uuid.to_string(),
+            subscription_uuid: subscription.uuid.to_string(),
+            topic: Some(topic_to_proto(topic)),
+        })),
+    })
+}
+
+#[builder]
+pub async fn unsubscribe<GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    user_subscriptions: UserSubscriptions,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    subscription_uuid: String,
+) -> Result<GameUpdate, Status>
+where
+    GTR: GameTickRepository,
+{
+    let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+    let Ok(subscription_uuid) = Uuid::parse_str(&subscription_uuid) else {
+        let game_update =
+            PresentationError::InvalidArgument("Invalid subscription UUID".to_string())
+                .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    let removed = user_subscriptions
+        .get_mut(&req_user_uuid)
+        .and_then(|mut subscriptions| {
+            let position = subscriptions
+                .iter()
+                .position(|subscription| subscription.uuid == subscription_uuid)?;
+
+            Some(subscriptions.remove(position))
+        });
+
+    if removed.is_none() {
+        return Ok(
+            PresentationError::NotFound.into_game_update(game_tick, request_uuid.to_string())
+        );
+    }
+
+    Ok(GameUpdate {
+        game_tick,
+        update: Some(Update::Unsubscribe(UnsubscribeResponse {
+            request_uuid: request_uuid.to_string(),
+            subscription_uuid: subscription_uuid.to_string(),
+        })),
+    })
+}
+
+/// Builds the notification for a world event, if it matches any of the given subscriptions.
+pub fn world_event_to_grpc_update(
+    game_tick: i64,
+    event: &WorldEvent,
+    subscriptions: &[Subscription],
+) -> Option<GameUpdate> {
+    let subscription_uuids: Vec<String> = subscriptions
+        .iter()
+        .filter(|subscription| subscription.topic.matches(event))
+        .map(|subscription| subscription.uuid.to_string())
+        .collect();
+
+    if subscription_uuids.is_empty() {
+        return None;
+    }
+
+    let event = match event.clone() {
+        WorldEvent::BusinessListed {
+            listing_uuid,
+            business_uuid,
+            market_uuid,
+            seller_corporation_uuid,
+            asking_price,
+            auction_closes_at_tick,
+        } => Event::BusinessListed(BusinessListedEvent {
+            listing_uuid: listing_uuid.to_string(),
+            business_uuid: business_uuid.to_string(),
+            market_uuid: market_uuid.to_string(),
+            seller_corporation_uuid: seller_corporation_uuid.map(|uuid| uuid.to_string()),
+            asking_price,
+            auction_closes_at_tick,
+        }),
+        WorldEvent::BuildingOwnerChanged {
+            building_gml_id,
+            longitude,
+            latitude,
+            previous_owner_corporation_uuid,
+            owning_corporation_uuid,
+        } => Event::BuildingOwnerChanged(BuildingOwnerChangedEvent {
+            building_gml_id,
+            longitude,
+            latitude,
+            previous_owner_corporation_uuid: previous_owner_corporation_uuid
+                .map(|uuid| uuid.to_string()),
+            owning_corporation_uuid: owning_corporation_uuid.map(|uuid| uuid.to_string()),
+        }),
+    };
+
+    Some(GameUpdate {
+        game_tick,
+        update: Some(Update::WorldEvent(WorldEventNotification {
+            subscription_uuids,
+            event: Some(event),
+        })),
+    })
+}
+
+fn topic_from_proto(
+    topic: Option<ProtoWorldEventTopic>,
+) -> Result<WorldEventTopic, PresentationError> {
+    match topic.and_then(|topic| topic.topic) {
+        Some(Topic::BusinessListings(topic)) => {
+            let market_uuid = topic
+                .market_uuid
+                .map(|market_uuid| Uuid::parse_str(&market_uuid))
+                .transpose()
+                .map_err(|_| {
+                    PresentationError::InvalidArgument("Invalid market UUID".to_string())
+                })?;
+
+            if topic
+                .max_asking_price
+                .is_some_and(|max_asking_price| max_asking_price < 0)
+            {
+                return Err(PresentationError::InvalidArgument(
+                    "Maximum asking price must not be negative".to_string(),
+                ));
+            }
+
+            Ok(WorldEventTopic::BusinessListings {
+                market_uuid,
+                max_asking_price: topic.max_asking_price,
+            })
+        }
+        Some(Topic::BuildingOwnership(topic)) => {
+            let is_valid = [
+                topic.min_longitude,
+                topic.min_latitude,
+                topic.max_longitude,
+                topic.max_latitude,
+            ]
+            .iter()
+            .all(|coordinate| coordinate.is_finite())
+                && topic.min_longitude <= topic.max_longitude
+                && topic.min_latitude <= topic.max_latitude;
+
+            if !is_valid {
+                return Err(PresentationError::InvalidArgument(
+                    "Invalid bounding box".to_string(),
+                ));
+            }
+
+            Ok(WorldEventTopic::BuildingOwnership {
+                min_longitude: topic.min_longitude,
+                min_latitude: topic.min_latitude,
+                max_longitude: topic.max_longitude,
+                max_latitude: topic.max_latitude,
+            })
+        }
+        None => Err(PresentationError::InvalidArgument(
+            "Missing subscription topic".to_string(),
+        )),
+    }
+}
+
+fn topic_to_proto(topic: WorldEventTopic) -> ProtoWorldEventTopic {
+    let topic = match topic {
+        WorldEventTopic::BusinessListings {
+            market_uuid,
+            max_asking_price,
+        } => Topic::BusinessListings(BusinessListingsTopic {
+            market_uuid: market_uuid.map(|uuid| uuid.to_string()),
+            max_asking_price,
+        }),
+        WorldEventTopic::BuildingOwnership {
+            min_longitude,
+            min_latitude,
+            max_longitude,
+            max_latitude,
+        } => Topic::BuildingOwnership(BuildingOwnershipTopic {
+            min_longitude,
+            min_latitude,
+            max_longitude,
+            max_latitude,
+        }),
+    };
+
+    ProtoWorldEventTopic { topic: Some(topic) }
+}
@@ -6,6 +6,8 @@ use tokio::sync::mpsc;
 use tonic::Status;
 use uuid::Uuid;
 
+use super::subscriptions::UserSubscriptions;
+
 // Type alias for the sender part of the channel for game updates to a specific user.
 pub type UserTx = mpsc::Sender<Result<GameUpdate, Status>>;
 // Type alias for the map storing user channels, keyed by user UUID.
@@ -17,6 +19,7 @@ pub type UserChannels = Arc<DashMap<Uuid, Arc<UserTx>>>;
 pub struct UserChannelGuard {
     pub user_id: Uuid,
     pub channels: UserChannels, // The shared map of all user channels
+    pub subscriptions: UserSubscriptions, // The shared map of all world event subscriptions
     pub channel_instance: Arc<UserTx>, // The specific channel instance this guard manages
 }
 
@@ -59,6 +62,8 @@ impl Drop for UserChannelGuard {
                 "UserChannelGuard drop: Successfully processed and removed entry for user {} via remove_if.",
                 self.user_id
             );
+            // Subscriptions belong to the user's stream, a newer connection keeps them.
+            self.subscriptions.remove(&self.user_id);
         } else {
             // This case means either:
             // 1. The user_id was not found in the map when remove_if was called.
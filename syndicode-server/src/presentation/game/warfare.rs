@@ -3,17 +3,22 @@ use crate::{
         game::get_game_tick::GetGameTickUseCase,
         ports::{game_tick::GameTickRepository, queuer::ActionQueueable},
         warfare::{
-            list_units_by_corporation::ListUnitsByCorporationUseCase, spawn_unit::SpawnUnitUseCase,
+            attack_building::AttackBuildingUseCase,
+            list_units_by_corporation::ListUnitsByCorporationUseCase, move_unit::MoveUnitUseCase,
+            spawn_unit::SpawnUnitUseCase,
         },
     },
-    domain::unit::repository::UnitRepository,
+    domain::{
+        economy::corporation::repository::CorporationRepository,
+        unit::{model::unit_type::UnitType, repository::UnitRepository},
+    },
     presentation::error::PresentationError,
 };
 use bon::builder;
 use std::sync::Arc;
 use syndicode_proto::{
     syndicode_interface_v1::{game_update::Update, ActionInitResponse, GameUpdate},
-    syndicode_warfare_v1::{ListUnitsResponse, Unit},
+    syndicode_warfare_v1::{ListUnitsResponse, Unit, UnitType as ProtoUnitType},
 };
 use tonic::{Result, Status};
 use uuid::Uuid;
@@ -24,15 +29,31 @@ pub async fn spawn_unit<Q, GTR>(
     spawn_unit_uc: Arc<SpawnUnitUseCase<Q, GTR>>,
     request_uuid: Uuid,
     req_user_uuid: Uuid,
+    unit_type: i32,
+    building_gml_id: String,
 ) -> Result<GameUpdate, Status>
 where
     Q: ActionQueueable,
     GTR: GameTickRepository,
 {
+    let Some(unit_type) = ProtoUnitType::try_from(unit_type)
+        .ok()
+        .and_then(unit_type_from_proto)
+    else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid unit type".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
     match spawn_unit_uc
         .execute()
         .req_user_uuid(req_user_uuid)
         .request_uuid(request_uuid)
+        .unit_type(unit_type)
+        .building_gml_id(building_gml_id)
         .call()
         .await
     {
@@ -51,15 +72,111 @@ where
 }
 
 #[builder]
-pub async fn list_units<GTR, UNT>(
+pub async fn move_unit<Q, GTR>(
     get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
-    list_units_by_corporation_uc: Arc<ListUnitsByCorporationUseCase<UNT>>,
+    move_unit_uc: Arc<MoveUnitUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    unit_uuid: String,
+    destination_building_gml_id: String,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(unit_uuid) = Uuid::parse_str(&unit_uuid) else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid unit UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match move_unit_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .unit_uuid(unit_uuid)
+        .destination_building_gml_id(destination_building_gml_id)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn attack_building<Q, GTR>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    attack_building_uc: Arc<AttackBuildingUseCase<Q, GTR>>,
+    request_uuid: Uuid,
+    req_user_uuid: Uuid,
+    building_gml_id: String,
+    unit_uuids: Vec<String>,
+) -> Result<GameUpdate, Status>
+where
+    Q: ActionQueueable,
+    GTR: GameTickRepository,
+{
+    let Ok(unit_uuids) = unit_uuids
+        .iter()
+        .map(|unit_uuid| Uuid::parse_str(unit_uuid))
+        .collect::<Result<Vec<Uuid>, _>>()
+    else {
+        let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+        let game_update = PresentationError::InvalidArgument("Invalid unit UUID".to_string())
+            .into_game_update(game_tick, request_uuid.to_string());
+
+        return Ok(game_update);
+    };
+
+    match attack_building_uc
+        .execute()
+        .req_user_uuid(req_user_uuid)
+        .request_uuid(request_uuid)
+        .building_gml_id(building_gml_id)
+        .unit_uuids(unit_uuids)
+        .call()
+        .await
+    {
+        Ok(game_tick) => Ok(GameUpdate {
+            game_tick,
+            update: Some(Update::ActionInitResponse(ActionInitResponse {
+                request_uuid: request_uuid.to_string(),
+            })),
+        }),
+        Err(err) => {
+            let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
+
+            Ok(PresentationError::from(err).into_game_update(game_tick, request_uuid.to_string()))
+        }
+    }
+}
+
+#[builder]
+pub async fn list_units<GTR, UNT, CRP>(
+    get_game_tick_uc: Arc<GetGameTickUseCase<GTR>>,
+    list_units_by_corporation_uc: Arc<ListUnitsByCorporationUseCase<UNT, CRP>>,
     corporation_uuid: String,
     request_uuid: Uuid,
+    req_user_uuid: Uuid,
 ) -> Result<GameUpdate, Status>
 where
     GTR: GameTickRepository,
     UNT: UnitRepository,
+    CRP: CorporationRepository,
 {
     let Ok(corporation_uuid) = Uuid::parse_str(&corporation_uuid) else {
         let game_tick = get_game_tick_uc.execute().await.unwrap_or_default();
@@ -73,16 +190,26 @@ where
 
     match list_units_by_corporation_uc
         .execute()
+        .req_user_uuid(req_user_uuid)
         .corporation_uuid(corporation_uuid)
         .call()
         .await
     {
         Ok(outcome) => {
             let mut unit_infos = Vec::<Unit>::with_capacity(outcome.units.len());
-            for u in outcome.units {
+            for details in outcome.units {
+                let u = details.unit;
                 unit_infos.push(Unit {
                     uuid: u.uuid.to_string(),
                     corporation_uuid: u.corporation_uuid.to_string(),
+                    unit_type: unit_type_to_proto(u.unit_type).into(),
+                    health: u.health,
+                    attack: u.attack,
+                    defense: u.defense,
+                    building_gml_id: details.building_gml_id,
+                    destination_building_gml_id: details.destination_building_gml_id,
+                    arrival_tick: u.arrival_tick,
+                    attack_target_building_gml_id: details.attack_target_building_gml_id,
                 });
             }
 
@@ -101,3 +228,20 @@ where
         }
     }
 }
+
+fn unit_type_from_proto(unit_type: ProtoUnitType) -> Option<UnitType> {
+    match unit_type {
+        ProtoUnitType::Unspecified => None,
+        ProtoUnitType::Enforcer => Some(UnitType::Enforcer),
+        ProtoUnitType::Drone => Some(UnitType::Drone),
+        ProtoUnitType::Mech => Some(UnitType::Mech),
+    }
+}
+
+pub(super) fn unit_type_to_proto(unit_type: UnitType) -> ProtoUnitType {
+    match unit_type {
+        UnitType::Enforcer => ProtoUnitType::Enforcer,
+        UnitType::Drone => ProtoUnitType::Drone,
+        UnitType::Mech => ProtoUnitType::Mech,
+    }
+}
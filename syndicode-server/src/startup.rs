@@ -1,13 +1,17 @@
 mod logging;
 mod provider;
+mod replay;
 mod server;
 
 use crate::{
     application::leader::LeaderLoopManager,
-    cli::Cli,
+    cli::{Cli, Command},
     config::ServerConfig,
     infrastructure::{postgres::PostgresDatabase, valkey::ValkeyStore},
-    presentation::{broadcaster::GameTickBroadcaster, game::user_channel_guard::UserChannels},
+    presentation::{
+        broadcaster::GameTickBroadcaster,
+        game::{subscriptions::UserSubscriptions, user_channel_guard::UserChannels},
+    },
 };
 use clap::Parser;
 use dashmap::DashMap;
@@ -22,9 +26,20 @@ pub async fn start_server() -> anyhow::Result<()> {
     logging::init();
 
     let user_channels: UserChannels = Arc::new(DashMap::new());
+    let user_subscriptions: UserSubscriptions = Arc::new(DashMap::new());
 
     let pg_db = Arc::new(PostgresDatabase::new(config.clone()).await?);
 
+    if let Some(Command::Replay { from_tick, to_tick }) = cli.command {
+        return replay::run_replay()
+            .config(config)
+            .pg_db(pg_db)
+            .from_tick(from_tick)
+            .to_tick(to_tick)
+            .call()
+            .await;
+    }
+
     let valkey_store = Arc::new(ValkeyStore::new(config.clone()).await?);
 
     let provider = AppProvider::build_services()
@@ -33,6 +48,7 @@ pub async fn start_server() -> anyhow::Result<()> {
         .pg_db(pg_db.clone())
         .valkey(valkey_store.clone())
         .user_channels(user_channels.clone())
+        .user_subscriptions(user_subscriptions.clone())
         .call()
         .await?;
 
@@ -59,6 +75,7 @@ pub async fn start_server() -> anyhow::Result<()> {
     let broadcaster = GameTickBroadcaster::builder()
         .valkey_client(valkey_store.get_client())
         .user_channels(user_channels.clone())
+        .user_subscriptions(user_subscriptions.clone())
         .build();
     broadcaster.spawn_listen_and_broadcast_task();
 
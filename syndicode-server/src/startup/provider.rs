@@ -8,18 +8,43 @@ use crate::{
             login::LoginUseCase, register_user::RegisterUserUseCase,
             resend_verification::ResendVerificationUseCase, verify_user::VerifyUserUseCase,
         },
+        diplomacy::{
+            accept_treaty::AcceptTreatyUseCase, break_treaty::BreakTreatyUseCase,
+            list_treaties::ListTreatiesUseCase, propose_treaty::ProposeTreatyUseCase,
+        },
         economy::{
+            accept_business_offer::AcceptBusinessOfferUseCase,
+            acquire_building::AcquireBuildingUseCase,
             acquire_listed_business::AcquireListedBusinessUseCase,
-            bootstrap::BootstrapEconomyUseCase, get_corporation::GetCorporationUseCase,
+            bootstrap::BootstrapEconomyUseCase,
+            cancel_business_listing::CancelBusinessListingUseCase,
+            create_contract::CreateContractUseCase,
+            get_building_locations::GetBuildingLocationsUseCase,
+            get_corporation::GetCorporationUseCase,
+            get_corporation_ledger::GetCorporationLedgerUseCase,
+            list_auction_bids::ListAuctionBidsUseCase,
+            list_building_locations::ListBuildingLocationsUseCase,
             list_building_ownerships::ListBuildingOwnershipsUseCase,
+            list_business_for_sale::ListBusinessForSaleUseCase,
             list_business_listings::ListBusinessListingUseCase,
             list_business_offers::ListBusinessOffersUseCase,
-            list_businesses::ListBusinessesUseCase, list_corporations::ListCorporationsUseCase,
-            list_markets::ListMarketsUseCase, query_buildings::QueryBuildingsUseCase,
+            list_businesses::ListBusinessesUseCase, list_contracts::ListContractsUseCase,
+            list_corporations::ListCorporationsUseCase, list_loans::ListLoansUseCase,
+            list_markets::ListMarketsUseCase, make_business_offer::MakeBusinessOfferUseCase,
+            place_auction_bid::PlaceAuctionBidUseCase, query_buildings::QueryBuildingsUseCase,
             query_business_listings::QueryBusinessListingsUseCase,
-            query_businesses::QueryBusinessesUseCase,
+            query_businesses::QueryBusinessesUseCase, query_markets::QueryMarketsUseCase,
+            query_territories::QueryTerritoriesUseCase,
+            reject_business_offer::RejectBusinessOfferUseCase, repay_loan::RepayLoanUseCase,
+            seed_wards::SeedWardsUseCase, set_operational_expenses::SetOperationalExpensesUseCase,
+            take_loan::TakeLoanUseCase, transfer_funds::TransferFundsUseCase,
+            withdraw_business_offer::WithdrawBusinessOfferUseCase,
+        },
+        espionage::{
+            counter_intel::CounterIntelUseCase, gather_intel::GatherIntelUseCase,
+            list_moles::ListMolesUseCase, plant_mole::PlantMoleUseCase,
         },
-        game::get_game_tick::GetGameTickUseCase,
+        game::{get_game_tick::GetGameTickUseCase, load_game_state::LoadGameStateUseCase},
         init::InitializationOrchestrator,
         ports::{
             crypto::{JwtHandler, PasswordHandler},
@@ -38,7 +63,8 @@ use crate::{
         },
         processor::GameTickProcessor,
         warfare::{
-            list_units::ListUnitsUseCase, list_units_by_corporation::ListUnitsByCorporationUseCase,
+            attack_building::AttackBuildingUseCase, list_units::ListUnitsUseCase,
+            list_units_by_corporation::ListUnitsByCorporationUseCase, move_unit::MoveUnitUseCase,
             spawn_unit::SpawnUnitUseCase,
         },
     },
@@ -48,7 +74,8 @@ use crate::{
         economy::{
             building::repository::BuildingRepository, business::repository::BusinessRepository,
             business_listing::repository::BusinessListingRepository,
-            corporation::repository::CorporationRepository,
+            corporation::repository::CorporationRepository, ledger::repository::LedgerRepository,
+            market::repository::MarketRepository, territory::repository::TerritoryRepository,
         },
         simulation::SimulationService,
         unit::repository::UnitRepository,
@@ -59,12 +86,16 @@ use crate::{
         email::EmailHandler,
         http::HttpBackupDownloader,
         postgres::{
+            diplomacy::PgTreatyService,
             economy::{
-                building::PgBuildingService, building_ownership::PgBuildingOwnershipService,
-                business::PgBusinessService, business_listing::PgBusinessListingService,
-                business_offer::PgBusinessOfferService, corporation::PgCorporationService,
-                market::PgMarketService,
+                auction_bid::PgAuctionBidService, building::PgBuildingService,
+                building_ownership::PgBuildingOwnershipService, business::PgBusinessService,
+                business_listing::PgBusinessListingService, business_offer::PgBusinessOfferService,
+                contract::PgContractService, corporation::PgCorporationService,
+                ledger::PgLedgerService, loan::PgLoanService, market::PgMarketService,
+                territory::PgTerritoryService,
             },
+            espionage::PgMoleService,
             game_tick::PgGameTickService,
             init::PgInitializationService,
             migration::PostgresMigrator,
@@ -80,7 +111,7 @@ use crate::{
         admin::AdminPresenter,
         auth::AuthPresenter,
         economy::EconomyPresenter,
-        game::{user_channel_guard::UserChannels, GamePresenter},
+        game::{subscriptions::UserSubscriptions, user_channel_guard::UserChannels, GamePresenter},
     },
 };
 use bon::bon;
@@ -104,7 +135,13 @@ pub type DefaultProvider = AppProvider<
         PgBusinessService,
         PgBusinessListingService,
         PgBusinessOfferService,
+        PgLoanService,
+        PgAuctionBidService,
+        PgMoleService,
+        PgTreatyService,
+        PgContractService,
         PgBuildingOwnershipService,
+        PgBuildingService,
     >,
     CryptoService,
     CryptoService,
@@ -124,6 +161,9 @@ pub type DefaultProvider = AppProvider<
     HttpBackupDownloader,
     PgRestoreExecutor,
     PgBusinessService,
+    PgLedgerService,
+    PgMarketService,
+    PgTerritoryService,
 >;
 
 pub struct AppProvider<
@@ -147,6 +187,9 @@ pub struct AppProvider<
     DOW,
     RES,
     B,
+    LED,
+    MRK,
+    TER,
 > where
     INI: InitializationRepository + 'static,
     G: GameTickProcessable + 'static,
@@ -168,6 +211,9 @@ pub struct AppProvider<
     DOW: BackupDownloader + 'static,
     RES: DatabaseRestorer + 'static,
     B: BusinessRepository + 'static,
+    LED: LedgerRepository + 'static,
+    MRK: MarketRepository + 'static,
+    TER: TerritoryRepository + 'static,
 {
     pub game_tick_processor: Arc<G>,
     pub leader_elector: Arc<L>,
@@ -176,7 +222,7 @@ pub struct AppProvider<
     pub game_presenter: GamePresenter<R, Q, UNT, CRP, RSR, GTR, BL, B>,
     pub admin_presenter: AdminPresenter<Q, R, P, USR, CRP>,
     pub auth_presenter: AuthPresenter<R, P, J, UOW, USR, VS, Q, CRP>,
-    pub economy_presenter: EconomyPresenter<R, BUI, CRP, B, BL, Q, GTR>,
+    pub economy_presenter: EconomyPresenter<R, BUI, CRP, B, BL, Q, GTR, LED, MRK, TER>,
 }
 
 #[bon]
@@ -188,6 +234,7 @@ impl DefaultProvider {
         pg_db: Arc<PostgresDatabase>,
         valkey: Arc<ValkeyStore>,
         user_channels: UserChannels,
+        user_subscriptions: UserSubscriptions,
     ) -> anyhow::Result<DefaultProvider> {
         tracing::info!("Setting up the provider...");
 
@@ -211,13 +258,35 @@ impl DefaultProvider {
         let game_tick_service = Arc::new(PgGameTickService::new(pg_db.clone()));
         let user_service = Arc::new(PgUserService::new(pg_db.clone(), PgUserRepository));
         let unit_service = Arc::new(PgUnitService::new(pg_db.clone()));
-        let corporation_service = Arc::new(PgCorporationService::new(pg_db.clone()));
+        let corporation_service = Arc::new(PgCorporationService::new(
+            pg_db.clone(),
+            config.game.tick_retention(),
+        ));
         let market_service = Arc::new(PgMarketService::new(pg_db.clone()));
-        let business_service = Arc::new(PgBusinessService::new(pg_db.clone()));
-        let business_listing_service = Arc::new(PgBusinessListingService::new(pg_db.clone()));
+        let business_service = Arc::new(PgBusinessService::new(
+            pg_db.clone(),
+            config.game.tick_retention(),
+        ));
+        let business_listing_service = Arc::new(PgBusinessListingService::new(
+            pg_db.clone(),
+            config.game.tick_retention(),
+        ));
         let business_offer_service = Arc::new(PgBusinessOfferService::new(pg_db.clone()));
-        let building_service = Arc::new(PgBuildingService::new(pg_db.clone()));
+        let loan_service = Arc::new(PgLoanService::new(pg_db.clone()));
+        let auction_bid_service = Arc::new(PgAuctionBidService::new(pg_db.clone()));
+        let mole_service = Arc::new(PgMoleService::new(pg_db.clone()));
+        let treaty_service = Arc::new(PgTreatyService::new(pg_db.clone()));
+        let contract_service = Arc::new(PgContractService::new(pg_db.clone()));
+        let building_service = Arc::new(PgBuildingService::new(
+            pg_db.clone(),
+            config.game.tick_retention(),
+        ));
         let building_ownership_service = Arc::new(PgBuildingOwnershipService::new(pg_db.clone()));
+        let ledger_service = Arc::new(PgLedgerService::new(pg_db.clone()));
+        let territory_service = Arc::new(PgTerritoryService::new(
+            pg_db.clone(),
+            config.game.tick_retention(),
+        ));
 
         // System use cases
         let get_game_tick_uc = Arc::new(
@@ -280,6 +349,7 @@ impl DefaultProvider {
         let list_units_by_corporation_uc = Arc::new(
             ListUnitsByCorporationUseCase::builder()
                 .unit_repository(unit_service.clone())
+                .corporation_repo(corporation_service.clone())
                 .build(),
         );
         let spawn_unit_uc = Arc::new(
@@ -288,6 +358,18 @@ impl DefaultProvider {
                 .game_tick_repo(game_tick_service.clone())
                 .build(),
         );
+        let move_unit_uc = Arc::new(
+            MoveUnitUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let attack_building_uc = Arc::new(
+            AttackBuildingUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
         let verify_user_uc = Arc::new(VerifyUserUseCase::builder().uow(uow.clone()).build());
         let resend_verification_uc = Arc::new(
             ResendVerificationUseCase::builder()
@@ -303,6 +385,120 @@ impl DefaultProvider {
                 .game_tick_repo(game_tick_service.clone())
                 .build(),
         );
+        let list_business_for_sale_uc = Arc::new(
+            ListBusinessForSaleUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let cancel_business_listing_uc = Arc::new(
+            CancelBusinessListingUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let make_business_offer_uc = Arc::new(
+            MakeBusinessOfferUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let accept_business_offer_uc = Arc::new(
+            AcceptBusinessOfferUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let reject_business_offer_uc = Arc::new(
+            RejectBusinessOfferUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let withdraw_business_offer_uc = Arc::new(
+            WithdrawBusinessOfferUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let set_operational_expenses_uc = Arc::new(
+            SetOperationalExpensesUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let take_loan_uc = Arc::new(
+            TakeLoanUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let repay_loan_uc = Arc::new(
+            RepayLoanUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let place_auction_bid_uc = Arc::new(
+            PlaceAuctionBidUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let acquire_building_uc = Arc::new(
+            AcquireBuildingUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let gather_intel_uc = Arc::new(
+            GatherIntelUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let plant_mole_uc = Arc::new(
+            PlantMoleUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let counter_intel_uc = Arc::new(
+            CounterIntelUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let propose_treaty_uc = Arc::new(
+            ProposeTreatyUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let accept_treaty_uc = Arc::new(
+            AcceptTreatyUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let break_treaty_uc = Arc::new(
+            BreakTreatyUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let transfer_funds_uc = Arc::new(
+            TransferFundsUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
+        let create_contract_uc = Arc::new(
+            CreateContractUseCase::builder()
+                .action_queuer(valkey.clone())
+                .game_tick_repo(game_tick_service.clone())
+                .build(),
+        );
         let bootstrap_economy_uc = Arc::new(
             BootstrapEconomyUseCase::builder()
                 .uow(uow.clone())
@@ -310,16 +506,40 @@ impl DefaultProvider {
                 .config(config.clone())
                 .build(),
         );
+        let seed_wards_uc = Arc::new(
+            SeedWardsUseCase::builder()
+                .uow(uow.clone())
+                .init_repo(init_service.clone())
+                .build(),
+        );
         let get_corporation_uc = Arc::new(
             GetCorporationUseCase::builder()
                 .corporation_repo(corporation_service.clone())
                 .build(),
         );
+        let get_corporation_ledger_uc = Arc::new(
+            GetCorporationLedgerUseCase::builder()
+                .corporation_repo(corporation_service.clone())
+                .ledger_repo(ledger_service.clone())
+                .build(),
+        );
         let list_corporations_uc = Arc::new(
             ListCorporationsUseCase::builder()
                 .corporation_repo(corporation_service.clone())
                 .build(),
         );
+        let query_markets_uc = Arc::new(
+            QueryMarketsUseCase::builder()
+                .market_repo(market_service.clone())
+                .build(),
+        );
+        let query_territories_uc = Arc::new(
+            QueryTerritoriesUseCase::builder()
+                .config(config.clone())
+                .corporation_repo(corporation_service.clone())
+                .territory_repo(territory_service.clone())
+                .build(),
+        );
         let list_markets_uc = Arc::new(
             ListMarketsUseCase::builder()
                 .market_repo(market_service.clone())
@@ -340,11 +560,46 @@ impl DefaultProvider {
                 .business_offer_repo(business_offer_service.clone())
                 .build(),
         );
+        let list_loans_uc = Arc::new(
+            ListLoansUseCase::builder()
+                .loan_repo(loan_service.clone())
+                .build(),
+        );
+        let list_auction_bids_uc = Arc::new(
+            ListAuctionBidsUseCase::builder()
+                .auction_bid_repo(auction_bid_service.clone())
+                .build(),
+        );
+        let list_moles_uc = Arc::new(
+            ListMolesUseCase::builder()
+                .mole_repo(mole_service.clone())
+                .build(),
+        );
+        let list_treaties_uc = Arc::new(
+            ListTreatiesUseCase::builder()
+                .treaty_repo(treaty_service.clone())
+                .build(),
+        );
+        let list_contracts_uc = Arc::new(
+            ListContractsUseCase::builder()
+                .contract_repo(contract_service.clone())
+                .build(),
+        );
         let list_building_ownerships = Arc::new(
             ListBuildingOwnershipsUseCase::builder()
                 .building_ownership_repo(building_ownership_service.clone())
                 .build(),
         );
+        let list_building_locations_uc = Arc::new(
+            ListBuildingLocationsUseCase::builder()
+                .building_repo(building_service.clone())
+                .build(),
+        );
+        let get_building_locations_uc = Arc::new(
+            GetBuildingLocationsUseCase::builder()
+                .building_repo(building_service.clone())
+                .build(),
+        );
         let query_business_listings_uc = Arc::new(
             QueryBusinessListingsUseCase::builder()
                 .business_listing_repo(business_listing_service.clone())
@@ -357,6 +612,7 @@ impl DefaultProvider {
         );
         let query_businesses_uc = Arc::new(
             QueryBusinessesUseCase::builder()
+                .config(config.clone())
                 .business_repo(business_service.clone())
                 .build(),
         );
@@ -372,11 +628,28 @@ impl DefaultProvider {
                 .downloader(http_downloader)
                 .bootstrap_admin_uc(bootstrap_admin_uc)
                 .bootstrap_economy_uc(bootstrap_economy_uc)
+                .seed_wards_uc(seed_wards_uc)
                 .maybe_restore_url(cli.restore.clone())
                 .build(),
         );
 
-        let simulation = Arc::new(SimulationService);
+        let load_game_state_uc = LoadGameStateUseCase::builder()
+            .list_units_uc(list_units_uc)
+            .list_corporations_uc(list_corporations_uc)
+            .list_markets_uc(list_markets_uc)
+            .list_businesses_uc(list_businesses_uc)
+            .list_business_listings_uc(list_business_listings_uc)
+            .list_business_offers_uc(list_business_offers_uc)
+            .list_loans_uc(list_loans_uc)
+            .list_auction_bids_uc(list_auction_bids_uc)
+            .list_moles_uc(list_moles_uc)
+            .list_treaties_uc(list_treaties_uc)
+            .list_contracts_uc(list_contracts_uc)
+            .list_building_ownerships_uc(list_building_ownerships)
+            .list_building_locations_uc(list_building_locations_uc)
+            .get_building_locations_uc(get_building_locations_uc)
+            .build();
+        let simulation = Arc::new(SimulationService::builder().config(config.clone()).build());
         let game_state = Arc::new(Mutex::new(None));
         let game_tick_processor = Arc::new(
             GameTickProcessor::builder()
@@ -386,15 +659,10 @@ impl DefaultProvider {
                 .simulation(simulation.clone())
                 .game_tick_repo(game_tick_service.clone())
                 .state(game_state)
-                .list_corporations_uc(list_corporations_uc.clone())
-                .list_units_uc(list_units_uc.clone())
                 .uow(uow.clone())
                 .init_repo(init_service.clone())
-                .list_markets_uc(list_markets_uc.clone())
-                .list_businesses_uc(list_businesses_uc.clone())
-                .list_business_listings_uc(list_business_listings_uc.clone())
-                .list_business_offers_uc(list_business_offers_uc.clone())
-                .list_building_ownerships(list_building_ownerships)
+                .load_game_state_uc(load_game_state_uc)
+                .tick_retention(config.game.tick_retention())
                 .build(),
         );
 
@@ -402,13 +670,36 @@ impl DefaultProvider {
         let game_presenter = GamePresenter::builder()
             .limit(valkey.clone())
             .user_channels(user_channels.clone())
+            .user_subscriptions(user_subscriptions)
+            .max_subscriptions_per_user(config.stream.max_subscriptions_per_user)
             .get_game_tick_uc(get_game_tick_uc.clone())
             .list_units_by_corporation_uc(list_units_by_corporation_uc.clone())
             .outcome_store_reader(valkey.clone())
             .spawn_unit_uc(spawn_unit_uc.clone())
+            .move_unit_uc(move_unit_uc.clone())
+            .attack_building_uc(attack_building_uc.clone())
             .get_corporation_uc(get_corporation_uc.clone())
             .valkey_client(valkey.get_client())
             .acquire_listed_business_uc(acquire_listed_business_uc.clone())
+            .list_business_for_sale_uc(list_business_for_sale_uc.clone())
+            .cancel_business_listing_uc(cancel_business_listing_uc.clone())
+            .make_business_offer_uc(make_business_offer_uc.clone())
+            .accept_business_offer_uc(accept_business_offer_uc.clone())
+            .reject_business_offer_uc(reject_business_offer_uc.clone())
+            .withdraw_business_offer_uc(withdraw_business_offer_uc.clone())
+            .set_operational_expenses_uc(set_operational_expenses_uc.clone())
+            .take_loan_uc(take_loan_uc.clone())
+            .repay_loan_uc(repay_loan_uc.clone())
+            .place_auction_bid_uc(place_auction_bid_uc.clone())
+            .acquire_building_uc(acquire_building_uc.clone())
+            .gather_intel_uc(gather_intel_uc)
+            .plant_mole_uc(plant_mole_uc)
+            .counter_intel_uc(counter_intel_uc)
+            .propose_treaty_uc(propose_treaty_uc)
+            .accept_treaty_uc(accept_treaty_uc)
+            .break_treaty_uc(break_treaty_uc)
+            .transfer_funds_uc(transfer_funds_uc)
+            .create_contract_uc(create_contract_uc)
             .query_business_listings_uc(query_business_listings_uc.clone())
             .query_businesses_uc(query_businesses_uc.clone())
             .build();
@@ -432,9 +723,23 @@ impl DefaultProvider {
         let economy_presenter = EconomyPresenter::builder()
             .query_buildings_uc(query_buildings_uc.clone())
             .get_corporation_uc(get_corporation_uc.clone())
+            .get_corporation_ledger_uc(get_corporation_ledger_uc.clone())
             .query_businesses_uc(query_businesses_uc.clone())
             .query_business_listings_uc(query_business_listings_uc.clone())
+            .query_markets_uc(query_markets_uc.clone())
+            .query_territories_uc(query_territories_uc.clone())
             .acquire_listed_business_uc(acquire_listed_business_uc.clone())
+            .list_business_for_sale_uc(list_business_for_sale_uc.clone())
+            .cancel_business_listing_uc(cancel_business_listing_uc.clone())
+            .make_business_offer_uc(make_business_offer_uc.clone())
+            .accept_business_offer_uc(accept_business_offer_uc.clone())
+            .reject_business_offer_uc(reject_business_offer_uc.clone())
+            .withdraw_business_offer_uc(withdraw_business_offer_uc.clone())
+            .set_operational_expenses_uc(set_operational_expenses_uc.clone())
+            .take_loan_uc(take_loan_uc.clone())
+            .repay_loan_uc(repay_loan_uc.clone())
+            .place_auction_bid_uc(place_auction_bid_uc.clone())
+            .acquire_building_uc(acquire_building_uc.clone())
             .limit(valkey.clone())
             .build();
 
@@ -0,0 +1,174 @@
+use crate::{
+    application::{
+        diplomacy::list_treaties::ListTreatiesUseCase,
+        economy::{
+            get_building_locations::GetBuildingLocationsUseCase,
+            list_auction_bids::ListAuctionBidsUseCase,
+            list_building_locations::ListBuildingLocationsUseCase,
+            list_building_ownerships::ListBuildingOwnershipsUseCase,
+            list_business_listings::ListBusinessListingUseCase,
+            list_business_offers::ListBusinessOffersUseCase,
+            list_businesses::ListBusinessesUseCase, list_contracts::ListContractsUseCase,
+            list_corporations::ListCorporationsUseCase, list_loans::ListLoansUseCase,
+            list_markets::ListMarketsUseCase,
+        },
+        espionage::list_moles::ListMolesUseCase,
+        game::{load_game_state::LoadGameStateUseCase, replay_ticks::ReplayTicksUseCase},
+        warfare::list_units::ListUnitsUseCase,
+    },
+    config::ServerConfig,
+    domain::simulation::SimulationService,
+    infrastructure::postgres::{
+        action_log::PgActionLogService,
+        diplomacy::PgTreatyService,
+        economy::{
+            auction_bid::PgAuctionBidService, building::PgBuildingService,
+            building_ownership::PgBuildingOwnershipService, business::PgBusinessService,
+            business_listing::PgBusinessListingService, business_offer::PgBusinessOfferService,
+            contract::PgContractService, corporation::PgCorporationService, loan::PgLoanService,
+            market::PgMarketService,
+        },
+        espionage::PgMoleService,
+        unit::PgUnitService,
+        PostgresDatabase,
+    },
+};
+use bon::builder;
+use std::sync::Arc;
+
+/// Replays the recorded ticks against the database and fails if any tick diverges.
+#[builder]
+pub async fn run_replay(
+    config: Arc<ServerConfig>,
+    pg_db: Arc<PostgresDatabase>,
+    from_tick: i64,
+    to_tick: i64,
+) -> anyhow::Result<()> {
+    tracing::info!(from_tick, to_tick, "Replaying recorded ticks...");
+
+    let building_service = Arc::new(PgBuildingService::new(
+        pg_db.clone(),
+        config.game.tick_retention(),
+    ));
+
+    let load_game_state_uc = LoadGameStateUseCase::builder()
+        .list_units_uc(Arc::new(
+            ListUnitsUseCase::builder()
+                .unit_repository(Arc::new(PgUnitService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_corporations_uc(Arc::new(
+            ListCorporationsUseCase::builder()
+                .corporation_repo(Arc::new(PgCorporationService::new(
+                    pg_db.clone(),
+                    config.game.tick_retention(),
+                )))
+                .build(),
+        ))
+        .list_markets_uc(Arc::new(
+            ListMarketsUseCase::builder()
+                .market_repo(Arc::new(PgMarketService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_businesses_uc(Arc::new(
+            ListBusinessesUseCase::builder()
+                .business_repo(Arc::new(PgBusinessService::new(
+                    pg_db.clone(),
+                    config.game.tick_retention(),
+                )))
+                .build(),
+        ))
+        .list_business_listings_uc(Arc::new(
+            ListBusinessListingUseCase::builder()
+                .business_listing_repo(Arc::new(PgBusinessListingService::new(
+                    pg_db.clone(),
+                    config.game.tick_retention(),
+                )))
+                .build(),
+        ))
+        .list_business_offers_uc(Arc::new(
+            ListBusinessOffersUseCase::builder()
+                .business_offer_repo(Arc::new(PgBusinessOfferService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_loans_uc(Arc::new(
+            ListLoansUseCase::builder()
+                .loan_repo(Arc::new(PgLoanService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_auction_bids_uc(Arc::new(
+            ListAuctionBidsUseCase::builder()
+                .auction_bid_repo(Arc::new(PgAuctionBidService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_moles_uc(Arc::new(
+            ListMolesUseCase::builder()
+                .mole_repo(Arc::new(PgMoleService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_treaties_uc(Arc::new(
+            ListTreatiesUseCase::builder()
+                .treaty_repo(Arc::new(PgTreatyService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_contracts_uc(Arc::new(
+            ListContractsUseCase::builder()
+                .contract_repo(Arc::new(PgContractService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_building_ownerships_uc(Arc::new(
+            ListBuildingOwnershipsUseCase::builder()
+                .building_ownership_repo(Arc::new(PgBuildingOwnershipService::new(pg_db.clone())))
+                .build(),
+        ))
+        .list_building_locations_uc(Arc::new(
+            ListBuildingLocationsUseCase::builder()
+                .building_repo(building_service.clone())
+                .build(),
+        ))
+        .get_building_locations_uc(Arc::new(
+            GetBuildingLocationsUseCase::builder()
+                .building_repo(building_service)
+                .build(),
+        ))
+        .build();
+
+    let replay_ticks_uc = ReplayTicksUseCase::builder()
+        .simulation(Arc::new(
+            SimulationService::builder().config(config).build(),
+        ))
+        .action_log_repo(Arc::new(PgActionLogService::new(pg_db)))
+        .load_game_state_uc(load_game_state_uc)
+        .build();
+
+    let report = replay_ticks_uc
+        .execute()
+        .from_tick(from_tick)
+        .to_tick(to_tick)
+        .call()
+        .await?;
+
+    match report.divergence {
+        None => {
+            tracing::info!(
+                replayed_ticks = report.replayed_ticks,
+                "Replay finished. All ticks match their recording."
+            );
+            Ok(())
+        }
+        Some(divergence) => {
+            tracing::error!(
+                tick = divergence.game_tick,
+                recorded_state_checksum = divergence.recorded_state_checksum,
+                replayed_state_checksum = divergence.replayed_state_checksum,
+                recorded_outcomes_checksum = divergence.recorded_outcomes_checksum,
+                replayed_outcomes_checksum = divergence.replayed_outcomes_checksum,
+                "Replay diverged from the recording."
+            );
+            Err(anyhow::anyhow!(
+                "Tick {} diverged from its recording",
+                divergence.game_tick
+            ))
+        }
+    }
+}